@@ -31,4 +31,8 @@ pub mod public {
     pub mod serializers {
         pub use crate::serializers::*;
     }
+
+    pub mod tx {
+        pub use crate::tx::*;
+    }
 }
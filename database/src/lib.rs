@@ -29,6 +29,9 @@ pub trait Database: Clone + Send + Sync + 'static {
     /// Add or overwrite new key - value
     fn put(&self, key: Vec<u8>, value: Vec<u8>);
 
+    /// Remove key - value if it exists
+    fn delete(&self, key: &[u8]);
+
     /// Iterate over values in database. Uses lexicographical order
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_;
 
@@ -39,6 +39,10 @@ impl Database for MemDB {
             .insert(key, value);
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.store.write().expect("poisoned lock").remove(key);
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.store
             .read()
@@ -28,6 +28,11 @@ impl<T: Database> Database for PrefixDB<T> {
         self.db.put(key, value)
     }
 
+    fn delete(&self, key: &[u8]) {
+        let key = [&self.prefix, key].concat();
+        self.db.delete(&key)
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         let prefix_length = self.prefix.len();
 
@@ -80,6 +85,18 @@ mod tests {
         assert_eq!(prefix_db.get(&[2]), Some(vec![1, 2, 3]));
     }
 
+    #[test]
+    fn delete_works() {
+        let db = Arc::new(MemDB::new());
+        db.put(vec![1, 1], vec![1]);
+        db.put(vec![2, 1], vec![2]);
+        let prefix_db = PrefixDB::new(Arc::clone(&db), vec![2]);
+        prefix_db.delete(&[1]);
+
+        assert!(prefix_db.get(&[1]).is_none());
+        assert_eq!(db.get(&[1, 1]), Some(vec![1]));
+    }
+
     #[test]
     fn iterator_works() {
         let db = MemDB::new();
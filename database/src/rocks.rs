@@ -46,6 +46,12 @@ impl Database for RocksDB {
             .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.db
+            .delete(key)
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.db
             .iterator(rocksdb::IteratorMode::Start)
@@ -35,6 +35,10 @@ impl Database for SledDb {
         let _ = self.0.insert(key, value).unwrap_or_corrupt();
     }
 
+    fn delete(&self, key: &[u8]) {
+        let _ = self.0.remove(key).unwrap_or_corrupt();
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.0
             .iter()
@@ -1,18 +1,23 @@
 use crate::{
     config::AppConfig,
     genesis::GenesisState,
+    hooks::GaiaStakingHooks,
     message::Message,
     modules::{GaiaModules, GaiaXmodules},
     store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
     GaiaNodeQueryRequest, GaiaNodeQueryResponse,
 };
+use evidence::RawEquivocation;
+use feemarket::abci_handler::FeeMarketAbciHandler;
+use gears::baseapp::genesis::NullGenesis;
+use gears::store::database::Database;
 use gears::tendermint::types::request::query::RequestQuery;
 use gears::types::tx::raw::TxWithRaw;
+use gears::x::{keepers::staking::KeeperHooks, module::Module};
 use gears::{application::handlers::node::ABCIHandler, x::ante::BaseAnteHandler};
 use gears::{application::handlers::node::ModuleInfo, context::init::InitContext};
 use gears::{application::handlers::node::TxError, config::Config};
 use gears::{baseapp::errors::QueryError, context::query::QueryContext};
-use gears::{baseapp::BaseAppParamsKeeper, store::database::Database};
 use gears::{context::tx::TxContext, x::ante::DefaultSignGasConsumer};
 use genutil::abci_handler::GenutilAbciHandler;
 use gov::{
@@ -20,10 +25,21 @@ use gov::{
     keeper::GovKeeper,
     proposal::{Proposals, ProposalsHandler},
 };
-use upgrade::{dummy::NullUpgradeHandler, keeper::UpgradeKeeper};
+use mint::{abci_handler::MintAbciHandler, keeper::MintKeeper};
+use params::abci_handler::ParamsAbciHandler;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use upgrade::{abci_handler::UpgradeAbciHandler, dummy::NullUpgradeHandler, keeper::UpgradeKeeper};
 
 #[derive(Debug, Clone)]
-struct BankModuleInfo;
+pub(crate) struct AuthModuleInfo;
+
+impl ModuleInfo for AuthModuleInfo {
+    const NAME: &'static str = "auth";
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BankModuleInfo;
 
 impl ModuleInfo for BankModuleInfo {
     const NAME: &'static str = "bank";
@@ -37,7 +53,7 @@ impl ModuleInfo for IbcModuleInfo {
 }
 
 #[derive(Debug, Clone)]
-struct StakingModuleInfo;
+pub(crate) struct StakingModuleInfo;
 
 impl ModuleInfo for StakingModuleInfo {
     const NAME: &'static str = "staking";
@@ -50,12 +66,82 @@ impl ModuleInfo for GovModuleInfo {
     const NAME: &'static str = "gov";
 }
 
+#[derive(Debug, Clone)]
+struct DistributionModuleInfo;
+
+impl ModuleInfo for DistributionModuleInfo {
+    const NAME: &'static str = "distribution";
+}
+
+#[derive(Debug, Clone)]
+struct MintModuleInfo;
+
+impl ModuleInfo for MintModuleInfo {
+    const NAME: &'static str = "mint";
+}
+
+#[derive(Debug, Clone)]
+struct FeeMarketModuleInfo;
+
+impl ModuleInfo for FeeMarketModuleInfo {
+    const NAME: &'static str = "feemarket";
+}
+
+#[derive(Debug, Clone)]
+struct UpgradeModuleInfo;
+
+impl ModuleInfo for UpgradeModuleInfo {
+    const NAME: &'static str = "upgrade";
+}
+
+pub type GaiaAuthKeeper = auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>;
+
+pub type GaiaBankKeeper =
+    bank::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaAuthKeeper, GaiaModules>;
+
+pub type StakingKeeper<KH> = staking::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    KH,
+    GaiaModules,
+>;
+
+type NoHooks = staking::MockHookKeeper<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>;
+
+// Keepers which are called from staking hooks can't hold the staking keeper with the same
+// hooks, that would make the type recursive. All staking keepers share the same store, so
+// the only difference between them is which hooks are triggered by their state changes.
+
+/// Distribution only reads the staking state, so it doesn't need any hooks.
+pub type GaiaDistributionKeeper = distribution::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    StakingKeeper<NoHooks>,
+    GaiaModules,
+>;
+
+/// Slashing jails and slashes validators, which has to be tracked by distribution.
+pub type GaiaSlashingKeeper = slashing::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    StakingKeeper<GaiaDistributionKeeper>,
+    GaiaModules,
+>;
+
+pub type GaiaStakingKeeper = StakingKeeper<GaiaStakingHooks>;
+
+pub type GaiaFeeMarketKeeper = feemarket::keeper::Keeper<GaiaStoreKey, GaiaParamsStoreKey>;
+
 #[derive(Debug, Clone)]
 pub struct GaiaABCIHandler {
     bank_abci_handler: bank::BankABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaAuthKeeper,
         GaiaModules,
         BankModuleInfo,
     >,
@@ -63,94 +149,98 @@ pub struct GaiaABCIHandler {
     staking_abci_handler: staking::StakingABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        staking::MockHookKeeper<
-            GaiaStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        GaiaStakingHooks,
         GaiaModules,
         StakingModuleInfo,
     >,
-    ibc_abci_handler: ibc_rs::ABCIHandler<GaiaStoreKey, GaiaParamsStoreKey, IbcModuleInfo>,
+    distribution_abci_handler: distribution::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        StakingKeeper<NoHooks>,
+        GaiaModules,
+    >,
+    slashing_abci_handler: slashing::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        StakingKeeper<GaiaDistributionKeeper>,
+        GaiaModules,
+    >,
+    evidence_abci_handler: evidence::ABCIHandler<
+        GaiaStoreKey,
+        GaiaStakingKeeper,
+        GaiaSlashingKeeper,
+        RawEquivocation,
+        GaiaModules,
+    >,
+    mint_abci_handler: MintAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaBankKeeper,
+        GaiaStakingKeeper,
+        GaiaModules,
+        MintModuleInfo,
+    >,
+    feemarket_abci_handler:
+        FeeMarketAbciHandler<GaiaStoreKey, GaiaParamsStoreKey, FeeMarketModuleInfo>,
+    upgrade_abci_handler: UpgradeAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaXmodules,
+        NullUpgradeHandler,
+        UpgradeModuleInfo,
+    >,
+    params_abci_handler: ParamsAbciHandler<GaiaStoreKey, GaiaParamsStoreKey>,
+    ibc_abci_handler: ibc_rs::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaBankKeeper,
+        GaiaModules,
+        IbcModuleInfo,
+    >,
     ante_handler: BaseAnteHandler<
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaBankKeeper,
+        GaiaAuthKeeper,
         GaiaStoreKey,
         DefaultSignGasConsumer,
         GaiaModules,
+        GaiaFeeMarketKeeper,
     >,
     genutil_handler: GenutilAbciHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        staking::MockHookKeeper<
-            GaiaStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        GaiaStakingHooks,
         GaiaModules,
         DefaultSignGasConsumer,
+        GaiaFeeMarketKeeper,
     >,
     gov_handler: GovAbciHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
         GaiaModules,
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        staking::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            bank::Keeper<
-                GaiaStoreKey,
-                GaiaParamsStoreKey,
-                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-                GaiaModules,
-            >,
-            staking::MockHookKeeper<
-                GaiaStoreKey,
-                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-                GaiaModules,
-            >,
-            GaiaModules,
-        >,
+        GaiaBankKeeper,
+        GaiaAuthKeeper,
+        GaiaStakingKeeper,
         Proposals<GaiaParamsStoreKey>,
         ProposalsHandler<
             GaiaStoreKey,
             GaiaParamsStoreKey,
-            BaseAppParamsKeeper<GaiaParamsStoreKey>,
             GaiaXmodules,
             NullUpgradeHandler, // Note: this is actual handler for upgrade handling. Not upgrade proposal
+            GaiaModules,
+            GaiaBankKeeper,
         >,
         GovModuleInfo,
     >,
 }
 
 impl GaiaABCIHandler {
-    pub fn new(_cfg: Config<AppConfig>) -> GaiaABCIHandler {
+    pub fn new(cfg: Config<AppConfig>) -> GaiaABCIHandler {
         let auth_keeper = auth::Keeper::new(
             GaiaStoreKey::Auth,
             GaiaParamsStoreKey::Auth,
@@ -163,31 +253,80 @@ impl GaiaABCIHandler {
             auth_keeper.clone(),
         );
 
-        let staking_keeper = staking::Keeper::new(
-            GaiaStoreKey::Staking,
-            GaiaParamsStoreKey::Staking,
+        // module accounts are not allowed to receive rewards, except the distribution one
+        // which holds them until withdrawal
+        let blocked_addrs: HashMap<String, bool> = GaiaModules::iter()
+            .filter(|module| *module != GaiaModules::Distribution)
+            .map(|module| (module.address().to_string(), true))
+            .collect();
+
+        let distribution_keeper: GaiaDistributionKeeper = distribution::Keeper::new(
+            GaiaStoreKey::Distribution,
+            GaiaParamsStoreKey::Distribution,
             auth_keeper.clone(),
             bank_keeper.clone(),
-            // NOTE: The variant with instance should have less performance.
-            // Some(staking::MockHookKeeper::new()),
-            // The compiler require type for option `None`
-            None::<
-                staking::MockHookKeeper<
-                    GaiaStoreKey,
-                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-                    GaiaModules,
-                >,
-            >,
-            GaiaModules::BondedPool,
-            GaiaModules::NotBondedPool,
+            staking_keeper(&auth_keeper, &bank_keeper, None::<NoHooks>),
+            GaiaModules::FeeCollector,
+            GaiaModules::Distribution,
+            blocked_addrs,
+        );
+
+        let slashing_keeper: GaiaSlashingKeeper = slashing::Keeper::new(
+            GaiaStoreKey::Slashing,
+            GaiaParamsStoreKey::Slashing,
+            staking_keeper(
+                &auth_keeper,
+                &bank_keeper,
+                Some(distribution_keeper.clone()),
+            ),
+        );
+
+        let staking_keeper: GaiaStakingKeeper = staking_keeper(
+            &auth_keeper,
+            &bank_keeper,
+            Some(staking::MultiHooks::new(
+                distribution_keeper.clone(),
+                slashing_keeper.clone(),
+            )),
+        );
+
+        let evidence_keeper = evidence::Keeper::new(
+            GaiaStoreKey::Evidence,
+            staking_keeper.clone(),
+            slashing_keeper.clone(),
+            None::<RawEquivocation>,
+        );
+
+        let mint_keeper = MintKeeper::new(
+            GaiaStoreKey::Mint,
+            staking_keeper.clone(),
+            bank_keeper.clone(),
+            GaiaModules::Mint,
+            GaiaModules::FeeCollector,
         );
 
-        let ibc_keeper = ibc_rs::keeper::Keeper::new(GaiaStoreKey::IBC, GaiaParamsStoreKey::IBC);
+        let ibc_keeper = ibc_rs::keeper::Keeper::new(
+            GaiaStoreKey::IBC,
+            GaiaParamsStoreKey::IBC,
+            bank_keeper.clone(),
+            GaiaModules::Transfer,
+        );
+        let feemarket_keeper =
+            GaiaFeeMarketKeeper::new(GaiaStoreKey::FeeMarket, GaiaParamsStoreKey::FeeMarket);
+
         let ante_handler = BaseAnteHandler::new(
             auth_keeper.clone(),
             bank_keeper.clone(),
             DefaultSignGasConsumer,
             GaiaModules::FeeCollector,
+        )
+        .with_fee_market(feemarket_keeper.clone());
+
+        let upgrade_keeper = UpgradeKeeper::<GaiaStoreKey, GaiaXmodules, NullUpgradeHandler>::new(
+            GaiaStoreKey::Upgrade,
+            [],
+            GaiaModules::Gov.address(),
+            cfg.home,
         );
 
         let gov_keeper = GovKeeper::new(
@@ -200,14 +339,15 @@ impl GaiaABCIHandler {
             ProposalsHandler::<
                 GaiaStoreKey,
                 GaiaParamsStoreKey,
-                BaseAppParamsKeeper<GaiaParamsStoreKey>,
-                GaiaXmodules,
-                NullUpgradeHandler,
-            >::new(UpgradeKeeper::<
-                GaiaStoreKey,
                 GaiaXmodules,
                 NullUpgradeHandler,
-            >::new(GaiaStoreKey::Upgrade, [])),
+                GaiaModules,
+                GaiaBankKeeper,
+            >::new(
+                upgrade_keeper.clone(),
+                ibc_rs::ClientKeeper::new(GaiaStoreKey::IBC, GaiaParamsStoreKey::IBC),
+                bank_keeper.clone(),
+            ),
         );
 
         GaiaABCIHandler {
@@ -215,6 +355,13 @@ impl GaiaABCIHandler {
             auth_abci_handler: auth::AuthABCIHandler::new(auth_keeper),
             genutil_handler: GenutilAbciHandler::new(staking_keeper.clone(), ante_handler.clone()),
             staking_abci_handler: staking::StakingABCIHandler::new(staking_keeper),
+            distribution_abci_handler: distribution::ABCIHandler::new(distribution_keeper),
+            slashing_abci_handler: slashing::ABCIHandler::new(slashing_keeper),
+            evidence_abci_handler: evidence::ABCIHandler::new(evidence_keeper),
+            mint_abci_handler: MintAbciHandler::new(mint_keeper, GaiaParamsStoreKey::Mint),
+            feemarket_abci_handler: FeeMarketAbciHandler::new(feemarket_keeper),
+            upgrade_abci_handler: UpgradeAbciHandler::new(upgrade_keeper),
+            params_abci_handler: ParamsAbciHandler::new(GaiaParamsStoreKey::BaseApp),
             ibc_abci_handler: ibc_rs::ABCIHandler::new(ibc_keeper.clone()),
             ante_handler,
             gov_handler: GovAbciHandler::new(gov_keeper),
@@ -222,6 +369,22 @@ impl GaiaABCIHandler {
     }
 }
 
+fn staking_keeper<KH: KeeperHooks<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>>(
+    auth_keeper: &GaiaAuthKeeper,
+    bank_keeper: &GaiaBankKeeper,
+    hooks: Option<KH>,
+) -> StakingKeeper<KH> {
+    staking::Keeper::new(
+        GaiaStoreKey::Staking,
+        GaiaParamsStoreKey::Staking,
+        auth_keeper.clone(),
+        bank_keeper.clone(),
+        hooks,
+        GaiaModules::BondedPool,
+        GaiaModules::NotBondedPool,
+    )
+}
+
 impl ABCIHandler for GaiaABCIHandler {
     type Message = Message;
     type Genesis = GenesisState;
@@ -237,7 +400,14 @@ impl ABCIHandler for GaiaABCIHandler {
         match msg {
             Message::Bank(msg) => self.bank_abci_handler.msg(ctx, msg),
             Message::Staking(msg) => self.staking_abci_handler.msg(ctx, msg),
+            Message::Distribution(msg) => self
+                .distribution_abci_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<DistributionModuleInfo>()),
+            Message::Slashing(msg) => self.slashing_abci_handler.tx(ctx, msg).map_err(Into::into),
             Message::IBC(msg) => self.ibc_abci_handler.msg(ctx, msg.clone()),
+            Message::Upgrade(msg) => self.upgrade_abci_handler.msg(ctx, msg),
+            Message::Gov(msg) => self.gov_handler.msg(ctx, msg),
         }
     }
 
@@ -246,8 +416,16 @@ impl ABCIHandler for GaiaABCIHandler {
         ctx: &mut gears::context::block::BlockContext<'_, DB, Self::StoreKey>,
         request: gears::tendermint::request::RequestBeginBlock,
     ) {
-        // TODO: need to add call to upgrade begin_block here
+        // the order follows the cosmos-sdk simapp: rewards of the previous block are
+        // allocated before the signatures are checked and misbehaving validators are slashed
+        self.upgrade_abci_handler.begin_block(ctx, request.clone());
+        self.mint_abci_handler.begin_block(ctx, request.clone());
+        self.distribution_abci_handler
+            .begin_block(ctx, request.clone());
+        self.slashing_abci_handler.begin_block(ctx, request.clone());
+        self.evidence_abci_handler.begin_block(ctx, request.clone());
         self.staking_abci_handler.begin_block(ctx, request);
+        self.ibc_abci_handler.begin_block(ctx);
     }
 
     fn end_block<DB: Database>(
@@ -256,6 +434,7 @@ impl ABCIHandler for GaiaABCIHandler {
         request: gears::tendermint::request::RequestEndBlock,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
         self.gov_handler.end_block(ctx, request.clone());
+        self.feemarket_abci_handler.end_block(ctx, request.clone());
         self.staking_abci_handler.end_block(ctx, request)
     }
 
@@ -265,11 +444,21 @@ impl ABCIHandler for GaiaABCIHandler {
         genesis: GenesisState,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
         self.bank_abci_handler.init_genesis(ctx, genesis.bank);
+        // distribution and slashing should be initialized before staking and genutil,
+        // genesis validators and validators created by gentxs trigger the hooks of both modules
+        self.distribution_abci_handler
+            .genesis(ctx, genesis.distribution);
+        self.slashing_abci_handler.genesis(ctx, genesis.slashing);
         let staking_updates = self.staking_abci_handler.genesis(ctx, genesis.staking);
         self.gov_handler.init_genesis(ctx, genesis.gov);
         self.ibc_abci_handler.genesis(ctx, genesis.ibc);
         self.auth_abci_handler.init_genesis(ctx, genesis.auth);
+        self.mint_abci_handler.init_genesis(ctx, genesis.mint);
+        self.feemarket_abci_handler
+            .init_genesis(ctx, genesis.feemarket);
         let genutil_updates = self.genutil_handler.init_genesis(ctx, genesis.genutil);
+        self.evidence_abci_handler.genesis(ctx, genesis.evidence);
+        self.upgrade_abci_handler.init_genesis(ctx, NullGenesis());
 
         match (genutil_updates.is_empty(), staking_updates.is_empty()) {
             (true, true) => vec![],
@@ -292,13 +481,38 @@ impl ABCIHandler for GaiaABCIHandler {
             self.bank_abci_handler.query(ctx, query)
         } else if query.path.starts_with("/cosmos.staking") {
             self.staking_abci_handler.query(ctx, query)
-        } else if query.path.starts_with("/ibc.core.client") {
+        } else if query.path.starts_with("/cosmos.distribution") {
+            self.distribution_abci_handler
+                .query(ctx, query)
+                .map(Into::into)
+        } else if query.path.starts_with("/cosmos.slashing") {
+            self.slashing_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.mint") {
+            self.mint_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.evidence") {
+            self.evidence_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.upgrade") {
+            self.upgrade_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.gov") {
+            self.gov_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.params") {
+            self.params_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/feemarket.") {
+            self.feemarket_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/ibc.") {
             self.ibc_abci_handler.query(ctx, query)
         } else {
             Err(QueryError::PathNotFound)
         }
     }
 
+    fn store_upgrades<DB: Database>(
+        &self,
+        ctx: &gears::context::simple::SimpleContext<'_, DB, Self::StoreKey>,
+    ) -> Option<(u32, gears::store::bank::multi::upgrades::StoreUpgrades)> {
+        self.upgrade_abci_handler.store_upgrades(ctx)
+    }
+
     fn run_ante_checks<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
@@ -314,35 +528,58 @@ impl ABCIHandler for GaiaABCIHandler {
         )
     }
 
+    fn run_post_handlers<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
+        tx: &TxWithRaw<Message>,
+    ) -> Result<(), TxError> {
+        self.ante_handler.run_post(ctx, tx)
+    }
+
     fn typed_query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, GaiaStoreKey>,
         query: GaiaNodeQueryRequest,
-    ) -> GaiaNodeQueryResponse {
-        match query {
+    ) -> Result<GaiaNodeQueryResponse, QueryError> {
+        let res = match query {
             GaiaNodeQueryRequest::Bank(req) => {
-                GaiaNodeQueryResponse::Bank(self.bank_abci_handler.typed_query(ctx, req))
+                GaiaNodeQueryResponse::Bank(self.bank_abci_handler.typed_query(ctx, req)?)
             }
             GaiaNodeQueryRequest::Auth(req) => {
-                GaiaNodeQueryResponse::Auth(self.auth_abci_handler.typed_query(ctx, req))
+                GaiaNodeQueryResponse::Auth(self.auth_abci_handler.typed_query(ctx, req)?)
             }
             GaiaNodeQueryRequest::Staking(req) => {
-                GaiaNodeQueryResponse::Staking(self.staking_abci_handler.typed_query(ctx, req))
+                GaiaNodeQueryResponse::Staking(self.staking_abci_handler.typed_query(ctx, req)?)
             }
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Slashing(_req) => GaiaNodeQueryResponse::Slashing(
-                slashing::SlashingNodeQueryResponse::Params(slashing::QueryParamsResponse {
-                    params: slashing::SlashingParams::default(),
-                }),
-            ),
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Distribution(_req) => GaiaNodeQueryResponse::Distribution(
-                distribution::DistributionNodeQueryResponse::Params(
-                    distribution::QueryParamsResponse {
-                        params: distribution::DistributionParams::default(),
-                    },
-                ),
+            GaiaNodeQueryRequest::Slashing(req) => {
+                GaiaNodeQueryResponse::Slashing(self.slashing_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Distribution(req) => GaiaNodeQueryResponse::Distribution(
+                self.distribution_abci_handler.typed_query(ctx, req),
             ),
-        }
+            GaiaNodeQueryRequest::Mint(req) => {
+                GaiaNodeQueryResponse::Mint(self.mint_abci_handler.typed_query(ctx, req)?)
+            }
+            GaiaNodeQueryRequest::Evidence(req) => {
+                GaiaNodeQueryResponse::Evidence(self.evidence_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Gov(req) => {
+                GaiaNodeQueryResponse::Gov(self.gov_handler.typed_query(ctx, req)?)
+            }
+            GaiaNodeQueryRequest::Upgrade(req) => {
+                GaiaNodeQueryResponse::Upgrade(self.upgrade_abci_handler.typed_query(ctx, req)?)
+            }
+            GaiaNodeQueryRequest::Params(req) => {
+                GaiaNodeQueryResponse::Params(self.params_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::FeeMarket(req) => {
+                GaiaNodeQueryResponse::FeeMarket(self.feemarket_abci_handler.typed_query(ctx, req)?)
+            }
+            GaiaNodeQueryRequest::Ibc(req) => {
+                GaiaNodeQueryResponse::Ibc(self.ibc_abci_handler.typed_query(ctx, req)?)
+            }
+        };
+
+        Ok(res)
     }
 }
@@ -5,18 +5,32 @@ use bank::cli::{
     tx::{run_bank_tx_command, BankTxCli},
 };
 use clap::{Args, Subcommand};
+use distribution::cli::{
+    query::DistributionQueryCli,
+    tx::{run_distribution_tx_command, DistributionTxCli},
+};
+use evidence::cli::query::EvidenceQueryCli;
+use feemarket::client::cli::query::FeeMarketQueryCli;
 use gears::{
     commands::client::tx::ClientTxContext,
     types::{address::AccAddress, tx::Messages},
 };
+use gov::client::{cli::tx::GovTxCli, tx_handler::run_gov_tx_command};
 use ibc_rs::client::cli::{
     query::IbcQueryCli,
-    tx::{run_ibc_tx_command, IbcTxCli},
+    tx::{run_ibc_tx_command, run_transfer_tx_command, IbcTxCli, TransferTxCli},
+};
+use mint::client::cli::query::MintQueryCli;
+use params::client::cli::query::ParamsQueryCli;
+use slashing::cli::{
+    query::SlashingQueryCli,
+    tx::{run_slashing_tx_command, SlashingTxCli},
 };
 use staking::cli::{
     query::StakingQueryCli,
     tx::{run_staking_tx_command, StakingTxCli},
 };
+use upgrade::client::cli::tx::{run_upgrade_tx_command, UpgradeTxCli};
 
 use crate::message::Message;
 
@@ -32,12 +46,23 @@ pub enum GaiaTxCommands {
     Bank(BankTxCli),
     /// Staking transaction subcommands
     Staking(StakingTxCli),
+    /// Distribution transaction subcommands
+    Distribution(DistributionTxCli),
+    /// Slashing transaction subcommands
+    Slashing(SlashingTxCli),
     /// IBC transaction subcommands
     IBC(IbcTxCli),
+    /// IBC fungible token transfer transaction subcommands
+    #[command(name = "ibc-transfer")]
+    IbcTransfer(TransferTxCli),
+    /// Upgrade transaction subcommands
+    Upgrade(UpgradeTxCli),
+    /// Governance transaction subcommands
+    Gov(GovTxCli),
 }
 
 pub fn tx_command_handler(
-    _ctx: &ClientTxContext,
+    ctx: &ClientTxContext,
     command: GaiaTxCommands,
     from_address: AccAddress,
 ) -> Result<Messages<Message>> {
@@ -45,12 +70,35 @@ pub fn tx_command_handler(
         GaiaTxCommands::Bank(args) => run_bank_tx_command(args, from_address)
             .map(Message::Bank)
             .map(Into::into),
-        GaiaTxCommands::Staking(args) => run_staking_tx_command(args, from_address)
+        GaiaTxCommands::Staking(args) => run_staking_tx_command(ctx, args, from_address)
             .map(Message::Staking)
             .map(Into::into),
+        GaiaTxCommands::Distribution(args) => {
+            let msgs = run_distribution_tx_command(ctx, args, from_address)?;
+            let chunk_size = msgs.chunk_size();
+            Ok(Messages::new(
+                msgs.into_msgs()
+                    .into_iter()
+                    .map(Message::Distribution)
+                    .collect(),
+                chunk_size,
+            )?)
+        }
+        GaiaTxCommands::Slashing(args) => run_slashing_tx_command(args, from_address)
+            .map(Message::Slashing)
+            .map(Into::into),
         GaiaTxCommands::IBC(args) => run_ibc_tx_command(args, from_address)
             .map(Message::IBC)
             .map(Into::into),
+        GaiaTxCommands::IbcTransfer(args) => run_transfer_tx_command(args, from_address)
+            .map(Message::IBC)
+            .map(Into::into),
+        GaiaTxCommands::Upgrade(args) => run_upgrade_tx_command(args, from_address)
+            .map(Message::Upgrade)
+            .map(Into::into),
+        GaiaTxCommands::Gov(args) => run_gov_tx_command(args, from_address)
+            .map(Message::Gov)
+            .map(Into::into),
     }
 }
 
@@ -60,8 +108,20 @@ pub enum GaiaQueryCommands {
     Bank(BankQueryCli),
     /// Querying commands for the auth module
     Auth(AuthQueryCli),
-    /// Querying commands for the auth module
+    /// Querying commands for the staking module
     Staking(StakingQueryCli),
+    /// Querying commands for the distribution module
+    Distribution(DistributionQueryCli),
+    /// Querying commands for the slashing module
+    Slashing(SlashingQueryCli),
+    /// Querying commands for the mint module
+    Mint(MintQueryCli),
+    /// Querying commands for the evidence module
+    Evidence(EvidenceQueryCli),
+    /// Querying commands for the params module
+    Params(ParamsQueryCli),
+    /// Querying commands for the fee market module
+    FeeMarket(FeeMarketQueryCli),
     /// Querying commands for the ibc module
     Ibc(IbcQueryCli),
 }
@@ -1,5 +1,8 @@
 use auth::GenesisState as AuthGenesis;
 use bank::GenesisState as BankGenesis;
+use distribution::GenesisState as DistributionGenesis;
+use evidence::{GenesisState as EvidenceGenesis, RawEquivocation};
+use feemarket::genesis::FeeMarketGenesis;
 use gears::{
     baseapp::genesis::GenesisError,
     types::{address::AccAddress, base::coins::UnsignedCoins},
@@ -7,7 +10,9 @@ use gears::{
 use genutil::genesis::GenutilGenesis;
 use gov::{genesis::GovGenesisState, proposal::Proposals};
 use ibc_rs::GenesisState as IBCGenesis;
+use mint::genesis::MintGenesis;
 use serde::{Deserialize, Serialize};
+use slashing::GenesisState as SlashingGenesis;
 use staking::GenesisState as StakingGenesis;
 
 use crate::store_keys::GaiaParamsStoreKey;
@@ -21,6 +26,13 @@ pub struct GenesisState {
     pub ibc: IBCGenesis,
     pub genutil: GenutilGenesis,
     pub gov: GovGenesisState<Proposals<GaiaParamsStoreKey>>,
+    pub distribution: DistributionGenesis,
+    pub slashing: SlashingGenesis,
+    pub mint: MintGenesis,
+    pub evidence: EvidenceGenesis<RawEquivocation>,
+    /// optional to keep genesis files of chains without fee market valid
+    #[serde(default)]
+    pub feemarket: FeeMarketGenesis,
 }
 
 impl gears::baseapp::genesis::Genesis for GenesisState {
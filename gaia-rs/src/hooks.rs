@@ -0,0 +1,5 @@
+use crate::abci_handler::{GaiaDistributionKeeper, GaiaSlashingKeeper};
+
+/// Staking hooks of the application. Every hook is forwarded to the distribution and then
+/// to the slashing keeper, in the same order as in the cosmos-sdk simapp.
+pub type GaiaStakingHooks = staking::MultiHooks<GaiaDistributionKeeper, GaiaSlashingKeeper>;
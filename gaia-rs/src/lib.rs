@@ -17,7 +17,16 @@ use bank::{
 };
 use clap::Subcommand;
 use client::{tx_command_handler, GaiaQueryCommands, WrappedGaiaQueryCommands};
-use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
+use distribution::{
+    cli::query::DistributionQueryHandler, DistributionNodeQueryRequest,
+    DistributionNodeQueryResponse,
+};
+use evidence::cli::query::EvidenceQueryHandler;
+use evidence::{EvidenceNodeQueryRequest, EvidenceNodeQueryResponse};
+use feemarket::{
+    client::cli::FeeMarketClientHandler,
+    types::query::{request::FeeMarketQueryRequest, response::FeeMarketQueryResponse},
+};
 use gears::{
     application::{
         client::Client,
@@ -38,21 +47,38 @@ use gears::{
     crypto::public::PublicKey,
     grpc::{health::health_server, tx::tx_server},
     rest::RestState,
+    tendermint::rpc::client::HttpClientUrl,
     types::{address::AccAddress, tx::Messages},
 };
-use ibc_rs::client::cli::query::IbcQueryHandler;
+use gov::{
+    proposal::Proposals,
+    query::{GovQuery, GovQueryResponse},
+};
+use ibc_rs::{client::cli::query::IbcQueryHandler, IbcNodeQueryRequest, IbcNodeQueryResponse};
+use mint::{
+    client::cli::MintClientHandler,
+    types::query::{request::MintQueryRequest, response::MintQueryResponse},
+};
+use params::{
+    client::cli::ParamsClientHandler,
+    types::query::{request::ParamsQueryRequest, response::ParamsQueryResponse},
+};
 use rest::get_router;
 use serde::Serialize;
-use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
+use slashing::{
+    cli::query::SlashingQueryHandler, SlashingNodeQueryRequest, SlashingNodeQueryResponse,
+};
 use staking::{cli::query::StakingQueryHandler, StakingNodeQueryRequest, StakingNodeQueryResponse};
 use tonic::transport::Server;
 use tonic::Status;
 use tower_layer::Identity;
+use upgrade::types::query::{UpgradeQueryRequest, UpgradeQueryResponse};
 
 pub mod abci_handler;
 pub mod client;
 pub mod config;
 pub mod genesis;
+pub mod hooks;
 pub mod message;
 pub mod modules;
 pub mod query;
@@ -108,6 +134,24 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Staking(command) => {
                 Self::QueryRequest::Staking(StakingQueryHandler.prepare_query_request(command)?)
             }
+            GaiaQueryCommands::Distribution(command) => Self::QueryRequest::Distribution(
+                DistributionQueryHandler.prepare_query_request(command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => {
+                Self::QueryRequest::Slashing(SlashingQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Mint(command) => {
+                Self::QueryRequest::Mint(MintClientHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Evidence(command) => {
+                Self::QueryRequest::Evidence(EvidenceQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Params(command) => {
+                Self::QueryRequest::Params(ParamsClientHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::FeeMarket(command) => Self::QueryRequest::FeeMarket(
+                FeeMarketClientHandler.prepare_query_request(command)?,
+            ),
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryRequest::Ibc(IbcQueryHandler.prepare_query_request(command)?)
             }
@@ -131,6 +175,24 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Staking(command) => Self::QueryResponse::Staking(
                 StakingQueryHandler.handle_raw_response(query_bytes, command)?,
             ),
+            GaiaQueryCommands::Distribution(command) => Self::QueryResponse::Distribution(
+                DistributionQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => Self::QueryResponse::Slashing(
+                SlashingQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Mint(command) => Self::QueryResponse::Mint(
+                MintClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Evidence(command) => Self::QueryResponse::Evidence(
+                EvidenceQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Params(command) => Self::QueryResponse::Params(
+                ParamsClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::FeeMarket(command) => Self::QueryResponse::FeeMarket(
+                FeeMarketClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryResponse::Ibc(IbcQueryHandler.handle_raw_response(query_bytes, command)?)
             }
@@ -206,6 +268,13 @@ pub enum GaiaNodeQueryRequest {
     Staking(StakingNodeQueryRequest),
     Slashing(SlashingNodeQueryRequest),
     Distribution(DistributionNodeQueryRequest),
+    Mint(MintQueryRequest),
+    Evidence(EvidenceNodeQueryRequest),
+    Gov(GovQuery),
+    Upgrade(UpgradeQueryRequest),
+    Params(ParamsQueryRequest),
+    FeeMarket(FeeMarketQueryRequest),
+    Ibc(IbcNodeQueryRequest),
 }
 
 impl QueryRequest for GaiaNodeQueryRequest {
@@ -244,6 +313,48 @@ impl From<DistributionNodeQueryRequest> for GaiaNodeQueryRequest {
     }
 }
 
+impl From<MintQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: MintQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Mint(req)
+    }
+}
+
+impl From<EvidenceNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: EvidenceNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Evidence(req)
+    }
+}
+
+impl From<GovQuery> for GaiaNodeQueryRequest {
+    fn from(req: GovQuery) -> Self {
+        GaiaNodeQueryRequest::Gov(req)
+    }
+}
+
+impl From<UpgradeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: UpgradeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Upgrade(req)
+    }
+}
+
+impl From<ParamsQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: ParamsQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Params(req)
+    }
+}
+
+impl From<FeeMarketQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: FeeMarketQueryRequest) -> Self {
+        GaiaNodeQueryRequest::FeeMarket(req)
+    }
+}
+
+impl From<IbcNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: IbcNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Ibc(req)
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum GaiaNodeQueryResponse {
@@ -252,6 +363,13 @@ pub enum GaiaNodeQueryResponse {
     Staking(StakingNodeQueryResponse),
     Slashing(SlashingNodeQueryResponse),
     Distribution(DistributionNodeQueryResponse),
+    Mint(MintQueryResponse),
+    Evidence(EvidenceNodeQueryResponse),
+    Gov(GovQueryResponse<Proposals<GaiaParamsStoreKey>>),
+    Upgrade(UpgradeQueryResponse),
+    Params(ParamsQueryResponse),
+    FeeMarket(FeeMarketQueryResponse),
+    Ibc(IbcNodeQueryResponse),
 }
 
 impl TryFrom<GaiaNodeQueryResponse> for BankNodeQueryResponse {
@@ -319,6 +437,97 @@ impl TryFrom<GaiaNodeQueryResponse> for DistributionNodeQueryResponse {
     }
 }
 
+impl TryFrom<GaiaNodeQueryResponse> for MintQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Mint(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for EvidenceNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Evidence(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for GovQueryResponse<Proposals<GaiaParamsStoreKey>> {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Gov(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for UpgradeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Upgrade(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for ParamsQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Params(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for FeeMarketQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::FeeMarket(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for IbcNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Ibc(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
 impl QueryResponse for GaiaNodeQueryResponse {
     fn into_bytes(self) -> Vec<u8> {
         todo!()
@@ -341,6 +550,7 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
     fn build_grpc_router<App: NodeQueryHandler<GaiaNodeQueryRequest, GaiaNodeQueryResponse>>(
         &self,
         app: App,
+        tendermint_rpc_address: HttpClientUrl,
     ) -> tonic::transport::server::Router<Identity> {
         let reflection_service = tonic_reflection::server::Builder::configure()
             .register_encoded_file_descriptor_set(ibc_proto::FILE_DESCRIPTOR_SET)
@@ -351,8 +561,28 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
             .add_service(reflection_service)
             .add_service(staking::grpc::new(app.clone()))
             .add_service(auth::grpc::new(app.clone()))
-            .add_service(bank::grpc::new(app))
-            .add_service(health_server())
+            .add_service(bank::grpc::new(app.clone()))
+            .add_service(distribution::grpc::new(app.clone()))
+            .add_service(slashing::grpc::new(app.clone()))
+            .add_service(gov::client::grpc::new::<
+                _,
+                _,
+                _,
+                Proposals<GaiaParamsStoreKey>,
+            >(app.clone()))
+            .add_service(gov::client::grpc::new_gears_service::<
+                _,
+                _,
+                _,
+                Proposals<GaiaParamsStoreKey>,
+            >(app.clone()))
+            .add_service(mint::client::grpc::new(app.clone()))
+            .add_service(evidence::grpc::new(app.clone()))
+            .add_service(upgrade::client::grpc::new(app.clone()))
+            .add_service(params::client::grpc::new(app.clone()))
+            .add_service(feemarket::client::grpc::new(app.clone()))
+            .add_service(ibc_rs::client::grpc::new(app))
+            .add_service(health_server::<GaiaApplication>(tendermint_rpc_address))
             .add_service(tx_server())
     }
 }
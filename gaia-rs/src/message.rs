@@ -15,16 +15,28 @@ pub enum Message {
     Bank(bank::Message),
     #[msg(url(string = "/cosmos.staking.v1beta1"))]
     Staking(staking::Message),
-    #[msg(url(string = "/ibc.core.client.v1"))]
+    #[msg(url(string = "/cosmos.distribution.v1beta1"))]
+    Distribution(distribution::Message),
+    #[msg(url(string = "/cosmos.slashing.v1beta1"))]
+    Slashing(slashing::Message),
+    #[msg(url(string = "/ibc."))]
     IBC(ibc_rs::message::Message),
+    #[msg(url(string = "/cosmos.upgrade.v1beta1"))]
+    Upgrade(upgrade::message::Message),
+    #[msg(url(string = "/cosmos.gov."))]
+    Gov(gov::msg::GovMsg),
 }
 
 impl ValueRenderer for Message {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             Message::Bank(msg) => msg.format(get_metadata),
-            Message::Staking(_) => Err(RenderError::NotImplemented),
+            Message::Staking(msg) => msg.format(get_metadata),
+            Message::Distribution(msg) => msg.format(get_metadata),
+            Message::Slashing(msg) => msg.format(get_metadata),
             Message::IBC(_) => Err(RenderError::NotImplemented),
+            Message::Upgrade(msg) => msg.format(get_metadata),
+            Message::Gov(msg) => msg.format(get_metadata),
         }
     }
 }
@@ -1,4 +1,6 @@
-use gears::x::module::Module;
+use gears::{application::handlers::node::ModuleInfo, x::module::Module};
+
+use crate::abci_handler::{AuthModuleInfo, BankModuleInfo, StakingModuleInfo};
 
 #[derive(Debug, Clone, PartialEq, Eq, strum::EnumIter)]
 pub enum GaiaModules {
@@ -6,6 +8,9 @@ pub enum GaiaModules {
     BondedPool,
     NotBondedPool,
     Gov,
+    Distribution,
+    Mint,
+    Transfer,
 }
 
 impl Module for GaiaModules {
@@ -15,6 +20,9 @@ impl Module for GaiaModules {
             GaiaModules::BondedPool => staking::BONDED_POOL_NAME.into(),
             GaiaModules::NotBondedPool => staking::NOT_BONDED_POOL_NAME.into(),
             GaiaModules::Gov => "gov".into(),
+            GaiaModules::Distribution => "distribution".into(),
+            GaiaModules::Mint => "mint".into(),
+            GaiaModules::Transfer => "transfer".into(),
         }
     }
 
@@ -24,6 +32,9 @@ impl Module for GaiaModules {
             GaiaModules::BondedPool => vec!["burner".into(), "staking".into()],
             GaiaModules::NotBondedPool => vec!["burner".into(), "staking".into()],
             GaiaModules::Gov => vec!["burner".into()],
+            GaiaModules::Distribution => Vec::new(),
+            GaiaModules::Mint => vec!["minter".into()],
+            GaiaModules::Transfer => vec!["minter".into(), "burner".into()],
         }
     }
 }
@@ -61,4 +72,12 @@ impl upgrade::Module for GaiaXmodules {
             GaiaXmodules::Staking => "staking",
         }
     }
+
+    fn consensus_version(&self) -> u64 {
+        match self {
+            GaiaXmodules::Auth => AuthModuleInfo::CONSENSUS_VERSION,
+            GaiaXmodules::Bank => BankModuleInfo::CONSENSUS_VERSION,
+            GaiaXmodules::Staking => StakingModuleInfo::CONSENSUS_VERSION,
+        }
+    }
 }
@@ -1,8 +1,14 @@
 use auth::cli::query::{AuthQuery, AuthQueryResponse};
 use bank::cli::query::{BankQuery, BankQueryResponse};
+use distribution::cli::query::{DistributionQueryRequest, DistributionQueryResponse};
+use evidence::cli::query::{EvidenceQueryRequest, EvidenceQueryResponse};
+use feemarket::types::query::{request::FeeMarketQueryRequest, response::FeeMarketQueryResponse};
 use gears::{baseapp::Query, derive::Query};
 use ibc_rs::client::cli::query::{IbcQuery, IbcQueryResponse};
+use mint::types::query::{request::MintQueryRequest, response::MintQueryResponse};
+use params::types::query::{request::ParamsQueryRequest, response::ParamsQueryResponse};
 use serde::{Deserialize, Serialize};
+use slashing::cli::query::{SlashingQueryRequest, SlashingQueryResponse};
 use staking::cli::query::{StakingQuery, StakingQueryResponse};
 
 #[derive(Clone, Debug, PartialEq)]
@@ -10,6 +16,12 @@ pub enum GaiaQuery {
     Auth(AuthQuery),
     Bank(BankQuery),
     Staking(StakingQuery),
+    Distribution(DistributionQueryRequest),
+    Slashing(SlashingQueryRequest),
+    Mint(MintQueryRequest),
+    Evidence(EvidenceQueryRequest),
+    Params(ParamsQueryRequest),
+    FeeMarket(FeeMarketQueryRequest),
     Ibc(IbcQuery),
 }
 
@@ -19,6 +31,12 @@ impl Query for GaiaQuery {
             GaiaQuery::Auth(var) => var.query_url(),
             GaiaQuery::Bank(var) => var.query_url(),
             GaiaQuery::Staking(var) => var.query_url(),
+            GaiaQuery::Distribution(var) => var.query_url(),
+            GaiaQuery::Slashing(var) => var.query_url(),
+            GaiaQuery::Mint(var) => var.query_url(),
+            GaiaQuery::Evidence(var) => var.query_url(),
+            GaiaQuery::Params(var) => var.query_url(),
+            GaiaQuery::FeeMarket(var) => var.query_url(),
             GaiaQuery::Ibc(var) => var.query_url(),
         }
     }
@@ -28,6 +46,12 @@ impl Query for GaiaQuery {
             GaiaQuery::Auth(var) => var.into_bytes(),
             GaiaQuery::Bank(var) => var.into_bytes(),
             GaiaQuery::Staking(var) => var.into_bytes(),
+            GaiaQuery::Distribution(var) => var.into_bytes(),
+            GaiaQuery::Slashing(var) => var.into_bytes(),
+            GaiaQuery::Mint(var) => var.into_bytes(),
+            GaiaQuery::Evidence(var) => var.into_bytes(),
+            GaiaQuery::Params(var) => var.into_bytes(),
+            GaiaQuery::FeeMarket(var) => var.into_bytes(),
             GaiaQuery::Ibc(var) => var.into_bytes(),
         }
     }
@@ -39,5 +63,11 @@ pub enum GaiaQueryResponse {
     Auth(AuthQueryResponse),
     Bank(BankQueryResponse),
     Staking(StakingQueryResponse),
+    Distribution(DistributionQueryResponse),
+    Slashing(SlashingQueryResponse),
+    Mint(MintQueryResponse),
+    Evidence(EvidenceQueryResponse),
+    Params(ParamsQueryResponse),
+    FeeMarket(FeeMarketQueryResponse),
     Ibc(IbcQueryResponse),
 }
@@ -2,13 +2,25 @@ use auth::{AuthNodeQueryRequest, AuthNodeQueryResponse};
 use axum::Router;
 use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
 use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
+use evidence::{EvidenceNodeQueryRequest, EvidenceNodeQueryResponse};
+use feemarket::types::query::{request::FeeMarketQueryRequest, response::FeeMarketQueryResponse};
 use gears::baseapp::NodeQueryHandler;
 use gears::{
     baseapp::{QueryRequest, QueryResponse},
     rest::RestState,
 };
+use gov::{
+    proposal::Proposals,
+    query::{GovQuery, GovQueryResponse},
+};
+use ibc_rs::IbcNodeQueryRequest;
+use mint::types::query::{request::MintQueryRequest, response::MintQueryResponse};
+use params::types::query::{request::ParamsQueryRequest, response::ParamsQueryResponse};
 use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
 use staking::{StakingNodeQueryRequest, StakingNodeQueryResponse};
+use upgrade::types::query::{UpgradeQueryRequest, UpgradeQueryResponse};
+
+use crate::store_keys::GaiaParamsStoreKey;
 
 pub fn get_router<
     QReq: QueryRequest
@@ -16,13 +28,26 @@ pub fn get_router<
         + From<BankNodeQueryRequest>
         + From<StakingNodeQueryRequest>
         + From<SlashingNodeQueryRequest>
-        + From<DistributionNodeQueryRequest>,
+        + From<DistributionNodeQueryRequest>
+        + From<MintQueryRequest>
+        + From<EvidenceNodeQueryRequest>
+        + From<UpgradeQueryRequest>
+        + From<ParamsQueryRequest>
+        + From<FeeMarketQueryRequest>
+        + From<IbcNodeQueryRequest>
+        + From<GovQuery>,
     QRes: QueryResponse
         + TryInto<AuthNodeQueryResponse>
         + TryInto<BankNodeQueryResponse>
         + TryInto<StakingNodeQueryResponse>
         + TryInto<SlashingNodeQueryResponse>
-        + TryInto<DistributionNodeQueryResponse>,
+        + TryInto<DistributionNodeQueryResponse>
+        + TryInto<MintQueryResponse>
+        + TryInto<EvidenceNodeQueryResponse>
+        + TryInto<UpgradeQueryResponse>
+        + TryInto<ParamsQueryResponse>
+        + TryInto<FeeMarketQueryResponse>
+        + TryInto<GovQueryResponse<Proposals<GaiaParamsStoreKey>>>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
     Router::new()
@@ -31,4 +56,14 @@ pub fn get_router<
         .nest("/cosmos/staking", staking::rest::get_router())
         .nest("/cosmos/slashing", slashing::rest::get_router())
         .nest("/cosmos/distribution", distribution::rest::get_router())
+        .nest("/cosmos/mint", mint::client::rest::get_router())
+        .nest("/cosmos/evidence", evidence::rest::get_router())
+        .nest("/cosmos/upgrade", upgrade::client::rest::get_router())
+        .nest("/cosmos/params", params::client::rest::get_router())
+        .nest("/feemarket", feemarket::client::rest::get_router())
+        .nest(
+            "/cosmos/gov",
+            gov::client::rest::get_router::<_, _, _, Proposals<GaiaParamsStoreKey>>(),
+        )
+        .nest("/ibc", ibc_rs::client::rest::get_router())
 }
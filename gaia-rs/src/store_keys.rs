@@ -1,4 +1,8 @@
-use gears::derive::{ParamsKeys, StoreKeys};
+use gears::{
+    baseapp::{ConsensusParams, GasScheduleParams},
+    derive::{ParamsKeys, StoreKeys},
+    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceValidation, ParamsValidationError},
+};
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
@@ -21,6 +25,16 @@ pub enum GaiaStoreKey {
     Gov,
     #[skey(to_string = "upgrade")]
     Upgrade,
+    #[skey(to_string = "distribution")]
+    Distribution,
+    #[skey(to_string = "slashing")]
+    Slashing,
+    #[skey(to_string = "mint")]
+    Mint,
+    #[skey(to_string = "evidence")]
+    Evidence,
+    #[skey(to_string = "feemarket")]
+    FeeMarket,
 }
 
 #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, Deserialize, Serialize)]
@@ -39,4 +53,44 @@ pub enum GaiaParamsStoreKey {
     Capability,
     #[pkey(to_string = "gov/")]
     Gov,
+    #[pkey(to_string = "distribution/")]
+    Distribution,
+    #[pkey(to_string = "slashing/")]
+    Slashing,
+    #[pkey(to_string = "mint/")]
+    Mint,
+    #[pkey(to_string = "feemarket/")]
+    FeeMarket,
+}
+
+impl ParamsSubspaceValidation for GaiaParamsStoreKey {
+    fn validate_raw(&self, key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match self {
+            GaiaParamsStoreKey::Bank => bank::BankParams::validate_raw(key, value),
+            GaiaParamsStoreKey::Auth => auth::AuthsParams::validate_raw(key, value),
+            // consensus params and gas schedule share the same subspace
+            GaiaParamsStoreKey::BaseApp => match ConsensusParams::keys().contains(key) {
+                true => ConsensusParams::validate_raw(key, value),
+                false => GasScheduleParams::validate_raw(key, value),
+            },
+            GaiaParamsStoreKey::Staking => staking::StakingParams::validate_raw(key, value),
+            // client and connection params share the same subspace
+            GaiaParamsStoreKey::IBC => match ibc_rs::ClientParams::keys().contains(key) {
+                true => ibc_rs::ClientParams::validate_raw(key, value),
+                false => ibc_rs::ConnectionParams::validate_raw(key, value),
+            },
+            GaiaParamsStoreKey::Capability => {
+                Err(ParamsValidationError::UnknownKey(key.to_owned()))
+            }
+            GaiaParamsStoreKey::Gov => gov::params::GovParams::validate_raw(key, value),
+            GaiaParamsStoreKey::Distribution => {
+                distribution::DistributionParams::validate_raw(key, value)
+            }
+            GaiaParamsStoreKey::Slashing => slashing::SlashingParams::validate_raw(key, value),
+            GaiaParamsStoreKey::Mint => mint::params::MintParams::validate_raw(key, value),
+            GaiaParamsStoreKey::FeeMarket => {
+                feemarket::params::FeeMarketParams::validate_raw(key, value)
+            }
+        }
+    }
 }
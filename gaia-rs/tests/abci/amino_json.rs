@@ -0,0 +1,64 @@
+use gears::{
+    tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp},
+    types::{address::AccAddress, base::coins::Coins, msg::send::MsgSend},
+    utils::node::generate_amino_json_tx,
+};
+
+use crate::setup_mock_node;
+
+fn send_msg(from_address: AccAddress) -> gaia_rs::message::Message {
+    gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }))
+}
+
+#[test]
+/// A tx signed with SIGN_MODE_LEGACY_AMINO_JSON by the client passes signature verification in ante
+fn amino_json_signed_tx_is_verified() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_amino_json_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    // the next tx is signed with the incremented sequence
+    let tx = generate_amino_json_tx(
+        vec1::vec1![send_msg(user.address())],
+        1,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+}
+
+#[test]
+/// Amino json sign doc includes chain id, so a tx signed for another chain is rejected
+fn amino_json_signed_tx_for_other_chain_is_rejected() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_amino_json_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        ChainId::new("other-chain").expect("hard coded chain id is valid"),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+    assert_ne!(res.tx_responses[0].code, 0);
+    assert!(res.tx_responses[0].log.contains("invalid signature"));
+}
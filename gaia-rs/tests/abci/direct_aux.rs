@@ -0,0 +1,181 @@
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    baseapp::genesis::Genesis,
+    core::tx::mode_info::{ModeInfo, SignMode},
+    tendermint::types::time::timestamp::Timestamp,
+    types::{
+        address::AccAddress,
+        base::coins::{Coins, UnsignedCoins},
+        msg::send::MsgSend,
+        uint::Uint256,
+    },
+    utils::node::{encode_tx, generate_aux_tx, User},
+};
+
+use crate::{ibc_transfer::balance, mock_node, GaiaMockNode, USER_0, USER_1};
+
+fn setup() -> (GaiaMockNode, User, User) {
+    let aux_signer = crate::user(3, USER_0);
+    let fee_payer = crate::user(4, USER_1);
+
+    let mut genesis = GenesisState::default();
+    for user in [&aux_signer, &fee_payer] {
+        genesis
+            .add_genesis_account(
+                user.address(),
+                "100uatom".parse().expect("hard coded coin is valid"),
+            )
+            .expect("won't fail since accounts are different");
+    }
+
+    let mut node = mock_node(genesis);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    (node, aux_signer, fee_payer)
+}
+
+fn send_msg(from_address: AccAddress) -> gaia_rs::message::Message {
+    gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }))
+}
+
+fn tip() -> UnsignedCoins {
+    UnsignedCoins::new(vec!["5uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid")
+}
+
+#[test]
+/// A tx signed with SIGN_MODE_DIRECT_AUX is completed by the fee payer, who pays the fees
+/// and receives the tip of the aux signer
+fn direct_aux_tx_pays_tip_to_fee_payer() {
+    let (mut node, aux_signer, fee_payer) = setup();
+
+    let tx = generate_aux_tx(
+        vec1::vec1![send_msg(aux_signer.address())],
+        Some(tip()),
+        (&aux_signer, 0),
+        (&fee_payer, 0),
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![encode_tx(tx)], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    // 100 - 10 sent - 5 tip
+    assert_eq!(
+        balance(&node, aux_signer.address(), "uatom"),
+        Uint256::from(85u8)
+    );
+    // 100 - 1 fee + 5 tip
+    assert_eq!(
+        balance(&node, fee_payer.address(), "uatom"),
+        Uint256::from(104u8)
+    );
+
+    // both signers use incremented sequences in the next tx
+    let tx = generate_aux_tx(
+        vec1::vec1![send_msg(aux_signer.address())],
+        None,
+        (&aux_signer, 1),
+        (&fee_payer, 1),
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![encode_tx(tx)], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    assert_eq!(
+        balance(&node, aux_signer.address(), "uatom"),
+        Uint256::from(75u8)
+    );
+    assert_eq!(
+        balance(&node, fee_payer.address(), "uatom"),
+        Uint256::from(103u8)
+    );
+}
+
+#[test]
+/// The tip is transferred after the messages, so it stays with the tipper if a message fails
+fn failed_msg_leaves_tip_with_tipper() {
+    let (mut node, aux_signer, fee_payer) = setup();
+
+    let mut msg = send_msg(aux_signer.address());
+    if let gaia_rs::message::Message::Bank(bank::Message::Send(send)) = &mut msg {
+        send.amount = Coins::new(vec!["1000uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid");
+    }
+
+    let tx = generate_aux_tx(
+        vec1::vec1![msg],
+        Some(tip()),
+        (&aux_signer, 0),
+        (&fee_payer, 0),
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![encode_tx(tx)], Timestamp::UNIX_EPOCH);
+    assert_ne!(res.tx_responses[0].code, 0);
+
+    assert_eq!(
+        balance(&node, aux_signer.address(), "uatom"),
+        Uint256::from(100u8)
+    );
+    // the fee is still paid
+    assert_eq!(
+        balance(&node, fee_payer.address(), "uatom"),
+        Uint256::from(99u8)
+    );
+}
+
+#[test]
+/// The tip is part of the aux signer's sign doc, so the fee payer can't change it
+fn direct_aux_tx_with_changed_tip_is_rejected() {
+    let (mut node, aux_signer, fee_payer) = setup();
+
+    let mut tx = generate_aux_tx(
+        vec1::vec1![send_msg(aux_signer.address())],
+        Some(tip()),
+        (&aux_signer, 0),
+        (&fee_payer, 0),
+        node.chain_id().clone(),
+    );
+    if let Some(tip) = tx.auth_info.tip.as_mut() {
+        tip.amount = Some(
+            UnsignedCoins::new(vec!["50uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        );
+    }
+
+    let res = node.step(vec![encode_tx(tx)], Timestamp::UNIX_EPOCH);
+    assert_ne!(res.tx_responses[0].code, 0);
+    assert!(res.tx_responses[0].log.contains("invalid signature"));
+
+    assert_eq!(
+        balance(&node, aux_signer.address(), "uatom"),
+        Uint256::from(100u8)
+    );
+}
+
+#[test]
+/// Fee payer signs the whole tx, so it can't use SIGN_MODE_DIRECT_AUX
+fn fee_payer_with_direct_aux_is_rejected() {
+    let (mut node, aux_signer, fee_payer) = setup();
+
+    let mut tx = generate_aux_tx(
+        vec1::vec1![send_msg(aux_signer.address())],
+        Some(tip()),
+        (&aux_signer, 0),
+        (&fee_payer, 0),
+        node.chain_id().clone(),
+    );
+    tx.auth_info.signer_infos[1].mode_info = ModeInfo::Single(SignMode::DirectAux);
+
+    let res = node.step(vec![encode_tx(tx)], Timestamp::UNIX_EPOCH);
+    assert_ne!(res.tx_responses[0].code, 0);
+    assert!(res.tx_responses[0]
+        .log
+        .contains("SIGN_MODE_DIRECT_AUX can't be used by fee payer"));
+}
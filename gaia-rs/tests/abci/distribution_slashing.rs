@@ -0,0 +1,280 @@
+use distribution::{
+    QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+};
+use gaia_rs::{genesis::GenesisState, message::Message};
+use gears::core::Protobuf;
+use gears::{
+    baseapp::genesis::Genesis,
+    tendermint::types::{
+        proto::crypto::PublicKey, request::query::RequestQuery, time::timestamp::Timestamp,
+    },
+    types::{
+        address::{ConsAddress, ValAddress},
+        base::{coin::DecimalCoin, coins::DecimalCoins},
+        decimal256::Decimal256,
+        msg::send::MsgSend,
+        uint::Uint256,
+    },
+    utils::node::{generate_tx, User},
+};
+use staking::{
+    CommissionRates, CreateValidator, Description, IbcV046Validator, QueryValidatorRequest,
+    QueryValidatorResponse,
+};
+
+use crate::{mock_network, GaiaMockNetwork, USER_0, USER_1, USER_2};
+
+pub(crate) const PUB_KEY_0: &str = r#"{
+    "type": "tendermint/PubKeyEd25519",
+    "value": "NJWo4rSXCswNmK0Bttxzb8/1ioFNkRVi6Fio2KzAlCo="
+}"#;
+
+pub(crate) const PUB_KEY_1: &str = r#"{
+    "type": "tendermint/PubKeyEd25519",
+    "value": "6Ob7SEB++IzwqXQQ/pgsD/bkxXNl+LDBhJZwpKuvnMo="
+}"#;
+
+pub(crate) const PUB_KEY_2: &str = r#"{
+    "type": "tendermint/PubKeyEd25519",
+    "value": "i4eDTliiogyfz5o4s7nP+62EmzOdIqAVxrS+hB8s0Tg="
+}"#;
+
+#[test]
+/// Three validators with 20%, 20% and 60% of the voting power share the fees of the blocks
+/// they sign. Inflation and proposer rewards are disabled, so a 1uatom fee is split exactly:
+/// 2% goes to the community pool, the rest to the validators by their power and 10% of a
+/// validator reward is its commission. The second validator goes offline and is slashed and
+/// jailed, after that the fees are shared by the other two validators only.
+fn distribution_slashing() {
+    let users = [
+        crate::user(3, USER_0),
+        crate::user(4, USER_1),
+        crate::user(5, USER_2),
+    ];
+
+    let mut genesis = GenesisState::default();
+    for user in &users {
+        genesis
+            .add_genesis_account(
+                user.address(),
+                "100000000000uatom".parse().expect("hardcoded is valid"),
+            )
+            .expect("won't fail since accounts are different");
+    }
+    genesis.mint.minter.inflation = Decimal256::zero();
+    genesis.mint.params.inflation_rate_change = Decimal256::zero();
+    genesis.mint.params.inflation_max = Decimal256::zero();
+    genesis.mint.params.inflation_min = Decimal256::zero();
+    genesis.distribution.params.base_proposer_reward = Decimal256::zero();
+    genesis.distribution.params.bonus_proposer_reward = Decimal256::zero();
+    genesis.slashing.params.signed_blocks_window = 10;
+    let mut network = mock_network(genesis, 3);
+
+    network.step(vec![], Timestamp::UNIX_EPOCH);
+
+    //----------------------------------------
+    // Create three validators, they join the validator set two blocks later
+
+    let pub_keys = [PUB_KEY_0, PUB_KEY_1, PUB_KEY_2]
+        .map(|key| serde_json::from_str::<PublicKey>(key).expect("hardcoded is valid"));
+    let cons_address_1 = ConsAddress::from(pub_keys[1].clone());
+    let stakes = ["10000000000uatom", "10000000000uatom", "30000000000uatom"];
+
+    let txs = users
+        .iter()
+        .zip(pub_keys)
+        .zip(stakes)
+        .map(|((user, pub_key), stake)| {
+            let mut msg = create_validator_msg(user, pub_key);
+            if let Message::Staking(staking::Message::CreateValidator(msg)) = &mut msg {
+                msg.value = stake.parse().expect("hardcoded is valid");
+            }
+
+            generate_tx(vec1::vec1![msg], 0, user, network.chain_id().clone())
+        })
+        .collect();
+    let step_response = network.step(txs, timestamp(2));
+    for tx_response in &step_response.tx_responses {
+        assert_eq!(tx_response.code, 0, "{}", tx_response.log);
+    }
+
+    network.step(vec![], timestamp(3));
+    assert_eq!(network.validators().len(), 3);
+
+    let validators = users
+        .each_ref()
+        .map(|user| ValAddress::from(user.address()));
+
+    //----------------------------------------
+    // Every block pays a fee to all validators. Fees of a block are distributed at the
+    // beginning of the next one.
+
+    for (sequence, height) in (1..=10).zip(4..=13) {
+        step_with_fee(&mut network, &users[0], sequence, height);
+    }
+    network.step(vec![], timestamp(14));
+
+    let rewards_before_jailing = [
+        ("1.96uatom", "0.196uatom"),
+        ("1.96uatom", "0.196uatom"),
+        ("5.88uatom", "0.588uatom"),
+    ];
+    for (validator, (rewards, commission)) in validators.iter().zip(rewards_before_jailing) {
+        assert_eq!(outstanding_rewards(&network, validator), coins(rewards));
+        assert_eq!(validator_commission(&network, validator), coins(commission));
+    }
+
+    //----------------------------------------
+    // The second validator stops signing blocks. It's slashed by 1% of its stake and jailed
+    // once it misses more than half of the 10 blocks window.
+
+    network.set_online(&cons_address_1, false);
+    let mut height = 15;
+    while !validator(&network, &validators[1]).jailed {
+        assert!(height < 50, "validator isn't jailed for downtime");
+        network.step(vec![], timestamp(height));
+        height += 1;
+    }
+
+    let jailed_validator = validator(&network, &validators[1]);
+    assert_eq!(jailed_validator.tokens, Uint256::from(9_900_000_000u64));
+    assert!(!validator(&network, &validators[0]).jailed);
+    assert!(!validator(&network, &validators[2]).jailed);
+
+    // Jailed validator leaves the validator set
+    network.step(vec![], timestamp(height));
+    network.step(vec![], timestamp(height + 1));
+    assert_eq!(network.validators().len(), 2);
+
+    // No fees were paid in the meantime, so slashing and jailing don't change the rewards
+    for (validator, (rewards, commission)) in validators.iter().zip(rewards_before_jailing) {
+        assert_eq!(outstanding_rewards(&network, validator), coins(rewards));
+        assert_eq!(validator_commission(&network, validator), coins(commission));
+    }
+
+    //----------------------------------------
+    // Fees are shared by the remaining validators only, with 25% and 75% of the voting power
+
+    for (sequence, height) in (11..=20).zip(height + 2..) {
+        step_with_fee(&mut network, &users[0], sequence, height);
+    }
+    network.step(vec![], timestamp(height + 12));
+
+    let rewards_after_jailing = [
+        ("4.41uatom", "0.441uatom"),
+        ("1.96uatom", "0.196uatom"),
+        ("13.23uatom", "1.323uatom"),
+    ];
+    for (validator, (rewards, commission)) in validators.iter().zip(rewards_after_jailing) {
+        assert_eq!(outstanding_rewards(&network, validator), coins(rewards));
+        assert_eq!(validator_commission(&network, validator), coins(commission));
+    }
+}
+
+/// Produces a block with a single tx which pays 1uatom fee
+fn step_with_fee(network: &mut GaiaMockNetwork, user: &User, sequence: u64, height: i64) {
+    let msg = Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hardcoded is valid"),
+        amount: "1uatom".parse().expect("hardcoded is valid"),
+    }));
+    let tx = generate_tx(vec1::vec1![msg], sequence, user, network.chain_id().clone());
+
+    let step_response = network.step(vec![tx], timestamp(height));
+    assert_eq!(
+        step_response.tx_responses[0].code, 0,
+        "{}",
+        step_response.tx_responses[0].log
+    );
+}
+
+fn outstanding_rewards(network: &GaiaMockNetwork, validator: &ValAddress) -> Option<DecimalCoins> {
+    let res = network.query(RequestQuery {
+        data: QueryValidatorOutstandingRewardsRequest {
+            validator_address: validator.clone(),
+        }
+        .encode_vec()
+        .into(),
+        path: "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards".to_string(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryValidatorOutstandingRewardsResponse::decode(res.value)
+        .expect("valid response")
+        .rewards
+        .and_then(|rewards| rewards.rewards)
+}
+
+fn validator_commission(network: &GaiaMockNetwork, validator: &ValAddress) -> Option<DecimalCoins> {
+    let res = network.query(RequestQuery {
+        data: QueryValidatorCommissionRequest {
+            validator_address: validator.clone(),
+        }
+        .encode_vec()
+        .into(),
+        path: "/cosmos.distribution.v1beta1.Query/ValidatorCommission".to_string(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryValidatorCommissionResponse::decode(res.value)
+        .expect("valid response")
+        .commission
+        .and_then(|commission| commission.commission)
+}
+
+fn validator(network: &GaiaMockNetwork, validator_addr: &ValAddress) -> IbcV046Validator {
+    let res = network.query(RequestQuery {
+        data: QueryValidatorRequest {
+            validator_addr: validator_addr.clone(),
+        }
+        .encode_vec()
+        .into(),
+        path: "/cosmos.staking.v1beta1.Query/Validator".to_string(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryValidatorResponse::decode(res.value)
+        .expect("valid response")
+        .validator
+        .expect("validator exists")
+}
+
+fn coins(coin: &str) -> Option<DecimalCoins> {
+    Some(
+        DecimalCoins::new([coin.parse::<DecimalCoin>().expect("hardcoded is valid")])
+            .expect("hardcoded is valid"),
+    )
+}
+
+fn timestamp(height: i64) -> Timestamp {
+    Timestamp::try_new(height * 5, 0).expect("hardcoded is valid")
+}
+
+pub(crate) fn create_validator_msg(user: &User, pubkey: PublicKey) -> Message {
+    Message::Staking(staking::Message::CreateValidator(CreateValidator {
+        description: Description {
+            moniker: "test".to_string(),
+            identity: "".to_string(),
+            website: "".to_string(),
+            details: "".to_string(),
+            security_contact: "".to_string(),
+        },
+        commission: CommissionRates::new(
+            "0.1".parse().expect("hardcoded is valid"),
+            "1".parse().expect("hardcoded is valid"),
+            "0.1".parse().expect("hardcoded is valid"),
+        )
+        .expect("hardcoded is valid"),
+        min_self_delegation: Uint256::from(100u32),
+        delegator_address: user.address(),
+        validator_address: user.address().into(),
+        pubkey,
+        value: "10000000000uatom".parse().expect("hardcoded is valid"),
+    }))
+}
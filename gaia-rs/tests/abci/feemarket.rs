@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use feemarket::{
+    genesis::FeeMarketGenesis,
+    params::FeeMarketParams,
+    types::query::{request::QueryBaseFeeRequest, response::QueryBaseFeeResponse},
+};
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    baseapp::genesis::Genesis,
+    core::Protobuf,
+    tendermint::types::{
+        chain_id::ChainId, consensus::ConsensusParams, request::query::RequestQuery,
+        time::timestamp::Timestamp,
+    },
+    types::{base::coins::Coins, decimal256::Decimal256, msg::send::MsgSend},
+    utils::node::{generate_tx, User},
+};
+
+use crate::{mock_node, mock_node_with_params, GaiaMockNode, USER_0};
+
+/// Node with fee market of `base_gas_price` and the user. Txs of the user pay 1uatom for
+/// 200000 gas, so their gas price is 0.000005uatom.
+fn setup(enabled: bool, base_gas_price: &str) -> (GaiaMockNode, User) {
+    let user = crate::user(3, USER_0);
+    let base_gas_price = price(base_gas_price);
+
+    let mut genesis = GenesisState::default();
+    genesis
+        .add_genesis_account(
+            user.address(),
+            "100uatom".parse().expect("hard coded coin is valid"),
+        )
+        .expect("won't fail since there's no existing account");
+    genesis.feemarket = FeeMarketGenesis {
+        params: FeeMarketParams {
+            enabled,
+            min_base_gas_price: base_gas_price,
+            ..FeeMarketParams::default()
+        },
+        base_gas_price,
+    };
+
+    let mut node = mock_node(genesis);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    (node, user)
+}
+
+/// Node whose base gas price starts above the minimal one and whose blocks are limited
+/// by `max_gas`
+fn node_with_block_gas_limit(enabled: bool, max_gas: i64) -> GaiaMockNode {
+    let mut genesis = GenesisState::default();
+    genesis.feemarket = FeeMarketGenesis {
+        params: FeeMarketParams {
+            enabled,
+            min_base_gas_price: price("0.00001"),
+            ..FeeMarketParams::default()
+        },
+        base_gas_price: price("0.0001"),
+    };
+
+    let mut consensus_params = ConsensusParams::default();
+    consensus_params.block.max_gas = max_gas;
+
+    mock_node_with_params(genesis, ChainId::default(), consensus_params)
+}
+
+fn price(value: &str) -> Decimal256 {
+    Decimal256::from_str(value).expect("hard coded price is valid")
+}
+
+fn base_gas_price(node: &GaiaMockNode) -> Decimal256 {
+    let res = node.query(RequestQuery {
+        data: QueryBaseFeeRequest {}.encode_vec().into(),
+        path: QueryBaseFeeRequest::QUERY_URL.to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryBaseFeeResponse::decode(res.value)
+        .expect("response is valid")
+        .base_fee
+        .amount
+}
+
+fn send_msg(user: &User) -> gaia_rs::message::Message {
+    gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }))
+}
+
+#[test]
+fn tx_below_base_gas_price_is_rejected() {
+    let (mut node, user) = setup(true, "0.00001");
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(&user)],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+    assert_eq!(res.tx_responses[0].code, 13, "{}", res.tx_responses[0].log);
+    assert!(res.tx_responses[0]
+        .log
+        .contains("insufficient fees for base gas price; got: 1uatom required: 2uatom"));
+}
+
+#[test]
+fn tx_at_or_above_base_gas_price_passes() {
+    for base_gas_price in ["0.000005", "0.000001"] {
+        let (mut node, user) = setup(true, base_gas_price);
+
+        let tx = generate_tx(
+            vec1::vec1![send_msg(&user)],
+            0,
+            &user,
+            node.chain_id().clone(),
+        );
+        let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+        assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+    }
+}
+
+#[test]
+fn base_gas_price_is_ignored_when_fee_market_is_disabled() {
+    let (mut node, user) = setup(false, "0.00001");
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(&user)],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+}
+
+#[test]
+fn base_gas_price_drops_after_empty_block_with_block_gas_limit() {
+    let mut node = node_with_block_gas_limit(true, 10_000_000);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    // the empty block is below the target utilization, so the price drops by max change rate
+    assert_eq!(base_gas_price(&node), price("0.0000875"));
+}
+
+#[test]
+fn base_gas_price_is_constant_without_block_gas_limit_or_when_disabled() {
+    for (enabled, max_gas) in [(true, -1), (false, 10_000_000)] {
+        let mut node = node_with_block_gas_limit(enabled, max_gas);
+        node.step(vec![], Timestamp::UNIX_EPOCH);
+
+        assert_eq!(
+            base_gas_price(&node),
+            price("0.0001"),
+            "enabled: {enabled}, max gas: {max_gas}"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::num::NonZero;
+
+use gaia_rs::{genesis::GenesisState, message::Message, modules::GaiaModules};
+use gears::{
+    baseapp::genesis::Genesis,
+    core::{any::google::Any, Protobuf},
+    tendermint::types::{
+        proto::crypto::PublicKey,
+        request::query::RequestQuery,
+        time::{duration::Duration, timestamp::Timestamp},
+    },
+    utils::node::{generate_tx, User},
+    x::module::Module,
+};
+use gov::{
+    msg::{
+        v1::proposal::MsgSubmitProposal,
+        vote::{Vote, VoteOption},
+        GovMsg,
+    },
+    query::v1::{request::QueryProposalRequest, response::QueryProposalResponse},
+    types::proposal::{v1::ProposalV1, ProposalStatus},
+};
+use upgrade::types::{
+    plan::{Plan, PlanName},
+    query::{QueryCurrentPlanRequest, QueryCurrentPlanResponse},
+    tx::MsgSoftwareUpgrade,
+};
+
+use crate::{
+    distribution_slashing::{create_validator_msg, PUB_KEY_0},
+    mock_node, GaiaMockNode, USER_0,
+};
+
+const UPGRADE_HEIGHT: u32 = 1000;
+
+/// Node with a single validator which holds all the voting power. Regular voting period is
+/// 100 seconds, expedited one is 50 seconds.
+fn setup() -> (GaiaMockNode, User) {
+    let user = crate::user(3, USER_0);
+
+    let mut genesis = GenesisState::default();
+    genesis
+        .add_genesis_account(
+            user.address(),
+            "100000000000uatom".parse().expect("hardcoded is valid"),
+        )
+        .expect("won't fail since there's no existing account");
+    genesis.gov.params.deposit.min_deposit = "1000uatom".parse().expect("hardcoded is valid");
+    genesis.gov.params.deposit.expedited_min_deposit =
+        "5000uatom".parse().expect("hardcoded is valid");
+    genesis.gov.params.deposit.max_deposit_period = Duration::new_from_secs(100);
+    genesis.gov.params.voting.voting_period = Duration::new_from_secs(100);
+    genesis.gov.params.voting.expedited_voting_period = Duration::new_from_secs(50);
+
+    let mut node = mock_node(genesis);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let pub_key = serde_json::from_str::<PublicKey>(PUB_KEY_0).expect("hardcoded is valid");
+    let tx = generate_tx(
+        vec1::vec1![create_validator_msg(&user, pub_key)],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(5));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    (node, user)
+}
+
+#[test]
+/// Proposal is submitted, voted and its message is executed by the gov module account
+/// once the voting period ends
+fn passed_proposal_is_executed() {
+    let (mut node, user) = setup();
+
+    let tx = generate_tx(
+        vec1::vec1![submit_upgrade_proposal(&user, "1000uatom", false)],
+        1,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(10));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    let proposal_v1 = proposal(&node, 1);
+    assert_eq!(proposal_v1.status, ProposalStatus::VotingPeriod);
+    assert!(!proposal_v1.expedited);
+    assert_eq!(proposal_v1.voting_end_time, Some(time(110)));
+
+    let tx = generate_tx(
+        vec1::vec1![vote(&user, 1)],
+        2,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(20));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    // the expedited voting period is over, but the regular one isn't
+    node.step(vec![], time(60));
+    assert_eq!(proposal(&node, 1).status, ProposalStatus::VotingPeriod);
+    assert!(current_plan(&node).is_none());
+
+    node.step(vec![], time(110));
+    assert_eq!(proposal(&node, 1).status, ProposalStatus::Passed);
+
+    let plan = current_plan(&node).expect("upgrade is scheduled by the proposal");
+    assert_eq!(plan.name.as_ref(), "v2");
+    assert_eq!(plan.height.get(), UPGRADE_HEIGHT);
+}
+
+#[test]
+/// Expedited proposal passes once its shorter voting period ends
+fn passed_expedited_proposal_is_executed() {
+    let (mut node, user) = setup();
+
+    let tx = generate_tx(
+        vec1::vec1![submit_upgrade_proposal(&user, "5000uatom", true)],
+        1,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(10));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    let proposal_v1 = proposal(&node, 1);
+    assert_eq!(proposal_v1.status, ProposalStatus::VotingPeriod);
+    assert!(proposal_v1.expedited);
+    assert_eq!(proposal_v1.voting_end_time, Some(time(60)));
+
+    let tx = generate_tx(
+        vec1::vec1![vote(&user, 1)],
+        2,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(20));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    node.step(vec![], time(60));
+    assert_eq!(proposal(&node, 1).status, ProposalStatus::Passed);
+
+    let plan = current_plan(&node).expect("upgrade is scheduled by the proposal");
+    assert_eq!(plan.name.as_ref(), "v2");
+    assert_eq!(plan.height.get(), UPGRADE_HEIGHT);
+}
+
+#[test]
+/// Expedited proposal without votes is converted to a regular one and rejected at the end
+/// of the regular voting period
+fn failed_expedited_proposal_is_converted_to_regular() {
+    let (mut node, user) = setup();
+
+    let tx = generate_tx(
+        vec1::vec1![submit_upgrade_proposal(&user, "5000uatom", true)],
+        1,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], time(10));
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    node.step(vec![], time(60));
+    let proposal_v1 = proposal(&node, 1);
+    assert_eq!(proposal_v1.status, ProposalStatus::VotingPeriod);
+    assert!(!proposal_v1.expedited);
+    assert_eq!(proposal_v1.voting_end_time, Some(time(110)));
+
+    node.step(vec![], time(110));
+    assert_eq!(proposal(&node, 1).status, ProposalStatus::Rejected);
+    assert!(current_plan(&node).is_none());
+}
+
+fn submit_upgrade_proposal(proposer: &User, deposit: &str, expedited: bool) -> Message {
+    let msg = MsgSoftwareUpgrade {
+        authority: GaiaModules::Gov.address(),
+        plan: Plan {
+            name: PlanName::try_new("v2".to_owned()).expect("hardcoded is valid"),
+            height: NonZero::new(UPGRADE_HEIGHT).expect("hardcoded is valid"),
+            info: String::new(),
+        },
+    };
+
+    Message::Gov(GovMsg::ProposalV1(MsgSubmitProposal {
+        messages: vec![Any {
+            type_url: "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade".to_owned(),
+            value: msg.encode_vec(),
+        }],
+        initial_deposit: deposit.parse().expect("hardcoded is valid"),
+        proposer: proposer.address(),
+        metadata: String::new(),
+        title: "Upgrade".to_owned(),
+        summary: "Schedule v2 upgrade".to_owned(),
+        expedited,
+    }))
+}
+
+fn vote(voter: &User, proposal_id: u64) -> Message {
+    Message::Gov(GovMsg::Vote(Vote {
+        proposal_id,
+        voter: voter.address(),
+        option: VoteOption::Yes,
+    }))
+}
+
+fn proposal(node: &GaiaMockNode, proposal_id: u64) -> ProposalV1 {
+    let res = node.query(RequestQuery {
+        data: QueryProposalRequest { proposal_id }.encode_vec().into(),
+        path: "/cosmos.gov.v1.Query/Proposal".to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryProposalResponse::decode(res.value)
+        .expect("valid response")
+        .proposal
+        .expect("proposal exists")
+}
+
+fn current_plan(node: &GaiaMockNode) -> Option<Plan> {
+    let res = node.query(RequestQuery {
+        data: QueryCurrentPlanRequest {}.encode_vec().into(),
+        path: "/cosmos.upgrade.v1beta1.QueryCurrentPlanRequest".to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryCurrentPlanResponse::decode(res.value)
+        .expect("valid response")
+        .plan
+}
+
+fn time(seconds: i64) -> Timestamp {
+    Timestamp::try_new(seconds, 0).expect("hardcoded is valid")
+}
@@ -0,0 +1,234 @@
+use gears::{tendermint::types::request::query::RequestQuery, utils::node::generate_tx};
+use ibc::core::client::types::proto::v1::{
+    QueryClientParamsRequest, QueryClientParamsResponse, QueryClientStateRequest,
+    QueryClientStateResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+    QueryConsensusStatesResponse,
+};
+use ibc::core::{
+    channel::types::proto::v1::QueryChannelRequest,
+    connection::types::proto::v1::{QueryClientConnectionsRequest, QueryConnectionRequest},
+};
+use prost::Message;
+
+use crate::{
+    ibc_connection::{create_client_msg, node, time},
+    GaiaMockNode, USER_0,
+};
+
+#[test]
+/// A created client is active and its state can be queried by a relayer
+fn client_queries() {
+    let user = crate::user(3, USER_0);
+    let mut node_a = node("chain-a-1", &user);
+    let mut node_b = node("chain-b-1", &user);
+
+    node_a.step(vec![], time(1));
+    node_b.step(vec![], time(1));
+    let client_height = u64::from(node_b.height());
+
+    let msg = create_client_msg(&node_b, time(1), &user);
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        0,
+        &user,
+        node_a.chain_id().clone(),
+    )];
+    let step_response = node_a.step(txs, time(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+
+    let res: QueryClientStateResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ClientState",
+        QueryClientStateRequest {
+            client_id: "07-tendermint-0".to_owned(),
+        },
+    );
+    assert!(res.client_state.is_some());
+
+    let res: QueryClientStatusResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ClientStatus",
+        QueryClientStatusRequest {
+            client_id: "07-tendermint-0".to_owned(),
+        },
+    );
+    assert_eq!(res.status, "Active");
+
+    let res: QueryClientStatusResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ClientStatus",
+        QueryClientStatusRequest {
+            client_id: "07-tendermint-1".to_owned(),
+        },
+    );
+    assert_eq!(res.status, "Unknown");
+
+    let res: QueryConsensusStateHeightsResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ConsensusStateHeights",
+        QueryConsensusStateHeightsRequest {
+            client_id: "07-tendermint-0".to_owned(),
+            pagination: None,
+        },
+    );
+    assert_eq!(res.consensus_state_heights.len(), 1);
+    assert_eq!(res.consensus_state_heights[0].revision_number, 1);
+    assert_eq!(
+        res.consensus_state_heights[0].revision_height,
+        client_height
+    );
+
+    let res: QueryConsensusStateResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ConsensusState",
+        QueryConsensusStateRequest {
+            client_id: "07-tendermint-0".to_owned(),
+            revision_number: 0,
+            revision_height: 0,
+            latest_height: true,
+        },
+    );
+    assert!(res.consensus_state.is_some());
+
+    let res: QueryClientParamsResponse = query(
+        &node_a,
+        "/ibc.core.client.v1.Query/ClientParams",
+        QueryClientParamsRequest {},
+    );
+    assert!(res
+        .params
+        .expect("params are set at genesis")
+        .allowed_clients
+        .contains(&"07-tendermint".to_owned()));
+}
+
+#[test]
+/// The consensus states of a client are returned page by page
+fn consensus_states_pagination() {
+    let user = crate::user(3, USER_0);
+    let mut node_a = node("chain-a-1", &user);
+    let mut node_b = node("chain-b-1", &user);
+
+    node_a.step(vec![], time(1));
+    node_b.step(vec![], time(1));
+
+    let msg = create_client_msg(&node_b, time(1), &user);
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        0,
+        &user,
+        node_a.chain_id().clone(),
+    )];
+    let step_response = node_a.step(txs, time(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+
+    let consensus_states = |offset: u64| -> QueryConsensusStatesResponse {
+        let mut req = QueryConsensusStatesRequest {
+            client_id: "07-tendermint-0".to_owned(),
+            pagination: Some(Default::default()),
+        };
+        let page = req.pagination.as_mut().expect("pagination is set");
+        page.offset = offset;
+        page.limit = 1;
+
+        query(&node_a, "/ibc.core.client.v1.Query/ConsensusStates", req)
+    };
+
+    let res = consensus_states(0);
+    assert_eq!(res.consensus_states.len(), 1);
+    assert_eq!(res.pagination.expect("request is paginated").total, 1);
+
+    let res = consensus_states(1);
+    assert!(res.consensus_states.is_empty());
+}
+
+#[test]
+/// The queries fail on invalid identifiers instead of returning empty responses
+fn queries_reject_invalid_identifiers() {
+    let user = crate::user(3, USER_0);
+    let mut node = node("chain-a-1", &user);
+
+    node.step(vec![], time(1));
+
+    let codes = [
+        query_code(
+            &node,
+            "/ibc.core.client.v1.Query/ClientState",
+            QueryClientStateRequest {
+                client_id: "invalid client".to_owned(),
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.client.v1.Query/ClientStatus",
+            QueryClientStatusRequest {
+                client_id: "invalid client".to_owned(),
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.client.v1.Query/ConsensusStates",
+            QueryConsensusStatesRequest {
+                client_id: "invalid client".to_owned(),
+                pagination: None,
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.client.v1.Query/ConsensusState",
+            QueryConsensusStateRequest {
+                client_id: "07-tendermint-0".to_owned(),
+                revision_number: 1,
+                revision_height: 0,
+                latest_height: false,
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.connection.v1.Query/Connection",
+            QueryConnectionRequest {
+                connection_id: "invalid connection".to_owned(),
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.connection.v1.Query/ClientConnections",
+            QueryClientConnectionsRequest {
+                client_id: "invalid client".to_owned(),
+            },
+        ),
+        query_code(
+            &node,
+            "/ibc.core.channel.v1.Query/Channel",
+            QueryChannelRequest {
+                port_id: "transfer".to_owned(),
+                channel_id: "invalid channel".to_owned(),
+            },
+        ),
+    ];
+
+    assert!(codes.iter().all(|code| *code != 0), "{codes:?}");
+}
+
+fn query<Req: Message, Res: Message + Default>(node: &GaiaMockNode, path: &str, req: Req) -> Res {
+    let res = node.query(RequestQuery {
+        data: req.encode_to_vec().into(),
+        path: path.to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    Res::decode(res.value).expect("response is valid")
+}
+
+fn query_code<Req: Message>(node: &GaiaMockNode, path: &str, req: Req) -> u32 {
+    node.query(RequestQuery {
+        data: req.encode_to_vec().into(),
+        path: path.to_owned(),
+        height: 0,
+        prove: false,
+    })
+    .code
+}
@@ -0,0 +1,344 @@
+use std::time::Duration;
+
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    baseapp::genesis::Genesis,
+    tendermint::types::{
+        chain_id::ChainId, request::query::RequestQuery, time::timestamp::Timestamp,
+    },
+    utils::node::{generate_tx, User},
+};
+use ibc::{
+    clients::tendermint::types::{
+        proto::v1::ConsensusState as RawTmConsensusState, AllowUpdate,
+        ClientState as TmClientState, ConsensusState as TmConsensusState, TrustThreshold,
+    },
+    core::{
+        channel::types::proto::v1::{
+            Counterparty as RawChannelCounterparty, IdentifiedChannel, QueryChannelRequest,
+            QueryChannelResponse,
+        },
+        client::types::Height,
+        commitment_types::{
+            commitment::CommitmentPrefix, proto::v1::MerklePrefix, specs::ProofSpecs,
+        },
+        connection::types::{
+            msgs::MsgConnectionOpenInit as IbcMsgConnectionOpenInit,
+            proto::v1::{
+                ConnectionPaths, Counterparty as RawConnectionCounterparty, IdentifiedConnection,
+                MsgConnectionOpenTry as RawMsgConnectionOpenTry, QueryClientConnectionsRequest,
+                QueryClientConnectionsResponse, QueryConnectionRequest, QueryConnectionResponse,
+                Version as RawVersion,
+            },
+            Counterparty,
+        },
+        host::types::identifiers::ChainId as IbcChainId,
+    },
+    primitives::{proto::Timestamp as RawTimestamp, Signer},
+};
+use ibc_rs::message::{MsgConnectionOpenInit, MsgConnectionOpenTry, MsgCreateClient};
+use prost::Message;
+
+use crate::{mock_node_with_chain_id, GaiaMockNode, USER_0};
+
+/// Next validators hash of the headers produced by the mock node
+const NEXT_VALIDATORS_HASH: &str =
+    "696d9de0dd248bc8121fab92bf453262d2d16fe1ff84224bb787e65934ad680d";
+
+#[test]
+/// Two chains create clients of each other, the first one starts a connection handshake.
+/// The chains can't prove their state yet, so the counterparty rejects the handshake.
+fn connection_open_init_and_try() {
+    let user = crate::user(3, USER_0);
+    let mut node_a = node("chain-a-1", &user);
+    let mut node_b = node("chain-b-1", &user);
+
+    node_a.step(vec![], time(1));
+    node_b.step(vec![], time(1));
+    let client_of_b = client_state(&node_b);
+
+    //----------------------------------------
+    // Each chain creates a client of the other one
+
+    let msg = create_client_msg(&node_b, time(1), &user);
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        0,
+        &user,
+        node_a.chain_id().clone(),
+    )];
+    let step_response = node_a.step(txs, time(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+
+    let msg = create_client_msg(&node_a, time(2), &user);
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        0,
+        &user,
+        node_b.chain_id().clone(),
+    )];
+    let step_response = node_b.step(txs, time(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    let client_of_a_height = node_a.height();
+
+    //----------------------------------------
+    // The first chain starts the handshake
+
+    let msg = MsgConnectionOpenInit {
+        inner: IbcMsgConnectionOpenInit {
+            client_id_on_a: "07-tendermint-0".parse().expect("hardcoded is valid"),
+            counterparty: Counterparty::new(
+                "07-tendermint-0".parse().expect("hardcoded is valid"),
+                None,
+                CommitmentPrefix::try_from(b"ibc".to_vec()).expect("hardcoded is valid"),
+            ),
+            version: None,
+            delay_period: Duration::ZERO,
+            signer: Signer::from(user.address().to_string()),
+        },
+        signer: user.address(),
+    };
+    let txs = vec![generate_tx(
+        vec1::vec1![gaia_rs::message::Message::IBC(
+            ibc_rs::message::Message::ConnectionOpenInit(msg)
+        )],
+        1,
+        &user,
+        node_a.chain_id().clone(),
+    )];
+    let step_response = node_a.step(txs, time(3));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    assert!(step_response.tx_responses[0]
+        .events
+        .iter()
+        .any(|event| event.r#type == "connection_open_init"
+            && event.attributes.iter().any(|attribute| {
+                attribute.key.as_ref() == b"connection_id"
+                    && attribute.value.as_ref() == b"connection-0"
+            })));
+
+    let connection = query_connection(&node_a, "connection-0").expect("connection is stored");
+    assert_eq!(connection.state, 1); // INIT
+    assert_eq!(connection.client_id, "07-tendermint-0");
+
+    let res = node_a.query(RequestQuery {
+        data: QueryClientConnectionsRequest {
+            client_id: "07-tendermint-0".to_owned(),
+        }
+        .encode_to_vec()
+        .into(),
+        path: "/ibc.core.connection.v1.Query/ClientConnections".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    let res = QueryClientConnectionsResponse::decode(res.value).expect("response is valid");
+    assert_eq!(res.connection_paths, vec!["connection-0".to_owned()]);
+
+    //----------------------------------------
+    // The counterparty can't verify the proofs of the connection end
+
+    let raw = RawMsgConnectionOpenTry {
+        client_id: "07-tendermint-0".to_owned(),
+        client_state: Some(client_of_b.clone().into()),
+        counterparty: Some(RawConnectionCounterparty {
+            client_id: "07-tendermint-0".to_owned(),
+            connection_id: "connection-0".to_owned(),
+            prefix: Some(MerklePrefix {
+                key_prefix: b"ibc".to_vec(),
+            }),
+        }),
+        delay_period: 0,
+        counterparty_versions: vec![version()],
+        proof_height: Some(
+            Height::new(1, client_of_a_height.into())
+                .expect("hardcoded is valid")
+                .into(),
+        ),
+        proof_init: vec![1; 32],
+        proof_client: vec![2; 32],
+        proof_consensus: vec![3; 32],
+        consensus_height: Some(client_of_b.latest_height.into()),
+        signer: user.address().to_string(),
+        ..Default::default()
+    };
+    let msg = MsgConnectionOpenTry::try_from(raw).expect("message is valid");
+    let txs = vec![generate_tx(
+        vec1::vec1![gaia_rs::message::Message::IBC(
+            ibc_rs::message::Message::ConnectionOpenTry(msg)
+        )],
+        1,
+        &user,
+        node_b.chain_id().clone(),
+    )];
+    let step_response = node_b.step(txs, time(3));
+    assert_ne!(step_response.tx_responses[0].code, 0);
+    assert!(query_connection(&node_b, "connection-0").is_none());
+}
+
+#[test]
+/// Connections and channels imported at genesis can be queried
+fn handshakes_from_genesis() {
+    let user = crate::user(3, USER_0);
+    let mut genesis = genesis(&user);
+
+    genesis.ibc.connection_genesis.connections = vec![IdentifiedConnection {
+        id: "connection-0".to_owned(),
+        client_id: "07-tendermint-0".to_owned(),
+        versions: vec![version()],
+        state: 3,
+        counterparty: Some(RawConnectionCounterparty {
+            client_id: "07-tendermint-2".to_owned(),
+            connection_id: "connection-4".to_owned(),
+            prefix: Some(MerklePrefix {
+                key_prefix: b"ibc".to_vec(),
+            }),
+        }),
+        delay_period: 0,
+    }];
+    genesis.ibc.connection_genesis.client_connection_paths = vec![ConnectionPaths {
+        client_id: "07-tendermint-0".to_owned(),
+        paths: vec!["connection-0".to_owned()],
+    }];
+    genesis.ibc.connection_genesis.next_connection_sequence = 1;
+    genesis.ibc.channel_genesis.channels = vec![IdentifiedChannel {
+        state: 3,
+        ordering: 1,
+        counterparty: Some(RawChannelCounterparty {
+            port_id: "transfer".to_owned(),
+            channel_id: "channel-9".to_owned(),
+        }),
+        connection_hops: vec!["connection-0".to_owned()],
+        version: "ics20-1".to_owned(),
+        port_id: "transfer".to_owned(),
+        channel_id: "channel-0".to_owned(),
+        ..Default::default()
+    }];
+    genesis.ibc.channel_genesis.next_channel_sequence = 1;
+
+    let mut node = mock_node_with_chain_id(
+        genesis,
+        ChainId::new("chain-a-1").expect("hardcoded is valid"),
+    );
+    node.step(vec![], time(1));
+
+    let connection = query_connection(&node, "connection-0").expect("connection is imported");
+    assert_eq!(connection.state, 3); // OPEN
+    assert_eq!(
+        connection.counterparty.map(|c| c.connection_id),
+        Some("connection-4".to_owned())
+    );
+
+    let res = node.query(RequestQuery {
+        data: QueryChannelRequest {
+            port_id: "transfer".to_owned(),
+            channel_id: "channel-0".to_owned(),
+        }
+        .encode_to_vec()
+        .into(),
+        path: "/ibc.core.channel.v1.Query/Channel".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    let channel = QueryChannelResponse::decode(res.value)
+        .expect("response is valid")
+        .channel
+        .expect("channel is imported");
+    assert_eq!(channel.state, 3); // OPEN
+    assert_eq!(channel.connection_hops, vec!["connection-0".to_owned()]);
+
+    assert!(query_connection(&node, "connection-1").is_none());
+}
+
+pub(crate) fn time(block: i64) -> Timestamp {
+    Timestamp::try_new(1_700_000_000 + block * 5, 0).expect("hardcoded is valid")
+}
+
+pub(crate) fn genesis(user: &User) -> GenesisState {
+    let mut genesis = GenesisState::default();
+    genesis
+        .add_genesis_account(
+            user.address(),
+            "100000000000uatom".parse().expect("hardcoded is valid"),
+        )
+        .expect("won't fail since there's no existing account");
+    genesis
+}
+
+pub(crate) fn node(chain_id: &str, user: &User) -> GaiaMockNode {
+    mock_node_with_chain_id(
+        genesis(user),
+        ChainId::new(chain_id).expect("hardcoded is valid"),
+    )
+}
+
+pub(crate) fn version() -> RawVersion {
+    RawVersion {
+        identifier: "1".to_owned(),
+        features: vec!["ORDER_ORDERED".to_owned(), "ORDER_UNORDERED".to_owned()],
+    }
+}
+
+/// Client state tracking the counterparty at its latest height
+pub(crate) fn client_state(counterparty: &GaiaMockNode) -> TmClientState {
+    TmClientState::new(
+        IbcChainId::new(counterparty.chain_id().as_str()).expect("chain id is valid"),
+        TrustThreshold::ONE_THIRD,
+        Duration::from_secs(14 * 24 * 60 * 60),
+        Duration::from_secs(21 * 24 * 60 * 60),
+        Duration::from_secs(10),
+        Height::new(1, counterparty.height().into()).expect("height is not zero"),
+        ProofSpecs::cosmos(),
+        vec!["upgrade".to_owned(), "upgradedIBCState".to_owned()],
+        AllowUpdate {
+            after_expiry: false,
+            after_misbehaviour: false,
+        },
+    )
+    .expect("client state is valid")
+}
+
+pub(crate) fn create_client_msg(
+    counterparty: &GaiaMockNode,
+    block_time: Timestamp,
+    user: &User,
+) -> gaia_rs::message::Message {
+    let consensus_state = TmConsensusState::try_from(RawTmConsensusState {
+        timestamp: Some(RawTimestamp {
+            seconds: block_time.timestamp_seconds().into(),
+            nanos: 0,
+        }),
+        root: Some(ibc::core::commitment_types::proto::v1::MerkleRoot {
+            hash: counterparty.app_hash().to_vec(),
+        }),
+        next_validators_hash: hex::decode(NEXT_VALIDATORS_HASH).expect("hardcoded is valid"),
+    })
+    .expect("consensus state is valid");
+
+    gaia_rs::message::Message::IBC(ibc_rs::message::Message::ClientCreate(
+        MsgCreateClient::new(
+            client_state(counterparty).into(),
+            consensus_state.into(),
+            user.address(),
+        ),
+    ))
+}
+
+fn query_connection(
+    node: &GaiaMockNode,
+    connection_id: &str,
+) -> Option<ibc::core::connection::types::proto::v1::ConnectionEnd> {
+    let res = node.query(RequestQuery {
+        data: QueryConnectionRequest {
+            connection_id: connection_id.to_owned(),
+        }
+        .encode_to_vec()
+        .into(),
+        path: "/ibc.core.connection.v1.Query/Connection".to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryConnectionResponse::decode(res.value)
+        .expect("response is valid")
+        .connection
+}
@@ -0,0 +1,209 @@
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    core::Protobuf,
+    extensions::testing::UnwrapTesting,
+    tendermint::types::{chain_id::ChainId, request::query::RequestQuery},
+    types::{address::AccAddress, uint::Uint256},
+    utils::node::{generate_tx, User},
+};
+use ibc::{
+    apps::transfer::types::{
+        proto::transfer::v1::{
+            MsgTransfer as RawMsgTransfer, QueryDenomTraceRequest, QueryDenomTraceResponse,
+        },
+        PrefixedCoin,
+    },
+    core::{
+        channel::types::proto::v1::{
+            Counterparty as RawChannelCounterparty, IdentifiedChannel, PacketSequence,
+        },
+        commitment_types::proto::v1::MerklePrefix,
+        connection::types::proto::v1::{
+            ConnectionPaths, Counterparty as RawConnectionCounterparty, IdentifiedConnection,
+        },
+    },
+};
+use ibc_rs::message::MsgTransfer;
+use prost::Message;
+
+use crate::{
+    ibc_connection::{create_client_msg, genesis, node, time, version},
+    mock_node_with_chain_id, GaiaMockNode, USER_0,
+};
+
+#[test]
+/// Tokens sent through an open channel are escrowed until the packet is acknowledged
+fn transfer_escrows_tokens() {
+    let user = crate::user(3, USER_0);
+    let mut counterparty = node("chain-b-1", &user);
+    counterparty.step(vec![], time(1));
+
+    let mut node = mock_node_with_chain_id(
+        genesis_with_channel(&user),
+        ChainId::new("chain-a-1").expect("hardcoded is valid"),
+    );
+    node.step(vec![], time(1));
+
+    // the connection of the channel is built on top of the client of the counterparty
+    let msg = create_client_msg(&counterparty, time(1), &user);
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        0,
+        &user,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, time(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+
+    let balance_before = balance(&node, user.address(), "uatom");
+
+    let msg = transfer_msg(&user, "100uatom");
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        1,
+        &user,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, time(3));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    assert!(step_response.tx_responses[0]
+        .events
+        .iter()
+        .any(|event| event.r#type == "send_packet"));
+
+    // the fee of the transaction is 1uatom
+    assert_eq!(
+        balance(&node, user.address(), "uatom"),
+        balance_before - Uint256::from(101u32)
+    );
+
+    //----------------------------------------
+    // Vouchers can't be sent unless their trace is known
+
+    let voucher = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+    let msg = transfer_msg(&user, &format!("100{voucher}"));
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        2,
+        &user,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, time(4));
+    assert_ne!(step_response.tx_responses[0].code, 0);
+
+    let res = node.query(RequestQuery {
+        data: QueryDenomTraceRequest {
+            hash: voucher.to_owned(),
+        }
+        .encode_to_vec()
+        .into(),
+        path: "/ibc.applications.transfer.v1.Query/DenomTrace".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    let res = QueryDenomTraceResponse::decode(res.value).expect("response is valid");
+    assert_eq!(res.denom_trace, None);
+
+    //----------------------------------------
+    // Tokens which the sender doesn't have can't be escrowed
+
+    let msg = transfer_msg(&user, "1000000000000uatom");
+    let txs = vec![generate_tx(
+        vec1::vec1![msg],
+        3,
+        &user,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, time(5));
+    assert_ne!(step_response.tx_responses[0].code, 0);
+}
+
+/// Genesis of a chain with an open transfer channel to `chain-b-1`
+fn genesis_with_channel(user: &User) -> GenesisState {
+    let mut genesis = genesis(user);
+
+    genesis.ibc.connection_genesis.connections = vec![IdentifiedConnection {
+        id: "connection-0".to_owned(),
+        client_id: "07-tendermint-0".to_owned(),
+        versions: vec![version()],
+        state: 3,
+        counterparty: Some(RawConnectionCounterparty {
+            client_id: "07-tendermint-0".to_owned(),
+            connection_id: "connection-0".to_owned(),
+            prefix: Some(MerklePrefix {
+                key_prefix: b"ibc".to_vec(),
+            }),
+        }),
+        delay_period: 0,
+    }];
+    genesis.ibc.connection_genesis.client_connection_paths = vec![ConnectionPaths {
+        client_id: "07-tendermint-0".to_owned(),
+        paths: vec!["connection-0".to_owned()],
+    }];
+    genesis.ibc.connection_genesis.next_connection_sequence = 1;
+
+    genesis.ibc.channel_genesis.channels = vec![IdentifiedChannel {
+        state: 3,
+        ordering: 1,
+        counterparty: Some(RawChannelCounterparty {
+            port_id: "transfer".to_owned(),
+            channel_id: "channel-0".to_owned(),
+        }),
+        connection_hops: vec!["connection-0".to_owned()],
+        version: "ics20-1".to_owned(),
+        port_id: "transfer".to_owned(),
+        channel_id: "channel-0".to_owned(),
+        ..Default::default()
+    }];
+    let packet_sequence = PacketSequence {
+        port_id: "transfer".to_owned(),
+        channel_id: "channel-0".to_owned(),
+        sequence: 1,
+    };
+    genesis.ibc.channel_genesis.send_sequences = vec![packet_sequence.clone()];
+    genesis.ibc.channel_genesis.recv_sequences = vec![packet_sequence.clone()];
+    genesis.ibc.channel_genesis.ack_sequences = vec![packet_sequence];
+    genesis.ibc.channel_genesis.next_channel_sequence = 1;
+
+    genesis
+}
+
+fn transfer_msg(user: &User, amount: &str) -> gaia_rs::message::Message {
+    let token: PrefixedCoin = amount.parse().expect("hardcoded is valid");
+    let raw = RawMsgTransfer {
+        source_port: "transfer".to_owned(),
+        source_channel: "channel-0".to_owned(),
+        token: Some(token.into()),
+        sender: user.address().to_string(),
+        receiver: "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux".to_owned(),
+        timeout_height: None,
+        timeout_timestamp: u64::try_from(time(1000).timestamp_nanoseconds())
+            .expect("hardcoded is positive"),
+        memo: String::new(),
+    };
+
+    gaia_rs::message::Message::IBC(ibc_rs::message::Message::Transfer(
+        MsgTransfer::try_from(raw).expect("message is valid"),
+    ))
+}
+
+pub(crate) fn balance(node: &GaiaMockNode, address: AccAddress, denom: &str) -> Uint256 {
+    let res = node.query(RequestQuery {
+        data: QueryBalanceRequest {
+            address,
+            denom: denom.try_into().unwrap_test(),
+        }
+        .encode_vec()
+        .into(),
+        path: "/cosmos.bank.v1beta1.Query/Balance".to_owned(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryBalanceResponse::decode(res.value)
+        .unwrap_test()
+        .balance
+        .map(|coin| coin.amount)
+        .unwrap_or_default()
+}
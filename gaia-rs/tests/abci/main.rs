@@ -13,11 +13,22 @@ use gears::tendermint::types::chain_id::ChainId;
 use gears::tendermint::types::consensus::ConsensusParams;
 use gears::tendermint::types::proto::validator::{ValidatorUpdate, VotingPower};
 use gears::tendermint::types::time::timestamp::Timestamp;
-use gears::utils::node::{InitState, MockNode, User};
+use gears::utils::node::{InitState, MockNetwork, MockNode, User};
 use keyring::key::pair::KeyPair;
 use std::fs;
 use std::path::Path;
 
+mod amino_json;
+mod direct_aux;
+mod distribution_slashing;
+mod feemarket;
+mod gov;
+mod ibc_client;
+mod ibc_connection;
+mod ibc_transfer;
+mod network;
+mod params;
+mod rest;
 mod scenario_1;
 mod scenario_2;
 mod scenario_3;
@@ -28,6 +39,8 @@ mod two_tx;
 const USER_0: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
 // cosmos15qzm75pjh0jqsv3u40hzp2vzs2hdp47fkz7j5q
 const USER_1: &str = "unfair live spike near cushion blanket club salad poet cigar venue above north speak harbor salute curve tail appear obvious month end boss priority";
+// cosmos15jlqmacda2pzerhw48gvvxskweg8sz2saadn99
+const USER_2: &str = "utility radio trust maid picture hold palace heart craft fruit recycle void embrace gospel write what soccer resemble yellow decade rug knock control celery";
 
 // This is a helper function to create a user with a specific account number
 pub fn user(account_number: u64, mnemonic: &str) -> User {
@@ -41,29 +54,18 @@ pub fn user(account_number: u64, mnemonic: &str) -> User {
     }
 }
 
-fn setup_mock_node(
-    genesis_path: Option<impl AsRef<Path>>,
-) -> (
-    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>,
-    User,
-) {
-    let db = MemDB::new();
-    let node_options = NodeOptions::default();
-    let config: Config<AppConfig> = Config::default();
-    let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
-        db,
-        GaiaParamsStoreKey::BaseApp,
-        GaiaABCIHandler::new(config),
-        node_options,
-    );
-    let chain_id = ChainId::default();
+type GaiaApp = BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>;
 
+type GaiaMockNode = MockNode<GaiaApp, GenesisState>;
+
+type GaiaMockNetwork = MockNetwork<GaiaApp, GenesisState>;
+
+fn setup_mock_node(genesis_path: Option<impl AsRef<Path>>) -> (GaiaMockNode, User) {
     let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
     let mnemonic =
         bip32::Mnemonic::new(mnemonic, bip32::Language::English).expect("mnemonic is invalid");
     let key_pair = KeyPair::from_mnemonic(&mnemonic);
     let address = key_pair.get_address();
-    let consensus_key = gears::tendermint::crypto::new_private_key();
 
     let genesis = if let Some(path) = genesis_path {
         let genesis_state =
@@ -80,10 +82,61 @@ fn setup_mock_node(
         genesis
     };
 
+    (
+        mock_node(genesis),
+        User {
+            key_pair,
+            account_number: 3,
+        },
+    )
+}
+
+fn mock_node(genesis: GenesisState) -> GaiaMockNode {
+    mock_node_with_chain_id(genesis, ChainId::default())
+}
+
+fn mock_app() -> GaiaApp {
+    let config: Config<AppConfig> = Config::default();
+
+    BaseApp::new(
+        MemDB::new(),
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(config),
+        NodeOptions::default(),
+    )
+}
+
+/// Network of `nodes` gaia apps. Validators of the network are only the ones created by
+/// the app, since votes of a validator unknown to staking can't be processed by slashing.
+fn mock_network(genesis: GenesisState, nodes: usize) -> GaiaMockNetwork {
     let init_state = InitState {
         time: Timestamp::UNIX_EPOCH,
-        chain_id: chain_id.clone(),
+        chain_id: ChainId::default(),
         consensus_params: ConsensusParams::default(),
+        validators: vec![],
+        app_genesis: genesis,
+        initial_height: 1,
+    };
+
+    MockNetwork::new((0..nodes).map(|_| mock_app()), init_state)
+}
+
+fn mock_node_with_chain_id(genesis: GenesisState, chain_id: ChainId) -> GaiaMockNode {
+    mock_node_with_params(genesis, chain_id, ConsensusParams::default())
+}
+
+fn mock_node_with_params(
+    genesis: GenesisState,
+    chain_id: ChainId,
+    consensus_params: ConsensusParams,
+) -> GaiaMockNode {
+    let app = mock_app();
+    let consensus_key = gears::tendermint::crypto::new_private_key();
+
+    let init_state = InitState {
+        time: Timestamp::UNIX_EPOCH,
+        chain_id,
+        consensus_params,
         validators: vec![ValidatorUpdate {
             pub_key: consensus_key
                 .try_into()
@@ -94,11 +147,5 @@ fn setup_mock_node(
         initial_height: 1,
     };
 
-    (
-        MockNode::new(app, init_state),
-        User {
-            key_pair,
-            account_number: 3,
-        },
-    )
+    MockNode::new(app, init_state)
 }
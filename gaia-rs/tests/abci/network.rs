@@ -0,0 +1,124 @@
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    baseapp::genesis::Genesis,
+    core::Protobuf,
+    tendermint::types::{
+        proto::crypto::PublicKey, request::query::RequestQuery, time::timestamp::Timestamp,
+    },
+    types::address::{ConsAddress, ValAddress},
+    utils::node::generate_tx,
+};
+use slashing::{QuerySigningInfoRequest, QuerySigningInfoResponse};
+use staking::{QueryValidatorRequest, QueryValidatorResponse};
+
+use crate::{
+    distribution_slashing::{create_validator_msg, PUB_KEY_0, PUB_KEY_1},
+    mock_network, GaiaMockNetwork, USER_0, USER_1,
+};
+
+#[test]
+/// Three nodes run the blocks of two validators. The first validator double signs and is
+/// tombstoned, the second one goes offline and is jailed for downtime. The network asserts
+/// that all nodes agree on every block and query.
+fn network_slashing() {
+    let user_0 = crate::user(3, USER_0);
+    let user_1 = crate::user(4, USER_1);
+
+    let mut genesis = GenesisState::default();
+    for user in [&user_0, &user_1] {
+        genesis
+            .add_genesis_account(
+                user.address(),
+                "100000000000uatom".parse().expect("hardcoded is valid"),
+            )
+            .expect("won't fail since accounts are different");
+    }
+    let mut network = mock_network(genesis, 3);
+
+    network.step(vec![], Timestamp::UNIX_EPOCH);
+
+    //----------------------------------------
+    // Create two validators, they join the validator set two blocks later
+
+    let pub_key_0 = serde_json::from_str::<PublicKey>(PUB_KEY_0).expect("hardcoded is valid");
+    let pub_key_1 = serde_json::from_str::<PublicKey>(PUB_KEY_1).expect("hardcoded is valid");
+    let cons_address_0 = ConsAddress::from(pub_key_0.clone());
+    let cons_address_1 = ConsAddress::from(pub_key_1.clone());
+
+    let txs = vec![
+        generate_tx(
+            vec1::vec1![create_validator_msg(&user_0, pub_key_0)],
+            0,
+            &user_0,
+            network.chain_id().clone(),
+        ),
+        generate_tx(
+            vec1::vec1![create_validator_msg(&user_1, pub_key_1)],
+            0,
+            &user_1,
+            network.chain_id().clone(),
+        ),
+    ];
+    let step_response = network.step(txs, timestamp(2));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    assert_eq!(step_response.tx_responses[1].code, 0);
+    assert_eq!(network.nodes()[0].last_validator_updates().len(), 2);
+    assert!(network.validators().is_empty());
+
+    network.step(vec![], timestamp(3));
+    assert_eq!(network.validators().len(), 2);
+
+    //----------------------------------------
+    // The second validator stops signing blocks
+
+    network.set_online(&cons_address_1, false);
+    for height in 4..=110 {
+        network.step(vec![], timestamp(height));
+    }
+
+    //----------------------------------------
+    // The first validator signed two blocks at the same height
+
+    network.double_sign(&cons_address_0, 100);
+    network.step(vec![], timestamp(111));
+
+    let validator = |validator_addr: ValAddress| {
+        let res = network.query(RequestQuery {
+            data: QueryValidatorRequest { validator_addr }.encode_vec().into(),
+            path: "/cosmos.staking.v1beta1.Query/Validator".to_string(),
+            height: 0,
+            prove: false,
+        });
+        QueryValidatorResponse::decode(res.value)
+            .unwrap()
+            .validator
+            .expect("validator exists")
+    };
+    assert!(validator(ValAddress::from(user_0.address())).jailed);
+    assert!(validator(ValAddress::from(user_1.address())).jailed);
+
+    let signing_info = |network: &GaiaMockNetwork, cons_address: ConsAddress| {
+        let res = network.query(RequestQuery {
+            data: QuerySigningInfoRequest { cons_address }.encode_vec().into(),
+            path: "/cosmos.slashing.v1beta1.Query/SigningInfo".to_string(),
+            height: 0,
+            prove: false,
+        });
+        QuerySigningInfoResponse::decode(res.value)
+            .unwrap()
+            .val_signing_info
+    };
+    assert!(signing_info(&network, cons_address_0).tombstoned);
+    assert!(!signing_info(&network, cons_address_1).tombstoned);
+
+    //----------------------------------------
+    // Jailed validators leave the validator set
+
+    network.step(vec![], timestamp(112));
+    network.step(vec![], timestamp(113));
+    assert!(network.validators().is_empty());
+}
+
+fn timestamp(height: i64) -> Timestamp {
+    Timestamp::try_new(height * 5, 0).expect("hardcoded is valid")
+}
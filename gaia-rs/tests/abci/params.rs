@@ -0,0 +1,106 @@
+use gaia_rs::store_keys::{GaiaParamsStoreKey, GaiaStoreKey};
+use gears::params::{ParamsSubspaceValidation, ParamsValidationError};
+use gov::proposal::{
+    param::{ParamChange, ParamChangeProposalHandler, ParameterChangeProposal},
+    ProposalHandler,
+};
+
+type ParamsHandler = ParamChangeProposalHandler<GaiaStoreKey, GaiaParamsStoreKey>;
+
+const VOTING_PARAMS: &str =
+    r#"{"voting_period":"172800000000000","expedited_voting_period":"86400000000000"}"#;
+
+#[test]
+fn params_are_validated_by_their_subspace() {
+    let cases: [(GaiaParamsStoreKey, &str, &str); 8] = [
+        (GaiaParamsStoreKey::Bank, "DefaultSendEnabled", "true"),
+        (GaiaParamsStoreKey::Auth, "MaxMemoCharacters", "\"512\""),
+        (GaiaParamsStoreKey::Staking, "MaxValidators", "100"),
+        (GaiaParamsStoreKey::Staking, "BondDenom", "\"uatom\""),
+        (
+            GaiaParamsStoreKey::IBC,
+            "AllowedClients",
+            "[\"07-tendermint\"]",
+        ),
+        (
+            GaiaParamsStoreKey::IBC,
+            "MaxExpectedTimePerBlock",
+            "\"30000000000\"",
+        ),
+        (GaiaParamsStoreKey::Gov, "votingparams", VOTING_PARAMS),
+        (
+            GaiaParamsStoreKey::Slashing,
+            "SignedBlocksWindow",
+            "\"100\"",
+        ),
+    ];
+
+    for (subspace, key, value) in cases {
+        assert_eq!(
+            subspace.validate_raw(key, value.as_bytes()),
+            Ok(()),
+            "{subspace:?} {key}"
+        );
+    }
+}
+
+#[test]
+fn invalid_params_are_rejected_by_their_subspace() {
+    // the value is checked by the params of the subspace
+    assert!(matches!(
+        GaiaParamsStoreKey::Staking.validate_raw("MaxValidators", b"0"),
+        Err(ParamsValidationError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        GaiaParamsStoreKey::Bank.validate_raw("DefaultSendEnabled", b"\"yes\""),
+        Err(ParamsValidationError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        GaiaParamsStoreKey::IBC.validate_raw("MaxExpectedTimePerBlock", b"\"0\""),
+        Err(ParamsValidationError::InvalidValue { .. })
+    ));
+
+    // the key must belong to the subspace
+    assert_eq!(
+        GaiaParamsStoreKey::Bank.validate_raw("MaxValidators", b"100"),
+        Err(ParamsValidationError::UnknownKey(
+            "MaxValidators".to_owned()
+        ))
+    );
+    assert_eq!(
+        GaiaParamsStoreKey::Capability.validate_raw("index", b"1"),
+        Err(ParamsValidationError::UnknownKey("index".to_owned()))
+    );
+}
+
+#[test]
+fn param_change_proposal_is_checked_by_subspace() {
+    let proposal = |changes: Vec<ParamChange<GaiaParamsStoreKey>>| ParameterChangeProposal {
+        title: "title".to_owned(),
+        description: "description".to_owned(),
+        changes,
+    };
+    let change = |subspace: GaiaParamsStoreKey, key: &str, value: &str| ParamChange {
+        subspace,
+        key: key.as_bytes().to_vec(),
+        value: value.as_bytes().to_vec(),
+    };
+
+    assert!(ParamsHandler::check(&proposal(vec![change(
+        GaiaParamsStoreKey::Staking,
+        "MaxValidators",
+        "50"
+    )])));
+
+    // single invalid change rejects the whole proposal
+    assert!(!ParamsHandler::check(&proposal(vec![
+        change(GaiaParamsStoreKey::Staking, "MaxValidators", "50"),
+        change(GaiaParamsStoreKey::Staking, "MaxEntries", "0"),
+    ])));
+    // key of another subspace
+    assert!(!ParamsHandler::check(&proposal(vec![change(
+        GaiaParamsStoreKey::Gov,
+        "MaxValidators",
+        "50"
+    )])));
+}
@@ -0,0 +1,444 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use gaia_rs::{genesis::GenesisState, GaiaNodeQueryRequest, GaiaNodeQueryResponse};
+use gears::{
+    baseapp::genesis::Genesis,
+    rest::{RestState, BLOCK_HEIGHT_HEADER},
+    tendermint::types::{
+        chain_id::ChainId, consensus::ConsensusParams, proto::crypto::PublicKey,
+        time::timestamp::Timestamp,
+    },
+    types::{address::ValAddress, base::coin::UnsignedCoin},
+    utils::node::{generate_tx, InitState, MockNode, User},
+};
+use serde_json::Value;
+use staking::{DelegateMsg, UndelegateMsg};
+use tower::ServiceExt;
+
+use crate::{
+    distribution_slashing::{create_validator_msg, PUB_KEY_0},
+    mock_app, GaiaApp, GaiaMockNode, USER_0, USER_1,
+};
+
+/// Height at which the validator of `USER_0` exists but nobody has unbonded yet
+const HEIGHT_BEFORE_UNBONDING: u32 = 3;
+
+#[test]
+fn staking_routes() {
+    let (app, _node, user_0, _user_1) = setup();
+    let validator = ValAddress::from(user_0.address()).to_string();
+
+    let (status, body) = get(&app, "/cosmos/staking/v1beta1/validators", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["validators"].as_array().map(Vec::len), Some(1));
+    assert_eq!(body["validators"][0]["operator_address"], validator);
+
+    let (status, body) = get(
+        &app,
+        &format!("/cosmos/staking/v1beta1/validators/{validator}"),
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["validator"]["operator_address"], validator);
+
+    let (status, body) = get(
+        &app,
+        &format!("/cosmos/staking/v1beta1/validators/{validator}/delegations"),
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["delegation_responses"].as_array().map(Vec::len),
+        Some(2)
+    );
+
+    let (status, body) = get(&app, "/cosmos/staking/v1beta1/pool", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["pool"].is_object());
+
+    let (status, body) = get(&app, "/cosmos/staking/v1beta1/params", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["params"].is_object());
+}
+
+#[test]
+fn staking_unbonding_delegation_routes() {
+    let (app, _node, user_0, user_1) = setup();
+    let validator = ValAddress::from(user_0.address()).to_string();
+    let delegator = user_1.address().to_string();
+
+    // all the unbonding delegations of the validator
+    let (status, body) = get(
+        &app,
+        &format!("/cosmos/staking/v1beta1/validators/{validator}/unbonding_delegations"),
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["unbonding_responses"].as_array().map(Vec::len),
+        Some(2)
+    );
+
+    // the unbonding delegations of the validator filtered by the delegator
+    let uri = format!(
+        "/cosmos/staking/v1beta1/validators/{validator}/delegations/{delegator}/unbonding_delegation"
+    );
+    let (status, body) = get(&app, &uri, None);
+    assert_eq!(status, StatusCode::OK);
+    let unbonding_responses = body["unbonding_responses"]
+        .as_array()
+        .expect("unbonding responses are a list");
+    assert_eq!(unbonding_responses.len(), 1);
+    assert_eq!(unbonding_responses[0]["delegator_address"], delegator);
+    assert_eq!(unbonding_responses[0]["validator_address"], validator);
+
+    // nothing was unbonding at a past height
+    let (status, body) = get(&app, &uri, Some(HEIGHT_BEFORE_UNBONDING));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["unbonding_responses"], Value::Array(vec![]));
+}
+
+#[test]
+fn block_height_header() {
+    let (app, node, user_0, _user_1) = setup();
+    let uri = format!(
+        "/cosmos/staking/v1beta1/validators/{}",
+        ValAddress::from(user_0.address())
+    );
+
+    // the validator doesn't exist before it is created
+    let (status, body) = get(&app, "/cosmos/staking/v1beta1/validators", Some(1));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["validators"], Value::Array(vec![]));
+
+    let (status, _) = get(&app, &uri, Some(HEIGHT_BEFORE_UNBONDING));
+    assert_eq!(status, StatusCode::OK);
+
+    // the header must be a height
+    let request = Request::builder()
+        .uri(&uri)
+        .header(BLOCK_HEIGHT_HEADER, "latest")
+        .body(Body::empty())
+        .expect("hardcoded request is valid");
+    let (status, _) = send(&app, request);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // the height must have been committed
+    let (status, _) = get(&app, &uri, Some(node.height() + 10));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn slashing_routes() {
+    let (app, _node, _user_0, _user_1) = setup();
+
+    let (status, body) = get(&app, "/cosmos/slashing/v1beta1/params", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["params"].is_object());
+
+    let (status, body) = get(&app, "/cosmos/slashing/v1beta1/signing_infos", None);
+    assert_eq!(status, StatusCode::OK);
+    let info = body["info"].as_array().expect("info is a list");
+    assert_eq!(info.len(), 1);
+
+    let cons_address = info[0]["address"]
+        .as_str()
+        .expect("address is a string")
+        .to_owned();
+    let (status, body) = get(
+        &app,
+        &format!("/cosmos/slashing/v1beta1/signing_infos/{cons_address}"),
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["val_signing_info"]["address"], cons_address);
+
+    // signing infos are created when the validator is bonded
+    let (status, body) = get(&app, "/cosmos/slashing/v1beta1/signing_infos", Some(1));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["info"], Value::Array(vec![]));
+}
+
+#[test]
+fn evidence_routes() {
+    let (app, _node, _user_0, _user_1) = setup();
+
+    let (status, body) = get(&app, "/cosmos/evidence/v1beta1/evidence", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["evidence"], Value::Array(vec![]));
+
+    let (status, _) = get(&app, "/cosmos/evidence/v1beta1/evidence", Some(1));
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[test]
+fn mint_routes() {
+    let (app, _node, _user_0, _user_1) = setup();
+
+    for uri in [
+        "/cosmos/mint/v1beta1/params",
+        "/cosmos/mint/v1beta1/inflation",
+        "/cosmos/mint/v1beta1/annual_provisions",
+    ] {
+        let (status, _) = get(&app, uri, None);
+        assert_eq!(status, StatusCode::OK, "{uri}");
+
+        let (status, _) = get(&app, uri, Some(HEIGHT_BEFORE_UNBONDING));
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    let (status, body) = get(&app, "/cosmos/mint/v1beta1/params", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["params"].is_object());
+}
+
+#[test]
+fn upgrade_routes() {
+    let (app, _node, _user_0, _user_1) = setup();
+
+    let (status, body) = get(&app, "/cosmos/upgrade/v1beta1/current_plan", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["plan"], Value::Null);
+
+    let (status, body) = get(&app, "/cosmos/upgrade/v1beta1/applied_plan/unknown", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["height"], 0);
+
+    let (status, body) = get(&app, "/cosmos/upgrade/v1beta1/module_versions", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["module_versions"].is_array());
+
+    let (status, body) = get(
+        &app,
+        "/cosmos/upgrade/v1beta1/upgraded_consensus_state/10",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["upgraded_consensus_state"], Value::Array(vec![]));
+
+    let (status, body) = get(&app, "/cosmos/upgrade/v1beta1/authority", None);
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["address"].is_string());
+}
+
+#[test]
+fn ibc_routes() {
+    let (app, _node, _user_0, _user_1) = setup();
+
+    let (status, body) = get(&app, "/ibc/core/client/v1/client_states", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["client_states"], Value::Array(vec![]));
+
+    let (status, _) = get(&app, "/ibc/core/client/v1/client_states", Some(1));
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = get(&app, "/ibc/core/client/v1/params", None);
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = get(&app, "/ibc/core/connection/v1/connections", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["connections"], Value::Array(vec![]));
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/connection/v1/connections/connection-0",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["connection"], Value::Null);
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/connection/v1/client_connections/07-tendermint-0",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["connection_paths"], Value::Array(vec![]));
+
+    let (status, body) = get(&app, "/ibc/core/channel/v1/channels", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["channels"], Value::Array(vec![]));
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["channel"], Value::Null);
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/channel/v1/connections/connection-0/channels",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["channels"], Value::Array(vec![]));
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer/packet_commitments",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["commitments"], Value::Array(vec![]));
+
+    let (status, body) = get(
+        &app,
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer/packet_receipts/1",
+        None,
+    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["received"], false);
+
+    for uri in [
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer/packet_commitments/1",
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer/packet_acks/1",
+        "/ibc/core/channel/v1/channels/channel-0/ports/transfer/next_sequence",
+    ] {
+        let (status, _) = get(&app, uri, None);
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    let (status, body) = get(&app, "/ibc/apps/transfer/v1/denom_traces", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["denom_traces"], Value::Array(vec![]));
+
+    let (status, body) = get(&app, "/ibc/apps/transfer/v1/denom_traces/ibc/ABCD", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["denom_trace"], Value::Null);
+
+    let (status, body) = get(&app, "/ibc/apps/transfer/v1/params", None);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["params"]["send_enabled"], true);
+    assert_eq!(body["params"]["receive_enabled"], true);
+}
+
+/// Node where `USER_0` runs a validator delegated to by `USER_1`, both of them then unbond
+/// part of their delegation. The returned app shares its state with the node.
+fn setup() -> (GaiaApp, GaiaMockNode, User, User) {
+    let user_0 = crate::user(3, USER_0);
+    let user_1 = crate::user(4, USER_1);
+
+    let mut genesis = GenesisState::default();
+    for user in [&user_0, &user_1] {
+        genesis
+            .add_genesis_account(
+                user.address(),
+                "100000000000uatom".parse().expect("hardcoded is valid"),
+            )
+            .expect("won't fail since accounts are different");
+    }
+
+    let app = mock_app();
+    let mut node = MockNode::new(
+        app.clone(),
+        InitState {
+            time: Timestamp::UNIX_EPOCH,
+            chain_id: ChainId::default(),
+            consensus_params: ConsensusParams::default(),
+            validators: vec![],
+            app_genesis: genesis,
+            initial_height: 1,
+        },
+    );
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let pub_key = serde_json::from_str::<PublicKey>(PUB_KEY_0).expect("hardcoded is valid");
+    let validator_address = ValAddress::from(user_0.address());
+    let amount: UnsignedCoin = "1000uatom".parse().expect("hardcoded is valid");
+
+    let txs = vec![generate_tx(
+        vec1::vec1![create_validator_msg(&user_0, pub_key)],
+        0,
+        &user_0,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, Timestamp::try_new(5, 0).expect("hardcoded is valid"));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+
+    let delegate = gaia_rs::message::Message::Staking(staking::Message::Delegate(DelegateMsg {
+        delegator_address: user_1.address(),
+        validator_address: validator_address.clone(),
+        amount: amount.clone(),
+    }));
+    let txs = vec![generate_tx(
+        vec1::vec1![delegate],
+        0,
+        &user_1,
+        node.chain_id().clone(),
+    )];
+    let step_response = node.step(txs, Timestamp::try_new(10, 0).expect("hardcoded is valid"));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    assert_eq!(node.height(), HEIGHT_BEFORE_UNBONDING);
+
+    let txs = [(&user_0, 1), (&user_1, 1)]
+        .into_iter()
+        .map(|(user, sequence)| {
+            let undelegate =
+                gaia_rs::message::Message::Staking(staking::Message::Undelegate(UndelegateMsg {
+                    delegator_address: user.address(),
+                    validator_address: validator_address.clone(),
+                    amount: amount.clone(),
+                }));
+            generate_tx(
+                vec1::vec1![undelegate],
+                sequence,
+                user,
+                node.chain_id().clone(),
+            )
+        })
+        .collect();
+    let step_response = node.step(txs, Timestamp::try_new(15, 0).expect("hardcoded is valid"));
+    assert_eq!(step_response.tx_responses[0].code, 0);
+    assert_eq!(step_response.tx_responses[1].code, 0);
+
+    (app, node, user_0, user_1)
+}
+
+/// Sends a `GET` request to the gaia REST router, `height` sets the block height header
+fn get(app: &GaiaApp, uri: &str, height: Option<u32>) -> (StatusCode, Value) {
+    let mut request = Request::builder().uri(uri);
+    if let Some(height) = height {
+        request = request.header(BLOCK_HEIGHT_HEADER, height.to_string());
+    }
+
+    send(
+        app,
+        request
+            .body(Body::empty())
+            .expect("hardcoded request is valid"),
+    )
+}
+
+fn send(app: &GaiaApp, request: Request<Body>) -> (StatusCode, Value) {
+    let router = gaia_rs::rest::get_router().with_state(RestState::<
+        GaiaNodeQueryRequest,
+        GaiaNodeQueryResponse,
+        GaiaApp,
+    >::new(
+        app.clone(),
+        "http://localhost:26657"
+            .parse()
+            .expect("hardcoded url is valid"),
+    ));
+
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("runtime can be built")
+        .block_on(async {
+            let response = router.oneshot(request).await.expect("router is infallible");
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("response body can be read");
+
+            (
+                status,
+                serde_json::from_slice(&body).expect("response body is json"),
+            )
+        })
+}
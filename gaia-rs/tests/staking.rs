@@ -182,6 +182,9 @@ fn redelegate_failed_on_invalid_amount() -> anyhow::Result<()> {
         home: gaia.home(),
         keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
         bip39_mnemonic: Some(mnemonic.to_owned()),
+        bip39_passphrase: None,
+        interactive: false,
+        hd_path: Default::default(),
     }))?;
 
     // create destination validator
@@ -483,7 +486,8 @@ mod helpers {
     ) -> anyhow::Result<GaiaTxCommands> {
         let pubkey = serde_json::from_str(pubkey)?;
         let tx_cmd = StakingCommands::CreateValidator(CreateValidatorCli {
-            pubkey,
+            pubkey: Some(pubkey),
+            priv_validator_laddr: None,
             amount,
             moniker: moniker.to_string(),
             identity: "".to_string(),
@@ -530,6 +534,9 @@ mod helpers {
             home: node.home(),
             keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
             bip39_mnemonic: Some(mnemonic.to_owned()),
+            bip39_passphrase: None,
+            interactive: false,
+            hd_path: Default::default(),
         }))?;
 
         // create destination validator
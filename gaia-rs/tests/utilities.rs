@@ -120,6 +120,8 @@ impl GaiaNode {
                 log_level: LOG_LEVEL,
                 min_gas_prices: Default::default(),
                 tendermint_rpc_addr: Some(rpc_addr_moved.try_into().expect("invalid rpc addr")),
+                halt_height: None,
+                halt_time: None,
             };
 
             let _ = node
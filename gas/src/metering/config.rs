@@ -1,23 +1,44 @@
-use std::sync::OnceLock;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
 use super::FiniteGas;
 
-#[derive(Debug)]
+/// Upper bound of a single cost of the schedule. A tx could still afford a few store operations
+/// at this cost, while higher ones would make the chain unusable.
+pub const MAX_GAS_COST: u32 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GasConfigError {
+    #[error("{0} must be positive")]
+    Zero(&'static str),
+    #[error("{0} must not be greater than {MAX_GAS_COST}")]
+    TooHigh(&'static str),
+}
+
+/// Gas costs of store operations. Stored on chain so could be changed by governance
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GasConfig {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub has_cost: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub delete_cost: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub read_cost_flat: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub read_cost_per_byte: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub write_cost_flat: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub write_cost_per_byte: FiniteGas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
     pub iter_next_cost_flat: FiniteGas,
 }
 
 impl GasConfig {
-    pub fn kv() -> &'static Self {
-        static DEFAULT_KV_CONFIG: OnceLock<GasConfig> = OnceLock::new();
-
-        DEFAULT_KV_CONFIG.get_or_init(|| Self {
+    /// Default costs of kv store, same as in cosmos sdk
+    pub fn default_kv() -> Self {
+        Self {
             has_cost: FiniteGas::from(1000_u16),
             delete_cost: FiniteGas::from(1000_u32),
             read_cost_flat: FiniteGas::from(1000_u32),
@@ -25,13 +46,12 @@ impl GasConfig {
             write_cost_flat: FiniteGas::from(2000_u32),
             write_cost_per_byte: FiniteGas::from(30_u8),
             iter_next_cost_flat: FiniteGas::from(30_u8),
-        })
+        }
     }
 
-    pub fn default_transient() -> &'static Self {
-        static DEFAULT_TRANSIENT_CONFIG: OnceLock<GasConfig> = OnceLock::new();
-
-        DEFAULT_TRANSIENT_CONFIG.get_or_init(|| Self {
+    /// Default costs of transient store, same as in cosmos sdk
+    pub fn default_transient() -> Self {
+        Self {
             has_cost: FiniteGas::from(1000_u16),
             delete_cost: FiniteGas::from(100_u8),
             read_cost_flat: FiniteGas::from(100_u8),
@@ -39,6 +59,35 @@ impl GasConfig {
             write_cost_flat: FiniteGas::from(200_u8),
             write_cost_per_byte: FiniteGas::from(3_u8),
             iter_next_cost_flat: FiniteGas::from(3_u8),
-        })
+        }
+    }
+
+    /// Checks that flat costs of the operations aren't free, otherwise store access could be spammed,
+    /// and that no cost is higher than [MAX_GAS_COST]. Per byte costs may be zero.
+    pub fn validate(&self) -> Result<(), GasConfigError> {
+        let flat = [
+            ("has_cost", self.has_cost),
+            ("delete_cost", self.delete_cost),
+            ("read_cost_flat", self.read_cost_flat),
+            ("write_cost_flat", self.write_cost_flat),
+        ];
+        let other = [
+            ("read_cost_per_byte", self.read_cost_per_byte),
+            ("write_cost_per_byte", self.write_cost_per_byte),
+            ("iter_next_cost_flat", self.iter_next_cost_flat),
+        ];
+
+        if let Some((name, _)) = flat.iter().find(|(_, cost)| *cost == FiniteGas::ZERO) {
+            return Err(GasConfigError::Zero(*name));
+        }
+
+        match flat
+            .iter()
+            .chain(other.iter())
+            .find(|(_, cost)| *cost > FiniteGas::from(MAX_GAS_COST))
+        {
+            Some((name, _)) => Err(GasConfigError::TooHigh(*name)),
+            None => Ok(()),
+        }
     }
 }
@@ -16,11 +16,14 @@ use super::{
 const GUARD_DESC: &str = "GasGuard";
 
 #[derive(Debug, Clone)]
-pub struct GasGuard(pub(super) Arc<RefCell<GasMeter<TxKind>>>);
+pub struct GasGuard {
+    meter: Arc<RefCell<GasMeter<TxKind>>>,
+    config: GasConfig,
+}
 
 impl GasGuard {
-    pub fn new(inner: Arc<RefCell<GasMeter<TxKind>>>) -> Self {
-        Self(inner)
+    pub fn new(meter: Arc<RefCell<GasMeter<TxKind>>>, config: GasConfig) -> Self {
+        Self { meter, config }
     }
 
     pub fn get(
@@ -29,12 +32,12 @@ impl GasGuard {
         value: Option<usize>,
         get_key: &[u8],
     ) -> Result<(), GasStoreErrors> {
-        let mut gas_meter = self.0.borrow_mut();
+        let mut gas_meter = self.meter.borrow_mut();
 
-        let read_cost_per_byte = GasConfig::kv().read_cost_per_byte;
+        let read_cost_per_byte = self.config.read_cost_per_byte;
 
         gas_meter
-            .consume_gas(GasConfig::kv().read_cost_flat, READ_COST_FLAT_DESC)
+            .consume_gas(self.config.read_cost_flat, READ_COST_FLAT_DESC)
             .map_err(|e| GasStoreErrors::new(get_key, e))?;
 
         gas_meter
@@ -69,12 +72,12 @@ impl GasGuard {
     }
 
     pub fn set(&self, key: usize, value: usize, set_key: &[u8]) -> Result<(), GasStoreErrors> {
-        let mut gas_meter = self.0.borrow_mut();
+        let mut gas_meter = self.meter.borrow_mut();
         gas_meter
-            .consume_gas(GasConfig::kv().read_cost_flat, WRITE_COST_FLAT_DESC)
+            .consume_gas(self.config.read_cost_flat, WRITE_COST_FLAT_DESC)
             .map_err(|e| GasStoreErrors::new(set_key, e))?;
 
-        let write_cost_per_byte = GasConfig::kv().write_cost_per_byte;
+        let write_cost_per_byte = self.config.write_cost_per_byte;
 
         gas_meter
             .consume_gas(
@@ -104,19 +107,19 @@ impl GasGuard {
     }
 
     pub fn delete(&self, delete_key: &[u8]) -> Result<(), GasStoreErrors> {
-        self.0
+        self.meter
             .borrow_mut()
-            .consume_gas(GasConfig::kv().delete_cost, DELETE_DESC)
+            .consume_gas(self.config.delete_cost, DELETE_DESC)
             .map_err(|e| GasStoreErrors::new(delete_key, e))?;
 
         Ok(())
     }
 
     pub fn range(&self, key_value: Option<(usize, usize, &[u8])>) -> Result<(), GasStoreErrors> {
-        let mut gas_meter = self.0.borrow_mut();
+        let mut gas_meter = self.meter.borrow_mut();
 
         if let Some((key, value, get_key)) = key_value {
-            let read_cost_per_byte = GasConfig::kv().read_cost_per_byte;
+            let read_cost_per_byte = self.config.read_cost_per_byte;
 
             gas_meter
                 .consume_gas(
@@ -145,13 +148,34 @@ impl GasGuard {
                 .map_err(|e| GasStoreErrors::new(get_key, e))?;
 
             gas_meter
-                .consume_gas(
-                    GasConfig::kv().iter_next_cost_flat,
-                    ITER_NEXT_CAST_FLAT_DESC,
-                )
+                .consume_gas(self.config.iter_next_cost_flat, ITER_NEXT_CAST_FLAT_DESC)
                 .map_err(|e| GasStoreErrors::new(get_key, e))?; // I'm unsure how to handle such case
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use crate::metering::FiniteGas;
+
+    use super::*;
+
+    #[test]
+    fn set_charges_flat_and_per_byte_costs() {
+        let meter = Arc::new(RefCell::new(GasMeter::<TxKind>::infinite()));
+        let guard = GasGuard::new(Arc::clone(&meter), GasConfig::default_kv());
+
+        guard.set(3, 5, b"key").unwrap_test();
+
+        // the flat charge of set is the read cost as it always has been, changing it would
+        // change gas of every tx that writes
+        assert_eq!(
+            meter.borrow().consumed_or_limit(),
+            FiniteGas::from(1000_u32 + 30 * (3 + 5))
+        );
+    }
+}
@@ -3,7 +3,14 @@ use super::handlers::{
     AuxHandler,
 };
 use crate::{
-    commands::client::{keys::keys, query::run_query, tx::run_tx, ClientCommands},
+    commands::client::{
+        block::{run_block, run_block_results, run_blocks},
+        keys::keys,
+        query::run_query,
+        status::run_status,
+        tx::{run_aux_to_fee, run_tx},
+        ClientCommands,
+    },
     x::query::tx_query::{TxQueryHandler, TxsQueryHandler},
 };
 
@@ -44,6 +51,11 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
                     crate::commands::client::tx::RuntxResult::None => (),
                 }
             }
+            ClientCommands::AuxToFee(cmd) => {
+                if let Some(tx) = run_aux_to_fee(cmd, &self.core, &self.fetcher)?.broadcast() {
+                    println!("{}", serde_json::to_string_pretty(&tx)?);
+                }
+            }
             ClientCommands::Query(cmd) => {
                 let query = run_query(cmd, &self.core)?;
 
@@ -59,6 +71,30 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
 
                 println!("{}", serde_json::to_string_pretty(&query)?);
             }
+            ClientCommands::QueryBlock(cmd) => {
+                let output = cmd.output;
+                let block = run_block(cmd)?;
+
+                println!("{}", output.format(&block)?);
+            }
+            ClientCommands::QueryBlocks(cmd) => {
+                let output = cmd.output;
+                let blocks = run_blocks(cmd)?;
+
+                println!("{}", output.format(&blocks)?);
+            }
+            ClientCommands::QueryBlockResults(cmd) => {
+                let output = cmd.output;
+                let results = run_block_results(cmd)?;
+
+                println!("{}", output.format(&results)?);
+            }
+            ClientCommands::Status(cmd) => {
+                let output = cmd.output;
+                let status = run_status(cmd)?;
+
+                println!("{}", output.format(&status)?);
+            }
             ClientCommands::Keys(cmd) => keys(cmd)?,
         };
 
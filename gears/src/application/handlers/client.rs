@@ -4,7 +4,11 @@ use crate::{
     baseapp::Query,
     commands::client::tx::{broadcast_tx_commit, AccountProvider, ClientTxContext},
     crypto::{
-        info::{create_signed_transaction_direct, create_signed_transaction_textual, SigningInfo},
+        info::{
+            create_aux_signer_data, create_signed_transaction_amino_json,
+            create_signed_transaction_direct, create_signed_transaction_from_aux,
+            create_signed_transaction_textual, SigningInfo,
+        },
         keys::{GearsPublicKey, ReadAccAddress, SigningKey},
         public::PublicKey,
     },
@@ -13,8 +17,11 @@ use crate::{
     types::{
         account::{Account, BaseAccount},
         address::AccAddress,
+        auth::tip::Tip,
         denom::Denom,
-        tx::{body::TxBody, metadata::Metadata, Messages, Tx, TxMessage},
+        tx::{
+            body::TxBody, direct_aux::AuxSignerData, metadata::Metadata, Messages, Tx, TxMessage,
+        },
     },
 };
 
@@ -123,7 +130,10 @@ pub trait TxHandler {
             non_critical_extension_options: vec![], // TODO: remove hard coded
         };
 
-        let tip = None; //TODO: remove hard coded
+        let tip = ctx.tip.clone().map(|amount| Tip {
+            amount: Some(amount),
+            tipper: address.clone(),
+        });
 
         match mode {
             SignMode::Direct => create_signed_transaction_direct(
@@ -144,10 +154,91 @@ pub trait TxHandler {
                 fetcher,
             )
             .map_err(|e| anyhow!(e.to_string())),
+            SignMode::LegacyAminoJson => create_signed_transaction_amino_json(
+                signing_infos,
+                ctx.chain_id.clone(),
+                ctx.fee.clone(),
+                tip,
+                tx_body,
+            )
+            .map_err(|e| anyhow!(e.to_string())),
             _ => Err(anyhow!("unsupported sign mode")),
         }
     }
 
+    /// Sign messages with SIGN_MODE_DIRECT_AUX. The signer doesn't pay fees,
+    /// so the result should be completed by fee payer.
+    fn sign_aux<K: SigningKey + ReadAccAddress + GearsPublicKey, F: NodeFetcher + Clone>(
+        &self,
+        msgs: Messages<Self::Message>,
+        key: &K,
+        ctx: &mut ClientTxContext,
+        fetcher: &F,
+    ) -> anyhow::Result<AuxSignerData> {
+        let address = key.get_address();
+
+        let account = self
+            .account(address.to_owned(), ctx, fetcher)?
+            .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+        let signing_info = SigningInfo {
+            key,
+            sequence: account.get_sequence(),
+            account_number: account.get_account_number(),
+        };
+
+        let tx_body = TxBody {
+            messages: msgs.into_msgs(),
+            memo: ctx.memo.clone().unwrap_or_default(),
+            timeout_height: ctx.timeout_height.unwrap_or_default(),
+            extension_options: vec![],
+            non_critical_extension_options: vec![],
+        };
+
+        let tip = ctx.tip.clone().map(|amount| Tip {
+            amount: Some(amount),
+            tipper: address.clone(),
+        });
+
+        create_aux_signer_data(signing_info, ctx.chain_id.clone(), tip, tx_body)
+            .map_err(|e| anyhow!(e.to_string()))
+    }
+
+    /// Complete the tx of auxiliary signer. Signer of this tx pays fees and receives the tip.
+    fn sign_aux_as_fee_payer<
+        K: SigningKey + ReadAccAddress + GearsPublicKey,
+        F: NodeFetcher + Clone,
+    >(
+        &self,
+        aux_signer_data: AuxSignerData,
+        key: &K,
+        ctx: &mut ClientTxContext,
+        fetcher: &F,
+    ) -> anyhow::Result<Tx<Self::Message>> {
+        if aux_signer_data.sign_doc.chain_id != ctx.chain_id.as_str() {
+            Err(anyhow!(
+                "chain id of aux signer data doesn't match: expected {}, got {}",
+                ctx.chain_id,
+                aux_signer_data.sign_doc.chain_id
+            ))?
+        }
+
+        let address = key.get_address();
+
+        let account = self
+            .account(address.to_owned(), ctx, fetcher)?
+            .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+        let signing_info = SigningInfo {
+            key,
+            sequence: account.get_sequence(),
+            account_number: account.get_account_number(),
+        };
+
+        create_signed_transaction_from_aux(aux_signer_data, signing_info, ctx.fee.clone())
+            .map_err(|e| anyhow!(e.to_string()))
+    }
+
     fn handle_tx(
         &self,
         raw_tx: Tx<Self::Message>,
@@ -2,11 +2,14 @@ use std::{borrow::Cow, num::NonZero};
 
 use crate::{
     baseapp::{errors::QueryError, genesis::Genesis, QueryRequest, QueryResponse},
-    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, simple::SimpleContext,
+        tx::TxContext,
+    },
     types::tx::{raw::TxWithRaw, TxMessage},
 };
 use database::Database;
-use kv_store::StoreKey;
+use kv_store::{bank::multi::upgrades::StoreUpgrades, StoreKey};
 use tendermint::types::{
     proto::validator::ValidatorUpdate,
     request::{begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery},
@@ -15,6 +18,8 @@ use thiserror::Error;
 
 pub trait ModuleInfo: Clone + Sync + Send + 'static {
     const NAME: &'static str;
+    /// Version of the module state, increased by every change which needs a migration
+    const CONSENSUS_VERSION: u64 = 1;
 }
 
 #[derive(Error, Debug, Clone)]
@@ -47,7 +52,7 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes;
+    ) -> Result<Self::QRes, QueryError>;
 
     #[allow(unused_variables)]
     fn run_ante_checks<DB: Database>(
@@ -69,6 +74,17 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         msg: &Self::Message,
     ) -> Result<(), TxError>;
 
+    /// Runs after all messages of the tx succeeded. State changes are written in the
+    /// same cache as the messages, so a failed message or post handler discards both.
+    #[allow(unused_variables)]
+    fn run_post_handlers<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        tx: &TxWithRaw<Self::Message>,
+    ) -> Result<(), TxError> {
+        Ok(())
+    }
+
     #[allow(unused_variables)]
     fn begin_block<DB: Database>(
         &self,
@@ -97,4 +113,14 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: RequestQuery,
     ) -> Result<Vec<u8>, QueryError>;
+
+    /// Height of the scheduled upgrade with the stores it adds, renames or deletes.
+    /// They're applied when the application loads right before this height.
+    #[allow(unused_variables)]
+    fn store_upgrades<DB: Database>(
+        &self,
+        ctx: &SimpleContext<'_, DB, Self::StoreKey>,
+    ) -> Option<(u32, StoreUpgrades)> {
+        None
+    }
 }
@@ -27,7 +27,13 @@ pub trait ParamsKeeper<PSK: ParamsSubspaceKey> {
     }
 
     #[cfg(feature = "governance")]
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool;
+    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
+        <Self::Param as ParamsDeserialize>::validate_raw(
+            &String::from_utf8_lossy(key.as_ref()),
+            value.as_ref(),
+        )
+        .is_ok()
+    }
 
     fn get<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
         &self,
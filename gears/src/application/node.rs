@@ -10,6 +10,7 @@ use crate::commands::node::{
     genesis::genesis_account_add,
     init::init,
     run::{run, RouterBuilder},
+    tendermint::run_tendermint,
     AppCommands,
 };
 use crate::{
@@ -84,6 +85,7 @@ impl<
             AppCommands::GenesisAdd(cmd) => {
                 genesis_account_add::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?
             }
+            AppCommands::Tendermint(cmd) => println!("{}", run_tendermint(cmd)?),
             AppCommands::Aux(cmd) => {
                 let cmd = self.core.prepare_aux(cmd)?;
                 self.core.handle_aux(cmd)?;
@@ -233,7 +233,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
-        let height = self.get_block_header().height;
+        let header = self.get_block_header();
+        let height = header.height;
 
         let hash = state.commit(&mut multi_store);
 
@@ -243,6 +244,17 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             hex::encode(hash)
         );
 
+        // The state is persisted at this point, so tendermint replays the commit of this
+        // block on restart once the halt options are reset or moved further. The node
+        // process shuts down once it observes the signal, after this response is returned
+        if self
+            .options
+            .should_halt(height, header.time.timestamp_seconds().into())
+        {
+            info!("Halting node per configuration at height {height}");
+            self.options.halt_signal().raise();
+        }
+
         ResponseCommit {
             data: hash.to_vec().into(),
             retain_height: 0, // this is the height above which tendermint will retain all blocks // TODO: make this configurable as in Cosmos
@@ -284,6 +296,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             request.header.height,
             request.header.clone(),
             consensus_params,
+            state.deliver_mode.block_gas_meter.consumed_or_limit(),
         );
 
         self.abci_handler.begin_block(&mut ctx, request);
@@ -320,6 +333,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             header.height,
             header.clone(),
             consensus_params,
+            state.deliver_mode.block_gas_meter.consumed_or_limit(),
         );
 
         let validator_updates = self.abci_handler.end_block(&mut ctx, request);
@@ -40,7 +40,8 @@ mod params;
 mod query;
 pub mod state;
 pub use params::{
-    BaseAppParamsKeeper, BlockParams, ConsensusParams, EvidenceParams, ValidatorParams,
+    BaseAppParamsKeeper, BlockParams, ConsensusParams, EvidenceParams, GasScheduleParams,
+    ValidatorParams,
 };
 
 pub use query::*;
@@ -65,7 +66,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     ///
     /// See [BlockParams] for details on which values is used
     pub fn new(db: DB, params_subspace_key: PSK, abci_handler: H, options: NodeOptions) -> Self {
-        let multi_store = ApplicationMultiBank::new(Arc::new(db));
+        let db = Arc::new(db);
+        let multi_store = ApplicationMultiBank::new(Arc::clone(&db));
         let mut multi_store = match multi_store {
             Ok(ms) => ms,
             Err(err) => panic!("Failed to init MultiStore with err: {err}"),
@@ -76,6 +78,19 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         };
 
         let height = multi_store.head_version();
+
+        // the next block may be the height of an upgrade which changes the set of stores
+        let ctx = SimpleContext::new((&mut multi_store).into(), height, ChainId::default());
+        let store_upgrades = abci_handler.store_upgrades(&ctx);
+        if let Some((upgrade_height, upgrades)) = store_upgrades {
+            if Some(upgrade_height) == height.checked_add(1) && !upgrades.is_empty() {
+                multi_store = match ApplicationMultiBank::new_with_upgrades(db, &upgrades) {
+                    Ok(ms) => ms,
+                    Err(err) => panic!("Failed to apply store upgrades with err: {err}"),
+                };
+            }
+        }
+
         let ctx = SimpleContext::new((&mut multi_store).into(), height, ChainId::default());
 
         let max_gas = baseapp_params_keeper
@@ -141,13 +156,13 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let header = self.get_block_header();
         let height = header.height;
 
-        let consensus_params = {
-            self.baseapp_params_keeper
-                .consensus_params(&SimpleContext::new(
-                    multi_store.into(),
-                    height,
-                    header.chain_id.clone(),
-                ))
+        let (consensus_params, gas_schedule) = {
+            let ctx = SimpleContext::new(multi_store.into(), height, header.chain_id.clone());
+
+            (
+                self.baseapp_params_keeper.consensus_params(&ctx),
+                self.baseapp_params_keeper.gas_schedule(&ctx),
+            )
         };
 
         let mut ctx = TxContext::new(
@@ -155,6 +170,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             height,
             header,
             consensus_params,
+            gas_schedule,
             build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
             gas_meter,
             self.options.clone(),
@@ -168,11 +184,16 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let gas_wanted = ctx.gas_meter.borrow().limit();
         let gas_used = ctx.gas_meter.borrow().consumed_or_limit();
 
-        let events = MD::run_msg(
+        let mut events = MD::run_msg(
             &mut ctx,
             &self.abci_handler,
             tx_with_raw.tx.get_msgs().iter(),
         )?;
+        events.extend(MD::run_post_handlers(
+            &mut ctx,
+            &self.abci_handler,
+            &tx_with_raw,
+        )?);
 
         ctx.block_gas_meter
             .consume_gas(gas_used, BLOCK_GAS_DESCRIPTOR)?;
@@ -200,3 +221,236 @@ pub struct RunTxInfo {
     pub gas_wanted: Gas,
     pub gas_used: FiniteGas,
 }
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use kv_store::{
+        bank::multi::upgrades::{StoreRename, StoreUpgrades},
+        StoreKey,
+    };
+    use strum::IntoEnumIterator;
+    use tendermint::{
+        application::ABCIApplication,
+        types::{
+            proto::{consensus::ConsensusParams, validator::ValidatorUpdate},
+            request::init_chain::RequestInitChain,
+            time::timestamp::Timestamp,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        application::handlers::node::TxError, baseapp::genesis::NullGenesis,
+        context::init::InitContext, types::tx::NullTxMsg,
+    };
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params, gears)]
+    enum OldStoreKey {
+        #[skey(to_string = "params")]
+        Params,
+        #[skey(to_string = "old")]
+        Old,
+        #[skey(to_string = "gone")]
+        Gone,
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params, gears)]
+    enum NewStoreKey {
+        #[skey(to_string = "params")]
+        Params,
+        #[skey(to_string = "renamed")]
+        Renamed,
+        #[skey(to_string = "fresh")]
+        Fresh,
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[pkey(to_string = "baseapp/")]
+        BaseApp,
+    }
+
+    #[derive(Debug, Clone)]
+    struct TestApplication;
+
+    impl ApplicationInfo for TestApplication {}
+
+    /// Handler which writes the name of every store into it at genesis
+    #[derive(Debug, Clone)]
+    struct UpgradeHandler<SK> {
+        upgrade: Option<(u32, StoreUpgrades)>,
+        _marker: PhantomData<SK>,
+    }
+
+    impl<SK> UpgradeHandler<SK> {
+        fn new(upgrade: Option<(u32, StoreUpgrades)>) -> Self {
+            Self {
+                upgrade,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<SK: StoreKey> ABCIHandler for UpgradeHandler<SK> {
+        type Message = NullTxMsg;
+        type Genesis = NullGenesis;
+        type StoreKey = SK;
+        type QReq = NullQueryRequest;
+        type QRes = NullQueryResponse;
+
+        fn typed_query<DB: Database>(
+            &self,
+            _: &QueryContext<DB, Self::StoreKey>,
+            query: Self::QReq,
+        ) -> Result<Self::QRes, QueryError> {
+            match query {}
+        }
+
+        fn msg<DB: Database>(
+            &self,
+            _: &mut TxContext<'_, DB, Self::StoreKey>,
+            msg: &Self::Message,
+        ) -> Result<(), TxError> {
+            match *msg {}
+        }
+
+        fn init_genesis<DB: Database>(
+            &self,
+            ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+            _: Self::Genesis,
+        ) -> Vec<ValidatorUpdate> {
+            for sk in SK::iter() {
+                ctx.kv_store_mut(&sk)
+                    .set(sk.name().as_bytes().to_vec(), vec![1]);
+            }
+
+            Vec::new()
+        }
+
+        fn query<DB: Database + Send + Sync>(
+            &self,
+            _: &QueryContext<DB, Self::StoreKey>,
+            _: RequestQuery,
+        ) -> Result<Vec<u8>, QueryError> {
+            Err(QueryError::PathNotFound)
+        }
+
+        fn store_upgrades<DB: Database>(
+            &self,
+            _: &SimpleContext<'_, DB, Self::StoreKey>,
+        ) -> Option<(u32, StoreUpgrades)> {
+            self.upgrade.clone()
+        }
+    }
+
+    type TestApp<SK> = BaseApp<MemDB, SubspaceKey, UpgradeHandler<SK>, TestApplication>;
+
+    fn app<SK: StoreKey>(db: &MemDB, upgrade: Option<(u32, StoreUpgrades)>) -> TestApp<SK> {
+        BaseApp::new(
+            db.clone(),
+            SubspaceKey::BaseApp,
+            UpgradeHandler::new(upgrade),
+            NodeOptions::default(),
+        )
+    }
+
+    fn upgrade() -> Option<(u32, StoreUpgrades)> {
+        Some((
+            2,
+            StoreUpgrades {
+                added: vec!["fresh".to_owned()],
+                renamed: vec![StoreRename {
+                    old_key: "old".to_owned(),
+                    new_key: "renamed".to_owned(),
+                }],
+                deleted: vec!["gone".to_owned()],
+            },
+        ))
+    }
+
+    fn is_empty(db: &MemDB, prefix: &str) -> bool {
+        db.prefix_iterator(prefix.as_bytes().to_vec())
+            .next()
+            .is_none()
+    }
+
+    #[test]
+    fn new_applies_store_upgrades() {
+        let db = MemDB::new();
+
+        let app = app::<OldStoreKey>(&db, None);
+        app.init_chain(RequestInitChain {
+            time: Timestamp::UNIX_EPOCH,
+            chain_id: ChainId::default(),
+            consensus_params: ConsensusParams::default(),
+            validators: vec![],
+            app_genesis: NullGenesis(),
+            initial_height: 1,
+        });
+        let old_hash = app.commit().data;
+
+        // the upgrade height is the next block, the stores are upgraded
+        let app = app::<NewStoreKey>(&db, upgrade());
+        {
+            let multi_store = app.multi_store.read().expect(POISONED_LOCK);
+            assert_eq!(multi_store.head_version(), 1);
+            assert_eq!(multi_store.head_commit_hash().to_vec(), old_hash.to_vec());
+            assert_eq!(
+                multi_store.kv_store(&NewStoreKey::Renamed).get(b"old"),
+                Some(vec![1])
+            );
+            assert_eq!(
+                multi_store.kv_store(&NewStoreKey::Fresh).get(b"fresh"),
+                None
+            );
+            assert_eq!(
+                multi_store.kv_store(&NewStoreKey::Params).get(b"params"),
+                Some(vec![1])
+            );
+        }
+        // the data of renamed and deleted stores is only moved by the commit
+        assert!(!is_empty(&db, "old"));
+        assert!(!is_empty(&db, "gone"));
+        assert!(is_empty(&db, "renamed"));
+
+        let new_hash = app.commit().data;
+        assert_ne!(new_hash, old_hash);
+        assert!(is_empty(&db, "old"));
+        assert!(is_empty(&db, "gone"));
+        assert!(!is_empty(&db, "renamed"));
+
+        // the upgrade height is in the past, the stores are loaded as they are
+        let app = app::<NewStoreKey>(&db, upgrade());
+        let multi_store = app.multi_store.read().expect(POISONED_LOCK);
+        assert_eq!(multi_store.head_version(), 2);
+        assert_eq!(multi_store.head_commit_hash().to_vec(), new_hash.to_vec());
+        assert_eq!(
+            multi_store.kv_store(&NewStoreKey::Renamed).get(b"old"),
+            Some(vec![1])
+        );
+        assert_eq!(
+            multi_store.kv_store(&NewStoreKey::Fresh).get(b"fresh"),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to apply store upgrades")]
+    fn new_rejects_invalid_store_upgrades() {
+        let db = MemDB::new();
+
+        let app = app::<OldStoreKey>(&db, None);
+        app.commit();
+
+        let upgrade = StoreUpgrades {
+            added: vec!["unknown".to_owned()],
+            ..Default::default()
+        };
+        app::<NewStoreKey>(&db, Some((2, upgrade)));
+    }
+}
@@ -46,6 +46,19 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for CheckTxMode<DB, AH
         Ok(ctx.events_drain())
     }
 
+    fn run_post_handlers(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<Vec<Event>, RunTxError> {
+        // Messages aren't executed, but post handlers still update the check state like the sdk does
+        handler
+            .run_post_handlers(ctx, tx_with_raw)
+            .inspect_err(|_| ctx.multi_store_mut().clear_cache())?;
+
+        Ok(ctx.events_drain())
+    }
+
     fn run_ante_checks(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
         handler: &AH,
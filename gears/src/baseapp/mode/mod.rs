@@ -31,6 +31,12 @@ pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
         handler: &AH,
         msgs: impl Iterator<Item = &'m AH::Message>,
     ) -> Result<Vec<Event>, RunTxError>;
+
+    fn run_post_handlers(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<Vec<Event>, RunTxError>;
 }
 
 mod sealed {
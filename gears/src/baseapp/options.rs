@@ -1,4 +1,7 @@
-use std::sync::{Arc, RwLock};
+use std::{
+    sync::{Arc, Condvar, Mutex, RwLock},
+    time::Duration,
+};
 
 use crate::{error::POISONED_LOCK, types::base::min_gas::MinGasPrices};
 
@@ -8,11 +11,61 @@ pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
 #[derive(Debug, Default)]
 struct InnerOptions {
     pub min_gas_prices: MinGasPrices,
+    pub halt_height: Option<u32>,
+    pub halt_time: Option<u64>,
+    pub halt_signal: HaltSignal,
+}
+
+/// Raised once the node committed the block it was configured to halt at, the node
+/// process is expected to shut down when it observes the signal
+#[derive(Debug, Clone, Default)]
+pub struct HaltSignal(Arc<(Mutex<bool>, Condvar)>);
+
+impl HaltSignal {
+    pub fn raise(&self) {
+        let (raised, condvar) = &*self.0;
+        *raised.lock().expect(POISONED_LOCK) = true;
+        condvar.notify_all();
+    }
+
+    pub fn is_raised(&self) -> bool {
+        *self.0 .0.lock().expect(POISONED_LOCK)
+    }
+
+    /// Blocks until the signal is raised or `timeout` elapses. Returns whether the signal
+    /// was raised
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let (raised, condvar) = &*self.0;
+        let (raised, _) = condvar
+            .wait_timeout_while(raised.lock().expect(POISONED_LOCK), timeout, |raised| {
+                !*raised
+            })
+            .expect(POISONED_LOCK);
+
+        *raised
+    }
 }
 
 impl NodeOptions {
     pub fn new(min_gas_prices: MinGasPrices) -> Self {
-        Self(Arc::new(RwLock::new(InnerOptions { min_gas_prices })))
+        Self(Arc::new(RwLock::new(InnerOptions {
+            min_gas_prices,
+            halt_height: None,
+            halt_time: None,
+            halt_signal: HaltSignal::default(),
+        })))
+    }
+
+    /// Set the block height and the block time (seconds since unix epoch) after which the
+    /// node stops
+    pub fn with_halt(self, halt_height: Option<u32>, halt_time: Option<u64>) -> Self {
+        {
+            let mut inner = self.0.write().expect(POISONED_LOCK);
+            inner.halt_height = halt_height;
+            inner.halt_time = halt_time;
+        }
+
+        self
     }
 
     pub fn min_gas_prices(&self) -> MinGasPrices {
@@ -22,4 +75,61 @@ impl NodeOptions {
             .min_gas_prices
             .to_owned()
     }
+
+    pub fn halt_signal(&self) -> HaltSignal {
+        self.0.read().expect(POISONED_LOCK).halt_signal.clone()
+    }
+
+    /// Checks whether the node should stop after committing the block with `height` and
+    /// `time` in seconds since unix epoch
+    pub fn should_halt(&self, height: u32, time: i64) -> bool {
+        let inner = self.0.read().expect(POISONED_LOCK);
+
+        let halt_height = inner
+            .halt_height
+            .map(|halt_height| halt_height > 0 && height >= halt_height)
+            .unwrap_or_default();
+        let halt_time = inner
+            .halt_time
+            .map(|halt_time| halt_time > 0 && time >= 0 && time as u64 >= halt_time)
+            .unwrap_or_default();
+
+        halt_height || halt_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_halt_works() {
+        let options = NodeOptions::default();
+        assert!(!options.should_halt(100, 100));
+
+        let options = NodeOptions::default().with_halt(Some(10), None);
+        assert!(!options.should_halt(9, 100));
+        assert!(options.should_halt(10, 100));
+
+        let options = NodeOptions::default().with_halt(None, Some(1_000));
+        assert!(!options.should_halt(100, 999));
+        assert!(options.should_halt(100, 1_000));
+
+        let options = NodeOptions::default().with_halt(Some(0), Some(0));
+        assert!(!options.should_halt(100, 1_000));
+    }
+
+    #[test]
+    fn halt_signal_is_shared_between_clones() {
+        let options = NodeOptions::default();
+        let signal = options.clone().halt_signal();
+        assert!(!signal.is_raised());
+        assert!(!signal.wait_timeout(Duration::from_millis(1)));
+
+        let waiter = std::thread::spawn(move || signal.wait_timeout(Duration::from_secs(10)));
+        options.halt_signal().raise();
+
+        assert!(waiter.join().expect("waiter thread panicked"));
+        assert!(options.halt_signal().is_raised());
+    }
 }
@@ -1,5 +1,6 @@
 use database::Database;
 use extensions::corruption::UnwrapCorrupt;
+use gas::metering::config::GasConfig;
 use kv_store::StoreKey;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -16,7 +17,7 @@ use crate::{
     context::{InfallibleContext, InfallibleContextMut},
     params::{
         infallible_subspace, infallible_subspace_mut, ParamKind, ParamsDeserialize,
-        ParamsSerialize, ParamsSubspaceKey,
+        ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
     },
 };
 
@@ -30,6 +31,8 @@ mod inner {
 const KEY_BLOCK_PARAMS: &str = "BlockParams";
 const KEY_EVIDENCE_PARAMS: &str = "EvidenceParams";
 const KEY_VALIDATOR_PARAMS: &str = "ValidatorParams";
+const KEY_KV_GAS_CONFIG: &str = "KVGasConfig";
+const KEY_TRANSIENT_GAS_CONFIG: &str = "TransientGasConfig";
 
 const _SUBSPACE_NAME: &str = "baseapp/";
 
@@ -105,6 +108,51 @@ impl ParamsDeserialize for ConsensusParams {
                 .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let invalid = |reason: &str| Err(ParamsValidationError::invalid_value(key, reason));
+
+        // same checks as in cosmos sdk `x/params/types/consensus_params.go`
+        match key {
+            KEY_BLOCK_PARAMS => {
+                let params = serde_json::from_slice::<BlockParams>(value)
+                    .map_err(|e| ParamsValidationError::invalid_value(key, e))?;
+
+                if params.max_bytes <= 0 {
+                    return invalid("block maximum bytes must be positive");
+                }
+                if params.max_gas < -1 {
+                    return invalid("block maximum gas must be greater than or equal to -1");
+                }
+            }
+            KEY_EVIDENCE_PARAMS => {
+                let params = serde_json::from_slice::<EvidenceParams>(value)
+                    .map_err(|e| ParamsValidationError::invalid_value(key, e))?;
+
+                if params.max_age_num_blocks <= 0 {
+                    return invalid("evidence maximum age in blocks must be positive");
+                }
+                match params.max_age_duration {
+                    Some(duration) if duration > Duration::ZERO => (),
+                    _ => return invalid("evidence maximum age time duration must be positive"),
+                }
+                if params.max_bytes < 0 {
+                    return invalid("evidence maximum bytes must be non-negative");
+                }
+            }
+            KEY_VALIDATOR_PARAMS => {
+                let params = serde_json::from_slice::<ValidatorParams>(value)
+                    .map_err(|e| ParamsValidationError::invalid_value(key, e))?;
+
+                if params.pub_key_types.is_empty() {
+                    return invalid("validator allowed pubkey types must not be empty");
+                }
+            }
+            _ => return Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+
+        Ok(())
+    }
 }
 
 #[serde_as]
@@ -192,6 +240,69 @@ impl From<inner::EvidenceParams> for EvidenceParams {
     }
 }
 
+/// Gas schedule of store operations. Shares subspace with [ConsensusParams], but isn't a part of
+/// them as tendermint knows nothing about it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GasScheduleParams {
+    /// Costs of persistent kv stores
+    pub kv: GasConfig,
+    /// Costs of transient stores, which are discarded on commit
+    pub transient: GasConfig,
+}
+
+impl Default for GasScheduleParams {
+    fn default() -> Self {
+        Self {
+            kv: GasConfig::default_kv(),
+            transient: GasConfig::default_transient(),
+        }
+    }
+}
+
+impl ParamsSerialize for GasScheduleParams {
+    fn keys() -> HashSet<&'static str> {
+        [KEY_KV_GAS_CONFIG, KEY_TRANSIENT_GAS_CONFIG]
+            .into_iter()
+            .collect()
+    }
+
+    fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+        let kv = serde_json::to_string(&self.kv).expect("conversion to json won't fail");
+        let transient =
+            serde_json::to_string(&self.transient).expect("conversion to json won't fail");
+
+        vec![
+            (KEY_KV_GAS_CONFIG, kv.into_bytes()),
+            (KEY_TRANSIENT_GAS_CONFIG, transient.into_bytes()),
+        ]
+    }
+}
+
+impl ParamsDeserialize for GasScheduleParams {
+    fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        Self {
+            kv: serde_json::from_slice(fields.get(KEY_KV_GAS_CONFIG).unwrap_or_corrupt())
+                .unwrap_or_corrupt(),
+            transient: serde_json::from_slice(
+                fields.get(KEY_TRANSIENT_GAS_CONFIG).unwrap_or_corrupt(),
+            )
+            .unwrap_or_corrupt(),
+        }
+    }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match key {
+            KEY_KV_GAS_CONFIG | KEY_TRANSIENT_GAS_CONFIG => {
+                serde_json::from_slice::<GasConfig>(value)
+                    .map_err(|e| ParamsValidationError::invalid_value(key, e))?
+                    .validate()
+                    .map_err(|e| ParamsValidationError::invalid_value(key, e))
+            }
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BaseAppParamsKeeper<PSK: ParamsSubspaceKey> {
     pub params_subspace_key: PSK,
@@ -203,18 +314,6 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BaseAppParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    #[cfg(feature = "governance")]
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_BLOCK_PARAMS => serde_json::from_slice::<BlockParams>(value.as_ref()).is_ok(),
-            KEY_EVIDENCE_PARAMS => serde_json::from_slice::<EvidenceParams>(value.as_ref()).is_ok(),
-            KEY_VALIDATOR_PARAMS => {
-                serde_json::from_slice::<ValidatorParams>(value.as_ref()).is_ok()
-            }
-            _ => false,
-        }
-    }
 }
 
 impl<PSK: ParamsSubspaceKey> BaseAppParamsKeeper<PSK> {
@@ -258,6 +357,38 @@ impl<PSK: ParamsSubspaceKey> BaseAppParamsKeeper<PSK> {
         }
     }
 
+    pub fn set_gas_schedule<DB: Database, SK: StoreKey, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        params: GasScheduleParams,
+    ) {
+        let mut store = infallible_subspace_mut(ctx, &self.params_subspace_key);
+
+        store.params_set(&params);
+    }
+
+    /// Returns gas schedule from store. Defaults are used for unset values, so chains
+    /// started before the schedule became a param don't need a migration.
+    pub fn gas_schedule<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        store: &CTX,
+    ) -> GasScheduleParams {
+        let sub_store = infallible_subspace(store, &self.params_subspace_key);
+
+        let gas_config = |key: &str| {
+            sub_store.params_field(key, ParamKind::Bytes).map(|params| {
+                serde_json::from_slice(&params.bytes().expect("We sure that this is bytes"))
+                    .unwrap_or_corrupt()
+            })
+        };
+
+        GasScheduleParams {
+            kv: gas_config(KEY_KV_GAS_CONFIG).unwrap_or_else(GasConfig::default_kv),
+            transient: gas_config(KEY_TRANSIENT_GAS_CONFIG)
+                .unwrap_or_else(GasConfig::default_transient),
+        }
+    }
+
     pub fn block_params<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
         &self,
         store: &CTX,
@@ -372,4 +503,98 @@ mod tests {
 
         assert_eq!(ConsensusParams::default(), params);
     }
+
+    #[test]
+    fn gas_schedule_defaults_when_unset() {
+        let keeper = BaseAppParamsKeeper {
+            params_subspace_key: SubspaceKey::Params,
+        };
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            tendermint::types::time::timestamp::Timestamp::UNIX_EPOCH,
+            tendermint::types::chain_id::ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        assert_eq!(GasScheduleParams::default(), keeper.gas_schedule(&ctx));
+
+        let mut params = GasScheduleParams::default();
+        params.kv.write_cost_per_byte = gas::metering::FiniteGas::from(50_u8);
+        keeper.set_gas_schedule(&mut ctx, params);
+
+        assert_eq!(params, keeper.gas_schedule(&ctx));
+        assert_eq!(
+            ConsensusParams::default(),
+            keeper.consensus_params(&ctx),
+            "gas schedule shouldn't affect consensus params"
+        );
+    }
+
+    #[test]
+    fn gas_schedule_validate_raw() {
+        let raw = GasScheduleParams::default().to_raw();
+
+        for (key, value) in raw {
+            assert_eq!(Ok(()), GasScheduleParams::validate_raw(key, &value));
+        }
+
+        assert!(GasScheduleParams::validate_raw(KEY_KV_GAS_CONFIG, b"{}").is_err());
+        assert!(GasScheduleParams::validate_raw("UnknownKey", b"{}").is_err());
+
+        let free_writes = GasConfig {
+            write_cost_flat: gas::metering::FiniteGas::ZERO,
+            ..GasConfig::default_kv()
+        };
+        let absurd_reads = GasConfig {
+            read_cost_per_byte: gas::metering::FiniteGas::from(u32::MAX),
+            ..GasConfig::default_transient()
+        };
+        for config in [free_writes, absurd_reads] {
+            let raw = serde_json::to_vec(&config).expect("conversion to json won't fail");
+
+            assert!(matches!(
+                GasScheduleParams::validate_raw(KEY_KV_GAS_CONFIG, &raw),
+                Err(ParamsValidationError::InvalidValue { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn consensus_params_validate_raw() {
+        for (key, value) in ConsensusParams::default().to_raw() {
+            assert_eq!(Ok(()), ConsensusParams::validate_raw(key, &value));
+        }
+
+        let invalid: [(&str, &[u8]); 5] = [
+            (KEY_BLOCK_PARAMS, br#"{"max_bytes":"0","max_gas":"-1"}"#),
+            (
+                KEY_BLOCK_PARAMS,
+                br#"{"max_bytes":"200000","max_gas":"-2"}"#,
+            ),
+            (
+                KEY_EVIDENCE_PARAMS,
+                br#"{"max_age_num_blocks":"0","max_age_duration":"1000","max_bytes":"0"}"#,
+            ),
+            (
+                KEY_EVIDENCE_PARAMS,
+                br#"{"max_age_num_blocks":"1","max_age_duration":"0","max_bytes":"0"}"#,
+            ),
+            (KEY_VALIDATOR_PARAMS, br#"{"pub_key_types":[]}"#),
+        ];
+        for (key, value) in invalid {
+            assert!(
+                matches!(
+                    ConsensusParams::validate_raw(key, value),
+                    Err(ParamsValidationError::InvalidValue { .. })
+                ),
+                "{key}: {}",
+                String::from_utf8_lossy(value)
+            );
+        }
+    }
 }
@@ -35,6 +35,9 @@ pub trait QueryResponse: Clone + Send + Sync + 'static + Serialize {
 
 pub trait NodeQueryHandler<QReq, QRes>: Clone + Send + Sync + 'static {
     fn typed_query<Q: Into<QReq>>(&self, request: Q) -> Result<QRes, QueryError>;
+
+    /// Queries the state at the given height, `0` stands for the latest height
+    fn typed_query_at<Q: Into<QReq>>(&self, request: Q, height: u32) -> Result<QRes, QueryError>;
 }
 
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
@@ -44,12 +47,22 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let request = request.into();
         let version = request.height();
 
+        self.typed_query_at(request, version)
+    }
+
+    fn typed_query_at<Q: Into<H::QReq>>(
+        &self,
+        request: Q,
+        version: u32,
+    ) -> Result<H::QRes, QueryError> {
+        let request = request.into();
+
         let store = self.multi_store.read().expect(POISONED_LOCK);
         let ctx = QueryContext::new(
             QueryMultiStore::new(&*store, NonZero::new(version))?,
             version,
         )?;
-        Ok(self.abci_handler.typed_query(&ctx, request))
+        self.abci_handler.typed_query(&ctx, request)
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    commands::client::keys::KeyringBackend,
+    commands::{client::keys::KeyringBackend, output::OutputFormat},
     config::{ConfigDirectory, DEFAULT_TENDERMINT_RPC_ADDRESS},
 };
 use serde::{Deserialize, Serialize};
@@ -14,8 +14,6 @@ pub fn client_config(home: &PathBuf) -> &'static ClientConfig {
 
 pub const CHAIN_ID: &str = "test-chain";
 // TODO: make it working and add enum
-pub const OUTPUT: &str = "json";
-// TODO: make it working and add enum
 pub const BROADCAST_MODE: &str = "sync";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -35,7 +33,7 @@ pub struct ClientConfigRaw {
 pub struct ClientConfig {
     chain_id: ChainId,
     keyring_backend: KeyringBackend,
-    output: String,
+    output: OutputFormat,
     node: url::Url,
     broadcast_mode: String,
 }
@@ -45,7 +43,7 @@ impl Default for ClientConfig {
         Self {
             chain_id: ChainId::from_str(CHAIN_ID).expect("hardcoded value cannot fail"),
             keyring_backend: KeyringBackend::default(),
-            output: OUTPUT.to_string(),
+            output: OutputFormat::default(),
             node: DEFAULT_TENDERMINT_RPC_ADDRESS
                 .parse()
                 .expect("hardcoded value cannot fail"),
@@ -69,7 +67,7 @@ impl TryFrom<ClientConfigRaw> for ClientConfig {
         Ok(Self {
             chain_id: chain_id.try_into()?,
             keyring_backend,
-            output,
+            output: output.parse()?,
             node: node.parse()?,
             broadcast_mode,
         })
@@ -121,6 +119,10 @@ impl ClientConfig {
     pub fn keyring_backend(&self) -> KeyringBackend {
         self.keyring_backend.clone()
     }
+
+    pub fn output(&self) -> OutputFormat {
+        self.output
+    }
 }
 
 const CONFIG_TEMPLATE: &str = r#"# This is a TOML config file.
@@ -136,7 +138,7 @@ chain-id = "{{chain_id.id}}"
 # Name of keyring backend to read private keys
 keyring-backend = "{{keyring_backend}}"
 
-# Format of output: json, toml or text
+# Format of output: json or text
 output = "{{output}}"
 
 # Address of tendermint node
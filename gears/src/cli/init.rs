@@ -1,7 +1,7 @@
 use std::{marker::PhantomData, path::PathBuf, str::FromStr};
 
 use clap::{ArgAction, ValueHint};
-use tendermint::types::chain_id::ChainId;
+use tendermint::{privval::Address, types::chain_id::ChainId};
 
 use crate::{application::ApplicationInfo, commands::node::init::InitCommand};
 
@@ -14,6 +14,9 @@ pub struct CliInitCommand<T: ApplicationInfo> {
     pub moniker: String,
     #[arg(long =  "chain-id",  action = ArgAction::Set, default_value_t = ChainId::from_str( "test-chain" ).expect("unrechable: default should be valid"), help = "genesis file chain-id",)]
     pub chain_id: ChainId,
+    /// Socket address to listen on for connections from external priv_validator process (e.g. unix:///path/to/privval.sock). Only unix sockets are supported
+    #[arg(long, value_parser = tendermint::privval::parse_laddr)]
+    pub priv_validator_laddr: Option<Address>,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -25,6 +28,7 @@ impl<T: ApplicationInfo> From<CliInitCommand<T>> for InitCommand {
             home,
             moniker,
             chain_id,
+            priv_validator_laddr,
             _marker,
         } = value;
 
@@ -32,6 +36,7 @@ impl<T: ApplicationInfo> From<CliInitCommand<T>> for InitCommand {
             home,
             moniker,
             chain_id,
+            priv_validator_laddr,
         }
     }
 }
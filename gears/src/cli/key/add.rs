@@ -1,6 +1,7 @@
 use std::{marker::PhantomData, path::PathBuf};
 
 use clap::{ArgAction, ValueHint};
+use keyring::key::hd_path::{HdPath, ATOM_COIN_TYPE};
 
 use crate::{
     application::ApplicationInfo,
@@ -22,6 +23,21 @@ pub struct CliAddKeyCommand<T: ApplicationInfo> {
     /// select keyring's backend
     #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
     keyring_backend: KeyringBackend,
+    /// Interactively prompt user for BIP39 passphrase
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    interactive: bool,
+    /// Account number for HD derivation (less than equal 2147483647)
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u32).range(..=i32::MAX as i64))]
+    account: u32,
+    /// Address index number for HD derivation (less than equal 2147483647)
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u32).range(..=i32::MAX as i64))]
+    index: u32,
+    /// coin type number for HD derivation
+    #[arg(long = "coin-type", default_value_t = ATOM_COIN_TYPE)]
+    coin_type: u32,
+    /// Manual HD Path derivation (overrides BIP44 config)
+    #[arg(long = "hd-path", conflicts_with_all = ["account", "index", "coin_type"])]
+    hd_path: Option<HdPath>,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -34,6 +50,11 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             recover,
             home,
             keyring_backend,
+            interactive,
+            account,
+            index,
+            coin_type,
+            hd_path,
             _marker,
         } = value;
 
@@ -43,6 +64,9 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             home,
             keyring_backend,
             bip39_mnemonic: None,
+            bip39_passphrase: None,
+            interactive,
+            hd_path: hd_path.unwrap_or_else(|| HdPath::new(coin_type, account, index)),
         }
     }
 }
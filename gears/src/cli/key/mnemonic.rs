@@ -0,0 +1,19 @@
+use clap::ArgAction;
+
+use crate::commands::client::keys::MnemonicCommand;
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(
+    about = "Create a bip39 mnemonic, sometimes called a seed phrase, by reading from the system entropy. To pass your own entropy, use --unsafe-entropy"
+)]
+pub struct CliMnemonicCommand {
+    /// Prompt the user to supply their own entropy, instead of relying on the system
+    #[arg(long = "unsafe-entropy", action = ArgAction::SetTrue)]
+    unsafe_entropy: bool,
+}
+
+impl From<CliMnemonicCommand> for MnemonicCommand {
+    fn from(CliMnemonicCommand { unsafe_entropy }: CliMnemonicCommand) -> Self {
+        Self { unsafe_entropy }
+    }
+}
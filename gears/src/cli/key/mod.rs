@@ -1,19 +1,22 @@
 use crate::{application::ApplicationInfo, commands::client::keys::KeyCommand};
 
-use self::add::CliAddKeyCommand;
+use self::{add::CliAddKeyCommand, mnemonic::CliMnemonicCommand};
 
 pub mod add;
+pub mod mnemonic;
 
 #[derive(Debug, Clone, ::clap::Subcommand)]
 #[command(about = "Manage your application's keys")]
 pub enum CliKeyCommand<T: ApplicationInfo> {
     Add(CliAddKeyCommand<T>),
+    Mnemonic(CliMnemonicCommand),
 }
 
 impl<T: ApplicationInfo> From<CliKeyCommand<T>> for KeyCommand {
     fn from(value: CliKeyCommand<T>) -> Self {
         match value {
             CliKeyCommand::Add(cmd) => KeyCommand::Add(cmd.into()),
+            CliKeyCommand::Mnemonic(cmd) => KeyCommand::Mnemonic(cmd.into()),
         }
     }
 }
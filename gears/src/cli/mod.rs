@@ -14,8 +14,11 @@ use self::{
     init::CliInitCommand,
     key::CliKeyCommand,
     query::CliQueryCommand,
+    query_block::{CliQueryBlockCommand, CliQueryBlockResultsCommand, CliQueryBlocksCommand},
     query_txs::{CliQueryTxCommand, CliQueryTxsCommand},
     run::CliRunCommand,
+    status::CliStatusCommand,
+    tendermint::CliTendermintCommand,
     tx::CliTxCommand,
 };
 
@@ -26,8 +29,11 @@ pub mod init;
 pub mod key;
 pub mod pagination;
 pub mod query;
+pub mod query_block;
 pub mod query_txs;
 pub mod run;
+pub mod status;
+pub mod tendermint;
 pub mod tx;
 
 fn write_completions<G: Generator>(gen: G, cmd: &mut Command, buf: &mut dyn Write) {
@@ -169,9 +175,15 @@ where
     #[command(flatten)]
     Aux(CliAUX),
     Tx(CliTxCommand<T, CliTX>),
+    /// Complete a tx signed by auxiliary signer (e.g. a tipper) and broadcast it as fee payer
+    AuxToFee(CliTxCommand<T, tx::AuxToFeeCli>),
     Query(CliQueryCommand<T, CliQue>),
     QueryTx(CliQueryTxCommand<T>),
     QueryTxs(CliQueryTxsCommand<T>),
+    QueryBlock(CliQueryBlockCommand<T>),
+    QueryBlocks(CliQueryBlocksCommand<T>),
+    QueryBlockResults(CliQueryBlockResultsCommand<T>),
+    Status(CliStatusCommand<T>),
     #[command(subcommand)]
     Keys(CliKeyCommand<T>),
 }
@@ -192,9 +204,14 @@ where
         let res = match value {
             CliClientCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
             CliClientCommands::Tx(cmd) => Self::Tx(cmd.try_into()?),
+            CliClientCommands::AuxToFee(cmd) => Self::AuxToFee(cmd.try_into()?),
             CliClientCommands::Query(cmd) => Self::Query(cmd.try_into()?),
             CliClientCommands::QueryTx(cmd) => Self::QueryTx(cmd.into()),
             CliClientCommands::QueryTxs(cmd) => Self::QueryTxs(cmd.into()),
+            CliClientCommands::QueryBlock(cmd) => Self::QueryBlock(cmd.into()),
+            CliClientCommands::QueryBlocks(cmd) => Self::QueryBlocks(cmd.into()),
+            CliClientCommands::QueryBlockResults(cmd) => Self::QueryBlockResults(cmd.into()),
+            CliClientCommands::Status(cmd) => Self::Status(cmd.into()),
             CliClientCommands::Keys(cmd) => Self::Keys(cmd.into()),
         };
 
@@ -208,6 +225,7 @@ pub enum CliAppCommands<T: ApplicationInfo, CliAUX: Subcommand> {
     Run(CliRunCommand<T>),
     #[command(name = "add-genesis-account")]
     GenesisAdd(CliGenesisCommand<T>),
+    Tendermint(CliTendermintCommand<T>),
     #[command(flatten)]
     Aux(CliAUX),
 }
@@ -225,6 +243,7 @@ where
             CliAppCommands::Init(cmd) => Self::Init(cmd.into()),
             CliAppCommands::Run(cmd) => Self::Run(cmd.into()),
             CliAppCommands::GenesisAdd(cmd) => Self::GenesisAdd(cmd.into()),
+            CliAppCommands::Tendermint(cmd) => Self::Tendermint(cmd.into()),
             CliAppCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
         };
 
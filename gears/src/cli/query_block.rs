@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+
+use clap::{ArgAction, ValueHint};
+use tendermint::types::proto::block::Height;
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::{
+        client::block::{BlockCommand, BlockResultsCommand, BlocksCommand},
+        output::OutputFormat,
+    },
+};
+
+/// Query for a committed block by height, or the latest block if the height isn't given
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliQueryBlockCommand<T: ApplicationInfo> {
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    pub node: url::Url,
+    /// Output format
+    #[arg(long, short, default_value_t = client_config(&T::home_dir()).output())]
+    pub output: OutputFormat,
+
+    pub height: Option<Height>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliQueryBlockCommand<T>> for BlockCommand {
+    fn from(value: CliQueryBlockCommand<T>) -> Self {
+        let CliQueryBlockCommand {
+            node,
+            output,
+            height,
+            ..
+        } = value;
+
+        Self {
+            node,
+            height,
+            output,
+        }
+    }
+}
+
+/// Query for paginated blocks that match a set of events, e.g. "block.height > 5"
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliQueryBlocksCommand<T: ApplicationInfo> {
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    pub node: url::Url,
+    /// Output format
+    #[arg(long, short, default_value_t = client_config(&T::home_dir()).output())]
+    pub output: OutputFormat,
+
+    #[arg(long)]
+    pub query: String,
+    #[arg(long, default_value_t = 1)]
+    pub page: u32,
+    #[arg(long, default_value_t = 30)]
+    pub limit: u8,
+    /// Order of the blocks: asc or desc
+    #[arg(long, default_value = "asc")]
+    pub order_by: String,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliQueryBlocksCommand<T>> for BlocksCommand {
+    fn from(value: CliQueryBlocksCommand<T>) -> Self {
+        let CliQueryBlocksCommand {
+            node,
+            output,
+            query,
+            page,
+            limit,
+            order_by,
+            ..
+        } = value;
+
+        Self {
+            node,
+            query,
+            page,
+            limit,
+            order_by,
+            output,
+        }
+    }
+}
+
+/// Query for the results of a committed block by height, or of the latest block if the height
+/// isn't given
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliQueryBlockResultsCommand<T: ApplicationInfo> {
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    pub node: url::Url,
+    /// Output format
+    #[arg(long, short, default_value_t = client_config(&T::home_dir()).output())]
+    pub output: OutputFormat,
+
+    pub height: Option<Height>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliQueryBlockResultsCommand<T>> for BlockResultsCommand {
+    fn from(value: CliQueryBlockResultsCommand<T>) -> Self {
+        let CliQueryBlockResultsCommand {
+            node,
+            output,
+            height,
+            ..
+        } = value;
+
+        Self {
+            node,
+            height,
+            output,
+        }
+    }
+}
@@ -33,6 +33,12 @@ pub struct CliRunCommand<T: ApplicationInfo> {
     /// Minimum gas prices to accept for transactions; Any fee in a tx must meet this minimum (e.g. 0.01photino,0.0001stake)
     #[arg(long, action = ArgAction::Set)]
     pub min_gas_prices: Option<MinGasPrices>,
+    /// Block height at which to gracefully halt the node and shutdown the application
+    #[arg(long, action = ArgAction::Set)]
+    pub halt_height: Option<u32>,
+    /// Minimum block time (in Unix seconds) at which to gracefully halt the node and shutdown the application
+    #[arg(long, action = ArgAction::Set)]
+    pub halt_time: Option<u64>,
 
     #[arg(skip)]
     pub _marker: PhantomData<T>,
@@ -50,6 +56,8 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             min_gas_prices,
             grpc_listen_addr,
             rpc_addr,
+            halt_height,
+            halt_time,
         }: CliRunCommand<T>,
     ) -> Self {
         Self {
@@ -61,6 +69,8 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             log_level,
             min_gas_prices,
             tendermint_rpc_addr: rpc_addr,
+            halt_height,
+            halt_time,
         }
     }
 }
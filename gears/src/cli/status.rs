@@ -0,0 +1,31 @@
+use std::marker::PhantomData;
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::{client::status::StatusCommand, output::OutputFormat},
+};
+
+/// Query remote node for status
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliStatusCommand<T: ApplicationInfo> {
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    pub node: url::Url,
+    /// Output format
+    #[arg(long, short, default_value_t = client_config(&T::home_dir()).output())]
+    pub output: OutputFormat,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliStatusCommand<T>> for StatusCommand {
+    fn from(value: CliStatusCommand<T>) -> Self {
+        let CliStatusCommand { node, output, .. } = value;
+
+        Self { node, output }
+    }
+}
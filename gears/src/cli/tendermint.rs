@@ -0,0 +1,45 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    commands::node::tendermint::{TendermintCommand, TendermintCommandKind},
+};
+
+/// Tendermint subcommands
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliTendermintCommand<T: ApplicationInfo> {
+    #[arg(long, global = true, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+
+    #[command(subcommand)]
+    command: CliTendermintCommandKind,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+#[derive(Debug, Clone, ::clap::Subcommand)]
+pub enum CliTendermintCommandKind {
+    /// Show this node's ID
+    ShowNodeId,
+    /// Show this node's tendermint validator info
+    ShowValidator,
+    /// Shows this node's tendermint validator consensus address
+    ShowAddress,
+}
+
+impl<T: ApplicationInfo> From<CliTendermintCommand<T>> for TendermintCommand {
+    fn from(value: CliTendermintCommand<T>) -> Self {
+        let CliTendermintCommand { home, command, .. } = value;
+
+        let kind = match command {
+            CliTendermintCommandKind::ShowNodeId => TendermintCommandKind::ShowNodeId,
+            CliTendermintCommandKind::ShowValidator => TendermintCommandKind::ShowValidator,
+            CliTendermintCommandKind::ShowAddress => TendermintCommandKind::ShowAddress,
+        };
+
+        Self { home, kind }
+    }
+}
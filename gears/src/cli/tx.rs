@@ -2,6 +2,7 @@ use std::{marker::PhantomData, path::PathBuf};
 
 use address::AccAddress;
 use clap::{ArgAction, Args, Subcommand, ValueEnum, ValueHint};
+use core_types::tx::mode_info::SignMode;
 use strum::Display;
 use tendermint::types::chain_id::ChainId;
 
@@ -10,7 +11,10 @@ use crate::{
     cli::config::client_config,
     commands::client::{
         keys::KeyringBackend,
-        tx::{AccountProvider, ClientTxContext, Keyring as TxKeyring, LocalInfo, TxCommand},
+        tx::{
+            AccountProvider, AuxToFeeCommand, ClientTxContext, Keyring as TxKeyring, LocalInfo,
+            TxCommand,
+        },
     },
     types::{auth::fee::Fee, base::coins::UnsignedCoins},
 };
@@ -35,6 +39,10 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[arg(long, short, default_value_t = Keyring::Local)]
     pub keyring: Keyring,
 
+    /// Choose sign mode which is used to sign the transaction
+    #[arg(long = "sign-mode", global = true, default_value_t = SignModeCli::Direct)]
+    pub sign_mode: SignModeCli,
+
     #[command(flatten)]
     #[group(id = "local", conflicts_with = Keyring::Ledger, global = true)]
     pub local: Option<Local<T>>,
@@ -51,6 +59,14 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[arg(long, global = true, action = ArgAction::Set, required = false )]
     pub timeout_height: Option<u32>,
 
+    /// Tip is the amount that is going to be transferred to the fee payer on the target chain; eg: 10uatom
+    #[arg(long, global = true, action = ArgAction::Set, required = false )]
+    pub tip: Option<UnsignedCoins>,
+
+    /// Generate aux signer data instead of sending a tx. The tx should be completed by fee payer with `aux-to-fee` command
+    #[arg(long, global = true, default_value_t = false)]
+    pub aux: bool,
+
     #[command(flatten)]
     pub command: C,
 
@@ -104,6 +120,22 @@ impl TryFrom<FeeCli> for Fee {
     }
 }
 
+/// Complete a tx signed by auxiliary signer, pay fees for it and broadcast it
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct AuxToFeeCli {
+    /// Path to json file with aux signer data
+    #[arg(required = true, value_hint = ValueHint::FilePath)]
+    pub aux_signer_data: PathBuf,
+}
+
+impl TryFrom<AuxToFeeCli> for AuxToFeeCommand {
+    type Error = anyhow::Error;
+
+    fn try_from(AuxToFeeCli { aux_signer_data }: AuxToFeeCli) -> Result<Self, Self::Error> {
+        Ok(Self { aux_signer_data })
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Mode {
     /// makes sure that the client will not reach out to full node.
@@ -130,6 +162,29 @@ pub enum Keyring {
     Local,
 }
 
+#[derive(ValueEnum, Debug, Clone, Display)]
+pub enum SignModeCli {
+    /// Sign the protobuf encoded transaction
+    #[strum(to_string = "direct")]
+    Direct,
+    /// Sign human-readable screens of the transaction
+    #[strum(to_string = "textual")]
+    Textual,
+    /// Sign the legacy amino json representation of the transaction
+    #[strum(to_string = "amino-json")]
+    AminoJson,
+}
+
+impl From<SignModeCli> for SignMode {
+    fn from(value: SignModeCli) -> Self {
+        match value {
+            SignModeCli::Direct => SignMode::Direct,
+            SignModeCli::Textual => SignMode::Textual,
+            SignModeCli::AminoJson => SignMode::LegacyAminoJson,
+        }
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Local<T: ApplicationInfo> {
     /// from key
@@ -171,14 +226,23 @@ where
             chain_id,
             _marker,
             keyring,
+            sign_mode,
             local,
             mode,
             note,
             timeout_height,
             fee,
+            tip,
+            aux,
             command,
         } = value;
 
+        if aux && !matches!(sign_mode, SignModeCli::Direct) {
+            Err(anyhow::anyhow!(
+                "`aux` signs the tx with SIGN_MODE_DIRECT_AUX and can't be used with `sign-mode`"
+            ))?
+        }
+
         let keyring = match keyring {
             Keyring::Ledger => TxKeyring::Ledger,
             Keyring::Local => {
@@ -232,10 +296,13 @@ where
                 node,
                 chain_id,
                 keyring,
+                sign_mode: sign_mode.into(),
                 account,
                 memo: note,
                 timeout_height,
                 fee: fee.try_into()?,
+                tip,
+                aux,
             },
         })
     }
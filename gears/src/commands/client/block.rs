@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use tendermint::{
+    rpc::{
+        client::{Client, HttpClient},
+        query::Query,
+        response::block::{
+            results::Response as BlockResultsResponse, search::Response as BlockSearchResponse,
+            Response as BlockResponse,
+        },
+        Order,
+    },
+    types::proto::block::Height,
+};
+
+use crate::{commands::output::OutputFormat, runtime::runtime};
+
+#[derive(Debug, Clone, former::Former)]
+pub struct BlockCommand {
+    pub node: url::Url,
+    /// Latest block if not set
+    pub height: Option<Height>,
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct BlocksCommand {
+    pub node: url::Url,
+    /// Tendermint query on the events of the blocks, e.g. `block.height > 5`
+    pub query: String,
+    pub page: u32,
+    pub limit: u8,
+    /// `asc` or `desc`
+    pub order_by: String,
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct BlockResultsCommand {
+    pub node: url::Url,
+    /// Latest block if not set
+    pub height: Option<Height>,
+    pub output: OutputFormat,
+}
+
+/// Query a committed block
+pub fn run_block(BlockCommand { node, height, .. }: BlockCommand) -> anyhow::Result<BlockResponse> {
+    let client = HttpClient::new(node.as_str())?;
+
+    let res = match height {
+        Some(height) => runtime().block_on(client.block(height))?,
+        None => runtime().block_on(client.latest_block())?,
+    };
+
+    Ok(res)
+}
+
+/// Search for the blocks matching the query
+pub fn run_blocks(
+    BlocksCommand {
+        node,
+        query,
+        page,
+        limit,
+        order_by,
+        ..
+    }: BlocksCommand,
+) -> anyhow::Result<BlockSearchResponse> {
+    let client = HttpClient::new(node.as_str())?;
+    let query = Query::from_str(&query)?;
+    let order = Order::from_str(&order_by)?;
+
+    Ok(runtime().block_on(client.block_search(query, page, limit, order))?)
+}
+
+/// Query the results of the execution of a block
+pub fn run_block_results(
+    BlockResultsCommand { node, height, .. }: BlockResultsCommand,
+) -> anyhow::Result<BlockResultsResponse> {
+    let client = HttpClient::new(node.as_str())?;
+
+    let res = match height {
+        Some(height) => runtime().block_on(client.block_results(height))?,
+        None => runtime().block_on(client.latest_block_results())?,
+    };
+
+    Ok(res)
+}
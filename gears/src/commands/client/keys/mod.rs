@@ -1,6 +1,8 @@
 use anyhow::Result;
 use bip32::Mnemonic;
+use keyring::{key::hd_path::HdPath, DerivationOptions};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 use strum::Display;
 use text_io::read;
@@ -9,6 +11,8 @@ use crate::crypto::keys::ReadAccAddress;
 
 const KEYRING_SUB_DIR_FILE: &str = "keyring-file";
 const KEYRING_SUB_DIR_TEST: &str = "keyring-test";
+/// Minimal length of user supplied entropy. It gives 256 bits of entropy for dice rolls.
+const MIN_ENTROPY_INPUT_LEN: usize = 99;
 
 #[derive(Clone, Default, Debug, Display, Deserialize, Serialize)]
 #[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
@@ -39,6 +43,7 @@ impl KeyringBackend {
 #[derive(Debug, Clone)]
 pub enum KeyCommand {
     Add(AddKeyCommand),
+    Mnemonic(MnemonicCommand),
 }
 
 #[derive(Debug, Clone, former::Former)]
@@ -48,6 +53,53 @@ pub struct AddKeyCommand {
     pub home: PathBuf,
     pub keyring_backend: KeyringBackend,
     pub bip39_mnemonic: Option<String>,
+    /// BIP-39 passphrase. If it's not set and `interactive` is true, user is prompted for it
+    pub bip39_passphrase: Option<String>,
+    pub interactive: bool,
+    pub hd_path: HdPath,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct MnemonicCommand {
+    /// Prompt user for own entropy (e.g. dice rolls) instead of using system random generator
+    pub unsafe_entropy: bool,
+}
+
+/// Creates a mnemonic from user supplied entropy, e.g. dice rolls.
+/// Input is hashed with SHA-256 to get entropy of valid length.
+pub fn mnemonic_from_entropy_input(input: &str) -> Result<Mnemonic> {
+    let input = input.trim();
+    if input.len() < MIN_ENTROPY_INPUT_LEN {
+        Err(anyhow::anyhow!(
+            "256-bits is {MIN_ENTROPY_INPUT_LEN} dice rolls, got {}",
+            input.len()
+        ))?
+    }
+
+    let entropy: [u8; 32] = Sha256::digest(input.as_bytes()).into();
+
+    Ok(Mnemonic::from_entropy(entropy, bip32::Language::English))
+}
+
+/// Reads BIP-39 passphrase without echoing it, a non-empty passphrase has to be repeated
+fn prompt_bip39_passphrase() -> Result<String> {
+    println!("> Enter your bip39 passphrase. This is combined with the mnemonic to derive the seed. Most users should just hit enter to use the default, \"\"");
+    let passphrase = rpassword::prompt_password("> ")?;
+
+    match passphrase.is_empty() {
+        true => Ok(passphrase),
+        false => {
+            let repeated = rpassword::prompt_password("> Repeat the passphrase: ")?;
+            confirm_passphrase(passphrase, &repeated)
+        }
+    }
+}
+
+fn confirm_passphrase(passphrase: String, repeated: &str) -> Result<String> {
+    match passphrase == repeated {
+        true => Ok(passphrase),
+        false => Err(anyhow::anyhow!("passphrases don't match")),
+    }
 }
 
 // TODO: remove this cli code
@@ -60,12 +112,26 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 home,
                 keyring_backend,
                 bip39_mnemonic,
+                bip39_passphrase,
+                interactive,
+                hd_path,
             } = cmd;
 
             let keyring_home = home.join(keyring_backend.get_sub_dir());
 
             let backend = keyring_backend.to_keyring_backend(&keyring_home);
 
+            let passphrase = match bip39_passphrase {
+                Some(passphrase) => passphrase,
+                None if interactive => prompt_bip39_passphrase()?,
+                None => String::new(),
+            };
+
+            let options = DerivationOptions {
+                passphrase,
+                hd_path,
+            };
+
             if recover {
                 let phrase = if let Some(bip) = bip39_mnemonic {
                     bip
@@ -77,10 +143,16 @@ pub fn keys(command: KeyCommand) -> Result<()> {
 
                 let mnemonic = Mnemonic::new(phrase, bip32::Language::English)?;
 
-                keyring::add_key(&name, &mnemonic, keyring::KeyType::Secp256k1, backend)?;
+                keyring::add_key(
+                    &name,
+                    &mnemonic,
+                    keyring::KeyType::Secp256k1,
+                    &options,
+                    backend,
+                )?;
             } else {
                 let (mnemonic, key_pair) =
-                    keyring::create_key(&name, keyring::KeyType::Secp256k1, backend)?;
+                    keyring::create_key(&name, keyring::KeyType::Secp256k1, &options, backend)?;
 
                 println!("Created key {}\nAddress: {}", name, key_pair.get_address());
 
@@ -88,7 +160,53 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 println!("{}", mnemonic.phrase());
             }
         }
+        KeyCommand::Mnemonic(MnemonicCommand { unsafe_entropy }) => {
+            let mnemonic = if unsafe_entropy {
+                println!("> WARNING: Generate at least {MIN_ENTROPY_INPUT_LEN} rolls of a fair 6-sided die if using dice.");
+                println!("> Enter your entropy");
+                let input: String = read!("{}\n");
+
+                mnemonic_from_entropy_input(&input)?
+            } else {
+                Mnemonic::random(
+                    k256::elliptic_curve::rand_core::OsRng,
+                    bip32::Language::English,
+                )
+            };
+
+            println!("{}", mnemonic.phrase());
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mnemonic_from_entropy_input_works() -> anyhow::Result<()> {
+        let rolls = "6".repeat(MIN_ENTROPY_INPUT_LEN);
+
+        let mnemonic = mnemonic_from_entropy_input(&rolls)?;
+
+        assert_eq!(mnemonic.phrase().split(' ').count(), 24);
+        assert_eq!(
+            mnemonic.phrase(),
+            mnemonic_from_entropy_input(&format!("{rolls}\n"))?.phrase()
+        );
+        assert!(mnemonic_from_entropy_input("123456").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn confirm_passphrase_requires_same_input() {
+        assert_eq!(
+            confirm_passphrase("secret".to_owned(), "secret").ok(),
+            Some("secret".to_owned())
+        );
+        assert!(confirm_passphrase("secret".to_owned(), "secreT").is_err());
+    }
+}
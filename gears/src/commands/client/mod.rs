@@ -1,16 +1,29 @@
-use self::{keys::KeyCommand, query::QueryCommand, tx::TxCommand};
+use self::{
+    block::{BlockCommand, BlockResultsCommand, BlocksCommand},
+    keys::KeyCommand,
+    query::QueryCommand,
+    status::StatusCommand,
+    tx::{AuxToFeeCommand, TxCommand},
+};
 use crate::cli::query_txs::{TxQueryCli, TxsQueryCli};
 
+pub mod block;
 pub mod keys;
 pub mod query;
+pub mod status;
 pub mod tx;
 
 #[derive(Debug, Clone)]
 pub enum ClientCommands<AUX, TX, QUE> {
     Aux(AUX),
     Tx(TxCommand<TX>),
+    AuxToFee(TxCommand<AuxToFeeCommand>),
     Query(QueryCommand<QUE>),
     QueryTx(QueryCommand<TxQueryCli>),
     QueryTxs(QueryCommand<TxsQueryCli>),
+    QueryBlock(BlockCommand),
+    QueryBlocks(BlocksCommand),
+    QueryBlockResults(BlockResultsCommand),
+    Status(StatusCommand),
     Keys(KeyCommand),
 }
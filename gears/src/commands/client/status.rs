@@ -0,0 +1,19 @@
+use tendermint::rpc::{
+    client::{Client, HttpClient},
+    response::status::Response as StatusResponse,
+};
+
+use crate::{commands::output::OutputFormat, runtime::runtime};
+
+#[derive(Debug, Clone, former::Former)]
+pub struct StatusCommand {
+    pub node: url::Url,
+    pub output: OutputFormat,
+}
+
+/// Query the status of the node: its info, sync state and validator key
+pub fn run_status(StatusCommand { node, .. }: StatusCommand) -> anyhow::Result<StatusResponse> {
+    let client = HttpClient::new(node.as_str())?;
+
+    Ok(runtime().block_on(client.status())?)
+}
@@ -13,6 +13,8 @@ use crate::crypto::keys::GearsPublicKey;
 use crate::crypto::ledger::LedgerProxyKey;
 use crate::runtime::runtime;
 use crate::types::auth::fee::Fee;
+use crate::types::base::coins::UnsignedCoins;
+use crate::types::tx::direct_aux::AuxSignerData;
 use crate::types::tx::raw::TxRaw;
 use gas::Gas;
 
@@ -39,12 +41,17 @@ pub struct ClientTxContext {
     pub node: url::Url,
     pub home: PathBuf,
     pub keyring: Keyring,
+    pub sign_mode: SignMode,
     pub memo: Option<String>,
     pub account: AccountProvider,
     pub chain_id: ChainId,
     pub timeout_height: Option<u32>,
 
     pub fee: Fee,
+    /// Tip to pay from the signer account to the fee payer
+    pub tip: Option<UnsignedCoins>,
+    /// Sign the tx with SIGN_MODE_DIRECT_AUX and output it instead of broadcasting
+    pub aux: bool,
 }
 
 impl ClientTxContext {
@@ -74,6 +81,7 @@ impl ClientTxContext {
                 keyring_backend: KeyringBackend::Test,
                 from_key: from_key.to_owned(),
             }),
+            sign_mode: SignMode::Direct,
             node,
             chain_id,
             memo: None,
@@ -84,6 +92,8 @@ impl ClientTxContext {
                 payer: None,
                 granter: "".to_owned(),
             },
+            tip: None,
+            aux: false,
         }
     }
 }
@@ -170,6 +180,14 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     let key = handle_key(&ctx)?;
 
     let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
+    let sign_mode = ctx.sign_mode.clone();
+
+    if ctx.aux {
+        let aux_signer_data = handler.sign_aux(messages, &key, &mut ctx, fetcher)?;
+        println!("{}", serde_json::to_string_pretty(&aux_signer_data)?);
+
+        return Ok(RuntxResult::None);
+    }
 
     if messages.chunk_size() > 0
     // TODO: uncomment and update logic when command will be extended by broadcast_mode
@@ -187,7 +205,7 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
                         .try_into()
                         .expect("chunking of the messages excludes empty vectors"),
                     &key,
-                    SignMode::Direct,
+                    sign_mode.clone(),
                     &mut ctx,
                     fetcher,
                 )?,
@@ -203,13 +221,38 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
         // TODO: can be reduced by changing variable `step`. Do we need it?
         handler
             .handle_tx(
-                handler.sign_msg(messages, &key, SignMode::Direct, &mut ctx, fetcher)?,
+                handler.sign_msg(messages, &key, sign_mode, &mut ctx, fetcher)?,
                 &mut ctx,
             )
             .map(Into::into)
     }
 }
 
+/// Command to complete a tx signed by an auxiliary signer (e.g. a tipper)
+#[derive(Debug, Clone)]
+pub struct AuxToFeeCommand {
+    /// Path to file with `AuxSignerData` in json format
+    pub aux_signer_data: PathBuf,
+}
+
+/// Read data signed by auxiliary signer, sign it as fee payer and broadcast the tx
+pub fn run_aux_to_fee<H: TxHandler, F: NodeFetcher + Clone>(
+    TxCommand {
+        mut ctx,
+        inner: AuxToFeeCommand { aux_signer_data },
+    }: TxCommand<AuxToFeeCommand>,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    let key = handle_key(&ctx)?;
+
+    let aux_signer_data: AuxSignerData = serde_json::from_slice(&std::fs::read(aux_signer_data)?)?;
+
+    let tx = handler.sign_aux_as_fee_payer(aux_signer_data, &key, &mut ctx, fetcher)?;
+
+    handler.handle_tx(tx, &mut ctx).map(Into::into)
+}
+
 /// Helper method to run a tx with blocking.
 ///
 /// **WARNING**: never use this method in async context due internal blocking using tokio runtime
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use serde::Serialize;
-use tendermint::types::chain_id::ChainId;
+use tendermint::{privval::Address, types::chain_id::ChainId};
 
 use crate::config::{ApplicationConfig, ConfigDirectory};
 
@@ -10,6 +10,8 @@ pub struct InitCommand {
     pub home: PathBuf,
     pub moniker: String,
     pub chain_id: ChainId,
+    /// Address on which Tendermint listens for connection of remote signer
+    pub priv_validator_laddr: Option<Address>,
 }
 
 /// Init application configuration like `tendermint` config, genesis file
@@ -21,6 +23,7 @@ pub fn init<G: Serialize, AC: ApplicationConfig>(
         moniker,
         home,
         chain_id,
+        priv_validator_laddr,
     } = cmd;
 
     // Create config directory
@@ -36,7 +39,8 @@ pub fn init<G: Serialize, AC: ApplicationConfig>(
     let tm_config_file =
         std::fs::File::create(&tm_config_file_path).map_err(InitError::CreateConfigDirectory)?;
 
-    tendermint::write_tm_config(tm_config_file, &moniker).map_err(InitError::WriteConfigFile)?;
+    tendermint::write_tm_config(tm_config_file, &moniker, priv_validator_laddr)
+        .map_err(InitError::WriteConfigFile)?;
 
     #[cfg(not(feature = "utils"))]
     println!("Tendermint config written to {tm_config_file_path:?}");
@@ -1,11 +1,13 @@
 pub mod genesis;
 pub mod init;
 pub mod run;
+pub mod tendermint;
 
 #[derive(Debug, Clone)]
 pub enum AppCommands<AUX> {
     Init(init::InitCommand),
     Run(run::RunCommand),
     GenesisAdd(genesis::GenesisCommand),
+    Tendermint(tendermint::TendermintCommand),
     Aux(AUX),
 }
@@ -11,12 +11,17 @@ use axum::Router;
 use database::{Database, DatabaseBuilder};
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::time::Duration;
 use tendermint::abci::ServerBuilder;
 use tendermint::application::ABCI;
+use tendermint::rpc::client::HttpClientUrl;
 use tower_layer::Identity;
 use tracing::metadata::LevelFilter;
 use tracing::{error, info};
 
+/// How often the node checks that the ABCI server is still running while waiting for a halt
+const HALT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Clone)]
 pub struct RunCommand {
     pub home: PathBuf,
@@ -27,6 +32,8 @@ pub struct RunCommand {
     pub read_buf_size: usize,
     pub log_level: LogLevel,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub halt_height: Option<u32>,
+    pub halt_time: Option<u64>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -78,6 +85,7 @@ pub trait RouterBuilder<QReq, QRes> {
     fn build_grpc_router<App: NodeQueryHandler<QReq, QRes>>(
         &self,
         app: App,
+        tendermint_rpc_address: HttpClientUrl,
     ) -> tonic::transport::server::Router<Identity>;
 }
 
@@ -108,6 +116,8 @@ pub fn run<
         log_level,
         min_gas_prices,
         tendermint_rpc_addr: tendermint_addr,
+        halt_height,
+        halt_time,
     } = cmd;
 
     tracing_subscriber::fmt()
@@ -124,8 +134,9 @@ pub fn run<
 
     let cfg_file_path = ConfigDirectory::ConfigFile.path_from_home(&home);
 
-    let config: Config<AC> = Config::from_file(cfg_file_path)
+    let mut config: Config<AC> = Config::from_file(cfg_file_path)
         .map_err(|e| RunError::Custom(format!("Error reading config file: {:?}", e)))?;
+    config.home = home;
 
     let abci_handler = abci_handler_builder(config.clone());
 
@@ -133,21 +144,26 @@ pub fn run<
         RunError::HomeDirectory(
             "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
         ),
-    )?);
+    )?)
+    .with_halt(halt_height, halt_time);
+    let halt_signal = options.halt_signal();
 
     let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options);
 
+    let tendermint_rpc_address: HttpClientUrl = tendermint_addr
+        .unwrap_or(config.tendermint_rpc_address)
+        .try_into()?;
+
     run_rest_server::<H::Message, H::QReq, H::QRes, _>(
         app.clone(),
         rest_listen_addr.unwrap_or(config.rest_listen_addr),
         router_builder.build_router::<BaseApp<DB, PSK, H, AI>>(),
-        tendermint_addr
-            .unwrap_or(config.tendermint_rpc_address)
-            .try_into()?,
+        tendermint_rpc_address.clone(),
     );
 
     run_grpc_server(
-        router_builder.build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone()),
+        router_builder
+            .build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone(), tendermint_rpc_address),
         grpc_listen_addr.unwrap_or(config.grpc_listen_addr),
     );
 
@@ -157,5 +173,21 @@ pub fn run<
 
     info!("Starting proxy server at: {}", addr.to_string());
 
-    server.listen().map_err(|e| e.into())
+    let server = std::thread::spawn(move || server.listen());
+
+    // The signal is raised by the application while it handles the commit of the halt
+    // block, the pause after it lets the server deliver the commit response
+    while !halt_signal.wait_timeout(HALT_POLL_INTERVAL) {
+        if server.is_finished() {
+            return server
+                .join()
+                .map_err(|_| RunError::Custom("ABCI server thread panicked".to_owned()))?
+                .map_err(|e| e.into());
+        }
+    }
+
+    std::thread::sleep(HALT_POLL_INTERVAL);
+    info!("Node halted");
+
+    Ok(())
 }
@@ -0,0 +1,166 @@
+use std::{fs::File, path::PathBuf, time::Duration};
+
+use address::ConsAddress;
+use tendermint::types::proto::crypto::PublicKey as TendermintPublicKey;
+
+use crate::{config::ConfigDirectory, crypto::public::PublicKey};
+
+/// Time to wait for the remote signer to connect
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, former::Former)]
+pub struct TendermintCommand {
+    pub home: PathBuf,
+    pub kind: TendermintCommandKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum TendermintCommandKind {
+    /// Id of the node, derived from its p2p key
+    ShowNodeId,
+    /// Consensus public key of the validator
+    ShowValidator,
+    /// Consensus address of the validator
+    ShowAddress,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TendermintCommandError {
+    #[error("failed to open {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("{0}")]
+    Tendermint(#[from] tendermint::error::Error),
+    #[error("{0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+/// Returns the requested information about the node, ready to be printed
+pub fn run_tendermint(
+    TendermintCommand { home, kind }: TendermintCommand,
+) -> Result<String, TendermintCommandError> {
+    let res = match kind {
+        TendermintCommandKind::ShowNodeId => {
+            let file = open(ConfigDirectory::NodeKeyFile.path_from_home(&home))?;
+            // the node id is the hex encoded address of its ed25519 key
+            ConsAddress::from(tendermint::node_pub_key(file)?).as_hex()
+        }
+        TendermintCommandKind::ShowValidator => {
+            let pub_key = validator_pub_key(&home)?;
+            serde_json::to_string(&PublicKey::from(pub_key))?
+        }
+        TendermintCommandKind::ShowAddress => {
+            ConsAddress::from(validator_pub_key(&home)?).to_string()
+        }
+    };
+
+    Ok(res)
+}
+
+/// Returns the key of the remote signer if the node has one, the key of
+/// `priv_validator_key.json` otherwise
+fn validator_pub_key(home: &PathBuf) -> Result<TendermintPublicKey, TendermintCommandError> {
+    let tm_config = open(ConfigDirectory::TendermintConfigFile.path_from_home(home))?;
+
+    match tendermint::priv_validator_laddr(tm_config)? {
+        Some(laddr) => {
+            let genesis = open(ConfigDirectory::GenesisFile.path_from_home(home))?;
+            let chain_id = tendermint::genesis_chain_id(genesis)?;
+
+            Ok(tendermint::privval::fetch_validator_pub_key(
+                &laddr,
+                &chain_id,
+                REMOTE_SIGNER_TIMEOUT,
+            )?)
+        }
+        None => {
+            let file = open(ConfigDirectory::PrivValidatorKeyFile.path_from_home(home))?;
+            Ok(tendermint::get_validator_pub_key(file)?)
+        }
+    }
+}
+
+fn open(path: PathBuf) -> Result<File, TendermintCommandError> {
+    File::open(&path).map_err(|source| TendermintCommandError::Io { path, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tendermint::{privval::MockSigner, types::chain_id::ChainId};
+
+    use super::*;
+
+    /// Writes the keys, genesis and Tendermint config of a node to a fresh home
+    fn home(name: &str, laddr: Option<tendermint::privval::Address>) -> anyhow::Result<PathBuf> {
+        let home =
+            std::env::temp_dir().join(format!("show-validator-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&home);
+        fs::create_dir_all(ConfigDirectory::ConfigDir.path_from_home(&home))?;
+
+        tendermint::write_keys_and_genesis(
+            File::create(ConfigDirectory::NodeKeyFile.path_from_home(&home))?,
+            File::create(ConfigDirectory::PrivValidatorKeyFile.path_from_home(&home))?,
+            File::create(ConfigDirectory::GenesisFile.path_from_home(&home))?,
+            serde_json::json!({}),
+            ChainId::new("test-chain")?,
+        )?;
+        tendermint::write_tm_config(
+            File::create(ConfigDirectory::TendermintConfigFile.path_from_home(&home))?,
+            "test",
+            laddr,
+        )?;
+
+        Ok(home)
+    }
+
+    fn show_validator(home: PathBuf) -> Result<String, TendermintCommandError> {
+        run_tendermint(TendermintCommand {
+            home,
+            kind: TendermintCommandKind::ShowValidator,
+        })
+    }
+
+    #[test]
+    fn show_validator_reads_local_key() -> anyhow::Result<()> {
+        let home = home("local", None)?;
+        let pub_key = tendermint::get_validator_pub_key(File::open(
+            ConfigDirectory::PrivValidatorKeyFile.path_from_home(&home),
+        )?)?;
+
+        assert_eq!(
+            show_validator(home)?,
+            serde_json::to_string(&PublicKey::from(pub_key))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn show_validator_asks_remote_signer() -> anyhow::Result<()> {
+        let socket =
+            std::env::temp_dir().join(format!("show-validator-{}.sock", std::process::id()));
+        let laddr: tendermint::privval::Address = format!("unix://{}", socket.display()).parse()?;
+        let home = home("remote", Some(laddr.clone()))?;
+
+        let key_file = ConfigDirectory::PrivValidatorKeyFile.path_from_home(&home);
+        let signer =
+            MockSigner::from_key_file(File::open(&key_file)?, ChainId::new("test-chain")?)?;
+        let pub_key = signer.pub_key()?;
+        // the key is held by the signer only
+        fs::remove_file(key_file)?;
+
+        let handle = std::thread::spawn(move || signer.run(&laddr, Duration::from_secs(5)));
+        let res = show_validator(home)?;
+        handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("signer thread panicked"))??;
+
+        assert_eq!(res, serde_json::to_string(&PublicKey::from(pub_key))?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Format in which the result of a command is printed
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    #[strum(to_string = "json")]
+    Json,
+    /// One `path.to.field: value` line per field
+    #[strum(to_string = "text")]
+    Text,
+}
+
+impl OutputFormat {
+    pub fn format<T: Serialize>(&self, value: &T) -> Result<String, serde_json::Error> {
+        match self {
+            OutputFormat::Json => serde_json::to_string_pretty(value),
+            OutputFormat::Text => {
+                let mut lines = Vec::new();
+                flatten("", &serde_json::to_value(value)?, &mut lines);
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
+
+fn flatten(path: &str, value: &Value, lines: &mut Vec<String>) {
+    let field = |key: &str| match path {
+        "" => key.to_owned(),
+        _ => format!("{path}.{key}"),
+    };
+
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                flatten(&field(key), value, lines);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, value) in items.iter().enumerate() {
+                flatten(&field(&i.to_string()), value, lines);
+            }
+        }
+        value => {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                value => value.to_string(),
+            };
+            match path {
+                "" => lines.push(value),
+                _ => lines.push(format!("{path}: {value}")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_format_flattens_fields() {
+        let value = serde_json::json!({
+            "node_info": { "id": "abc", "other": {} },
+            "sync_info": { "latest_block_height": "10", "catching_up": false },
+            "validators": ["a", "b"]
+        });
+
+        let text = OutputFormat::Text
+            .format(&value)
+            .expect("value is serializable");
+
+        assert_eq!(
+            text,
+            "node_info.id: abc\nnode_info.other: {}\nsync_info.catching_up: false\nsync_info.latest_block_height: 10\nvalidators.0: a\nvalidators.1: b"
+        );
+    }
+}
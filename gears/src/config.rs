@@ -9,7 +9,10 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tendermint::rpc::url::Url;
 
-use crate::defaults::{CLIENT_CONFIG_FILE_NAME, CONFIG_DIR, CONFIG_FILE_NAME, GENESIS_FILE_NAME};
+use crate::defaults::{
+    CLIENT_CONFIG_FILE_NAME, CONFIG_DIR, CONFIG_FILE_NAME, GENESIS_FILE_NAME, NODE_KEY_FILE_NAME,
+    PRIV_VALIDATOR_KEY_FILE_NAME, TENDERMINT_CONFIG_FILE_NAME,
+};
 use crate::types::base::min_gas::MinGasPrices;
 
 pub const DEFAULT_GRPC_LISTEN_ADDR: SocketAddr = socket_addr!(127, 0, 0, 1, 8080);
@@ -24,6 +27,9 @@ pub enum ConfigDirectory {
     GenesisFile,
     ConfigFile,
     ClientConfigFile,
+    TendermintConfigFile,
+    NodeKeyFile,
+    PrivValidatorKeyFile,
     ConfigDir,
 }
 
@@ -35,6 +41,15 @@ impl ConfigDirectory {
             ConfigDirectory::ClientConfigFile => {
                 home.as_ref().join(CONFIG_DIR).join(CLIENT_CONFIG_FILE_NAME)
             }
+            ConfigDirectory::TendermintConfigFile => home
+                .as_ref()
+                .join(CONFIG_DIR)
+                .join(TENDERMINT_CONFIG_FILE_NAME),
+            ConfigDirectory::NodeKeyFile => home.as_ref().join(CONFIG_DIR).join(NODE_KEY_FILE_NAME),
+            ConfigDirectory::PrivValidatorKeyFile => home
+                .as_ref()
+                .join(CONFIG_DIR)
+                .join(PRIV_VALIDATOR_KEY_FILE_NAME),
             ConfigDirectory::ConfigDir => home.as_ref().join(CONFIG_DIR),
         }
     }
@@ -52,6 +67,9 @@ pub struct Config<AC: Default + Clone> {
     pub address: SocketAddr,
     pub min_gas_prices: Option<MinGasPrices>,
     pub app_config: AC,
+    /// Home directory of the node, set when it starts
+    #[serde(skip)]
+    pub home: PathBuf,
 }
 
 impl<AC: ApplicationConfig> Config<AC> {
@@ -92,6 +110,7 @@ impl<AC: ApplicationConfig> Config<AC> {
             address: self.address.to_owned(),
             min_gas_prices: self.min_gas_prices.to_owned(),
             app_config: AC::default(),
+            home: self.home.to_owned(),
         }
     }
 }
@@ -107,6 +126,7 @@ impl<AC: ApplicationConfig> Default for Config<AC> {
             app_config: AC::default(),
             min_gas_prices: None,
             grpc_listen_addr: DEFAULT_GRPC_LISTEN_ADDR,
+            home: PathBuf::new(),
         }
     }
 }
@@ -1,4 +1,5 @@
 use database::{prefix::PrefixDB, Database};
+use gas::metering::FiniteGas;
 use kv_store::{
     bank::multi::ApplicationMultiBank,
     store::kv::{immutable::KVStore, mutable::KVStoreMut},
@@ -23,6 +24,7 @@ pub struct BlockContext<'a, DB, SK> {
     pub(crate) height: u32,
     pub header: Header,
     pub(crate) consensus_params: ConsensusParams,
+    pub(crate) block_gas_used: FiniteGas,
     pub events: Vec<Event>,
 }
 
@@ -32,12 +34,14 @@ impl<'a, DB, SK> BlockContext<'a, DB, SK> {
         height: u32,
         header: Header,
         consensus_params: ConsensusParams,
+        block_gas_used: FiniteGas,
     ) -> Self {
         BlockContext {
             multi_store,
             height,
             events: Vec::new(),
             consensus_params,
+            block_gas_used,
             header,
         }
     }
@@ -49,6 +53,11 @@ impl<'a, DB, SK> BlockContext<'a, DB, SK> {
     pub fn consensus_params(&self) -> &ConsensusParams {
         &self.consensus_params
     }
+
+    /// Gas consumed by the txs delivered in this block so far
+    pub fn block_gas_used(&self) -> FiniteGas {
+        self.block_gas_used
+    }
 }
 
 impl<DB: Database, SK: StoreKey> BlockContext<'_, DB, SK> {
@@ -59,6 +68,37 @@ impl<DB: Database, SK: StoreKey> BlockContext<'_, DB, SK> {
     pub fn kv_store_mut(&mut self, store_key: &SK) -> KVStoreMut<'_, PrefixDB<DB>> {
         KVStoreMut::from(self.multi_store.kv_store_mut(store_key))
     }
+
+    /// Run `f` with a context over a branch of the block state. State changes and events of
+    /// the branch are kept only when `f` succeeds
+    pub fn branched<T, E>(
+        &mut self,
+        f: impl FnOnce(&mut BlockContext<'_, DB, SK>) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut multi_store = self.multi_store.branch();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            self.height,
+            self.header.clone(),
+            std::mem::take(&mut self.consensus_params),
+            self.block_gas_used,
+        );
+
+        let result = f(&mut ctx);
+
+        let BlockContext {
+            consensus_params,
+            mut events,
+            ..
+        } = ctx;
+        self.consensus_params = consensus_params;
+
+        let result = result?;
+        self.multi_store.merge_branch(multi_store);
+        self.events.append(&mut events);
+
+        Ok(result)
+    }
 }
 
 impl<DB: Database, SK: StoreKey> QueryableContext<DB, SK> for BlockContext<'_, DB, SK> {
@@ -23,7 +23,7 @@ use tendermint::types::{
 };
 
 use crate::{
-    baseapp::{options::NodeOptions, ConsensusParams},
+    baseapp::{options::NodeOptions, ConsensusParams, GasScheduleParams},
     types::store::kv::{mutable::StoreMut, Store},
 };
 
@@ -38,6 +38,7 @@ pub struct TxContext<'a, DB, SK> {
     pub(crate) header: Header,
     pub(crate) block_gas_meter: &'a mut GasMeter<BlockKind>,
     pub(crate) consensus_params: ConsensusParams,
+    pub(crate) gas_schedule: GasScheduleParams,
     multi_store: &'a mut TransactionMultiBank<DB, SK>,
 }
 
@@ -47,6 +48,7 @@ impl<'a, DB, SK> TxContext<'a, DB, SK> {
         height: u32,
         header: Header,
         consensus_params: ConsensusParams,
+        gas_schedule: GasScheduleParams,
         gas_meter: GasMeter<TxKind>,
         block_gas_meter: &'a mut GasMeter<BlockKind>,
         node_opt: NodeOptions,
@@ -59,6 +61,7 @@ impl<'a, DB, SK> TxContext<'a, DB, SK> {
             gas_meter: Arc::new(RefCell::new(gas_meter)),
             block_gas_meter,
             consensus_params,
+            gas_schedule,
             node_opt,
         }
     }
@@ -86,16 +89,21 @@ impl<DB: Database, SK: StoreKey> TxContext<'_, DB, SK> {
         &self.consensus_params
     }
 
+    /// Gas schedule which was active when the tx started
+    pub fn gas_schedule(&self) -> &GasScheduleParams {
+        &self.gas_schedule
+    }
+
     pub fn kv_store(&self, store_key: &SK) -> GasKVStore<'_, PrefixDB<DB>> {
         GasKVStore::new(
-            GasGuard::new(Arc::clone(&self.gas_meter)),
+            GasGuard::new(Arc::clone(&self.gas_meter), self.gas_schedule.kv),
             self.multi_store.kv_store(store_key).into(),
         )
     }
 
     pub fn kv_store_mut(&mut self, store_key: &SK) -> GasKVStoreMut<'_, PrefixDB<DB>> {
         GasKVStoreMut::new(
-            GasGuard::new(Arc::clone(&self.gas_meter)),
+            GasGuard::new(Arc::clone(&self.gas_meter), self.gas_schedule.kv),
             self.multi_store.kv_store_mut(store_key).into(),
         )
     }
@@ -14,12 +14,23 @@ use std::{
 use crate::{
     application::handlers::client::{MetadataViaRPC, NodeFetcher},
     signing::{
-        errors::SigningErrors, handler::SignModeHandler, renderer::value_renderer::ValueRenderer,
+        errors::SigningErrors,
+        handler::SignModeHandler,
+        renderer::{
+            amino_renderer::{AminoRenderer, RenderError},
+            value_renderer::ValueRenderer,
+        },
+        std_sign_doc::StdSignDoc,
     },
     types::{
         auth::{fee::Fee, info::AuthInfo, tip::Tip},
         signing::SignerInfo,
-        tx::{body::TxBody, signer::SignerData, Tx, TxMessage},
+        tx::{
+            body::TxBody,
+            direct_aux::{AuxSignerData, SignDocDirectAux},
+            signer::SignerData,
+            Tx, TxMessage,
+        },
     },
 };
 
@@ -67,6 +78,128 @@ pub fn create_signed_transaction_direct<M: TxMessage, K: SigningKey + GearsPubli
     // Ok()
 }
 
+/// Sign the tx body with SIGN_MODE_DIRECT_AUX. Returned data should be passed to
+/// the fee payer who completes and broadcasts the tx.
+pub fn create_aux_signer_data<M: TxMessage, K: SigningKey + ReadAccAddress + GearsPublicKey>(
+    signing_info: SigningInfo<'_, K>,
+    chain_id: ChainId,
+    tip: Option<Tip>,
+    body: TxBody<M>,
+) -> Result<AuxSignerData, K::Error> {
+    let sign_doc = SignDocDirectAux {
+        body_bytes: body.encode_vec(),
+        public_key: signing_info.key.get_gears_public_key(),
+        chain_id: chain_id.into(),
+        account_number: signing_info.account_number,
+        sequence: signing_info.sequence,
+        tip,
+    };
+
+    let sig = signing_info.key.sign(&sign_doc.encode_vec())?;
+
+    Ok(AuxSignerData {
+        address: signing_info.key.get_address(),
+        sign_doc,
+        mode: SignMode::DirectAux,
+        sig,
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuxSigningError<E> {
+    #[error("invalid aux signer data: {0}")]
+    InvalidData(String),
+    #[error("{0}")]
+    Key(E),
+}
+
+/// Complete the tx signed by auxiliary signer. Fee payer signs whole tx
+/// with SIGN_MODE_DIRECT and pays the fees.
+pub fn create_signed_transaction_from_aux<
+    M: TxMessage,
+    K: SigningKey + ReadAccAddress + GearsPublicKey,
+>(
+    aux_signer_data: AuxSignerData,
+    fee_payer: SigningInfo<'_, K>,
+    mut fee: Fee,
+) -> Result<Tx<M>, AuxSigningError<K::Error>> {
+    let AuxSignerData {
+        address,
+        sign_doc,
+        mode,
+        sig,
+    } = aux_signer_data;
+
+    if mode != SignMode::DirectAux {
+        return Err(AuxSigningError::InvalidData(format!(
+            "unsupported sign mode {mode:?}"
+        )));
+    }
+
+    if sign_doc.public_key.get_address() != address {
+        return Err(AuxSigningError::InvalidData(
+            "public key doesn't match address".to_owned(),
+        ));
+    }
+
+    sign_doc
+        .public_key
+        .verify_signature(sign_doc.encode_vec(), &sig)
+        .map_err(|e| AuxSigningError::InvalidData(format!("invalid signature: {e}")))?;
+
+    let body = TxBody::<M>::decode_vec(&sign_doc.body_bytes)
+        .map_err(|e| AuxSigningError::InvalidData(e.to_string()))?;
+
+    let payer_address = fee_payer.key.get_address();
+    fee.payer = Some(payer_address.clone());
+
+    let aux_signer_info = SignerInfo {
+        public_key: Some(sign_doc.public_key.clone()),
+        mode_info: ModeInfo::Single(SignMode::DirectAux),
+        sequence: sign_doc.sequence,
+    };
+    let fee_payer_info = SignerInfo {
+        public_key: Some(fee_payer.key.get_gears_public_key()),
+        mode_info: ModeInfo::Single(SignMode::Direct),
+        sequence: fee_payer.sequence,
+    };
+
+    let tx = Tx {
+        body,
+        auth_info: AuthInfo {
+            signer_infos: vec![aux_signer_info, fee_payer_info],
+            fee,
+            tip: sign_doc.tip,
+        },
+        signatures: vec![],
+        signatures_data: vec![],
+    };
+
+    let signers = tx.get_signers();
+    if signers != [&address, &payer_address] {
+        return Err(AuxSigningError::InvalidData(
+            "tx must be signed by single auxiliary signer and fee payer".to_owned(),
+        ));
+    }
+
+    let sign_doc = SignDoc {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: tx.auth_info.encode_vec(),
+        chain_id: sign_doc.chain_id,
+        account_number: fee_payer.account_number,
+    };
+
+    let payer_sig = fee_payer
+        .key
+        .sign(&sign_doc.encode_to_vec())
+        .map_err(AuxSigningError::Key)?;
+
+    Ok(Tx {
+        signatures: vec![sig, payer_sig],
+        ..tx
+    })
+}
+
 // NOTE: we can't implement From<K::Error> for this type
 #[derive(Debug)]
 pub enum TextualSigningError<K: SigningKey> {
@@ -139,10 +272,57 @@ pub fn create_signed_transaction_textual<
     })
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum AminoJsonSigningError<E> {
+    #[error("{0}")]
+    Rendering(#[from] RenderError),
+    #[error("{0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("{0}")]
+    Key(E),
+}
+
+pub fn create_signed_transaction_amino_json<
+    M: TxMessage + AminoRenderer,
+    K: SigningKey + GearsPublicKey,
+>(
+    signing_infos: Vec<SigningInfo<'_, K>>,
+    chain_id: ChainId,
+    fee: Fee,
+    tip: Option<Tip>,
+    body: TxBody<M>,
+) -> Result<Tx<M>, AminoJsonSigningError<K::Error>> {
+    let auth_info = auth_info(&signing_infos, fee.clone(), tip, Mode::LegacyAminoJson);
+
+    let signatures = signing_infos
+        .iter()
+        .map(|s| {
+            let sign_bytes = StdSignDoc::new(
+                &body,
+                fee.clone(),
+                chain_id.clone(),
+                s.account_number,
+                s.sequence,
+            )?
+            .to_sign_bytes()?;
+
+            s.key.sign(&sign_bytes).map_err(AminoJsonSigningError::Key)
+        })
+        .collect::<Result<Vec<Vec<u8>>, AminoJsonSigningError<K::Error>>>()?;
+
+    Ok(Tx {
+        body,
+        auth_info,
+        signatures,
+        signatures_data: Vec::new(),
+    })
+}
+
 #[derive(Clone)]
 enum Mode {
     Direct,
     Textual,
+    LegacyAminoJson,
 }
 
 impl From<Mode> for SignMode {
@@ -150,6 +330,7 @@ impl From<Mode> for SignMode {
         match mode {
             Mode::Direct => SignMode::Direct,
             Mode::Textual => SignMode::Textual,
+            Mode::LegacyAminoJson => SignMode::LegacyAminoJson,
         }
     }
 }
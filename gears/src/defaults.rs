@@ -6,6 +6,9 @@ pub const CONFIG_DIR: &str = "config";
 pub const GENESIS_FILE_NAME: &str = "genesis.json";
 pub const CONFIG_FILE_NAME: &str = "app.toml";
 pub const CLIENT_CONFIG_FILE_NAME: &str = "client.toml";
+pub const TENDERMINT_CONFIG_FILE_NAME: &str = "config.toml";
+pub const NODE_KEY_FILE_NAME: &str = "node_key.json";
+pub const PRIV_VALIDATOR_KEY_FILE_NAME: &str = "priv_validator_key.json";
 
 pub const DEFAULT_DIR_NAME: &str = ".tendermint";
 
@@ -83,6 +83,12 @@ impl From<std::num::TryFromIntError> for ProtobufError {
     }
 }
 
+impl From<gas::GasError> for ProtobufError {
+    fn from(value: gas::GasError) -> Self {
+        Self::Custom(anyhow::anyhow!("{value}"))
+    }
+}
+
 impl From<SubspaceParseError> for ProtobufError {
     fn from(value: SubspaceParseError) -> Self {
         Self::Core(CoreError::DecodeGeneral(value.to_string()))
@@ -5,11 +5,9 @@ use crate::baseapp::errors::QueryError;
 impl From<QueryError> for Status {
     fn from(err: QueryError) -> Self {
         match err {
-            QueryError::Store(_) => {
-                // The store can return a version not found error, however gRPC queries do not supply a version. Instead
-                // we always query the latests version. Therefore, something has gone badly wrong if we get this error.
-                Status::internal("An internal error occurred while querying the application state.")
-            }
+            // The store returns an error when the version requested with the `x-cosmos-block-height`
+            // metadata doesn't exist, i.e. it has been pruned or isn't committed yet.
+            QueryError::Store(_) => Status::not_found("The requested version could not be found."),
             _ => Status::invalid_argument("Invalid message."), // TODO: Don't forget to add more info later
         }
     }
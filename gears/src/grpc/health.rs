@@ -1,77 +1,231 @@
+use std::marker::PhantomData;
+
+use extensions::pagination::IteratorPaginate;
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::Service as HealthService;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::ServiceServer as HealthServer;
 use ibc_proto::cosmos::base::tendermint::v1beta1::{
     AbciQueryRequest, AbciQueryResponse, GetBlockByHeightRequest, GetBlockByHeightResponse,
     GetLatestBlockRequest, GetLatestBlockResponse, GetLatestValidatorSetRequest,
     GetLatestValidatorSetResponse, GetNodeInfoRequest, GetNodeInfoResponse, GetSyncingRequest,
-    GetSyncingResponse, GetValidatorSetByHeightRequest, GetValidatorSetByHeightResponse,
+    GetSyncingResponse, GetValidatorSetByHeightRequest, GetValidatorSetByHeightResponse, ProofOp,
+    ProofOps, Validator as RawValidator, VersionInfo,
 };
+use tendermint::rpc::client::{Client, HttpClient, HttpClientUrl, Paging};
+use tendermint::rpc::url::Url;
+use tendermint::types::proto::{block::Height, p2p::DefaultNodeInfo};
 use tonic::{Request, Response, Status};
 use tracing::info;
 
+use crate::application::ApplicationInfo;
+use crate::types::pagination::{request::PaginationRequest, response::PaginationResponse};
+use crate::types::response::validators::Validator;
+
+/// Implementation of the `cosmos.base.tendermint.v1beta1.Service` which forwards the requests
+/// to the Tendermint node
 #[derive(Debug)]
-pub struct GearsHealthService;
+pub struct GearsHealthService<AI> {
+    client: HttpClient,
+    _phantom: PhantomData<AI>,
+}
+
+impl<AI> GearsHealthService<AI> {
+    async fn validator_set(
+        &self,
+        height: Option<Height>,
+        pagination: Option<PageRequest>,
+    ) -> Result<(i64, Vec<RawValidator>, Option<PaginationResponse>), Status> {
+        let res = match height {
+            Some(height) => self.client.validators(height, Paging::All).await,
+            None => self.client.validators_latest(Paging::All).await,
+        }
+        .map_err(tendermint_error)?;
+
+        let (p_result, validators) = res
+            .validators
+            .into_iter()
+            .map(Validator::from)
+            .maybe_paginate(pagination.map(PaginationRequest::from));
+
+        let validators = validators
+            .map(
+                |Validator {
+                     address,
+                     pub_key,
+                     voting_power,
+                     proposer_priority,
+                 }| RawValidator {
+                    address,
+                    pub_key,
+                    voting_power,
+                    proposer_priority,
+                },
+            )
+            .collect();
+
+        Ok((
+            res.block_height.into(),
+            validators,
+            p_result.map(PaginationResponse::from),
+        ))
+    }
+}
 
 #[tonic::async_trait]
-impl HealthService for GearsHealthService {
+impl<AI: ApplicationInfo> HealthService for GearsHealthService<AI> {
     async fn abci_query(
         &self,
-        _request: Request<AbciQueryRequest>,
+        request: Request<AbciQueryRequest>,
     ) -> Result<Response<AbciQueryResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::abci_query");
+        let AbciQueryRequest {
+            data,
+            path,
+            height,
+            prove,
+        } = request.into_inner();
+        let height = match height {
+            0 => None,
+            height => Some(parse_height(height)?),
+        };
+
+        let res = self
+            .client
+            .abci_query(Some(path), data, height, prove)
+            .await
+            .map_err(tendermint_error)?;
+
+        Ok(Response::new(AbciQueryResponse {
+            code: res.code.value(),
+            log: res.log.to_string(),
+            info: res.info,
+            index: res.index,
+            key: res.key,
+            value: res.value,
+            proof_ops: res.proof.map(|proof| ProofOps {
+                ops: proof
+                    .ops
+                    .into_iter()
+                    .map(|op| ProofOp {
+                        r#type: op.field_type,
+                        key: op.key,
+                        data: op.data,
+                    })
+                    .collect(),
+            }),
+            height: res.height.into(),
+            codespace: res.codespace,
+        }))
     }
 
     async fn get_node_info(
         &self,
         _request: Request<GetNodeInfoRequest>,
     ) -> Result<Response<GetNodeInfoResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_node_info");
+        let res = self.client.status().await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetNodeInfoResponse {
+            default_node_info: Some(DefaultNodeInfo::from(res.node_info).into()),
+            application_version: Some(VersionInfo {
+                name: AI::APP_NAME.to_owned(),
+                app_name: AI::APP_NAME.to_owned(),
+                version: AI::APP_VERSION.to_owned(),
+                ..Default::default()
+            }),
+        }))
     }
 
     async fn get_syncing(
         &self,
         _request: Request<GetSyncingRequest>,
     ) -> Result<Response<GetSyncingResponse>, Status> {
-        //TODO: query the node for syncing status and return it
         info!("Received a gRPC request health::get_syncing");
-        Ok(Response::new(GetSyncingResponse { syncing: false }))
+        let res = self.client.status().await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetSyncingResponse {
+            syncing: res.sync_info.catching_up,
+        }))
     }
 
     async fn get_latest_block(
         &self,
         _request: Request<GetLatestBlockRequest>,
     ) -> Result<Response<GetLatestBlockResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_latest_block");
+        let res = self.client.latest_block().await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetLatestBlockResponse {
+            block_id: Some(res.block_id.into()),
+            block: Some(res.block.into()),
+        }))
     }
 
     async fn get_block_by_height(
         &self,
-        _request: Request<GetBlockByHeightRequest>,
+        request: Request<GetBlockByHeightRequest>,
     ) -> Result<Response<GetBlockByHeightResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_block_by_height");
+        let height = parse_height(request.into_inner().height)?;
+        let res = self.client.block(height).await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetBlockByHeightResponse {
+            block_id: Some(res.block_id.into()),
+            block: Some(res.block.into()),
+        }))
     }
 
     async fn get_latest_validator_set(
         &self,
-        _request: Request<GetLatestValidatorSetRequest>,
+        request: Request<GetLatestValidatorSetRequest>,
     ) -> Result<Response<GetLatestValidatorSetResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_latest_validator_set");
+        let (block_height, validators, pagination) = self
+            .validator_set(None, request.into_inner().pagination)
+            .await?;
+
+        Ok(Response::new(GetLatestValidatorSetResponse {
+            block_height,
+            validators,
+            pagination: pagination.map(Into::into),
+        }))
     }
 
     async fn get_validator_set_by_height(
         &self,
-        _request: Request<GetValidatorSetByHeightRequest>,
+        request: Request<GetValidatorSetByHeightRequest>,
     ) -> Result<Response<GetValidatorSetByHeightResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_validator_set_by_height");
+        let GetValidatorSetByHeightRequest { height, pagination } = request.into_inner();
+        let (block_height, validators, pagination) = self
+            .validator_set(Some(parse_height(height)?), pagination)
+            .await?;
+
+        Ok(Response::new(GetValidatorSetByHeightResponse {
+            block_height,
+            validators,
+            pagination: pagination.map(Into::into),
+        }))
     }
 }
 
-pub fn health_server() -> HealthServer<GearsHealthService> {
-    HealthServer::new(GearsHealthService)
+fn parse_height(height: i64) -> Result<Height, Status> {
+    Height::try_from(height)
+        .map_err(|_| Status::invalid_argument(format!("invalid block height: {height}")))
+}
+
+fn tendermint_error(e: tendermint::rpc::error::Error) -> Status {
+    tracing::error!("Error connecting to Tendermint: {e}");
+    Status::unavailable("Error connecting to Tendermint")
+}
+
+pub fn health_server<AI: ApplicationInfo>(
+    tendermint_rpc_address: HttpClientUrl,
+) -> HealthServer<GearsHealthService<AI>> {
+    let client = HttpClient::new::<Url>(tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
+
+    HealthServer::new(GearsHealthService {
+        client,
+        _phantom: PhantomData,
+    })
 }
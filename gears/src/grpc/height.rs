@@ -0,0 +1,47 @@
+use tonic::{Request, Status};
+
+/// gRPC metadata key used to query the state at a past block height
+pub const BLOCK_HEIGHT_METADATA: &str = "x-cosmos-block-height";
+
+/// Height requested through the `x-cosmos-block-height` metadata, `0` (latest) if missing
+pub fn block_height<T>(request: &Request<T>) -> Result<u32, Status> {
+    match request.metadata().get(BLOCK_HEIGHT_METADATA) {
+        Some(value) => value
+            .to_str()
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| {
+                Status::invalid_argument(format!(
+                    "{BLOCK_HEIGHT_METADATA} metadata must be a valid block height"
+                ))
+            }),
+        None => Ok(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_height_defaults_to_latest() {
+        assert_eq!(block_height(&Request::new(())).unwrap(), 0);
+    }
+
+    #[test]
+    fn block_height_from_metadata() {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_METADATA, "42".parse().unwrap());
+        assert_eq!(block_height(&request).unwrap(), 42);
+
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_METADATA, "latest".parse().unwrap());
+        assert_eq!(
+            block_height(&request).unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+}
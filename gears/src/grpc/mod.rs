@@ -7,8 +7,12 @@ use crate::runtime::runtime;
 
 mod error;
 pub mod health;
+mod height;
+pub mod service;
 pub mod tx;
 
+pub use height::*;
+
 pub fn run_grpc_server(router: Router<Identity>, listen_addr: SocketAddr) {
     std::thread::spawn(move || {
         let result = runtime().block_on(launch(router, listen_addr));
@@ -0,0 +1,72 @@
+//! Helpers to serve query services without a `tonic` generated server, i.e. modules whose
+//! protobuf definitions are declared in this repository and not in `ibc_proto`.
+
+use std::convert::Infallible;
+
+use tonic::{
+    body::{empty_body, BoxBody},
+    codec::ProstCodec,
+    codegen::{http, Body, BoxFuture, StdError},
+    server::{Grpc, UnaryService},
+    Code, Request, Response, Status,
+};
+
+use super::height::block_height;
+
+/// Decodes the unary request of type `Req`, answers with `handler` called with the decoded
+/// request and the height of its `x-cosmos-block-height` metadata, then encodes the response.
+pub fn unary<B, Req, Res, F>(
+    request: http::Request<B>,
+    handler: F,
+) -> BoxFuture<http::Response<BoxBody>, Infallible>
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+    Req: prost::Message + Default + Send + 'static,
+    Res: prost::Message + Send + 'static,
+    F: FnOnce(Req, u32) -> Result<Res, Status> + Send + 'static,
+{
+    Box::pin(async move {
+        let mut grpc = Grpc::new(ProstCodec::<Res, Req>::default());
+        Ok(grpc.unary(UnaryHandler(Some(handler)), request).await)
+    })
+}
+
+/// Maps the error of a raw request conversion to the `InvalidArgument` status
+pub fn invalid_argument(e: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(e.to_string())
+}
+
+/// Answers with the `Unimplemented` status, for paths which don't belong to the service
+pub fn unimplemented() -> BoxFuture<http::Response<BoxBody>, Infallible> {
+    Box::pin(async move {
+        let response = http::Response::builder()
+            .status(200)
+            .header("grpc-status", (Code::Unimplemented as i32).to_string())
+            .header(http::header::CONTENT_TYPE, "application/grpc")
+            .body(empty_body())
+            .expect("static response parts are valid");
+        Ok(response)
+    })
+}
+
+struct UnaryHandler<F>(Option<F>);
+
+impl<Req, Res, F> UnaryService<Req> for UnaryHandler<F>
+where
+    Res: Send + 'static,
+    F: FnOnce(Req, u32) -> Result<Res, Status>,
+{
+    type Response = Res;
+    type Future = std::future::Ready<Result<Response<Res>, Status>>;
+
+    fn call(&mut self, request: Request<Req>) -> Self::Future {
+        let result = match (self.0.take(), block_height(&request)) {
+            (Some(handler), Ok(height)) => handler(request.into_inner(), height).map(Response::new),
+            (None, _) => Err(Status::internal("the unary handler is called only once")),
+            (_, Err(status)) => Err(status),
+        };
+
+        std::future::ready(result)
+    }
+}
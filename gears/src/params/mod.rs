@@ -67,6 +67,33 @@ pub trait ParamsSerialize {
 
 pub trait ParamsDeserialize: ParamsSerialize {
     fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self;
+
+    /// Validate raw value of a single field before it gets written to the store.
+    /// Any value accepted here must be readable by `from_raw` without panic.
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError>;
+}
+
+/// Subspace key of application which knows the params stored in each subspace.
+/// Used to validate raw changes of params which come without the structure, like gov proposals.
+pub trait ParamsSubspaceValidation: ParamsSubspaceKey {
+    fn validate_raw(&self, key: &str, value: &[u8]) -> Result<(), ParamsValidationError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParamsValidationError {
+    #[error("unknown param key: {0}")]
+    UnknownKey(String),
+    #[error("invalid value of param `{key}`: {reason}")]
+    InvalidValue { key: String, reason: String },
+}
+
+impl ParamsValidationError {
+    pub fn invalid_value(key: impl Into<String>, reason: impl ToString) -> Self {
+        Self::InvalidValue {
+            key: key.into(),
+            reason: reason.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -121,4 +148,47 @@ impl ParamKind {
             ParamKind::I8 => Params::I8(parse_primitive_bytes(bytes)),
         }
     }
+
+    /// Same as [`ParamKind::parse_param`], but returns `None` instead of panic
+    /// if value doesn't match the kind
+    pub fn try_parse_param(self, bytes: Vec<u8>) -> Option<Params> {
+        fn try_parse_primitive_bytes<T: FromStr>(value: &[u8]) -> Option<T> {
+            let value = std::str::from_utf8(value).ok()?;
+
+            value
+                .strip_suffix('\"')
+                .and_then(|this| this.strip_prefix('\"'))
+                .unwrap_or(value)
+                .parse()
+                .ok()
+        }
+
+        match self {
+            ParamKind::Bytes | ParamKind::String | ParamKind::Bool => {
+                match self.parse_param(bytes) {
+                    Params::InvalidCast(_) => None,
+                    param => Some(param),
+                }
+            }
+            ParamKind::U64 => try_parse_primitive_bytes(&bytes).map(Params::U64),
+            ParamKind::I64 => try_parse_primitive_bytes(&bytes).map(Params::I64),
+            ParamKind::U32 => try_parse_primitive_bytes(&bytes).map(Params::U32),
+            ParamKind::I32 => try_parse_primitive_bytes(&bytes).map(Params::I32),
+            ParamKind::U16 => try_parse_primitive_bytes(&bytes).map(Params::U16),
+            ParamKind::I16 => try_parse_primitive_bytes(&bytes).map(Params::I16),
+            ParamKind::U8 => try_parse_primitive_bytes(&bytes).map(Params::U8),
+            ParamKind::I8 => try_parse_primitive_bytes(&bytes).map(Params::I8),
+        }
+    }
+
+    /// Check that raw value of the param could be parsed as this kind
+    pub fn validate(self, key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match self.try_parse_param(value.to_vec()) {
+            Some(_) => Ok(()),
+            None => Err(ParamsValidationError::invalid_value(
+                key,
+                format!("expected {self:?}"),
+            )),
+        }
+    }
 }
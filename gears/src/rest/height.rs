@@ -0,0 +1,32 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+use super::error::HTTPError;
+
+/// Header used by the Cosmos SDK gateway to query the state at a past height
+pub const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+
+/// Height set with the `x-cosmos-block-height` header, `0` (the latest height) if the header
+/// is missing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockHeight(pub u32);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for BlockHeight {
+    type Rejection = HTTPError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        match parts.headers.get(BLOCK_HEIGHT_HEADER) {
+            Some(value) => value
+                .to_str()
+                .ok()
+                .and_then(|height| height.parse().ok())
+                .map(BlockHeight)
+                .ok_or_else(|| {
+                    HTTPError::bad_request(format!(
+                        "{BLOCK_HEIGHT_HEADER} header must be a valid block height"
+                    ))
+                }),
+            None => Ok(BlockHeight::default()),
+        }
+    }
+}
@@ -47,6 +47,16 @@ pub struct RestState<QReq, QRes, App: NodeQueryHandler<QReq, QRes>> {
     phantom: PhantomData<(QReq, QRes)>,
 }
 
+impl<QReq, QRes, App: NodeQueryHandler<QReq, QRes>> RestState<QReq, QRes, App> {
+    pub fn new(app: App, tendermint_rpc_address: HttpClientUrl) -> Self {
+        Self {
+            app,
+            tendermint_rpc_address,
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<QReq, QRes, App: NodeQueryHandler<QReq, QRes>> FromRef<RestState<QReq, QRes, App>>
     for HttpClientUrl
 {
@@ -74,11 +84,7 @@ async fn launch<
         .allow_methods([Method::GET, Method::POST])
         .allow_origin(Any);
 
-    let rest_state = RestState {
-        app,
-        tendermint_rpc_address,
-        phantom: PhantomData,
-    };
+    let rest_state = RestState::new(app, tendermint_rpc_address);
 
     let app = Router::new()
         .route("/cosmos/base/tendermint/v1beta1/health", get(health))
@@ -19,6 +19,19 @@ pub trait MetadataGetter {
     fn metadata(&self, denom: &Denom) -> Result<Option<Metadata>, Self::Error>;
 }
 
+/// Metadata getter which has no metadata for any denom, so amounts are rendered in
+/// their base denom.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoneMetadataGetter;
+
+impl MetadataGetter for NoneMetadataGetter {
+    type Error = std::convert::Infallible;
+
+    fn metadata(&self, _denom: &Denom) -> Result<Option<Metadata>, Self::Error> {
+        Ok(None)
+    }
+}
+
 #[derive(Debug)]
 pub struct SignModeHandler;
 
@@ -53,8 +66,14 @@ impl SignModeHandler {
 
 #[cfg(test)]
 mod tests {
-    use crate::signing::handler::SignModeHandler;
-    use crate::signing::renderer::test_functions::{TestMetadataGetter, TestNoneMetadataGetter};
+    use crate::crypto::public::PublicKey;
+    use crate::signing::handler::{MetadataGetter, NoneMetadataGetter, SignModeHandler};
+    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::tx::Envelope;
+    use crate::signing::renderer::value_renderer::{
+        nested_screens, DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+        ValueRenderer,
+    };
     use crate::types::address::AccAddress;
     use crate::types::denom::Denom;
     use crate::types::{
@@ -66,9 +85,11 @@ mod tests {
             screen::{Content, Indent, Screen},
         },
         signing::SignerInfo,
-        tx::{body::TxBody, signer::SignerData},
+        tx::{body::TxBody, signer::SignerData, TxMessage},
     };
     use ciborium::Value;
+    use core_types::any::google::Any;
+    use core_types::errors::CoreError;
     use core_types::tx::mode_info::{ModeInfo, SignMode};
     use cosmwasm_std::Uint256;
     use extensions::testing::UnwrapTesting;
@@ -329,8 +350,7 @@ mod tests {
 
         let handler = SignModeHandler;
 
-        let cbor =
-            handler.sign_bytes_get(&TestNoneMetadataGetter, signer_data, &tx_body, &auth_inf)?;
+        let cbor = handler.sign_bytes_get(&NoneMetadataGetter, signer_data, &tx_body, &auth_inf)?;
 
         let expected = [
             161u8, 1, 142, 162, 1, 104, 67, 104, 97, 105, 110, 32, 105, 100, 2, 106, 116, 101, 115,
@@ -431,8 +451,7 @@ mod tests {
 
         let handler = SignModeHandler;
 
-        let cbor =
-            handler.sign_bytes_get(&TestNoneMetadataGetter, signer_data, &tx_body, &auth_inf)?;
+        let cbor = handler.sign_bytes_get(&NoneMetadataGetter, signer_data, &tx_body, &auth_inf)?;
 
         let expected = [
             161, 1, 142, 162, 1, 104, 67, 104, 97, 105, 110, 32, 105, 100, 2, 106, 116, 101, 115,
@@ -473,4 +492,143 @@ mod tests {
 
         Ok(())
     }
+
+    /// Message with a public key nested into its screens, like `MsgCreateValidator`
+    #[derive(Debug, Clone, serde::Serialize)]
+    struct MsgRotateKey {
+        signer: AccAddress,
+        new_key: PublicKey,
+    }
+
+    impl From<MsgRotateKey> for Any {
+        fn from(msg: MsgRotateKey) -> Self {
+            Any {
+                type_url: "/test.v1.MsgRotateKey".to_string(),
+                value: serde_json::to_vec(&msg).expect("test message is serializable"),
+            }
+        }
+    }
+
+    impl TryFrom<Any> for MsgRotateKey {
+        type Error = CoreError;
+
+        fn try_from(_: Any) -> Result<Self, Self::Error> {
+            Err(CoreError::DecodeGeneral(
+                "test message is never decoded".to_string(),
+            ))
+        }
+    }
+
+    impl TxMessage for MsgRotateKey {
+        fn get_signers(&self) -> Vec<&AccAddress> {
+            vec![&self.signer]
+        }
+
+        fn type_url(&self) -> &'static str {
+            "/test.v1.MsgRotateKey"
+        }
+    }
+
+    impl ValueRenderer for MsgRotateKey {
+        fn format<MG: MetadataGetter>(
+            &self,
+            get_metadata: &MG,
+        ) -> Result<Vec<Screen>, RenderError> {
+            let mut screens = vec![Screen {
+                title: "Signer".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.signer.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            }];
+            screens.append(&mut nested_screens(
+                "New key",
+                ValueRenderer::format(&self.new_key, get_metadata)?,
+                2,
+            )?);
+
+            Ok(screens)
+        }
+    }
+
+    #[test]
+    fn test_tx_screens_with_nested_message_screens() -> anyhow::Result<()> {
+        let public_key: PublicKey = serde_json::from_str(
+            r#"{
+                "@type": "/cosmos.crypto.secp256k1.PubKey",
+                "key": "A7Jg0Wg+RHwI7CAkSbCjpfWFROGtYYkUlaBVxCT6UXJ4"
+            }"#,
+        )?;
+        let signer = AccAddress::from_bech32("cosmos12vrgunwvszgzpykdrqlx3m6puedvcajlxcyw8z")?;
+
+        let auth_info = AuthInfo {
+            signer_infos: vec![SignerInfo {
+                public_key: Some(public_key.clone()),
+                mode_info: ModeInfo::Single(SignMode::Textual),
+                sequence: 6,
+            }],
+            fee: Fee {
+                amount: None,
+                gas_limit: 200000_u64.try_into().expect("this is a valid gas limit"),
+                payer: None,
+                granter: String::new(),
+            },
+            tip: None,
+        };
+
+        let signer_data = SignerData {
+            address: signer.clone(),
+            chain_id: ChainId::from_str("test-chain").expect("this is a valid chain id"),
+            account_number: 8,
+            sequence: 6,
+            pub_key: public_key,
+        };
+
+        let tx_body = TxBody::<MsgRotateKey> {
+            messages: vec1![MsgRotateKey {
+                signer,
+                new_key: serde_json::from_str(
+                    r#"{
+                        "@type": "/cosmos.crypto.ed25519.PubKey",
+                        "key": "+uo5x4+nFiCBt2MuhVwT5XeMfj6ttkjY/JC6WyHb+rE="
+                    }"#,
+                )?,
+            }],
+            memo: "rotate".to_string(),
+            timeout_height: 10,
+            extension_options: Vec::new(),
+            non_critical_extension_options: Vec::new(),
+        };
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Chain id", "content": "test-chain" },
+            { "title": "Account number", "content": "8" },
+            { "title": "Sequence", "content": "6" },
+            { "title": "Address", "content": "cosmos12vrgunwvszgzpykdrqlx3m6puedvcajlxcyw8z", "expert": true },
+            { "title": "Public key", "content": "/cosmos.crypto.secp256k1.PubKey", "expert": true },
+            { "title": "Key", "content": "03B2 60D1 683E 447C 08EC 2024 49B0 A3A5 F585 44E1 AD61 8914 95A0 55C4 24FA 5172 78", "indent": 1, "expert": true },
+            { "title": "", "content": "This transaction has 1 Message" },
+            { "title": "Message (1/1)", "content": "/test.v1.MsgRotateKey", "indent": 1 },
+            { "title": "Signer", "content": "cosmos12vrgunwvszgzpykdrqlx3m6puedvcajlxcyw8z", "indent": 2 },
+            { "title": "New key", "content": "/cosmos.crypto.ed25519.PubKey", "indent": 2, "expert": true },
+            { "title": "Key", "content": "FAEA 39C7 8FA7 1620 81B7 632E 855C 13E5 778C 7E3E ADB6 48D8 FC90 BA5B 21DB FAB1", "indent": 3, "expert": true },
+            { "title": "", "content": "End of Message" },
+            { "title": "Memo", "content": "rotate" },
+            { "title": "Gas limit", "content": "200'000", "expert": true },
+            { "title": "Timeout height", "content": "10", "expert": true }
+        ]"#,
+        )?;
+
+        let screens = Envelope::new(signer_data, &tx_body, &auth_info)
+            .format(&NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        // hash of raw bytes depends on encoding of the test message so only its screen is checked
+        let (hash, screens) = screens.split_last().expect("tx has screens");
+        assert_eq!(expected_screens, screens);
+        assert_eq!("Hash of raw bytes", hash.title);
+        assert!(hash.expert);
+
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use serde_json::{Map, Value};
+
 #[cfg_attr(test, derive(PartialEq, Eq))]
 #[derive(thiserror::Error, Debug)]
 pub enum RenderError {
@@ -8,28 +10,155 @@ pub enum RenderError {
 /// A renderer for amino signature handler.
 pub trait AminoRenderer {
     /// Render a message into a compatible amino json struct.
-    fn render(&self) -> Result<serde_json::Map<String, serde_json::Value>, RenderError>;
+    fn render(&self) -> Result<Map<String, Value>, RenderError>;
 }
 
 impl<T: serde::Serialize> AminoRenderer for T {
-    fn render(&self) -> Result<serde_json::Map<String, serde_json::Value>, RenderError> {
-        let mut value: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(
+    fn render(&self) -> Result<Map<String, Value>, RenderError> {
+        let mut value: Map<String, Value> = serde_json::from_slice(
             &serde_json::to_vec(&self).map_err(|e| RenderError::Rendering(e.to_string()))?,
         )
         .map_err(|e| RenderError::Rendering(e.to_string()))?;
         value.remove("@type");
 
-        // fn filter_type(value: &mut serde_json::Map<String, serde_json::Value>) {
-        //     value.remove("@type");
-        //     // for (_k, v) in value.iter_mut() {
-        //     //     if v.is_object() {
-        //     //         filter_type(v.as_object_mut().expect("condition is checked"));
-        //     //     }
-        //     // }
-        // }
-        //
-        // filter_type(&mut value);
+        for (_, field) in value.iter_mut() {
+            amino_any(field);
+        }
 
         Ok(value)
     }
 }
+
+/// Legacy amino names of public keys which could be nested into messages.
+const AMINO_PUB_KEYS: [(&str, &str); 2] = [
+    (
+        "/cosmos.crypto.secp256k1.PubKey",
+        "tendermint/PubKeySecp256k1",
+    ),
+    ("/cosmos.crypto.ed25519.PubKey", "tendermint/PubKeyEd25519"),
+];
+
+/// Legacy amino names of gov proposal contents which are nested into messages as `Any`.
+const AMINO_CONTENTS: [(&str, &str); 5] = [
+    (
+        "/cosmos.params.v1beta1/TextProposal",
+        "cosmos-sdk/TextProposal",
+    ),
+    (
+        "/cosmos.params.v1beta1/ParameterChangeProposal",
+        "cosmos-sdk/ParameterChangeProposal",
+    ),
+    (
+        "/cosmos.upgrade.v1beta1/SoftwareUpgradeProposal",
+        "cosmos-sdk/SoftwareUpgradeProposal",
+    ),
+    (
+        "/cosmos.upgrade.v1beta1/CancelSoftwareUpgradeProposal",
+        "cosmos-sdk/CancelSoftwareUpgradeProposal",
+    ),
+    (
+        "/ibc.core.client.v1.ClientUpdateProposal",
+        "cosmos-sdk/ClientUpdateProposal",
+    ),
+];
+
+fn amino_name(names: &[(&str, &'static str)], map: &Map<String, Value>) -> Option<&'static str> {
+    let url = map.get("@type").and_then(Value::as_str)?;
+
+    names
+        .iter()
+        .find(|(type_url, _)| *type_url == url)
+        .map(|(_, name)| *name)
+}
+
+fn amino_value(name: &str, value: Value) -> Map<String, Value> {
+    let mut amino = Map::new();
+    amino.insert("type".to_owned(), Value::String(name.to_owned()));
+    amino.insert("value".to_owned(), value);
+    amino
+}
+
+/// Replaces nested `Any` values in protobuf json form with their amino form.
+/// Amino represents them as `{"type": <amino name>, "value": <value>}` where value of
+/// a public key is its base64 key and value of a proposal content is its fields.
+fn amino_any(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let (Some(name), Some(key)) = (amino_name(&AMINO_PUB_KEYS, map), map.get("key")) {
+                *map = amino_value(name, key.clone());
+            } else if let Some(name) = amino_name(&AMINO_CONTENTS, map) {
+                let mut fields = std::mem::take(map);
+                fields.remove("@type");
+
+                let mut fields = Value::Object(fields);
+                amino_any(&mut fields);
+                *map = amino_value(name, fields);
+            } else {
+                map.values_mut().for_each(amino_any)
+            }
+        }
+        Value::Array(array) => array.iter_mut().for_each(amino_any),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn render_nested_pub_key() -> anyhow::Result<()> {
+        let msg = json!({
+            "@type": "/cosmos.staking.v1beta1.MsgCreateValidator",
+            "pubkey": {
+                "@type": "/cosmos.crypto.ed25519.PubKey",
+                "key": "+uo5x4+nFiCBt2MuhVwT5XeMfj6ttkjY/JC6WyHb+rE="
+            },
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4"
+        });
+
+        let expected = json!({
+            "pubkey": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "+uo5x4+nFiCBt2MuhVwT5XeMfj6ttkjY/JC6WyHb+rE="
+            },
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4"
+        });
+
+        assert_eq!(Value::Object(msg.render()?), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_nested_proposal_content() -> anyhow::Result<()> {
+        let msg = json!({
+            "@type": "/cosmos.gov.v1beta1.MsgSubmitProposal",
+            "content": {
+                "@type": "/cosmos.params.v1beta1/TextProposal",
+                "description": "description",
+                "title": "title"
+            },
+            "initial_deposit": [{ "amount": "10", "denom": "uatom" }],
+            "proposer": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+        });
+
+        let expected = json!({
+            "content": {
+                "type": "cosmos-sdk/TextProposal",
+                "value": {
+                    "description": "description",
+                    "title": "title"
+                }
+            },
+            "initial_deposit": [{ "amount": "10", "denom": "uatom" }],
+            "proposer": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+        });
+
+        assert_eq!(Value::Object(msg.render()?), expected);
+
+        Ok(())
+    }
+}
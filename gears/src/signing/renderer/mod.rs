@@ -14,16 +14,6 @@ pub(super) mod test_functions {
         },
     };
 
-    pub struct TestNoneMetadataGetter;
-
-    impl MetadataGetter for TestNoneMetadataGetter {
-        type Error = std::io::Error; // this is not used here
-
-        fn metadata(&self, _denom: &Denom) -> Result<Option<Metadata>, Self::Error> {
-            Ok(None)
-        }
-    }
-
     pub struct TestMetadataGetter;
 
     impl MetadataGetter for TestMetadataGetter {
@@ -0,0 +1,74 @@
+use crate::{
+    crypto::ed25519::Ed25519PubKey,
+    signing::handler::MetadataGetter,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRenderer, ValueRenderer,
+};
+
+const TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+impl ValueRenderer for Ed25519PubKey {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(TYPE_URL)
+                    .expect("hard coded type URL is not empty"),
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(
+                    Vec::<u8>::from(self.clone()).as_slice(),
+                )?,
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::ed25519::Ed25519PubKey;
+    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::types::rendering::screen::{Content, Indent, Screen};
+
+    use crate::signing::renderer::value_renderer::ValueRenderer;
+
+    #[test]
+    fn ed25519_pubkey_formating() -> anyhow::Result<()> {
+        let key: Ed25519PubKey = serde_json::from_str(
+            r#"{
+            "@type": "/cosmos.crypto.ed25519.PubKey",
+            "key": "+uo5x4+nFiCBt2MuhVwT5XeMfj6ttkjY/JC6WyHb+rE="
+        }"#,
+        )?;
+
+        let expected_screens = vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: Content::try_new("/cosmos.crypto.ed25519.PubKey")?,
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: Content::try_new("FAEA 39C7 8FA7 1620 81B7 632E 855C 13E5 778C 7E3E ADB6 48D8 FC90 BA5B 21DB FAB1")?,
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ];
+
+        let actual_screens = ValueRenderer::format(&key, &TestMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
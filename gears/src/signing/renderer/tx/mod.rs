@@ -1,3 +1,4 @@
+pub mod ed25519_pubkey;
 mod envelope;
 pub mod public_key_enum;
 pub mod secp256_pubkey;
@@ -2,7 +2,7 @@
 
 use crate::{
     signing::handler::MetadataGetter,
-    types::rendering::screen::{Content, Screen},
+    types::rendering::screen::{Content, Indent, Screen},
 };
 
 /// Render primitive type into content for `Screen`.
@@ -39,6 +39,35 @@ pub trait ValueRenderer {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError>;
 }
 
+/// Nest screens of a value into a message field.
+/// The first screen gets the `title` of the field, all screens are shifted by `indent`
+/// levels and keep their expert mode.
+pub fn nested_screens(
+    title: &str,
+    screens: Vec<Screen>,
+    indent: u8,
+) -> Result<Vec<Screen>, RenderError> {
+    screens
+        .into_iter()
+        .enumerate()
+        .map(|(i, screen)| {
+            let level = screen.indent.map(Indent::into_inner).unwrap_or_default() + indent;
+
+            Ok(Screen {
+                title: match i {
+                    0 => title.to_owned(),
+                    _ => screen.title,
+                },
+                content: screen.content,
+                indent: Some(
+                    Indent::try_new(level).map_err(|e| RenderError::Rendering(e.to_string()))?,
+                ),
+                expert: screen.expert,
+            })
+        })
+        .collect()
+}
+
 /// Default implementation of `PrimitiveValueRenderer` for `Screen`. This is an attempt
 /// at a blanket implementation for all primitive types described in the Cosmos SDK:
 /// https://docs.cosmos.network/v0.50/build/architecture/adr-050-sign-mode-textual-annex1#bytes
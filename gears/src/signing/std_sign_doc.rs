@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::types::auth::fee::Fee;
+use crate::types::{
+    auth::fee::Fee,
+    tx::{body::TxBody, TxMessage},
+};
+
+use super::renderer::amino_renderer::{AminoRenderer, RenderError};
 
 // gears::core::base::coin::Coin has wrong order of fields
 // It is better to create a struct with correct order than
@@ -79,6 +84,41 @@ pub struct StdSignDoc {
 }
 
 impl StdSignDoc {
+    /// Builds the legacy amino json sign doc of a transaction for a signer with
+    /// `account_number` and `sequence`.
+    pub fn new<M: TxMessage + AminoRenderer>(
+        body: &TxBody<M>,
+        fee: Fee,
+        chain_id: impl Into<String>,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, RenderError> {
+        let msgs = body
+            .messages
+            .iter()
+            .map(|msg| {
+                Ok(Msg {
+                    kind: msg.amino_url().to_string(),
+                    value: msg.render()?,
+                })
+            })
+            .collect::<Result<Vec<_>, RenderError>>()?;
+
+        Ok(Self {
+            account_number: account_number.to_string(),
+            chain_id: chain_id.into(),
+            fee: fee.into(),
+            memo: body.memo.clone(),
+            msgs,
+            sequence: sequence.to_string(),
+            // amino json omits empty timeout height
+            timeout_height: match body.timeout_height {
+                0 => None,
+                height => Some(height.to_string()),
+            },
+        })
+    }
+
     pub fn to_sign_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
         serde_json::to_vec(self)
     }
@@ -88,10 +128,50 @@ impl StdSignDoc {
 mod test {
     use extensions::testing::UnwrapTesting;
 
-    use crate::crypto::secp256k1::Secp256k1PubKey;
+    use crate::{
+        crypto::secp256k1::Secp256k1PubKey,
+        types::{
+            address::AccAddress,
+            base::{coin::UnsignedCoin, coins::UnsignedCoins},
+            denom::Denom,
+            msg::send::MsgSend,
+        },
+    };
+    use cosmwasm_std::Uint256;
+    use vec1::vec1;
 
     use super::*;
 
+    #[test]
+    fn new_from_send_transaction() -> anyhow::Result<()> {
+        let uatom = |amount: u32| -> anyhow::Result<UnsignedCoins> {
+            Ok(UnsignedCoins::new(vec![UnsignedCoin {
+                denom: Denom::try_from("uatom".to_owned())?,
+                amount: Uint256::from(amount),
+            }])?)
+        };
+
+        let body = TxBody::new_with_defaults(vec1![MsgSend {
+            from_address: AccAddress::from_bech32("cosmos1rm96mrd64yykxyuprjlcxa4yr4llph0rpg27vy")?,
+            to_address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")?,
+            amount: uatom(1)?,
+        }]);
+        let fee = Fee {
+            amount: Some(uatom(2000)?),
+            gas_limit: 200000_u64.try_into().expect("this is a valid gas limit"),
+            payer: None,
+            granter: String::new(),
+        };
+
+        let std_sign_doc = StdSignDoc::new(&body, fee, "test-chain", 5, 0)?;
+
+        let expected: StdSignDoc = serde_json::from_str("{\"chain_id\":\"test-chain\",\"account_number\":\"5\",\"sequence\":\"0\",\"fee\":{\"gas\":\"200000\",\"amount\":[{\"amount\":\"2000\",\"denom\":\"uatom\"}]},\"msgs\":[{\"type\":\"cosmos-sdk/MsgSend\",\"value\":{\"from_address\":\"cosmos1rm96mrd64yykxyuprjlcxa4yr4llph0rpg27vy\",\"to_address\":\"cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux\",\"amount\":[{\"amount\":\"1\",\"denom\":\"uatom\"}]}}],\"memo\":\"\"}")?;
+
+        assert_eq!(std_sign_doc.to_sign_bytes()?, expected.to_sign_bytes()?);
+
+        Ok(())
+    }
+
     #[test]
     fn parse_verify_send_transaction() -> anyhow::Result<()> {
         let any_json_std_sign_doc_str = "{\"chain_id\":\"test-chain\",\"account_number\":\"5\",\"sequence\":\"0\",\"fee\":{\"gas\":\"200000\",\"amount\":[{\"amount\":\"2000\",\"denom\":\"uatom\"}]},\"msgs\":[{\"type\":\"cosmos-sdk/MsgSend\",\"value\":{\"from_address\":\"cosmos1rm96mrd64yykxyuprjlcxa4yr4llph0rpg27vy\",\"to_address\":\"cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux\",\"amount\":[{\"amount\":\"1\",\"denom\":\"uatom\"}]}}],\"memo\":\"\"}";
@@ -164,6 +164,46 @@ impl DecimalCoins {
         Self::new(coins)
     }
 
+    /// Multiplies each coin by a number truncating the result to the decimal precision.
+    /// Coins with zero amount are removed from the result.
+    pub fn checked_mul_dec_truncate_to_precision(
+        &self,
+        multiplier: Decimal256,
+    ) -> Result<Self, CoinsError> {
+        let mut coins = vec![];
+        for coin in self.inner().iter() {
+            let amount = coin
+                .amount
+                .checked_mul(multiplier)
+                .map_err(|_| CoinsError::InvalidAmount)?;
+            if !amount.is_zero() {
+                coins.push(DecimalCoin::new(amount, coin.denom.clone()));
+            }
+        }
+
+        Self::new(coins)
+    }
+
+    /// Divides each coin by a number truncating the result to the decimal precision.
+    /// Coins with zero amount are removed from the result.
+    pub fn checked_quo_dec_truncate_to_precision(
+        &self,
+        divider: Decimal256,
+    ) -> Result<Self, CoinsError> {
+        let mut coins = vec![];
+        for coin in self.inner().iter() {
+            let amount = coin
+                .amount
+                .checked_div(divider)
+                .map_err(|_| CoinsError::InvalidAmount)?;
+            if !amount.is_zero() {
+                coins.push(DecimalCoin::new(amount, coin.denom.clone()));
+            }
+        }
+
+        Self::new(coins)
+    }
+
     /// split the coins into two parts: unsigned truncated coins and decimal change. Returns None for
     /// the part that contains only zeros.
     pub fn truncate_decimal(&self) -> (Option<UnsignedCoins>, Option<DecimalCoins>) {
@@ -472,6 +512,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn checked_mul_dec_truncate_to_precision() -> anyhow::Result<()> {
+        let dec_coins = generate_coins(vec![100, 90]);
+        let dec_coins_mul_truncated = dec_coins.checked_mul_dec_truncate_to_precision(
+            Decimal256::from_atomics(31u64, 3).expect("hardcoded value can't fail"),
+        )?;
+        assert_eq!(
+            dec_coins_mul_truncated.inner(),
+            &vec![
+                DecimalCoin {
+                    denom: DENOMS.get().expect("cannot fail initialized variable")[0].clone(),
+                    amount: Decimal256::from_atomics(31u64, 1).unwrap_test(),
+                },
+                DecimalCoin {
+                    denom: DENOMS.get().expect("cannot fail initialized variable")[1].clone(),
+                    amount: Decimal256::from_atomics(279u64, 2).unwrap_test(),
+                },
+            ]
+        );
+
+        // 1 * 0.000000000000000001 * 0.1 is truncated to zero and removed
+        let dec_coins = DecimalCoins::new(vec![
+            DecimalCoin {
+                denom: DENOMS.get().expect("cannot fail initialized variable")[0].clone(),
+                amount: Decimal256::from_atomics(1u64, 0).unwrap_test(),
+            },
+            DecimalCoin {
+                denom: DENOMS.get().expect("cannot fail initialized variable")[1].clone(),
+                amount: Decimal256::from_atomics(1u64, 18).unwrap_test(),
+            },
+        ])
+        .unwrap_test();
+        let dec_coins_mul_truncated = dec_coins.checked_mul_dec_truncate_to_precision(
+            Decimal256::from_atomics(1u64, 1).expect("hardcoded value can't fail"),
+        )?;
+        assert_eq!(
+            dec_coins_mul_truncated.inner(),
+            &vec![DecimalCoin {
+                denom: DENOMS.get().expect("cannot fail initialized variable")[0].clone(),
+                amount: Decimal256::from_atomics(1u64, 1).unwrap_test(),
+            }]
+        );
+
+        let dec_coins_mul_truncated =
+            dec_coins.checked_mul_dec_truncate_to_precision(Decimal256::zero());
+        assert!(matches!(
+            dec_coins_mul_truncated,
+            Err(CoinsError::EmptyList)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn checked_quo_dec_truncate_to_precision() -> anyhow::Result<()> {
+        let dec_coins = generate_coins(vec![17, 12]);
+        let dec_coins_quo_truncated = dec_coins.checked_quo_dec_truncate_to_precision(
+            Decimal256::from_atomics(10u64, 0).expect("hardcoded value can't fail"),
+        )?;
+        assert_eq!(
+            dec_coins_quo_truncated.inner(),
+            &vec![
+                DecimalCoin {
+                    denom: DENOMS.get().expect("cannot fail initialized variable")[0].clone(),
+                    amount: Decimal256::from_atomics(17u64, 1).unwrap_test(),
+                },
+                DecimalCoin {
+                    denom: DENOMS.get().expect("cannot fail initialized variable")[1].clone(),
+                    amount: Decimal256::from_atomics(12u64, 1).unwrap_test(),
+                },
+            ]
+        );
+
+        let dec_coins_quo_truncated =
+            dec_coins.checked_quo_dec_truncate_to_precision(Decimal256::zero());
+        assert!(dec_coins_quo_truncated.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn truncate_decimal() -> anyhow::Result<()> {
         let dec_coins = generate_coins(vec![17]);
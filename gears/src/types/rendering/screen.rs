@@ -33,6 +33,10 @@ impl Indent {
     pub fn two() -> Indent {
         Indent::try_new(2).expect("indent is less than 16")
     }
+
+    pub fn three() -> Indent {
+        Indent::try_new(3).expect("indent is less than 16")
+    }
 }
 
 /// Screen is the abstract unit of Textual rendering.
@@ -0,0 +1,169 @@
+use core_types::{tx::mode_info::SignMode, Protobuf};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto::public::PublicKey,
+    types::{address::AccAddress, auth::tip::Tip},
+};
+
+use super::errors::TxError;
+
+mod inner {
+    pub use core_types::signing::AuxSignerData;
+    pub use core_types::signing::SignDocDirectAux;
+    pub use ibc_proto::cosmos::tx::v1beta1::mode_info::Single;
+}
+
+/// SignDocDirectAux is the type used for generating sign bytes for
+/// SIGN_MODE_DIRECT_AUX.
+///
+/// Since: cosmos-sdk 0.46
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignDocDirectAux {
+    /// body_bytes is protobuf serialization of a TxBody that matches the
+    /// representation in TxRaw.
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub body_bytes: Vec<u8>,
+    /// public_key is the public key of the signing account.
+    pub public_key: PublicKey,
+    /// chain_id is the identifier of the chain this transaction targets.
+    /// It prevents signed transactions from being used on another chain by an
+    /// attacker.
+    pub chain_id: String,
+    /// account_number is the account number of the account in state.
+    pub account_number: u64,
+    /// sequence is the sequence number of the signing account.
+    pub sequence: u64,
+    /// tip is the optional tip used for transactions fees paid in another denom.
+    /// It should be left empty if the signer is not the tipper for this
+    /// transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tip: Option<Tip>,
+}
+
+impl TryFrom<inner::SignDocDirectAux> for SignDocDirectAux {
+    type Error = TxError;
+
+    #[allow(deprecated)] // tip is deprecated in proto definitions, but still supported by gears
+    fn try_from(raw: inner::SignDocDirectAux) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body_bytes: raw.body_bytes,
+            public_key: raw
+                .public_key
+                .ok_or(TxError::MissingField("public_key".to_owned()))?
+                .try_into()
+                .map_err(|e: crate::crypto::public::DecodeError| {
+                    TxError::DecodeGeneral(e.to_string())
+                })?,
+            chain_id: raw.chain_id,
+            account_number: raw.account_number,
+            sequence: raw.sequence,
+            tip: raw
+                .tip
+                .map(Tip::try_from)
+                .transpose()
+                .map_err(|e| TxError::DecodeGeneral(e.to_string()))?,
+        })
+    }
+}
+
+impl From<SignDocDirectAux> for inner::SignDocDirectAux {
+    #[allow(deprecated)] // tip is deprecated in proto definitions, but still supported by gears
+    fn from(doc: SignDocDirectAux) -> Self {
+        Self {
+            body_bytes: doc.body_bytes,
+            public_key: Some(doc.public_key.into()),
+            chain_id: doc.chain_id,
+            account_number: doc.account_number,
+            sequence: doc.sequence,
+            tip: doc.tip.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<inner::SignDocDirectAux> for SignDocDirectAux {}
+
+/// AuxSignerData is the intermediary format that an auxiliary signer (e.g. a
+/// tipper) builds and sends to the fee payer (who will build and broadcast the
+/// actual tx).
+///
+/// Since: cosmos-sdk 0.46
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuxSignerData {
+    /// address is the bech32-encoded address of the auxiliary signer.
+    pub address: AccAddress,
+    /// sign_doc is the SIGN_MODE_DIRECT_AUX sign doc that the auxiliary signer
+    /// signs.
+    pub sign_doc: SignDocDirectAux,
+    /// mode is the signing mode of the single signer.
+    pub mode: SignMode,
+    /// sig is the signature of the sign doc.
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub sig: Vec<u8>,
+}
+
+impl TryFrom<inner::AuxSignerData> for AuxSignerData {
+    type Error = TxError;
+
+    fn try_from(raw: inner::AuxSignerData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: AccAddress::from_bech32(&raw.address)
+                .map_err(|e| TxError::DecodeGeneral(e.to_string()))?,
+            sign_doc: raw
+                .sign_doc
+                .ok_or(TxError::MissingField("sign_doc".to_owned()))?
+                .try_into()?,
+            mode: inner::Single { mode: raw.mode }.try_into()?,
+            sig: raw.sig,
+        })
+    }
+}
+
+impl From<AuxSignerData> for inner::AuxSignerData {
+    fn from(data: AuxSignerData) -> Self {
+        Self {
+            address: data.address.to_string(),
+            sign_doc: Some(data.sign_doc.into()),
+            mode: data.mode as i32,
+            sig: data.sig,
+        }
+    }
+}
+
+impl Protobuf<inner::AuxSignerData> for AuxSignerData {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aux_signer_data_protobuf_roundtrip() -> anyhow::Result<()> {
+        let data: AuxSignerData = serde_json::from_str(
+            r#"{
+            "address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "sign_doc": {
+                "body_bytes": "CgRib2R5",
+                "public_key": {
+                    "@type": "/cosmos.crypto.secp256k1.PubKey",
+                    "key": "Auvdf+T963bciiBe9l15DNMOijdaXCUo6zqSOvH7TXlN"
+                },
+                "chain_id": "test-chain",
+                "account_number": 1,
+                "sequence": 2,
+                "tip": {
+                    "amount": [{ "denom": "uatom", "amount": "10" }],
+                    "tipper": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+                }
+            },
+            "mode": "DirectAux",
+            "sig": "c2lnbmF0dXJl"
+        }"#,
+        )?;
+
+        let decoded = AuxSignerData::decode_vec(&data.encode_vec())?;
+
+        assert_eq!(data, decoded);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 pub mod data;
+pub mod direct_aux;
 pub mod errors;
 pub mod metadata;
 pub mod signer;
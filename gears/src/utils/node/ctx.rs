@@ -12,7 +12,7 @@ use kv_store::{
 use tendermint::types::proto::header::Header;
 
 use crate::{
-    baseapp::{options::NodeOptions, ConsensusParams},
+    baseapp::{options::NodeOptions, ConsensusParams, GasScheduleParams},
     context::{init::InitContext, tx::TxContext},
 };
 
@@ -25,6 +25,7 @@ pub struct ContextOptions {
     height: u32,
     header: Header,
     consensus_params: ConsensusParams,
+    gas_schedule: GasScheduleParams,
     gas_meter: GasMeter<TxKind>,
     options: NodeOptions,
 }
@@ -38,6 +39,7 @@ pub fn build_tx_ctx<'a, DB, SK>(
         height,
         header,
         consensus_params,
+        gas_schedule,
         gas_meter,
         options,
     } = opt.into();
@@ -46,6 +48,7 @@ pub fn build_tx_ctx<'a, DB, SK>(
         height,
         header,
         consensus_params,
+        gas_schedule,
         gas_meter,
         block_gas_meter,
         options,
@@ -9,9 +9,10 @@ use vec1::Vec1;
 
 use crate::{
     crypto::info::SigningInfo,
+    signing::renderer::amino_renderer::AminoRenderer,
     types::{
-        auth::fee::Fee,
-        base::coins::Coins,
+        auth::{fee::Fee, tip::Tip},
+        base::coins::{Coins, UnsignedCoins},
         tx::{body::TxBody, Tx, TxMessage},
     },
 };
@@ -30,18 +31,33 @@ pub fn generate_tx<M: TxMessage>(
     user: &User,
     chain_id: ChainId,
 ) -> Bytes {
-    let fee = Fee {
-        amount: Some(
-            Coins::new(vec!["1uatom".parse().expect("hard coded coin is valid")])
-                .expect("hard coded coins are valid"),
-        ),
-        gas_limit: 200_000_u64
-            .try_into()
-            .expect("hard coded gas limit is valid"),
-        payer: None,
-        granter: "".into(),
+    let signing_info = SigningInfo {
+        key: &user.key_pair,
+        sequence,
+        account_number: user.account_number,
     };
 
+    let body = TxBody::new_with_defaults(msgs);
+
+    let tx = crate::crypto::info::create_signed_transaction_direct(
+        vec![signing_info],
+        chain_id.to_owned(),
+        fee(),
+        None,
+        body,
+    )
+    .unwrap_infallible();
+
+    encode_tx(tx)
+}
+
+/// Same as [`generate_tx`], but the tx is signed with SIGN_MODE_LEGACY_AMINO_JSON
+pub fn generate_amino_json_tx<M: TxMessage + AminoRenderer>(
+    msgs: Vec1<M>,
+    sequence: u64,
+    user: &User,
+    chain_id: ChainId,
+) -> Bytes {
     let signing_info = SigningInfo {
         key: &user.key_pair,
         sequence,
@@ -50,20 +66,79 @@ pub fn generate_tx<M: TxMessage>(
 
     let body = TxBody::new_with_defaults(msgs);
 
-    let Tx {
-        body,
-        auth_info,
-        signatures,
-        signatures_data: _,
-    } = crate::crypto::info::create_signed_transaction_direct(
+    let tx = crate::crypto::info::create_signed_transaction_amino_json(
         vec![signing_info],
         chain_id.to_owned(),
-        fee.to_owned(),
+        fee(),
         None,
         body,
     )
+    .expect("messages of a test tx are renderable");
+
+    encode_tx(tx)
+}
+
+/// Tx with messages signed by `aux_signer` with SIGN_MODE_DIRECT_AUX and completed by
+/// `fee_payer`, who pays the fees and receives the `tip`
+pub fn generate_aux_tx<M: TxMessage>(
+    msgs: Vec1<M>,
+    tip: Option<UnsignedCoins>,
+    (aux_signer, aux_sequence): (&User, u64),
+    (fee_payer, fee_payer_sequence): (&User, u64),
+    chain_id: ChainId,
+) -> Tx<M> {
+    let body = TxBody::new_with_defaults(msgs);
+
+    let aux_signer_data = crate::crypto::info::create_aux_signer_data(
+        SigningInfo {
+            key: &aux_signer.key_pair,
+            sequence: aux_sequence,
+            account_number: aux_signer.account_number,
+        },
+        chain_id,
+        tip.map(|amount| Tip {
+            amount: Some(amount),
+            tipper: aux_signer.address(),
+        }),
+        body,
+    )
     .unwrap_infallible();
 
+    crate::crypto::info::create_signed_transaction_from_aux(
+        aux_signer_data,
+        SigningInfo {
+            key: &fee_payer.key_pair,
+            sequence: fee_payer_sequence,
+            account_number: fee_payer.account_number,
+        },
+        fee(),
+    )
+    .expect("aux signer data is valid")
+}
+
+fn fee() -> Fee {
+    Fee {
+        amount: Some(
+            Coins::new(vec!["1uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        ),
+        gas_limit: 200_000_u64
+            .try_into()
+            .expect("hard coded gas limit is valid"),
+        payer: None,
+        granter: "".into(),
+    }
+}
+
+/// Encodes the tx into raw bytes which are delivered to the node
+pub fn encode_tx<M: TxMessage>(
+    Tx {
+        body,
+        auth_info,
+        signatures,
+        signatures_data: _,
+    }: Tx<M>,
+) -> Bytes {
     core_types::tx::raw::TxRaw {
         body_bytes: body.encode_vec(),
         auth_info_bytes: auth_info.encode_vec(),
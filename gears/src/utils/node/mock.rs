@@ -8,7 +8,7 @@ use tendermint::{
             block::BlockId,
             consensus::{Consensus, ConsensusParams},
             header::{Header, PartSetHeader},
-            info::LastCommitInfo,
+            info::{Evidence, LastCommitInfo},
             validator::ValidatorUpdate,
         },
         request::{
@@ -16,9 +16,16 @@ use tendermint::{
             end_block::RequestEndBlock, init_chain::RequestInitChain, query::RequestQuery,
         },
         response::{deliver_tx::ResponseDeliverTx, query::ResponseQuery},
-        time::timestamp::Timestamp,
+        time::{duration::Duration, timestamp::Timestamp},
     },
 };
+pub(super) const DEFAULT_PROPOSER_ADDRESS: [u8; 20] = [
+    139, 66, 235, 161, 172, 24, 201, 229, 172, 156, 56, 187, 215, 206, 138, 87, 207, 173, 214, 85,
+];
+
+/// Time between the blocks produced by `skip_steps`
+pub const BLOCK_INTERVAL: Duration = Duration::new_from_secs(5);
+
 #[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
 pub struct InitState<G> {
     pub time: Timestamp,
@@ -51,6 +58,8 @@ pub struct MockNode<App, G> {
     time: Timestamp,
     last_block_id: BlockId,
     // last_header: Header,
+    genesis_validators: Vec<ValidatorUpdate>,
+    last_validator_updates: Vec<ValidatorUpdate>,
     _phantom: std::marker::PhantomData<G>,
 }
 
@@ -74,6 +83,12 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
 
         let res = app.init_chain(init_state.clone().into());
 
+        // like tendermint, validators of the request are used if the app doesn't set any
+        let genesis_validators = match res.validators.is_empty() {
+            true => init_state.validators,
+            false => res.validators,
+        };
+
         Self {
             app,
             app_hash: res.app_hash,
@@ -87,6 +102,8 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
                     hash: vec![],
                 }),
             },
+            genesis_validators,
+            last_validator_updates: vec![],
             _phantom: Default::default(),
         }
     }
@@ -96,19 +113,50 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
     }
 
     pub fn step(&mut self, txs: Vec<Bytes>, block_time: Timestamp) -> StepResponse {
+        self.step_with_commit_info(
+            txs,
+            block_time,
+            DEFAULT_PROPOSER_ADDRESS.to_vec(),
+            LastCommitInfo {
+                round: 0,
+                votes: vec![],
+            },
+        )
+    }
+
+    /// Same as `step` but allows to set the proposer of the block and the votes of the
+    /// previous block which are passed to the application in `begin_block`.
+    pub fn step_with_commit_info(
+        &mut self,
+        txs: Vec<Bytes>,
+        block_time: Timestamp,
+        proposer_address: Vec<u8>,
+        last_commit_info: LastCommitInfo,
+    ) -> StepResponse {
+        self.step_with_evidence(txs, block_time, proposer_address, last_commit_info, vec![])
+    }
+
+    /// Same as `step_with_commit_info` but also reports misbehaving validators to the
+    /// application in `begin_block`.
+    pub fn step_with_evidence(
+        &mut self,
+        txs: Vec<Bytes>,
+        block_time: Timestamp,
+        proposer_address: Vec<u8>,
+        last_commit_info: LastCommitInfo,
+        byzantine_validators: Vec<Evidence>,
+    ) -> StepResponse {
         self.height += 1;
         self.time = block_time;
-        let header = self.calculate_header();
+        let mut header = self.calculate_header();
+        header.proposer_address = proposer_address;
 
         // TODO: update last_block_id
 
         let request_begin_block = RequestBeginBlock {
             header,
-            last_commit_info: LastCommitInfo {
-                round: 0,
-                votes: vec![],
-            },
-            byzantine_validators: vec![],
+            last_commit_info,
+            byzantine_validators,
             hash:  b"\xaaw\xbd^\x9d\x041\xfdc\x17\x11\x82\xb9iU\xde2\xd0\x19\xca\xdeV\x0e\x7fK\x1c\x88\xb6\xa3\xe3\x8b\x89".as_slice().into(),
         };
         self.app.begin_block(request_begin_block);
@@ -118,13 +166,14 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             tx_responses.push(self.app.deliver_tx(RequestDeliverTx { tx }));
         }
 
-        self.app.end_block(RequestEndBlock {
+        let res_end_block = self.app.end_block(RequestEndBlock {
             height: self.height as i64,
         });
 
         let res_commit = self.app.commit();
 
         self.app_hash = res_commit.data;
+        self.last_validator_updates = res_end_block.validator_updates;
 
         StepResponse {
             app_hash: self.app_hash.clone(),
@@ -181,10 +230,7 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
                 174, 65, 228, 100, 155, 147, 76, 164, 149, 153, 27, 120, 82, 184, 85,
             ],
             //TODO: need to calculate this
-            proposer_address: vec![
-                139, 66, 235, 161, 172, 24, 201, 229, 172, 156, 56, 187, 215, 206, 138, 87, 207,
-                173, 214, 85,
-            ],
+            proposer_address: DEFAULT_PROPOSER_ADDRESS.to_vec(),
         }
     }
 
@@ -192,13 +238,36 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         &self.chain_id
     }
 
+    /// Produces `steps` empty blocks, each one [BLOCK_INTERVAL] after the previous one
     pub fn skip_steps(&mut self, steps: usize) {
         for _ in 0..steps {
-            let _ = self.step(vec![], Timestamp::UNIX_EPOCH);
+            let time = self.next_block_time();
+            let _ = self.step(vec![], time);
         }
     }
 
     pub fn height(&self) -> u32 {
         self.height
     }
+
+    /// Time of the last block or genesis time before the first one
+    pub fn time(&self) -> Timestamp {
+        self.time
+    }
+
+    pub(super) fn next_block_time(&self) -> Timestamp {
+        self.time
+            .checked_add(BLOCK_INTERVAL)
+            .expect("block time is far from the maximum")
+    }
+
+    /// Validators of the first block as set by `init_chain`
+    pub fn genesis_validators(&self) -> &[ValidatorUpdate] {
+        &self.genesis_validators
+    }
+
+    /// Validator updates returned by `end_block` of the last step
+    pub fn last_validator_updates(&self) -> &[ValidatorUpdate] {
+        &self.last_validator_updates
+    }
 }
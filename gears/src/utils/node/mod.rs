@@ -1,6 +1,7 @@
 mod ctx;
 mod helpers;
 mod mock;
+mod network;
 mod presets;
 
 use address::AccAddress;
@@ -9,6 +10,7 @@ use keyring::key::pair::KeyPair;
 pub use ctx::*;
 pub use helpers::*;
 pub use mock::*;
+pub use network::*;
 pub use presets::*;
 
 use crate::{application::ApplicationInfo, crypto::keys::ReadAccAddress};
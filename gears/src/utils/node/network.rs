@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+
+use address::{ConsAddress, ValAddress};
+use bytes::Bytes;
+use tendermint::{
+    application::ABCIApplication,
+    types::{
+        chain_id::ChainId,
+        proto::{
+            info::{Evidence, EvidenceType, LastCommitInfo, VoteInfo},
+            validator::{Validator, ValidatorUpdate},
+        },
+        request::query::RequestQuery,
+        response::query::ResponseQuery,
+        time::timestamp::Timestamp,
+    },
+};
+
+use super::{mock::DEFAULT_PROPOSER_ADDRESS, InitState, MockNode, StepResponse};
+
+/// Validators and time of a committed block, kept to report misbehavior at past heights
+#[derive(Debug, Clone)]
+struct BlockInfo {
+    time: Timestamp,
+    validators: Vec<Validator>,
+}
+
+/// In-process network of several nodes which run the same application. Every node
+/// receives the same blocks and the network asserts that all of them come to the same
+/// app hash, tx results and validator updates after each block.
+///
+/// The validator set follows the updates returned by the application the same way
+/// tendermint does: updates returned in `end_block` of height `H` take effect at `H + 2`.
+/// Each block is proposed by the next validator of the set in a round-robin order.
+#[derive(Debug)]
+pub struct MockNetwork<App, G> {
+    nodes: Vec<MockNode<App, G>>,
+    /// validators of the latest block which sign it in the next one
+    last_validators: Vec<Validator>,
+    /// validators of the next block
+    validators: Vec<Validator>,
+    /// validators of the block after the next one
+    next_validators: Vec<Validator>,
+    /// validators which don't sign blocks
+    offline: HashSet<ValAddress>,
+    /// evidence of misbehavior reported in the next block
+    evidence: Vec<Evidence>,
+    history: Vec<BlockInfo>,
+}
+
+impl<G: Clone, App: ABCIApplication<G>> MockNetwork<App, G> {
+    /// Initializes every app with the same `init_state`
+    pub fn new(apps: impl IntoIterator<Item = App>, init_state: InitState<G>) -> Self {
+        let nodes: Vec<_> = apps
+            .into_iter()
+            .map(|app| MockNode::new(app, init_state.clone()))
+            .collect();
+
+        let genesis_validators = match nodes.first() {
+            Some(node) => node.genesis_validators().to_vec(),
+            None => panic!("mock network requires at least one node"),
+        };
+
+        for (index, node) in nodes.iter().enumerate().skip(1) {
+            assert_eq!(
+                genesis_validators,
+                node.genesis_validators(),
+                "node {index} diverged from node 0 on genesis validators"
+            );
+        }
+
+        let validators = apply_updates(vec![], &genesis_validators);
+
+        Self {
+            nodes,
+            last_validators: vec![],
+            next_validators: validators.clone(),
+            validators,
+            offline: HashSet::new(),
+            evidence: vec![],
+            history: vec![],
+        }
+    }
+
+    /// Produces a block with `txs` on every node
+    pub fn step(&mut self, txs: Vec<Bytes>, block_time: Timestamp) -> StepResponse {
+        let proposer_address = match self.validators.is_empty() {
+            true => DEFAULT_PROPOSER_ADDRESS.to_vec(),
+            false => {
+                let proposer = self.nodes[0].height() as usize % self.validators.len();
+                self.validators[proposer].address.as_ref().to_vec()
+            }
+        };
+
+        let last_commit_info = LastCommitInfo {
+            round: 0,
+            votes: self
+                .last_validators
+                .iter()
+                .map(|validator| VoteInfo {
+                    validator: validator.clone(),
+                    signed_last_block: !self.offline.contains(&validator.address),
+                })
+                .collect(),
+        };
+        let evidence = std::mem::take(&mut self.evidence);
+
+        let mut responses = self.nodes.iter_mut().map(|node| {
+            node.step_with_evidence(
+                txs.clone(),
+                block_time,
+                proposer_address.clone(),
+                last_commit_info.clone(),
+                evidence.clone(),
+            )
+        });
+
+        let response = responses.next().expect("network has at least one node");
+        for (index, other) in responses.enumerate() {
+            assert_agreement(&response, &other, index + 1);
+        }
+
+        let validator_updates = self.nodes[0].last_validator_updates();
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            assert_eq!(
+                validator_updates,
+                node.last_validator_updates(),
+                "node {index} diverged from node 0 on validator updates at height {}",
+                response.height
+            );
+        }
+
+        self.history.push(BlockInfo {
+            time: block_time,
+            validators: self.validators.clone(),
+        });
+
+        let next_validators = apply_updates(self.next_validators.clone(), validator_updates);
+        self.last_validators = std::mem::replace(
+            &mut self.validators,
+            std::mem::replace(&mut self.next_validators, next_validators),
+        );
+
+        response
+    }
+
+    /// Produces `steps` empty blocks on every node, each one [BLOCK_INTERVAL](super::BLOCK_INTERVAL)
+    /// after the previous one
+    pub fn skip_steps(&mut self, steps: usize) {
+        for _ in 0..steps {
+            let time = self.nodes[0].next_block_time();
+            let _ = self.step(vec![], time);
+        }
+    }
+
+    /// Sets whether the validator signs blocks. Offline validators are reported
+    /// as absent in `LastCommitInfo` of the following blocks.
+    pub fn set_online(&mut self, validator: &ConsAddress, online: bool) {
+        let address = val_address(validator);
+
+        match online {
+            true => self.offline.remove(&address),
+            false => self.offline.insert(address),
+        };
+    }
+
+    /// Reports that the validator signed two different blocks at `height`. The evidence is
+    /// included in the next block.
+    pub fn double_sign(&mut self, validator: &ConsAddress, height: u32) {
+        let address = val_address(validator);
+
+        let block = match height
+            .checked_sub(1)
+            .and_then(|index| self.history.get(index as usize))
+        {
+            Some(block) => block,
+            None => panic!("block at height {height} isn't committed yet"),
+        };
+
+        let validator = match block
+            .validators
+            .iter()
+            .find(|validator| validator.address == address)
+        {
+            Some(validator) => validator.clone(),
+            None => panic!("{validator} isn't a validator at height {height}"),
+        };
+
+        self.evidence.push(Evidence {
+            r#type: EvidenceType::DuplicateVote.into(),
+            validator,
+            height: height.into(),
+            time: block.time,
+            total_voting_power: block
+                .validators
+                .iter()
+                .map(|validator| validator.power.power() as i64)
+                .sum(),
+        });
+    }
+
+    /// Queries every node and asserts that they return the same response
+    pub fn query(&self, req: RequestQuery) -> ResponseQuery {
+        let response = self.nodes[0].query(req.clone());
+
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            assert_eq!(
+                response,
+                node.query(req.clone()),
+                "node {index} diverged from node 0 on query {}",
+                req.path
+            );
+        }
+
+        response
+    }
+
+    pub fn nodes(&self) -> &[MockNode<App, G>] {
+        &self.nodes
+    }
+
+    /// Validators of the next block, its proposer is one of them
+    pub fn validators(&self) -> &[Validator] {
+        &self.validators
+    }
+
+    pub fn app_hash(&self) -> &Bytes {
+        self.nodes[0].app_hash()
+    }
+
+    pub fn chain_id(&self) -> &ChainId {
+        self.nodes[0].chain_id()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.nodes[0].height()
+    }
+}
+
+fn assert_agreement(expected: &StepResponse, actual: &StepResponse, index: usize) {
+    let height = expected.height;
+
+    assert_eq!(
+        expected.app_hash, actual.app_hash,
+        "node {index} diverged from node 0 on app hash at height {height}"
+    );
+    assert_eq!(
+        expected.tx_responses, actual.tx_responses,
+        "node {index} diverged from node 0 on tx results at height {height}"
+    );
+}
+
+fn val_address(validator: &ConsAddress) -> ValAddress {
+    ValAddress::try_from(validator.as_ref().to_vec()).expect("consensus address has a valid length")
+}
+
+/// Applies validator updates to the set, validators with zero power are removed. The set
+/// is sorted the same way as in tendermint: by voting power and then by address.
+fn apply_updates(mut validators: Vec<Validator>, updates: &[ValidatorUpdate]) -> Vec<Validator> {
+    for ValidatorUpdate { pub_key, power } in updates {
+        let address = val_address(&ConsAddress::from(pub_key.clone()));
+
+        validators.retain(|validator| validator.address != address);
+        if power.power() > 0 {
+            validators.push(Validator {
+                address,
+                power: *power,
+            });
+        }
+    }
+
+    validators.sort_by(|a, b| {
+        b.power
+            .power()
+            .cmp(&a.power.power())
+            .then_with(|| a.address.cmp(&b.address))
+    });
+
+    validators
+}
@@ -6,21 +6,27 @@ use crate::signing::handler::MetadataGetter;
 use crate::signing::renderer::amino_renderer::{AminoRenderer, RenderError as AminoRendererError};
 use crate::signing::std_sign_doc;
 use crate::signing::{handler::SignModeHandler, renderer::value_renderer::ValueRenderer};
+use crate::types::address::AccAddress;
 use crate::types::base::coin::UnsignedCoin;
 use crate::types::base::coins::UnsignedCoins;
 use crate::types::denom::Denom;
+use crate::types::msg::send::MsgSend;
+use crate::types::tx::direct_aux::SignDocDirectAux;
 use crate::x::errors::{AnteError, AnteGasError};
 use crate::x::keepers::auth::AuthKeeper;
 use crate::x::keepers::auth::AuthParams;
 use crate::x::keepers::bank::BankKeeper;
+use crate::x::keepers::feemarket::{FeeMarketKeeper, NoFeeMarket};
 use crate::{
     context::QueryableContext,
+    types::auth::tip::Tip,
     types::tx::{raw::TxWithRaw, signer::SignerData, Tx, TxMessage},
 };
 use core_types::tx::signature::SignatureData;
 use core_types::{
     signing::SignDoc,
     tx::mode_info::{ModeInfo, SignMode},
+    Protobuf,
 };
 use cosmwasm_std::Decimal256;
 use database::Database;
@@ -84,11 +90,13 @@ pub struct BaseAnteHandler<
     SK: StoreKey,
     GC,
     M: Module,
+    FM = NoFeeMarket,
 > {
     bank_keeper: BK,
     auth_keeper: AK,
     sign_gas_consumer: GC,
     fee_collector_module: M,
+    fee_market: FM,
     sk: PhantomData<SK>,
 }
 
@@ -111,9 +119,36 @@ impl<
             auth_keeper,
             sign_gas_consumer,
             fee_collector_module,
+            fee_market: NoFeeMarket,
             sk: PhantomData,
         }
     }
+
+    /// Enforce base gas price of the fee market in both `check_tx` and `deliver_tx`
+    pub fn with_fee_market<FM: FeeMarketKeeper<SK>>(
+        self,
+        fee_market: FM,
+    ) -> BaseAnteHandler<BK, AK, SK, GC, MOD, FM> {
+        BaseAnteHandler {
+            bank_keeper: self.bank_keeper,
+            auth_keeper: self.auth_keeper,
+            sign_gas_consumer: self.sign_gas_consumer,
+            fee_collector_module: self.fee_collector_module,
+            fee_market,
+            sk: PhantomData,
+        }
+    }
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+        FM: FeeMarketKeeper<SK>,
+    > BaseAnteHandler<BK, AK, SK, GC, MOD, FM>
+{
     pub fn run<
         DB: Database,
         M: TxMessage + ValueRenderer + AminoRenderer,
@@ -129,11 +164,13 @@ impl<
         // Note: we currently don't have simulate mode at all, so some methods receive hardcoded values for this mode
         // ante.NewSetUpContextDecorator(), // WE not going to implement this in ante. Some logic should be in application
         self.mempool_fee(tx, is_check, node_opt)?;
+        self.base_fee_ante_handler(ctx, &tx.tx)?;
         self.validate_basic_ante_handler(&tx.tx)?;
         self.tx_timeout_height_ante_handler(ctx, &tx.tx)?;
         self.validate_memo_ante_handler(ctx, &tx.tx)?;
         self.consume_gas_for_tx_size(ctx, tx, gas_meter.clone())?;
         self.deduct_fee_ante_handler(ctx, &tx.tx)?;
+        self.tip_ante_handler(&tx.tx)?;
         self.set_pub_key_ante_handler(ctx, &tx.tx)?;
         //  ** ante.NewValidateSigCountDecorator(opts.AccountKeeper),
         self.sign_gas_consume(ctx, &tx.tx, gas_meter.clone())?;
@@ -159,6 +196,19 @@ impl<
         Ok(())
     }
 
+    /// Runs after the messages of the tx succeeded, see `ABCIHandler::run_post_handlers`
+    pub fn run_post<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+    ) -> Result<(), TxError> {
+        self.tip_post_handler(ctx, &tx.tx)?;
+
+        //  - posthandler.NewTipDecorator(opts.BankKeeper),
+
+        Ok(())
+    }
+
     fn mempool_fee<M: TxMessage>(
         &self,
         TxWithRaw {
@@ -229,6 +279,49 @@ impl<
         Ok(())
     }
 
+    fn base_fee_ante_handler<M: TxMessage, DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        tx: &Tx<M>,
+    ) -> Result<(), AnteError> {
+        // gentxs are delivered in genesis, before fee market has any block to price
+        if ctx.height() == 0 {
+            return Ok(());
+        }
+
+        let base_gas_price = match self.fee_market.base_gas_price(ctx)? {
+            Some(base_gas_price) => base_gas_price,
+            None => return Ok(()),
+        };
+
+        let required = base_gas_price
+            .amount
+            .checked_mul(Into::<Decimal256>::into(tx.auth_info.fee.gas_limit))
+            .map_err(|_| AnteGasError::Overflow("overflow calculating required fees".into()))?
+            .to_uint_ceil();
+
+        if required.is_zero() {
+            return Ok(());
+        }
+
+        let got = tx
+            .auth_info
+            .fee
+            .amount
+            .as_ref()
+            .map(|fee| fee.amount_of(&base_gas_price.denom))
+            .unwrap_or_default();
+
+        if got < required {
+            Err(AnteError::InsufficientBaseFee {
+                got: format!("{got}{}", base_gas_price.denom),
+                required: format!("{required}{}", base_gas_price.denom),
+            })?
+        }
+
+        Ok(())
+    }
+
     fn consume_gas_for_tx_size<M: TxMessage, DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -375,6 +468,39 @@ impl<
         Ok(())
     }
 
+    /// The tipper is required to be one of the signers of the tx, so the transfer
+    /// of the tip in [`Self::run_post`] is authorized by the tipper signature.
+    fn tip_ante_handler<M: TxMessage>(&self, tx: &Tx<M>) -> Result<(), AnteError> {
+        match tip_of(tx) {
+            Some((_, tipper)) if !tx.get_signers().contains(&tipper) => Err(AnteError::Validation(
+                format!("tipper {tipper} must be a signer of the tx"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Transfers the tip from the tipper to the fee payer
+    fn tip_post_handler<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &Tx<M>,
+    ) -> Result<(), AnteError> {
+        let Some((amount, tipper)) = tip_of(tx) else {
+            return Ok(());
+        };
+
+        self.bank_keeper.send_coins_from_account_to_account(
+            ctx,
+            &MsgSend {
+                from_address: tipper.to_owned(),
+                to_address: tx.get_fee_payer().to_owned(),
+                amount: amount.to_owned(),
+            },
+        )?;
+
+        Ok(())
+    }
+
     fn set_pub_key_ante_handler<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -431,6 +557,7 @@ impl<
     ) -> Result<(), AnteError> {
         let signers = tx.tx.get_signers();
         let signature_data = tx.tx.get_signatures_data();
+        let fee_payer = tx.tx.get_fee_payer();
 
         // NOTE: this is also checked in validate_basic_ante_handler
         if signature_data.len() != signers.len() {
@@ -477,30 +604,34 @@ impl<
                         account_number,
                     }
                     .encode_to_vec(),
-                    SignMode::LegacyAminoJson => {
-                        let mut msgs = vec![];
-                        for msg in tx.tx.get_msgs() {
-                            msgs.push(std_sign_doc::Msg {
-                                kind: msg.amino_url().to_string(),
-                                value: msg.render()?,
-                            })
+                    SignMode::DirectAux => {
+                        if signer == fee_payer {
+                            return Err(AnteError::Validation(
+                                "SIGN_MODE_DIRECT_AUX can't be used by fee payer".to_string(),
+                            ));
                         }
-                        let doc = std_sign_doc::StdSignDoc {
-                            account_number: account_number.to_string(),
-                            chain_id: ctx.chain_id().to_string(),
-                            fee: tx.tx.auth_info.fee.clone().into(),
-                            memo: tx.tx.get_memo().to_string(),
-                            msgs,
-                            sequence: account_seq.to_string(),
-                            // TODO: check impl
-                            // timeout_height: Some(u64::from(tx.tx.get_timeout_height()).to_string()),
-                            timeout_height: None,
-                        };
 
-                        doc.to_sign_bytes().map_err(|e| {
-                            AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
-                        })?
+                        SignDocDirectAux {
+                            body_bytes: tx.raw.body_bytes.clone(),
+                            public_key: public_key.to_owned(),
+                            chain_id: ctx.chain_id().to_string(),
+                            account_number,
+                            sequence: account_seq,
+                            tip: tx.tx.auth_info.tip.clone(),
+                        }
+                        .encode_vec()
                     }
+                    SignMode::LegacyAminoJson => std_sign_doc::StdSignDoc::new(
+                        &tx.tx.body,
+                        tx.tx.auth_info.fee.clone(),
+                        ctx.chain_id().to_string(),
+                        account_number,
+                        account_seq,
+                    )?
+                    .to_sign_bytes()
+                    .map_err(|e| {
+                        AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
+                    })?,
                     SignMode::Textual => {
                         let handler = SignModeHandler;
 
@@ -588,6 +719,17 @@ impl<
     }
 }
 
+/// Returns the non empty tip of the tx with its tipper
+fn tip_of<M: TxMessage>(tx: &Tx<M>) -> Option<(&UnsignedCoins, &AccAddress)> {
+    match &tx.auth_info.tip {
+        Some(Tip {
+            amount: Some(amount),
+            tipper,
+        }) if !amount.is_empty() => Some((amount, tipper)),
+        _ => None,
+    }
+}
+
 // TODO: uncomment tests
 // #[cfg(test)]
 // mod tests {
@@ -85,6 +85,8 @@ pub(crate) enum AnteError {
     InsufficientFees { got: String, required: String },
     #[error("fee required")]
     MissingFee,
+    #[error("insufficient fees for base gas price; got: {got} required: {required}")]
+    InsufficientBaseFee { got: String, required: String },
     #[error("{0}")]
     Validation(String), //TODO: consider breaking this down into more specific errors
     #[error("tx has timed out; timeout height: {timeout}, current height: {current}")]
@@ -129,6 +131,10 @@ impl From<AnteError> for TxError {
             AnteError::Gas(_) => 10,
             AnteError::LegacyAminoJson(_) => 11,
             AnteError::Signing(_) => 12,
+            AnteError::InsufficientBaseFee {
+                got: _,
+                required: _,
+            } => 13,
         };
 
         TxError {
@@ -145,6 +151,16 @@ pub enum AuthKeeperError {
     GasError(#[from] GasStoreErrors),
 }
 
+/// Error of a staking hook. A failing hook aborts the operation which triggered it,
+/// e.g. the whole transaction.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StakingHooksError {
+    #[error("{0}")]
+    GasError(#[from] GasStoreErrors),
+    #[error("{0}")]
+    Other(String),
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum InsufficientFundsError {
     #[error("account: {account} doesn't have sufficient funds: {funds}")]
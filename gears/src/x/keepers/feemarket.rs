@@ -0,0 +1,27 @@
+use database::Database;
+use gas::store::errors::GasStoreErrors;
+use kv_store::StoreKey;
+
+use crate::{context::QueryableContext, types::base::coin::DecimalCoin};
+
+/// Source of the minimal gas price required by consensus, on top of node's `min_gas_prices`
+pub trait FeeMarketKeeper<SK: StoreKey>: Clone + Send + Sync + 'static {
+    /// Base price of a gas unit for the current block, `None` if fee market is disabled
+    fn base_gas_price<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Option<DecimalCoin>, GasStoreErrors>;
+}
+
+/// Keeper for applications without fee market. Fees are checked only against `min_gas_prices`
+#[derive(Debug, Clone, Default)]
+pub struct NoFeeMarket;
+
+impl<SK: StoreKey> FeeMarketKeeper<SK> for NoFeeMarket {
+    fn base_gas_price<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _ctx: &CTX,
+    ) -> Result<Option<DecimalCoin>, GasStoreErrors> {
+        Ok(None)
+    }
+}
@@ -13,7 +13,10 @@ use crate::{
     },
     x::{
         errors::BankKeeperError,
-        keepers::bank::{BalancesKeeper, BankKeeper},
+        keepers::{
+            bank::{BalancesKeeper, BankKeeper},
+            mint::MintingBankKeeper,
+        },
         module::Module,
     },
 };
@@ -119,3 +122,14 @@ impl<SK: StoreKey, M: Module> BankKeeper<SK, M> for MockBankKeeper {
         Ok(())
     }
 }
+
+impl<SK: StoreKey, M: Module> MintingBankKeeper<SK, M> for MockBankKeeper {
+    fn mint_coins<DB: database::Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _: &mut CTX,
+        _: &M,
+        _: UnsignedCoins,
+    ) -> Result<(), BankKeeperError> {
+        Ok(())
+    }
+}
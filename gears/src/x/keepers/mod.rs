@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod bank;
+pub mod feemarket;
 pub mod gov;
 pub mod mint;
 #[cfg(feature = "mocks")]
@@ -11,7 +11,7 @@ use crate::{
         base::{coin::UnsignedCoin, coins::UnsignedCoins},
     },
     x::{
-        errors::BankKeeperError,
+        errors::{BankKeeperError, StakingHooksError},
         module::Module,
         types::{delegation::StakingDelegation, validator::StakingValidator},
     },
@@ -37,7 +37,8 @@ pub const VALIDATOR_UPDATE_DELAY: u32 = 1;
 /// These can be utilized to communicate between a staking keeper and another
 /// keeper which must take particular actions when validators/delegators change
 /// state. The second keeper must implement this interface, which then the
-/// staking keeper can call.
+/// staking keeper can call. An error returned by a hook aborts the operation
+/// which triggered the hook.
 pub trait KeeperHooks<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module>:
     Clone + Send + Sync + 'static
 {
@@ -45,69 +46,69 @@ pub trait KeeperHooks<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module>:
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
         fraction: Decimal256,
-    );
+    ) -> Result<(), StakingHooksError>;
 }
 
 /// Staking keeper which used in gov xmod
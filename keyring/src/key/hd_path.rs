@@ -0,0 +1,67 @@
+use std::{fmt::Display, str::FromStr};
+
+use bip32::DerivationPath;
+
+/// Coin type registered for atom in SLIP-0044.
+pub const ATOM_COIN_TYPE: u32 = 118;
+
+/// BIP-44 hierarchical deterministic path used to derive keys from a mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HdPath(DerivationPath);
+
+impl HdPath {
+    /// Returns BIP-44 path of the form `m/44'/<coin_type>'/<account>'/0/<index>`.
+    pub fn new(coin_type: u32, account: u32, index: u32) -> Self {
+        Self(
+            format!("m/44'/{coin_type}'/{account}'/0/{index}")
+                .parse()
+                .expect("path is formatted from integers so it is always valid"),
+        )
+    }
+
+    pub fn inner(&self) -> &DerivationPath {
+        &self.0
+    }
+}
+
+impl Default for HdPath {
+    /// Returns the default Cosmos path `m/44'/118'/0'/0/0`.
+    fn default() -> Self {
+        Self::new(ATOM_COIN_TYPE, 0, 0)
+    }
+}
+
+impl FromStr for HdPath {
+    type Err = bip32::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Display for HdPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<DerivationPath> for HdPath {
+    fn from(value: DerivationPath) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_cosmos_path() {
+        assert_eq!(HdPath::default().to_string(), "m/44'/118'/0'/0/0");
+    }
+
+    #[test]
+    fn new_works() {
+        assert_eq!(HdPath::new(60, 1, 5).to_string(), "m/44'/60'/1'/0/5");
+    }
+}
@@ -3,6 +3,7 @@ pub mod secp256k1_key_pair;
 use serde::{Deserialize, Serialize};
 
 use self::secp256k1_key_pair::Secp256k1KeyPair;
+use super::hd_path::HdPath;
 
 /// A key pair.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -56,6 +57,16 @@ impl KeyPair {
     pub fn from_mnemonic(mnemonic: &bip32::Mnemonic) -> Self {
         Self::Secp256k1(Secp256k1KeyPair::from_mnemonic(mnemonic))
     }
+
+    pub fn from_mnemonic_with_path(
+        mnemonic: &bip32::Mnemonic,
+        passphrase: &str,
+        hd_path: &HdPath,
+    ) -> Self {
+        Self::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(
+            mnemonic, passphrase, hd_path,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use bip32::{DerivationPath, Mnemonic, XPrv};
+use bip32::{Mnemonic, XPrv};
 use hex::{FromHex, ToHex};
 use k256::ecdsa::signature::Signer;
 use k256::ecdsa::SigningKey;
@@ -10,7 +10,8 @@ use pkcs8::{
     DecodePrivateKey, EncodePrivateKey, EncryptedPrivateKeyInfo, LineEnding, PrivateKeyInfo,
 };
 use std::error::Error;
-const HDPATH: &str = "m/44'/118'/0'/0/0";
+
+use crate::key::hd_path::HdPath;
 
 type SignatureBytes = [u8; 64];
 
@@ -95,11 +96,20 @@ impl Secp256k1KeyPair {
         Ok(Self(SecretKey::from_pkcs8_encrypted_pem(s, password)?))
     }
 
-    /// Returns a key pair from a mnemonic.
+    /// Returns a key pair from a mnemonic using the default Cosmos path and no passphrase.
     pub fn from_mnemonic(mnemonic: &Mnemonic) -> Self {
-        let seed = mnemonic.to_seed("");
-        let child_path: DerivationPath = HDPATH.parse().expect("hard coded path will never fail");
-        let child_xprv = XPrv::derive_from_path(&seed, &child_path)
+        Self::from_mnemonic_with_path(mnemonic, "", &HdPath::default())
+    }
+
+    /// Returns a key pair from a mnemonic protected by BIP-39 passphrase
+    /// derived with provided hd path.
+    pub fn from_mnemonic_with_path(
+        mnemonic: &Mnemonic,
+        passphrase: &str,
+        hd_path: &HdPath,
+    ) -> Self {
+        let seed = mnemonic.to_seed(passphrase);
+        let child_xprv = XPrv::derive_from_path(&seed, hd_path.inner())
             .expect("seed has length 64 so this will never return an error");
         let signing_key = child_xprv.private_key();
 
@@ -181,6 +191,26 @@ mod tests {
         assert_eq!(key_pair, key_pair_from_pem);
     }
 
+    #[test]
+    fn from_mnemonic_with_path_works() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        let default_key_pair = Secp256k1KeyPair::from_mnemonic(&mnemonic);
+
+        assert_eq!(
+            default_key_pair,
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, "", &HdPath::new(118, 0, 0))
+        );
+        assert_ne!(
+            default_key_pair,
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, "", &HdPath::new(118, 0, 1))
+        );
+        assert_ne!(
+            default_key_pair,
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, "passphrase", &HdPath::default())
+        );
+    }
+
     #[test]
     fn sandpit() {
         let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
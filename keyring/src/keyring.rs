@@ -2,7 +2,10 @@ use std::path::Path;
 
 use crate::{
     error::Error,
-    key::pair::{secp256k1_key_pair::Secp256k1KeyPair, KeyPair},
+    key::{
+        hd_path::HdPath,
+        pair::{secp256k1_key_pair::Secp256k1KeyPair, KeyPair},
+    },
     key_store::file_store,
 };
 use bip32::Mnemonic;
@@ -16,6 +19,15 @@ pub enum KeyType {
     Secp256k1,
 }
 
+/// Options used to derive a key from mnemonic.
+#[derive(Debug, Clone, Default)]
+pub struct DerivationOptions {
+    /// BIP-39 passphrase which protects the mnemonic
+    pub passphrase: String,
+    /// BIP-44 path of derived key
+    pub hd_path: HdPath,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Backend<'a> {
     File(&'a Path),
@@ -27,13 +39,18 @@ pub fn add_key<S>(
     name: S,
     mnemonic: &Mnemonic,
     key_type: KeyType,
+    options: &DerivationOptions,
     backend: Backend<'_>,
 ) -> Result<KeyPair, Error>
 where
     S: AsRef<str>,
 {
     let key_pair = match key_type {
-        KeyType::Secp256k1 => KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic(mnemonic)),
+        KeyType::Secp256k1 => KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(
+            mnemonic,
+            &options.passphrase,
+            &options.hd_path,
+        )),
     };
 
     match backend {
@@ -53,13 +70,14 @@ where
 pub fn create_key<S>(
     name: S,
     key_type: KeyType,
+    options: &DerivationOptions,
     backend: Backend<'_>,
 ) -> Result<(Mnemonic, KeyPair), Error>
 where
     S: AsRef<str>,
 {
     let mnemonic = Mnemonic::random(OsRng, bip32::Language::English);
-    let key_pair = add_key(name, &mnemonic, key_type, backend)?;
+    let key_pair = add_key(name, &mnemonic, key_type, options, backend)?;
     Ok((mnemonic, key_pair))
 }
 
@@ -109,12 +127,24 @@ mod tests {
         // add key should succeed
         let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
         let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
-        add_key("bob", &mnemonic, KeyType::Secp256k1, Backend::Test(&path))
-            .expect("key should be added");
+        add_key(
+            "bob",
+            &mnemonic,
+            KeyType::Secp256k1,
+            &DerivationOptions::default(),
+            Backend::Test(&path),
+        )
+        .expect("key should be added");
 
         // add key with same name should fail
-        let error = add_key("bob", &mnemonic, KeyType::Secp256k1, Backend::Test(&path))
-            .expect_err("key should not be added");
+        let error = add_key(
+            "bob",
+            &mnemonic,
+            KeyType::Secp256k1,
+            &DerivationOptions::default(),
+            Backend::Test(&path),
+        )
+        .expect_err("key should not be added");
         assert!(matches!(error, Error::AlreadyExists { .. }));
 
         // get key should succeed
@@ -134,7 +164,13 @@ mod tests {
         assert!(matches!(error, Error::DoesNotExist { .. }));
 
         // create key should succeed
-        create_key("bob", KeyType::Secp256k1, Backend::Test(&path)).expect("key should be created");
+        create_key(
+            "bob",
+            KeyType::Secp256k1,
+            &DerivationOptions::default(),
+            Backend::Test(&path),
+        )
+        .expect("key should be created");
 
         // get key should succeed
         key_by_name("bob", Backend::Test(&path)).expect("key should be retrieved");
@@ -64,6 +64,36 @@ impl<DB: Database> ApplicationKVBank<DB> {
         self.persistent.read().expect(POISONED_LOCK)
     }
 
+    /// Load the latest version of the tree persisted in `db`. The tree is replaced in place,
+    /// so transaction stores created from `self` see it too.
+    pub(crate) fn reload(&self, db: DB, name: Option<String>) -> Result<(), KVStoreError> {
+        let tree = Tree::new(
+            db,
+            None,
+            TREE_CACHE_SIZE
+                .try_into()
+                .expect("Unreachable. Tree cache size is > 0"),
+            name,
+        )?;
+        *self.persistent.write().expect(POISONED_LOCK) = tree;
+
+        Ok(())
+    }
+
+    /// Return store with same tree and copied cache. Changes made in the branch are kept
+    /// only if it's merged back with [Self::merge_branch]
+    pub fn branch(&self) -> Self {
+        Self {
+            persistent: Arc::clone(&self.persistent),
+            cache: self.cache.clone(),
+        }
+    }
+
+    /// Replace uncommitted cache with the cache of `branch` created by [Self::branch]
+    pub fn merge_branch(&mut self, branch: Self) {
+        self.cache = branch.cache;
+    }
+
     /// Clear uncommitted cache
     #[inline]
     pub fn cache_clear(&mut self) {
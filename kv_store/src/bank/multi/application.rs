@@ -1,19 +1,35 @@
 //! Application kind of multi store
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, num::NonZero, sync::Arc};
 
 use database::{prefix::PrefixDB, Database};
+use strum::IntoEnumIterator;
 
 use crate::{
-    bank::kv::application::ApplicationKVBank, build_prefixed_stores, error::MultiStoreError,
-    hash::StoreInfo, StoreKey,
+    bank::kv::application::ApplicationKVBank,
+    build_prefixed_stores,
+    error::{MultiStoreError, StoreUpgradeError, KEY_EXISTS_MSG, POISONED_LOCK},
+    hash::StoreInfo,
+    StoreKey,
 };
 
-use super::*;
+use super::{upgrades::StoreUpgrades, *};
 
 /// Backend for application multi store
 #[derive(Debug)]
-pub struct ApplicationStore<DB, SK>(pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>);
+pub struct ApplicationStore<DB, SK>(
+    pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>,
+    /// Stores deleted or renamed by an upgrade, their data is moved or removed on the next commit
+    pub(crate) Vec<RemovedStore<DB, SK>>,
+);
+
+/// Data of a store deleted or renamed by an upgrade
+#[derive(Debug)]
+pub(crate) struct RemovedStore<DB, SK> {
+    data: PrefixDB<DB>,
+    /// Store which takes over the data of a renamed store with its prefixed db
+    renamed_to: Option<(SK, PrefixDB<DB>)>,
+}
 
 impl<SK, DB> MultiBankBackend<DB, SK> for ApplicationStore<DB, SK> {
     type Bank = ApplicationKVBank<PrefixDB<DB>>;
@@ -50,7 +66,7 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
             };
 
             store_infos.push(store_info);
-            head_version = kv_store.persistent().loaded_version();
+            head_version = head_version.max(kv_store.persistent().loaded_version());
 
             stores.insert(store_key, kv_store);
         }
@@ -58,11 +74,123 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         Ok(MultiBank {
             head_version,
             head_commit_hash: crate::hash::hash_store_infos(store_infos),
-            backend: ApplicationStore(stores),
+            backend: ApplicationStore(stores, Vec::new()),
             _marker: PhantomData,
         })
     }
 
+    /// Return new `self` with `upgrades` applied. Call it only when the next block is the upgrade
+    /// height: the head commit hash is still the one of the stores before the upgrade, while
+    /// the next commit uses the upgraded stores.
+    ///
+    /// The database is left untouched until the next commit: a renamed store works on the data
+    /// under its old name, which is moved under the new name by the commit together with the
+    /// removal of deleted stores. Loading the same upgrades again before it is safe.
+    pub fn new_with_upgrades(
+        db: Arc<DB>,
+        upgrades: &StoreUpgrades,
+    ) -> Result<Self, StoreUpgradeError> {
+        let store_key = |name: &str| {
+            SK::iter()
+                .find(|sk| sk.name() == name)
+                .ok_or_else(|| StoreUpgradeError::UnknownStore(name.to_owned()))
+        };
+        // stores share one database, the data of a removed store must not share a prefix with
+        // the data of a store of the application
+        let removed_prefix = |name: &str| {
+            let overlap =
+                SK::iter().find(|sk| sk.name().starts_with(name) || name.starts_with(sk.name()));
+            match overlap {
+                Some(sk) => Err(StoreUpgradeError::Overlap(
+                    name.to_owned(),
+                    sk.name().to_owned(),
+                )),
+                None => Ok(PrefixDB::new(Arc::clone(&db), name.as_bytes().to_vec())),
+            }
+        };
+
+        let added = upgrades
+            .added
+            .iter()
+            .map(|name| store_key(name.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut multi_store =
+            Self::new(Arc::clone(&db)).map_err(|MultiStoreError { sk, err }| {
+                StoreUpgradeError::Load {
+                    name: sk.name().to_owned(),
+                    err,
+                }
+            })?;
+        let head_version = multi_store.head_version;
+
+        let mut removed = Vec::new();
+        for rename in &upgrades.renamed {
+            let sk = store_key(&rename.new_key)?;
+            let old_store = removed_prefix(&rename.old_key)?;
+            let new_store = PrefixDB::new(Arc::clone(&db), rename.new_key.as_bytes().to_vec());
+            if added.contains(&sk) || new_store.iterator().next().is_some() {
+                return Err(StoreUpgradeError::NotEmpty(rename.new_key.clone()));
+            }
+
+            let kv_store = ApplicationKVBank::new(
+                old_store.clone(),
+                NonZero::new(head_version),
+                Some(rename.new_key.clone()),
+            )
+            .map_err(|err| StoreUpgradeError::Load {
+                name: rename.old_key.clone(),
+                err,
+            })?;
+            multi_store.backend.0.insert(sk.clone(), kv_store);
+
+            removed.push(RemovedStore {
+                data: old_store,
+                renamed_to: Some((sk, new_store)),
+            });
+        }
+
+        let mut store_infos = Vec::new();
+        for (sk, kv_store) in &multi_store.backend.0 {
+            if added.contains(sk) {
+                kv_store
+                    .persistent
+                    .write()
+                    .expect(POISONED_LOCK)
+                    .set_initial_version(head_version)
+                    .map_err(|_| StoreUpgradeError::NotEmpty(sk.name().to_owned()))?;
+            } else {
+                store_infos.push(StoreInfo {
+                    name: upgrades.old_name(sk.name()).to_owned(),
+                    hash: kv_store.persistent().root_hash(),
+                });
+            }
+        }
+
+        for name in &upgrades.deleted {
+            let store = removed_prefix(name)?;
+            let kv_store = ApplicationKVBank::new(store.clone(), NonZero::new(head_version), None)
+                .map_err(|err| StoreUpgradeError::Load {
+                    name: name.to_owned(),
+                    err,
+                })?;
+
+            store_infos.push(StoreInfo {
+                name: name.to_owned(),
+                hash: kv_store.persistent().root_hash(),
+            });
+            removed.push(RemovedStore {
+                data: store,
+                renamed_to: None,
+            });
+        }
+
+        multi_store.head_commit_hash = crate::hash::hash_store_infos(store_infos);
+        multi_store.backend.1 = removed;
+
+        Ok(multi_store)
+    }
+
     /// Return tx kind of store. You need to create application kind before creation of transaction
     pub fn to_tx_kind(&self) -> TransactionMultiBank<DB, SK> {
         TransactionMultiBank {
@@ -79,6 +207,30 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         }
     }
 
+    /// Return multi store with same trees and copied caches, see [ApplicationKVBank::branch]
+    pub fn branch(&self) -> Self {
+        Self {
+            head_version: self.head_version,
+            head_commit_hash: self.head_commit_hash,
+            backend: ApplicationStore(
+                self.backend
+                    .0
+                    .iter()
+                    .map(|(sk, store)| (sk.to_owned(), store.branch()))
+                    .collect(),
+                Vec::new(),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Keep changes of `branch` created by [Self::branch]
+    pub fn merge_branch(&mut self, mut branch: Self) {
+        for (sk, store) in &mut self.backend.0 {
+            store.merge_branch(branch.backend.0.remove(sk).expect(KEY_EXISTS_MSG));
+        }
+    }
+
     /// Consume block cache of transaction stores
     pub fn consume_block_cache(&mut self, other: &mut TransactionMultiBank<DB, SK>) {
         for (sk, store) in &mut self.backend.0 {
@@ -100,6 +252,28 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
 
         let hash = crate::hash::hash_store_infos(store_infos);
 
+        for RemovedStore { data, renamed_to } in std::mem::take(&mut self.backend.1) {
+            let entries = data.iterator().collect::<Vec<_>>();
+            if let Some((sk, store)) = renamed_to {
+                for (key, value) in &entries {
+                    store.put(key.to_vec(), value.to_vec());
+                }
+
+                self.backend
+                    .0
+                    .get(&sk)
+                    .expect(KEY_EXISTS_MSG)
+                    .reload(store, Some(sk.name().to_owned()))
+                    .unwrap_or_else(|err| {
+                        panic!("failed to load renamed store {}: {err}", sk.name())
+                    });
+            }
+
+            for (key, _) in entries {
+                data.delete(&key);
+            }
+        }
+
         self.head_commit_hash = hash;
         self.head_version = match self.head_version.checked_add(1) {
             Some(head_version) => head_version,
@@ -115,3 +289,235 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+    use crate::bank::multi::upgrades::StoreRename;
+
+    #[derive(Debug, Clone, Hash, PartialEq, Eq, strum::EnumIter)]
+    enum OldStoreKey {
+        Bank,
+        Old,
+        Gone,
+    }
+
+    impl StoreKey for OldStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                OldStoreKey::Bank => "bank",
+                OldStoreKey::Old => "old",
+                OldStoreKey::Gone => "gone",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &OldStoreKey::Bank
+        }
+    }
+
+    #[derive(Debug, Clone, Hash, PartialEq, Eq, strum::EnumIter)]
+    enum NewStoreKey {
+        Bank,
+        Renamed,
+        Fresh,
+    }
+
+    impl StoreKey for NewStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                NewStoreKey::Bank => "bank",
+                NewStoreKey::Renamed => "renamed",
+                NewStoreKey::Fresh => "fresh",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &NewStoreKey::Bank
+        }
+    }
+
+    fn upgrades() -> StoreUpgrades {
+        StoreUpgrades {
+            added: vec!["fresh".to_owned()],
+            renamed: vec![StoreRename {
+                old_key: "old".to_owned(),
+                new_key: "renamed".to_owned(),
+            }],
+            deleted: vec!["gone".to_owned()],
+        }
+    }
+
+    #[test]
+    fn new_with_upgrades_works() {
+        let db = Arc::new(MemDB::new());
+
+        let mut old_store =
+            ApplicationMultiBank::<_, OldStoreKey>::new(Arc::clone(&db)).unwrap_test();
+        for sk in OldStoreKey::iter() {
+            old_store
+                .kv_store_mut(&sk)
+                .set(sk.name().as_bytes().to_vec(), vec![1]);
+        }
+        let old_hash = old_store.commit();
+
+        let mut store =
+            ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(Arc::clone(&db), &upgrades())
+                .unwrap_test();
+        assert_eq!(store.head_version(), 1);
+        assert_eq!(store.head_commit_hash(), old_hash);
+        assert_eq!(
+            store.kv_store(&NewStoreKey::Renamed).get(b"old"),
+            Some(vec![1])
+        );
+        // nothing is written before the commit
+        assert!(db.prefix_iterator(b"renamed".to_vec()).next().is_none());
+        assert!(db.prefix_iterator(b"old".to_vec()).next().is_some());
+        assert!(db.prefix_iterator(b"gone".to_vec()).next().is_some());
+
+        store
+            .kv_store_mut(&NewStoreKey::Renamed)
+            .set(b"renamed".to_vec(), vec![3]);
+
+        store
+            .kv_store_mut(&NewStoreKey::Fresh)
+            .set(b"fresh".to_vec(), vec![2]);
+        store.commit();
+
+        assert_eq!(store.head_version(), 2);
+        assert!(db.prefix_iterator(b"gone".to_vec()).next().is_none());
+        assert!(db.prefix_iterator(b"old".to_vec()).next().is_none());
+        assert_eq!(
+            store.kv_store(&NewStoreKey::Renamed).get(b"renamed"),
+            Some(vec![3])
+        );
+
+        let store = ApplicationMultiBank::<_, NewStoreKey>::new(Arc::clone(&db)).unwrap_test();
+        assert_eq!(store.head_version(), 2);
+        assert_eq!(
+            store.kv_store(&NewStoreKey::Fresh).get(b"fresh"),
+            Some(vec![2])
+        );
+        assert_eq!(
+            store.kv_store(&NewStoreKey::Renamed).get(b"old"),
+            Some(vec![1])
+        );
+        assert_eq!(
+            store.kv_store(&NewStoreKey::Renamed).get(b"renamed"),
+            Some(vec![3])
+        );
+    }
+
+    #[test]
+    fn new_with_upgrades_rejects_overlapping_stores() {
+        // the removed store covers the data of the bank store
+        let upgrades = StoreUpgrades {
+            deleted: vec!["ban".to_owned()],
+            ..Default::default()
+        };
+        let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::new(MemDB::new()),
+            &upgrades,
+        );
+        assert_eq!(
+            result.err(),
+            Some(StoreUpgradeError::Overlap(
+                "ban".to_owned(),
+                "bank".to_owned()
+            ))
+        );
+
+        // the bank store covers the data of the removed store
+        let upgrades = StoreUpgrades {
+            renamed: vec![StoreRename {
+                old_key: "bank_old".to_owned(),
+                new_key: "renamed".to_owned(),
+            }],
+            ..Default::default()
+        };
+        let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::new(MemDB::new()),
+            &upgrades,
+        );
+        assert_eq!(
+            result.err(),
+            Some(StoreUpgradeError::Overlap(
+                "bank_old".to_owned(),
+                "bank".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_with_upgrades_rejects_rename_into_used_store() {
+        let db = Arc::new(MemDB::new());
+        let mut store = ApplicationMultiBank::<_, NewStoreKey>::new(Arc::clone(&db)).unwrap_test();
+        store
+            .kv_store_mut(&NewStoreKey::Renamed)
+            .set(b"key".to_vec(), vec![1]);
+        store.commit();
+
+        let upgrades = StoreUpgrades {
+            renamed: vec![StoreRename {
+                old_key: "old".to_owned(),
+                new_key: "renamed".to_owned(),
+            }],
+            ..Default::default()
+        };
+        let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(db, &upgrades);
+
+        assert_eq!(
+            result.err(),
+            Some(StoreUpgradeError::NotEmpty("renamed".to_owned()))
+        );
+    }
+
+    #[test]
+    fn branch_changes_are_kept_after_merge() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, NewStoreKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        multi_store
+            .kv_store_mut(&NewStoreKey::Bank)
+            .set(b"kept".to_vec(), b"1".to_vec());
+
+        let mut branch = multi_store.branch();
+        branch
+            .kv_store_mut(&NewStoreKey::Bank)
+            .set(b"branch".to_vec(), b"2".to_vec());
+        assert_eq!(
+            branch.kv_store(&NewStoreKey::Bank).get(b"kept"),
+            Some(b"1".to_vec())
+        );
+        assert_eq!(
+            multi_store.kv_store(&NewStoreKey::Bank).get(b"branch"),
+            None
+        );
+
+        // dropped branch leaves the store untouched
+        drop(multi_store.branch());
+        multi_store.merge_branch(branch);
+
+        let store = multi_store.kv_store(&NewStoreKey::Bank);
+        assert_eq!(store.get(b"kept"), Some(b"1".to_vec()));
+        assert_eq!(store.get(b"branch"), Some(b"2".to_vec()));
+    }
+
+    #[test]
+    fn new_with_upgrades_rejects_unknown_store() {
+        let db = Arc::new(MemDB::new());
+        let upgrades = StoreUpgrades {
+            added: vec!["unknown".to_owned()],
+            ..Default::default()
+        };
+
+        let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(db, &upgrades);
+
+        assert_eq!(
+            result.err(),
+            Some(StoreUpgradeError::UnknownStore("unknown".to_owned()))
+        );
+    }
+}
@@ -10,6 +10,7 @@ use crate::{error::KEY_EXISTS_MSG, StoreKey};
 
 pub mod application;
 pub mod transaction;
+pub mod upgrades;
 
 /// Backend for multi stores
 pub trait MultiBankBackend<DB, SK> {
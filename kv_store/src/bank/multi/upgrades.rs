@@ -0,0 +1,35 @@
+//! Changes to the set of stores of an application multi store at an upgrade height
+
+/// Stores to add, rename or delete when the multi store loads right before the upgrade height.
+/// Names are the [crate::StoreKey::name] of the stores.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreUpgrades {
+    /// New stores which start empty at the upgrade height
+    pub added: Vec<String>,
+    /// Stores which keep their data under another name
+    pub renamed: Vec<StoreRename>,
+    /// Stores which are removed with all their data
+    pub deleted: Vec<String>,
+}
+
+impl StoreUpgrades {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.renamed.is_empty() && self.deleted.is_empty()
+    }
+
+    /// Return the name under which the store was known before the upgrade
+    pub fn old_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.renamed
+            .iter()
+            .find(|rename| rename.new_key == name)
+            .map(|rename| rename.old_key.as_str())
+            .unwrap_or(name)
+    }
+}
+
+/// Rename of a store from `old_key` to `new_key`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreRename {
+    pub old_key: String,
+    pub new_key: String,
+}
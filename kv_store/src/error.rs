@@ -26,5 +26,17 @@ impl<SK: StoreKey> std::fmt::Display for MultiStoreError<SK> {
     }
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StoreUpgradeError {
+    #[error("store {0} is not a store of the application")]
+    UnknownStore(String),
+    #[error("store {0} can't be removed because its data overlaps with store {1}")]
+    Overlap(String, String),
+    #[error("added store {0} already contains data")]
+    NotEmpty(String),
+    #[error("failed to load store {name}: {err}")]
+    Load { name: String, err: KVStoreError },
+}
+
 pub const KEY_EXISTS_MSG: &str = "a store for every key is guaranteed to exist";
 pub const POISONED_LOCK: &str = "poisoned lock";
@@ -38,6 +38,8 @@ impl From<StoreInfo> for Pair {
     }
 }
 
+/// Hash of the commit info of all mounted stores. Store names are part of the hash,
+/// so stores added, renamed or deleted by an upgrade change it from the upgrade height on.
 pub fn hash_store_infos(store_infos: Vec<StoreInfo>) -> [u8; 32] {
     if store_infos.is_empty() {
         panic!("must contain at least one store")
@@ -45,6 +47,9 @@ pub fn hash_store_infos(store_infos: Vec<StoreInfo>) -> [u8; 32] {
 
     let mut pairs: Vec<Pair> = store_infos.into_iter().map(|info| info.into()).collect();
     pairs.sort();
+    if pairs.windows(2).any(|pair| pair[0].key == pair[1].key) {
+        panic!("store names must be unique")
+    }
     let byte_pairs: Vec<Vec<u8>> = pairs.into_iter().map(|pair| pair.to_bytes()).collect();
     trees::merkle::root_hash(&byte_pairs)
 }
@@ -95,4 +100,30 @@ mod tests {
             "9328960b097a043bd62b6d22075084251688dff84d004743d0666f4ecdd5b86d"
         );
     }
+
+    #[test]
+    fn hash_store_infos_depends_on_names() {
+        let hash = [7; 32];
+        let before = hash_store_infos(vec![StoreInfo {
+            name: "bob".to_string(),
+            hash,
+        }]);
+        let after = hash_store_infos(vec![StoreInfo {
+            name: "carol".to_string(),
+            hash,
+        }]);
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    #[should_panic(expected = "store names must be unique")]
+    fn hash_store_infos_panics_on_duplicate_names() {
+        let store_info = || StoreInfo {
+            name: "bob".to_string(),
+            hash: [7; 32],
+        };
+
+        hash_store_infos(vec![store_info(), store_info()]);
+    }
 }
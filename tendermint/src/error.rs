@@ -10,6 +10,9 @@ pub enum Error {
 
     #[error("invalid data: {0}")]
     InvalidData(String),
+
+    #[error("privval: {0}")]
+    Privval(String),
 }
 
 pub mod proto {
@@ -1,7 +1,12 @@
 #![allow(missing_docs)]
 
 use error::Error;
-use std::{fs::File, io::Write, path::PathBuf, time::Duration};
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::PathBuf,
+    time::Duration,
+};
 use tendermint_config::{
     AbciMode, ConsensusConfig, CorsHeader, CorsMethod, DbBackend, FastsyncConfig,
     InstrumentationConfig, LogFormat, MempoolConfig, NodeKey, P2PConfig, PrivValidatorKey,
@@ -16,6 +21,7 @@ pub mod crypto;
 pub mod error;
 pub(crate) mod ext;
 pub mod informal;
+pub mod privval;
 pub mod public;
 pub mod rpc;
 pub mod types;
@@ -29,6 +35,29 @@ pub fn get_validator_pub_key(priv_validator_key_file: File) -> Result<PublicKey,
     Ok(pub_key)
 }
 
+/// Returns the address the remote signer connects to, `None` if the validator signs
+/// with the key of `priv_validator_key.json`
+pub fn priv_validator_laddr(mut tm_config_file: File) -> Result<Option<privval::Address>, Error> {
+    let mut tm_config = String::new();
+    tm_config_file.read_to_string(&mut tm_config)?;
+    let tm_config =
+        TendermintConfig::parse_toml(tm_config).map_err(|e| Error::InvalidData(e.to_string()))?;
+    Ok(tm_config.priv_validator_laddr)
+}
+
+/// Returns the chain id of the genesis file
+pub fn genesis_chain_id(genesis_file: File) -> Result<ChainId, Error> {
+    #[derive(serde::Deserialize)]
+    struct Genesis {
+        chain_id: String,
+    }
+
+    let Genesis { chain_id } = serde_json::from_reader(genesis_file)?;
+    chain_id
+        .parse()
+        .map_err(|e: types::chain_id::ChainIdErrors| Error::InvalidData(e.to_string()))
+}
+
 pub fn node_pub_key(node_key_file: File) -> Result<PublicKey, Error> {
     let priv_validator_key: NodeKey = serde_json::from_reader(node_key_file)?;
     let pub_key = priv_validator_key.public_key().try_into()?;
@@ -133,11 +162,16 @@ pub fn write_priv_validator_state(mut priv_validator_state_key_file: File) -> Re
 pub fn write_tm_config(
     mut file: File,
     node_name: &str,
+    priv_validator_laddr: Option<privval::Address>,
     // peers: Vec<Address>,
     // external_address: Option<Address>,
     // tm_rpc_bind: Option<SocketAddr>,
     // tm_p2p_bind: Option<SocketAddr>,
 ) -> Result<(), Error> {
+    if let Some(laddr) = &priv_validator_laddr {
+        privval::parse_laddr(&laddr.to_string())?;
+    }
+
     let mut handlebars = handlebars::Handlebars::new();
     handlebars
         .register_template_string("config", TM_CONFIG_TEMPLATE)
@@ -147,6 +181,7 @@ pub fn write_tm_config(
     tm_config.moniker = node_name
         .parse()
         .expect("the Moniker::from_str method never fails");
+    tm_config.priv_validator_laddr = priv_validator_laddr;
 
     let tm_config = handlebars
         .render("config", &tm_config)
@@ -0,0 +1,581 @@
+//! Client and mock server of the Tendermint privval protocol which is used to
+//! communicate with remote signers of consensus keys (tmkms, horcrux etc).
+//!
+//! Tendermint listens on `priv_validator_laddr` and the remote signer dials in.
+//! Messages are length delimited protobuf `privval.Message`s.
+//!
+//! Only unix sockets are supported. TCP connections require a SecretConnection handshake
+//! which isn't implemented, so `tcp://` addresses are rejected with an error.
+
+use std::{
+    fs::File,
+    io::{ErrorKind, Read, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use prost::Message as ProstMessage;
+use tendermint_config::PrivValidatorKey;
+
+use crate::{
+    error::Error,
+    types::{chain_id::ChainId, proto::crypto::PublicKey},
+};
+
+pub use tendermint_config::net::Address;
+
+mod inner {
+    pub use tendermint_proto::privval::message::Sum;
+    pub use tendermint_proto::privval::Message;
+    pub use tendermint_proto::privval::PingRequest;
+    pub use tendermint_proto::privval::PingResponse;
+    pub use tendermint_proto::privval::PubKeyRequest;
+    pub use tendermint_proto::privval::PubKeyResponse;
+    pub use tendermint_proto::privval::RemoteSignerError;
+    pub use tendermint_proto::privval::SignProposalRequest;
+    pub use tendermint_proto::privval::SignVoteRequest;
+    pub use tendermint_proto::privval::SignedProposalResponse;
+    pub use tendermint_proto::privval::SignedVoteResponse;
+}
+
+pub use tendermint_proto::types::{Proposal, Vote};
+
+/// Max size of single privval message. Same as in Tendermint.
+const MAX_MSG_LEN: u64 = 10 * 1024;
+
+/// How long cli commands wait for remote signer to connect
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval between attempts to accept or dial connection
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+fn unix_socket_path(laddr: &Address) -> Result<PathBuf, Error> {
+    match laddr {
+        Address::Unix { path } => Ok(PathBuf::from(path)),
+        Address::Tcp { .. } => Err(Error::Privval(format!(
+            "unsupported address {laddr}: only unix sockets are supported"
+        ))),
+    }
+}
+
+/// Parse address of remote signer. Used as value parser of cli arguments so unsupported
+/// addresses are rejected before they get written into config.
+pub fn parse_laddr(laddr: &str) -> Result<Address, Error> {
+    let address = laddr
+        .parse::<Address>()
+        .map_err(|e| Error::Privval(e.to_string()))?;
+    unix_socket_path(&address)?;
+
+    Ok(address)
+}
+
+fn read_msg(stream: &mut impl Read) -> Result<Option<inner::Message>, Error> {
+    let mut len_buf = Vec::with_capacity(10);
+    loop {
+        let mut byte = [0_u8];
+        if stream.read(&mut byte)? == 0 {
+            return match len_buf.is_empty() {
+                true => Ok(None),
+                false => Err(Error::Privval("unexpected end of stream".to_owned())),
+            };
+        }
+
+        len_buf.push(byte[0]);
+        if byte[0] < 0x80 {
+            break;
+        }
+        if len_buf.len() >= 10 {
+            return Err(Error::Privval("invalid message length".to_owned()));
+        }
+    }
+
+    let len = prost::encoding::decode_varint(&mut len_buf.as_slice())
+        .map_err(|e| Error::Privval(e.to_string()))?;
+    if len > MAX_MSG_LEN {
+        return Err(Error::Privval(format!(
+            "message length {len} exceeds max length {MAX_MSG_LEN}"
+        )));
+    }
+
+    let mut buf = vec![0_u8; len as usize];
+    stream.read_exact(&mut buf)?;
+
+    inner::Message::decode(buf.as_slice())
+        .map(Some)
+        .map_err(|e| Error::Privval(e.to_string()))
+}
+
+fn write_msg(stream: &mut impl Write, sum: inner::Sum) -> Result<(), Error> {
+    let msg = inner::Message { sum: Some(sum) };
+    stream.write_all(&msg.encode_length_delimited_to_vec())?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Listener of incoming connections from remote signer. The socket file is removed on drop.
+#[derive(Debug)]
+pub struct PrivValidatorListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl PrivValidatorListener {
+    pub fn bind(laddr: &Address) -> Result<Self, Error> {
+        let path = unix_socket_path(laddr)?;
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self { listener, path })
+    }
+
+    /// Wait for remote signer to connect
+    pub fn accept(&self, timeout: Duration) -> Result<PrivValidatorClient, Error> {
+        let start = Instant::now();
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    stream.set_nonblocking(false)?;
+                    stream.set_read_timeout(Some(timeout))?;
+                    return Ok(PrivValidatorClient { stream });
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if start.elapsed() > timeout {
+                        return Err(Error::Privval(format!(
+                            "remote signer didn't connect to {} in {timeout:?}",
+                            self.path.display()
+                        )));
+                    }
+                    std::thread::sleep(RETRY_INTERVAL);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for PrivValidatorListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Connection with remote signer from validator side
+#[derive(Debug)]
+pub struct PrivValidatorClient {
+    stream: UnixStream,
+}
+
+impl PrivValidatorClient {
+    fn request(&mut self, sum: inner::Sum) -> Result<inner::Sum, Error> {
+        write_msg(&mut self.stream, sum)?;
+        read_msg(&mut self.stream)?
+            .and_then(|msg| msg.sum)
+            .ok_or(Error::Privval("remote signer closed connection".to_owned()))
+    }
+
+    pub fn ping(&mut self) -> Result<(), Error> {
+        match self.request(inner::Sum::PingRequest(inner::PingRequest {}))? {
+            inner::Sum::PingResponse(_) => Ok(()),
+            _ => Err(Error::Privval("unexpected response to ping".to_owned())),
+        }
+    }
+
+    /// Request consensus public key of validator for `chain_id`
+    pub fn pub_key(&mut self, chain_id: &ChainId) -> Result<PublicKey, Error> {
+        let response = self.request(inner::Sum::PubKeyRequest(inner::PubKeyRequest {
+            chain_id: chain_id.to_string(),
+        }))?;
+
+        match response {
+            inner::Sum::PubKeyResponse(inner::PubKeyResponse {
+                error: Some(inner::RemoteSignerError { code, description }),
+                ..
+            }) => Err(Error::Privval(format!(
+                "remote signer error {code}: {description}"
+            ))),
+            inner::Sum::PubKeyResponse(inner::PubKeyResponse {
+                pub_key: Some(pub_key),
+                error: None,
+            }) => pub_key.try_into(),
+            inner::Sum::PubKeyResponse(_) => {
+                Err(Error::InvalidData("public key is empty".to_owned()))
+            }
+            _ => Err(Error::Privval(
+                "unexpected response to public key request".to_owned(),
+            )),
+        }
+    }
+
+    /// Request signature of `vote`. Returns the vote with signature set
+    pub fn sign_vote(&mut self, chain_id: &ChainId, vote: Vote) -> Result<Vote, Error> {
+        let response = self.request(inner::Sum::SignVoteRequest(inner::SignVoteRequest {
+            vote: Some(vote),
+            chain_id: chain_id.to_string(),
+        }))?;
+
+        match response {
+            inner::Sum::SignedVoteResponse(inner::SignedVoteResponse {
+                error: Some(inner::RemoteSignerError { code, description }),
+                ..
+            }) => Err(Error::Privval(format!(
+                "remote signer error {code}: {description}"
+            ))),
+            inner::Sum::SignedVoteResponse(inner::SignedVoteResponse {
+                vote: Some(vote),
+                error: None,
+            }) => Ok(vote),
+            inner::Sum::SignedVoteResponse(_) => {
+                Err(Error::InvalidData("signed vote is empty".to_owned()))
+            }
+            _ => Err(Error::Privval(
+                "unexpected response to sign vote request".to_owned(),
+            )),
+        }
+    }
+
+    /// Request signature of `proposal`. Returns the proposal with signature set
+    pub fn sign_proposal(
+        &mut self,
+        chain_id: &ChainId,
+        proposal: Proposal,
+    ) -> Result<Proposal, Error> {
+        let response = self.request(inner::Sum::SignProposalRequest(
+            inner::SignProposalRequest {
+                proposal: Some(proposal),
+                chain_id: chain_id.to_string(),
+            },
+        ))?;
+
+        match response {
+            inner::Sum::SignedProposalResponse(inner::SignedProposalResponse {
+                error: Some(inner::RemoteSignerError { code, description }),
+                ..
+            }) => Err(Error::Privval(format!(
+                "remote signer error {code}: {description}"
+            ))),
+            inner::Sum::SignedProposalResponse(inner::SignedProposalResponse {
+                proposal: Some(proposal),
+                error: None,
+            }) => Ok(proposal),
+            inner::Sum::SignedProposalResponse(_) => {
+                Err(Error::InvalidData("signed proposal is empty".to_owned()))
+            }
+            _ => Err(Error::Privval(
+                "unexpected response to sign proposal request".to_owned(),
+            )),
+        }
+    }
+}
+
+/// Wait for remote signer to connect to `laddr` and fetch the consensus public key from it.
+/// Used to get validator key when `priv_validator_key.json` isn't available locally.
+pub fn fetch_validator_pub_key(
+    laddr: &Address,
+    chain_id: &ChainId,
+    timeout: Duration,
+) -> Result<PublicKey, Error> {
+    let listener = PrivValidatorListener::bind(laddr)?;
+    let mut client = listener.accept(timeout)?;
+
+    client.pub_key(chain_id)
+}
+
+/// Remote signer which holds an ed25519 key in memory. Intended for tests and local
+/// setups only: it doesn't track last signed height/round so it has no double sign protection.
+#[derive(Debug, Clone)]
+pub struct MockSigner {
+    priv_key: tendermint_informal::PrivateKey,
+    chain_id: ChainId,
+}
+
+impl MockSigner {
+    pub fn new(priv_key: tendermint_informal::PrivateKey, chain_id: ChainId) -> Self {
+        Self { priv_key, chain_id }
+    }
+
+    /// Create signer from `priv_validator_key.json`
+    pub fn from_key_file(priv_validator_key_file: File, chain_id: ChainId) -> Result<Self, Error> {
+        let PrivValidatorKey { priv_key, .. } = serde_json::from_reader(priv_validator_key_file)?;
+
+        Ok(Self::new(priv_key, chain_id))
+    }
+
+    pub fn pub_key(&self) -> Result<PublicKey, Error> {
+        self.priv_key.public_key().try_into()
+    }
+
+    /// Dial validator on `laddr` and serve requests until validator closes connection.
+    /// Tries to connect until `timeout` elapses.
+    pub fn run(&self, laddr: &Address, timeout: Duration) -> Result<(), Error> {
+        let path = unix_socket_path(laddr)?;
+        let start = Instant::now();
+        let stream = loop {
+            match UnixStream::connect(&path) {
+                Ok(stream) => break stream,
+                Err(_) if start.elapsed() < timeout => std::thread::sleep(RETRY_INTERVAL),
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        self.serve(stream)
+    }
+
+    fn serve(&self, mut stream: UnixStream) -> Result<(), Error> {
+        while let Some(msg) = read_msg(&mut stream)? {
+            let response = match msg.sum {
+                Some(inner::Sum::PingRequest(_)) => {
+                    inner::Sum::PingResponse(inner::PingResponse {})
+                }
+                Some(inner::Sum::PubKeyRequest(request)) => {
+                    let (pub_key, error) = match self.check_chain_id(&request.chain_id) {
+                        Ok(()) => (Some(self.pub_key()?.into()), None),
+                        Err(e) => (None, Some(e)),
+                    };
+                    inner::Sum::PubKeyResponse(inner::PubKeyResponse { pub_key, error })
+                }
+                Some(inner::Sum::SignVoteRequest(request)) => {
+                    let signed = self.check_chain_id(&request.chain_id).and_then(|_| {
+                        let mut vote = request.vote.ok_or(remote_error("vote is empty"))?;
+                        let sign_bytes = tendermint_informal::Vote::try_from(vote.clone())
+                            .map_err(|e| remote_error(e.to_string()))?
+                            .to_signable_vec(self.chain_id.clone().into())
+                            .map_err(|e| remote_error(e.to_string()))?;
+                        vote.signature = self.sign(&sign_bytes)?;
+                        Ok(vote)
+                    });
+                    let (vote, error) = match signed {
+                        Ok(vote) => (Some(vote), None),
+                        Err(e) => (None, Some(e)),
+                    };
+                    inner::Sum::SignedVoteResponse(inner::SignedVoteResponse { vote, error })
+                }
+                Some(inner::Sum::SignProposalRequest(request)) => {
+                    let signed = self.check_chain_id(&request.chain_id).and_then(|_| {
+                        let mut proposal =
+                            request.proposal.ok_or(remote_error("proposal is empty"))?;
+                        let sign_bytes =
+                            tendermint_informal::proposal::Proposal::try_from(proposal.clone())
+                                .map_err(|e| remote_error(e.to_string()))?
+                                .to_signable_vec(self.chain_id.clone().into())
+                                .map_err(|e| remote_error(e.to_string()))?;
+                        proposal.signature = self.sign(&sign_bytes)?;
+                        Ok(proposal)
+                    });
+                    let (proposal, error) = match signed {
+                        Ok(proposal) => (Some(proposal), None),
+                        Err(e) => (None, Some(e)),
+                    };
+                    inner::Sum::SignedProposalResponse(inner::SignedProposalResponse {
+                        proposal,
+                        error,
+                    })
+                }
+                _ => Err(Error::Privval("unexpected request".to_owned()))?,
+            };
+
+            write_msg(&mut stream, response)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_chain_id(&self, chain_id: &str) -> Result<(), inner::RemoteSignerError> {
+        match self.chain_id.as_str() == chain_id {
+            true => Ok(()),
+            false => Err(remote_error(format!(
+                "unexpected chain id {chain_id}, expected {}",
+                self.chain_id
+            ))),
+        }
+    }
+
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, inner::RemoteSignerError> {
+        match &self.priv_key {
+            tendermint_informal::PrivateKey::Ed25519(key) => {
+                let key = ed25519_consensus::SigningKey::try_from(key.as_bytes())
+                    .map_err(|e| remote_error(e.to_string()))?;
+                Ok(key.sign(msg).to_bytes().to_vec())
+            }
+            _ => Err(remote_error("only ed25519 keys are supported")),
+        }
+    }
+}
+
+fn remote_error(description: impl Into<String>) -> inner::RemoteSignerError {
+    inner::RemoteSignerError {
+        code: 1,
+        description: description.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_proto::google::protobuf::Timestamp;
+
+    use super::*;
+
+    /// Run `f` against client connected to mock signer and wait for signer to finish
+    fn with_mock_signer<T>(
+        name: &str,
+        signer: MockSigner,
+        f: impl FnOnce(&mut PrivValidatorClient) -> T,
+    ) -> anyhow::Result<T> {
+        let socket =
+            std::env::temp_dir().join(format!("privval-{name}-{}.sock", std::process::id()));
+        let laddr: Address = format!("unix://{}", socket.display()).parse()?;
+
+        let listener = PrivValidatorListener::bind(&laddr)?;
+        let signer_laddr = laddr.clone();
+        let handle = std::thread::spawn(move || signer.run(&signer_laddr, Duration::from_secs(5)));
+
+        let mut client = listener.accept(Duration::from_secs(5))?;
+        let result = f(&mut client);
+        drop(client);
+
+        handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("signer thread panicked"))??;
+
+        Ok(result)
+    }
+
+    fn verify(signer: &MockSigner, sign_bytes: &[u8], signature: &[u8]) -> anyhow::Result<()> {
+        let key = ed25519_consensus::VerificationKey::try_from(
+            signer.priv_key.public_key().to_bytes().as_slice(),
+        )?;
+        let signature = ed25519_consensus::Signature::try_from(signature)?;
+
+        Ok(key.verify(&signature, sign_bytes)?)
+    }
+
+    fn vote() -> Vote {
+        Vote {
+            r#type: tendermint_proto::types::SignedMsgType::Prevote as i32,
+            height: 10,
+            round: 1,
+            block_id: None,
+            timestamp: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            validator_address: vec![1; 20],
+            validator_index: 0,
+            signature: vec![],
+        }
+    }
+
+    fn proposal() -> Proposal {
+        Proposal {
+            r#type: tendermint_proto::types::SignedMsgType::Proposal as i32,
+            height: 10,
+            round: 1,
+            pol_round: -1,
+            block_id: None,
+            timestamp: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            signature: vec![],
+        }
+    }
+
+    #[test]
+    fn fetch_pub_key_from_mock_signer() -> anyhow::Result<()> {
+        let chain_id = ChainId::default();
+        let signer = MockSigner::new(crate::crypto::new_private_key(), chain_id.clone());
+        let expected = signer.pub_key()?;
+
+        let actual = with_mock_signer("pub-key", signer, |client| {
+            client.ping()?;
+            client.pub_key(&chain_id)
+        })??;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_signer_rejects_other_chain() -> anyhow::Result<()> {
+        let signer = MockSigner::new(crate::crypto::new_private_key(), ChainId::default());
+        let other_chain = "other-chain".parse()?;
+
+        let (pub_key, vote, proposal) = with_mock_signer("chain", signer, |client| {
+            (
+                client.pub_key(&other_chain),
+                client.sign_vote(&other_chain, vote()),
+                client.sign_proposal(&other_chain, proposal()),
+            )
+        })?;
+
+        assert!(pub_key.is_err());
+        assert!(vote.is_err());
+        assert!(proposal.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_signer_signs_vote() -> anyhow::Result<()> {
+        let chain_id = ChainId::default();
+        let signer = MockSigner::new(crate::crypto::new_private_key(), chain_id.clone());
+
+        let signed = with_mock_signer("vote", signer.clone(), |client| {
+            client.sign_vote(&chain_id, vote())
+        })??;
+
+        let sign_bytes = tendermint_informal::Vote::try_from(vote())?
+            .to_signable_vec(chain_id.clone().into())?;
+        verify(&signer, &sign_bytes, &signed.signature)?;
+
+        assert_eq!(
+            Vote {
+                signature: vec![],
+                ..signed
+            },
+            vote()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_signer_signs_proposal() -> anyhow::Result<()> {
+        let chain_id = ChainId::default();
+        let signer = MockSigner::new(crate::crypto::new_private_key(), chain_id.clone());
+
+        let signed = with_mock_signer("proposal", signer.clone(), |client| {
+            client.sign_proposal(&chain_id, proposal())
+        })??;
+
+        let sign_bytes = tendermint_informal::proposal::Proposal::try_from(proposal())?
+            .to_signable_vec(chain_id.clone().into())?;
+        verify(&signer, &sign_bytes, &signed.signature)?;
+
+        assert_eq!(
+            Proposal {
+                signature: vec![],
+                ..signed
+            },
+            proposal()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tcp_address_is_rejected() -> anyhow::Result<()> {
+        let laddr: Address = "tcp://127.0.0.1:26659".parse()?;
+
+        assert!(parse_laddr("tcp://127.0.0.1:26659").is_err());
+        assert!(parse_laddr("unix:///tmp/privval.sock").is_ok());
+        assert!(PrivValidatorListener::bind(&laddr).is_err());
+
+        Ok(())
+    }
+}
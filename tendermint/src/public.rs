@@ -77,6 +77,10 @@ pub mod crypto {
     pub use crate::crypto::*;
 }
 
+pub mod privval {
+    pub use crate::privval::*;
+}
+
 pub mod rpc {
     pub mod response {
         pub mod tx {
@@ -1,21 +1,46 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use hyper::client::HttpConnector;
+use hyper_proxy::{Intercept, Proxy, ProxyConnector};
+use hyper_rustls::HttpsConnector;
+use serde::de::DeserializeOwned;
 use tendermint_rpc::endpoint::validators::DEFAULT_VALIDATORS_PER_PAGE;
-use tendermint_rpc::{Error, HttpClient as TendermintHttpClient, SimpleRequest};
+use tendermint_rpc::{
+    Error, HttpClient as TendermintHttpClient, Order, Scheme, SimpleRequest, Url,
+};
+
+use super::endpoint::{check_tx, genesis_chunked, num_unconfirmed_txs, unconfirmed_txs};
+use super::query::Query;
+use super::response::block::Response as BlockResponse;
+use super::response::tx::Response as TxResponse;
 
 pub use tendermint_rpc::Client;
 pub use tendermint_rpc::HttpClientUrl;
 pub use tendermint_rpc::Paging;
 
+/// Maximum number of items per page accepted by the search endpoints
+pub const MAX_PER_PAGE: u8 = 100;
+
 #[derive(Debug, Clone)]
 pub struct HttpClient {
     inner: TendermintHttpClient,
+    transport: Transport,
+    url: Url,
 }
 impl HttpClient {
     pub fn new<U>(url: U) -> Result<Self, Error>
     where
         U: TryInto<HttpClientUrl, Error = Error>,
     {
-        let client = TendermintHttpClient::new(url)?;
-        Ok(Self { inner: client })
+        let url: HttpClientUrl = url.try_into()?;
+        let url = Url::from(url);
+        let client = TendermintHttpClient::new(url.clone())?;
+        let transport = Transport::new(&url, None)?;
+        Ok(Self {
+            inner: client,
+            transport,
+            url,
+        })
     }
 
     pub fn new_with_proxy<U, P>(url: U, proxy_url: P) -> Result<Self, Error>
@@ -23,9 +48,73 @@ impl HttpClient {
         U: TryInto<HttpClientUrl, Error = Error>,
         P: TryInto<HttpClientUrl, Error = Error>,
     {
-        let client = TendermintHttpClient::new_with_proxy(url, proxy_url)?;
-        Ok(Self { inner: client })
+        let url: HttpClientUrl = url.try_into()?;
+        let url = Url::from(url);
+        let proxy_url = Url::from(proxy_url.try_into()?);
+        let client = TendermintHttpClient::new_with_proxy(url.clone(), proxy_url.clone())?;
+        let transport = Transport::new(&url, Some(&proxy_url))?;
+        Ok(Self {
+            inner: client,
+            transport,
+            url,
+        })
+    }
+}
+
+/// Transport of the JSON-RPC calls which aren't covered by [`tendermint_rpc::Client`],
+/// built the same way as the one of [`TendermintHttpClient`]
+#[derive(Debug, Clone)]
+enum Transport {
+    Http(hyper::Client<HttpConnector>),
+    Https(hyper::Client<HttpsConnector<HttpConnector>>),
+    HttpProxy(hyper::Client<ProxyConnector<HttpConnector>>),
+    HttpsProxy(hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>>),
+}
+
+impl Transport {
+    fn new(url: &Url, proxy_url: Option<&Url>) -> Result<Self, Error> {
+        let transport = match (url.scheme(), proxy_url) {
+            (Scheme::Http, None) => Self::Http(hyper::Client::new()),
+            (Scheme::Https, None) => {
+                Self::Https(hyper::Client::builder().build(HttpsConnector::with_native_roots()))
+            }
+            (Scheme::Http, Some(proxy_url)) => Self::HttpProxy(
+                hyper::Client::builder().build(proxy_connector(proxy_url, HttpConnector::new())?),
+            ),
+            (Scheme::Https, Some(proxy_url)) => Self::HttpsProxy(hyper::Client::builder().build(
+                proxy_connector(proxy_url, HttpsConnector::with_native_roots())?,
+            )),
+            (scheme, _) => {
+                return Err(Error::client_internal(format!(
+                    "unsupported scheme of http client: {scheme:?}"
+                )))
+            }
+        };
+
+        Ok(transport)
     }
+
+    async fn request(
+        &self,
+        request: hyper::Request<hyper::Body>,
+    ) -> Result<hyper::Response<hyper::Body>, hyper::Error> {
+        match self {
+            Self::Http(client) => client.request(request).await,
+            Self::Https(client) => client.request(request).await,
+            Self::HttpProxy(client) => client.request(request).await,
+            Self::HttpsProxy(client) => client.request(request).await,
+        }
+    }
+}
+
+fn proxy_connector<C>(proxy_url: &Url, connector: C) -> Result<ProxyConnector<C>, Error> {
+    let proxy_uri = proxy_url
+        .to_string()
+        .parse()
+        .map_err(|e: hyper::http::uri::InvalidUri| Error::client_internal(e.to_string()))?;
+    let proxy = Proxy::new(Intercept::All, proxy_uri);
+
+    ProxyConnector::from_proxy(connector, proxy).map_err(|e| Error::client_internal(e.to_string()))
 }
 
 #[async_trait::async_trait]
@@ -89,3 +178,270 @@ impl HttpClient {
         }
     }
 }
+
+impl HttpClient {
+    /// `/tx_search`: get all the transactions matching the query, fetching as many pages as needed.
+    pub async fn tx_search_all(
+        &self,
+        query: Query,
+        prove: bool,
+        order: Order,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let mut page = 1_u32;
+        let mut txs = Vec::new();
+        loop {
+            let response = self
+                .tx_search(query.clone(), prove, page, MAX_PER_PAGE, order.clone())
+                .await?;
+            let fetched = response.txs.len();
+            txs.extend(response.txs);
+            if fetched == 0 || txs.len() >= response.total_count as usize {
+                return Ok(txs);
+            }
+            page += 1;
+        }
+    }
+
+    /// `/block_search`: get all the blocks matching the query, fetching as many pages as needed.
+    pub async fn block_search_all(
+        &self,
+        query: Query,
+        order: Order,
+    ) -> Result<Vec<BlockResponse>, Error> {
+        let mut page = 1_u32;
+        let mut blocks = Vec::new();
+        loop {
+            let response = self
+                .block_search(query.clone(), page, MAX_PER_PAGE, order.clone())
+                .await?;
+            let fetched = response.blocks.len();
+            blocks.extend(response.blocks);
+            if fetched == 0 || blocks.len() >= response.total_count as usize {
+                return Ok(blocks);
+            }
+            page += 1;
+        }
+    }
+
+    /// `/unconfirmed_txs`: get the transactions in the mempool, up to `limit` of them
+    /// (the node caps it at 100).
+    pub async fn unconfirmed_txs(
+        &self,
+        limit: Option<u32>,
+    ) -> Result<unconfirmed_txs::Response, Error> {
+        let params = match limit {
+            Some(limit) => serde_json::json!({ "limit": limit.to_string() }),
+            None => serde_json::json!({}),
+        };
+        self.call(unconfirmed_txs::METHOD, params).await
+    }
+
+    /// `/num_unconfirmed_txs`: get the number of transactions in the mempool.
+    pub async fn num_unconfirmed_txs(&self) -> Result<num_unconfirmed_txs::Response, Error> {
+        self.call(num_unconfirmed_txs::METHOD, serde_json::json!({}))
+            .await
+    }
+
+    /// `/check_tx`: check a transaction without adding it to the mempool.
+    pub async fn check_tx(&self, tx: impl AsRef<[u8]>) -> Result<check_tx::Response, Error> {
+        let tx = String::from_utf8(subtle_encoding::base64::encode(tx))
+            .map_err(|e| Error::client_internal(e.to_string()))?;
+        self.call(check_tx::METHOD, serde_json::json!({ "tx": tx }))
+            .await
+    }
+
+    /// `/genesis_chunked`: get a chunk of the genesis document.
+    pub async fn genesis_chunked(&self, chunk: u64) -> Result<genesis_chunked::Response, Error> {
+        self.call(
+            genesis_chunked::METHOD,
+            serde_json::json!({ "chunk": chunk.to_string() }),
+        )
+        .await
+    }
+
+    /// `/genesis_chunked`: get the whole genesis document by fetching all of its chunks.
+    pub async fn genesis_chunked_all(&self) -> Result<Vec<u8>, Error> {
+        let first = self.genesis_chunked(0).await?;
+        let mut data = first.data;
+        for chunk in 1..first.total {
+            data.extend(self.genesis_chunked(chunk).await?.data);
+        }
+        Ok(data)
+    }
+
+    /// Calls a JSON-RPC method which isn't covered by [`tendermint_rpc::Client`].
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, Error> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params,
+        });
+        let uri: hyper::Uri = self
+            .url
+            .to_string()
+            .parse()
+            .map_err(|e: hyper::http::uri::InvalidUri| Error::client_internal(e.to_string()))?;
+        let request = hyper::Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(body.to_string()))
+            .map_err(|e| Error::client_internal(e.to_string()))?;
+
+        let response = self
+            .transport
+            .request(request)
+            .await
+            .map_err(|e| Error::client_internal(e.to_string()))?;
+        let bytes = hyper::body::to_bytes(response.into_body())
+            .await
+            .map_err(|e| Error::client_internal(e.to_string()))?;
+
+        let mut wrapper: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(Error::serde)?;
+        if let Some(error) = wrapper.get("error").filter(|error| !error.is_null()) {
+            return Err(Error::server(error.to_string()));
+        }
+        serde_json::from_value(wrapper["result"].take()).map_err(Error::serde)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::rpc::mock::MockRpcServer;
+
+    #[tokio::test]
+    async fn check_tx_decodes_response() {
+        let server = MockRpcServer::builder()
+            .with_handler(check_tx::METHOD, |params| {
+                assert_eq!(params["tx"], "AQID");
+                Ok(json!({
+                    "code": 5,
+                    "data": null,
+                    "log": "insufficient funds",
+                    "info": "",
+                    "gas_wanted": "200000",
+                    "gas_used": "41000",
+                    "events": [],
+                    "codespace": "sdk"
+                }))
+            })
+            .start()
+            .expect("server starts");
+        let client = HttpClient::new(server.url().as_str()).expect("valid url");
+
+        let response = client.check_tx([1, 2, 3]).await.expect("check_tx succeeds");
+
+        assert!(!response.is_ok());
+        assert_eq!(response.log, "insufficient funds");
+        assert_eq!(response.gas_wanted, 200000);
+        assert_eq!(response.gas_used, 41000);
+        assert_eq!(response.codespace, "sdk");
+    }
+
+    #[tokio::test]
+    async fn genesis_chunked_all_concatenates_chunks() {
+        let chunks = ["eyJjaGFpbl9p", "ZCI6InRlc3Qi", "fQ=="];
+        let server = MockRpcServer::builder()
+            .with_handler(genesis_chunked::METHOD, move |params| {
+                let chunk: usize = params["chunk"]
+                    .as_str()
+                    .and_then(|chunk| chunk.parse().ok())
+                    .ok_or("invalid chunk")?;
+                let data = chunks.get(chunk).ok_or("chunk out of range")?;
+                Ok(json!({
+                    "chunk": chunk.to_string(),
+                    "total": chunks.len().to_string(),
+                    "data": data
+                }))
+            })
+            .start()
+            .expect("server starts");
+        let client = HttpClient::new(server.url().as_str()).expect("valid url");
+
+        let genesis = client
+            .genesis_chunked_all()
+            .await
+            .expect("genesis_chunked succeeds");
+
+        let expected = [&b"{\"chain_i"[..], b"d\":\"test\"", b"}"].concat();
+        assert_eq!(genesis, expected);
+    }
+
+    #[tokio::test]
+    async fn unknown_method_returns_server_error() {
+        let server = MockRpcServer::builder()
+            .with_result(
+                num_unconfirmed_txs::METHOD,
+                json!({ "n_txs": "2", "total": "2", "total_bytes": "10" }),
+            )
+            .start()
+            .expect("server starts");
+        let client = HttpClient::new(server.url().as_str()).expect("valid url");
+
+        let count = client
+            .num_unconfirmed_txs()
+            .await
+            .expect("num_unconfirmed_txs succeeds");
+        assert_eq!(count.n_txs, 2);
+        assert_eq!(count.total_bytes, 10);
+
+        assert!(client.unconfirmed_txs(Some(10)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn raw_calls_go_through_proxy() {
+        let proxy = MockRpcServer::builder()
+            .with_result(
+                num_unconfirmed_txs::METHOD,
+                json!({ "n_txs": "1", "total": "1", "total_bytes": "4" }),
+            )
+            .start()
+            .expect("server starts");
+        // nothing listens on the port of the node, the request reaches the proxy only
+        let client = HttpClient::new_with_proxy("http://127.0.0.1:9", proxy.url().as_str())
+            .expect("valid urls");
+
+        let count = client
+            .num_unconfirmed_txs()
+            .await
+            .expect("num_unconfirmed_txs succeeds");
+        assert_eq!(count.n_txs, 1);
+    }
+
+    #[test]
+    fn transport_follows_scheme_and_proxy() {
+        let transport = |client: HttpClient| client.transport;
+
+        assert!(matches!(
+            transport(HttpClient::new("http://127.0.0.1:26657").expect("valid url")),
+            Transport::Http(_)
+        ));
+        assert!(matches!(
+            transport(HttpClient::new("https://127.0.0.1:26657").expect("valid url")),
+            Transport::Https(_)
+        ));
+        assert!(matches!(
+            transport(
+                HttpClient::new_with_proxy("http://127.0.0.1:26657", "http://127.0.0.1:8080")
+                    .expect("valid urls")
+            ),
+            Transport::HttpProxy(_)
+        ));
+        assert!(matches!(
+            transport(
+                HttpClient::new_with_proxy("https://127.0.0.1:26657", "http://127.0.0.1:8080")
+                    .expect("valid urls")
+            ),
+            Transport::HttpsProxy(_)
+        ));
+    }
+}
@@ -1 +1,118 @@
 pub use tendermint_rpc::endpoint::block::Response;
+
+use serde::{Deserialize, Serialize};
+use tendermint_informal::abci::Event;
+
+/// `/unconfirmed_txs`: transactions waiting in the mempool
+pub mod unconfirmed_txs {
+    use super::*;
+
+    pub const METHOD: &str = "unconfirmed_txs";
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Response {
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub n_txs: u64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub total: u64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub total_bytes: u64,
+        #[serde(with = "base64_list", default)]
+        pub txs: Vec<Vec<u8>>,
+    }
+}
+
+/// `/num_unconfirmed_txs`: number of transactions waiting in the mempool
+pub mod num_unconfirmed_txs {
+    use super::*;
+
+    pub const METHOD: &str = "num_unconfirmed_txs";
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Response {
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub n_txs: u64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub total: u64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub total_bytes: u64,
+    }
+}
+
+/// `/check_tx`: runs `CheckTx` on a transaction without adding it to the mempool
+pub mod check_tx {
+    use super::*;
+
+    pub const METHOD: &str = "check_tx";
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Response {
+        #[serde(default)]
+        pub code: u32,
+        #[serde(with = "crate::types::serializers::bytes::base64string", default)]
+        pub data: Vec<u8>,
+        #[serde(default)]
+        pub log: String,
+        #[serde(default)]
+        pub info: String,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub gas_wanted: i64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub gas_used: i64,
+        #[serde(default)]
+        pub events: Vec<Event>,
+        #[serde(default)]
+        pub codespace: String,
+    }
+
+    impl Response {
+        pub fn is_ok(&self) -> bool {
+            self.code == 0
+        }
+    }
+}
+
+/// `/genesis_chunked`: genesis document split in base64 encoded chunks
+pub mod genesis_chunked {
+    use super::*;
+
+    pub const METHOD: &str = "genesis_chunked";
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Response {
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub chunk: u64,
+        #[serde(with = "crate::types::serializers::from_str")]
+        pub total: u64,
+        #[serde(with = "crate::types::serializers::bytes::base64string")]
+        pub data: Vec<u8>,
+    }
+}
+
+/// Serialize a list of byte vectors as base64 strings
+mod base64_list {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use subtle_encoding::base64;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Vec<String>>::deserialize(deserializer)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| base64::decode(s).map_err(serde::de::Error::custom))
+            .collect()
+    }
+
+    pub fn serialize<S>(value: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value
+            .iter()
+            .map(|v| String::from_utf8(base64::encode(v)).map_err(serde::ser::Error::custom))
+            .collect::<Result<Vec<_>, _>>()?
+            .serialize(serializer)
+    }
+}
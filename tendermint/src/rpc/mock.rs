@@ -0,0 +1,129 @@
+//! Local JSON-RPC server answering with canned results, to test code using the RPC client
+//! without running a node.
+
+use std::{collections::HashMap, convert::Infallible, net::SocketAddr, sync::Arc};
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use serde_json::Value;
+use tokio::task::JoinHandle;
+
+type Handler = Arc<dyn Fn(&Value) -> Result<Value, String> + Send + Sync>;
+
+/// Builder of a [`MockRpcServer`] mapping each JSON-RPC method to a handler of its params
+#[derive(Default, Clone)]
+pub struct MockRpcServerBuilder {
+    handlers: HashMap<String, Handler>,
+}
+
+impl MockRpcServerBuilder {
+    /// Answers the method with the same result whatever the params
+    pub fn with_result(self, method: &str, result: Value) -> Self {
+        self.with_handler(method, move |_| Ok(result.clone()))
+    }
+
+    /// Answers the method with the result, or the error message, computed from the params
+    pub fn with_handler(
+        mut self,
+        method: &str,
+        handler: impl Fn(&Value) -> Result<Value, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.handlers.insert(method.to_owned(), Arc::new(handler));
+        self
+    }
+
+    /// Starts listening on a random local port
+    pub fn start(self) -> Result<MockRpcServer, hyper::Error> {
+        let handlers = Arc::new(self.handlers);
+        let make_service = make_service_fn(move |_| {
+            let handlers = handlers.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let handlers = handlers.clone();
+                    async move { Ok::<_, Infallible>(handle(&handlers, request).await) }
+                }))
+            }
+        });
+
+        let server = Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))?.serve(make_service);
+        let addr = server.local_addr();
+        let handle = tokio::spawn(async move {
+            let _ = server.await;
+        });
+
+        Ok(MockRpcServer { addr, handle })
+    }
+}
+
+/// Running mock server, stopped when dropped
+#[derive(Debug)]
+pub struct MockRpcServer {
+    addr: SocketAddr,
+    handle: JoinHandle<()>,
+}
+
+impl MockRpcServer {
+    pub fn builder() -> MockRpcServerBuilder {
+        MockRpcServerBuilder::default()
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Url to build a client from
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for MockRpcServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn handle(handlers: &HashMap<String, Handler>, request: Request<Body>) -> Response<Body> {
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(e) => return json_response(rpc_error(Value::Null, -32700, &e.to_string())),
+    };
+    let request: Value = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => return json_response(rpc_error(Value::Null, -32700, &e.to_string())),
+    };
+
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = request
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+    let response = match handlers.get(method) {
+        Some(handler) => match handler(&params) {
+            Ok(result) => serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(message) => rpc_error(id, -32603, &message),
+        },
+        None => rpc_error(id, -32601, &format!("method {method} not found")),
+    };
+
+    json_response(response)
+}
+
+fn rpc_error(id: Value, code: i32, message: &str) -> Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message, "data": "" },
+    })
+}
+
+fn json_response(value: Value) -> Response<Body> {
+    Response::builder()
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(value.to_string()))
+        .expect("static response parts are valid")
+}
@@ -1,8 +1,11 @@
 pub mod client;
 pub mod endpoint;
 pub mod error;
+#[cfg(any(test, feature = "mocks"))]
+pub mod mock;
 pub mod query;
 pub mod response;
+pub mod subscription;
 pub mod url;
 
 pub use tendermint_rpc::Order;
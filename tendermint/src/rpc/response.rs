@@ -1,15 +1,70 @@
+pub mod abci_info {
+    pub use tendermint_rpc::endpoint::abci_info::Response;
+}
+
+pub mod abci_query {
+    pub use tendermint_rpc::endpoint::abci_query::Response;
+}
+
 pub mod block {
     pub use tendermint_rpc::endpoint::block::Response;
+
+    pub mod results {
+        pub use tendermint_rpc::endpoint::block_results::Response;
+    }
+
+    pub mod search {
+        pub use tendermint_rpc::endpoint::block_search::Response;
+    }
+}
+
+pub mod blockchain {
+    pub use tendermint_rpc::endpoint::blockchain::Response;
+}
+
+pub mod commit {
+    pub use tendermint_rpc::endpoint::commit::Response;
+}
+
+pub mod consensus_state {
+    pub use tendermint_rpc::endpoint::consensus_state::Response;
+}
+
+pub mod genesis {
+    pub use tendermint_rpc::endpoint::genesis::Response;
+
+    pub mod chunked {
+        pub use crate::rpc::endpoint::genesis_chunked::Response;
+    }
+}
+
+pub mod mempool {
+    pub use crate::rpc::endpoint::unconfirmed_txs::Response as UnconfirmedTxsResponse;
+
+    pub use crate::rpc::endpoint::num_unconfirmed_txs::Response as NumUnconfirmedTxsResponse;
+}
+
+pub mod net_info {
+    pub use tendermint_rpc::endpoint::net_info::Response;
+}
+
+pub mod status {
+    pub use tendermint_rpc::endpoint::status::Response;
 }
 
 pub mod tx {
     pub use tendermint_rpc::endpoint::tx::Response;
 
     pub mod broadcast {
+        pub use tendermint_rpc::endpoint::broadcast::tx_async::Response as AsyncResponse;
         pub use tendermint_rpc::endpoint::broadcast::tx_commit::Response;
         pub use tendermint_rpc::endpoint::broadcast::tx_sync::Response as SyncResponse;
     }
 
+    pub mod check {
+        pub use crate::rpc::endpoint::check_tx::Response;
+    }
+
     pub mod search {
         pub use tendermint_rpc::endpoint::tx_search::Response;
     }
@@ -0,0 +1,24 @@
+//! Subscriptions to the events of a node over a WebSocket connection
+
+use tendermint_rpc::Error;
+use tokio::task::JoinHandle;
+
+pub use tendermint_rpc::event::{Event, EventData};
+pub use tendermint_rpc::query::EventType;
+pub use tendermint_rpc::{
+    Subscription, SubscriptionClient, WebSocketClient, WebSocketClientDriver, WebSocketClientUrl,
+};
+
+/// Connects to the WebSocket endpoint of a node, e.g. `ws://127.0.0.1:26657/websocket`,
+/// and spawns the driver of the connection.
+///
+/// The driver stops once [`WebSocketClient::close`] is called, its result can be awaited
+/// through the returned handle.
+pub async fn connect<U>(url: U) -> Result<(WebSocketClient, JoinHandle<Result<(), Error>>), Error>
+where
+    U: TryInto<WebSocketClientUrl, Error = Error>,
+{
+    let (client, driver) = WebSocketClient::new(url).await?;
+    let handle = tokio::spawn(driver.run());
+    Ok((client, handle))
+}
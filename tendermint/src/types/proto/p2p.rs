@@ -1,9 +1,8 @@
-// the alternatives are place below, for now we don't need conversions
-// mod inner {
-//     pub use tendermint_proto::p2p::DefaultNodeInfo;
-//     pub use tendermint_proto::p2p::DefaultNodeInfoOther;
-//     pub use tendermint_proto::p2p::ProtocolVersion;
-// }
+mod inner {
+    pub use tendermint_proto::p2p::DefaultNodeInfo;
+    pub use tendermint_proto::p2p::DefaultNodeInfoOther;
+    pub use tendermint_proto::p2p::ProtocolVersion;
+}
 
 use crate::informal::node::{Info, OtherInfo, ProtocolVersionInfo};
 use serde::{Deserialize, Serialize};
@@ -46,6 +45,32 @@ impl From<Info> for DefaultNodeInfo {
     }
 }
 
+impl From<DefaultNodeInfo> for inner::DefaultNodeInfo {
+    fn from(
+        DefaultNodeInfo {
+            protocol_version,
+            default_node_id,
+            listen_addr,
+            network,
+            version,
+            channels,
+            moniker,
+            other,
+        }: DefaultNodeInfo,
+    ) -> Self {
+        Self {
+            protocol_version: protocol_version.map(Into::into),
+            default_node_id,
+            listen_addr,
+            network,
+            version,
+            channels,
+            moniker,
+            other: other.map(Into::into),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ProtocolVersion {
     pub p2p: u64,
@@ -59,6 +84,12 @@ impl From<ProtocolVersionInfo> for ProtocolVersion {
     }
 }
 
+impl From<ProtocolVersion> for inner::ProtocolVersion {
+    fn from(ProtocolVersion { p2p, block, app }: ProtocolVersion) -> Self {
+        Self { p2p, block, app }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DefaultNodeInfoOther {
     // TODO: original is a String
@@ -80,3 +111,17 @@ impl From<OtherInfo> for DefaultNodeInfoOther {
         }
     }
 }
+
+impl From<DefaultNodeInfoOther> for inner::DefaultNodeInfoOther {
+    fn from(
+        DefaultNodeInfoOther {
+            tx_index,
+            rpc_address,
+        }: DefaultNodeInfoOther,
+    ) -> Self {
+        Self {
+            tx_index: if tx_index { "on" } else { "off" }.to_owned(),
+            rpc_address,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Implementation
 
 mod node_db;
+mod proof;
 mod query_tree;
 mod range;
 mod tree;
@@ -8,5 +9,6 @@ mod tree;
 #[allow(missing_docs)]
 mod tree_v3;
 
+pub use proof::*;
 pub use query_tree::*;
 pub use tree::*;
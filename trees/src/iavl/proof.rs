@@ -0,0 +1,45 @@
+//! Existence proofs of IAVL tree keys
+
+use sha2::{Digest, Sha256};
+
+use crate::merkle::Sha256Hash;
+
+use super::tree::encode_bytes;
+
+/// Proof that a key value pair belongs to a tree with a given root hash.
+/// Layout follows the ICS-23 `ExistenceProof` for the IAVL spec, so it converts to it field by field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistenceProof {
+    /// Proven key
+    pub key: Vec<u8>,
+    /// Value of the key
+    pub value: Vec<u8>,
+    /// Varint encoded height, size and version of the leaf
+    pub leaf_prefix: Vec<u8>,
+    /// Inner nodes from the leaf up to the root
+    pub path: Vec<InnerOp>,
+}
+
+/// Parent hash is `sha256(prefix || child_hash || suffix)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InnerOp {
+    /// Bytes preceding the child hash
+    pub prefix: Vec<u8>,
+    /// Bytes following the child hash
+    pub suffix: Vec<u8>,
+}
+
+impl ExistenceProof {
+    /// Root hash of the tree the proof was built from
+    pub fn calculate_root(&self) -> Sha256Hash {
+        let mut leaf = self.leaf_prefix.clone();
+        leaf.extend(encode_bytes(&self.key));
+        leaf.extend(encode_bytes(&Sha256::digest(&self.value)));
+
+        self.path
+            .iter()
+            .fold(Sha256::digest(leaf).into(), |hash, op| {
+                Sha256::digest([op.prefix.as_slice(), &hash, &op.suffix].concat()).into()
+            })
+    }
+}
@@ -1,10 +1,11 @@
 use std::{num::NonZero, ops::RangeBounds};
 
 use database::Database;
+use integer_encoding::VarInt;
 
-use crate::Error;
+use crate::{merkle::HASH_LENGTH, Error};
 
-use super::{node_db::NodeDB, Node, Range, Tree};
+use super::{node_db::NodeDB, tree::encode_bytes, ExistenceProof, InnerOp, Node, Range, Tree};
 
 /// QueryTree is a "checked out" Tree at a given height which
 /// borrows a Tree's NodeDb
@@ -21,7 +22,18 @@ impl<DB: Database> QueryTree<DB> {
             .map(|this| this.get())
             .unwrap_or(tree.loaded_version);
 
-        if tree.versions.contains(&version) {
+        // the store was mounted after this version, so it was empty back then
+        let before_first_version = match tree.versions.first() {
+            Some(first) => version < *first,
+            None => version > 0 && version <= tree.loaded_version,
+        };
+
+        if before_first_version {
+            Ok(QueryTree {
+                root: None,
+                node_db: tree.node_db.clone(),
+            })
+        } else if tree.versions.contains(&version) {
             let root = tree.node_db.get_root_node(version).expect(
                 "the requested version is in the list of versions so the node should be in the db",
             );
@@ -92,6 +104,77 @@ impl<DB: Database> QueryTree<DB> {
         }
     }
 
+    /// Return proof that the key is in the tree or `None` if it isn't
+    pub fn get_proof(&self, key: &[u8]) -> Option<ExistenceProof> {
+        let mut loop_node = self.root.as_deref()?;
+        let mut cached_node;
+        let mut path = Vec::new();
+
+        loop {
+            match loop_node {
+                Node::Leaf(leaf) => {
+                    if leaf.key != key {
+                        return None;
+                    }
+
+                    // the path was collected from the root down
+                    path.reverse();
+
+                    return Some(ExistenceProof {
+                        key: leaf.key.clone(),
+                        value: leaf.value.clone(),
+                        leaf_prefix: leaf.hash_prefix(),
+                        path,
+                    });
+                }
+                Node::Inner(node) => {
+                    let mut prefix = node.hash_prefix();
+
+                    if key < &node.key {
+                        prefix.extend(HASH_LENGTH.encode_var_vec());
+                        path.push(InnerOp {
+                            prefix,
+                            suffix: encode_bytes(&node.right_hash),
+                        });
+
+                        match &node.left_node {
+                            Some(left_node) => loop_node = left_node,
+                            None => {
+                                let left_node = self
+                                    .node_db
+                                    .get_node(&node.left_hash)
+                                    .expect("node db should contain all nodes");
+
+                                cached_node = left_node;
+                                loop_node = &cached_node;
+                            }
+                        }
+                    } else {
+                        prefix.extend(encode_bytes(&node.left_hash));
+                        prefix.extend(HASH_LENGTH.encode_var_vec());
+                        path.push(InnerOp {
+                            prefix,
+                            suffix: Vec::new(),
+                        });
+
+                        match &node.right_node {
+                            Some(right_node) => loop_node = right_node,
+                            None => {
+                                let right_node = self
+                                    .node_db
+                                    .get_node(&node.right_hash)
+                                    .expect("node db should contain all nodes");
+
+                                cached_node = right_node;
+                                loop_node = &cached_node;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Return range iterator
     pub fn range<R: RangeBounds<RB>, RB: AsRef<[u8]>>(&self, range: R) -> Range<'_, DB, RB, R> {
         match &self.root {
@@ -130,6 +213,43 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn new_query_tree_before_initial_version_is_empty() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set_initial_version(5).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, Some(nz::u32!(3))).unwrap_test();
+        assert_eq!(query_tree.get(b"alice".as_slice()), None);
+
+        let query_tree = QueryTree::new(&tree, Some(nz::u32!(6))).unwrap_test();
+        assert_eq!(query_tree.get(b"alice".as_slice()), Some(b"abc".to_vec()));
+    }
+
+    #[test]
+    fn get_proof_works() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in 0..20u8 {
+            tree.set(vec![i], vec![i, i]);
+        }
+        let (root_hash, _) = tree.save_version().unwrap_test();
+        tree.set(vec![5], vec![0]);
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, Some(nz::u32!(1))).unwrap_test();
+        for i in 0..20u8 {
+            let proof = query_tree.get_proof(&[i]).unwrap_test();
+
+            assert_eq!(proof.value, vec![i, i]);
+            assert_eq!(proof.calculate_root(), root_hash);
+        }
+
+        assert_eq!(query_tree.get_proof(&[20]), None);
+    }
+
     #[test]
     fn new_query_tree_works_empty_tree() {
         let db = MemDB::new();
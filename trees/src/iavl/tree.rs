@@ -36,6 +36,20 @@ pub(crate) struct InnerNode {
 }
 
 impl InnerNode {
+    /// Height, size and version which precede the children hashes in the hashed bytes
+    pub(crate) fn hash_prefix(&self) -> Vec<u8> {
+        // NOTE: i64 is used here for parameters for compatibility wih cosmos
+        let height: i64 = self.height.into();
+        let size: i64 = self.size.into();
+        let version: i64 = self.version.into();
+
+        let mut prefix = height.encode_var_vec();
+        prefix.extend(size.encode_var_vec());
+        prefix.extend(version.encode_var_vec());
+
+        prefix
+    }
+
     fn get_mut_left_node<T: Database>(&mut self, node_db: &NodeDB<T>) -> &mut Node {
         self.left_node.get_or_insert_with(|| {
             node_db
@@ -145,16 +159,24 @@ pub(crate) struct LeafNode {
 }
 
 impl LeafNode {
-    fn hash_serialize(&self) -> Vec<u8> {
+    /// Height, size and version which precede the key and the value in the hashed bytes
+    pub(crate) fn hash_prefix(&self) -> Vec<u8> {
         // NOTE: i64 is used here for parameters for compatibility wih cosmos
         let height: i64 = 0;
         let size: i64 = 1;
         let version: i64 = self.version.into();
+
+        let mut prefix = height.encode_var_vec();
+        prefix.extend(size.encode_var_vec());
+        prefix.extend(version.encode_var_vec());
+
+        prefix
+    }
+
+    fn hash_serialize(&self) -> Vec<u8> {
         let hashed_value = Sha256::digest(&self.value);
 
-        let mut serialized = height.encode_var_vec();
-        serialized.extend(size.encode_var_vec());
-        serialized.extend(version.encode_var_vec());
+        let mut serialized = self.hash_prefix();
         serialized.extend(encode_bytes(&self.key));
         serialized.extend(encode_bytes(&hashed_value));
 
@@ -417,14 +439,7 @@ impl Node {
         match &self {
             Node::Leaf(node) => node.hash_serialize(),
             Node::Inner(node) => {
-                // NOTE: i64 is used here for parameters for compatibility wih cosmos
-                let height: i64 = node.height.into();
-                let size: i64 = node.size.into();
-                let version: i64 = node.version.into();
-
-                let mut serialized = height.encode_var_vec();
-                serialized.extend(size.encode_var_vec());
-                serialized.extend(version.encode_var_vec());
+                let mut serialized = node.hash_prefix();
                 serialized.extend(encode_bytes(&node.left_hash));
                 serialized.extend(encode_bytes(&node.right_hash));
 
@@ -616,6 +631,17 @@ where
         Ok((root_hash, self.loaded_version))
     }
 
+    /// Start an empty tree at `version`, so the next saved version is `version + 1`.
+    /// Used to mount a new store in the middle of the chain.
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), Error> {
+        if !self.versions.is_empty() || self.root.is_some() {
+            return Err(Error::Overwrite);
+        }
+
+        self.loaded_version = version;
+        Ok(())
+    }
+
     /// Return hash of root
     pub fn root_hash(&self) -> [u8; 32] {
         match &self.root {
@@ -973,7 +999,7 @@ where
     }
 }
 
-fn encode_bytes(bz: &[u8]) -> Vec<u8> {
+pub(crate) fn encode_bytes(bz: &[u8]) -> Vec<u8> {
     let mut enc_bytes = bz.len().encode_var_vec();
     enc_bytes.extend_from_slice(bz);
 
@@ -1174,6 +1200,19 @@ mod tests {
                ▼             ▼
              k2 v5         k3 v6
     */
+    #[test]
+    fn set_initial_version_works() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set_initial_version(10).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+
+        let (_, version) = tree.save_version().unwrap_test();
+
+        assert_eq!(version, 11);
+        assert_eq!(tree.set_initial_version(20), Err(Error::Overwrite));
+    }
+
     #[test]
     fn remove_leaf_works() {
         let db = MemDB::new();
@@ -68,8 +68,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        match query {
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
             AuthNodeQueryRequest::Account(req) => {
                 let res = self.query_account(ctx, req);
                 AuthNodeQueryResponse::Account(res)
@@ -82,7 +82,9 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
                 let res = self.query_params(ctx, req);
                 AuthNodeQueryResponse::Params(res)
             }
-        }
+        };
+
+        Ok(res)
     }
 
     fn run_ante_checks<DB: Database>(
@@ -1,5 +1,6 @@
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::block_height,
     types::address::AccAddress,
 };
 use ibc_proto::cosmos::auth::v1beta1::{
@@ -42,8 +43,9 @@ where
         request: Request<QueryAccountsRequest>,
     ) -> Result<Response<QueryAccountsResponse>, Status> {
         info!("Received a gRPC request auth::accounts");
+        let height = block_height(&request)?;
         let req = AuthNodeQueryRequest::Accounts(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: AuthNodeQueryResponse = response.try_into()?;
         let AuthNodeQueryResponse::Accounts(response) = response else {
             return Err(Status::internal(ERROR_STATE_MSG));
@@ -56,8 +58,9 @@ where
         request: Request<QueryAccountRequest>,
     ) -> Result<Response<QueryAccountResponse>, Status> {
         info!("Received a gRPC request auth::account");
+        let height = block_height(&request)?;
         let req = AuthNodeQueryRequest::Account(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: AuthNodeQueryResponse = response.try_into()?;
         let AuthNodeQueryResponse::Account(response) = response else {
             return Err(Status::internal(ERROR_STATE_MSG));
@@ -78,8 +81,9 @@ where
         request: Request<AuthQueryParamsRequest>,
     ) -> Result<Response<AuthQueryParamsResponse>, Status> {
         info!("Received a gRPC request auth::params");
+        let height = block_height(&request)?;
         let req = AuthNodeQueryRequest::Params(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: AuthNodeQueryResponse = response.try_into()?;
         let AuthNodeQueryResponse::Params(response) = response else {
             return Err(Status::internal(ERROR_STATE_MSG));
@@ -4,7 +4,9 @@ use gears::application::keepers::params::ParamsKeeper;
 
 use gears::core::serializers::serialize_number_to_string;
 use gears::extensions::corruption::UnwrapCorrupt;
-use gears::params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey};
+use gears::params::{
+    ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
+};
 
 use gears::x::keepers::auth::AuthParams;
 use serde::{Deserialize, Serialize};
@@ -144,6 +146,17 @@ impl ParamsDeserialize for AuthsParams {
                 .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match key {
+            KEY_MAX_MEMO_CHARACTERS
+            | KEY_TX_SIG_LIMIT
+            | KEY_TX_SIZE_COST_PER_BYTE
+            | KEY_SIG_VERIFY_COST_ED25519
+            | KEY_SIG_VERIFY_COST_SECP256K1 => ParamKind::U64.validate(key, value),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 impl AuthParams for AuthsParams {
@@ -179,32 +192,6 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for AuthParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_MAX_MEMO_CHARACTERS => ParamKind::U64
-                .parse_param(value.as_ref().to_vec())
-                .unsigned_64()
-                .is_some(),
-            KEY_TX_SIG_LIMIT => ParamKind::U64
-                .parse_param(value.as_ref().to_vec())
-                .unsigned_64()
-                .is_some(),
-            KEY_TX_SIZE_COST_PER_BYTE => ParamKind::U64
-                .parse_param(value.as_ref().to_vec())
-                .unsigned_64()
-                .is_some(),
-            KEY_SIG_VERIFY_COST_ED25519 => ParamKind::U64
-                .parse_param(value.as_ref().to_vec())
-                .unsigned_64()
-                .is_some(),
-            KEY_SIG_VERIFY_COST_SECP256K1 => ParamKind::U64
-                .parse_param(value.as_ref().to_vec())
-                .unsigned_64()
-                .is_some(),
-            _ => false,
-        }
-    }
 }
 
 #[cfg(test)]
@@ -93,8 +93,8 @@ impl<
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        match query {
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
             BankNodeQueryRequest::Balance(req) => {
                 let res = self.query_balance(ctx, req);
                 BankNodeQueryResponse::Balance(res)
@@ -124,7 +124,9 @@ impl<
                 let balance = self.query_spendable(ctx, req);
                 BankNodeQueryResponse::Spendable(balance)
             }
-        }
+        };
+
+        Ok(res)
     }
 
     fn run_ante_checks<DB: Database>(
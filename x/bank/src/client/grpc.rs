@@ -1,4 +1,7 @@
-use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::block_height,
+};
 use ibc_proto::cosmos::bank::v1beta1::{
     query_server::{Query, QueryServer},
     QueryAllBalancesRequest, QueryAllBalancesResponse,
@@ -39,8 +42,9 @@ where
         request: Request<RawQueryBalanceRequest>,
     ) -> Result<Response<RawQueryBalanceResponse>, Status> {
         info!("Received a gRPC request bank::balance");
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::Balance(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::Balance(response) = response {
             Ok(Response::new(response.into()))
@@ -53,8 +57,9 @@ where
         &self,
         request: Request<QueryAllBalancesRequest>,
     ) -> Result<Response<QueryAllBalancesResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::AllBalances(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::AllBalances(response) = response {
             Ok(Response::new(response.into()))
@@ -67,8 +72,9 @@ where
         &self,
         request: Request<QuerySpendableBalancesRequest>,
     ) -> Result<Response<QuerySpendableBalancesResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::Spendable(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::Spendable(response) = response {
             Ok(Response::new(response.into()))
@@ -81,8 +87,9 @@ where
         &self,
         request: Request<QueryTotalSupplyRequest>,
     ) -> Result<Response<QueryTotalSupplyResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::TotalSupply(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::TotalSupply(response) = response {
             Ok(Response::new(response.into()))
@@ -95,8 +102,9 @@ where
         &self,
         request: Request<QuerySupplyOfRequest>,
     ) -> Result<Response<QuerySupplyOfResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::SupplyOf(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::SupplyOf(response) = response {
             Ok(Response::new(response.into()))
@@ -109,8 +117,9 @@ where
         &self,
         request: Request<QueryParamsRequest>,
     ) -> Result<Response<QueryParamsResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::Params(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::Params(response) = response {
             Ok(Response::new(response.into()))
@@ -123,8 +132,9 @@ where
         &self,
         request: Request<QueryDenomMetadataRequest>,
     ) -> Result<Response<QueryDenomMetadataResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::DenomMetadata(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::DenomMetadata(response) = response {
             Ok(Response::new(response.into()))
@@ -137,8 +147,9 @@ where
         &self,
         request: Request<QueryDenomsMetadataRequest>,
     ) -> Result<Response<QueryDenomsMetadataResponse>, Status> {
+        let height = block_height(&request)?;
         let req = BankNodeQueryRequest::DenomsMetadata(request.into_inner().try_into()?);
-        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let response: BankNodeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let BankNodeQueryResponse::DenomsMetadata(response) = response {
             Ok(Response::new(response.into()))
@@ -1,7 +1,9 @@
 use gears::application::keepers::params::ParamsKeeper;
 use gears::derive::Protobuf;
 use gears::extensions::corruption::UnwrapCorrupt;
-use gears::params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey};
+use gears::params::{
+    ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
+};
 use gears::types::denom::Denom;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -83,6 +85,16 @@ impl ParamsDeserialize for BankParams {
             .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match key {
+            KEY_SEND_ENABLED => serde_json::from_slice::<Vec<SendEnabled>>(value)
+                .map(|_| ())
+                .map_err(|e| ParamsValidationError::invalid_value(key, e)),
+            KEY_DEFAULT_SEND_ENABLED => ParamKind::Bool.validate(key, value),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -96,15 +108,4 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BankParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_SEND_ENABLED => ParamKind::Bool
-                .parse_param(value.as_ref().to_vec())
-                .boolean()
-                .is_some(),
-            KEY_DEFAULT_SEND_ENABLED => false, // add logic when we start setting this key
-            _ => false,
-        }
-    }
 }
@@ -1,10 +1,12 @@
 use crate::{
     errors::DistributionTxError, GenesisState, Keeper, Message, QueryCommunityPoolRequest,
-    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    QueryDelegatorParams, QueryDelegatorTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
+    QueryWithdrawAllRewardsResponse,
 };
 use gears::{
     baseapp::errors::QueryError,
@@ -33,15 +35,23 @@ pub enum DistributionNodeQueryRequest {
     ValidatorCommission(QueryValidatorCommissionRequest),
     ValidatorSlashes(QueryValidatorSlashesRequest),
     DelegatorTotalRewards(QueryDelegatorParams),
+    DelegationRewards(QueryDelegationRewardsRequest),
+    DelegatorValidators(QueryWithdrawAllRewardsRequest),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressRequest),
     CommunityPool(QueryCommunityPoolRequest),
     Params(QueryParamsRequest),
 }
 #[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum DistributionNodeQueryResponse {
     ValidatorOutstandingRewards(QueryValidatorOutstandingRewardsResponse),
     ValidatorCommission(QueryValidatorCommissionResponse),
     ValidatorSlashes(QueryValidatorSlashesResponse),
     DelegatorTotalRewards(QueryDelegatorTotalRewardsResponse),
+    /// `None` if the delegation doesn't exist
+    DelegationRewards(Option<QueryDelegationRewardsResponse>),
+    DelegatorValidators(QueryWithdrawAllRewardsResponse),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressResponse),
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
@@ -151,6 +161,15 @@ impl<
                     .encode_vec()
                     .into())
             }
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => {
+                let req = QueryDelegatorWithdrawAddressRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_delegator_withdraw_address(ctx, req)
+                    .encode_vec()
+                    .into())
+            }
             "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
                 let req = QueryCommunityPoolRequest::decode(query.data)?;
 
@@ -195,6 +214,21 @@ impl<
                     self.keeper.query_delegator_total_rewards(ctx, req),
                 )
             }
+            DistributionNodeQueryRequest::DelegationRewards(req) => {
+                DistributionNodeQueryResponse::DelegationRewards(
+                    self.keeper.query_delegation_rewards(ctx, req).ok(),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorValidators(req) => {
+                DistributionNodeQueryResponse::DelegatorValidators(
+                    self.keeper.query_delegator_validators(ctx, req),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorWithdrawAddress(req) => {
+                DistributionNodeQueryResponse::DelegatorWithdrawAddress(
+                    self.keeper.query_delegator_withdraw_address(ctx, req),
+                )
+            }
             DistributionNodeQueryRequest::CommunityPool(req) => {
                 DistributionNodeQueryResponse::CommunityPool(
                     self.keeper.query_community_pool(ctx, req),
@@ -8,7 +8,7 @@ use crate::{
 use clap::{Args, Subcommand};
 use gears::{
     application::handlers::client::QueryHandler,
-    baseapp::Query,
+    baseapp::{Query, QueryResponse},
     cli::pagination::CliPaginationRequest,
     core::Protobuf,
     extensions::try_map::FallibleMapExt,
@@ -218,3 +218,16 @@ pub enum DistributionQueryResponse {
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
+
+impl QueryResponse for DistributionQueryResponse {
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            DistributionQueryResponse::ValidatorOutstandingRewards(var) => var.encode_vec(),
+            DistributionQueryResponse::ValidatorCommission(var) => var.encode_vec(),
+            DistributionQueryResponse::ValidatorSlashes(var) => var.encode_vec(),
+            DistributionQueryResponse::DelegationRewards(var) => var.encode_vec(),
+            DistributionQueryResponse::CommunityPool(var) => var.encode_vec(),
+            DistributionQueryResponse::Params(var) => var.encode_vec(),
+        }
+    }
+}
@@ -38,7 +38,7 @@ pub enum DistributionCommands {
     FundCommunityPool { amount: UnsignedCoin },
 }
 
-pub fn run_staking_tx_command(
+pub fn run_distribution_tx_command(
     ctx: &ClientTxContext,
     args: DistributionTxCli,
     from_address: AccAddress,
@@ -1,45 +1,237 @@
-// TODO: uncomment and add impl for new version of ibc_proto
-// use crate::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
-// use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
-// use ibc_proto::cosmos::distribution::v1beta1::{
-//     query_server::{Query, QueryServer},
-//     QueryParamsRequest, QueryParamsResponse,
-// };
-// use std::marker::PhantomData;
-// use tonic::{Request, Response, Status};
-//
-// #[derive(Debug, Default)]
-// pub struct DistributionService<QH, QReq, QRes> {
-//     app: QH,
-//     _phantom: PhantomData<(QReq, QRes)>,
-// }
-//
-// #[tonic::async_trait]
-// impl<
-//         QReq: Send + Sync + 'static,
-//         QRes: Send + Sync + 'static,
-//         QH: NodeQueryHandler<QReq, QRes>,
-//     > Query for DistributionService<QH, QReq, QRes>
-// where
-//     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
-//     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
-// {
-//     // fill
-// }
-//
-// pub fn new<QH, QReq, QRes>(app: QH) -> QueryServer<DistributionService<QH, QReq, QRes>>
-// where
-//     QReq: QueryRequest + Send + Sync + 'static + From<DistributionNodeQueryRequest>,
-//     QRes: QueryResponse
-//         + Send
-//         + Sync
-//         + 'static
-//         + TryInto<DistributionNodeQueryResponse, Error = Status>,
-//     QH: NodeQueryHandler<QReq, QRes>,
-// {
-//     let distribution_service = DistributionService {
-//         app,
-//         _phantom: Default::default(),
-//     };
-//     QueryServer::new(distribution_service)
-// }
+use crate::{
+    DistributionNodeQueryRequest, DistributionNodeQueryResponse, QueryCommunityPoolRequest,
+    QueryDelegationRewardsResponseRaw, QueryParamsRequest, QueryValidatorCommissionRequestRaw,
+    QueryValidatorCommissionResponseRaw, QueryValidatorOutstandingRewardsRequestRaw,
+    QueryValidatorOutstandingRewardsResponseRaw, QueryValidatorSlashesRequestRaw,
+    QueryValidatorSlashesResponseRaw, QueryWithdrawAllRewardsRequestRaw,
+    QueryWithdrawAllRewardsResponseRaw, RawQueryCommunityPoolResponse,
+    RawQueryDelegationRewardsRequest, RawQueryDelegatorParams,
+    RawQueryDelegatorTotalRewardsResponse, RawQueryDelegatorWithdrawAddressRequest,
+    RawQueryDelegatorWithdrawAddressResponse, RawQueryParamsResponse,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{invalid_argument, unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The distribution protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct DistributionService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> DistributionService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: DistributionNodeQueryRequest,
+        height: u32,
+    ) -> Result<DistributionNodeQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for DistributionService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.distribution.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for DistributionService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/cosmos.distribution.v1beta1.Query/Params" => {
+                unary(request, move |req: QueryParamsRequest, height| {
+                    info!("Received a gRPC request distribution::params");
+                    let req = DistributionNodeQueryRequest::Params(req);
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::Params(res) => {
+                            Ok(RawQueryParamsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards" => unary(
+                request,
+                move |req: QueryValidatorOutstandingRewardsRequestRaw, height| {
+                    info!("Received a gRPC request distribution::validator_outstanding_rewards");
+                    let req = DistributionNodeQueryRequest::ValidatorOutstandingRewards(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::ValidatorOutstandingRewards(res) => {
+                            Ok(QueryValidatorOutstandingRewardsResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/ValidatorCommission" => unary(
+                request,
+                move |req: QueryValidatorCommissionRequestRaw, height| {
+                    info!("Received a gRPC request distribution::validator_commission");
+                    let req = DistributionNodeQueryRequest::ValidatorCommission(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::ValidatorCommission(res) => {
+                            Ok(QueryValidatorCommissionResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/ValidatorSlashes" => unary(
+                request,
+                move |req: QueryValidatorSlashesRequestRaw, height| {
+                    info!("Received a gRPC request distribution::validator_slashes");
+                    let req = DistributionNodeQueryRequest::ValidatorSlashes(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::ValidatorSlashes(res) => {
+                            Ok(QueryValidatorSlashesResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/DelegationRewards" => unary(
+                request,
+                move |req: RawQueryDelegationRewardsRequest, height| {
+                    info!("Received a gRPC request distribution::delegation_rewards");
+                    let req = DistributionNodeQueryRequest::DelegationRewards(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::DelegationRewards(Some(res)) => {
+                            Ok(QueryDelegationRewardsResponseRaw::from(res))
+                        }
+                        DistributionNodeQueryResponse::DelegationRewards(None) => {
+                            Err(Status::not_found("delegation not found"))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards" => {
+                unary(request, move |req: RawQueryDelegatorParams, height| {
+                    info!("Received a gRPC request distribution::delegation_total_rewards");
+                    let req = DistributionNodeQueryRequest::DelegatorTotalRewards(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::DelegatorTotalRewards(res) => {
+                            Ok(RawQueryDelegatorTotalRewardsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/DelegatorValidators" => unary(
+                request,
+                move |req: QueryWithdrawAllRewardsRequestRaw, height| {
+                    info!("Received a gRPC request distribution::delegator_validators");
+                    let req = DistributionNodeQueryRequest::DelegatorValidators(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::DelegatorValidators(res) => {
+                            Ok(QueryWithdrawAllRewardsResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => unary(
+                request,
+                move |req: RawQueryDelegatorWithdrawAddressRequest, height| {
+                    info!("Received a gRPC request distribution::delegator_withdraw_address");
+                    let req = DistributionNodeQueryRequest::DelegatorWithdrawAddress(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::DelegatorWithdrawAddress(res) => {
+                            Ok(RawQueryDelegatorWithdrawAddressResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
+                unary(request, move |req: QueryCommunityPoolRequest, height| {
+                    info!("Received a gRPC request distribution::community_pool");
+                    let req = DistributionNodeQueryRequest::CommunityPool(req);
+
+                    match service.typed_query(req, height)? {
+                        DistributionNodeQueryResponse::CommunityPool(res) => {
+                            Ok(RawQueryCommunityPoolResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> DistributionService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse
+        + Send
+        + Sync
+        + 'static
+        + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    DistributionService {
+        app,
+        _phantom: Default::default(),
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
-    DistributionNodeQueryRequest, DistributionNodeQueryResponse, DistributionParams,
-    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegatorParams,
-    QueryParamsRequest, QueryParamsResponse, QueryValidatorCommissionRequest,
-    QueryValidatorOutstandingRewardsRequest, QueryValidatorSlashesRequest,
+    DistributionNodeQueryRequest, DistributionNodeQueryResponse, QueryCommunityPoolRequest,
+    QueryDelegationRewardsRequest, QueryDelegatorParams, QueryDelegatorWithdrawAddressRequest,
+    QueryParamsRequest, QueryValidatorCommissionRequest, QueryValidatorOutstandingRewardsRequest,
+    QueryValidatorSlashesRequest, QueryWithdrawAllRewardsRequest,
 };
 use axum::{
     extract::{Path, Query, State},
@@ -11,12 +11,13 @@ use axum::{
 };
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
+    rest::{error::HTTPError, BlockHeight, Pagination, RestState},
     types::{
         address::{AccAddress, ValAddress},
         pagination::request::PaginationRequest,
     },
 };
+use serde::Deserialize;
 
 pub async fn delegation_delegator_rewards<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
@@ -24,38 +25,90 @@ pub async fn delegation_delegator_rewards<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(delegator_address): Path<AccAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::DelegatorTotalRewards(QueryDelegatorParams {
         delegator_address,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
-pub async fn community_pool<
+pub async fn delegation_rewards<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_address, validator_address)): Path<(AccAddress, ValAddress)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<DistributionNodeQueryResponse>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegationRewards(QueryDelegationRewardsRequest {
+        delegator_address: delegator_address.clone(),
+        validator_address: validator_address.clone(),
+    });
+
+    match rest_state
+        .app
+        .typed_query_at(req, height)?
+        .try_into()
+        .map_err(|_| HTTPError::internal_server_error())?
+    {
+        DistributionNodeQueryResponse::DelegationRewards(Some(res)) => Ok(Json(
+            DistributionNodeQueryResponse::DelegationRewards(Some(res)),
+        )),
+        DistributionNodeQueryResponse::DelegationRewards(None) => {
+            Err(HTTPError::not_found_with_msg(format!(
+                "delegation of {delegator_address} to validator {validator_address} is not found"
+            )))
+        }
+        _ => Err(HTTPError::internal_server_error()),
+    }
+}
+
+pub async fn delegator_validators<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Path(delegator_address): Path<AccAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
-    let req = DistributionNodeQueryRequest::CommunityPool(QueryCommunityPoolRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let req = DistributionNodeQueryRequest::DelegatorValidators(QueryWithdrawAllRewardsRequest {
+        delegator_address,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_withdraw_address<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_address): Path<AccAddress>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegatorWithdrawAddress(
+        QueryDelegatorWithdrawAddressRequest { delegator_address },
+    );
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
-pub async fn const_community_pool() -> Result<Json<QueryCommunityPoolResponse>, HTTPError> {
-    let res = QueryCommunityPoolResponse {
-        pool: gears::types::base::coins::DecimalCoins::new(vec![
-            gears::types::base::coin::DecimalCoin {
-                denom: "uatom".try_into().expect("hardcoded value cannot fail"),
-                amount: gears::types::decimal256::Decimal256::from_atomics(10_000_000_000u64, 1)
-                    .expect("Default is valid"),
-            },
-        ])
-        .ok(),
-    };
+pub async fn community_pool<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::CommunityPool(QueryCommunityPoolRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -65,12 +118,13 @@ pub async fn validator_commission<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_address): Path<ValAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::ValidatorCommission(QueryValidatorCommissionRequest {
         validator_address,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -80,32 +134,46 @@ pub async fn validator_outstanding_rewards<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_address): Path<ValAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::ValidatorOutstandingRewards(
         QueryValidatorOutstandingRewardsRequest { validator_address },
     );
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
+#[derive(Deserialize)]
+pub struct ValidatorSlashesQuery {
+    starting_height: Option<u64>,
+    ending_height: Option<u64>,
+    #[serde(alias = "pagination.offset")]
+    offset: Option<u32>,
+    #[serde(alias = "pagination.limit")]
+    limit: Option<u8>,
+}
+
 pub async fn validator_slashes<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_address): Path<ValAddress>,
-    Query(pagination): Query<Pagination>,
+    Query(query): Query<ValidatorSlashesQuery>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::ValidatorSlashes(QueryValidatorSlashesRequest {
         validator_address,
-        // TODO: does it use a query height parameters?
-        starting_height: u64::MIN,
-        ending_height: u64::MAX,
-        pagination: Some(PaginationRequest::from(pagination)),
+        starting_height: query.starting_height.unwrap_or(u64::MIN),
+        ending_height: query.ending_height.unwrap_or(u64::MAX),
+        pagination: Some(PaginationRequest::from(Pagination::new(
+            query.offset,
+            query.limit,
+        ))),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -114,17 +182,11 @@ pub async fn params<
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::Params(QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
-    Ok(Json(res))
-}
-
-pub async fn const_params() -> Result<Json<QueryParamsResponse>, HTTPError> {
-    let res = QueryParamsResponse {
-        params: DistributionParams::default(),
-    };
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -134,16 +196,23 @@ pub fn get_router<
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
     Router::new()
-        .route(
-            "/v1beta1/community_pool/current", /* "/v1beta1/community_pool" */
-            get(community_pool),
-        )
-        // TODO: remove const handler and route after integration and update route
-        .route("/v1beta1/community_pool", get(const_community_pool))
+        .route("/v1beta1/community_pool", get(community_pool))
         .route(
             "/v1beta1/delegators/:delegator_address/rewards",
             get(delegation_delegator_rewards),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_address/rewards/:validator_address",
+            get(delegation_rewards),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/validators",
+            get(delegator_validators),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/withdraw_address",
+            get(delegator_withdraw_address),
+        )
         .route(
             "/v1beta1/validators/:validator_address/commission",
             get(validator_commission),
@@ -156,10 +225,5 @@ pub fn get_router<
             "/v1beta1/validators/:validator_address/slashes",
             get(validator_slashes),
         )
-        .route(
-            "/v1beta1/params/current", /* "/v1beta1/params" */
-            get(params),
-        )
-        // TODO: remove const handler and route after integration and update route
-        .route("/v1beta1/params", get(const_params))
+        .route("/v1beta1/params", get(params))
 }
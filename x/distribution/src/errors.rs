@@ -1,8 +1,9 @@
 use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
     error::NumericError, gas::store::errors::GasStoreErrors, types::{
         address::{AccAddress, ValAddress},
         base::errors::CoinsError,
-    }, x::errors::{AccountNotFound, BankKeeperError}
+    }, x::errors::{AccountNotFound, BankKeeperError, StakingHooksError}
 };
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -11,6 +12,16 @@ pub enum DistributionTxError {
     DelegatorValidator(#[from] DistributionError),
 }
 
+impl DistributionTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        let code = match &self {
+            DistributionTxError::DelegatorValidator(_) => nz::u16!(1),
+        };
+
+        TxError::new::<MI>(self.to_string(), code)
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum TokenAllocationError {
     #[error(transparent)]
@@ -61,6 +72,15 @@ pub enum DistributionError {
     Gas(#[from] GasStoreErrors),
 }
 
+impl From<DistributionError> for StakingHooksError {
+    fn from(error: DistributionError) -> Self {
+        match error {
+            DistributionError::Gas(e) => StakingHooksError::GasError(e),
+            e => StakingHooksError::Other(e.to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ValidatorHistoricalRewardsReferenceCountError {
     #[error("cannot create counter with value higher than upper bound.\ngot: {0}, expected: {1}")]
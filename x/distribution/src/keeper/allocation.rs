@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
-    errors::TokenAllocationError, ValidatorAccumulatedCommission, ValidatorCurrentRewards,
-    ValidatorOutstandingRewards,
+    errors::TokenAllocationError, types::coins, ValidatorAccumulatedCommission,
+    ValidatorCurrentRewards, ValidatorOutstandingRewards,
 };
 use gears::{
     context::block::BlockContext,
@@ -47,6 +47,10 @@ impl<
             .balance_all(ctx, self.fee_collector_module.address(), None)
             .unwrap_gas()
             .1;
+        if fees_collected_int.is_empty() {
+            // nothing to distribute
+            return Ok(());
+        }
         let fees_collected = DecimalCoins::try_from(fees_collected_int.clone())?;
 
         // transfer collected fees to the distribution module account
@@ -64,7 +68,8 @@ impl<
             .unwrap_gas()
             .ok_or(TokenAllocationError::FeePoolNone)?;
         if total_previous_power == 0 {
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&fees_collected)?;
+            fee_pool.community_pool =
+                coins::checked_add(&fee_pool.community_pool, &Some(fees_collected))?;
             self.set_fee_pool(ctx, &fee_pool).unwrap_gas();
             return Ok(());
         }
@@ -86,12 +91,12 @@ impl<
             .checked_add(
                 bonus_proposer_reward
                     .checked_mul(previous_fraction_votes)
-                    .map_err(|_| NumericError::Overflow(MathOperation::Mul))?
-                    // TODO: check the operation
-                    .floor(),
+                    .map_err(|_| NumericError::Overflow(MathOperation::Mul))?,
             )
             .map_err(|_| NumericError::Overflow(MathOperation::Add))?;
-        let proposer_reward = fees_collected.checked_mul_dec_truncate(proposer_multiplier)?;
+        let proposer_reward = coins::empty_to_none(
+            fees_collected.checked_mul_dec_truncate_to_precision(proposer_multiplier),
+        )?;
 
         // pay previous proposer
         let mut remaining = if let Some(proposer_validator) = self
@@ -124,7 +129,7 @@ impl<
                 proposer_validator.commission(),
                 &proposer_reward,
             )?;
-            fees_collected.checked_sub(&proposer_reward)?
+            coins::checked_sub(&Some(fees_collected.clone()), &proposer_reward)?
         } else {
             // previous proposer can be unknown if say, the unbonding period is 1 block, so
             // e.g. a validator undelegates at block X, it's removed entirely by
@@ -136,7 +141,7 @@ impl<
                     We recommend you investigate immediately.",
                 previous_proposer);
             tracing::error!(error);
-            fees_collected.clone()
+            Some(fees_collected.clone())
         };
 
         // calculate fraction allocated to validators
@@ -146,7 +151,9 @@ impl<
             .map_err(|_| NumericError::Overflow(MathOperation::Sub))?
             .checked_sub(community_tax)
             .map_err(|_| NumericError::Overflow(MathOperation::Sub))?;
-        let fee_multiplier = fees_collected.checked_mul_dec_truncate(vote_multiplier)?;
+        let fee_multiplier = coins::empty_to_none(
+            fees_collected.checked_mul_dec_truncate_to_precision(vote_multiplier),
+        )?;
 
         // allocate tokens proportionally to voting power
         //
@@ -168,20 +175,24 @@ impl<
                     Decimal256::from_atomics(total_previous_power, 0)
                         .map_err(NumericError::from)?,
                 )
-                .map_err(|_| NumericError::Overflow(MathOperation::Div))?
-                .floor();
-            let reward = fee_multiplier.checked_mul_dec_truncate(power_fraction)?;
+                .map_err(|_| NumericError::Overflow(MathOperation::Div))?;
+            let reward = match &fee_multiplier {
+                Some(fee_multiplier) => coins::empty_to_none(
+                    fee_multiplier.checked_mul_dec_truncate_to_precision(power_fraction),
+                )?,
+                None => None,
+            };
             self.allocate_tokens_to_validator(
                 ctx,
                 validator.operator(),
                 validator.commission(),
                 &reward,
             )?;
-            remaining = remaining.checked_sub(&reward)?;
+            remaining = coins::checked_sub(&remaining, &reward)?;
         }
 
         // allocate community funding
-        fee_pool.community_pool = fee_pool.community_pool.checked_add(&remaining)?;
+        fee_pool.community_pool = coins::checked_add(&fee_pool.community_pool, &remaining)?;
         self.set_fee_pool(ctx, &fee_pool).unwrap_gas();
 
         Ok(())
@@ -194,11 +205,18 @@ impl<
         ctx: &mut BlockContext<'_, DB, SK>,
         validator_operator_addr: &ValAddress,
         validator_commission_rate: Decimal256,
-        tokens: &DecimalCoins,
+        tokens: &Option<DecimalCoins>,
     ) -> Result<(), TokenAllocationError> {
+        let Some(tokens) = tokens else {
+            // nothing to allocate
+            return Ok(());
+        };
+
         // split tokens between validator and delegators according to commission
-        let commission = tokens.checked_mul_dec(validator_commission_rate)?;
-        let shared = tokens.checked_sub(&commission)?;
+        let commission = coins::empty_to_none(
+            tokens.checked_mul_dec_truncate_to_precision(validator_commission_rate),
+        )?;
+        let shared = coins::checked_sub(&Some(tokens.clone()), &commission)?;
 
         // update current commission
         ctx.push_event(Event {
@@ -225,7 +243,7 @@ impl<
             .unwrap_gas()
         {
             current_commission.commission =
-                current_commission.commission.checked_add(&commission)?;
+                coins::checked_add(&current_commission.commission, &commission)?;
             current_commission
         } else {
             ValidatorAccumulatedCommission { commission }
@@ -242,7 +260,7 @@ impl<
             .validator_current_rewards(ctx, validator_operator_addr)
             .unwrap_gas()
         {
-            cur_reward.rewards = cur_reward.rewards.checked_add(&shared)?;
+            cur_reward.rewards = coins::checked_add(&cur_reward.rewards, &shared)?;
             cur_reward
         } else {
             // TODO: sdk doesn't have this branch
@@ -278,12 +296,13 @@ impl<
             .validator_outstanding_rewards(ctx, validator_operator_addr)
             .unwrap_gas()
         {
-            outstanding_rewards.rewards = outstanding_rewards.rewards.checked_add(tokens)?;
+            outstanding_rewards.rewards =
+                coins::checked_add(&outstanding_rewards.rewards, &Some(tokens.clone()))?;
             outstanding_rewards
         } else {
             // TODO: sdk doesn't have this branch
             ValidatorOutstandingRewards {
-                rewards: tokens.clone(),
+                rewards: Some(tokens.clone()),
             }
         };
         self.set_validator_outstanding_rewards(ctx, validator_operator_addr, &outstanding)
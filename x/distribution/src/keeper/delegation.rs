@@ -1,6 +1,6 @@
 use super::*;
 use crate::{
-    errors::DistributionError, DelegatorStartingInfo, SlashEventIterator,
+    errors::DistributionError, types::coins, DelegatorStartingInfo, SlashEventIterator,
     ValidatorOutstandingRewards,
 };
 use gears::{
@@ -24,9 +24,9 @@ impl<
     > Keeper<SK, PSK, AK, BK, DSK, M>
 {
     /// initialize starting info for a new delegation
-    pub fn initialize_delegation<DB: Database>(
+    pub fn initialize_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
         delegator_address: &AccAddress,
     ) -> Result<(), DistributionError> {
@@ -71,9 +71,9 @@ impl<
         )?)
     }
 
-    pub fn delegation_withdraw_rewards<DB: Database>(
+    pub fn delegation_withdraw_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator: impl StakingValidator,
         delegation: impl StakingDelegation,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
@@ -87,18 +87,13 @@ impl<
         // end current period and calculate rewards
         let ending_period =
             self.increment_validator_period(ctx, validator.operator(), validator.tokens())?;
-        let rewards_raw = self
-            .calculate_delegation_rewards(
-                ctx,
-                validator.operator(),
-                delegation.delegator(),
-                validator.tokens_from_shares(*delegation.shares())?,
-                ending_period,
-            )?
-            .ok_or(DistributionError::DelegationRewardsNotFound(
-                delegation.delegator().clone(),
-                validator.operator().clone(),
-            ))?;
+        let rewards_raw = self.calculate_delegation_rewards(
+            ctx,
+            validator.operator(),
+            delegation.delegator(),
+            validator.tokens_from_shares(*delegation.shares())?,
+            ending_period,
+        )?;
         let outstanding = self
             .validator_outstanding_rewards(ctx, delegation.validator())?
             .ok_or(DistributionError::ValidatorOutstandingRewardsNotFound(
@@ -108,7 +103,12 @@ impl<
 
         // defensive edge case may happen on the very final digits
         // of the DecimalCoins due to operation order of the distribution mechanism.
-        let rewards = rewards_raw.intersect(&outstanding)?;
+        let rewards = match (&rewards_raw, &outstanding) {
+            (Some(rewards_raw), Some(outstanding)) => {
+                coins::empty_to_none(rewards_raw.intersect(outstanding))?
+            }
+            _ => None,
+        };
 
         if rewards.ne(&rewards_raw) {
             tracing::info!(
@@ -123,15 +123,16 @@ impl<
         }
 
         // truncate reward dec coins, return remainder to community pool
-        let (final_rewards, remainder) = rewards.truncate_decimal();
+        let (final_rewards, remainder) = match &rewards {
+            Some(rewards) => rewards.truncate_decimal(),
+            None => (None, None),
+        };
 
         // add coins to user account
         if final_rewards.is_some() {
             let withdraw_address = self
                 .delegator_withdraw_addr(ctx, delegation.delegator())?
-                .ok_or(DistributionError::AccountNotFound(
-                    delegation.delegator().clone().into(),
-                ))?;
+                .unwrap_or(delegation.delegator().clone());
             self.bank_keeper.send_coins_from_module_to_account(
                 ctx,
                 &withdraw_address,
@@ -148,12 +149,12 @@ impl<
             ctx,
             delegation.validator(),
             &ValidatorOutstandingRewards {
-                rewards: outstanding.checked_sub(&rewards)?,
+                rewards: coins::checked_sub(&outstanding, &rewards)?,
             },
         )?;
-        let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
-        if let Some(rem) = remainder {
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&rem)?;
+        if remainder.is_some() {
+            let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
+            fee_pool.community_pool = coins::checked_add(&fee_pool.community_pool, &remainder)?;
             self.set_fee_pool(ctx, &fee_pool)?;
         }
 
@@ -267,11 +268,7 @@ impl<
                         ending_period,
                         stake,
                     )?;
-                    rewards = if let Some(r) = rewards {
-                        Some(r.checked_add(&addition)?)
-                    } else {
-                        Some(addition)
-                    };
+                    rewards = coins::checked_add(&rewards, &addition)?;
                     // Note: It is necessary to truncate so we don't allow withdrawing
                     // more rewards than owed.
                     stake = stake
@@ -345,11 +342,7 @@ impl<
             ending_period,
             stake,
         )?;
-        rewards = if let Some(r) = rewards {
-            Some(r.checked_add(&addition)?)
-        } else {
-            Some(addition)
-        };
+        rewards = coins::checked_add(&rewards, &addition)?;
         Ok(rewards)
     }
 
@@ -361,7 +354,7 @@ impl<
         starting_period: u64,
         ending_period: u64,
         stake: Decimal256,
-    ) -> Result<DecimalCoins, DistributionError> {
+    ) -> Result<Option<DecimalCoins>, DistributionError> {
         // sanity check
         if starting_period > ending_period {
             panic!("starting_period cannot be greater than ending_period");
@@ -379,11 +372,17 @@ impl<
                 validator_address.clone(),
             ))?;
         // TODO: panics if there are some negative values
-        let difference = ending
-            .cumulative_reward_ratio
-            .checked_sub(&starting.cumulative_reward_ratio)?;
+        let difference = coins::checked_sub(
+            &ending.cumulative_reward_ratio,
+            &starting.cumulative_reward_ratio,
+        )?;
 
         // note: necessary to truncate so we don't allow withdrawing more rewards than owed
-        Ok(difference.checked_mul_dec_truncate(stake)?)
+        match difference {
+            Some(difference) => Ok(coins::empty_to_none(
+                difference.checked_mul_dec_truncate_to_precision(stake),
+            )?),
+            None => Ok(None),
+        }
     }
 }
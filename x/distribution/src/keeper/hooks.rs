@@ -0,0 +1,311 @@
+use super::*;
+use crate::{
+    ValidatorCurrentRewards, ValidatorHistoricalRewards, ValidatorHistoricalRewardsReferenceCount,
+    ValidatorSlashEvent,
+};
+use gears::{
+    types::decimal256::{Decimal256, ONE_DEC},
+    x::{errors::StakingHooksError, keepers::staking::KeeperHooks},
+};
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    /// initialize rewards for a new validator
+    pub fn initialize_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), GasStoreErrors> {
+        // set initial historical rewards (period 0) with reference count of 1
+        self.set_validator_historical_rewards(
+            ctx,
+            validator_address,
+            0,
+            &ValidatorHistoricalRewards {
+                cumulative_reward_ratio: None,
+                reference_count: ValidatorHistoricalRewardsReferenceCount::new(1)
+                    .expect("hardcoded value is less than upper bound"),
+            },
+        )?;
+
+        // set current rewards (starting at period 1)
+        self.set_validator_current_rewards(
+            ctx,
+            validator_address,
+            &ValidatorCurrentRewards {
+                rewards: None,
+                period: 1,
+            },
+        )?;
+
+        // set accumulated commission
+        self.set_validator_accumulated_commission(
+            ctx,
+            validator_address,
+            &ValidatorAccumulatedCommission { commission: None },
+        )?;
+
+        // set outstanding rewards
+        self.set_validator_outstanding_rewards(
+            ctx,
+            validator_address,
+            &ValidatorOutstandingRewards { rewards: None },
+        )
+    }
+
+    /// cleanup the state of removed validator and move its rewards to the community pool
+    fn cleanup_removed_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        // fetch outstanding
+        let mut outstanding = self
+            .validator_outstanding_rewards(ctx, validator_address)?
+            .ok_or(DistributionError::ValidatorOutstandingRewardsNotFound(
+                validator_address.clone(),
+            ))?
+            .rewards;
+
+        // force-withdraw commission
+        let commission = self
+            .validator_accumulated_commission(ctx, validator_address)?
+            .and_then(|commission| commission.commission);
+        if let Some(commission) = commission {
+            // subtract from outstanding
+            outstanding = coins::checked_sub(&outstanding, &Some(commission.clone()))?;
+
+            // split into integral & remainder
+            let (truncated, remainder) = commission.truncate_decimal();
+
+            // remainder to community pool
+            let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
+            fee_pool.community_pool = coins::checked_add(&fee_pool.community_pool, &remainder)?;
+            self.set_fee_pool(ctx, &fee_pool)?;
+
+            // add to validator account
+            if let Some(truncated) = truncated {
+                let acc_address = AccAddress::from(validator_address.clone());
+                let withdraw_address = self
+                    .delegator_withdraw_addr(ctx, &acc_address)?
+                    .unwrap_or(acc_address);
+                self.bank_keeper.send_coins_from_module_to_account(
+                    ctx,
+                    &withdraw_address,
+                    &self.distribution_module,
+                    truncated,
+                )?;
+            }
+        }
+
+        // add outstanding to community pool
+        let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
+        fee_pool.community_pool = coins::checked_add(&fee_pool.community_pool, &outstanding)?;
+        self.set_fee_pool(ctx, &fee_pool)?;
+
+        // delete outstanding
+        self.delete_validator_outstanding_rewards(ctx, validator_address)?;
+        // remove commission record
+        self.delete_validator_accumulated_commission(ctx, validator_address)?;
+        // clear slashes
+        self.delete_validator_slash_events(ctx, validator_address)?;
+        // clear historical rewards
+        self.delete_validator_historical_rewards_all(ctx, validator_address)?;
+        // clear current rewards
+        self.delete_validator_current_rewards(ctx, validator_address)?;
+
+        Ok(())
+    }
+
+    /// increment the period of the validator which is going to get a new delegation
+    fn increment_period_before_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        let validator = self
+            .staking_keeper
+            .validator(ctx, validator_address)?
+            .ok_or(DistributionError::AccountNotFound(
+                validator_address.clone().into(),
+            ))?;
+        self.increment_validator_period(ctx, validator_address, validator.tokens())?;
+        Ok(())
+    }
+
+    /// update the slash fraction of the validator, ending the current period
+    fn update_validator_slash_fraction<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), DistributionError> {
+        if fraction > ONE_DEC {
+            panic!("fraction must be >=0 and <=1, current fraction: {fraction}");
+        }
+
+        let validator = self
+            .staking_keeper
+            .validator(ctx, validator_address)?
+            .ok_or(DistributionError::AccountNotFound(
+                validator_address.clone().into(),
+            ))?;
+
+        // increment current period
+        let new_period =
+            self.increment_validator_period(ctx, validator_address, validator.tokens())?;
+
+        // increment reference count on period we need to track
+        self.increment_reference_count(ctx, validator_address, new_period)?;
+
+        let height = ctx.height() as u64;
+        self.set_validator_slash_event(
+            ctx,
+            validator_address,
+            height,
+            new_period,
+            &ValidatorSlashEvent {
+                validator_period: new_period,
+                fraction,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// withdraw rewards of a delegation before its shares are changed
+    fn withdraw_before_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        let validator = self
+            .staking_keeper
+            .validator(ctx, validator_address)?
+            .ok_or(DistributionError::AccountNotFound(
+                validator_address.clone().into(),
+            ))?;
+        let delegation = self
+            .staking_keeper
+            .delegation(ctx, delegator_address, validator_address)?
+            .ok_or(DistributionError::DelegationNotFound(
+                delegator_address.clone(),
+                validator_address.clone(),
+            ))?;
+
+        self.delegation_withdraw_rewards(ctx, validator, delegation)?;
+        Ok(())
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > KeeperHooks<SK, AK, M> for Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.initialize_validator(ctx, &val_addr)?;
+        Ok(())
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.cleanup_removed_validator(ctx, &val_addr)?;
+        Ok(())
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        _del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.increment_period_before_delegation(ctx, &val_addr)?;
+        Ok(())
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.withdraw_before_shares_modified(ctx, &del_addr, &val_addr)?;
+        Ok(())
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.initialize_delegation(ctx, &val_addr, &del_addr)?;
+        Ok(())
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        self.update_validator_slash_fraction(ctx, &val_addr, fraction)?;
+        Ok(())
+    }
+}
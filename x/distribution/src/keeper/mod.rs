@@ -1,5 +1,5 @@
 use crate::{
-    errors::DistributionError, params::DistributionParamsKeeper, GenesisState,
+    errors::DistributionError, params::DistributionParamsKeeper, types::coins, GenesisState,
     ValidatorAccumulatedCommission, ValidatorOutstandingRewards,
 };
 use anyhow::anyhow;
@@ -30,6 +30,7 @@ use std::collections::HashMap;
 
 mod allocation;
 mod delegation;
+mod hooks;
 mod query;
 mod store;
 mod tx;
@@ -102,15 +103,13 @@ impl<
                 .unwrap_gas()
         });
 
-        let previous_proposer = if !genesis.previous_proposer.is_empty() {
-            ConsAddress::from_bech32(&genesis.previous_proposer)?
-        } else {
-            todo!("the sdk doesn't have this branch. It may use a default value");
-        };
-
-        self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        // the previous proposer is empty for a new chain, it will be set in the first begin block
+        if !genesis.previous_proposer.is_empty() {
+            let previous_proposer = ConsAddress::from_bech32(&genesis.previous_proposer)?;
+            self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        }
 
-        let mut module_holdings = vec![];
+        let mut module_holdings = None;
         for rew in genesis.outstanding_rewards {
             self.set_validator_outstanding_rewards(
                 ctx,
@@ -118,13 +117,8 @@ impl<
                 &rew.outstanding_rewards,
             )
             .unwrap_gas();
-            module_holdings.push(rew.outstanding_rewards.rewards);
+            module_holdings = coins::checked_add(&module_holdings, &rew.outstanding_rewards.rewards)?;
         }
-        let start = module_holdings[0].clone();
-        let module_holdings = module_holdings
-            .into_iter()
-            .take(1)
-            .try_fold(start, |acc, holdings| acc.checked_add(&holdings))?;
 
         genesis
             .validator_accumulated_commissions
@@ -171,10 +165,12 @@ impl<
                 vse.period,
                 &vse.validator_slash_event,
             )
+            .unwrap_gas()
         });
 
-        let module_holdings = module_holdings.checked_add(&genesis.fee_pool.community_pool)?;
-        let (module_holdings_int, _) = module_holdings.truncate_decimal();
+        let module_holdings =
+            coins::checked_add(&module_holdings, &genesis.fee_pool.community_pool)?;
+        let module_holdings_int = module_holdings.and_then(|holdings| holdings.truncate_decimal().0);
 
         // check if the module account exists
 
@@ -185,7 +181,13 @@ impl<
             .unwrap_gas()
             .1;
 
-        if module_holdings_int != Some(UnsignedCoins::new(balances)?) {
+        let balances = if balances.is_empty() {
+            None
+        } else {
+            Some(UnsignedCoins::new(balances)?)
+        };
+
+        if module_holdings_int != balances {
             return Err(anyhow!(
                 "distribution module balance does not match the module holdings".to_string(),
             ));
@@ -205,9 +207,9 @@ impl<
     }
 
     /// withdraw rewards from a delegation
-    pub fn withdraw_delegation_rewards<DB: Database>(
+    pub fn withdraw_delegation_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         delegator_address: &AccAddress,
         validator_address: &ValAddress,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
@@ -239,9 +241,9 @@ impl<
     }
 
     /// withdraw validator commission
-    pub fn withdraw_validator_commission<DB: Database>(
+    pub fn withdraw_validator_commission<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
         // fetch validator accumulated commission
@@ -250,22 +252,22 @@ impl<
             .ok_or(DistributionError::ValidatorAccumulatedCommissionNotFound(
                 validator_address.clone(),
             ))?;
-        if accumulated_commission.commission.is_empty() {
+        let Some(accumulated_commission) = accumulated_commission.commission else {
             return Err(DistributionError::ValidatorAccumulatedCommissionNotSet(
                 validator_address.clone(),
             ));
-        }
+        };
 
-        let (commission, reminder) = accumulated_commission.commission.truncate_decimal();
+        let (commission, reminder) = accumulated_commission.truncate_decimal();
 
-        if let Some(rem) = reminder {
-            // leave remainder to withdraw later
-            self.set_validator_accumulated_commission(
-                ctx,
-                validator_address,
-                &ValidatorAccumulatedCommission { commission: rem },
-            )?
-        }
+        // leave remainder to withdraw later
+        self.set_validator_accumulated_commission(
+            ctx,
+            validator_address,
+            &ValidatorAccumulatedCommission {
+                commission: reminder,
+            },
+        )?;
 
         // update outstanding
         let outstanding = self
@@ -274,9 +276,10 @@ impl<
                 validator_address.clone(),
             ))?;
         let rewards = if let Some(commission) = &commission {
-            outstanding
-                .rewards
-                .checked_sub(&DecimalCoins::try_from(commission.inner().clone())?)?
+            coins::checked_sub(
+                &outstanding.rewards,
+                &Some(DecimalCoins::try_from(commission.inner().clone())?),
+            )?
         } else {
             outstanding.rewards
         };
@@ -331,9 +334,10 @@ impl<
             amount.clone(),
         )?;
         let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
-        fee_pool.community_pool = fee_pool
-            .community_pool
-            .checked_add(&DecimalCoins::try_from(amount.into_inner())?)?;
+        fee_pool.community_pool = coins::checked_add(
+            &fee_pool.community_pool,
+            &Some(DecimalCoins::try_from(amount.into_inner())?),
+        )?;
         self.set_fee_pool(ctx, &fee_pool)?;
         Ok(())
     }
@@ -2,7 +2,8 @@ use super::*;
 use crate::{
     DelegationDelegatorReward, QueryCommunityPoolRequest, QueryCommunityPoolResponse,
     QueryDelegationRewardsRequest, QueryDelegationRewardsResponse, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryDelegatorTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
@@ -225,6 +226,19 @@ impl<
         QueryWithdrawAllRewardsResponse { validators }
     }
 
+    pub fn query_delegator_withdraw_address<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDelegatorWithdrawAddressRequest { delegator_address }: QueryDelegatorWithdrawAddressRequest,
+    ) -> QueryDelegatorWithdrawAddressResponse {
+        // the rewards are withdrawn to the delegator address if no other address is set
+        let withdraw_address = self
+            .delegator_withdraw_addr(ctx, &delegator_address)
+            .unwrap_gas()
+            .unwrap_or(delegator_address);
+        QueryDelegatorWithdrawAddressResponse { withdraw_address }
+    }
+
     pub fn query_community_pool<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -234,7 +248,7 @@ impl<
             pool: self
                 .fee_pool(ctx)
                 .unwrap_gas()
-                .map(|fee_pool| fee_pool.community_pool),
+                .and_then(|fee_pool| fee_pool.community_pool),
         }
     }
 
@@ -3,8 +3,9 @@ use crate::{
     keys::{
         delegator_starting_info_key, delegator_withdraw_addr_key,
         validator_accumulated_commission_key, validator_current_rewards_key,
-        validator_historical_rewards_key, validator_outstanding_rewards_key,
-        validator_slash_event_key,
+        validator_historical_rewards_key, validator_historical_rewards_prefix,
+        validator_outstanding_rewards_key, validator_slash_event_key,
+        validator_slash_events_prefix,
     },
     types::FeePool,
     ByteValue, DelegatorStartingInfo, ValidatorAccumulatedCommission, ValidatorCurrentRewards,
@@ -126,6 +127,16 @@ impl<
         )
     }
 
+    /// delete validator outstanding rewards
+    pub fn delete_validator_outstanding_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        address: &ValAddress,
+    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&validator_outstanding_rewards_key(address.clone()))
+    }
+
     /// get accumulated commission for a validator
     pub fn validator_accumulated_commission<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -158,6 +169,19 @@ impl<
         )
     }
 
+    /// delete accumulated commission for a validator
+    pub fn delete_validator_accumulated_commission<
+        DB: Database,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        address: &ValAddress,
+    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&validator_accumulated_commission_key(address.clone()))
+    }
+
     /// get historical rewards for a particular period
     pub fn validator_historical_rewards<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -197,6 +221,18 @@ impl<
         store.delete(&validator_historical_rewards_key(address.clone(), power))
     }
 
+    /// delete historical rewards for a validator
+    pub fn delete_validator_historical_rewards_all<
+        DB: Database,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        address: &ValAddress,
+    ) -> Result<(), GasStoreErrors> {
+        self.delete_prefixed(ctx, validator_historical_rewards_prefix(address.clone()))
+    }
+
     /// get current rewards for a validator
     pub fn validator_current_rewards<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -223,6 +259,16 @@ impl<
         )
     }
 
+    /// delete current rewards for a validator
+    pub fn delete_validator_current_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        address: &ValAddress,
+    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&validator_current_rewards_key(address.clone()))
+    }
+
     /// get the starting info associated with a delegator
     pub fn delegator_starting_info<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -285,18 +331,49 @@ impl<
     }
 
     /// set slash event for height
-    pub fn set_validator_slash_event<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+    pub fn set_validator_slash_event<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         validator_address: &ValAddress,
         height: u64,
         period: u64,
         slash_event: &ValidatorSlashEvent,
-    ) {
-        let mut store = ctx.infallible_store_mut(&self.store_key);
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
         store.set(
             validator_slash_event_key(validator_address.clone(), height, period),
             slash_event.encode_vec(),
         )
     }
+
+    /// delete slash events for a particular validator
+    pub fn delete_validator_slash_events<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), GasStoreErrors> {
+        self.delete_prefixed(
+            ctx,
+            validator_slash_events_prefix(validator_address.clone()),
+        )
+    }
+
+    fn delete_prefixed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        prefix: Vec<u8>,
+    ) -> Result<(), GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        let mut keys = vec![];
+        for r in store.prefix_store(prefix.clone()).into_range(..) {
+            let (k, _) = r?;
+            keys.push([prefix.as_slice(), &k].concat());
+        }
+
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        for key in keys {
+            store.delete(&key)?;
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use super::*;
 use crate::{
-    ValidatorCurrentRewards, ValidatorHistoricalRewards, ValidatorHistoricalRewardsReferenceCount,
+    types::coins, ValidatorCurrentRewards, ValidatorHistoricalRewards,
+    ValidatorHistoricalRewardsReferenceCount,
 };
 use gears::types::{decimal256::Decimal256, uint::Uint256};
 
@@ -14,9 +15,9 @@ impl<
     > Keeper<SK, PSK, AK, BK, DSK, M>
 {
     /// increment the reference count for a historical rewards value
-    pub fn increment_reference_count<DB: Database>(
+    pub fn increment_reference_count<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
         period: u64,
     ) -> Result<(), DistributionError> {
@@ -31,9 +32,9 @@ impl<
     }
 
     /// increment validator period, returning the period just ended
-    pub fn increment_validator_period<DB: Database>(
+    pub fn increment_validator_period<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_operator_addr: &ValAddress,
         validator_tokens: Uint256,
     ) -> Result<u64, DistributionError> {
@@ -55,19 +56,23 @@ impl<
                 .ok_or(DistributionError::ValidatorOutstandingRewardsNotFound(
                     validator_operator_addr.clone(),
                 ))?;
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&rewards.rewards)?;
-            outstanding.rewards = outstanding.rewards.checked_sub(&rewards.rewards)?;
+            fee_pool.community_pool =
+                coins::checked_add(&fee_pool.community_pool, &rewards.rewards)?;
+            outstanding.rewards = coins::checked_sub(&outstanding.rewards, &rewards.rewards)?;
             self.set_fee_pool(ctx, &fee_pool)?;
             self.set_validator_outstanding_rewards(ctx, validator_operator_addr, &outstanding)?;
             None
         } else {
             // note: necessary to truncate so we don't allow withdrawing more rewards than owed
-            Some(
-                rewards.rewards.checked_quo_dec_truncate(
-                    Decimal256::from_atomics(validator_tokens, 0)
-                        .map_err(|e| DistributionError::Numeric(e.into()))?,
+            match &rewards.rewards {
+                Some(current_rewards) => coins::empty_to_none(
+                    current_rewards.checked_quo_dec_truncate_to_precision(
+                        Decimal256::from_atomics(validator_tokens, 0)
+                            .map_err(|e| DistributionError::Numeric(e.into()))?,
+                    ),
                 )?,
-            )
+                None => None,
+            }
         };
 
         // fetch historical rewards for last period
@@ -85,11 +90,7 @@ impl<
         self.decrement_reference_count(ctx, validator_operator_addr, rewards.period - 1)?;
 
         // set new historical rewards with reference count of 1
-        let cumulative_reward_ratio = if let Some(current) = current {
-            historical.checked_add(&current)?
-        } else {
-            historical
-        };
+        let cumulative_reward_ratio = coins::checked_add(&historical, &current)?;
         self.set_validator_historical_rewards(
             ctx,
             validator_operator_addr,
@@ -106,8 +107,7 @@ impl<
             ctx,
             validator_operator_addr,
             &ValidatorCurrentRewards {
-                // TODO: empty rewards in the sdk
-                rewards: rewards.rewards,
+                rewards: None,
                 period: rewards.period + 1,
             },
         )?;
@@ -116,9 +116,9 @@ impl<
     }
 
     /// decrement the reference count for a historical rewards value, and delete if zero references remain
-    pub fn decrement_reference_count<DB: Database>(
+    pub fn decrement_reference_count<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_operator_addr: &ValAddress,
         period: u64,
     ) -> Result<(), DistributionError> {
@@ -1,9 +1,17 @@
-use gears::derive::AppMessage;
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 use serde::Serialize;
 
 use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
+#[serde(tag = "@type")]
 pub enum Message {
     #[serde(rename = "/cosmos.distribution.v1beta1.WithdrawRewards")]
     #[msg(url(path = MsgWithdrawDelegatorReward::TYPE_URL))]
@@ -15,3 +23,13 @@ pub enum Message {
     #[msg(url(path = MsgFundCommunityPool::TYPE_URL))]
     FundCommunityPool(MsgFundCommunityPool),
 }
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::WithdrawRewards(msg) => msg.format(get_metadata),
+            Message::SetWithdrawAddr(msg) => msg.format(get_metadata),
+            Message::FundCommunityPool(msg) => msg.format(get_metadata),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use gears::{
     gas::store::errors::GasStoreErrors,
     params::{
         gas, infallible_subspace, infallible_subspace_mut, ParamKind, ParamsDeserialize,
-        ParamsSerialize, ParamsSubspaceKey,
+        ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
     },
     store::{database::Database, StoreKey},
     types::{decimal256::Decimal256, errors::StdError},
@@ -157,6 +157,25 @@ impl ParamsDeserialize for DistributionParams {
                 .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let invalid = |reason: &str| ParamsValidationError::invalid_value(key, reason);
+
+        match key {
+            KEY_COMMUNITY_TAX | KEY_BASE_PROPOSER_REWARD | KEY_BONUS_PROPOSER_REWARD => {
+                match std::str::from_utf8(value)
+                    .ok()
+                    .and_then(|this| Decimal256::from_str(this).ok())
+                {
+                    Some(var) if var <= Decimal256::one() => Ok(()),
+                    Some(_) => Err(invalid("must not be greater than 1")),
+                    None => Err(invalid("expected decimal")),
+                }
+            }
+            KEY_WITHDRAW_ADDR_ENABLED => ParamKind::Bool.validate(key, value),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 impl Default for DistributionParams {
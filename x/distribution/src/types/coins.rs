@@ -0,0 +1,133 @@
+//! Distribution stores many coin sets which can legitimately be empty (e.g. the rewards of a
+//! validator without delegators). Coins structure cannot be empty, so such sets are represented
+//! as `Option<DecimalCoins>` where `None` stands for an empty set.
+
+use gears::{
+    core::errors::CoreError,
+    types::base::{
+        coin::{DecimalCoin, DecimalCoinRaw},
+        coins::DecimalCoins,
+        errors::CoinsError,
+    },
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Creates coins from a list which can be empty
+pub fn coins_from_vec(coins: Vec<DecimalCoin>) -> Result<Option<DecimalCoins>, CoinsError> {
+    if coins.is_empty() {
+        Ok(None)
+    } else {
+        DecimalCoins::new(coins).map(Some)
+    }
+}
+
+pub(crate) fn coins_from_raw(
+    coins: Vec<DecimalCoinRaw>,
+) -> Result<Option<DecimalCoins>, CoreError> {
+    let mut result = vec![];
+    for coin in coins {
+        result.push(coin.try_into()?);
+    }
+    coins_from_vec(result).map_err(|e| CoreError::Coin(e.to_string()))
+}
+
+pub(crate) fn coins_to_raw(coins: Option<DecimalCoins>) -> Vec<DecimalCoinRaw> {
+    coins
+        .map(|coins| coins.into_inner().into_iter().map(Into::into).collect())
+        .unwrap_or_default()
+}
+
+/// Adds two sets of coins where `None` is an empty set
+pub fn checked_add(
+    lhs: &Option<DecimalCoins>,
+    rhs: &Option<DecimalCoins>,
+) -> Result<Option<DecimalCoins>, CoinsError> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => lhs.checked_add(rhs).map(Some),
+        (Some(coins), None) | (None, Some(coins)) => Ok(Some(coins.clone())),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Subtracts two sets of coins where `None` is an empty set. Returns `None` if
+/// all coins were subtracted.
+pub fn checked_sub(
+    lhs: &Option<DecimalCoins>,
+    rhs: &Option<DecimalCoins>,
+) -> Result<Option<DecimalCoins>, CoinsError> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => match lhs.checked_sub(rhs) {
+            Ok(coins) => Ok(Some(coins)),
+            Err(CoinsError::EmptyList) => Ok(None),
+            Err(e) => Err(e),
+        },
+        (lhs, None) => Ok(lhs.clone()),
+        (None, Some(_)) => Err(CoinsError::InvalidAmount),
+    }
+}
+
+/// Maps the error about empty result of coins operation to `None`
+pub fn empty_to_none(
+    result: Result<DecimalCoins, CoinsError>,
+) -> Result<Option<DecimalCoins>, CoinsError> {
+    match result {
+        Ok(coins) => Ok(Some(coins)),
+        Err(CoinsError::EmptyList) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Serde helpers which (de)serialize an empty list of coins as `None`
+pub(crate) mod serde_optional_coins {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        coins: &Option<DecimalCoins>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match coins {
+            Some(coins) => coins.inner().serialize(serializer),
+            None => Vec::<DecimalCoin>::new().serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DecimalCoins>, D::Error> {
+        let coins = Vec::<DecimalCoin>::deserialize(deserializer)?;
+        coins_from_vec(coins).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gears::types::decimal256::Decimal256;
+
+    fn coins(amount: u64) -> DecimalCoins {
+        DecimalCoins::new(vec![DecimalCoin::new(
+            Decimal256::from_atomics(amount, 0).expect("hardcoded value"),
+            "uatom".parse().expect("hardcoded value"),
+        )])
+        .expect("hardcoded value")
+    }
+
+    #[test]
+    fn checked_sub_to_empty_returns_none() {
+        let result = checked_sub(&Some(coins(10)), &Some(coins(10))).expect("valid operation");
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn checked_sub_from_empty_fails() {
+        let result = checked_sub(&None, &Some(coins(10)));
+        assert!(matches!(result, Err(CoinsError::InvalidAmount)));
+    }
+
+    #[test]
+    fn checked_add_with_empty() {
+        let result = checked_add(&None, &Some(coins(10))).expect("valid operation");
+        assert_eq!(result, Some(coins(10)));
+        assert_eq!(checked_add(&None, &None).expect("valid operation"), None);
+    }
+}
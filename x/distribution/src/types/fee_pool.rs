@@ -1,3 +1,4 @@
+use crate::types::coins::{coins_from_raw, coins_from_vec, coins_to_raw};
 use gears::{
     core::{errors::CoreError, Protobuf},
     types::base::{
@@ -18,20 +19,17 @@ pub struct FeePoolRaw {
 impl From<FeePool> for FeePoolRaw {
     fn from(FeePool { community_pool }: FeePool) -> Self {
         Self {
-            community_pool: community_pool
-                .into_inner()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
+            community_pool: coins_to_raw(community_pool),
         }
     }
 }
 
 /// FeePool is the global fee pool for distribution.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
 #[serde(try_from = "Vec<DecimalCoin>")]
 pub struct FeePool {
-    pub community_pool: DecimalCoins,
+    /// community_pool is `None` when the pool is empty
+    pub community_pool: Option<DecimalCoins>,
 }
 
 impl TryFrom<Vec<DecimalCoin>> for FeePool {
@@ -39,7 +37,7 @@ impl TryFrom<Vec<DecimalCoin>> for FeePool {
 
     fn try_from(value: Vec<DecimalCoin>) -> Result<Self, Self::Error> {
         Ok(Self {
-            community_pool: DecimalCoins::new(value)?,
+            community_pool: coins_from_vec(value)?,
         })
     }
 }
@@ -48,13 +46,9 @@ impl TryFrom<FeePoolRaw> for FeePool {
     type Error = CoreError;
 
     fn try_from(FeePoolRaw { community_pool }: FeePoolRaw) -> Result<Self, Self::Error> {
-        let mut coins = vec![];
-        for coin in community_pool {
-            coins.push(coin.try_into()?);
-        }
-        let community_pool =
-            DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
-        Ok(Self { community_pool })
+        Ok(Self {
+            community_pool: coins_from_raw(community_pool)?,
+        })
     }
 }
 
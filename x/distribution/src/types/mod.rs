@@ -1,8 +1,10 @@
 mod byte_value;
+pub(crate) mod coins;
 mod delegator;
 mod fee_pool;
 mod iter;
 mod query;
+mod rendering;
 mod tx;
 mod validator;
 
@@ -223,6 +223,15 @@ pub struct QueryDelegatorParams {
     pub delegator_address: AccAddress,
 }
 
+/// QueryDelegatorWithdrawAddressRequest is the request type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub delegator_address: AccAddress,
+}
+
 #[derive(Clone, PartialEq, Message, Raw, Protobuf)]
 pub struct QueryCommunityPoolRequest {}
 
@@ -465,6 +474,15 @@ pub struct QueryDelegatorTotalRewardsResponse {
     pub total: Option<DecimalCoins>,
 }
 
+/// QueryDelegatorWithdrawAddressResponse is the response type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressResponse {
+    /// withdraw_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub withdraw_address: AccAddress,
+}
+
 #[derive(Clone, Serialize, Message)]
 pub struct QueryCommunityPoolResponseRaw {
     #[prost(bytes, optional, tag = "1")]
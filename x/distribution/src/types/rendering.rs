@@ -0,0 +1,128 @@
+//! Textual sign mode rendering of distribution messages as specified in ADR-050
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Indent, Screen},
+};
+
+use super::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+
+impl ValueRenderer for MsgWithdrawDelegatorReward {
+    /// Format `MsgWithdrawDelegatorReward`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ];
+
+        // fields with default values are not rendered
+        if self.withdraw_commission {
+            screens.push(Screen {
+                title: "Withdraw commission".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.withdraw_commission),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for MsgSetWithdrawAddr {
+    /// Format `MsgSetWithdrawAddr`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Withdraw address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.withdraw_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for MsgFundCommunityPool {
+    /// Format `MsgFundCommunityPool`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Depositor".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.depositor.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        signing::{handler::NoneMetadataGetter, renderer::value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use crate::MsgWithdrawDelegatorReward;
+
+    #[test]
+    fn withdraw_rewards_works() -> anyhow::Result<()> {
+        let msg: MsgWithdrawDelegatorReward = serde_json::from_str(
+            r#"{
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "withdraw_commission": true
+        }"#,
+        )?;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Withdraw commission", "content": "True", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
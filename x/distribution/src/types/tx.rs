@@ -38,7 +38,10 @@ impl From<MsgWithdrawDelegatorReward> for MsgWithdrawDelegatorRewardRaw {
 /// MsgWithdrawDelegatorReward represents delegation withdrawal to a delegator
 /// from a single validator.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.distribution.v1beta1.WithdrawRewards")]
+#[msg(
+    url = "/cosmos.distribution.v1beta1.WithdrawRewards",
+    amino_url = "cosmos-sdk/MsgWithdrawDelegationReward"
+)]
 pub struct MsgWithdrawDelegatorReward {
     pub validator_address: ValAddress,
     #[msg(signer)]
@@ -91,7 +94,10 @@ impl From<MsgSetWithdrawAddr> for MsgSetWithdrawAddrRaw {
 /// MsgSetWithdrawAddr represents delegation withdrawal to a delegator
 /// from a single validator.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.distribution.v1beta1.SetWithdrawAddr")]
+#[msg(
+    url = "/cosmos.distribution.v1beta1.SetWithdrawAddr",
+    amino_url = "cosmos-sdk/MsgModifyWithdrawAddress"
+)]
 pub struct MsgSetWithdrawAddr {
     #[msg(signer)]
     pub delegator_address: AccAddress,
@@ -136,7 +142,10 @@ impl From<MsgFundCommunityPool> for MsgFundCommunityPoolRaw {
 /// MsgFundCommunityPool represents delegation withdrawal to a delegator
 /// from a single validator.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.distribution.v1beta1.FundCommunityPool")]
+#[msg(
+    url = "/cosmos.distribution.v1beta1.FundCommunityPool",
+    amino_url = "cosmos-sdk/MsgFundCommunityPool"
+)]
 pub struct MsgFundCommunityPool {
     pub amount: UnsignedCoins,
     #[msg(signer)]
@@ -1,4 +1,7 @@
-use crate::errors::ValidatorHistoricalRewardsReferenceCountError;
+use crate::{
+    errors::ValidatorHistoricalRewardsReferenceCountError,
+    types::coins::{coins_from_raw, coins_from_vec, coins_to_raw, serde_optional_coins},
+};
 use gears::{
     core::{errors::CoreError, Protobuf},
     types::{
@@ -34,7 +37,7 @@ pub struct ValidatorOutstandingRewardsRaw {
 impl From<ValidatorOutstandingRewards> for ValidatorOutstandingRewardsRaw {
     fn from(ValidatorOutstandingRewards { rewards }: ValidatorOutstandingRewards) -> Self {
         Self {
-            rewards: rewards.into_inner().into_iter().map(Into::into).collect(),
+            rewards: coins_to_raw(rewards),
         }
     }
 }
@@ -44,7 +47,7 @@ impl From<ValidatorOutstandingRewards> for ValidatorOutstandingRewardsRaw {
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(try_from = "Vec<DecimalCoin>")]
 pub struct ValidatorOutstandingRewards {
-    pub rewards: DecimalCoins,
+    pub rewards: Option<DecimalCoins>,
 }
 
 impl TryFrom<Vec<DecimalCoin>> for ValidatorOutstandingRewards {
@@ -52,7 +55,7 @@ impl TryFrom<Vec<DecimalCoin>> for ValidatorOutstandingRewards {
 
     fn try_from(value: Vec<DecimalCoin>) -> Result<Self, Self::Error> {
         Ok(Self {
-            rewards: DecimalCoins::new(value)?,
+            rewards: coins_from_vec(value)?,
         })
     }
 }
@@ -62,12 +65,9 @@ impl TryFrom<ValidatorOutstandingRewardsRaw> for ValidatorOutstandingRewards {
     fn try_from(
         ValidatorOutstandingRewardsRaw { rewards }: ValidatorOutstandingRewardsRaw,
     ) -> Result<Self, Self::Error> {
-        let mut coins = vec![];
-        for coin in rewards {
-            coins.push(coin.try_into()?);
-        }
-        let rewards = DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
-        Ok(Self { rewards })
+        Ok(Self {
+            rewards: coins_from_raw(rewards)?,
+        })
     }
 }
 
@@ -91,11 +91,7 @@ pub struct ValidatorAccumulatedCommissionRaw {
 impl From<ValidatorAccumulatedCommission> for ValidatorAccumulatedCommissionRaw {
     fn from(ValidatorAccumulatedCommission { commission }: ValidatorAccumulatedCommission) -> Self {
         Self {
-            commission: commission
-                .into_inner()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
+            commission: coins_to_raw(commission),
         }
     }
 }
@@ -105,7 +101,7 @@ impl From<ValidatorAccumulatedCommission> for ValidatorAccumulatedCommissionRaw
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(try_from = "Vec<DecimalCoin>")]
 pub struct ValidatorAccumulatedCommission {
-    pub commission: DecimalCoins,
+    pub commission: Option<DecimalCoins>,
 }
 
 impl TryFrom<Vec<DecimalCoin>> for ValidatorAccumulatedCommission {
@@ -113,7 +109,7 @@ impl TryFrom<Vec<DecimalCoin>> for ValidatorAccumulatedCommission {
 
     fn try_from(value: Vec<DecimalCoin>) -> Result<Self, Self::Error> {
         Ok(Self {
-            commission: DecimalCoins::new(value)?,
+            commission: coins_from_vec(value)?,
         })
     }
 }
@@ -123,12 +119,9 @@ impl TryFrom<ValidatorAccumulatedCommissionRaw> for ValidatorAccumulatedCommissi
     fn try_from(
         ValidatorAccumulatedCommissionRaw { commission }: ValidatorAccumulatedCommissionRaw,
     ) -> Result<Self, Self::Error> {
-        let mut coins = vec![];
-        for coin in commission {
-            coins.push(coin.try_into()?);
-        }
-        let commission = DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
-        Ok(Self { commission })
+        Ok(Self {
+            commission: coins_from_raw(commission)?,
+        })
     }
 }
 
@@ -153,7 +146,7 @@ pub struct ValidatorHistoricalRewardsReferenceCount {
 }
 
 impl ValidatorHistoricalRewardsReferenceCount {
-    const DEFAULT_UPPER_LIMIT: u32 = 2;
+    const DEFAULT_UPPER_LIMIT: u32 = 3;
 
     pub fn new(counter: u32) -> Result<Self, ValidatorHistoricalRewardsReferenceCountError> {
         if counter > Self::DEFAULT_UPPER_LIMIT {
@@ -213,11 +206,7 @@ impl From<ValidatorHistoricalRewards> for ValidatorHistoricalRewardsRaw {
         }: ValidatorHistoricalRewards,
     ) -> Self {
         Self {
-            cumulative_reward_ratio: cumulative_reward_ratio
-                .into_inner()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
+            cumulative_reward_ratio: coins_to_raw(cumulative_reward_ratio),
             reference_count: reference_count.counter(),
         }
     }
@@ -235,9 +224,9 @@ impl From<ValidatorHistoricalRewards> for ValidatorHistoricalRewardsRaw {
 ///  + number of slashes which ended the associated period (and might need to read that record)
 ///  + one per validator for the zeroeth period, set on initialization
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-// TODO: add serde(try_from) to check coins during genesis
 pub struct ValidatorHistoricalRewards {
-    pub cumulative_reward_ratio: DecimalCoins,
+    #[serde(with = "serde_optional_coins")]
+    pub cumulative_reward_ratio: Option<DecimalCoins>,
     pub reference_count: ValidatorHistoricalRewardsReferenceCount,
 }
 
@@ -249,14 +238,8 @@ impl TryFrom<ValidatorHistoricalRewardsRaw> for ValidatorHistoricalRewards {
             reference_count,
         }: ValidatorHistoricalRewardsRaw,
     ) -> Result<Self, Self::Error> {
-        let mut coins = vec![];
-        for coin in cumulative_reward_ratio {
-            coins.push(coin.try_into()?);
-        }
-        let cumulative_reward_ratio =
-            DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
         Ok(Self {
-            cumulative_reward_ratio,
+            cumulative_reward_ratio: coins_from_raw(cumulative_reward_ratio)?,
             reference_count: ValidatorHistoricalRewardsReferenceCount::new(reference_count)
                 .map_err(|e| CoreError::Custom(e.to_string()))?,
         })
@@ -285,7 +268,7 @@ pub struct ValidatorCurrentRewardsRaw {
 impl From<ValidatorCurrentRewards> for ValidatorCurrentRewardsRaw {
     fn from(ValidatorCurrentRewards { rewards, period }: ValidatorCurrentRewards) -> Self {
         Self {
-            rewards: rewards.into_inner().into_iter().map(Into::into).collect(),
+            rewards: coins_to_raw(rewards),
             period,
         }
     }
@@ -295,9 +278,9 @@ impl From<ValidatorCurrentRewards> for ValidatorCurrentRewardsRaw {
 /// period for a validator kept as a running counter and incremented
 /// each block as long as the validator's tokens remain constant.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-// TODO: add serde(try_from) to check coins during genesis
 pub struct ValidatorCurrentRewards {
-    pub rewards: DecimalCoins,
+    #[serde(with = "serde_optional_coins")]
+    pub rewards: Option<DecimalCoins>,
     pub period: u64,
 }
 
@@ -306,12 +289,10 @@ impl TryFrom<ValidatorCurrentRewardsRaw> for ValidatorCurrentRewards {
     fn try_from(
         ValidatorCurrentRewardsRaw { rewards, period }: ValidatorCurrentRewardsRaw,
     ) -> Result<Self, Self::Error> {
-        let mut coins = vec![];
-        for coin in rewards {
-            coins.push(coin.try_into()?);
-        }
-        let rewards = DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
-        Ok(Self { rewards, period })
+        Ok(Self {
+            rewards: coins_from_raw(rewards)?,
+            period,
+        })
     }
 }
 
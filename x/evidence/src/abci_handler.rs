@@ -21,13 +21,15 @@ use gears::{
         module::Module,
     },
 };
+use serde::Serialize;
 
 #[derive(Debug, Clone)]
 pub enum EvidenceNodeQueryRequest {
     Evidence(QueryEvidenceRequest),
     AllEvidence(QueryAllEvidenceRequest),
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum EvidenceNodeQueryResponse {
     Evidence(QueryEvidenceResponse),
     AllEvidence(QueryAllEvidenceResponse),
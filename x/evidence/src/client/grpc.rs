@@ -1 +1,120 @@
+use crate::{
+    types::{
+        RawQueryAllEvidenceRequest, RawQueryAllEvidenceResponse, RawQueryEvidenceRequest,
+        RawQueryEvidenceResponse,
+    },
+    EvidenceNodeQueryRequest, EvidenceNodeQueryResponse,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{invalid_argument, unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
 
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The evidence protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct EvidenceService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> EvidenceService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<EvidenceNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<EvidenceNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: EvidenceNodeQueryRequest,
+        height: u32,
+    ) -> Result<EvidenceNodeQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for EvidenceService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.evidence.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for EvidenceService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<EvidenceNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<EvidenceNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/cosmos.evidence.v1beta1.Query/Evidence" => {
+                unary(request, move |req: RawQueryEvidenceRequest, height| {
+                    info!("Received a gRPC request evidence::evidence");
+                    let req = EvidenceNodeQueryRequest::Evidence(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        EvidenceNodeQueryResponse::Evidence(res) => {
+                            Ok(RawQueryEvidenceResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.evidence.v1beta1.Query/AllEvidence" => {
+                unary(request, move |req: RawQueryAllEvidenceRequest, height| {
+                    info!("Received a gRPC request evidence::all_evidence");
+                    let req = EvidenceNodeQueryRequest::AllEvidence(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        EvidenceNodeQueryResponse::AllEvidence(res) => {
+                            Ok(RawQueryAllEvidenceResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> EvidenceService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<EvidenceNodeQueryRequest>,
+    QRes:
+        QueryResponse + Send + Sync + 'static + TryInto<EvidenceNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    EvidenceService {
+        app,
+        _phantom: Default::default(),
+    }
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::get,
+    Json, Router,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, Pagination, RestState},
+    tendermint::informal::hash::Hash,
+    types::pagination::request::PaginationRequest,
+};
+
+use crate::{
+    types::{QueryAllEvidenceRequest, QueryEvidenceRequest},
+    EvidenceNodeQueryRequest, EvidenceNodeQueryResponse,
+};
+
+pub async fn evidence<
+    QReq: QueryRequest + From<EvidenceNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<EvidenceNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(evidence_hash): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<EvidenceNodeQueryResponse>, HTTPError> {
+    let hash = Hash::from_str(&evidence_hash)
+        .map_err(|e| HTTPError::bad_request(format!("invalid evidence hash: {e}")))?;
+    let req = EvidenceNodeQueryRequest::Evidence(QueryEvidenceRequest {
+        evidence_hash: hash,
+    });
+
+    match rest_state
+        .app
+        .typed_query_at(req, height)?
+        .try_into()
+        .map_err(|_| HTTPError::internal_server_error())?
+    {
+        EvidenceNodeQueryResponse::Evidence(res) if res.evidence.is_some() => {
+            Ok(Json(EvidenceNodeQueryResponse::Evidence(res)))
+        }
+        EvidenceNodeQueryResponse::Evidence(_) => Err(HTTPError::not_found_with_msg(format!(
+            "evidence {evidence_hash} is not found"
+        ))),
+        _ => Err(HTTPError::internal_server_error()),
+    }
+}
+
+pub async fn all_evidence<
+    QReq: QueryRequest + From<EvidenceNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<EvidenceNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = EvidenceNodeQueryRequest::AllEvidence(QueryAllEvidenceRequest {
+        pagination: PaginationRequest::from(pagination),
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<EvidenceNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<EvidenceNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/evidence", get(all_evidence))
+        .route("/v1beta1/evidence/:evidence_hash", get(evidence))
+}
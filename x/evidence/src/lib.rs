@@ -10,4 +10,4 @@ pub use abci_handler::*;
 pub use client::*;
 pub use genesis::*;
 pub use keeper::*;
-pub use types::Evidence;
+pub use types::{Evidence, RawEquivocation};
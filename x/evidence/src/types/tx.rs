@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 /// MsgSubmitEvidence represents a message that supports submitting arbitrary
 /// Evidence of misbehavior such as equivocation or counterfactual signing.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, AppMessage)]
-#[msg(url = "/cosmos.evidence.v1beta1.SubmitEvidence")]
+#[msg(
+    url = "/cosmos.evidence.v1beta1.SubmitEvidence",
+    amino_url = "cosmos-sdk/MsgSubmitEvidence"
+)]
 pub struct MsgSubmitEvidence {
     #[raw(kind(string), raw = String)]
     #[msg(signer)]
@@ -0,0 +1,153 @@
+use std::marker::PhantomData;
+
+use gears::{
+    application::handlers::node::{ABCIHandler, ModuleInfo},
+    baseapp::{errors::QueryError, QueryResponse},
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, InfallibleContext,
+        TransactionalContext,
+    },
+    core::Protobuf,
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::{
+        request::RequestEndBlock,
+        types::{
+            proto::{
+                event::{Event, EventAttribute},
+                validator::ValidatorUpdate,
+            },
+            request::query::RequestQuery,
+        },
+    },
+    types::{base::coin::DecimalCoin, decimal256::CosmosDecimalProtoString, tx::NullTxMsg},
+};
+
+use crate::{
+    genesis::FeeMarketGenesis,
+    keeper::Keeper,
+    types::query::{
+        request::{FeeMarketQueryRequest, QueryBaseFeeRequest, QueryParamsRequest},
+        response::{FeeMarketQueryResponse, QueryBaseFeeResponse, QueryParamsResponse},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct FeeMarketAbciHandler<SK, PSK, MI> {
+    keeper: Keeper<SK, PSK>,
+    _marker: PhantomData<MI>,
+}
+
+impl<SK, PSK, MI> FeeMarketAbciHandler<SK, PSK, MI> {
+    pub fn new(keeper: Keeper<SK, PSK>) -> Self {
+        Self {
+            keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> FeeMarketAbciHandler<SK, PSK, MI> {
+    pub fn query_params<CTX: InfallibleContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+    ) -> QueryParamsResponse {
+        QueryParamsResponse {
+            params: self.keeper.params(ctx),
+        }
+    }
+
+    pub fn query_base_fee<CTX: InfallibleContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+    ) -> QueryBaseFeeResponse {
+        QueryBaseFeeResponse {
+            base_fee: DecimalCoin {
+                denom: self.keeper.params(ctx).denom,
+                amount: self.keeper.base_gas_price(ctx),
+            },
+        }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler
+    for FeeMarketAbciHandler<SK, PSK, MI>
+{
+    type Message = NullTxMsg;
+
+    type Genesis = FeeMarketGenesis;
+
+    type StoreKey = SK;
+
+    type QReq = FeeMarketQueryRequest;
+
+    type QRes = FeeMarketQueryResponse;
+
+    fn typed_query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: Self::QReq,
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
+            FeeMarketQueryRequest::Params(_) => Self::QRes::Params(self.query_params(ctx)),
+            FeeMarketQueryRequest::BaseFee(_) => Self::QRes::BaseFee(self.query_base_fee(ctx)),
+        };
+
+        Ok(res)
+    }
+
+    fn msg<DB: Database>(
+        &self,
+        _ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
+        _msg: &Self::Message,
+    ) -> Result<(), gears::application::handlers::node::TxError> {
+        unreachable!("Module {} doesn't have any tx", MI::NAME)
+    }
+
+    fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+        genesis: Self::Genesis,
+    ) -> Vec<ValidatorUpdate> {
+        self.keeper.init_genesis(ctx, genesis);
+
+        Vec::new()
+    }
+
+    fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        RequestQuery { data, path, .. }: RequestQuery,
+    ) -> Result<Vec<u8>, QueryError> {
+        let query = match path.as_str() {
+            QueryParamsRequest::QUERY_URL => {
+                Self::QReq::Params(QueryParamsRequest::decode_vec(&data)?)
+            }
+            QueryBaseFeeRequest::QUERY_URL => {
+                Self::QReq::BaseFee(QueryBaseFeeRequest::decode_vec(&data)?)
+            }
+            _ => Err(QueryError::PathNotFound)?,
+        };
+
+        Ok(self.typed_query(ctx, query)?.into_bytes())
+    }
+
+    fn end_block<'a, DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        _request: RequestEndBlock,
+    ) -> Vec<ValidatorUpdate> {
+        if let Some(base_gas_price) = self.keeper.update_base_gas_price(ctx) {
+            ctx.push_event(Event::new(
+                "fee_market",
+                [EventAttribute::new(
+                    "base_fee".into(),
+                    base_gas_price.to_cosmos_proto_string().into(),
+                    true,
+                )],
+            ));
+        }
+
+        Vec::new()
+    }
+}
@@ -0,0 +1,49 @@
+use gears::{application::handlers::client::QueryHandler, core::Protobuf};
+use query::{FeeMarketCommands, FeeMarketQueryCli};
+
+use crate::types::query::{
+    request::{FeeMarketQueryRequest, QueryBaseFeeRequest, QueryParamsRequest},
+    response::{FeeMarketQueryResponse, QueryBaseFeeResponse, QueryParamsResponse},
+};
+
+pub mod query;
+
+#[derive(Debug, Clone, Default)]
+pub struct FeeMarketClientHandler;
+
+impl QueryHandler for FeeMarketClientHandler {
+    type QueryCommands = FeeMarketQueryCli;
+
+    type QueryRequest = FeeMarketQueryRequest;
+
+    type QueryResponse = FeeMarketQueryResponse;
+
+    fn prepare_query_request(
+        &self,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryRequest> {
+        let request = match &command.command {
+            FeeMarketCommands::Params => Self::QueryRequest::Params(QueryParamsRequest {}),
+            FeeMarketCommands::BaseFee => Self::QueryRequest::BaseFee(QueryBaseFeeRequest {}),
+        };
+
+        Ok(request)
+    }
+
+    fn handle_raw_response(
+        &self,
+        bytes: Vec<u8>,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryResponse> {
+        let response = match &command.command {
+            FeeMarketCommands::Params => {
+                Self::QueryResponse::Params(QueryParamsResponse::decode_vec(&bytes)?)
+            }
+            FeeMarketCommands::BaseFee => {
+                Self::QueryResponse::BaseFee(QueryBaseFeeResponse::decode_vec(&bytes)?)
+            }
+        };
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,15 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct FeeMarketQueryCli {
+    #[command(subcommand)]
+    pub command: FeeMarketCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum FeeMarketCommands {
+    /// Query the current fee market parameters
+    Params,
+    /// Query the base gas price of the current block
+    BaseFee,
+}
@@ -0,0 +1,116 @@
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
+
+use crate::types::query::{
+    request::{
+        FeeMarketQueryRequest, QueryBaseFeeRequest, QueryParamsRequest, RawQueryBaseFeeRequest,
+        RawQueryParamsRequest,
+    },
+    response::{FeeMarketQueryResponse, RawQueryBaseFeeResponse, RawQueryParamsResponse},
+};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The fee market protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct FeeMarketService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> FeeMarketService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + TryInto<FeeMarketQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: FeeMarketQueryRequest,
+        height: u32,
+    ) -> Result<FeeMarketQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for FeeMarketService<QH, QReq, QRes> {
+    const NAME: &'static str = "feemarket.feemarket.v1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for FeeMarketService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + TryInto<FeeMarketQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/feemarket.feemarket.v1.Query/Params" => {
+                unary(request, move |_: RawQueryParamsRequest, height| {
+                    info!("Received a gRPC request feemarket::params");
+                    let req = FeeMarketQueryRequest::Params(QueryParamsRequest {});
+
+                    match service.typed_query(req, height)? {
+                        FeeMarketQueryResponse::Params(res) => {
+                            Ok(RawQueryParamsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/feemarket.feemarket.v1.Query/BaseFee" => {
+                unary(request, move |_: RawQueryBaseFeeRequest, height| {
+                    info!("Received a gRPC request feemarket::base_fee");
+                    let req = FeeMarketQueryRequest::BaseFee(QueryBaseFeeRequest {});
+
+                    match service.typed_query(req, height)? {
+                        FeeMarketQueryResponse::BaseFee(res) => {
+                            Ok(RawQueryBaseFeeResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> FeeMarketService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<FeeMarketQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    FeeMarketService {
+        app,
+        _phantom: Default::default(),
+    }
+}
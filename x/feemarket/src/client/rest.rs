@@ -0,0 +1,46 @@
+use axum::{extract::State, routing::get, Json, Router};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, RestState},
+};
+
+use crate::types::query::{
+    request::{FeeMarketQueryRequest, QueryBaseFeeRequest, QueryParamsRequest},
+    response::FeeMarketQueryResponse,
+};
+
+pub async fn params<
+    QReq: QueryRequest + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + TryInto<FeeMarketQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = FeeMarketQueryRequest::Params(QueryParamsRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn base_fee<
+    QReq: QueryRequest + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + TryInto<FeeMarketQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = FeeMarketQueryRequest::BaseFee(QueryBaseFeeRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<FeeMarketQueryRequest>,
+    QRes: QueryResponse + TryInto<FeeMarketQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1/params", get(params))
+        .route("/v1/base_fee", get(base_fee))
+}
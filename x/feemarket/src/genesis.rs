@@ -0,0 +1,32 @@
+use gears::{baseapp::genesis::Genesis, types::decimal256::Decimal256};
+use serde::{Deserialize, Serialize};
+
+use crate::params::FeeMarketParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeMarketGenesis {
+    pub params: FeeMarketParams,
+    /// base price of a gas unit for the first block
+    pub base_gas_price: Decimal256,
+}
+
+impl Default for FeeMarketGenesis {
+    fn default() -> Self {
+        let params = FeeMarketParams::default();
+
+        Self {
+            base_gas_price: params.min_base_gas_price,
+            params,
+        }
+    }
+}
+
+impl Genesis for FeeMarketGenesis {
+    fn add_genesis_account(
+        &mut self,
+        _address: gears::types::address::AccAddress,
+        _coins: gears::types::base::coins::UnsignedCoins,
+    ) -> Result<(), gears::baseapp::genesis::GenesisError> {
+        Ok(())
+    }
+}
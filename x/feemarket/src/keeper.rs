@@ -0,0 +1,136 @@
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    context::{block::BlockContext, InfallibleContext, InfallibleContextMut, QueryableContext},
+    extensions::corruption::UnwrapCorrupt,
+    gas::{metering::Gas, store::errors::GasStoreErrors},
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    types::{
+        base::coin::DecimalCoin,
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+    },
+    x::keepers::feemarket::FeeMarketKeeper,
+};
+
+use crate::{
+    genesis::FeeMarketGenesis,
+    params::{FeeMarketParams, FeeMarketParamsKeeper},
+};
+
+const BASE_GAS_PRICE_KEY: [u8; 1] = [0x00];
+
+#[derive(Debug, Clone)]
+pub struct Keeper<SK, PSK> {
+    store_key: SK,
+    params_keeper: FeeMarketParamsKeeper<PSK>,
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
+    pub fn new(store_key: SK, params_subspace_key: PSK) -> Self {
+        Self {
+            store_key,
+            params_keeper: FeeMarketParamsKeeper {
+                params_subspace_key,
+            },
+        }
+    }
+
+    pub fn init_genesis<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        FeeMarketGenesis {
+            params,
+            base_gas_price,
+        }: FeeMarketGenesis,
+    ) {
+        self.params_keeper.set(ctx, params);
+        self.set_base_gas_price(ctx, base_gas_price);
+    }
+
+    pub fn params<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> FeeMarketParams {
+        self.params_keeper.get(ctx)
+    }
+
+    /// Base gas price of the current block. Falls back to minimal price of params if it was never set
+    pub fn base_gas_price<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Decimal256 {
+        ctx.infallible_store(&self.store_key)
+            .get(&BASE_GAS_PRICE_KEY)
+            .map(|this| {
+                Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(&this))
+                    .unwrap_or_corrupt()
+            })
+            .unwrap_or_else(|| self.params_keeper.get(ctx).min_base_gas_price)
+    }
+
+    pub fn set_base_gas_price<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        base_gas_price: Decimal256,
+    ) {
+        ctx.infallible_store_mut(&self.store_key).set(
+            BASE_GAS_PRICE_KEY,
+            base_gas_price.to_cosmos_proto_string().into_bytes(),
+        );
+    }
+
+    /// Sets base gas price for the next block from gas used by the current one.
+    /// Returns new price if it was changed. The price is left as is while the fee market is
+    /// disabled. It only follows the utilization with a finite block gas limit, i.e. `max_gas`
+    /// of consensus params, with unlimited blocks (`-1`) it never changes.
+    pub fn update_base_gas_price<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+    ) -> Option<Decimal256> {
+        let params = self.params_keeper.get(ctx);
+        if !params.enabled {
+            return None;
+        }
+
+        let base_gas_price = self.base_gas_price(ctx);
+
+        let next = params
+            .next_base_gas_price(
+                base_gas_price,
+                ctx.block_gas_used(),
+                Gas::from(ctx.consensus_params().block.max_gas),
+            )
+            .unwrap_or(base_gas_price);
+
+        match next != base_gas_price {
+            true => {
+                self.set_base_gas_price(ctx, next);
+                Some(next)
+            }
+            false => None,
+        }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey> FeeMarketKeeper<SK> for Keeper<SK, PSK> {
+    fn base_gas_price<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Option<DecimalCoin>, GasStoreErrors> {
+        let params = self.params_keeper.try_get(ctx)?;
+        if !params.enabled {
+            return Ok(None);
+        }
+
+        let amount = match ctx.kv_store(&self.store_key).get(&BASE_GAS_PRICE_KEY)? {
+            Some(bytes) => Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(&bytes))
+                .unwrap_or_corrupt(),
+            None => params.min_base_gas_price,
+        };
+
+        Ok(Some(DecimalCoin {
+            denom: params.denom,
+            amount,
+        }))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod abci_handler;
+pub mod client;
+pub mod genesis;
+pub mod keeper;
+pub mod params;
+pub mod types;
@@ -0,0 +1,307 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    derive::{Protobuf, Raw},
+    extensions::corruption::UnwrapCorrupt,
+    gas::metering::{FiniteGas, Gas},
+    params::{
+        ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
+    },
+    types::{
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        denom::Denom,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+const ENABLED_KEY: &str = "Enabled";
+const DENOM_KEY: &str = "Denom";
+const MIN_BASE_GAS_PRICE_KEY: &str = "MinBaseGasPrice";
+const TARGET_BLOCK_UTILIZATION_KEY: &str = "TargetBlockUtilization";
+const MAX_CHANGE_RATE_KEY: &str = "MaxChangeRate";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct FeeMarketParams {
+    /// whether base gas price is enforced for txs. The price only adjusts to block utilization
+    /// when consensus params limit block gas, the default `max_gas` of `-1` keeps it constant
+    #[raw(kind(bool))]
+    pub enabled: bool,
+    /// denom of the base gas price
+    #[raw(kind(string), raw = String)]
+    pub denom: Denom,
+    /// base gas price never drops below this value
+    #[raw(kind(string), raw = String)]
+    #[proto(
+        from = "CosmosDecimalProtoString::from_cosmos_proto_string",
+        from_ref,
+        into = "CosmosDecimalProtoString::to_cosmos_proto_string",
+        into_ref
+    )]
+    pub min_base_gas_price: Decimal256,
+    /// share of the block gas limit at which base gas price stays the same
+    #[raw(kind(string), raw = String)]
+    #[proto(
+        from = "CosmosDecimalProtoString::from_cosmos_proto_string",
+        from_ref,
+        into = "CosmosDecimalProtoString::to_cosmos_proto_string",
+        into_ref
+    )]
+    pub target_block_utilization: Decimal256,
+    /// maximum change of base gas price between two blocks
+    #[raw(kind(string), raw = String)]
+    #[proto(
+        from = "CosmosDecimalProtoString::from_cosmos_proto_string",
+        from_ref,
+        into = "CosmosDecimalProtoString::to_cosmos_proto_string",
+        into_ref
+    )]
+    pub max_change_rate: Decimal256,
+}
+
+impl Default for FeeMarketParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            denom: Denom::from_str(env!("XMOD_STAKING_PARAMS_BOND_DENOM"))
+                .expect("default denom for fee market is invalid"),
+            min_base_gas_price: Decimal256::from_atomics(25_u8, 4).expect("default is valid"),
+            target_block_utilization: Decimal256::from_atomics(5_u8, 1).expect("default is valid"),
+            // same as in EIP-1559
+            max_change_rate: Decimal256::from_atomics(125_u8, 3).expect("default is valid"),
+        }
+    }
+}
+
+impl FeeMarketParams {
+    /// Adjusts base gas price by the gas used in the block like EIP-1559 does. The price grows
+    /// when the block uses more gas than targeted and drops otherwise, but never more than
+    /// `max_change_rate` per block.
+    pub fn next_base_gas_price(
+        &self,
+        base_gas_price: Decimal256,
+        block_gas_used: FiniteGas,
+        max_block_gas: Gas,
+    ) -> Option<Decimal256> {
+        let max_block_gas = match max_block_gas {
+            Gas::Finite(max_block_gas) if max_block_gas > FiniteGas::ZERO => max_block_gas,
+            // there is no utilization to target without block gas limit
+            _ => return Some(base_gas_price.max(self.min_base_gas_price)),
+        };
+
+        let target = Decimal256::from(max_block_gas)
+            .checked_mul(self.target_block_utilization)
+            .ok()?;
+        let used = Decimal256::from(block_gas_used);
+
+        let next = if used >= target {
+            let change = used
+                .checked_sub(target)
+                .ok()?
+                .checked_div(target)
+                .ok()?
+                .min(Decimal256::one())
+                .checked_mul(self.max_change_rate)
+                .ok()?;
+
+            base_gas_price
+                .checked_mul(Decimal256::one().checked_add(change).ok()?)
+                .ok()?
+        } else {
+            let change = target
+                .checked_sub(used)
+                .ok()?
+                .checked_div(target)
+                .ok()?
+                .min(Decimal256::one())
+                .checked_mul(self.max_change_rate)
+                .ok()?;
+
+            base_gas_price
+                .checked_mul(Decimal256::one().checked_sub(change).ok()?)
+                .ok()?
+        };
+
+        Some(next.max(self.min_base_gas_price))
+    }
+}
+
+impl ParamsSerialize for FeeMarketParams {
+    fn keys() -> HashSet<&'static str> {
+        HashSet::from_iter([
+            ENABLED_KEY,
+            DENOM_KEY,
+            MIN_BASE_GAS_PRICE_KEY,
+            TARGET_BLOCK_UTILIZATION_KEY,
+            MAX_CHANGE_RATE_KEY,
+        ])
+    }
+
+    fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+        vec![
+            (ENABLED_KEY, self.enabled.to_string().into_bytes()),
+            (DENOM_KEY, self.denom.to_string().into_bytes()),
+            (
+                MIN_BASE_GAS_PRICE_KEY,
+                self.min_base_gas_price
+                    .to_cosmos_proto_string()
+                    .into_bytes(),
+            ),
+            (
+                TARGET_BLOCK_UTILIZATION_KEY,
+                self.target_block_utilization
+                    .to_cosmos_proto_string()
+                    .into_bytes(),
+            ),
+            (
+                MAX_CHANGE_RATE_KEY,
+                self.max_change_rate.to_cosmos_proto_string().into_bytes(),
+            ),
+        ]
+    }
+}
+
+impl ParamsDeserialize for FeeMarketParams {
+    fn from_raw(mut fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        let decimal = |value: Vec<u8>| {
+            Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(&value))
+                .unwrap_or_corrupt()
+        };
+
+        Self {
+            enabled: ParamKind::Bool
+                .parse_param(fields.remove(ENABLED_KEY).unwrap_or_corrupt())
+                .boolean()
+                .unwrap_or_corrupt(),
+            denom: Denom::from_str(&String::from_utf8_lossy(
+                &fields.remove(DENOM_KEY).unwrap_or_corrupt(),
+            ))
+            .unwrap_or_corrupt(),
+            min_base_gas_price: decimal(fields.remove(MIN_BASE_GAS_PRICE_KEY).unwrap_or_corrupt()),
+            target_block_utilization: decimal(
+                fields
+                    .remove(TARGET_BLOCK_UTILIZATION_KEY)
+                    .unwrap_or_corrupt(),
+            ),
+            max_change_rate: decimal(fields.remove(MAX_CHANGE_RATE_KEY).unwrap_or_corrupt()),
+        }
+    }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let decimal = || {
+            Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(value))
+                .map_err(|e| ParamsValidationError::invalid_value(key, e))
+        };
+
+        match key {
+            ENABLED_KEY => ParamKind::Bool.validate(key, value),
+            DENOM_KEY => Denom::from_str(&String::from_utf8_lossy(value))
+                .map(|_| ())
+                .map_err(|e| ParamsValidationError::invalid_value(key, e)),
+            MIN_BASE_GAS_PRICE_KEY => decimal().map(|_| ()),
+            TARGET_BLOCK_UTILIZATION_KEY => match decimal()? {
+                utilization if utilization.is_zero() || utilization > Decimal256::one() => Err(
+                    ParamsValidationError::invalid_value(key, "must be in (0, 1]"),
+                ),
+                _ => Ok(()),
+            },
+            MAX_CHANGE_RATE_KEY => match decimal()? > Decimal256::one() {
+                true => Err(ParamsValidationError::invalid_value(
+                    key,
+                    "must not be greater than 1",
+                )),
+                false => Ok(()),
+            },
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeMarketParamsKeeper<PSK> {
+    pub(super) params_subspace_key: PSK,
+}
+
+impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for FeeMarketParamsKeeper<PSK> {
+    type Param = FeeMarketParams;
+
+    fn psk(&self) -> &PSK {
+        &self.params_subspace_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gas(value: u32) -> FiniteGas {
+        FiniteGas::from(value)
+    }
+
+    fn price(value: u64, decimal_places: u32) -> Decimal256 {
+        Decimal256::from_atomics(value, decimal_places).expect("hardcoded is valid")
+    }
+
+    fn params() -> FeeMarketParams {
+        FeeMarketParams {
+            min_base_gas_price: Decimal256::zero(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn base_gas_price_unchanged_on_target() {
+        let next = params().next_base_gas_price(price(1, 0), gas(500), Gas::Finite(gas(1000)));
+
+        assert_eq!(Some(price(1, 0)), next);
+    }
+
+    #[test]
+    fn base_gas_price_changes_at_most_by_max_change_rate() {
+        let params = params();
+
+        let full = params.next_base_gas_price(price(1, 0), gas(1000), Gas::Finite(gas(1000)));
+        assert_eq!(Some(price(1125, 3)), full);
+
+        let empty = params.next_base_gas_price(price(1, 0), gas(0), Gas::Finite(gas(1000)));
+        assert_eq!(Some(price(875, 3)), empty);
+
+        let quarter = params.next_base_gas_price(price(1, 0), gas(250), Gas::Finite(gas(1000)));
+        assert_eq!(Some(price(9375, 4)), quarter);
+    }
+
+    #[test]
+    fn base_gas_price_never_below_min() {
+        let params = FeeMarketParams::default();
+
+        let next =
+            params.next_base_gas_price(params.min_base_gas_price, gas(0), Gas::Finite(gas(1000)));
+
+        assert_eq!(Some(params.min_base_gas_price), next);
+    }
+
+    #[test]
+    fn base_gas_price_without_block_limit() {
+        let next = params().next_base_gas_price(price(1, 0), gas(1_000_000), Gas::Infinite);
+
+        assert_eq!(Some(price(1, 0)), next);
+    }
+
+    #[test]
+    fn validate_raw_rejects_invalid_values() {
+        for (key, value) in FeeMarketParams::default().to_raw() {
+            assert_eq!(Ok(()), FeeMarketParams::validate_raw(key, &value));
+        }
+
+        let zero = Decimal256::zero().to_cosmos_proto_string();
+        assert!(
+            FeeMarketParams::validate_raw(TARGET_BLOCK_UTILIZATION_KEY, zero.as_bytes()).is_err()
+        );
+
+        let two = price(2, 0).to_cosmos_proto_string();
+        assert!(FeeMarketParams::validate_raw(MAX_CHANGE_RATE_KEY, two.as_bytes()).is_err());
+    }
+}
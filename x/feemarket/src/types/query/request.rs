@@ -0,0 +1,24 @@
+use gears::{
+    baseapp::QueryRequest,
+    derive::{Protobuf, Query, Raw},
+};
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[query(url = "/feemarket.feemarket.v1.Query/Params")]
+pub struct QueryParamsRequest {}
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[query(url = "/feemarket.feemarket.v1.Query/BaseFee")]
+pub struct QueryBaseFeeRequest {}
+
+#[derive(Debug, Clone, PartialEq, Query)]
+pub enum FeeMarketQueryRequest {
+    Params(QueryParamsRequest),
+    BaseFee(QueryBaseFeeRequest),
+}
+
+impl QueryRequest for FeeMarketQueryRequest {
+    fn height(&self) -> u32 {
+        0
+    }
+}
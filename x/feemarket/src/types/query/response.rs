@@ -0,0 +1,26 @@
+use gears::{
+    derive::{Protobuf, Query, Raw},
+    types::base::coin::{DecimalCoin, DecimalCoinRaw},
+};
+
+use crate::params::{FeeMarketParams, RawFeeMarketParams};
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+pub struct QueryParamsResponse {
+    #[raw(kind(message), raw = RawFeeMarketParams)]
+    pub params: FeeMarketParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+pub struct QueryBaseFeeResponse {
+    /// base price of a gas unit for the current block
+    #[raw(kind(message), raw = DecimalCoinRaw)]
+    pub base_fee: DecimalCoin,
+}
+
+#[derive(Debug, Clone, PartialEq, Query, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum FeeMarketQueryResponse {
+    Params(QueryParamsResponse),
+    BaseFee(QueryBaseFeeResponse),
+}
@@ -15,6 +15,7 @@ use gears::{
         keepers::{
             auth::AuthKeeper,
             bank::BankKeeper,
+            feemarket::{FeeMarketKeeper, NoFeeMarket},
             staking::{KeeperHooks, StakingBankKeeper},
         },
         module::Module,
@@ -32,9 +33,10 @@ pub struct GenutilAbciHandler<
     KH: KeeperHooks<SK, AK, M>,
     M: Module,
     GC: SignGasConsumer,
+    FM: FeeMarketKeeper<SK> = NoFeeMarket,
 > {
     staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-    ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+    ante_handler: BaseAnteHandler<BK, AK, SK, GC, M, FM>,
 }
 
 impl<
@@ -45,11 +47,12 @@ impl<
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
         GC: SignGasConsumer,
-    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        FM: FeeMarketKeeper<SK>,
+    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC, FM>
 {
     pub fn new(
         staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-        ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+        ante_handler: BaseAnteHandler<BK, AK, SK, GC, M, FM>,
     ) -> Self {
         Self {
             staking,
@@ -66,7 +69,8 @@ impl<
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
         GC: SignGasConsumer,
-    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        FM: FeeMarketKeeper<SK>,
+    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC, FM>
 {
     type Message = NullTxMsg;
 
@@ -82,7 +86,7 @@ impl<
         &self,
         _ctx: &gears::context::query::QueryContext<DB, Self::StoreKey>,
         _query: Self::QReq,
-    ) -> Self::QRes {
+    ) -> Result<Self::QRes, gears::baseapp::errors::QueryError> {
         unreachable!()
     }
 
@@ -139,6 +143,10 @@ impl<
                     Ok(_) => (),
                     Err(err) => panic!("Failed to run message from tx: {err}"),
                 }
+
+                if let Err(err) = self.ante_handler.run_post(ctx, &tx) {
+                    panic!("Failed to run post handlers for tx: {err}")
+                }
             }
 
             match self.staking.apply_and_return_validator_set_updates(ctx) {
@@ -4,7 +4,7 @@ use std::{net::SocketAddr, path::PathBuf};
 use clap::{ArgAction, Args, ValueHint};
 use gears::application::ApplicationInfo;
 use gears::extensions::socket_addr;
-use gears::tendermint::types::proto::crypto::PublicKey as TendermintPublicKey;
+use gears::tendermint::{privval::Address, types::proto::crypto::PublicKey as TendermintPublicKey};
 use gears::types::{base::coin::UnsignedCoin, decimal256::Decimal256, uint::Uint256};
 
 use crate::gentx::GentxCmd;
@@ -16,6 +16,9 @@ pub struct GentxCli<AI: ApplicationInfo> {
     /// The validator's Protobuf JSON encoded public key
     #[arg(long)]
     pub pubkey: Option<TendermintPublicKey>,
+    /// Fetch the validator's public key from remote signer which connects to this address (e.g. unix:///path/to/privval.sock). Only unix sockets are supported
+    #[arg(long, conflicts_with = "pubkey", value_parser = gears::tendermint::privval::parse_laddr)]
+    pub priv_validator_laddr: Option<Address>,
     /// Amount of coins to bond
     pub amount: UnsignedCoin,
     /// The validator's name
@@ -75,6 +78,7 @@ impl<AI: ApplicationInfo> TryFrom<GentxCli<AI>> for GentxCmd {
             ip,
             node_id,
             pubkey,
+            priv_validator_laddr,
             amount,
             moniker,
             identity,
@@ -97,6 +101,7 @@ impl<AI: ApplicationInfo> TryFrom<GentxCli<AI>> for GentxCmd {
             node_id,
             ip,
             pubkey,
+            priv_validator_laddr,
             amount,
             moniker,
             identity: identity.unwrap_or_default(),
@@ -16,11 +16,13 @@ use staking::{CommissionRates, CreateValidator, Description};
 
 use crate::utils::{parse_staking_params_from_genesis, GenesisBalanceIter};
 
-use gears::tendermint::types::proto::crypto::PublicKey as TendermintPublicKey;
+use gears::tendermint::{privval::Address, types::proto::crypto::PublicKey as TendermintPublicKey};
 
 #[derive(Debug, Clone)]
 pub struct GentxCmd {
     pub pubkey: Option<TendermintPublicKey>,
+    /// Fetch consensus public key from remote signer connected to this address
+    pub priv_validator_laddr: Option<Address>,
     pub amount: UnsignedCoin,
     pub moniker: Option<String>,
     pub identity: String,
@@ -120,6 +122,7 @@ impl TxHandler for GentxTxHandler {
         client_tx_context: &mut ClientTxContext,
         Self::TxCommands {
             pubkey: pub_key,
+            priv_validator_laddr,
             amount,
             moniker,
             identity,
@@ -167,9 +170,18 @@ impl TxHandler for GentxTxHandler {
             ))?,
         }
 
-        let pub_key = match pub_key {
-            Some(var) => PublicKey::from(var),
-            None => {
+        let pub_key = match (pub_key, priv_validator_laddr) {
+            (Some(var), _) => PublicKey::from(var),
+            (None, Some(laddr)) => {
+                println!("Waiting for remote signer to connect to {laddr}");
+                gears::tendermint::privval::fetch_validator_pub_key(
+                    &laddr,
+                    &client_tx_context.chain_id,
+                    gears::tendermint::privval::CONNECT_TIMEOUT,
+                )?
+                .into()
+            }
+            (None, None) => {
                 let file = std::fs::File::open(
                     client_tx_context
                         .home
@@ -1,7 +1,6 @@
 use std::marker::PhantomData;
 
 use gears::baseapp::QueryResponse;
-use gears::extensions::gas::GasResultExt;
 use gears::tendermint::request::RequestEndBlock;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::{
@@ -39,9 +38,10 @@ use crate::{
         request::{
             QueryAllParamsRequest, QueryDepositRequest, QueryDepositsRequest, QueryParamsRequest,
             QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest,
-            QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
+            QueryTallyBreakdownRequest, QueryTallyResultRequest, QueryVoteRequest,
+            QueryVotesRequest,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
 };
 
@@ -107,8 +107,8 @@ impl<
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        self.keeper.query(ctx, query).unwrap_gas()
+    ) -> Result<Self::QRes, QueryError> {
+        Ok(self.keeper.query(ctx, query)?)
     }
 
     fn run_ante_checks<DB: Database>(
@@ -178,6 +178,40 @@ impl<
                         ),
                     })
             }
+            GovMsg::ProposalV1(msg) => {
+                let proposal_id = self
+                    .keeper
+                    .submit_proposal_v1(ctx, msg.clone())
+                    .map_err(GovTxError::from)
+                    .map_err(|e| e.into::<MI>())?;
+
+                let proposal_type = msg
+                    .messages
+                    .iter()
+                    .map(|this| this.type_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                self.keeper
+                    .deposit_add(
+                        ctx,
+                        Deposit {
+                            proposal_id,
+                            depositor: msg.proposer.clone(),
+                            amount: msg.initial_deposit.clone(),
+                        },
+                    )
+                    .map(|is_voting_started| match is_voting_started {
+                        true => (
+                            msg.proposer.to_string(),
+                            EmitEvent::Proposal((proposal_type, Some(proposal_id))),
+                        ),
+                        false => (
+                            msg.proposer.to_string(),
+                            EmitEvent::Proposal((proposal_type, None)),
+                        ),
+                    })
+            }
         }
         .map_err(GovTxError::from)
         .map_err(|e| e.into::<MI>())?;
@@ -273,10 +307,25 @@ impl<
             QueryProposerRequest::QUERY_URL => {
                 GovQuery::Proposer(QueryProposerRequest::decode(data)?)
             }
+            v1::request::QueryProposalRequest::QUERY_URL => {
+                GovQuery::ProposalV1(v1::request::QueryProposalRequest::decode(data)?)
+            }
+            v1::request::QueryProposalsRequest::QUERY_URL => {
+                GovQuery::ProposalsV1(v1::request::QueryProposalsRequest::decode(data)?)
+            }
+            v1::request::QueryParamsRequest::QUERY_URL => {
+                GovQuery::ParamsV1(v1::request::QueryParamsRequest::decode(data)?)
+            }
+            v1::request::QueryTallyResultRequest::QUERY_URL => {
+                GovQuery::TallyV1(v1::request::QueryTallyResultRequest::decode(data)?)
+            }
+            QueryTallyBreakdownRequest::QUERY_URL => {
+                GovQuery::TallyBreakdown(QueryTallyBreakdownRequest::decode(data)?)
+            }
             _ => Err(QueryError::PathNotFound)?,
         };
 
-        let result = self.keeper.query(ctx, query).unwrap_gas();
+        let result = self.keeper.query(ctx, query)?;
 
         Ok(result.into_bytes())
     }
@@ -43,4 +43,23 @@ pub enum GovQueryCliCommands {
     Proposer {
         proposal_id: u64,
     },
+    ProposalV1 {
+        proposal_id: u64,
+    },
+    ProposalsV1 {
+        voter: Option<AccAddress>,
+        depositor: Option<AccAddress>,
+        status: Option<ProposalStatus>,
+    },
+    ParamsV1 {
+        kind: ParamsQuery,
+    },
+    TallyV1 {
+        proposal_id: u64,
+    },
+    /// Votes of bonded validators and their delegators which voted on their own. Votes are
+    /// deleted when voting ends, use an earlier height for finished proposals
+    TallyBreakdown {
+        proposal_id: u64,
+    },
 }
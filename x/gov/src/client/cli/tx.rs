@@ -17,6 +17,7 @@ pub enum GovTxCommands {
     Vote(VoteCliCommand),
     WeightedVote(WeightedVoteCliCommand),
     SubmitProposal(ProposalCliCommand<ProposalCliSubcommand>),
+    SubmitProposalV1(ProposalV1CliCommand),
 }
 
 /// Deposit tokens for an active proposal
@@ -48,10 +49,24 @@ pub struct WeightedVoteCliCommand {
 #[derive(Args, Debug, Clone)]
 pub struct ProposalCliCommand<T: Subcommand> {
     pub initial_deposit: UnsignedCoins,
+    /// Submit as expedited proposal with shorter voting period and higher threshold
+    #[arg(long, default_value_t = false)]
+    pub expedited: bool,
     #[command(subcommand)]
     pub command: T,
 }
 
+/// Submit a gov v1 proposal along with an initial deposit. File contains json with `messages`,
+/// `metadata`, `title` and `summary` of the proposal
+#[derive(Args, Debug, Clone)]
+pub struct ProposalV1CliCommand {
+    pub initial_deposit: UnsignedCoins,
+    pub file: PathBuf,
+    /// Submit as expedited proposal with shorter voting period and higher threshold
+    #[arg(long, default_value_t = false)]
+    pub expedited: bool,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum ProposalCliSubcommand {
     Text(TextProposalCliCommand),
@@ -1,8 +1,18 @@
 use crate::{
     proposal::Proposal,
-    query::{GovQuery, GovQueryResponse},
+    query::{
+        request::inner::QueryTallyBreakdownRequest as RawQueryTallyBreakdownRequest,
+        response::inner::QueryTallyBreakdownResponse as RawQueryTallyBreakdownResponse, GovQuery,
+        GovQueryResponse,
+    },
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::{
+        block_height,
+        service::{invalid_argument, unary, unimplemented},
+    },
 };
-use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
 use ibc_proto::cosmos::gov::v1beta1::{
     query_server::{Query, QueryServer},
     QueryDepositRequest, QueryDepositResponse, QueryDepositsRequest, QueryDepositsResponse,
@@ -11,8 +21,17 @@ use ibc_proto::cosmos::gov::v1beta1::{
     QueryTallyResultResponse, QueryVoteRequest, QueryVoteResponse, QueryVotesRequest,
     QueryVotesResponse,
 };
-use std::marker::PhantomData;
-use tonic::{Request, Response, Status};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Request, Response, Status,
+};
 use tracing::info;
 
 const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
@@ -39,8 +58,9 @@ where
         request: Request<QueryProposalRequest>,
     ) -> Result<Response<QueryProposalResponse>, Status> {
         info!("Received a gRPC request gov::proposal");
+        let height = block_height(&request)?;
         let req = GovQuery::Proposal(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Proposal(response) = response {
             Ok(Response::new(response.into()))
@@ -54,8 +74,9 @@ where
         request: Request<QueryProposalsRequest>,
     ) -> Result<Response<QueryProposalsResponse>, Status> {
         info!("Received a gRPC request gov::proposals");
+        let height = block_height(&request)?;
         let req = GovQuery::Proposals(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Proposals(response) = response {
             Ok(Response::new(response.into()))
@@ -69,8 +90,9 @@ where
         request: Request<QueryVoteRequest>,
     ) -> Result<Response<QueryVoteResponse>, Status> {
         info!("Received a gRPC request gov::vote");
+        let height = block_height(&request)?;
         let req = GovQuery::Vote(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Vote(response) = response {
             Ok(Response::new(response.into()))
@@ -84,8 +106,9 @@ where
         request: Request<QueryVotesRequest>,
     ) -> Result<Response<QueryVotesResponse>, Status> {
         info!("Received a gRPC request gov::votes");
+        let height = block_height(&request)?;
         let req = GovQuery::Votes(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Votes(response) = response {
             Ok(Response::new(response.into()))
@@ -99,8 +122,9 @@ where
         request: Request<QueryParamsRequest>,
     ) -> Result<Response<QueryParamsResponse>, Status> {
         info!("Received a gRPC request gov::params");
+        let height = block_height(&request)?;
         let req = GovQuery::Params(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Params(response) = response {
             Ok(Response::new(response.into()))
@@ -114,8 +138,9 @@ where
         request: Request<QueryDepositRequest>,
     ) -> Result<Response<QueryDepositResponse>, Status> {
         info!("Received a gRPC request gov::deposit");
+        let height = block_height(&request)?;
         let req = GovQuery::Deposit(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Deposit(response) = response {
             Ok(Response::new(response.into()))
@@ -129,8 +154,9 @@ where
         request: Request<QueryDepositsRequest>,
     ) -> Result<Response<QueryDepositsResponse>, Status> {
         info!("Received a gRPC request gov::deposits");
+        let height = block_height(&request)?;
         let req = GovQuery::Deposits(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Deposits(response) = response {
             Ok(Response::new(response.into()))
@@ -144,8 +170,9 @@ where
         request: Request<QueryTallyResultRequest>,
     ) -> Result<Response<QueryTallyResultResponse>, Status> {
         info!("Received a gRPC request gov::tally_result");
+        let height = block_height(&request)?;
         let req = GovQuery::Tally(request.into_inner().try_into()?);
-        let response: GovQueryResponse<P> = self.app.typed_query(req)?.try_into()?;
+        let response: GovQueryResponse<P> = self.app.typed_query_at(req, height)?.try_into()?;
 
         if let GovQueryResponse::Tally(response) = response {
             Ok(Response::new(response.into()))
@@ -167,3 +194,79 @@ where
     };
     QueryServer::new(gov_service)
 }
+
+/// Gov queries of gears which aren't a part of cosmos sdk, so the service is dispatched by hand
+/// on the method path
+#[derive(Debug, Clone)]
+pub struct GearsGovService<QH, QReq, QRes, P> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes, P)>,
+}
+
+impl<QH, QReq, QRes, P> GearsGovService<QH, QReq, QRes, P>
+where
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    P: Proposal,
+{
+    fn typed_query(&self, req: GovQuery, height: u32) -> Result<GovQueryResponse<P>, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes, P> NamedService for GearsGovService<QH, QReq, QRes, P> {
+    const NAME: &'static str = "gears.gov.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, P, B> Service<http::Request<B>> for GearsGovService<QH, QReq, QRes, P>
+where
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    P: Proposal + Send + Sync,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/gears.gov.v1beta1.Query/TallyBreakdown" => unary(
+                request,
+                move |req: RawQueryTallyBreakdownRequest, height| {
+                    info!("Received a gRPC request gov::tally_breakdown");
+                    let req = GovQuery::TallyBreakdown(req.try_into().map_err(invalid_argument)?);
+
+                    match service.typed_query(req, height)? {
+                        GovQueryResponse::TallyBreakdown(res) => {
+                            Ok(RawQueryTallyBreakdownResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new_gears_service<QH, QReq, QRes, P: Proposal>(app: QH) -> GearsGovService<QH, QReq, QRes, P>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<GovQuery>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<GovQueryResponse<P>, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    GearsGovService {
+        app,
+        _phantom: Default::default(),
+    }
+}
@@ -7,14 +7,16 @@ use crate::{
         request::{
             QueryAllParamsRequest, QueryDepositRequest, QueryDepositsRequest, QueryParamsRequest,
             QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest,
-            QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
+            QueryTallyBreakdownRequest, QueryTallyResultRequest, QueryVoteRequest,
+            QueryVotesRequest,
         },
         response::{
             QueryAllParamsResponse, QueryDepositResponse, QueryParamsResponse,
             QueryProposalResponse, QueryProposalsResponse, QueryProposerResponse,
-            QueryTallyResultResponse, QueryVoteResponse, QueryVotesResponse,
+            QueryTallyBreakdownResponse, QueryTallyResultResponse, QueryVoteResponse,
+            QueryVotesResponse,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
 };
 
@@ -80,6 +82,28 @@ impl<T: Proposal> QueryHandler for GovClientHandler<T> {
             GovQueryCliCommands::Proposer { proposal_id } => {
                 Self::QueryRequest::Proposer(QueryProposerRequest { proposal_id })
             }
+            GovQueryCliCommands::ProposalV1 { proposal_id } => {
+                Self::QueryRequest::ProposalV1(v1::request::QueryProposalRequest { proposal_id })
+            }
+            GovQueryCliCommands::ProposalsV1 {
+                voter,
+                depositor,
+                status,
+            } => Self::QueryRequest::ProposalsV1(v1::request::QueryProposalsRequest {
+                voter,
+                depositor,
+                proposal_status: status,
+                pagination: None,
+            }),
+            GovQueryCliCommands::ParamsV1 { kind } => {
+                Self::QueryRequest::ParamsV1(v1::request::QueryParamsRequest { kind })
+            }
+            GovQueryCliCommands::TallyV1 { proposal_id } => {
+                Self::QueryRequest::TallyV1(v1::request::QueryTallyResultRequest { proposal_id })
+            }
+            GovQueryCliCommands::TallyBreakdown { proposal_id } => {
+                Self::QueryRequest::TallyBreakdown(QueryTallyBreakdownRequest { proposal_id })
+            }
         };
 
         Ok(result)
@@ -129,6 +153,27 @@ impl<T: Proposal> QueryHandler for GovClientHandler<T> {
             GovQueryCliCommands::Proposer { proposal_id: _ } => Self::QueryResponse::Proposer(
                 QueryProposerResponse::decode::<Bytes>(query_bytes.into())?,
             ),
+            GovQueryCliCommands::ProposalV1 { proposal_id: _ } => Self::QueryResponse::ProposalV1(
+                v1::response::QueryProposalResponse::decode::<Bytes>(query_bytes.into())?,
+            ),
+            GovQueryCliCommands::ProposalsV1 {
+                voter: _,
+                depositor: _,
+                status: _,
+            } => Self::QueryResponse::ProposalsV1(v1::response::QueryProposalsResponse::decode::<
+                Bytes,
+            >(query_bytes.into())?),
+            GovQueryCliCommands::ParamsV1 { kind: _ } => Self::QueryResponse::ParamsV1(
+                v1::response::QueryParamsResponse::decode::<Bytes>(query_bytes.into())?,
+            ),
+            GovQueryCliCommands::TallyV1 { proposal_id: _ } => Self::QueryResponse::TallyV1(
+                v1::response::QueryTallyResultResponse::decode::<Bytes>(query_bytes.into())?,
+            ),
+            GovQueryCliCommands::TallyBreakdown { proposal_id: _ } => {
+                Self::QueryResponse::TallyBreakdown(QueryTallyBreakdownResponse::decode::<Bytes>(
+                    query_bytes.into(),
+                )?)
+            }
         };
 
         Ok(result)
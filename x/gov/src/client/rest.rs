@@ -3,7 +3,8 @@ use crate::{
     query::{
         request::{
             ParamsQuery, QueryDepositsRequest, QueryParamsRequest, QueryProposalRequest,
-            QueryProposalsRequest, QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
+            QueryProposalsRequest, QueryTallyBreakdownRequest, QueryTallyResultRequest,
+            QueryVoteRequest, QueryVotesRequest,
         },
         GovQuery, GovQueryResponse,
     },
@@ -15,7 +16,7 @@ use axum::{
 };
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
+    rest::{error::HTTPError, BlockHeight, Pagination, RestState},
     types::{address::AccAddress, pagination::request::PaginationRequest},
 };
 
@@ -84,6 +85,21 @@ pub async fn proposals_tally<
     Ok(Json(res))
 }
 
+pub async fn proposals_tally_breakdown<
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
+    App: NodeQueryHandler<QReq, QRes>,
+    P: Proposal,
+>(
+    Path(proposal_id): Path<u64>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = GovQuery::TallyBreakdown(QueryTallyBreakdownRequest { proposal_id });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
 pub async fn proposals_votes<
     QReq: QueryRequest + From<GovQuery>,
     QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
@@ -181,6 +197,10 @@ pub fn get_router<
             "/v1beta1/proposals/:proposal_id/tally",
             get(proposals_tally),
         )
+        .route(
+            "/v1beta1/proposals/:proposal_id/tally_breakdown",
+            get(proposals_tally_breakdown::<QReq, QRes, App, P>),
+        )
         .route(
             "/v1beta1/proposals/:proposal_id/votes",
             get(proposals_votes),
@@ -2,17 +2,23 @@ use std::{fs::File, io::Read};
 
 use gears::{
     application::handlers::client::TxHandler, commands::client::tx::ClientTxContext,
-    crypto::public::PublicKey, types::tx::Messages,
+    core::any::google::Any, crypto::public::PublicKey, types::address::AccAddress,
+    types::tx::Messages, x::module::Module,
 };
+use serde::Deserialize;
 
 use crate::{
     client::cli::tx::{
         DepositCliCommand, GovTxCli, GovTxCommands, ParamChangeProposalCliCommand,
-        ProposalCliCommand, ProposalCliSubcommand, TextProposalCliCommand, VoteCliCommand,
-        WeightedVoteCliCommand,
+        ProposalCliCommand, ProposalCliSubcommand, ProposalV1CliCommand, TextProposalCliCommand,
+        VoteCliCommand, WeightedVoteCliCommand,
     },
     msg::{
-        deposit::Deposit, proposal::MsgSubmitProposal, vote::Vote, weighted_vote::MsgVoteWeighted,
+        deposit::Deposit,
+        proposal::MsgSubmitProposal,
+        v1::{self, exec_legacy_content::MsgExecLegacyContent},
+        vote::Vote,
+        weighted_vote::MsgVoteWeighted,
         GovMsg,
     },
     proposal::{
@@ -27,6 +33,26 @@ use super::{
     GovClientHandler,
 };
 
+/// Gov module which authority executes proposals. Client derives its address the same way as app
+#[derive(Debug, Clone)]
+struct GovModule;
+
+impl Module for GovModule {
+    fn name(&self) -> String {
+        "gov".to_owned()
+    }
+}
+
+/// Content of gov v1 proposal file
+#[derive(Debug, Deserialize)]
+struct ProposalV1File {
+    messages: Vec<Any>,
+    #[serde(default)]
+    metadata: String,
+    title: String,
+    summary: String,
+}
+
 impl<T> TxHandler for GovClientHandler<T> {
     type Message = GovMsg;
 
@@ -38,73 +64,105 @@ impl<T> TxHandler for GovClientHandler<T> {
         command: Self::TxCommands,
         pubkey: PublicKey,
     ) -> anyhow::Result<Messages<Self::Message>> {
-        let command = match command.command {
-            GovTxCommands::Deposit(DepositCliCommand {
-                proposal_id,
-                amount,
-            }) => GovMsg::Deposit(Deposit {
-                proposal_id,
-                depositor: pubkey.get_address(),
-                amount,
-            }),
-            GovTxCommands::Vote(VoteCliCommand {
-                proposal_id,
-                option,
-            }) => GovMsg::Vote(Vote {
-                proposal_id,
-                voter: pubkey.get_address(),
-                option,
-            }),
-            GovTxCommands::WeightedVote(WeightedVoteCliCommand {
-                proposal_id,
-                options,
-            }) => GovMsg::Weighted(MsgVoteWeighted {
-                proposal_id,
-                voter: pubkey.get_address(),
-                options,
-            }),
-            GovTxCommands::SubmitProposal(ProposalCliCommand {
-                initial_deposit,
-                command,
-            }) => match command {
+        run_gov_tx_command(command, pubkey.get_address()).map(Into::into)
+    }
+}
+
+pub fn run_gov_tx_command(args: GovTxCli, from_address: AccAddress) -> anyhow::Result<GovMsg> {
+    let msg = match args.command {
+        GovTxCommands::Deposit(DepositCliCommand {
+            proposal_id,
+            amount,
+        }) => GovMsg::Deposit(Deposit {
+            proposal_id,
+            depositor: from_address.clone(),
+            amount,
+        }),
+        GovTxCommands::Vote(VoteCliCommand {
+            proposal_id,
+            option,
+        }) => GovMsg::Vote(Vote {
+            proposal_id,
+            voter: from_address.clone(),
+            option,
+        }),
+        GovTxCommands::WeightedVote(WeightedVoteCliCommand {
+            proposal_id,
+            options,
+        }) => GovMsg::Weighted(MsgVoteWeighted {
+            proposal_id,
+            voter: from_address.clone(),
+            options,
+        }),
+        GovTxCommands::SubmitProposal(ProposalCliCommand {
+            initial_deposit,
+            expedited,
+            command,
+        }) => {
+            let content: Any = match command {
                 ProposalCliSubcommand::Text(TextProposalCliCommand { title, description }) => {
-                    GovMsg::Proposal(MsgSubmitProposal {
-                        content: TextProposal { title, description }.into(),
-                        initial_deposit,
-                        proposer: pubkey.get_address(),
-                    })
+                    TextProposal { title, description }.into()
                 }
                 ProposalCliSubcommand::ParamChange(ParamChangeProposalCliCommand { file }) => {
                     let mut buf = String::new();
                     File::open(file)?.read_to_string(&mut buf)?;
 
-                    let proposal = serde_json::from_str::<RawParameterChangeProposal>(&buf)?;
-
-                    GovMsg::Proposal(MsgSubmitProposal {
-                        content: proposal.into(),
-                        initial_deposit,
-                        proposer: pubkey.get_address(),
-                    })
+                    serde_json::from_str::<RawParameterChangeProposal>(&buf)?.into()
                 }
                 ProposalCliSubcommand::SoftwareUpgrade(SoftwareUpgradeProposalCliCommand {
                     file,
-                }) => GovMsg::Proposal(MsgSubmitProposal {
-                    content: serde_json::from_slice::<SoftwareUpgradeProposal>(&std::fs::read(
-                        file,
-                    )?)?
-                    .into(),
-                    initial_deposit,
-                    proposer: pubkey.get_address(),
-                }),
+                }) => {
+                    serde_json::from_slice::<SoftwareUpgradeProposal>(&std::fs::read(file)?)?.into()
+                }
                 ProposalCliSubcommand::CancelSoftwareUpgrade(
                     CancelSoftwareUpgradeProposalCliCommand { title, description },
-                ) => GovMsg::Proposal(MsgSubmitProposal {
-                    content: CancelSoftwareUpgradeProposal { title, description }.into(),
+                ) => CancelSoftwareUpgradeProposal { title, description }.into(),
+            };
+
+            match expedited {
+                // v1beta1 has no expedited proposals so legacy content is submitted via v1
+                true => GovMsg::ProposalV1(v1::proposal::MsgSubmitProposal {
+                    messages: vec![MsgExecLegacyContent {
+                        content,
+                        authority: GovModule.address(),
+                    }
+                    .into()],
                     initial_deposit,
-                    proposer: pubkey.get_address(),
+                    proposer: from_address.clone(),
+                    metadata: String::new(),
+                    title: String::new(),
+                    summary: String::new(),
+                    expedited,
                 }),
-            },
-        };
-        Ok(command.into())
-    }
+                false => GovMsg::Proposal(MsgSubmitProposal {
+                    content,
+                    initial_deposit,
+                    proposer: from_address.clone(),
+                }),
+            }
+        }
+        GovTxCommands::SubmitProposalV1(ProposalV1CliCommand {
+            initial_deposit,
+            file,
+            expedited,
+        }) => {
+            let ProposalV1File {
+                messages,
+                metadata,
+                title,
+                summary,
+            } = serde_json::from_slice(&std::fs::read(file)?)?;
+
+            GovMsg::ProposalV1(v1::proposal::MsgSubmitProposal {
+                messages,
+                initial_deposit,
+                proposer: from_address.clone(),
+                metadata,
+                title,
+                summary,
+                expedited,
+            })
+        }
+    };
+    Ok(msg)
 }
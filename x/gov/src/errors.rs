@@ -1,5 +1,6 @@
 use gears::{
     application::handlers::node::{ModuleInfo, TxError},
+    baseapp::errors::QueryError,
     gas::store::errors::GasStoreErrors,
     types::base::errors::CoinsError,
     x::errors::BankKeeperError,
@@ -7,6 +8,8 @@ use gears::{
 
 pub const SERDE_JSON_CONVERSION: &str = "conversion to json shouldn't fail";
 pub const EXISTS: &str = "value guaranteed to exists";
+/// Max length of gov v1 proposal metadata, same as cosmos sdk default
+pub const MAX_METADATA_LEN: usize = 255;
 
 #[derive(thiserror::Error, Debug)]
 pub enum GovTxError {
@@ -28,10 +31,24 @@ pub enum TallyError {
     Math(String),
 }
 
+impl From<TallyError> for QueryError {
+    fn from(value: TallyError) -> Self {
+        Self::TODO(anyhow::anyhow!(value.to_string()))
+    }
+}
+
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum GovKeeperError {
     #[error("gov: no handler exists for proposal type")]
     NoHandler,
+    #[error("gov: expected gov account {expected} as only signer for proposal message {msg}")]
+    InvalidSigner { expected: String, msg: String },
+    #[error("gov: {0} is not a legacy content")]
+    NotLegacyContent(String),
+    #[error("gov: legacy content {0} must be wrapped into MsgExecLegacyContent")]
+    UnwrappedLegacyContent(String),
+    #[error("gov: metadata too long: {0} > {MAX_METADATA_LEN}")]
+    MetadataTooLong(usize),
     #[error("{0}")]
     Bank(#[from] BankKeeperError),
     #[error("{0}")]
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, marker::PhantomData, ops::Mul};
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+    ops::Mul,
+};
 
 use gears::core::errors::CoreError;
 use gears::extensions::gas::GasResultExt;
@@ -16,6 +20,7 @@ use gears::{
     types::{
         address::{AccAddress, ValAddress},
         decimal256::Decimal256,
+        uint::Uint256,
     },
     x::{
         keepers::{gov::GovernanceBankKeeper, staking::GovStakingKeeper},
@@ -23,33 +28,43 @@ use gears::{
         types::{delegation::StakingDelegation, validator::StakingValidator},
     },
 };
+use gears::{
+    core::any::google::Any,
+    types::{base::coins::UnsignedCoins, tx::TxMessage},
+};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use strum::IntoEnumIterator;
 
 use crate::proposal::{Proposal, ProposalHandler};
 use crate::{
-    errors::{GovKeeperError, TallyError, SERDE_JSON_CONVERSION},
+    errors::{GovKeeperError, TallyError, MAX_METADATA_LEN, SERDE_JSON_CONVERSION},
     genesis::GovGenesisState,
     msg::{
         deposit::Deposit,
         proposal::MsgSubmitProposal,
+        v1::{
+            exec_legacy_content::MsgExecLegacyContent,
+            proposal::MsgSubmitProposal as MsgSubmitProposalV1,
+        },
         vote::VoteOption,
         weighted_vote::{MsgVoteWeighted, VoteOptionWeighted},
     },
-    params::GovParamsKeeper,
+    params::{GovParams, GovParamsKeeper},
     query::{
         request::{
             ParamsQuery, QueryDepositRequest, QueryDepositsRequest, QueryParamsRequest,
             QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest,
-            QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
+            QueryTallyBreakdownRequest, QueryTallyResultRequest, QueryVoteRequest,
+            QueryVotesRequest,
         },
         response::{
             QueryAllParamsResponse, QueryDepositResponse, QueryDepositsResponse,
             QueryParamsResponse, QueryProposalResponse, QueryProposalsResponse,
-            QueryTallyResultResponse, QueryVoteResponse, QueryVotesResponse,
+            QueryTallyBreakdownResponse, QueryTallyResultResponse, QueryVoteResponse,
+            QueryVotesResponse,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
     types::{
         deposit_iter::DepositIterator,
@@ -57,6 +72,7 @@ use crate::{
             active_iter::ActiveProposalIterator, inactive_iter::InactiveProposalIterator,
             ProposalModel, ProposalStatus, ProposalsIterator, TallyResult,
         },
+        tally::{DelegatorOverride, ValidatorTallyBreakdown},
         validator::ValidatorGovInfo,
         vote_iters::WeightedVoteIterator,
     },
@@ -179,7 +195,10 @@ impl<
                     ProposalStatus::VotingPeriod => store_mut.set(
                         ProposalModel::<P>::active_queue_key(
                             proposal.proposal_id,
-                            &proposal.deposit_end_time,
+                            proposal
+                                .voting_end_time
+                                .as_ref()
+                                .expect("proposal in voting period has voting end time"),
                         ),
                         proposal.proposal_id.to_be_bytes(),
                     ),
@@ -221,7 +240,7 @@ impl<
         &self,
         ctx: &CTX,
         query: GovQuery,
-    ) -> Result<GovQueryResponse<P>, GasStoreErrors> {
+    ) -> Result<GovQueryResponse<P>, TallyError> {
         let result = match query {
             GovQuery::Deposit(QueryDepositRequest {
                 proposal_id,
@@ -247,25 +266,7 @@ impl<
             GovQuery::Params(QueryParamsRequest { kind }) => {
                 let params = self.gov_params_keeper.try_get(ctx)?;
 
-                let result = match kind {
-                    ParamsQuery::Voting => QueryParamsResponse {
-                        voting_params: Some(params.voting),
-                        deposit_params: None,
-                        tally_params: None,
-                    },
-                    ParamsQuery::Deposit => QueryParamsResponse {
-                        voting_params: None,
-                        deposit_params: Some(params.deposit),
-                        tally_params: None,
-                    },
-                    ParamsQuery::Tally => QueryParamsResponse {
-                        voting_params: None,
-                        deposit_params: None,
-                        tally_params: Some(params.tally),
-                    },
-                };
-
-                GovQueryResponse::Params(result)
+                GovQueryResponse::Params(params_by_kind(params, kind))
             }
             GovQuery::AllParams(_) => {
                 let params = self.gov_params_keeper.try_get(ctx)?;
@@ -286,42 +287,10 @@ impl<
                 depositor,
                 proposal_status,
                 pagination: _,
-            }) => {
-                let iterator = ProposalsIterator::new(ctx.kv_store(&self.store_key))
-                    .map(|this| this.map(|(_key, value)| value))
-                    .filter_map(|this| this.ok());
-
-                let mut proposals = Vec::new();
-                for proposal in iterator {
-                    if let Some(voter) = &voter {
-                        let vote = vote_get(ctx, &self.store_key, proposal.proposal_id, voter)?;
-                        if vote.is_none() {
-                            continue;
-                        }
-                    }
-
-                    if let Some(depositor) = &depositor {
-                        let deposit =
-                            deposit_get(ctx, &self.store_key, proposal.proposal_id, depositor)?;
-                        if deposit.is_none() {
-                            continue;
-                        }
-                    }
-
-                    if let Some(proposal_status) = proposal_status {
-                        if proposal.status != proposal_status {
-                            continue;
-                        }
-                    }
-
-                    proposals.push(proposal);
-                }
-
-                GovQueryResponse::Proposals(QueryProposalsResponse {
-                    proposals,
-                    pagination: None,
-                })
-            }
+            }) => GovQueryResponse::Proposals(QueryProposalsResponse {
+                proposals: self.proposals_filtered(ctx, voter, depositor, proposal_status)?,
+                pagination: None,
+            }),
             GovQuery::Tally(QueryTallyResultRequest { proposal_id }) => {
                 let proposal = proposal_get::<_, _, _, P>(ctx, &self.store_key, proposal_id)?;
 
@@ -349,11 +318,260 @@ impl<
                 })
             }
             GovQuery::Proposer(QueryProposerRequest { proposal_id: _ }) => unimplemented!(), // TODO:NOW I couldn't find where this query handles or what method
+            GovQuery::ProposalV1(v1::request::QueryProposalRequest { proposal_id }) => {
+                GovQueryResponse::ProposalV1(v1::response::QueryProposalResponse {
+                    proposal: proposal_get::<_, _, _, P>(ctx, &self.store_key, proposal_id)?
+                        .map(|this| this.into_v1(&self.gov_mod.address())),
+                })
+            }
+            GovQuery::ProposalsV1(v1::request::QueryProposalsRequest {
+                voter,
+                depositor,
+                proposal_status,
+                pagination: _,
+            }) => {
+                let authority = self.gov_mod.address();
+
+                GovQueryResponse::ProposalsV1(v1::response::QueryProposalsResponse {
+                    proposals: self
+                        .proposals_filtered(ctx, voter, depositor, proposal_status)?
+                        .into_iter()
+                        .map(|this| this.into_v1(&authority))
+                        .collect(),
+                    pagination: None,
+                })
+            }
+            GovQuery::ParamsV1(v1::request::QueryParamsRequest { kind }) => {
+                let params = self.gov_params_keeper.try_get(ctx)?;
+                let QueryParamsResponse {
+                    voting_params,
+                    deposit_params,
+                    tally_params,
+                } = params_by_kind(params.clone(), kind);
+
+                GovQueryResponse::ParamsV1(v1::response::QueryParamsResponse {
+                    voting_params,
+                    deposit_params,
+                    tally_params,
+                    params: Some(params),
+                })
+            }
+            GovQuery::TallyV1(v1::request::QueryTallyResultRequest { proposal_id }) => {
+                let proposal = proposal_get::<_, _, _, P>(ctx, &self.store_key, proposal_id)?;
+
+                GovQueryResponse::TallyV1(v1::response::QueryTallyResultResponse {
+                    tally: proposal.and_then(|this| this.final_tally_result),
+                })
+            }
+            GovQuery::TallyBreakdown(QueryTallyBreakdownRequest { proposal_id }) => {
+                let VotesCount {
+                    validators,
+                    tally_results,
+                    total_voting_power: _,
+                    voters: _,
+                } = self.votes_count(ctx, proposal_id)?;
+
+                GovQueryResponse::TallyBreakdown(QueryTallyBreakdownResponse {
+                    tally: tally_results.into_result(),
+                    validators: validators
+                        .into_iter()
+                        .map(
+                            |ValidatorGovInfo {
+                                 address,
+                                 bounded_tokens,
+                                 delegator_shares,
+                                 delegator_deduction,
+                                 vote,
+                                 delegator_overrides,
+                             }| {
+                                Ok(ValidatorTallyBreakdown {
+                                    validator: address,
+                                    voting_power: validator_voting_power(
+                                        bounded_tokens,
+                                        delegator_shares,
+                                        delegator_deduction,
+                                    )?,
+                                    vote,
+                                    delegator_deduction,
+                                    delegator_overrides,
+                                })
+                            },
+                        )
+                        .collect::<Result<Vec<_>, TallyError>>()?,
+                })
+            }
         };
 
         Ok(result)
     }
 
+    /// Counts stored votes of proposal. Delegator which voted on its own overrides the vote of
+    /// its validator, so delegated shares are deducted from the validator voting power.
+    fn votes_count<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        proposal_id: u64,
+    ) -> Result<VotesCount, TallyError> {
+        // ordered by address, so the breakdown of validators is the same on every node
+        let mut curr_validators = BTreeMap::<ValAddress, ValidatorGovInfo>::new();
+
+        for validator in self.staking_keeper.bonded_validators_by_power_iter(ctx)? {
+            let validator = validator?;
+
+            curr_validators.insert(
+                validator.operator().clone(),
+                ValidatorGovInfo {
+                    address: validator.operator().clone(),
+                    bounded_tokens: validator.bonded_tokens(),
+                    delegator_shares: validator.delegator_shares(),
+                    delegator_deduction: Decimal256::zero(),
+                    vote: Vec::new(),
+                    delegator_overrides: Vec::new(),
+                },
+            );
+        }
+
+        let mut tally_results = TallyResultMap::new();
+        let mut total_voting_power = Decimal256::zero();
+        let mut voters = Vec::new();
+
+        for vote in WeightedVoteIterator::new(ctx.kv_store(&self.store_key), proposal_id)
+            .map(|this| this.map(|(_, value)| value))
+            .collect::<Vec<_>>()
+        {
+            let MsgVoteWeighted {
+                proposal_id: _,
+                voter,
+                options: vote_options,
+            } = vote?;
+
+            let val_addr = ValAddress::from(voter.clone());
+            if let Some(validator) = curr_validators.get_mut(&val_addr) {
+                validator.vote = vote_options.clone();
+            }
+
+            for delegation in self
+                .staking_keeper
+                .delegations_iter(ctx, &voter)
+                .collect::<Vec<_>>()
+            {
+                let delegation = delegation?;
+
+                if let Some(validator) = curr_validators.get_mut(delegation.validator()) {
+                    // from cosmos: https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/keeper/tally.go#L51
+                    // There is no need to handle the special case that validator address equal to voter address.
+                    // Because voter's voting power will tally again even if there will deduct voter's voting power from validator.
+
+                    validator.delegator_deduction = validator
+                        .delegator_deduction
+                        .checked_add(*delegation.shares())
+                        .map_err(|e| {
+                            TallyError::Math(format!("Delegator deduction overflow: {e}"))
+                        })?;
+
+                    // delegation shares * bonded / total shares
+                    let voting_power = delegation
+                        .shares()
+                        .mul(
+                            Decimal256::from_atomics(validator.bounded_tokens, 0).map_err(|e| {
+                                TallyError::Math(format!(
+                                    "Decimal overflow while calculating voting power: {e}"
+                                ))
+                            })?,
+                        )
+                        .checked_div(validator.delegator_shares)
+                        .map_err(|e| TallyError::Math(format!("Div on voting power: {e}")))?;
+
+                    for VoteOptionWeighted { option, weight } in &vote_options {
+                        let result_option = tally_results.get_mut(option);
+
+                        *result_option += voting_power * Decimal256::from(weight.clone());
+                    }
+
+                    total_voting_power += voting_power;
+
+                    validator.delegator_overrides.push(DelegatorOverride {
+                        delegator: voter.clone(),
+                        voting_power,
+                        vote: vote_options.clone(),
+                    });
+                }
+            }
+
+            voters.push(voter);
+        }
+
+        for ValidatorGovInfo {
+            address: _,
+            bounded_tokens,
+            delegator_shares,
+            delegator_deduction,
+            vote,
+            delegator_overrides: _,
+        } in curr_validators.values()
+        {
+            if vote.is_empty() {
+                continue;
+            }
+
+            let voting_power =
+                validator_voting_power(*bounded_tokens, *delegator_shares, *delegator_deduction)?;
+
+            for VoteOptionWeighted { option, weight } in vote {
+                let result = tally_results.get_mut(option);
+                *result += voting_power * Decimal256::from(weight.clone());
+            }
+
+            total_voting_power += voting_power;
+        }
+
+        Ok(VotesCount {
+            validators: curr_validators.into_values().collect(),
+            tally_results,
+            total_voting_power,
+            voters,
+        })
+    }
+
+    fn proposals_filtered<CTX: QueryableContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+        voter: Option<AccAddress>,
+        depositor: Option<AccAddress>,
+        proposal_status: Option<ProposalStatus>,
+    ) -> Result<Vec<ProposalModel<P>>, GasStoreErrors> {
+        let iterator = ProposalsIterator::new(ctx.kv_store(&self.store_key))
+            .map(|this| this.map(|(_key, value)| value))
+            .filter_map(|this| this.ok());
+
+        let mut proposals = Vec::new();
+        for proposal in iterator {
+            if let Some(voter) = &voter {
+                let vote = vote_get(ctx, &self.store_key, proposal.proposal_id, voter)?;
+                if vote.is_none() {
+                    continue;
+                }
+            }
+
+            if let Some(depositor) = &depositor {
+                let deposit = deposit_get(ctx, &self.store_key, proposal.proposal_id, depositor)?;
+                if deposit.is_none() {
+                    continue;
+                }
+            }
+
+            if let Some(proposal_status) = proposal_status {
+                if proposal.status != proposal_status {
+                    continue;
+                }
+            }
+
+            proposals.push(proposal);
+        }
+
+        Ok(proposals)
+    }
+
     pub fn deposit_add<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
@@ -379,13 +597,22 @@ impl<
         )?;
 
         proposal.total_deposit = proposal.total_deposit.checked_add(&amount)?;
-        proposal_set(ctx, &self.store_key, &proposal)?;
 
         let deposit_params = self.gov_params_keeper.try_get(ctx)?.deposit;
+        let min_deposit = match proposal.expedited {
+            true => deposit_params.expedited_min_deposit,
+            false => deposit_params.min_deposit,
+        };
 
         let activated_voting_period = matches!(proposal.status, ProposalStatus::DepositPeriod if proposal
                    .total_deposit
-                   .is_all_gte(Vec::from(deposit_params.min_deposit.clone()).iter()));
+                   .is_all_gte(min_deposit.inner()));
+
+        if activated_voting_period {
+            self.voting_period_activate(ctx, &mut proposal)?;
+        }
+
+        proposal_set(ctx, &self.store_key, &proposal)?;
 
         let deposit = match deposit_get(ctx, &self.store_key, proposal_id, &depositor)? {
             Some(mut deposit) => {
@@ -422,6 +649,39 @@ impl<
         Ok(activated_voting_period)
     }
 
+    fn voting_period_activate<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        proposal: &mut ProposalModel<P>,
+    ) -> Result<(), GovKeeperError> {
+        let voting_params = self.gov_params_keeper.try_get(ctx)?.voting;
+        let voting_period = match proposal.expedited {
+            true => voting_params.expedited_voting_period,
+            false => voting_params.voting_period,
+        };
+
+        let voting_start_time = ctx.header().time;
+        let voting_end_time = voting_start_time
+            .checked_add(voting_period)
+            .ok_or(GovKeeperError::Time("Voting end time overflow".to_owned()))?;
+
+        proposal.status = ProposalStatus::VotingPeriod;
+        proposal.voting_start_time = Some(voting_start_time);
+        proposal.voting_end_time = Some(voting_end_time);
+
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&ProposalModel::<P>::inactive_queue_key(
+            proposal.proposal_id,
+            &proposal.deposit_end_time,
+        ))?;
+        store.set(
+            ProposalModel::<P>::active_queue_key(proposal.proposal_id, &voting_end_time),
+            proposal.proposal_id.to_be_bytes(),
+        )?;
+
+        Ok(())
+    }
+
     pub fn vote_add<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
@@ -460,9 +720,129 @@ impl<
         MsgSubmitProposal {
             content,
             initial_deposit,
-            proposer: _proposer,
+            proposer,
         }: MsgSubmitProposal,
     ) -> Result<u64, GovKeeperError> {
+        let content = proposal_decode::<P>(content)?;
+        if !content.is_legacy_content() {
+            return Err(GovKeeperError::NotLegacyContent(
+                content.type_url().to_owned(),
+            ));
+        }
+
+        self.proposal_store(
+            ctx,
+            ProposalInfo {
+                messages: vec![content],
+                initial_deposit,
+                proposer,
+                metadata: String::new(),
+                title: String::new(),
+                summary: String::new(),
+                expedited: false,
+            },
+        )
+    }
+
+    /// Submit gov v1 proposal. Every message must be signed by the gov module account only,
+    /// legacy contents must be wrapped into `MsgExecLegacyContent` with the gov authority.
+    pub fn submit_proposal_v1<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        MsgSubmitProposalV1 {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }: MsgSubmitProposalV1,
+    ) -> Result<u64, GovKeeperError> {
+        if metadata.len() > MAX_METADATA_LEN {
+            return Err(GovKeeperError::MetadataTooLong(metadata.len()));
+        }
+
+        let authority = self.gov_mod.address();
+        let invalid_signer = |msg: &str| GovKeeperError::InvalidSigner {
+            expected: authority.to_string(),
+            msg: msg.to_owned(),
+        };
+
+        let mut proposal_msgs = Vec::with_capacity(messages.len());
+        for msg in messages {
+            let msg = match msg.type_url.as_str() {
+                MsgExecLegacyContent::TYPE_URL => {
+                    let MsgExecLegacyContent {
+                        content,
+                        authority: signer,
+                    } = msg
+                        .try_into()
+                        .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?;
+                    if signer != authority {
+                        return Err(invalid_signer(MsgExecLegacyContent::TYPE_URL));
+                    }
+
+                    let content = proposal_decode::<P>(content)?;
+                    if !content.is_legacy_content() {
+                        return Err(GovKeeperError::NotLegacyContent(
+                            content.type_url().to_owned(),
+                        ));
+                    }
+
+                    content
+                }
+                _ => {
+                    let msg = proposal_decode::<P>(msg)?;
+                    if msg.is_legacy_content() {
+                        return Err(GovKeeperError::UnwrappedLegacyContent(
+                            msg.type_url().to_owned(),
+                        ));
+                    }
+
+                    let signers = msg.get_signers();
+                    if signers.is_empty() || signers.into_iter().any(|this| *this != authority) {
+                        return Err(invalid_signer(msg.type_url()));
+                    }
+
+                    msg
+                }
+            };
+
+            proposal_msgs.push(msg);
+        }
+
+        self.proposal_store(
+            ctx,
+            ProposalInfo {
+                messages: proposal_msgs,
+                initial_deposit,
+                proposer,
+                metadata,
+                title,
+                summary,
+                expedited,
+            },
+        )
+    }
+
+    fn proposal_store<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        ProposalInfo {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }: ProposalInfo<P>,
+    ) -> Result<u64, GovKeeperError> {
+        if !messages.iter().all(PH::check) {
+            return Err(GovKeeperError::NoHandler);
+        }
+
         let proposal_id = proposal_id_get(ctx, &self.store_key)?;
         let submit_time = ctx.header().time;
         let deposit_period = self
@@ -473,9 +853,7 @@ impl<
 
         let proposal = ProposalModel {
             proposal_id,
-            content: content
-                .try_into()
-                .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?, // TODO: Better way. Generic or smth else
+            messages,
             status: ProposalStatus::DepositPeriod,
             final_tally_result: None,
             submit_time,
@@ -485,12 +863,13 @@ impl<
             total_deposit: initial_deposit,
             voting_start_time: None,
             voting_end_time: None,
+            metadata,
+            title,
+            summary,
+            proposer: Some(proposer),
+            expedited,
         };
 
-        if !PH::check(&proposal.content) {
-            return Err(GovKeeperError::NoHandler);
-        }
-
         proposal_set(ctx, &self.store_key, &proposal)?;
         let mut store = ctx.kv_store_mut(&self.store_key);
 
@@ -557,61 +936,128 @@ impl<
         {
             let active_iter = {
                 let store = ctx.kv_store(&self.store_key).into();
-                ActiveProposalIterator::new(store, &ctx.header.time)
-                    .map(|this| this.map(|((_, _), proposal)| proposal))
+                ActiveProposalIterator::<'_, _, P>::new(store, &ctx.header.time)
+                    .map(|this| this.map(|((proposal_id, _), _)| proposal_id))
                     .collect::<Vec<_>>()
             };
 
-            for proposal in active_iter {
-                let mut proposal: ProposalModel<P> = proposal.unwrap_gas();
+            for var in active_iter {
+                let proposal_id = var.unwrap_gas();
+                let Some(mut proposal) =
+                    proposal_get::<_, _, _, P>(ctx, &self.store_key, proposal_id).unwrap_gas()
+                else {
+                    continue;
+                };
 
-                let (passes, burn_deposit, tally_result) =
-                    match self.tally(ctx, proposal.proposal_id) {
-                        Ok(var) => var,
-                        Err(err) => match err {
-                            TallyError::Gas(_) => unreachable!("block ctx doesn't have any gas"),
-                            TallyError::Math(e) => panic!("Failed to get tally: {e}"),
-                        },
-                    };
+                let (passes, burn_deposit, tally_result) = match self.tally(ctx, &proposal) {
+                    Ok(var) => var,
+                    Err(err) => match err {
+                        TallyError::Gas(_) => unreachable!("block ctx doesn't have any gas"),
+                        TallyError::Math(e) => panic!("Failed to get tally: {e}"),
+                    },
+                };
 
-                if burn_deposit {
-                    deposit_del(ctx, self, proposal.proposal_id).unwrap_gas();
-                } else {
-                    deposit_refund(ctx, self).unwrap_gas();
-                }
+                // Failed expedited proposal is converted to a regular one which voting ends
+                // after the regular voting period, its deposits are kept until then. Like in
+                // cosmos sdk the tally has already deleted its votes, so the regular voting
+                // period starts without votes and voters have to vote again.
+                let regular_voting_end_time = match proposal.expedited && !passes {
+                    true => {
+                        let voting_period = self.gov_params_keeper.get(ctx).voting.voting_period;
+                        let voting_end_time = proposal
+                            .voting_start_time
+                            .expect("proposal in voting period has voting start time")
+                            .checked_add(voting_period);
+                        if voting_end_time.is_none() {
+                            tracing::error!(
+                                "proposal {}: regular voting end time overflows, the expedited proposal is rejected",
+                                proposal.proposal_id
+                            );
+                        }
 
-                match passes {
-                    true if self
-                        .proposal_handler
-                        .handle(proposal.content.clone(), ctx)
-                        .is_ok() =>
-                    {
-                        proposal.status = ProposalStatus::Passed
+                        voting_end_time
+                    }
+                    false => None,
+                };
+                if regular_voting_end_time.is_none() {
+                    if burn_deposit {
+                        deposit_del(ctx, self, proposal.proposal_id).unwrap_gas();
+                    } else {
+                        deposit_refund(ctx, self).unwrap_gas();
                     }
-                    true => proposal.status = ProposalStatus::Failed,
-                    false => proposal.status = ProposalStatus::Rejected,
                 }
 
-                proposal.final_tally_result = Some(tally_result);
-
-                proposal_set(ctx, &self.store_key, &proposal).unwrap_gas();
+                let voting_end_time = proposal
+                    .voting_end_time
+                    .expect("proposal in voting period has voting end time");
                 ctx.kv_store_mut(&self.store_key)
                     .delete(&ProposalModel::<P>::active_queue_key(
                         proposal.proposal_id,
-                        &proposal.deposit_end_time,
+                        &voting_end_time,
                     ));
 
-                // TODO: HOOKS https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/abci.go#L97
+                let proposal_result = if let Some(voting_end_time) = regular_voting_end_time {
+                    proposal.expedited = false;
+                    proposal.voting_end_time = Some(voting_end_time);
+                    ctx.kv_store_mut(&self.store_key).set(
+                        ProposalModel::<P>::active_queue_key(
+                            proposal.proposal_id,
+                            &voting_end_time,
+                        ),
+                        proposal.proposal_id.to_be_bytes(),
+                    );
 
-                events.push(Event::new(
-                    "active_proposal",
-                    vec![
-                        EventAttribute::new(
+                    "expedited_proposal_rejected"
+                } else {
+                    // Messages are executed in order on a branch of the block state and the first
+                    // failure fails the whole proposal, state changes are kept only if all succeed
+                    match passes {
+                        true => match ctx.branched(|ctx| {
+                            proposal
+                                .messages
+                                .iter()
+                                .cloned()
+                                .try_for_each(|msg| self.proposal_handler.handle(msg, ctx))
+                        }) {
+                            Ok(()) => {
+                                proposal.status = ProposalStatus::Passed;
+                                "proposal_passed"
+                            }
+                            Err(err) => {
+                                tracing::info!(
+                                    "proposal {} failed on execution: {err}",
+                                    proposal.proposal_id
+                                );
+                                proposal.status = ProposalStatus::Failed;
+                                "proposal_failed"
+                            }
+                        },
+                        false => {
+                            proposal.status = ProposalStatus::Rejected;
+                            "proposal_rejected"
+                        }
+                    }
+                };
+
+                proposal.final_tally_result = Some(tally_result);
+
+                proposal_set(ctx, &self.store_key, &proposal).unwrap_gas();
+
+                // TODO: HOOKS https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/abci.go#L97
+
+                events.push(Event::new(
+                    "active_proposal",
+                    vec![
+                        EventAttribute::new(
                             "proposal_id".into(),
                             proposal.proposal_id.to_string().into(),
                             false,
                         ),
-                        EventAttribute::new("proposal_result".into(), "TODO".into(), false),
+                        EventAttribute::new(
+                            "proposal_result".into(),
+                            proposal_result.into(),
+                            false,
+                        ),
                     ],
                 ))
             }
@@ -620,113 +1066,24 @@ impl<
         events
     }
 
+    /// Tally votes of proposal. Returns whether the proposal passes, whether deposits should
+    /// be burned and the tally result. Expedited proposal needs `expedited_threshold` to pass.
     fn tally<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
-        proposal_id: u64,
+        proposal: &ProposalModel<P>,
     ) -> Result<(bool, bool, TallyResult), TallyError> {
-        let mut curr_validators = HashMap::<ValAddress, ValidatorGovInfo>::new();
-
-        for validator in self.staking_keeper.bonded_validators_by_power_iter(ctx)? {
-            let validator = validator?;
-
-            curr_validators.insert(
-                validator.operator().clone(),
-                ValidatorGovInfo {
-                    address: validator.operator().clone(),
-                    bounded_tokens: validator.bonded_tokens(),
-                    delegator_shares: validator.delegator_shares(),
-                    delegator_deduction: Decimal256::zero(),
-                    vote: Vec::new(),
-                },
-            );
-        }
-
-        let mut tally_results = TallyResultMap::new();
-        let mut total_voting_power = Decimal256::zero();
-
-        for vote in WeightedVoteIterator::new(ctx.kv_store(&self.store_key), proposal_id)
-            .map(|this| this.map(|(_, value)| value))
-            .collect::<Vec<_>>()
-        {
-            let MsgVoteWeighted {
-                proposal_id: _,
-                voter,
-                options: vote_options,
-            } = vote?;
-
-            let val_addr = ValAddress::from(voter.clone());
-            if let Some(validator) = curr_validators.get_mut(&val_addr) {
-                validator.vote = vote_options.clone();
-            }
-
-            for delegation in self
-                .staking_keeper
-                .delegations_iter(ctx, &voter)
-                .collect::<Vec<_>>()
-            {
-                let delegation = delegation?;
-
-                if let Some(validator) = curr_validators.get_mut(delegation.validator()) {
-                    // from cosmos: https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/keeper/tally.go#L51
-                    // There is no need to handle the special case that validator address equal to voter address.
-                    // Because voter's voting power will tally again even if there will deduct voter's voting power from validator.
-
-                    validator.delegator_deduction = validator
-                        .delegator_deduction
-                        .checked_add(*delegation.shares())
-                        .map_err(|e| {
-                            TallyError::Math(format!("Delegator deduction overflow: {e}"))
-                        })?;
-
-                    // delegation shares * bonded / total shares
-                    let voting_power = delegation
-                        .shares()
-                        .mul(
-                            Decimal256::from_atomics(validator.bounded_tokens, 0).map_err(|e| {
-                                TallyError::Math(format!(
-                                    "Decimal overflow while calculating voting power: {e}"
-                                ))
-                            })?,
-                        )
-                        .checked_div(validator.delegator_shares)
-                        .map_err(|e| TallyError::Math(format!("Div on voting power: {e}")))?;
-
-                    for VoteOptionWeighted { option, weight } in &vote_options {
-                        let result_option = tally_results.get_mut(option);
-
-                        *result_option += voting_power * Decimal256::from(weight.clone());
-                    }
-
-                    total_voting_power += voting_power;
-                }
-
-                vote_del(ctx, &self.store_key, proposal_id, &voter)?;
-            }
-        }
-
-        for ValidatorGovInfo {
-            address: _,
-            bounded_tokens,
-            delegator_shares,
-            delegator_deduction,
-            vote,
-        } in curr_validators.values()
-        {
-            if vote.is_empty() {
-                continue;
-            }
-
-            let voting_power = (delegator_shares - delegator_deduction)
-                * Decimal256::from_atomics(*bounded_tokens, 0).unwrap() // TODO: HANDLE THIS
-                / delegator_shares;
-
-            for VoteOptionWeighted { option, weight } in vote {
-                let result = tally_results.get_mut(option);
-                *result += voting_power * Decimal256::from(weight.clone());
-            }
-
-            total_voting_power += voting_power;
+        let VotesCount {
+            validators: _,
+            mut tally_results,
+            total_voting_power,
+            voters,
+        } = self.votes_count(ctx, proposal.proposal_id)?;
+
+        // Vote is deleted once per voter like in cosmos, also for voters without delegations
+        // whose votes were kept before when deleted per delegation
+        for voter in voters {
+            vote_del(ctx, &self.store_key, proposal.proposal_id, &voter)?;
         }
 
         let tally_params = self.gov_params_keeper.try_get(ctx)?.tally;
@@ -739,8 +1096,15 @@ impl<
         }
 
         // If there is not enough quorum of votes, the proposal fails
-        let percent_voting =
-            total_voting_power / Decimal256::from_atomics(total_bonded_tokens.amount, 0).unwrap(); // TODO: HANDLE THIS
+        let percent_voting = total_voting_power
+            .checked_div(
+                Decimal256::from_atomics(total_bonded_tokens.amount, 0).map_err(|e| {
+                    TallyError::Math(format!(
+                        "Decimal overflow while calculating total bonded tokens: {e}"
+                    ))
+                })?,
+            )
+            .map_err(|e| TallyError::Math(format!("Div on percent voting: {e}")))?;
         if percent_voting < tally_params.quorum {
             return Ok((false, true, tally_results.into_result()));
         }
@@ -758,10 +1122,15 @@ impl<
             return Ok((false, true, tally_results.into_result()));
         }
 
-        // If more than 1/2 of non-abstaining voters vote Yes, proposal passes
+        let threshold = match proposal.expedited {
+            true => tally_params.expedited_threshold,
+            false => tally_params.threshold,
+        };
+
+        // If more than threshold of non-abstaining voters vote Yes, proposal passes
         if *tally_results.get_mut(&VoteOption::Yes)
             / (total_voting_power - *tally_results.get_mut(&VoteOption::Abstain))
-            > tally_params.threshold
+            > threshold
         {
             return Ok((true, false, tally_results.into_result()));
         }
@@ -771,6 +1140,79 @@ impl<
     }
 }
 
+fn params_by_kind(params: GovParams, kind: ParamsQuery) -> QueryParamsResponse {
+    match kind {
+        ParamsQuery::Voting => QueryParamsResponse {
+            voting_params: Some(params.voting),
+            deposit_params: None,
+            tally_params: None,
+        },
+        ParamsQuery::Deposit => QueryParamsResponse {
+            voting_params: None,
+            deposit_params: Some(params.deposit),
+            tally_params: None,
+        },
+        ParamsQuery::Tally => QueryParamsResponse {
+            voting_params: None,
+            deposit_params: None,
+            tally_params: Some(params.tally),
+        },
+    }
+}
+
+/// Votes of proposal counted by validators
+#[derive(Debug)]
+struct VotesCount {
+    validators: Vec<ValidatorGovInfo>,
+    tally_results: TallyResultMap,
+    total_voting_power: Decimal256,
+    voters: Vec<AccAddress>,
+}
+
+/// Voting power of validator which is left after delegators voted on their own:
+/// (delegator shares - deduction) * bonded / delegator shares. Validator without delegator
+/// shares has no voting power.
+fn validator_voting_power(
+    bounded_tokens: Uint256,
+    delegator_shares: Decimal256,
+    delegator_deduction: Decimal256,
+) -> Result<Decimal256, TallyError> {
+    if delegator_shares.is_zero() {
+        return Ok(Decimal256::zero());
+    }
+
+    let bounded_tokens = Decimal256::from_atomics(bounded_tokens, 0).map_err(|e| {
+        TallyError::Math(format!(
+            "Decimal overflow while calculating voting power: {e}"
+        ))
+    })?;
+
+    delegator_shares
+        .checked_sub(delegator_deduction)
+        .map_err(|e| TallyError::Math(format!("Delegator deduction underflow: {e}")))?
+        .checked_mul(bounded_tokens)
+        .map_err(|e| TallyError::Math(format!("Mul on voting power: {e}")))?
+        .checked_div(delegator_shares)
+        .map_err(|e| TallyError::Math(format!("Div on voting power: {e}")))
+}
+
+/// Fields of proposal which are common for gov v1beta1 and v1 submissions
+#[derive(Debug)]
+struct ProposalInfo<P> {
+    messages: Vec<P>,
+    initial_deposit: UnsignedCoins,
+    proposer: AccAddress,
+    metadata: String,
+    title: String,
+    summary: String,
+    expedited: bool,
+}
+
+fn proposal_decode<P: Proposal>(msg: Any) -> Result<P, GovKeeperError> {
+    msg.try_into()
+        .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))
+}
+
 #[derive(Debug, Clone)]
 struct TallyResultMap(HashMap<VoteOption, Decimal256>);
 
@@ -889,10 +1331,12 @@ fn proposal_del<
             &proposal.deposit_end_time,
         ))?;
 
-        store.delete(&ProposalModel::<P>::active_queue_key(
-            proposal_id,
-            &proposal.deposit_end_time,
-        ))?;
+        if let Some(voting_end_time) = &proposal.voting_end_time {
+            store.delete(&ProposalModel::<P>::active_queue_key(
+                proposal_id,
+                voting_end_time,
+            ))?;
+        }
 
         store.delete(&proposal.key())?;
 
@@ -1062,3 +1506,744 @@ fn vote_del<DB: Database, SK: StoreKey, CTX: TransactionalContext<DB, SK>>(
 
     Ok(is_deleted.is_some())
 }
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams, GasScheduleParams},
+        context::InfallibleContextMut,
+        derive::{ParamsKeys, StoreKeys},
+        error::NumericError,
+        extensions::testing::UnwrapTesting,
+        gas::metering::GasMeter,
+        store::database::MemDB,
+        tendermint::types::{
+            proto::{crypto::PublicKey, header::Header},
+            time::timestamp::Timestamp,
+        },
+        utils::node::{build_init_ctx, build_store},
+        x::{
+            keepers::mocks::{auth::MockAuthKeeper, bank::MockBankKeeper},
+            types::validator::BondStatus,
+        },
+    };
+    use serde::Deserialize;
+
+    use crate::{msg::weighted_vote::VoteWeight, proposal::ProposalHandlingError};
+
+    use super::*;
+
+    #[test]
+    fn submit_proposal_v1_checks_signers() {
+        let keeper = keeper(MockStakingKeeper::default());
+        let mut multi_store = build_store::<SubspaceKey>();
+        init_genesis(&keeper, &mut multi_store, GovGenesisState::default());
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasScheduleParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let submit = |ctx: &mut TxContext<'_, MemDB, SubspaceKey>, messages: Vec<Any>| {
+            keeper.submit_proposal_v1(
+                ctx,
+                MsgSubmitProposalV1 {
+                    messages,
+                    initial_deposit: UnsignedCoins::new(vec![]).unwrap_test(),
+                    proposer: address(1),
+                    metadata: String::new(),
+                    title: "title".to_owned(),
+                    summary: "summary".to_owned(),
+                    expedited: false,
+                },
+            )
+        };
+        let authority = GovModule.address();
+
+        let err = submit(&mut ctx, vec![TestProposal::new(address(1), "key").into()])
+            .expect_err("message must be signed by gov");
+        assert!(matches!(err, GovKeeperError::InvalidSigner { .. }));
+
+        let legacy = TestProposal {
+            legacy: true,
+            ..TestProposal::new(authority.clone(), "key")
+        };
+        let err = submit(&mut ctx, vec![legacy.clone().into()])
+            .expect_err("legacy content must be wrapped");
+        assert!(matches!(err, GovKeeperError::UnwrappedLegacyContent(_)));
+
+        let wrapped = |content: TestProposal, authority: AccAddress| -> Any {
+            MsgExecLegacyContent {
+                content: content.into(),
+                authority,
+            }
+            .into()
+        };
+        let err = submit(&mut ctx, vec![wrapped(legacy.clone(), address(1))])
+            .expect_err("legacy content must be executed by gov");
+        assert!(matches!(err, GovKeeperError::InvalidSigner { .. }));
+
+        let err = submit(
+            &mut ctx,
+            vec![wrapped(
+                TestProposal::new(authority.clone(), "key"),
+                authority.clone(),
+            )],
+        )
+        .expect_err("only legacy content could be wrapped");
+        assert!(matches!(err, GovKeeperError::NotLegacyContent(_)));
+
+        let proposal_id = submit(
+            &mut ctx,
+            vec![
+                TestProposal::new(authority.clone(), "key").into(),
+                wrapped(legacy, authority),
+            ],
+        )
+        .unwrap_test();
+        let proposal = proposal_get::<_, _, _, TestProposal>(&ctx, &SubspaceKey::Gov, proposal_id)
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(proposal.messages.len(), 2);
+        assert_eq!(proposal.status, ProposalStatus::DepositPeriod);
+    }
+
+    #[test]
+    fn passed_proposal_executes_messages() {
+        let (proposal, multi_store) = execute(vec![
+            TestProposal::new(GovModule.address(), "first"),
+            TestProposal::new(GovModule.address(), "second"),
+        ]);
+
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        let store = multi_store.kv_store(&SubspaceKey::Executed);
+        assert_eq!(store.get(b"first"), Some(vec![1]));
+        assert_eq!(store.get(b"second"), Some(vec![1]));
+    }
+
+    #[test]
+    fn failed_proposal_reverts_executed_messages() {
+        let (proposal, multi_store) = execute(vec![
+            TestProposal::new(GovModule.address(), "first"),
+            TestProposal {
+                fail: true,
+                ..TestProposal::new(GovModule.address(), "second")
+            },
+        ]);
+
+        assert_eq!(proposal.status, ProposalStatus::Failed);
+        let store = multi_store.kv_store(&SubspaceKey::Executed);
+        assert_eq!(store.get(b"first"), None);
+        assert_eq!(store.get(b"second"), None);
+    }
+
+    #[test]
+    fn tally_passes_over_threshold() {
+        let (proposal, _, _) = end_block(
+            voting_proposal(1, vec![]),
+            staking(&[(1, 60), (2, 40)]),
+            vec![vote(1, VoteOption::Yes), vote(2, VoteOption::No)],
+        );
+
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert_eq!(
+            proposal.final_tally_result,
+            Some(TallyResult {
+                yes: Uint256::from(60u32),
+                abstain: Uint256::zero(),
+                no: Uint256::from(40u32),
+                no_with_veto: Uint256::zero(),
+            })
+        );
+    }
+
+    #[test]
+    fn tally_rejects_without_quorum_or_with_veto() {
+        // 30 of 100 bonded tokens voted
+        let (proposal, _, _) = end_block(
+            voting_proposal(1, vec![]),
+            staking(&[(1, 30), (2, 70)]),
+            vec![vote(1, VoteOption::Yes)],
+        );
+        assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+        let (proposal, _, _) = end_block(
+            voting_proposal(1, vec![]),
+            staking(&[(1, 60), (2, 40)]),
+            vec![vote(1, VoteOption::Yes), vote(2, VoteOption::NoWithVeto)],
+        );
+        assert_eq!(proposal.status, ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn expedited_proposal_passes_over_expedited_threshold() {
+        let (proposal, _, _) = end_block(
+            ProposalModel {
+                expedited: true,
+                ..voting_proposal(1, vec![])
+            },
+            staking(&[(1, 70), (2, 30)]),
+            vec![vote(1, VoteOption::Yes), vote(2, VoteOption::No)],
+        );
+
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert!(proposal.expedited);
+    }
+
+    #[test]
+    fn rejected_expedited_proposal_is_converted_to_regular() {
+        // 60% passes the regular threshold but not the expedited one
+        let (proposal, events, multi_store) = end_block(
+            ProposalModel {
+                expedited: true,
+                ..voting_proposal(1, vec![])
+            },
+            staking(&[(1, 60), (2, 40)]),
+            vec![vote(1, VoteOption::Yes), vote(2, VoteOption::No)],
+        );
+
+        let voting_end_time = Timestamp::UNIX_EPOCH
+            .checked_add(GovParams::default().voting.voting_period)
+            .unwrap_test();
+        assert_eq!(proposal.status, ProposalStatus::VotingPeriod);
+        assert!(!proposal.expedited);
+        assert_eq!(proposal.voting_end_time, Some(voting_end_time));
+        assert!(proposal.final_tally_result.is_some());
+        assert_eq!(
+            events[0].attributes[1].value,
+            "expedited_proposal_rejected".as_bytes()
+        );
+
+        // proposal is queued until the end of the regular voting period
+        let store = multi_store.kv_store(&SubspaceKey::Gov);
+        assert_eq!(
+            store.get(&ProposalModel::<TestProposal>::active_queue_key(
+                1,
+                &Timestamp::try_new(VOTING_END, 0).unwrap_test()
+            )),
+            None
+        );
+        assert!(store
+            .get(&ProposalModel::<TestProposal>::active_queue_key(
+                1,
+                &voting_end_time
+            ))
+            .is_some());
+
+        // votes of the expedited period don't count in the regular one
+        for voter in [address(1), address(2)] {
+            let key = [
+                MsgVoteWeighted::KEY_PREFIX.as_slice(),
+                &1_u64.to_be_bytes(),
+                &[voter.len()],
+                voter.as_ref(),
+            ]
+            .concat();
+            assert_eq!(store.get(&key), None);
+        }
+    }
+
+    #[test]
+    fn tally_breakdown_matches_tally() {
+        // delegator 3 overrides the vote of validator 1 with 30 of its 100 shares, delegator 4
+        // of validator 2 doesn't vote
+        let shares = |amount: u32| Decimal256::from_atomics(amount, 0).unwrap_test();
+        let keeper = keeper(MockStakingKeeper {
+            delegations: vec![
+                MockDelegation {
+                    delegator: address(3),
+                    validator: ValAddress::from(address(1)),
+                    shares: shares(30),
+                },
+                MockDelegation {
+                    delegator: address(4),
+                    validator: ValAddress::from(address(2)),
+                    shares: shares(10),
+                },
+            ],
+            ..staking(&[(1, 100), (2, 50)])
+        });
+        let mut multi_store = build_store::<SubspaceKey>();
+        init_genesis(
+            &keeper,
+            &mut multi_store,
+            GovGenesisState {
+                votes: vec![
+                    vote(1, VoteOption::Yes),
+                    vote(2, VoteOption::No),
+                    vote(3, VoteOption::No),
+                ],
+                proposals: vec![voting_proposal(1, vec![])],
+                ..GovGenesisState::default()
+            },
+        );
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            2,
+            Header::default(),
+            ConsensusParams::default(),
+            Default::default(),
+        );
+
+        let GovQueryResponse::TallyBreakdown(QueryTallyBreakdownResponse { tally, validators }) =
+            keeper
+                .query(
+                    &ctx,
+                    GovQuery::TallyBreakdown(QueryTallyBreakdownRequest { proposal_id: 1 }),
+                )
+                .unwrap_test()
+        else {
+            panic!("unexpected query response")
+        };
+        let proposal = proposal_get(&ctx, &SubspaceKey::Gov, 1)
+            .unwrap_test()
+            .unwrap_test();
+        let (_, _, tally_result) = keeper.tally(&mut ctx, &proposal).unwrap_test();
+
+        assert_eq!(tally, tally_result);
+        assert_eq!(
+            tally,
+            TallyResult {
+                yes: Uint256::from(70u32),
+                abstain: Uint256::zero(),
+                no: Uint256::from(80u32),
+                no_with_veto: Uint256::zero(),
+            }
+        );
+
+        let validator = |byte: u8| {
+            validators
+                .iter()
+                .find(|this| this.validator == ValAddress::from(address(byte)))
+                .unwrap_test()
+        };
+        assert_eq!(validator(1).voting_power, shares(70));
+        assert_eq!(validator(1).delegator_deduction, shares(30));
+        assert_eq!(
+            validator(1).delegator_overrides,
+            vec![DelegatorOverride {
+                delegator: address(3),
+                voting_power: shares(30),
+                vote: vote(3, VoteOption::No).options,
+            }]
+        );
+        assert_eq!(validator(2).voting_power, shares(50));
+        assert_eq!(validator(2).delegator_deduction, Decimal256::zero());
+        assert!(validator(2).delegator_overrides.is_empty());
+        assert_eq!(
+            validators
+                .iter()
+                .map(|this| this.validator.clone())
+                .collect::<Vec<_>>(),
+            vec![ValAddress::from(address(1)), ValAddress::from(address(2))],
+            "validators are ordered by address"
+        );
+
+        // the tally deletes votes, so the breakdown of a finished proposal is empty
+        let GovQueryResponse::TallyBreakdown(QueryTallyBreakdownResponse { tally, validators }) =
+            keeper
+                .query(
+                    &ctx,
+                    GovQuery::TallyBreakdown(QueryTallyBreakdownRequest { proposal_id: 1 }),
+                )
+                .unwrap_test()
+        else {
+            panic!("unexpected query response")
+        };
+        assert_eq!(tally, TallyResult::default());
+        assert!(validators.iter().all(|this| this.vote.is_empty()));
+    }
+
+    #[test]
+    fn validator_without_shares_has_no_voting_power() {
+        assert_eq!(
+            validator_voting_power(
+                Uint256::from(100u32),
+                Decimal256::zero(),
+                Decimal256::zero()
+            )
+            .unwrap_test(),
+            Decimal256::zero()
+        );
+    }
+
+    /// Runs end block after the voting period of a proposal with `messages` which the only
+    /// validator voted for
+    fn execute(
+        messages: Vec<TestProposal>,
+    ) -> (
+        ProposalModel<TestProposal>,
+        gears::store::bank::multi::ApplicationMultiBank<MemDB, SubspaceKey>,
+    ) {
+        let (proposal, _, multi_store) = end_block(
+            voting_proposal(1, messages),
+            staking(&[(1, 100)]),
+            vec![vote(1, VoteOption::Yes)],
+        );
+
+        (proposal, multi_store)
+    }
+
+    /// Runs end block at the end of the voting period of `proposal` with `votes`
+    fn end_block(
+        proposal: ProposalModel<TestProposal>,
+        staking_keeper: MockStakingKeeper,
+        votes: Vec<MsgVoteWeighted>,
+    ) -> (
+        ProposalModel<TestProposal>,
+        Vec<Event>,
+        gears::store::bank::multi::ApplicationMultiBank<MemDB, SubspaceKey>,
+    ) {
+        let proposal_id = proposal.proposal_id;
+        let keeper = keeper(staking_keeper);
+        let mut multi_store = build_store::<SubspaceKey>();
+        init_genesis(
+            &keeper,
+            &mut multi_store,
+            GovGenesisState {
+                votes,
+                proposals: vec![proposal],
+                ..GovGenesisState::default()
+            },
+        );
+
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            2,
+            Header {
+                time: Timestamp::try_new(VOTING_END, 0).unwrap_test(),
+                ..Header::default()
+            },
+            ConsensusParams::default(),
+            Default::default(),
+        );
+        let events = keeper.end_block(&mut ctx);
+
+        let proposal = proposal_get(&ctx, &SubspaceKey::Gov, proposal_id)
+            .unwrap_test()
+            .unwrap_test();
+
+        (proposal, events, multi_store)
+    }
+
+    /// Staking with a validator of each `(operator, bonded tokens)`, all shares are
+    /// self delegated
+    fn staking(validators: &[(u8, u32)]) -> MockStakingKeeper {
+        MockStakingKeeper {
+            validators: validators
+                .iter()
+                .map(|(operator, tokens)| MockValidator {
+                    operator: ValAddress::from(address(*operator)),
+                    bonded_tokens: Uint256::from(*tokens),
+                    delegator_shares: Decimal256::from_atomics(*tokens, 0).unwrap_test(),
+                })
+                .collect(),
+            delegations: vec![],
+        }
+    }
+
+    fn vote(voter: u8, option: VoteOption) -> MsgVoteWeighted {
+        MsgVoteWeighted {
+            proposal_id: 1,
+            voter: address(voter),
+            options: vec![VoteOptionWeighted {
+                option,
+                weight: VoteWeight::try_from(Decimal256::one()).unwrap_test(),
+            }],
+        }
+    }
+
+    const VOTING_END: i64 = 100;
+
+    fn voting_proposal(
+        proposal_id: u64,
+        messages: Vec<TestProposal>,
+    ) -> ProposalModel<TestProposal> {
+        ProposalModel {
+            proposal_id,
+            messages,
+            status: ProposalStatus::VotingPeriod,
+            final_tally_result: None,
+            submit_time: Timestamp::UNIX_EPOCH,
+            deposit_end_time: Timestamp::try_new(VOTING_END, 0).unwrap_test(),
+            total_deposit: UnsignedCoins::new(vec![]).unwrap_test(),
+            voting_start_time: Some(Timestamp::UNIX_EPOCH),
+            voting_end_time: Some(Timestamp::try_new(VOTING_END, 0).unwrap_test()),
+            metadata: String::new(),
+            title: "title".to_owned(),
+            summary: "summary".to_owned(),
+            proposer: None,
+            expedited: false,
+        }
+    }
+
+    type TestKeeper = GovKeeper<
+        SubspaceKey,
+        SubspaceKey,
+        GovModule,
+        MockBankKeeper,
+        MockAuthKeeper,
+        MockStakingKeeper,
+        TestProposal,
+        TestProposalHandler,
+    >;
+
+    fn keeper(staking_keeper: MockStakingKeeper) -> TestKeeper {
+        GovKeeper::new(
+            SubspaceKey::Gov,
+            SubspaceKey::Gov,
+            GovModule,
+            MockBankKeeper::former().form(),
+            MockAuthKeeper::former().form(),
+            staking_keeper,
+            TestProposalHandler,
+        )
+    }
+
+    fn init_genesis(
+        keeper: &TestKeeper,
+        multi_store: &mut gears::store::bank::multi::ApplicationMultiBank<MemDB, SubspaceKey>,
+        genesis: GovGenesisState<TestProposal>,
+    ) {
+        let mut ctx = build_init_ctx(multi_store, ConsensusParams::default());
+        keeper.init_genesis(&mut ctx, genesis);
+    }
+
+    fn address(byte: u8) -> AccAddress {
+        AccAddress::try_from(vec![byte; 20]).unwrap_test()
+    }
+
+    /// Message which writes `key` to the executed store, or fails after writing it
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestProposal {
+        authority: AccAddress,
+        key: String,
+        legacy: bool,
+        fail: bool,
+    }
+
+    impl TestProposal {
+        const TYPE_URL: &'static str = "/test.TestProposal";
+
+        fn new(authority: AccAddress, key: &str) -> Self {
+            Self {
+                authority,
+                key: key.to_owned(),
+                legacy: false,
+                fail: false,
+            }
+        }
+    }
+
+    impl From<TestProposal> for Any {
+        fn from(msg: TestProposal) -> Self {
+            Any {
+                type_url: TestProposal::TYPE_URL.to_owned(),
+                value: serde_json::to_vec(&msg).unwrap_test(),
+            }
+        }
+    }
+
+    impl TryFrom<Any> for TestProposal {
+        type Error = CoreError;
+
+        fn try_from(value: Any) -> Result<Self, Self::Error> {
+            match value.type_url.as_str() {
+                TestProposal::TYPE_URL => serde_json::from_slice(&value.value)
+                    .map_err(|e| CoreError::DecodeGeneral(e.to_string())),
+                _ => Err(CoreError::DecodeAny(value.type_url)),
+            }
+        }
+    }
+
+    impl TxMessage for TestProposal {
+        fn get_signers(&self) -> Vec<&AccAddress> {
+            match self.legacy {
+                true => vec![],
+                false => vec![&self.authority],
+            }
+        }
+
+        fn type_url(&self) -> &'static str {
+            TestProposal::TYPE_URL
+        }
+    }
+
+    impl Proposal for TestProposal {
+        fn is_legacy_content(&self) -> bool {
+            self.legacy
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct TestProposalHandler;
+
+    impl ProposalHandler<TestProposal, SubspaceKey> for TestProposalHandler {
+        fn handle<CTX: InfallibleContextMut<DB, SubspaceKey>, DB: Database>(
+            &self,
+            proposal: TestProposal,
+            ctx: &mut CTX,
+        ) -> Result<(), ProposalHandlingError> {
+            ctx.infallible_store_mut(&SubspaceKey::Executed)
+                .set(proposal.key.into_bytes(), [1]);
+
+            match proposal.fail {
+                true => Err(ProposalHandlingError::InvalidProposal),
+                false => Ok(()),
+            }
+        }
+
+        fn check(_: &TestProposal) -> bool {
+            true
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct GovModule;
+
+    impl Module for GovModule {
+        fn name(&self) -> String {
+            "gov".to_owned()
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockStakingKeeper {
+        validators: Vec<MockValidator>,
+        delegations: Vec<MockDelegation>,
+    }
+
+    impl GovStakingKeeper<SubspaceKey, GovModule> for MockStakingKeeper {
+        type Validator = MockValidator;
+        type Delegation = MockDelegation;
+
+        fn bonded_validators_by_power_iter<DB: Database, CTX: QueryableContext<DB, SubspaceKey>>(
+            &self,
+            _: &CTX,
+        ) -> Result<impl Iterator<Item = Result<Self::Validator, GasStoreErrors>>, GasStoreErrors>
+        {
+            Ok(self.validators.clone().into_iter().map(Ok))
+        }
+
+        fn delegations_iter<DB: Database, CTX: QueryableContext<DB, SubspaceKey>>(
+            &self,
+            _: &CTX,
+            voter: &AccAddress,
+        ) -> impl Iterator<Item = Result<Self::Delegation, GasStoreErrors>> {
+            self.delegations
+                .iter()
+                .filter(|this| this.delegator == *voter)
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+
+        fn total_bonded_tokens<DB: Database, CTX: QueryableContext<DB, SubspaceKey>>(
+            &self,
+            _: &CTX,
+        ) -> Result<gears::types::base::coin::UnsignedCoin, GasStoreErrors> {
+            Ok(gears::types::base::coin::UnsignedCoin {
+                denom: "uatom".parse().unwrap_test(),
+                amount: self
+                    .validators
+                    .iter()
+                    .fold(Uint256::zero(), |acc, this| acc + this.bonded_tokens),
+            })
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct MockValidator {
+        operator: ValAddress,
+        bonded_tokens: Uint256,
+        delegator_shares: Decimal256,
+    }
+
+    impl StakingValidator for MockValidator {
+        fn operator(&self) -> &ValAddress {
+            &self.operator
+        }
+
+        fn tokens(&self) -> Uint256 {
+            self.bonded_tokens
+        }
+
+        fn bonded_tokens(&self) -> Uint256 {
+            self.bonded_tokens
+        }
+
+        fn delegator_shares(&self) -> Decimal256 {
+            self.delegator_shares
+        }
+
+        fn cons_pub_key(&self) -> &PublicKey {
+            unimplemented!("gov doesn't use consensus keys")
+        }
+
+        fn is_jailed(&self) -> bool {
+            false
+        }
+
+        fn min_self_delegation(&self) -> Uint256 {
+            Uint256::zero()
+        }
+
+        fn commission(&self) -> Decimal256 {
+            Decimal256::zero()
+        }
+
+        fn status(&self) -> BondStatus {
+            BondStatus::Bonded
+        }
+
+        fn tokens_from_shares(&self, shares: Decimal256) -> Result<Decimal256, NumericError> {
+            Ok(
+                shares * Decimal256::from_atomics(self.bonded_tokens, 0).unwrap_test()
+                    / self.delegator_shares,
+            )
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct MockDelegation {
+        delegator: AccAddress,
+        validator: ValAddress,
+        shares: Decimal256,
+    }
+
+    impl StakingDelegation for MockDelegation {
+        fn delegator(&self) -> &AccAddress {
+            &self.delegator
+        }
+
+        fn validator(&self) -> &ValAddress {
+            &self.validator
+        }
+
+        fn shares(&self) -> &Decimal256 {
+            &self.shares
+        }
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "gov")]
+        #[pkey(to_string = "gov")]
+        Gov,
+        #[skey(to_string = "executed")]
+        #[pkey(to_string = "executed")]
+        Executed,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
+}
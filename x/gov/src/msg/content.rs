@@ -0,0 +1,128 @@
+//! Json form of legacy proposal content. Known contents are written in protobuf json form
+//! `{"@type": <type url>, ..fields}` which amino json signing renders by their amino names,
+//! unknown contents are written as raw `Any`.
+
+use gears::core::Protobuf;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::proposal::{
+    client::ClientUpdateProposal,
+    param::RawParameterChangeProposal,
+    text::TextProposal,
+    upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal},
+};
+
+const TYPE_KEY: &str = "@type";
+
+pub fn serialize<S: Serializer>(content: &Any, serializer: S) -> Result<S::Ok, S::Error> {
+    let fields = match content.type_url.as_str() {
+        TextProposal::TYPE_URL => serde_json::to_value(
+            TextProposal::decode_vec(&content.value).map_err(S::Error::custom)?,
+        ),
+        RawParameterChangeProposal::TYPE_URL => serde_json::to_value(
+            RawParameterChangeProposal::decode(content.value.as_slice())
+                .map_err(S::Error::custom)?,
+        ),
+        SoftwareUpgradeProposal::TYPE_URL => serde_json::to_value(
+            SoftwareUpgradeProposal::decode_vec(&content.value).map_err(S::Error::custom)?,
+        ),
+        CancelSoftwareUpgradeProposal::TYPE_URL => serde_json::to_value(
+            CancelSoftwareUpgradeProposal::decode_vec(&content.value).map_err(S::Error::custom)?,
+        ),
+        ClientUpdateProposal::TYPE_URL => serde_json::to_value(
+            ClientUpdateProposal::decode_vec(&content.value).map_err(S::Error::custom)?,
+        ),
+        _ => return content.serialize(serializer),
+    }
+    .map_err(S::Error::custom)?;
+
+    let mut map = Map::new();
+    map.insert(TYPE_KEY.to_owned(), Value::String(content.type_url.clone()));
+    match fields {
+        Value::Object(fields) => map.extend(fields),
+        _ => return Err(S::Error::custom("proposal content isn't a json object")),
+    }
+
+    map.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Any, D::Error> {
+    let mut map = Map::<String, Value>::deserialize(deserializer)?;
+
+    let type_url = match map.remove(TYPE_KEY) {
+        Some(Value::String(type_url)) => type_url,
+        Some(_) => {
+            return Err(D::Error::custom(
+                "`@type` of proposal content isn't a string",
+            ))
+        }
+        None => return serde_json::from_value(Value::Object(map)).map_err(D::Error::custom),
+    };
+    let fields = Value::Object(map);
+
+    let content: Any = match type_url.as_str() {
+        TextProposal::TYPE_URL => serde_json::from_value::<TextProposal>(fields).map(Into::into),
+        RawParameterChangeProposal::TYPE_URL => {
+            serde_json::from_value::<RawParameterChangeProposal>(fields).map(Into::into)
+        }
+        SoftwareUpgradeProposal::TYPE_URL => {
+            serde_json::from_value::<SoftwareUpgradeProposal>(fields).map(Into::into)
+        }
+        CancelSoftwareUpgradeProposal::TYPE_URL => {
+            serde_json::from_value::<CancelSoftwareUpgradeProposal>(fields).map(Into::into)
+        }
+        ClientUpdateProposal::TYPE_URL => {
+            serde_json::from_value::<ClientUpdateProposal>(fields).map(Into::into)
+        }
+        _ => {
+            return Err(D::Error::custom(format!(
+                "unknown proposal content {type_url}"
+            )))
+        }
+    }
+    .map_err(D::Error::custom)?;
+
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "super")]
+        content: Any,
+    }
+
+    #[test]
+    fn known_content_in_protobuf_json_form() {
+        let wrapper = Wrapper {
+            content: TextProposal {
+                title: "title".to_owned(),
+                description: "description".to_owned(),
+            }
+            .into(),
+        };
+
+        let json = serde_json::to_value(&wrapper).expect("content is valid");
+
+        assert_eq!(
+            serde_json::json!({
+                "content": {
+                    "@type": TextProposal::TYPE_URL,
+                    "title": "title",
+                    "description": "description"
+                }
+            }),
+            json
+        );
+        assert_eq!(
+            wrapper,
+            serde_json::from_value::<Wrapper>(json).expect("json is valid")
+        );
+    }
+}
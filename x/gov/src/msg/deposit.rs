@@ -25,6 +25,7 @@ pub struct Deposit {
 impl Deposit {
     pub(crate) const KEY_PREFIX: [u8; 1] = [0x10];
     pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1/MsgDeposit";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/MsgDeposit";
 
     pub(crate) fn key(proposal_id: u64, depositor: &AccAddress) -> Vec<u8> {
         [
@@ -47,6 +48,10 @@ impl TxMessage for Deposit {
     fn type_url(&self) -> &'static str {
         Deposit::TYPE_URL
     }
+
+    fn amino_url(&self) -> &'static str {
+        Deposit::AMINO_URL
+    }
 }
 
 impl TryFrom<inner::MsgDeposit> for Deposit {
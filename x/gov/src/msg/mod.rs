@@ -12,25 +12,42 @@ use serde::Serialize;
 use vote::Vote;
 use weighted_vote::MsgVoteWeighted;
 
+mod content;
 pub mod deposit;
 pub mod proposal;
+mod rendering;
+pub mod v1;
 pub mod vote;
 pub mod weighted_vote;
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
+#[serde(tag = "@type")]
 pub enum GovMsg {
+    #[serde(rename = "/cosmos.gov.v1beta1/MsgDeposit")]
     #[msg(url(path = Deposit::TYPE_URL))]
     Deposit(Deposit),
+    #[serde(rename = "/cosmos.gov.v1beta1/MsgVote")]
     #[msg(url(path = Vote::TYPE_URL))]
     Vote(Vote),
+    #[serde(rename = "/cosmos.gov.v1beta1/MsgVoteWeighted")]
     #[msg(url(path = MsgVoteWeighted::TYPE_URL))]
     Weighted(MsgVoteWeighted),
+    #[serde(rename = "/cosmos.gov.v1beta1/MsgSubmitProposal")]
     #[msg(url(path = MsgSubmitProposal::TYPE_URL))]
     Proposal(MsgSubmitProposal),
+    #[serde(rename = "/cosmos.gov.v1.MsgSubmitProposal")]
+    #[msg(url(path = v1::proposal::MsgSubmitProposal::TYPE_URL))]
+    ProposalV1(v1::proposal::MsgSubmitProposal),
 }
 
 impl ValueRenderer for GovMsg {
-    fn format<MG: MetadataGetter>(&self, _: &MG) -> Result<Vec<Screen>, RenderError> {
-        Err(RenderError::NotImplemented)
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            GovMsg::Deposit(msg) => msg.format(get_metadata),
+            GovMsg::Vote(msg) => msg.format(get_metadata),
+            GovMsg::Weighted(msg) => msg.format(get_metadata),
+            GovMsg::Proposal(msg) => msg.format(get_metadata),
+            GovMsg::ProposalV1(msg) => msg.format(get_metadata),
+        }
     }
 }
@@ -16,6 +16,7 @@ mod inner {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MsgSubmitProposal {
+    #[serde(with = "crate::msg::content")]
     pub content: Any, // TODO: Generic?
     pub initial_deposit: UnsignedCoins,
     pub proposer: AccAddress,
@@ -23,6 +24,7 @@ pub struct MsgSubmitProposal {
 
 impl MsgSubmitProposal {
     pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1/MsgSubmitProposal";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/MsgSubmitProposal";
 }
 
 impl TxMessage for MsgSubmitProposal {
@@ -33,6 +35,10 @@ impl TxMessage for MsgSubmitProposal {
     fn type_url(&self) -> &'static str {
         MsgSubmitProposal::TYPE_URL
     }
+
+    fn amino_url(&self) -> &'static str {
+        MsgSubmitProposal::AMINO_URL
+    }
 }
 
 impl Protobuf<inner::MsgSubmitProposal> for MsgSubmitProposal {}
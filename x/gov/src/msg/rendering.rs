@@ -0,0 +1,411 @@
+//! Textual sign mode rendering of governance messages as specified in ADR-050
+
+use gears::{
+    core::any::google::Any,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    types::{
+        decimal256::Decimal256,
+        rendering::screen::{Content, Indent, Screen},
+    },
+};
+
+use crate::proposal::{
+    text::TextProposal,
+    upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal},
+};
+
+use super::{
+    deposit::Deposit,
+    proposal::MsgSubmitProposal,
+    v1::{
+        exec_legacy_content::MsgExecLegacyContent,
+        proposal::MsgSubmitProposal as MsgSubmitProposalV1,
+    },
+    vote::{Vote, VoteOption},
+    weighted_vote::MsgVoteWeighted,
+};
+
+/// Name of vote option as it declared in protobuf enum
+fn vote_option_name(option: &VoteOption) -> &'static str {
+    match option {
+        VoteOption::Empty => "VOTE_OPTION_UNSPECIFIED",
+        VoteOption::Yes => "VOTE_OPTION_YES",
+        VoteOption::Abstain => "VOTE_OPTION_ABSTAIN",
+        VoteOption::No => "VOTE_OPTION_NO",
+        VoteOption::NoWithVeto => "VOTE_OPTION_NO_WITH_VETO",
+    }
+}
+
+fn screen(title: &str, content: Content, indent: Indent) -> Screen {
+    Screen {
+        title: title.to_owned(),
+        content,
+        indent: Some(indent),
+        expert: false,
+    }
+}
+
+/// Screens of `title` and `description` fields which are common for all proposals
+fn title_description_screens(title: &str, description: &str) -> Result<Vec<Screen>, RenderError> {
+    let mut screens = Vec::with_capacity(2);
+    for (field, value) in [("Title", title), ("Description", description)] {
+        if !value.is_empty() {
+            screens.push(screen(
+                field,
+                DefaultPrimitiveRenderer::try_format(value)?,
+                Indent::three(),
+            ));
+        }
+    }
+
+    Ok(screens)
+}
+
+/// Format content of proposal. Known proposals are decoded and rendered field by field,
+/// other contents are rendered as bytes in expert mode.
+fn content_screens(content: &Any) -> Result<Vec<Screen>, RenderError> {
+    let mut screens = vec![screen(
+        "Content",
+        DefaultPrimitiveRenderer::try_format(content.type_url.as_str())?,
+        Indent::two(),
+    )];
+
+    let decode_err = |e: gears::core::errors::CoreError| RenderError::Rendering(e.to_string());
+
+    match content.type_url.as_str() {
+        TextProposal::TYPE_URL => {
+            let proposal = TextProposal::try_from(content.clone()).map_err(decode_err)?;
+            screens.extend(title_description_screens(
+                &proposal.title,
+                &proposal.description,
+            )?);
+        }
+        CancelSoftwareUpgradeProposal::TYPE_URL => {
+            let proposal =
+                CancelSoftwareUpgradeProposal::try_from(content.clone()).map_err(decode_err)?;
+            screens.extend(title_description_screens(
+                &proposal.title,
+                &proposal.description,
+            )?);
+        }
+        SoftwareUpgradeProposal::TYPE_URL => {
+            let SoftwareUpgradeProposal {
+                title,
+                description,
+                plan,
+            } = SoftwareUpgradeProposal::try_from(content.clone()).map_err(decode_err)?;
+            screens.extend(title_description_screens(&title, &description)?);
+
+            let indent = Indent::try_new(4).map_err(|e| RenderError::Rendering(e.to_string()))?;
+            screens.push(screen(
+                "Plan",
+                DefaultPrimitiveRenderer::try_format("Plan object")?,
+                Indent::three(),
+            ));
+            screens.push(screen(
+                "Name",
+                DefaultPrimitiveRenderer::try_format(plan.name.as_ref().as_str())?,
+                indent,
+            ));
+            screens.push(screen(
+                "Height",
+                DefaultPrimitiveRenderer::format(u64::from(plan.height.get())),
+                indent,
+            ));
+            if !plan.info.is_empty() {
+                screens.push(screen(
+                    "Info",
+                    DefaultPrimitiveRenderer::try_format(plan.info.as_str())?,
+                    indent,
+                ));
+            }
+        }
+        _ => {
+            if !content.value.is_empty() {
+                screens.push(Screen {
+                    title: "Value".to_owned(),
+                    content: DefaultPrimitiveRenderer::try_format(content.value.as_slice())?,
+                    indent: Some(Indent::three()),
+                    expert: true,
+                });
+            }
+        }
+    }
+
+    Ok(screens)
+}
+
+impl ValueRenderer for Deposit {
+    /// Format `MsgDeposit`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            screen(
+                "Proposal id",
+                DefaultPrimitiveRenderer::format(self.proposal_id),
+                Indent::two(),
+            ),
+            screen(
+                "Depositor",
+                DefaultPrimitiveRenderer::format(self.depositor.clone()),
+                Indent::two(),
+            ),
+            screen(
+                "Amount",
+                DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                Indent::two(),
+            ),
+        ])
+    }
+}
+
+impl ValueRenderer for Vote {
+    /// Format `MsgVote`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            screen(
+                "Proposal id",
+                DefaultPrimitiveRenderer::format(self.proposal_id),
+                Indent::two(),
+            ),
+            screen(
+                "Voter",
+                DefaultPrimitiveRenderer::format(self.voter.clone()),
+                Indent::two(),
+            ),
+            screen(
+                "Option",
+                DefaultPrimitiveRenderer::try_format(vote_option_name(&self.option))?,
+                Indent::two(),
+            ),
+        ])
+    }
+}
+
+impl ValueRenderer for MsgVoteWeighted {
+    /// Format `MsgVoteWeighted`. Options are rendered as repeated field.
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let total = self.options.len();
+
+        let mut screens = vec![
+            screen(
+                "Proposal id",
+                DefaultPrimitiveRenderer::format(self.proposal_id),
+                Indent::two(),
+            ),
+            screen(
+                "Voter",
+                DefaultPrimitiveRenderer::format(self.voter.clone()),
+                Indent::two(),
+            ),
+        ];
+
+        if total == 0 {
+            return Ok(screens);
+        }
+
+        screens.push(screen(
+            "Options",
+            DefaultPrimitiveRenderer::try_format(format!("{total} WeightedVoteOption"))?,
+            Indent::two(),
+        ));
+
+        for (i, option) in self.options.iter().enumerate() {
+            screens.push(screen(
+                &format!("Options ({}/{total})", i + 1),
+                DefaultPrimitiveRenderer::try_format("WeightedVoteOption object")?,
+                Indent::two(),
+            ));
+            screens.push(screen(
+                "Option",
+                DefaultPrimitiveRenderer::try_format(vote_option_name(&option.option))?,
+                Indent::three(),
+            ));
+            screens.push(screen(
+                "Weight",
+                DefaultPrimitiveRenderer::format(Decimal256::from(option.weight.clone())),
+                Indent::three(),
+            ));
+        }
+
+        screens.push(screen(
+            "",
+            DefaultPrimitiveRenderer::try_format("End of Options")?,
+            Indent::two(),
+        ));
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for MsgSubmitProposal {
+    /// Format `MsgSubmitProposal`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = content_screens(&self.content)?;
+
+        if !self.initial_deposit.is_empty() {
+            screens.push(screen(
+                "Initial deposit",
+                DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.initial_deposit.clone(),
+                    get_metadata,
+                )?,
+                Indent::two(),
+            ));
+        }
+
+        screens.push(screen(
+            "Proposer",
+            DefaultPrimitiveRenderer::format(self.proposer.clone()),
+            Indent::two(),
+        ));
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for MsgSubmitProposalV1 {
+    /// Format gov v1 `MsgSubmitProposal`. Legacy contents are unwrapped from `MsgExecLegacyContent`
+    /// and rendered the same way as in v1beta1 message
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = Vec::new();
+
+        for message in &self.messages {
+            match message.type_url.as_str() {
+                MsgExecLegacyContent::TYPE_URL => {
+                    let legacy = MsgExecLegacyContent::try_from(message.clone())
+                        .map_err(|e| RenderError::Rendering(e.to_string()))?;
+                    screens.extend(content_screens(&legacy.content)?);
+                }
+                _ => screens.extend(content_screens(message)?),
+            }
+        }
+
+        for (field, value) in [
+            ("Title", &self.title),
+            ("Summary", &self.summary),
+            ("Metadata", &self.metadata),
+        ] {
+            if !value.is_empty() {
+                screens.push(screen(
+                    field,
+                    DefaultPrimitiveRenderer::try_format(value.as_str())?,
+                    Indent::two(),
+                ));
+            }
+        }
+
+        if !self.initial_deposit.is_empty() {
+            screens.push(screen(
+                "Initial deposit",
+                DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.initial_deposit.clone(),
+                    get_metadata,
+                )?,
+                Indent::two(),
+            ));
+        }
+
+        screens.push(screen(
+            "Proposer",
+            DefaultPrimitiveRenderer::format(self.proposer.clone()),
+            Indent::two(),
+        ));
+
+        if self.expedited {
+            screens.push(screen(
+                "Expedited",
+                DefaultPrimitiveRenderer::format(self.expedited),
+                Indent::two(),
+            ));
+        }
+
+        Ok(screens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        core::{any::google::Any, Protobuf},
+        signing::{handler::NoneMetadataGetter, renderer::value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use crate::{
+        msg::{proposal::MsgSubmitProposal, vote::Vote},
+        proposal::text::TextProposal,
+    };
+
+    #[test]
+    fn vote_works() -> anyhow::Result<()> {
+        let msg: Vote = serde_json::from_str(
+            r#"{
+            "proposal_id": 1,
+            "voter": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "option": "Yes"
+        }"#,
+        )?;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Proposal id", "content": "1", "indent": 2 },
+            { "title": "Voter", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Option", "content": "VOTE_OPTION_YES", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn submit_text_proposal_works() -> anyhow::Result<()> {
+        let proposal = TextProposal {
+            title: "Test proposal".to_owned(),
+            description: "Some description".to_owned(),
+        };
+
+        let msg = MsgSubmitProposal {
+            content: Any {
+                type_url: TextProposal::TYPE_URL.to_owned(),
+                value: proposal.encode_vec(),
+            },
+            initial_deposit: serde_json::from_str(r#"[{ "denom": "uatom", "amount": "1000" }]"#)?,
+            proposer: serde_json::from_str(r#""cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux""#)?,
+        };
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Content", "content": "/cosmos.params.v1beta1/TextProposal", "indent": 2 },
+            { "title": "Title", "content": "Test proposal", "indent": 3 },
+            { "title": "Description", "content": "Some description", "indent": 3 },
+            { "title": "Initial deposit", "content": "1'000 uatom", "indent": 2 },
+            { "title": "Proposer", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
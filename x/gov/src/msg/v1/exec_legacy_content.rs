@@ -0,0 +1,97 @@
+use bytes::Bytes;
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    types::{address::AccAddress, tx::TxMessage},
+};
+use ibc_proto::google::protobuf::Any;
+use serde::{Deserialize, Serialize};
+
+mod inner {
+    use ibc_proto::google::protobuf::Any;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MsgExecLegacyContent {
+        #[prost(message, optional, tag = "1")]
+        pub content: Option<Any>,
+        #[prost(string, tag = "2")]
+        pub authority: String,
+    }
+}
+
+/// Wraps v1beta1 proposal content so it can be a message of gov v1 proposal.
+/// `authority` must be the gov module account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MsgExecLegacyContent {
+    #[serde(with = "crate::msg::content")]
+    pub content: Any,
+    pub authority: AccAddress,
+}
+
+impl MsgExecLegacyContent {
+    pub const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgExecLegacyContent";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/v1/MsgExecLegacyContent";
+}
+
+impl TxMessage for MsgExecLegacyContent {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.authority]
+    }
+
+    fn type_url(&self) -> &'static str {
+        MsgExecLegacyContent::TYPE_URL
+    }
+
+    fn amino_url(&self) -> &'static str {
+        MsgExecLegacyContent::AMINO_URL
+    }
+}
+
+impl Protobuf<inner::MsgExecLegacyContent> for MsgExecLegacyContent {}
+
+impl TryFrom<inner::MsgExecLegacyContent> for MsgExecLegacyContent {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::MsgExecLegacyContent { content, authority }: inner::MsgExecLegacyContent,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: content.ok_or(CoreError::MissingField(
+                "MsgExecLegacyContent missing content".to_owned(),
+            ))?,
+            authority: AccAddress::from_bech32(&authority)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+        })
+    }
+}
+
+impl From<MsgExecLegacyContent> for inner::MsgExecLegacyContent {
+    fn from(MsgExecLegacyContent { content, authority }: MsgExecLegacyContent) -> Self {
+        Self {
+            content: Some(content),
+            authority: authority.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgExecLegacyContent {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        MsgExecLegacyContent::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<MsgExecLegacyContent> for Any {
+    fn from(msg: MsgExecLegacyContent) -> Self {
+        Any {
+            type_url: MsgExecLegacyContent::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
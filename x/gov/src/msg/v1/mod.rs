@@ -0,0 +1,2 @@
+pub mod exec_legacy_content;
+pub mod proposal;
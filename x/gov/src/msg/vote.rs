@@ -104,6 +104,7 @@ impl TryFrom<i32> for VoteOption {
 
 impl Vote {
     pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1/MsgVote";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/MsgVote";
 }
 
 impl TxMessage for Vote {
@@ -114,6 +115,10 @@ impl TxMessage for Vote {
     fn type_url(&self) -> &'static str {
         Vote::TYPE_URL
     }
+
+    fn amino_url(&self) -> &'static str {
+        Vote::AMINO_URL
+    }
 }
 
 impl Protobuf<inner::MsgVote> for Vote {}
@@ -34,6 +34,7 @@ impl MsgVoteWeighted {
     /// We always store vote with weight
     pub(crate) const KEY_PREFIX: [u8; 1] = [0x20];
     pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1/MsgVoteWeighted";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/MsgVoteWeighted";
 
     pub fn key(proposal_id: u64, voter: &AccAddress) -> Vec<u8> {
         [
@@ -158,6 +159,10 @@ impl TxMessage for MsgVoteWeighted {
     fn type_url(&self) -> &'static str {
         MsgVoteWeighted::TYPE_URL
     }
+
+    fn amino_url(&self) -> &'static str {
+        MsgVoteWeighted::AMINO_URL
+    }
 }
 
 impl Protobuf<inner::MsgVoteWeighted> for MsgVoteWeighted {}
@@ -10,14 +10,15 @@ use gears::{
     application::keepers::params::ParamsKeeper,
     core::{errors::CoreError, Protobuf},
     error::ProtobufError,
-    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError},
     tendermint::types::time::duration::Duration,
     types::{
         base::{
             coin::UnsignedCoin,
             coins::{Coins, UnsignedCoins},
         },
-        decimal256::Decimal256,
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        uint::Uint256,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -30,8 +31,11 @@ const KEY_VOTING_PARAMS: &str = "votingparams";
 const KEY_TALLY_PARAMS: &str = "tallyparams";
 
 const DEFAULT_PERIOD: Duration = Duration::new_from_secs(172800); // 2 days
+const DEFAULT_EXPEDITED_PERIOD: Duration = Duration::new_from_secs(86400); // 1 day
+const EXPEDITED_MIN_DEPOSIT_RATIO: u8 = 5;
 
 mod environment;
+pub mod v1;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -40,6 +44,24 @@ pub struct DepositParams {
     #[serde(serialize_with = "serialize_duration_to_nanos_string")]
     #[serde(deserialize_with = "deserialize_duration_from_nanos_string")]
     pub max_deposit_period: Duration, // ?
+    /// Minimum deposit to start voting of expedited proposal
+    #[serde(default = "default_expedited_min_deposit")]
+    pub expedited_min_deposit: UnsignedCoins,
+}
+
+/// Expedited proposals need five times the default min deposit, as in cosmos-sdk
+fn default_expedited_min_deposit() -> UnsignedCoins {
+    let min_deposit =
+        UnsignedCoin::from_str(environment::DEFAULT_MIN_DEPOSIT).expect("default is valid");
+
+    UnsignedCoins::new(vec![UnsignedCoin {
+        amount: min_deposit
+            .amount
+            .checked_mul(Uint256::from(EXPEDITED_MIN_DEPOSIT_RATIO))
+            .expect("default is valid"),
+        ..min_deposit
+    }])
+    .expect("default is valid")
 }
 
 impl Default for DepositParams {
@@ -51,6 +73,7 @@ impl Default for DepositParams {
             .expect("default is valid")])
             .expect("default is valid"),
             max_deposit_period: DEFAULT_PERIOD,
+            expedited_min_deposit: default_expedited_min_deposit(),
         }
     }
 }
@@ -61,12 +84,21 @@ pub struct VotingParams {
     #[serde(serialize_with = "serialize_duration_to_nanos_string")]
     #[serde(deserialize_with = "deserialize_duration_from_nanos_string")]
     pub voting_period: Duration,
+    #[serde(serialize_with = "serialize_duration_to_nanos_string")]
+    #[serde(deserialize_with = "deserialize_duration_from_nanos_string")]
+    #[serde(default = "default_expedited_voting_period")]
+    pub expedited_voting_period: Duration,
+}
+
+fn default_expedited_voting_period() -> Duration {
+    DEFAULT_EXPEDITED_PERIOD
 }
 
 impl Default for VotingParams {
     fn default() -> Self {
         Self {
             voting_period: DEFAULT_PERIOD,
+            expedited_voting_period: DEFAULT_EXPEDITED_PERIOD,
         }
     }
 }
@@ -76,6 +108,13 @@ pub struct TallyParams {
     pub quorum: Decimal256,
     pub threshold: Decimal256,
     pub veto_threshold: Decimal256,
+    /// Minimum proportion of Yes votes for expedited proposal to pass
+    #[serde(default = "default_expedited_threshold")]
+    pub expedited_threshold: Decimal256,
+}
+
+fn default_expedited_threshold() -> Decimal256 {
+    Decimal256::from_atomics(667_u16, 3).expect("Default should be valid")
 }
 
 impl Default for TallyParams {
@@ -92,11 +131,12 @@ impl Default for TallyParams {
             //veto_threshold: Decimal256::from_atomics(334_u16, 3).expect("Default should be valid"),
             veto_threshold: Decimal256::from_atomics(334000000000000001_u64, 18)
                 .expect("Default should be valid"),
+            expedited_threshold: default_expedited_threshold(),
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GovParams {
     pub deposit: DepositParams,
     pub voting: VotingParams,
@@ -139,6 +179,50 @@ impl ParamsDeserialize for GovParams {
                 .expect(SERDE_JSON_CONVERSION),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let invalid = |reason: String| ParamsValidationError::invalid_value(key, reason);
+
+        match key {
+            KEY_DEPOSIT_PARAMS => {
+                let params = serde_json::from_slice::<DepositParams>(value)
+                    .map_err(|e| invalid(e.to_string()))?;
+                let expedited_is_greater =
+                    params.min_deposit.inner().iter().all(|coin| {
+                        params.expedited_min_deposit.amount_of(&coin.denom) > coin.amount
+                    });
+                if !expedited_is_greater {
+                    Err(invalid(
+                        "expedited min deposit must be greater than min deposit".to_owned(),
+                    ))?
+                }
+            }
+            KEY_VOTING_PARAMS => {
+                let params = serde_json::from_slice::<VotingParams>(value)
+                    .map_err(|e| invalid(e.to_string()))?;
+                if params.expedited_voting_period >= params.voting_period {
+                    Err(invalid(
+                        "expedited voting period must be less than voting period".to_owned(),
+                    ))?
+                }
+            }
+            KEY_TALLY_PARAMS => {
+                let params = serde_json::from_slice::<TallyParams>(value)
+                    .map_err(|e| invalid(e.to_string()))?;
+                if params.expedited_threshold <= params.threshold
+                    || params.expedited_threshold > Decimal256::one()
+                {
+                    Err(invalid(
+                        "expedited threshold must be greater than threshold and not greater than 1"
+                            .to_owned(),
+                    ))?
+                }
+            }
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned()))?,
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -152,15 +236,6 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for GovParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_DEPOSIT_PARAMS => serde_json::from_slice::<DepositParams>(value.as_ref()).is_ok(),
-            KEY_VOTING_PARAMS => serde_json::from_slice::<VotingParams>(value.as_ref()).is_ok(),
-            KEY_TALLY_PARAMS => serde_json::from_slice::<TallyParams>(value.as_ref()).is_ok(),
-            _ => false,
-        }
-    }
 }
 
 mod inner {
@@ -203,6 +278,8 @@ impl TryFrom<inner::DepositParams> for DepositParams {
                     ))
                 })?
             },
+            // v1beta1 has no expedited params
+            expedited_min_deposit: default_expedited_min_deposit(),
         })
     }
 }
@@ -212,6 +289,7 @@ impl From<DepositParams> for inner::DepositParams {
         DepositParams {
             min_deposit,
             max_deposit_period,
+            expedited_min_deposit: _,
         }: DepositParams,
     ) -> Self {
         Self {
@@ -231,22 +309,45 @@ impl TryFrom<inner::TallyParams> for TallyParams {
 
     fn try_from(
         inner::TallyParams {
-            quorum: _,
-            threshold: _,
-            veto_threshold: _,
+            quorum,
+            threshold,
+            veto_threshold,
         }: inner::TallyParams,
     ) -> Result<Self, Self::Error> {
+        // v1beta1 decimals are bytes of cosmos proto string
+        let parse = |field: &str, bytes: Vec<u8>| {
+            String::from_utf8(bytes)
+                .map_err(|e| e.to_string())
+                .and_then(|this| {
+                    Decimal256::from_cosmos_proto_string(&this).map_err(|e| e.to_string())
+                })
+                .map_err(|e| CoreError::DecodeGeneral(format!("TallyParams: field `{field}`: {e}")))
+        };
+
         Ok(Self {
-            quorum: Decimal256::one(),
-            threshold: Decimal256::one(),
-            veto_threshold: Decimal256::one(),
-        }) // TODO:NOW
+            quorum: parse("quorum", quorum)?,
+            threshold: parse("threshold", threshold)?,
+            veto_threshold: parse("veto_threshold", veto_threshold)?,
+            // v1beta1 has no expedited params
+            expedited_threshold: default_expedited_threshold(),
+        })
     }
 }
 
 impl From<TallyParams> for inner::TallyParams {
-    fn from(_value: TallyParams) -> Self {
-        todo!()
+    fn from(
+        TallyParams {
+            quorum,
+            threshold,
+            veto_threshold,
+            expedited_threshold: _,
+        }: TallyParams,
+    ) -> Self {
+        Self {
+            quorum: quorum.to_cosmos_proto_string().into_bytes(),
+            threshold: threshold.to_cosmos_proto_string().into_bytes(),
+            veto_threshold: veto_threshold.to_cosmos_proto_string().into_bytes(),
+        }
     }
 }
 
@@ -267,12 +368,19 @@ impl TryFrom<inner::VotingParams> for VotingParams {
                 Duration::try_new(duration.seconds, duration.nanos)
                     .map_err(|err| anyhow::anyhow!("failed to map duration: {err}"))?
             },
+            // v1beta1 has no expedited params
+            expedited_voting_period: DEFAULT_EXPEDITED_PERIOD,
         })
     }
 }
 
 impl From<VotingParams> for inner::VotingParams {
-    fn from(VotingParams { voting_period }: VotingParams) -> Self {
+    fn from(
+        VotingParams {
+            voting_period,
+            expedited_voting_period: _,
+        }: VotingParams,
+    ) -> Self {
         Self {
             voting_period: Some(inner::Duration {
                 seconds: voting_period.duration_seconds().into(),
@@ -283,3 +391,56 @@ impl From<VotingParams> for inner::VotingParams {
 }
 
 impl Protobuf<inner::VotingParams> for VotingParams {}
+
+#[cfg(test)]
+mod tests {
+    use gears::extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn default_params_are_valid() {
+        for (key, value) in GovParams::default().to_raw() {
+            assert_eq!(GovParams::validate_raw(key, &value), Ok(()), "{key}");
+        }
+    }
+
+    #[test]
+    fn validate_raw_rejects_invalid_params() {
+        let deposit = DepositParams {
+            expedited_min_deposit: DepositParams::default().min_deposit,
+            ..DepositParams::default()
+        };
+        let voting = VotingParams {
+            expedited_voting_period: DEFAULT_PERIOD,
+            ..VotingParams::default()
+        };
+        let tally = TallyParams {
+            expedited_threshold: Decimal256::from_atomics(11_u8, 1).unwrap_test(),
+            ..TallyParams::default()
+        };
+
+        for (key, value) in [
+            (
+                KEY_DEPOSIT_PARAMS,
+                serde_json::to_vec(&deposit).unwrap_test(),
+            ),
+            (KEY_VOTING_PARAMS, serde_json::to_vec(&voting).unwrap_test()),
+            (KEY_TALLY_PARAMS, serde_json::to_vec(&tally).unwrap_test()),
+            (KEY_TALLY_PARAMS, b"1".to_vec()),
+        ] {
+            assert!(
+                matches!(
+                    GovParams::validate_raw(key, &value),
+                    Err(ParamsValidationError::InvalidValue { .. })
+                ),
+                "{key}"
+            );
+        }
+
+        assert_eq!(
+            GovParams::validate_raw("unknown", b"1"),
+            Err(ParamsValidationError::UnknownKey("unknown".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::str::FromStr;
+
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    tendermint::types::time::duration::Duration,
+    types::{base::coins::Coins, decimal256::Decimal256},
+};
+
+use super::{default_expedited_threshold, DepositParams, GovParams, TallyParams, VotingParams};
+
+/// Gov v1 encodes decimals as strings instead of bytes of v1beta1
+pub mod inner {
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+    use ibc_proto::google::protobuf::Duration;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TallyParams {
+        #[prost(string, tag = "1")]
+        pub quorum: String,
+        #[prost(string, tag = "2")]
+        pub threshold: String,
+        #[prost(string, tag = "3")]
+        pub veto_threshold: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Params {
+        #[prost(message, repeated, tag = "1")]
+        pub min_deposit: Vec<Coin>,
+        #[prost(message, optional, tag = "2")]
+        pub max_deposit_period: Option<Duration>,
+        #[prost(message, optional, tag = "3")]
+        pub voting_period: Option<Duration>,
+        #[prost(string, tag = "4")]
+        pub quorum: String,
+        #[prost(string, tag = "5")]
+        pub threshold: String,
+        #[prost(string, tag = "6")]
+        pub veto_threshold: String,
+        #[prost(message, optional, tag = "10")]
+        pub expedited_voting_period: Option<Duration>,
+        #[prost(string, tag = "11")]
+        pub expedited_threshold: String,
+        #[prost(message, repeated, tag = "12")]
+        pub expedited_min_deposit: Vec<Coin>,
+    }
+}
+
+fn decimal_parse(field: &str, value: &str) -> Result<Decimal256, CoreError> {
+    Decimal256::from_str(value)
+        .map_err(|e| CoreError::DecodeGeneral(format!("TallyParams: field `{field}`: {e}")))
+}
+
+impl TryFrom<inner::TallyParams> for TallyParams {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::TallyParams {
+            quorum,
+            threshold,
+            veto_threshold,
+        }: inner::TallyParams,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            quorum: decimal_parse("quorum", &quorum)?,
+            threshold: decimal_parse("threshold", &threshold)?,
+            veto_threshold: decimal_parse("veto_threshold", &veto_threshold)?,
+            // v1 keeps expedited threshold in `Params` only
+            expedited_threshold: default_expedited_threshold(),
+        })
+    }
+}
+
+impl From<TallyParams> for inner::TallyParams {
+    fn from(
+        TallyParams {
+            quorum,
+            threshold,
+            veto_threshold,
+            expedited_threshold: _,
+        }: TallyParams,
+    ) -> Self {
+        Self {
+            quorum: quorum.to_string(),
+            threshold: threshold.to_string(),
+            veto_threshold: veto_threshold.to_string(),
+        }
+    }
+}
+
+impl Protobuf<inner::TallyParams> for TallyParams {}
+
+impl TryFrom<inner::Params> for GovParams {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::Params {
+            min_deposit,
+            max_deposit_period,
+            voting_period,
+            quorum,
+            threshold,
+            veto_threshold,
+            expedited_voting_period,
+            expedited_threshold,
+            expedited_min_deposit,
+        }: inner::Params,
+    ) -> Result<Self, Self::Error> {
+        let duration = |field: &str, var: Option<ibc_proto::google::protobuf::Duration>| {
+            Duration::try_from(
+                var.ok_or(CoreError::MissingField(format!("Params: field `{field}`")))?,
+            )
+            .map_err(|e| CoreError::DecodeGeneral(format!("Params: field `{field}`: {e}")))
+        };
+
+        let coins = |coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>| {
+            let mut result = Vec::with_capacity(coins.len());
+
+            for coin in coins {
+                result.push(coin.try_into().map_err(
+                    |e: gears::types::base::errors::CoinError| CoreError::Coin(e.to_string()),
+                )?)
+            }
+
+            Coins::new(result).map_err(|e| CoreError::Coins(e.to_string()))
+        };
+
+        let tally: TallyParams = inner::TallyParams {
+            quorum,
+            threshold,
+            veto_threshold,
+        }
+        .try_into()?;
+
+        Ok(Self {
+            deposit: DepositParams {
+                min_deposit: coins(min_deposit)?,
+                max_deposit_period: duration("max_deposit_period", max_deposit_period)?,
+                expedited_min_deposit: coins(expedited_min_deposit)?,
+            },
+            voting: VotingParams {
+                voting_period: duration("voting_period", voting_period)?,
+                expedited_voting_period: duration(
+                    "expedited_voting_period",
+                    expedited_voting_period,
+                )?,
+            },
+            tally: TallyParams {
+                expedited_threshold: decimal_parse("expedited_threshold", &expedited_threshold)?,
+                ..tally
+            },
+        })
+    }
+}
+
+impl From<GovParams> for inner::Params {
+    fn from(
+        GovParams {
+            deposit,
+            voting,
+            tally,
+        }: GovParams,
+    ) -> Self {
+        let expedited_threshold = tally.expedited_threshold.to_string();
+        let inner::TallyParams {
+            quorum,
+            threshold,
+            veto_threshold,
+        } = tally.into();
+
+        Self {
+            min_deposit: deposit.min_deposit.into_iter().map(|e| e.into()).collect(),
+            max_deposit_period: Some(deposit.max_deposit_period.into()),
+            voting_period: Some(voting.voting_period.into()),
+            quorum,
+            threshold,
+            veto_threshold,
+            expedited_voting_period: Some(voting.expedited_voting_period.into()),
+            expedited_threshold,
+            expedited_min_deposit: deposit
+                .expedited_min_deposit
+                .into_iter()
+                .map(|e| e.into())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::Params> for GovParams {}
@@ -0,0 +1,45 @@
+use std::marker::PhantomData;
+
+use gears::{
+    context::InfallibleContextMut,
+    store::StoreKey,
+    types::msg::send::MsgSend,
+    x::{keepers::bank::BankKeeper, module::Module},
+};
+
+use super::handler::{ProposalHandler, ProposalHandlingError};
+
+/// Executes bank sends of gov v1 proposals. Sender is the gov module account which was
+/// checked on proposal submission.
+#[derive(Debug, Clone)]
+pub struct BankProposalHandler<SK, M, BK> {
+    bank_keeper: BK,
+    _marker: PhantomData<(SK, M)>,
+}
+
+impl<SK, M, BK> BankProposalHandler<SK, M, BK> {
+    pub fn new(bank_keeper: BK) -> Self {
+        Self {
+            bank_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, M: Module, BK: BankKeeper<SK, M>> ProposalHandler<MsgSend, SK>
+    for BankProposalHandler<SK, M, BK>
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: gears::store::database::Database>(
+        &self,
+        proposal: MsgSend,
+        ctx: &mut CTX,
+    ) -> Result<(), ProposalHandlingError> {
+        self.bank_keeper
+            .send_coins_from_account_to_account(ctx, &proposal)
+            .map_err(|e| ProposalHandlingError::Other(e.to_string()))
+    }
+
+    fn check(_proposal: &MsgSend) -> bool {
+        true
+    }
+}
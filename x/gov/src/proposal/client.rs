@@ -0,0 +1,70 @@
+use gears::{
+    context::InfallibleContextMut,
+    derive::{AppMessage, Protobuf},
+    params::ParamsSubspaceKey,
+    store::StoreKey,
+};
+use ibc::core::host::types::identifiers::ClientId;
+use ibc_rs::ClientKeeper;
+use serde::{Deserialize, Serialize};
+
+use super::handler::{ProposalHandler, ProposalHandlingError};
+
+mod inner {
+    pub use ibc_proto::ibc::core::client::v1::ClientUpdateProposal;
+}
+
+/// Replaces an expired or frozen IBC client by an active client tracking the same chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Protobuf, AppMessage)]
+#[proto(raw = "inner::ClientUpdateProposal")]
+#[msg(url = "/ibc.core.client.v1.ClientUpdateProposal")]
+pub struct ClientUpdateProposal {
+    pub title: String,
+    pub description: String,
+    pub subject_client_id: String,
+    pub substitute_client_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientUpdateProposalHandler<SK, PSK> {
+    keeper: ClientKeeper<SK, PSK>,
+}
+
+impl<SK, PSK> ClientUpdateProposalHandler<SK, PSK> {
+    pub fn new(keeper: ClientKeeper<SK, PSK>) -> Self {
+        Self { keeper }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey> ProposalHandler<ClientUpdateProposal, SK>
+    for ClientUpdateProposalHandler<SK, PSK>
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: gears::store::database::Database>(
+        &self,
+        proposal: ClientUpdateProposal,
+        ctx: &mut CTX,
+    ) -> Result<(), ProposalHandlingError> {
+        let subject = proposal
+            .subject_client_id
+            .parse::<ClientId>()
+            .map_err(|_| ProposalHandlingError::InvalidProposal)?;
+        let substitute = proposal
+            .substitute_client_id
+            .parse::<ClientId>()
+            .map_err(|_| ProposalHandlingError::InvalidProposal)?;
+
+        self.keeper
+            .recover_client(ctx, &subject, &substitute)
+            .map_err(|e| ProposalHandlingError::Other(e.to_string()))
+    }
+
+    fn check(proposal: &ClientUpdateProposal) -> bool {
+        match (
+            proposal.subject_client_id.parse::<ClientId>(),
+            proposal.substitute_client_id.parse::<ClientId>(),
+        ) {
+            (Ok(subject), Ok(substitute)) => subject != substitute,
+            _ => false,
+        }
+    }
+}
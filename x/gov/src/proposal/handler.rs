@@ -1,6 +1,7 @@
 use gears::context::InfallibleContextMut;
 use gears::core::errors::CoreError;
 use gears::gas::store::errors::GasStoreErrors;
+use gears::params::ParamsValidationError;
 use gears::store::database::Database;
 use gears::store::StoreKey;
 
@@ -25,6 +26,8 @@ pub enum ProposalHandlingError {
     #[error("Can't handle this proposal: invalid bytes")]
     InvalidProposal,
     #[error("Can't handle this proposal: {0}")]
+    Params(#[from] ParamsValidationError),
+    #[error("Can't handle this proposal: {0}")]
     Gas(#[from] GasStoreErrors),
     #[error("{0}")]
     Other(String),
@@ -1,30 +1,35 @@
+pub mod bank;
+pub mod client;
 mod handler;
 pub mod param;
 pub mod text;
 pub mod upgrade;
 
-use ::upgrade::{keeper::UpgradeKeeper, UpgradeHandler};
+use ::upgrade::{
+    keeper::UpgradeKeeper,
+    types::tx::{MsgCancelUpgrade, MsgSoftwareUpgrade},
+    UpgradeHandler,
+};
+use bank::BankProposalHandler;
+use client::{ClientUpdateProposal, ClientUpdateProposalHandler};
 use gears::{
-    application::keepers::params::ParamsKeeper, core::errors::CoreError, derive::AppMessage,
-    params::ParamsSubspaceKey, store::StoreKey,
+    derive::AppMessage,
+    params::{ParamsSubspaceKey, ParamsSubspaceValidation},
+    store::StoreKey,
+    types::{msg::send::MsgSend, tx::TxMessage},
+    x::{keepers::bank::BankKeeper, module::Module},
 };
 pub use handler::*;
-use ibc_proto::google::protobuf::Any;
+use ibc_rs::ClientKeeper;
 use param::{ParamChangeProposalHandler, ParameterChangeProposal};
 use serde::{Deserialize, Serialize};
 use text::TextProposal;
 use upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal, UpgradeProposalHandler};
 
-pub trait Proposal:
-    Clone
-    + std::fmt::Debug
-    + Send
-    + Sync
-    + serde::Serialize
-    + TryFrom<Any, Error = CoreError>
-    + Into<Any>
-    + 'static
-{
+/// Message which could be executed by passed proposal. Gov v1 messages are signed by the gov
+/// module account, v1beta1 contents have no signer and are wrapped into `MsgExecLegacyContent`.
+pub trait Proposal: TxMessage + std::fmt::Debug {
+    fn is_legacy_content(&self) -> bool;
 }
 
 #[derive(Debug, Clone, AppMessage, Deserialize)]
@@ -37,6 +42,14 @@ pub enum Proposals<PSK: ParamsSubspaceKey> {
     Upgrade(SoftwareUpgradeProposal),
     #[msg(url(path = CancelSoftwareUpgradeProposal::TYPE_URL))]
     CancelUpgrade(CancelSoftwareUpgradeProposal),
+    #[msg(url(path = ClientUpdateProposal::TYPE_URL))]
+    ClientUpdate(ClientUpdateProposal),
+    #[msg(url(path = MsgSend::TYPE_URL))]
+    Send(MsgSend),
+    #[msg(url(path = MsgSoftwareUpgrade::TYPE_URL))]
+    SoftwareUpgrade(MsgSoftwareUpgrade),
+    #[msg(url(path = MsgCancelUpgrade::TYPE_URL))]
+    CancelSoftwareUpgrade(MsgCancelUpgrade),
 }
 
 impl<PSK: ParamsSubspaceKey> Serialize for Proposals<PSK> {
@@ -49,34 +62,60 @@ impl<PSK: ParamsSubspaceKey> Serialize for Proposals<PSK> {
             Proposals::Params(inner) => inner.serialize(serializer),
             Proposals::Upgrade(inner) => inner.serialize(serializer),
             Proposals::CancelUpgrade(inner) => inner.serialize(serializer),
+            Proposals::ClientUpdate(inner) => inner.serialize(serializer),
+            Proposals::Send(inner) => inner.serialize(serializer),
+            Proposals::SoftwareUpgrade(inner) => inner.serialize(serializer),
+            Proposals::CancelSoftwareUpgrade(inner) => inner.serialize(serializer),
         }
     }
 }
 
-impl<PSK: ParamsSubspaceKey> Proposal for Proposals<PSK> {}
+impl<PSK: ParamsSubspaceKey> Proposal for Proposals<PSK> {
+    fn is_legacy_content(&self) -> bool {
+        match self {
+            Proposals::Text(_)
+            | Proposals::Params(_)
+            | Proposals::Upgrade(_)
+            | Proposals::CancelUpgrade(_)
+            | Proposals::ClientUpdate(_) => true,
+            Proposals::Send(_)
+            | Proposals::SoftwareUpgrade(_)
+            | Proposals::CancelSoftwareUpgrade(_) => false,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
-pub struct ProposalsHandler<SK, PSK, PK, M, UH> {
-    params_handler: ParamChangeProposalHandler<PK, SK, PSK>,
+pub struct ProposalsHandler<SK, PSK, M, UH, GM, BK> {
+    params_handler: ParamChangeProposalHandler<SK, PSK>,
     upgrade_handler: UpgradeProposalHandler<SK, M, UH>,
+    client_handler: ClientUpdateProposalHandler<SK, PSK>,
+    bank_handler: BankProposalHandler<SK, GM, BK>,
 }
 
-impl<SK, PSK, PK, M, UH> ProposalsHandler<SK, PSK, PK, M, UH> {
-    pub fn new(keeper: UpgradeKeeper<SK, M, UH>) -> Self {
+impl<SK, PSK, M, UH, GM, BK> ProposalsHandler<SK, PSK, M, UH, GM, BK> {
+    pub fn new(
+        keeper: UpgradeKeeper<SK, M, UH>,
+        client_keeper: ClientKeeper<SK, PSK>,
+        bank_keeper: BK,
+    ) -> Self {
         Self {
             params_handler: ParamChangeProposalHandler::new(),
             upgrade_handler: UpgradeProposalHandler::new(keeper),
+            client_handler: ClientUpdateProposalHandler::new(client_keeper),
+            bank_handler: BankProposalHandler::new(bank_keeper),
         }
     }
 }
 
 impl<
-        PSK: ParamsSubspaceKey,
+        PSK: ParamsSubspaceValidation,
         SK: StoreKey,
-        PK: ParamsKeeper<PSK>,
         M: ::upgrade::Module + TryFrom<Vec<u8>, Error = anyhow::Error>,
         UH: UpgradeHandler,
-    > ProposalHandler<Proposals<PSK>, SK> for ProposalsHandler<SK, PSK, PK, M, UH>
+        GM: Module,
+        BK: BankKeeper<SK, GM>,
+    > ProposalHandler<Proposals<PSK>, SK> for ProposalsHandler<SK, PSK, M, UH, GM, BK>
 {
     fn handle<
         CTX: gears::context::InfallibleContextMut<DB, SK>,
@@ -91,13 +130,18 @@ impl<
             Proposals::Params(proposal) => self.params_handler.handle(proposal, ctx),
             Proposals::Upgrade(proposal) => self.upgrade_handler.handle(proposal, ctx),
             Proposals::CancelUpgrade(proposal) => self.upgrade_handler.handle(proposal, ctx),
+            Proposals::ClientUpdate(proposal) => self.client_handler.handle(proposal, ctx),
+            Proposals::Send(msg) => self.bank_handler.handle(msg, ctx),
+            Proposals::SoftwareUpgrade(msg) => self.upgrade_handler.handle(msg, ctx),
+            Proposals::CancelSoftwareUpgrade(msg) => self.upgrade_handler.handle(msg, ctx),
         }
     }
 
     fn check(proposal: &Proposals<PSK>) -> bool {
         match proposal {
-            Proposals::Params(proposal) => {
-                ParamChangeProposalHandler::<PK, SK, PSK>::check(proposal)
+            Proposals::Params(proposal) => ParamChangeProposalHandler::<SK, PSK>::check(proposal),
+            Proposals::ClientUpdate(proposal) => {
+                ClientUpdateProposalHandler::<SK, PSK>::check(proposal)
             }
             _ => true,
         }
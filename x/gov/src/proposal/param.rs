@@ -1,16 +1,15 @@
 use std::marker::PhantomData;
 
 use gears::{
-    application::keepers::params::ParamsKeeper,
     derive::{AppMessage, Protobuf, Raw},
-    params::ParamsSubspaceKey,
+    params::{ParamsSubspaceKey, ParamsSubspaceValidation, ParamsValidationError},
     store::StoreKey,
 };
 use ibc_proto::google::protobuf::Any;
 use prost::Message;
 use serde::{Deserialize, Serialize};
 
-use super::handler::{ProposalHandler, ProposalHandlingError};
+use super::handler::ProposalHandler;
 
 #[derive(Debug, Clone, PartialEq, Raw, Protobuf, AppMessage, Deserialize)]
 #[raw(derive(Serialize, Deserialize, Clone, PartialEq))]
@@ -64,32 +63,48 @@ impl<PSK: ParamsSubspaceKey> Serialize for ParameterChangeProposal<PSK> {
     }
 }
 
+impl RawParameterChangeProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.params.v1beta1/ParameterChangeProposal";
+}
+
 impl From<RawParameterChangeProposal> for Any {
     fn from(msg: RawParameterChangeProposal) -> Self {
         Any {
-            type_url: "/cosmos.params.v1beta1/ParameterChangeProposal".to_owned(),
+            type_url: RawParameterChangeProposal::TYPE_URL.to_owned(),
             value: msg.encode_to_vec(),
         }
     }
 }
 
+/// Applies param changes of passed proposal. Every change is validated by the params
+/// of its subspace before anything is written to the store.
 #[derive(Debug, Clone)]
-pub struct ParamChangeProposalHandler<PK, SK, PSK>(PhantomData<(PK, SK, PSK)>);
+pub struct ParamChangeProposalHandler<SK, PSK>(PhantomData<(SK, PSK)>);
 
-impl<PK, SK, PSK> Default for ParamChangeProposalHandler<PK, SK, PSK> {
+impl<SK, PSK> Default for ParamChangeProposalHandler<SK, PSK> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<PK, SK, PSK> ParamChangeProposalHandler<PK, SK, PSK> {
+impl<SK, PSK> ParamChangeProposalHandler<SK, PSK> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<PSK: ParamsSubspaceKey, PK: ParamsKeeper<PSK>, SK: StoreKey>
-    ProposalHandler<ParameterChangeProposal<PSK>, SK> for ParamChangeProposalHandler<PK, SK, PSK>
+impl<PSK: ParamsSubspaceValidation> ParamChange<PSK> {
+    pub fn validate(&self) -> Result<(), ParamsValidationError> {
+        let key = std::str::from_utf8(&self.key).map_err(|_| {
+            ParamsValidationError::UnknownKey(String::from_utf8_lossy(&self.key).into_owned())
+        })?;
+
+        self.subspace.validate_raw(key, &self.value)
+    }
+}
+
+impl<PSK: ParamsSubspaceValidation, SK: StoreKey> ProposalHandler<ParameterChangeProposal<PSK>, SK>
+    for ParamChangeProposalHandler<SK, PSK>
 {
     fn handle<
         CTX: gears::context::InfallibleContextMut<DB, SK>,
@@ -103,20 +118,17 @@ impl<PSK: ParamsSubspaceKey, PK: ParamsKeeper<PSK>, SK: StoreKey>
         }: ParameterChangeProposal<PSK>,
         ctx: &mut CTX,
     ) -> Result<(), super::handler::ProposalHandlingError> {
+        // params could be changed since submission, so they are validated once more
+        for change in &changes {
+            change.validate()?;
+        }
+
         for ParamChange {
             subspace,
             key,
             value,
         } in changes
         {
-            if !PK::check_key(&key) {
-                Err(ProposalHandlingError::KeyNotFound)?
-            }
-
-            if !PK::validate(&key, &value) {
-                Err(ProposalHandlingError::InvalidProposal)?
-            }
-
             let mut store = gears::params::gas::subspace_mut(ctx, &subspace);
 
             store.raw_key_set(key, value)?;
@@ -132,8 +144,6 @@ impl<PSK: ParamsSubspaceKey, PK: ParamsKeeper<PSK>, SK: StoreKey>
             changes,
         }: &ParameterChangeProposal<PSK>,
     ) -> bool {
-        changes
-            .iter()
-            .all(|this| PK::check_key(&this.key) && PK::validate(&this.key, &this.value))
+        changes.iter().all(|this| this.validate().is_ok())
     }
 }
@@ -4,40 +4,23 @@ use gears::{
     store::StoreKey,
 };
 use serde::{Deserialize, Serialize};
-use upgrade::{keeper::UpgradeKeeper, types::plan::Plan, Module, UpgradeHandler};
+use upgrade::{
+    keeper::UpgradeKeeper,
+    types::{
+        plan::Plan,
+        tx::{MsgCancelUpgrade, MsgSoftwareUpgrade},
+    },
+    Module, UpgradeHandler,
+};
 
 use super::handler::{ProposalHandler, ProposalHandlingError};
 
 mod inner {
-
-    // pub use ibc_proto::cosmos::upgrade::v1beta1::MsgCancelUpgrade;
-    // pub use ibc_proto::cosmos::upgrade::v1beta1::MsgSoftwareUpgrade;
     // Deprecated, but we need to use it
     pub use ibc_proto::cosmos::upgrade::v1beta1::CancelSoftwareUpgradeProposal;
     pub use ibc_proto::cosmos::upgrade::v1beta1::SoftwareUpgradeProposal;
 }
 
-// #[derive(Debug, Clone, Protobuf, Serialize, Deserialize, AppMessage)]
-// #[proto(raw = "inner::MsgCancelUpgrade")]
-// #[serde(try_from = "inner::MsgCancelUpgrade", into = "inner::MsgCancelUpgrade")]
-// #[msg(url = "/cosmos.upgrade.v1beta1/MsgCancelUpgrade")]
-// pub struct MsgCancelUpgrade {
-//     pub authority: AccAddress,
-// }
-
-// #[derive(Debug, Clone, Protobuf, Serialize, Deserialize, AppMessage)]
-// #[proto(raw = "inner::MsgSoftwareUpgrade")]
-// #[serde(
-//     try_from = "inner::MsgSoftwareUpgrade",
-//     into = "inner::MsgSoftwareUpgrade"
-// )]
-// #[msg(url = "/cosmos.upgrade.v1beta1/MsgSoftwareUpgrade")]
-// pub struct MsgSoftwareUpgrade {
-//     pub authority: AccAddress,
-//     #[proto(optional)]
-//     pub plan: Plan,
-// }
-
 #[derive(Debug, Clone, Protobuf, Serialize, Deserialize, AppMessage)]
 #[proto(raw = "inner::CancelSoftwareUpgradeProposal")]
 #[serde(
@@ -123,3 +106,45 @@ where
         true
     }
 }
+
+impl<SK: StoreKey, M: Module, UH: UpgradeHandler> ProposalHandler<MsgSoftwareUpgrade, SK>
+    for UpgradeProposalHandler<SK, M, UH>
+where
+    <M as TryFrom<Vec<u8>>>::Error: std::fmt::Display + std::fmt::Debug,
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: gears::store::database::Database>(
+        &self,
+        MsgSoftwareUpgrade { authority: _, plan }: MsgSoftwareUpgrade,
+        ctx: &mut CTX,
+    ) -> Result<(), ProposalHandlingError> {
+        self.keeper
+            .schedule_upgrade(ctx, plan, true)
+            .map_err(|e| ProposalHandlingError::Other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn check(_proposal: &MsgSoftwareUpgrade) -> bool {
+        true
+    }
+}
+
+impl<SK: StoreKey, M: Module, UH: UpgradeHandler> ProposalHandler<MsgCancelUpgrade, SK>
+    for UpgradeProposalHandler<SK, M, UH>
+where
+    <M as TryFrom<Vec<u8>>>::Error: std::fmt::Display + std::fmt::Debug,
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: gears::store::database::Database>(
+        &self,
+        MsgCancelUpgrade { authority: _ }: MsgCancelUpgrade,
+        ctx: &mut CTX,
+    ) -> Result<(), ProposalHandlingError> {
+        self.keeper.delete_upgrade_plan(ctx);
+
+        Ok(())
+    }
+
+    fn check(_proposal: &MsgCancelUpgrade) -> bool {
+        true
+    }
+}
@@ -1,13 +1,13 @@
 use gears::{baseapp::QueryRequest, derive::Query};
 use request::{
     QueryAllParamsRequest, QueryDepositRequest, QueryDepositsRequest, QueryParamsRequest,
-    QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest, QueryTallyResultRequest,
-    QueryVoteRequest, QueryVotesRequest,
+    QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest, QueryTallyBreakdownRequest,
+    QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
 };
 use response::{
     QueryAllParamsResponse, QueryDepositResponse, QueryDepositsResponse, QueryParamsResponse,
-    QueryProposalResponse, QueryProposalsResponse, QueryProposerResponse, QueryTallyResultResponse,
-    QueryVoteResponse, QueryVotesResponse,
+    QueryProposalResponse, QueryProposalsResponse, QueryProposerResponse,
+    QueryTallyBreakdownResponse, QueryTallyResultResponse, QueryVoteResponse, QueryVotesResponse,
 };
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,7 @@ use crate::proposal::Proposal;
 
 pub mod request;
 pub mod response;
+pub mod v1;
 
 #[derive(Debug, Clone, Query)]
 #[query(request)]
@@ -29,6 +30,11 @@ pub enum GovQuery {
     Vote(QueryVoteRequest),
     Votes(QueryVotesRequest),
     Proposer(QueryProposerRequest),
+    ProposalV1(v1::request::QueryProposalRequest),
+    ProposalsV1(v1::request::QueryProposalsRequest),
+    ParamsV1(v1::request::QueryParamsRequest),
+    TallyV1(v1::request::QueryTallyResultRequest),
+    TallyBreakdown(QueryTallyBreakdownRequest),
 }
 
 impl QueryRequest for GovQuery {
@@ -50,4 +56,9 @@ pub enum GovQueryResponse<T: Proposal> {
     Vote(QueryVoteResponse),
     Votes(QueryVotesResponse),
     Proposer(QueryProposerResponse),
+    ProposalV1(v1::response::QueryProposalResponse),
+    ProposalsV1(v1::response::QueryProposalsResponse),
+    ParamsV1(v1::response::QueryParamsResponse),
+    TallyV1(v1::response::QueryTallyResultResponse),
+    TallyBreakdown(QueryTallyBreakdownResponse),
 }
@@ -27,6 +27,12 @@ pub mod inner {
         #[prost(uint64, tag = "1")]
         pub proposal_id: u64,
     }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryTallyBreakdownRequest {
+        #[prost(uint64, tag = "1")]
+        pub proposal_id: u64,
+    }
 }
 
 #[derive(Clone, PartialEq, Debug, Query, Protobuf)]
@@ -185,3 +191,13 @@ pub struct QueryAllParamsRequest;
 pub struct QueryProposerRequest {
     pub proposal_id: u64,
 }
+
+/// Breakdown of the current tally by validators. It isn't a part of cosmos sdk gov, so it's
+/// served by its own service. Votes are deleted when the voting period ends, so the breakdown
+/// of a finished proposal is only available at heights before that.
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/gears.gov.v1beta1.Query/TallyBreakdown")]
+#[proto(raw = "inner::QueryTallyBreakdownRequest")]
+pub struct QueryTallyBreakdownRequest {
+    pub proposal_id: u64,
+}
@@ -10,10 +10,13 @@ use crate::{
     msg::{deposit::Deposit, weighted_vote::MsgVoteWeighted},
     params::{DepositParams, TallyParams, VotingParams},
     proposal::Proposal,
-    types::proposal::{ProposalModel, TallyResult},
+    types::{
+        proposal::{ProposalModel, TallyResult},
+        tally::ValidatorTallyBreakdown,
+    },
 };
 
-mod inner {
+pub mod inner {
     pub use ibc_proto::cosmos::gov::v1beta1::{
         QueryDepositResponse, QueryDepositsResponse, QueryParamsResponse, QueryProposalResponse,
         QueryProposalsResponse, QueryTallyResultResponse, QueryVoteResponse, QueryVotesResponse,
@@ -24,6 +27,14 @@ mod inner {
         #[prost(string, tag = "1")]
         pub proposer: String,
     }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryTallyBreakdownResponse {
+        #[prost(message, optional, tag = "1")]
+        pub tally: Option<ibc_proto::cosmos::gov::v1beta1::TallyResult>,
+        #[prost(message, repeated, tag = "2")]
+        pub validators: Vec<crate::types::tally::inner::ValidatorTallyBreakdown>,
+    }
 }
 
 #[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
@@ -217,3 +228,14 @@ pub struct QueryAllParamsResponse {
 pub struct QueryProposerResponse {
     proposer: AccAddress,
 }
+
+/// Tally of currently stored votes. Votes are removed once the proposal is tallied, so
+/// the breakdown is available during voting period only.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryTallyBreakdownResponse")]
+pub struct QueryTallyBreakdownResponse {
+    #[proto(optional)]
+    pub tally: TallyResult,
+    #[proto(repeated)]
+    pub validators: Vec<ValidatorTallyBreakdown>,
+}
@@ -0,0 +1,90 @@
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    derive::{Protobuf, Query},
+    types::{address::AccAddress, pagination::request::PaginationRequest},
+};
+
+use crate::{
+    query::request::{ParamsQuery, QueryProposalsRequest as QueryProposalsRequestV1Beta1},
+    types::proposal::ProposalStatus,
+};
+
+/// Requests of gov v1 have the same wire format as v1beta1 ones
+mod inner {
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryParamsRequest;
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryProposalRequest;
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryProposalsRequest;
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryTallyResultRequest;
+}
+
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.gov.v1.Query/Proposal")]
+#[proto(raw = "inner::QueryProposalRequest")]
+pub struct QueryProposalRequest {
+    pub proposal_id: u64,
+}
+
+#[derive(Clone, PartialEq, Debug, Query)]
+#[query(url = "/cosmos.gov.v1.Query/Proposals")]
+pub struct QueryProposalsRequest {
+    pub voter: Option<AccAddress>,
+    pub depositor: Option<AccAddress>,
+    pub proposal_status: Option<ProposalStatus>,
+    pub pagination: Option<PaginationRequest>,
+}
+
+impl TryFrom<inner::QueryProposalsRequest> for QueryProposalsRequest {
+    type Error = CoreError;
+
+    fn try_from(value: inner::QueryProposalsRequest) -> Result<Self, Self::Error> {
+        let QueryProposalsRequestV1Beta1 {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        } = value.try_into()?;
+
+        Ok(Self {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        })
+    }
+}
+
+impl From<QueryProposalsRequest> for inner::QueryProposalsRequest {
+    fn from(
+        QueryProposalsRequest {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        }: QueryProposalsRequest,
+    ) -> Self {
+        QueryProposalsRequestV1Beta1 {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        }
+        .into()
+    }
+}
+
+impl Protobuf<inner::QueryProposalsRequest> for QueryProposalsRequest {}
+
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.gov.v1.Query/Params")]
+#[proto(raw = "inner::QueryParamsRequest")]
+pub struct QueryParamsRequest {
+    #[proto(name = "params_type")]
+    pub kind: ParamsQuery,
+}
+
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.gov.v1.Query/TallyResult")]
+#[proto(raw = "inner::QueryTallyResultRequest")]
+pub struct QueryTallyResultRequest {
+    pub proposal_id: u64,
+}
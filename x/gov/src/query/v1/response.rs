@@ -0,0 +1,125 @@
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    derive::{Protobuf, Query},
+    types::pagination::response::PaginationResponse,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    params::{DepositParams, GovParams, TallyParams, VotingParams},
+    types::proposal::{v1::ProposalV1, TallyResult},
+};
+
+mod inner {
+    pub use crate::params::v1::inner::{Params, TallyParams};
+    pub use crate::types::proposal::v1::inner::{Proposal, TallyResult};
+    pub use ibc_proto::cosmos::base::query::v1beta1::PageResponse;
+    pub use ibc_proto::cosmos::gov::v1beta1::{DepositParams, VotingParams};
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryProposalResponse {
+        #[prost(message, optional, tag = "1")]
+        pub proposal: Option<Proposal>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryProposalsResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub proposals: Vec<Proposal>,
+        #[prost(message, optional, tag = "2")]
+        pub pagination: Option<PageResponse>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryParamsResponse {
+        #[prost(message, optional, tag = "1")]
+        pub voting_params: Option<VotingParams>,
+        #[prost(message, optional, tag = "2")]
+        pub deposit_params: Option<DepositParams>,
+        #[prost(message, optional, tag = "3")]
+        pub tally_params: Option<TallyParams>,
+        #[prost(message, optional, tag = "4")]
+        pub params: Option<Params>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryTallyResultResponse {
+        #[prost(message, optional, tag = "1")]
+        pub tally: Option<TallyResult>,
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryProposalResponse")]
+pub struct QueryProposalResponse {
+    #[proto(optional)]
+    pub proposal: Option<ProposalV1>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+pub struct QueryProposalsResponse {
+    pub proposals: Vec<ProposalV1>,
+    pub pagination: Option<PaginationResponse>,
+}
+
+impl TryFrom<inner::QueryProposalsResponse> for QueryProposalsResponse {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::QueryProposalsResponse {
+            proposals,
+            pagination,
+        }: inner::QueryProposalsResponse,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposals: {
+                let mut result = Vec::with_capacity(proposals.len());
+
+                for proposal in proposals {
+                    result.push(proposal.try_into()?)
+                }
+
+                result
+            },
+            pagination: pagination.map(|e| e.into()),
+        })
+    }
+}
+
+impl From<QueryProposalsResponse> for inner::QueryProposalsResponse {
+    fn from(
+        QueryProposalsResponse {
+            proposals,
+            pagination,
+        }: QueryProposalsResponse,
+    ) -> Self {
+        Self {
+            proposals: proposals.into_iter().map(|this| this.into()).collect(),
+            pagination: pagination.map(|e| e.into()),
+        }
+    }
+}
+
+impl Protobuf<inner::QueryProposalsResponse> for QueryProposalsResponse {}
+
+/// Deprecated `voting_params`, `deposit_params` and `tally_params` are set according to
+/// requested type like in v1beta1, `params` is always set.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryParamsResponse")]
+pub struct QueryParamsResponse {
+    #[proto(optional)]
+    pub voting_params: Option<VotingParams>,
+    #[proto(optional)]
+    pub deposit_params: Option<DepositParams>,
+    #[proto(optional)]
+    pub tally_params: Option<TallyParams>,
+    #[proto(optional)]
+    pub params: Option<GovParams>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryTallyResultResponse")]
+pub struct QueryTallyResultResponse {
+    #[proto(optional)]
+    pub tally: Option<TallyResult>,
+}
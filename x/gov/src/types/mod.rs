@@ -1,4 +1,5 @@
 pub mod deposit_iter;
 pub mod proposal;
+pub mod tally;
 pub mod validator;
 pub mod vote_iters;
@@ -1,4 +1,4 @@
-use std::{marker::PhantomData, ops::Bound};
+use std::{borrow::Cow, marker::PhantomData, ops::Bound};
 
 use chrono::{DateTime, Utc};
 use gears::{
@@ -7,9 +7,8 @@ use gears::{
     tendermint::types::time::timestamp::Timestamp,
     types::store::{kv::Store, range::VectoredStoreRange},
 };
-use serde::de::DeserializeOwned;
 
-use crate::{errors::SERDE_JSON_CONVERSION, proposal::Proposal};
+use crate::proposal::Proposal;
 
 use super::{parse_proposal_key_bytes, ProposalModel};
 
@@ -35,18 +34,13 @@ impl<'a, DB: Database, P: Proposal> ActiveProposalIterator<'a, DB, P> {
     }
 }
 
-impl<'a, DB: Database, P: Proposal + DeserializeOwned> Iterator
-    for ActiveProposalIterator<'a, DB, P>
-{
-    type Item = Result<((u64, DateTime<Utc>), ProposalModel<P>), GasStoreErrors>;
+impl<'a, DB: Database, P: Proposal> Iterator for ActiveProposalIterator<'a, DB, P> {
+    type Item = Result<((u64, DateTime<Utc>), Cow<'a, Vec<u8>>), GasStoreErrors>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(var) = self.0.next() {
             match var {
-                Ok((key, value)) => Some(Ok((
-                    parse_proposal_key_bytes(key.as_ref()),
-                    serde_json::from_slice(&value).expect(SERDE_JSON_CONVERSION),
-                ))),
+                Ok((key, value)) => Some(Ok((parse_proposal_key_bytes(key.as_ref()), value))),
                 Err(err) => Some(Err(err)),
             }
         } else {
@@ -8,6 +8,7 @@ use gears::{
     store::database::Database,
     tendermint::types::time::timestamp::Timestamp,
     types::{
+        address::AccAddress,
         base::coins::UnsignedCoins,
         store::{kv::Store, range::VectoredStoreRange},
         uint::Uint256,
@@ -19,6 +20,7 @@ use crate::{errors::SERDE_JSON_CONVERSION, keeper::KEY_PROPOSAL_PREFIX, proposal
 
 pub mod active_iter;
 pub mod inactive_iter;
+pub mod v1;
 
 mod inner {
     pub use ibc_proto::cosmos::gov::v1beta1::Proposal;
@@ -28,10 +30,12 @@ mod inner {
 // Slight modification of the RFC3339Nano but it right pads all zeros and drops the time zone info
 const SORTABLE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT&H:%M:%S.000000000";
 
+/// Proposal state shared by gov v1beta1 and v1. Proposal of v1beta1 has a single legacy content
+/// in `messages` and empty v1 only fields.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProposalModel<T> {
     pub proposal_id: u64,
-    pub content: T,
+    pub messages: Vec<T>,
     pub status: ProposalStatus,
     pub final_tally_result: Option<TallyResult>,
     pub submit_time: Timestamp,
@@ -39,6 +43,18 @@ pub struct ProposalModel<T> {
     pub total_deposit: UnsignedCoins,
     pub voting_start_time: Option<Timestamp>,
     pub voting_end_time: Option<Timestamp>,
+    #[serde(default)]
+    pub metadata: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub proposer: Option<AccAddress>,
+    /// Expedited proposal has shorter voting period and higher threshold. It's converted to
+    /// a regular one if the expedited voting fails.
+    #[serde(default)]
+    pub expedited: bool,
 }
 
 impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
@@ -67,11 +83,11 @@ impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
 
         Ok(Self {
             proposal_id,
-            content: content
+            messages: vec![content
                 .ok_or(CoreError::MissingField(
                     "Proposal: field `content`".to_owned(),
                 ))?
-                .try_into()?,
+                .try_into()?],
             status: status.try_into()?,
             final_tally_result: match final_tally_result {
                 Some(var) => Some(var.try_into()?),
@@ -109,6 +125,11 @@ impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
                 })?),
                 None => None,
             },
+            metadata: String::new(),
+            title: String::new(),
+            summary: String::new(),
+            proposer: None,
+            expedited: false,
         })
     }
 }
@@ -117,7 +138,7 @@ impl<T: Proposal> From<ProposalModel<T>> for inner::Proposal {
     fn from(
         ProposalModel {
             proposal_id,
-            content,
+            messages,
             status,
             final_tally_result,
             submit_time,
@@ -125,11 +146,20 @@ impl<T: Proposal> From<ProposalModel<T>> for inner::Proposal {
             total_deposit,
             voting_start_time,
             voting_end_time,
+            metadata: _,
+            title: _,
+            summary: _,
+            proposer: _,
+            expedited: _,
         }: ProposalModel<T>,
     ) -> Self {
         Self {
             proposal_id,
-            content: Some(content.into()),
+            // v1 proposal without legacy content has nothing to show in v1beta1
+            content: messages
+                .into_iter()
+                .find(|this| this.is_legacy_content())
+                .map(|this| this.into()),
             status: status as i32,
             final_tally_result: final_tally_result.map(|e| e.into()),
             submit_time: Some(ibc_proto::google::protobuf::Timestamp {
@@ -231,16 +261,12 @@ impl<T> ProposalModel<T> {
         )
     }
 
-    pub fn active_queue_key(proposal_id: u64, deposit_end_time: &Timestamp) -> Vec<u8> {
-        Self::queue_key(
-            &Self::KEY_ACTIVE_QUEUE_PREFIX,
-            proposal_id,
-            deposit_end_time,
-        )
+    pub fn active_queue_key(proposal_id: u64, voting_end_time: &Timestamp) -> Vec<u8> {
+        Self::queue_key(&Self::KEY_ACTIVE_QUEUE_PREFIX, proposal_id, voting_end_time)
     }
 
-    fn queue_key(prefix: &[u8], proposal_id: u64, deposit_end_time: &Timestamp) -> Vec<u8> {
-        let date_key = deposit_end_time.format_bytes_rounded();
+    fn queue_key(prefix: &[u8], proposal_id: u64, end_time: &Timestamp) -> Vec<u8> {
+        let date_key = end_time.format_bytes_rounded();
 
         [prefix, date_key.as_slice(), &proposal_id.to_be_bytes()].concat()
     }
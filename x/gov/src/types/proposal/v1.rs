@@ -0,0 +1,294 @@
+use std::str::FromStr;
+
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    tendermint::types::time::timestamp::Timestamp,
+    types::{address::AccAddress, base::coins::UnsignedCoins, uint::Uint256},
+};
+use ibc_proto::google::protobuf::Any;
+use serde::{Deserialize, Serialize};
+
+use crate::{msg::v1::exec_legacy_content::MsgExecLegacyContent, proposal::Proposal};
+
+use super::{ProposalModel, ProposalStatus, TallyResult};
+
+pub mod inner {
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+    use ibc_proto::google::protobuf::{Any, Timestamp};
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Proposal {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(message, repeated, tag = "2")]
+        pub messages: Vec<Any>,
+        #[prost(int32, tag = "3")]
+        pub status: i32,
+        #[prost(message, optional, tag = "4")]
+        pub final_tally_result: Option<TallyResult>,
+        #[prost(message, optional, tag = "5")]
+        pub submit_time: Option<Timestamp>,
+        #[prost(message, optional, tag = "6")]
+        pub deposit_end_time: Option<Timestamp>,
+        #[prost(message, repeated, tag = "7")]
+        pub total_deposit: Vec<Coin>,
+        #[prost(message, optional, tag = "8")]
+        pub voting_start_time: Option<Timestamp>,
+        #[prost(message, optional, tag = "9")]
+        pub voting_end_time: Option<Timestamp>,
+        #[prost(string, tag = "10")]
+        pub metadata: String,
+        #[prost(string, tag = "11")]
+        pub title: String,
+        #[prost(string, tag = "12")]
+        pub summary: String,
+        #[prost(string, tag = "13")]
+        pub proposer: String,
+        #[prost(bool, tag = "14")]
+        pub expedited: bool,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TallyResult {
+        #[prost(string, tag = "1")]
+        pub yes_count: String,
+        #[prost(string, tag = "2")]
+        pub abstain_count: String,
+        #[prost(string, tag = "3")]
+        pub no_count: String,
+        #[prost(string, tag = "4")]
+        pub no_with_veto_count: String,
+    }
+}
+
+/// Proposal as it represented in gov v1 queries. Messages are kept encoded
+/// and legacy contents are wrapped into `MsgExecLegacyContent`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProposalV1 {
+    pub proposal_id: u64,
+    pub messages: Vec<Any>,
+    pub status: ProposalStatus,
+    pub final_tally_result: Option<TallyResult>,
+    pub submit_time: Timestamp,
+    pub deposit_end_time: Timestamp,
+    pub total_deposit: UnsignedCoins,
+    pub voting_start_time: Option<Timestamp>,
+    pub voting_end_time: Option<Timestamp>,
+    pub metadata: String,
+    pub title: String,
+    pub summary: String,
+    pub proposer: Option<AccAddress>,
+    pub expedited: bool,
+}
+
+impl<T: Proposal> ProposalModel<T> {
+    /// Convert to gov v1 representation. `authority` is the gov module account which
+    /// executes legacy contents.
+    pub fn into_v1(self, authority: &AccAddress) -> ProposalV1 {
+        let ProposalModel {
+            proposal_id,
+            messages,
+            status,
+            final_tally_result,
+            submit_time,
+            deposit_end_time,
+            total_deposit,
+            voting_start_time,
+            voting_end_time,
+            metadata,
+            title,
+            summary,
+            proposer,
+            expedited,
+        } = self;
+
+        ProposalV1 {
+            proposal_id,
+            messages: messages
+                .into_iter()
+                .map(|msg| match msg.is_legacy_content() {
+                    true => MsgExecLegacyContent {
+                        content: msg.into(),
+                        authority: authority.clone(),
+                    }
+                    .into(),
+                    false => msg.into(),
+                })
+                .collect(),
+            status,
+            final_tally_result,
+            submit_time,
+            deposit_end_time,
+            total_deposit,
+            voting_start_time,
+            voting_end_time,
+            metadata,
+            title,
+            summary,
+            proposer,
+            expedited,
+        }
+    }
+}
+
+impl TryFrom<inner::Proposal> for ProposalV1 {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::Proposal {
+            id,
+            messages,
+            status,
+            final_tally_result,
+            submit_time,
+            deposit_end_time,
+            total_deposit,
+            voting_start_time,
+            voting_end_time,
+            metadata,
+            title,
+            summary,
+            proposer,
+            expedited,
+        }: inner::Proposal,
+    ) -> Result<Self, Self::Error> {
+        let timestamp = |field: &str, var: ibc_proto::google::protobuf::Timestamp| {
+            Timestamp::try_from(var)
+                .map_err(|e| CoreError::DecodeGeneral(format!("Proposal: invalid `{field}`: {e}")))
+        };
+
+        Ok(Self {
+            proposal_id: id,
+            messages,
+            status: status.try_into()?,
+            final_tally_result: final_tally_result.map(TryInto::try_into).transpose()?,
+            submit_time: timestamp(
+                "submit_time",
+                submit_time.ok_or(CoreError::MissingField(
+                    "Proposal: field `submit_time`".to_owned(),
+                ))?,
+            )?,
+            deposit_end_time: timestamp(
+                "deposit_end_time",
+                deposit_end_time.ok_or(CoreError::MissingField(
+                    "Proposal: field `deposit_end_time`".to_owned(),
+                ))?,
+            )?,
+            total_deposit: UnsignedCoins::new({
+                let mut result = Vec::with_capacity(total_deposit.len());
+
+                for coin in total_deposit {
+                    result.push(
+                        coin.try_into()
+                            .map_err(|e| CoreError::Coins(format!("Proposal: {e}")))?,
+                    );
+                }
+
+                result
+            })
+            .map_err(|e| CoreError::Coins(e.to_string()))?,
+            voting_start_time: voting_start_time
+                .map(|var| timestamp("voting_start_time", var))
+                .transpose()?,
+            voting_end_time: voting_end_time
+                .map(|var| timestamp("voting_end_time", var))
+                .transpose()?,
+            metadata,
+            title,
+            summary,
+            proposer: match proposer.is_empty() {
+                true => None,
+                false => Some(
+                    AccAddress::from_bech32(&proposer)
+                        .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+                ),
+            },
+            expedited,
+        })
+    }
+}
+
+impl From<ProposalV1> for inner::Proposal {
+    fn from(
+        ProposalV1 {
+            proposal_id,
+            messages,
+            status,
+            final_tally_result,
+            submit_time,
+            deposit_end_time,
+            total_deposit,
+            voting_start_time,
+            voting_end_time,
+            metadata,
+            title,
+            summary,
+            proposer,
+            expedited,
+        }: ProposalV1,
+    ) -> Self {
+        Self {
+            id: proposal_id,
+            messages,
+            status: status.into(),
+            final_tally_result: final_tally_result.map(Into::into),
+            submit_time: Some(submit_time.into()),
+            deposit_end_time: Some(deposit_end_time.into()),
+            total_deposit: total_deposit.into_iter().map(Into::into).collect(),
+            voting_start_time: voting_start_time.map(Into::into),
+            voting_end_time: voting_end_time.map(Into::into),
+            metadata,
+            title,
+            summary,
+            proposer: proposer.map(|this| this.to_string()).unwrap_or_default(),
+            expedited,
+        }
+    }
+}
+
+impl Protobuf<inner::Proposal> for ProposalV1 {}
+
+impl TryFrom<inner::TallyResult> for TallyResult {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::TallyResult {
+            yes_count,
+            abstain_count,
+            no_count,
+            no_with_veto_count,
+        }: inner::TallyResult,
+    ) -> Result<Self, Self::Error> {
+        let parse = |field: &str, value: &str| {
+            Uint256::from_str(value)
+                .map_err(|e| CoreError::DecodeGeneral(format!("{field} votes parse error: {e}")))
+        };
+
+        Ok(Self {
+            yes: parse("Yes", &yes_count)?,
+            abstain: parse("Abstain", &abstain_count)?,
+            no: parse("No", &no_count)?,
+            no_with_veto: parse("NoWithVeto", &no_with_veto_count)?,
+        })
+    }
+}
+
+impl From<TallyResult> for inner::TallyResult {
+    fn from(
+        TallyResult {
+            yes,
+            abstain,
+            no,
+            no_with_veto,
+        }: TallyResult,
+    ) -> Self {
+        Self {
+            yes_count: yes.to_string(),
+            abstain_count: abstain.to_string(),
+            no_count: no.to_string(),
+            no_with_veto_count: no_with_veto.to_string(),
+        }
+    }
+}
+
+impl Protobuf<inner::TallyResult> for TallyResult {}
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    types::{
+        address::{AccAddress, ValAddress},
+        decimal256::Decimal256,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::msg::weighted_vote::VoteOptionWeighted;
+
+pub mod inner {
+    use ibc_proto::cosmos::gov::v1beta1::WeightedVoteOption;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DelegatorOverride {
+        #[prost(string, tag = "1")]
+        pub delegator_address: String,
+        #[prost(string, tag = "2")]
+        pub voting_power: String,
+        #[prost(message, repeated, tag = "3")]
+        pub options: Vec<WeightedVoteOption>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ValidatorTallyBreakdown {
+        #[prost(string, tag = "1")]
+        pub validator_address: String,
+        #[prost(string, tag = "2")]
+        pub voting_power: String,
+        #[prost(message, repeated, tag = "3")]
+        pub options: Vec<WeightedVoteOption>,
+        #[prost(string, tag = "4")]
+        pub delegator_deduction: String,
+        #[prost(message, repeated, tag = "5")]
+        pub delegator_overrides: Vec<DelegatorOverride>,
+    }
+}
+
+/// Vote of delegator which overrides the vote of its validator for delegated shares
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DelegatorOverride {
+    pub delegator: AccAddress,
+    pub voting_power: Decimal256,
+    pub vote: Vec<VoteOptionWeighted>,
+}
+
+/// Vote of bonded validator and changes made by its delegators which voted on their own
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorTallyBreakdown {
+    pub validator: ValAddress,
+    /// Voting power of validator left after delegators deduction
+    pub voting_power: Decimal256,
+    /// Empty if validator didn't vote
+    pub vote: Vec<VoteOptionWeighted>,
+    /// Delegator shares which are excluded from the validator vote
+    pub delegator_deduction: Decimal256,
+    pub delegator_overrides: Vec<DelegatorOverride>,
+}
+
+fn decimal_parse(field: &str, value: &str) -> Result<Decimal256, CoreError> {
+    Decimal256::from_str(value).map_err(|e| CoreError::DecodeGeneral(format!("`{field}`: {e}")))
+}
+
+fn options_parse(
+    options: Vec<ibc_proto::cosmos::gov::v1beta1::WeightedVoteOption>,
+) -> Result<Vec<VoteOptionWeighted>, CoreError> {
+    options.into_iter().map(TryInto::try_into).collect()
+}
+
+impl TryFrom<inner::DelegatorOverride> for DelegatorOverride {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::DelegatorOverride {
+            delegator_address,
+            voting_power,
+            options,
+        }: inner::DelegatorOverride,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: AccAddress::from_bech32(&delegator_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            voting_power: decimal_parse("voting_power", &voting_power)?,
+            vote: options_parse(options)?,
+        })
+    }
+}
+
+impl From<DelegatorOverride> for inner::DelegatorOverride {
+    fn from(
+        DelegatorOverride {
+            delegator,
+            voting_power,
+            vote,
+        }: DelegatorOverride,
+    ) -> Self {
+        Self {
+            delegator_address: delegator.to_string(),
+            voting_power: voting_power.to_string(),
+            options: vote.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::DelegatorOverride> for DelegatorOverride {}
+
+impl TryFrom<inner::ValidatorTallyBreakdown> for ValidatorTallyBreakdown {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::ValidatorTallyBreakdown {
+            validator_address,
+            voting_power,
+            options,
+            delegator_deduction,
+            delegator_overrides,
+        }: inner::ValidatorTallyBreakdown,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator: ValAddress::from_bech32(&validator_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            voting_power: decimal_parse("voting_power", &voting_power)?,
+            vote: options_parse(options)?,
+            delegator_deduction: decimal_parse("delegator_deduction", &delegator_deduction)?,
+            delegator_overrides: delegator_overrides
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<ValidatorTallyBreakdown> for inner::ValidatorTallyBreakdown {
+    fn from(
+        ValidatorTallyBreakdown {
+            validator,
+            voting_power,
+            vote,
+            delegator_deduction,
+            delegator_overrides,
+        }: ValidatorTallyBreakdown,
+    ) -> Self {
+        Self {
+            validator_address: validator.to_string(),
+            voting_power: voting_power.to_string(),
+            options: vote.into_iter().map(Into::into).collect(),
+            delegator_deduction: delegator_deduction.to_string(),
+            delegator_overrides: delegator_overrides.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::ValidatorTallyBreakdown> for ValidatorTallyBreakdown {}
@@ -2,6 +2,8 @@ use gears::types::{address::ValAddress, decimal256::Decimal256, uint::Uint256};
 
 use crate::msg::weighted_vote::VoteOptionWeighted;
 
+use super::tally::DelegatorOverride;
+
 #[derive(Debug)]
 pub struct ValidatorGovInfo {
     pub address: ValAddress,
@@ -9,4 +11,5 @@ pub struct ValidatorGovInfo {
     pub delegator_shares: Decimal256,
     pub delegator_deduction: Decimal256,
     pub vote: Vec<VoteOptionWeighted>,
+    pub delegator_overrides: Vec<DelegatorOverride>,
 }
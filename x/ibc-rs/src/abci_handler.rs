@@ -1,33 +1,163 @@
 use std::marker::PhantomData;
 
 use crate::{
-    errors, ics02_client::client::cli::query::client_states::STATES_URL, keeper::Keeper,
-    message::Message, types::genesis::GenesisState,
+    errors::tx::IbcTxError,
+    ics02_client::{
+        client::cli::query::{
+            client_params::PARAMS_URL, client_state::STATE_URL, client_states::STATES_URL,
+            client_status::STATUS_URL, consensus_state::CONSENSUS_STATE_URL,
+            consensus_state_heights::CONSENSUS_STATE_HEIGHTS_URL,
+            consensus_states::CONSENSUS_STATES_URL,
+        },
+        types::query::QueryClientStatesResponse,
+    },
+    ics20_transfer::client::cli::query::DENOM_TRACE_URL,
+    keeper::Keeper,
+    message::Message,
+    types::genesis::GenesisState,
 };
 use gears::{
     application::handlers::node::{ModuleInfo, TxError},
-    baseapp::errors::QueryError,
-    context::{init::InitContext, query::QueryContext, tx::TxContext},
+    baseapp::{errors::QueryError, QueryRequest, QueryResponse},
+    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
     core::errors::CoreError,
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
+    x::{
+        keepers::{gov::GovernanceBankKeeper, mint::MintingBankKeeper},
+        module::Module,
+    },
 };
+use ibc::apps::transfer::types::proto::transfer::v1::{
+    QueryDenomTraceRequest, QueryDenomTraceResponse, QueryDenomTracesRequest,
+    QueryDenomTracesResponse, QueryParamsRequest as QueryTransferParamsRequest,
+    QueryParamsResponse as QueryTransferParamsResponse,
+};
+use ibc::core::channel::types::proto::v1::{
+    QueryChannelRequest, QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
+    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
+    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+};
+use ibc::core::client::types::proto::v1::{
+    QueryClientParamsRequest, QueryClientParamsResponse, QueryClientStateRequest,
+    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
+    QueryConsensusStatesRequest, QueryConsensusStatesResponse,
+};
+use ibc::core::connection::types::proto::v1::{
+    QueryClientConnectionsRequest, QueryClientConnectionsResponse, QueryConnectionRequest,
+    QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
+};
+use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::primitives::proto::Protobuf;
-//use ibc::core::client::types::{
-//    msgs::{MsgUpdateClient, MsgUpgradeClient},
-//    proto::v1::MsgRecoverClient,
-//};
 use prost::Message as ProstMessage;
+use serde::Serialize;
+
+#[derive(Clone, Debug)]
+pub enum IbcNodeQueryRequest {
+    ClientState(QueryClientStateRequest),
+    ClientStates(QueryClientStatesRequest),
+    ClientStatus(QueryClientStatusRequest),
+    ConsensusState(QueryConsensusStateRequest),
+    ConsensusStates(QueryConsensusStatesRequest),
+    ConsensusStateHeights(QueryConsensusStateHeightsRequest),
+    ClientParams(QueryClientParamsRequest),
+    Connection(QueryConnectionRequest),
+    Connections(QueryConnectionsRequest),
+    ClientConnections(QueryClientConnectionsRequest),
+    Channel(QueryChannelRequest),
+    Channels(QueryChannelsRequest),
+    ConnectionChannels(QueryConnectionChannelsRequest),
+    PacketCommitment(QueryPacketCommitmentRequest),
+    PacketCommitments(QueryPacketCommitmentsRequest),
+    PacketReceipt(QueryPacketReceiptRequest),
+    PacketAcknowledgement(QueryPacketAcknowledgementRequest),
+    NextSequenceReceive(QueryNextSequenceReceiveRequest),
+    DenomTrace(QueryDenomTraceRequest),
+    DenomTraces(QueryDenomTracesRequest),
+    TransferParams(QueryTransferParamsRequest),
+}
+
+impl QueryRequest for IbcNodeQueryRequest {
+    fn height(&self) -> u32 {
+        0
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum IbcNodeQueryResponse {
+    ClientState(QueryClientStateResponse),
+    ClientStates(QueryClientStatesResponse),
+    ClientStatus(QueryClientStatusResponse),
+    ConsensusState(QueryConsensusStateResponse),
+    ConsensusStates(QueryConsensusStatesResponse),
+    ConsensusStateHeights(QueryConsensusStateHeightsResponse),
+    ClientParams(QueryClientParamsResponse),
+    Connection(QueryConnectionResponse),
+    Connections(QueryConnectionsResponse),
+    ClientConnections(QueryClientConnectionsResponse),
+    Channel(QueryChannelResponse),
+    Channels(QueryChannelsResponse),
+    ConnectionChannels(QueryConnectionChannelsResponse),
+    PacketCommitment(QueryPacketCommitmentResponse),
+    PacketCommitments(QueryPacketCommitmentsResponse),
+    PacketReceipt(QueryPacketReceiptResponse),
+    PacketAcknowledgement(QueryPacketAcknowledgementResponse),
+    NextSequenceReceive(QueryNextSequenceReceiveResponse),
+    DenomTrace(QueryDenomTraceResponse),
+    DenomTraces(QueryDenomTracesResponse),
+    TransferParams(QueryTransferParamsResponse),
+}
+
+impl QueryResponse for IbcNodeQueryResponse {
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::ClientState(res) => res.encode_to_vec(),
+            Self::ClientStates(res) => res.encode_vec(),
+            Self::ClientStatus(res) => res.encode_to_vec(),
+            Self::ConsensusState(res) => res.encode_to_vec(),
+            Self::ConsensusStates(res) => res.encode_to_vec(),
+            Self::ConsensusStateHeights(res) => res.encode_to_vec(),
+            Self::ClientParams(res) => res.encode_to_vec(),
+            Self::Connection(res) => res.encode_to_vec(),
+            Self::Connections(res) => res.encode_to_vec(),
+            Self::ClientConnections(res) => res.encode_to_vec(),
+            Self::Channel(res) => res.encode_to_vec(),
+            Self::Channels(res) => res.encode_to_vec(),
+            Self::ConnectionChannels(res) => res.encode_to_vec(),
+            Self::PacketCommitment(res) => res.encode_to_vec(),
+            Self::PacketCommitments(res) => res.encode_to_vec(),
+            Self::PacketReceipt(res) => res.encode_to_vec(),
+            Self::PacketAcknowledgement(res) => res.encode_to_vec(),
+            Self::NextSequenceReceive(res) => res.encode_to_vec(),
+            Self::DenomTrace(res) => res.encode_to_vec(),
+            Self::DenomTraces(res) => res.encode_to_vec(),
+            Self::TransferParams(res) => res.encode_to_vec(),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
-pub struct ABCIHandler<SK: StoreKey, PSK: ParamsSubspaceKey, MI> {
+pub struct ABCIHandler<SK: StoreKey, PSK: ParamsSubspaceKey, BK, M, MI> {
     //tx_keeper: TxKeeper<SK, PSK>, // TODO: Should signature for Handler always be &self or allow &mut self?
     //query_keeper: QueryKeeper<SK, PSK>,
-    keeper: Keeper<SK, PSK>,
+    keeper: Keeper<SK, PSK, BK, M>,
     _marker: PhantomData<MI>,
 }
 
-impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK, MI> {
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: Module,
+        MI: ModuleInfo,
+    > ABCIHandler<SK, PSK, BK, M, MI>
+{
     // pub fn new(tx_keeper: TxKeeper<SK, PSK>, query_keeper: QueryKeeper<SK, PSK>) -> Self {
     //     Self {
     //         tx_keeper,
@@ -35,7 +165,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
     //     }
     // }
 
-    pub fn new(keeper: Keeper<SK, PSK>) -> Self {
+    pub fn new(keeper: Keeper<SK, PSK, BK, M>) -> Self {
         Self {
             keeper,
             _marker: PhantomData,
@@ -61,72 +191,54 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
 
                 self.keeper
                     .client_create(ctx, msg)
-                    .map_err(|e| TxError::new::<MI>(e.to_string(), nz::u16!(1)))?;
+                    .map_err(|e| IbcTxError::from(e).into::<MI>())?;
 
                 Ok(())
-            } // Message::ClientUpdate(msg) => {
-              //     let MsgUpdateClient {
-              //         client_id,
-              //         client_message,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper
-              //         .client_update(ctx, &client_id, client_message)?;
-
-              //     Ok(())
-              // }
-              // Message::ClientUpgrade(msg) => {
-              //     let MsgUpgradeClient {
-              //         client_id,
-              //         upgraded_client_state,
-              //         upgraded_consensus_state,
-              //         proof_upgrade_client,
-              //         proof_upgrade_consensus_state,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper.client_upgrade(
-              //         ctx,
-              //         &client_id,
-              //         upgraded_client_state,
-              //         upgraded_consensus_state,
-              //         proof_upgrade_client,
-              //         proof_upgrade_consensus_state,
-              //     )?;
-
-              //     Ok(())
-              // }
-              // Message::RecoverClient(msg) => {
-              //     let MsgRecoverClient {
-              //         subject_client_id,
-              //         substitute_client_id,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper
-              //         .recover_client(ctx, &subject_client_id, &substitute_client_id)?;
-
-              //     Ok(())
-              // }
+            }
+            Message::ClientUpdate(msg) => self.dispatch(ctx, msg),
+            Message::ClientUpgrade(msg) => self.dispatch(ctx, msg),
+            Message::SubmitMisbehaviour(msg) => self.dispatch(ctx, msg),
+            Message::ConnectionOpenInit(msg) => self.dispatch(ctx, msg),
+            Message::ConnectionOpenTry(msg) => self.dispatch(ctx, msg),
+            Message::ConnectionOpenAck(msg) => self.dispatch(ctx, msg),
+            Message::ConnectionOpenConfirm(msg) => self.dispatch(ctx, msg),
+            Message::ChannelOpenInit(msg) => self.dispatch(ctx, msg),
+            Message::ChannelOpenTry(msg) => self.dispatch(ctx, msg),
+            Message::ChannelOpenAck(msg) => self.dispatch(ctx, msg),
+            Message::ChannelOpenConfirm(msg) => self.dispatch(ctx, msg),
+            Message::ChannelCloseInit(msg) => self.dispatch(ctx, msg),
+            Message::ChannelCloseConfirm(msg) => self.dispatch(ctx, msg),
+            Message::RecvPacket(msg) => self.dispatch(ctx, msg),
+            Message::Acknowledgement(msg) => self.dispatch(ctx, msg),
+            Message::Timeout(msg) => self.dispatch(ctx, msg),
+            Message::TimeoutOnClose(msg) => self.dispatch(ctx, msg),
+            Message::Transfer(msg) => self
+                .keeper
+                .transfer(ctx, msg)
+                .map_err(|e| IbcTxError::from(e).into::<MI>()),
         }
     }
 
+    fn dispatch<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: impl Into<MsgEnvelope>,
+    ) -> Result<(), TxError> {
+        self.keeper
+            .dispatch(ctx, msg.into())
+            .map_err(|e| IbcTxError::from(e).into::<MI>())
+    }
+
     pub fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, SK>,
         query: gears::tendermint::types::request::query::RequestQuery,
     ) -> Result<Vec<u8>, QueryError> {
         match query.path.as_str() {
-            // "/ibc.core.client.v1.Query/ClientParams" => {
-            //     //Ok(self.query_keeper.client_params(ctx)?.encode_vec().into())
-            //     Ok(vec![].into())
-            // }
-            // "/ibc.core.client.v1.Query/UpgradedClientState" => Ok(self
-            //     .query_keeper
-            //     .client_state(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
+            STATE_URL => Ok(self
+                .keeper
+                .client_state(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
             STATES_URL => Ok(self
                 .keeper
                 .client_states(
@@ -135,30 +247,170 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
                         .map_err(|e| QueryError::Proto(e.to_string()))?,
                 )
                 .encode_vec()),
-            // "/ibc.core.client.v1.Query/ClientStatus" => Ok(self
-            //     .query_keeper
-            //     .client_status(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
-            // "/ibc.core.client.v1.Query/ConsensusStateHeights" => Ok(self
-            //     .query_keeper
-            //     .consensus_state_heights(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
-            // "/ibc.core.client.v1.Query/ConsensusState" => Ok(self
-            //     .query_keeper
-            //     .consensus_state(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
-            // "/ibc.core.client.v1.Query/ConsensusStates" => Ok(self
-            //     .query_keeper
-            //     .consensus_states(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
+            STATUS_URL => Ok(self
+                .keeper
+                .client_status(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            CONSENSUS_STATE_URL => Ok(self
+                .keeper
+                .consensus_state(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            CONSENSUS_STATES_URL => Ok(self
+                .keeper
+                .consensus_states(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            CONSENSUS_STATE_HEIGHTS_URL => Ok(self
+                .keeper
+                .consensus_state_heights(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            PARAMS_URL => Ok(self
+                .keeper
+                .client_params(ctx, ProstMessage::decode(query.data)?)
+                .encode_to_vec()),
+            "/ibc.core.connection.v1.Query/Connection" => Ok(self
+                .keeper
+                .connection(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.connection.v1.Query/Connections" => Ok(self
+                .keeper
+                .connections(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.connection.v1.Query/ClientConnections" => Ok(self
+                .keeper
+                .client_connections(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/Channel" => Ok(self
+                .keeper
+                .channel(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/Channels" => Ok(self
+                .keeper
+                .channels(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/ConnectionChannels" => Ok(self
+                .keeper
+                .connection_channels(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/PacketCommitment" => Ok(self
+                .keeper
+                .packet_commitment(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/PacketCommitments" => Ok(self
+                .keeper
+                .packet_commitments(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/PacketReceipt" => Ok(self
+                .keeper
+                .packet_receipt(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/PacketAcknowledgement" => Ok(self
+                .keeper
+                .packet_acknowledgement(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            "/ibc.core.channel.v1.Query/NextSequenceReceive" => Ok(self
+                .keeper
+                .next_sequence_receive(ctx, ProstMessage::decode(query.data)?)?
+                .encode_to_vec()),
+            DENOM_TRACE_URL => Ok(self
+                .keeper
+                .denom_trace(ctx, ProstMessage::decode(query.data)?)
+                .encode_to_vec()),
+            "/ibc.applications.transfer.v1.Query/DenomTraces" => Ok(self
+                .keeper
+                .denom_traces(ctx, ProstMessage::decode(query.data)?)
+                .encode_to_vec()),
+            "/ibc.applications.transfer.v1.Query/Params" => Ok(self
+                .keeper
+                .transfer_params(ProstMessage::decode(query.data)?)
+                .encode_to_vec()),
             _ => Err(QueryError::PathNotFound),
         }
     }
 
+    pub fn typed_query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: IbcNodeQueryRequest,
+    ) -> Result<IbcNodeQueryResponse, QueryError> {
+        let res = match query {
+            IbcNodeQueryRequest::ClientState(req) => {
+                IbcNodeQueryResponse::ClientState(self.keeper.client_state(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ClientStates(req) => {
+                IbcNodeQueryResponse::ClientStates(self.keeper.client_states(ctx, req))
+            }
+            IbcNodeQueryRequest::ClientStatus(req) => {
+                IbcNodeQueryResponse::ClientStatus(self.keeper.client_status(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ConsensusState(req) => {
+                IbcNodeQueryResponse::ConsensusState(self.keeper.consensus_state(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ConsensusStates(req) => {
+                IbcNodeQueryResponse::ConsensusStates(self.keeper.consensus_states(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ConsensusStateHeights(req) => {
+                IbcNodeQueryResponse::ConsensusStateHeights(
+                    self.keeper.consensus_state_heights(ctx, req)?,
+                )
+            }
+            IbcNodeQueryRequest::ClientParams(req) => {
+                IbcNodeQueryResponse::ClientParams(self.keeper.client_params(ctx, req))
+            }
+            IbcNodeQueryRequest::Connection(req) => {
+                IbcNodeQueryResponse::Connection(self.keeper.connection(ctx, req)?)
+            }
+            IbcNodeQueryRequest::Connections(req) => {
+                IbcNodeQueryResponse::Connections(self.keeper.connections(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ClientConnections(req) => {
+                IbcNodeQueryResponse::ClientConnections(self.keeper.client_connections(ctx, req)?)
+            }
+            IbcNodeQueryRequest::Channel(req) => {
+                IbcNodeQueryResponse::Channel(self.keeper.channel(ctx, req)?)
+            }
+            IbcNodeQueryRequest::Channels(req) => {
+                IbcNodeQueryResponse::Channels(self.keeper.channels(ctx, req)?)
+            }
+            IbcNodeQueryRequest::ConnectionChannels(req) => {
+                IbcNodeQueryResponse::ConnectionChannels(self.keeper.connection_channels(ctx, req)?)
+            }
+            IbcNodeQueryRequest::PacketCommitment(req) => {
+                IbcNodeQueryResponse::PacketCommitment(self.keeper.packet_commitment(ctx, req)?)
+            }
+            IbcNodeQueryRequest::PacketCommitments(req) => {
+                IbcNodeQueryResponse::PacketCommitments(self.keeper.packet_commitments(ctx, req)?)
+            }
+            IbcNodeQueryRequest::PacketReceipt(req) => {
+                IbcNodeQueryResponse::PacketReceipt(self.keeper.packet_receipt(ctx, req)?)
+            }
+            IbcNodeQueryRequest::PacketAcknowledgement(req) => {
+                IbcNodeQueryResponse::PacketAcknowledgement(
+                    self.keeper.packet_acknowledgement(ctx, req)?,
+                )
+            }
+            IbcNodeQueryRequest::NextSequenceReceive(req) => {
+                IbcNodeQueryResponse::NextSequenceReceive(
+                    self.keeper.next_sequence_receive(ctx, req)?,
+                )
+            }
+            IbcNodeQueryRequest::DenomTrace(req) => {
+                IbcNodeQueryResponse::DenomTrace(self.keeper.denom_trace(ctx, req))
+            }
+            IbcNodeQueryRequest::DenomTraces(req) => {
+                IbcNodeQueryResponse::DenomTraces(self.keeper.denom_traces(ctx, req))
+            }
+            IbcNodeQueryRequest::TransferParams(req) => {
+                IbcNodeQueryResponse::TransferParams(self.keeper.transfer_params(req))
+            }
+        };
+
+        Ok(res)
+    }
+
+    pub fn begin_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        self.keeper.begin_block(ctx)
+    }
+
     pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
         self.keeper.init_genesis(ctx, genesis)
     }
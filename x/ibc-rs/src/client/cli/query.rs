@@ -11,6 +11,10 @@ use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use crate::ics02_client::client::cli::query::{ClientQuery, ClientQueryCli, ClientQueryResponse};
+use crate::ics20_transfer::client::cli::query::{self as transfer_query, TransferQueryCli};
+use ibc::apps::transfer::types::proto::transfer::v1::{
+    QueryDenomTraceRequest, QueryDenomTraceResponse,
+};
 
 /// Querying commands for the ibc module
 #[derive(Args, Debug)]
@@ -22,23 +26,27 @@ pub struct IbcQueryCli {
 #[derive(Subcommand, Debug, Clone)]
 pub enum IbcQueryCommands {
     Client(ClientQueryCli),
+    Transfer(TransferQueryCli),
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum IbcQuery {
     Client(ClientQuery),
+    DenomTrace(QueryDenomTraceRequest),
 }
 
 impl Query for IbcQuery {
     fn query_url(&self) -> &'static str {
         match self {
             IbcQuery::Client(query) => query.query_url(),
+            IbcQuery::DenomTrace(_) => transfer_query::DENOM_TRACE_URL,
         }
     }
 
     fn into_bytes(self) -> Vec<u8> {
         match self {
             IbcQuery::Client(query) => query.into_bytes(),
+            IbcQuery::DenomTrace(query) => query.encode_to_vec(),
         }
     }
 }
@@ -47,6 +55,7 @@ impl Query for IbcQuery {
 #[serde(untagged)]
 pub enum IbcQueryResponse {
     Client(ClientQueryResponse),
+    DenomTrace(QueryDenomTraceResponse),
 }
 
 impl IbcQueryResponse {
@@ -61,6 +70,7 @@ impl IbcQueryResponse {
                 ClientQueryResponse::ConsensusStates(q) => q.encode_to_vec(),
                 ClientQueryResponse::ConsensusStateHeights(q) => q.encode_to_vec(),
             },
+            IbcQueryResponse::DenomTrace(q) => q.encode_to_vec(),
         }
     }
 }
@@ -81,6 +91,9 @@ impl QueryHandler for IbcQueryHandler {
             IbcQueryCommands::Client(command) => {
                 Self::QueryRequest::Client(ClientQueryHandler.prepare_query_request(command)?)
             }
+            IbcQueryCommands::Transfer(command) => {
+                Self::QueryRequest::DenomTrace(transfer_query::handle_query(command))
+            }
         };
 
         Ok(res)
@@ -95,6 +108,9 @@ impl QueryHandler for IbcQueryHandler {
             IbcQueryCommands::Client(command) => Self::QueryResponse::Client(
                 ClientQueryHandler.handle_raw_response(query_bytes, command)?,
             ),
+            IbcQueryCommands::Transfer(_) => Self::QueryResponse::DenomTrace(
+                QueryDenomTraceResponse::decode(query_bytes.as_slice())?,
+            ),
         };
 
         Ok(res)
@@ -4,6 +4,10 @@ use gears::types::address::AccAddress;
 
 use crate::{ics02_client::client::cli::tx::ClientTxCli, message::Message as IbcMessage};
 
+pub use crate::ics20_transfer::client::cli::tx::{
+    run_transfer_tx_command, TransferCommands, TransferTxCli,
+};
+
 #[derive(Args, Debug, Clone)]
 pub struct IbcTxCli {
     #[command(subcommand)]
@@ -0,0 +1,192 @@
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::block_height,
+};
+use ibc_proto::ibc::core::client::v1::{
+    query_server::{Query, QueryServer},
+    QueryClientParamsRequest, QueryClientParamsResponse, QueryClientStateRequest,
+    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatesResponse,
+    QueryClientStatusRequest, QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
+    QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
+    QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
+    QueryUpgradedConsensusStateResponse,
+};
+use std::marker::PhantomData;
+use tonic::{Request, Response, Status};
+
+use crate::{
+    ics02_client::types::query::RawQueryClientStatesResponse, IbcNodeQueryRequest,
+    IbcNodeQueryResponse,
+};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+const UNIMPLEMENTED_MSG: &str = "Unimplemented";
+
+/// The gRPC server is generated from the `ibc-proto` crate of the workspace while the
+/// queries use the types of the `ibc` crate. Both are generated from the same proto files,
+/// so a message is converted by re-encoding it.
+fn convert<T: prost::Message, U: prost::Message + Default>(msg: T) -> Result<U, Status> {
+    U::decode(msg.encode_to_vec().as_slice()).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+#[derive(Debug, Default)]
+pub struct IbcClientService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> IbcClientService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<IbcNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: IbcNodeQueryRequest,
+        height: u32,
+    ) -> Result<IbcNodeQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+#[tonic::async_trait]
+impl<
+        QReq: Send + Sync + 'static,
+        QRes: Send + Sync + 'static,
+        QH: NodeQueryHandler<QReq, QRes>,
+    > Query for IbcClientService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<IbcNodeQueryResponse, Error = Status>,
+{
+    async fn client_state(
+        &self,
+        request: Request<QueryClientStateRequest>,
+    ) -> Result<Response<QueryClientStateResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ClientState(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ClientState(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn client_states(
+        &self,
+        request: Request<QueryClientStatesRequest>,
+    ) -> Result<Response<QueryClientStatesResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ClientStates(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ClientStates(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(RawQueryClientStatesResponse::from(
+                response,
+            ))?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn consensus_state(
+        &self,
+        request: Request<QueryConsensusStateRequest>,
+    ) -> Result<Response<QueryConsensusStateResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ConsensusState(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ConsensusState(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn consensus_states(
+        &self,
+        request: Request<QueryConsensusStatesRequest>,
+    ) -> Result<Response<QueryConsensusStatesResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ConsensusStates(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ConsensusStates(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn consensus_state_heights(
+        &self,
+        request: Request<QueryConsensusStateHeightsRequest>,
+    ) -> Result<Response<QueryConsensusStateHeightsResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ConsensusStateHeights(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ConsensusStateHeights(response) =
+            self.typed_query(req, height)?
+        {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn client_status(
+        &self,
+        request: Request<QueryClientStatusRequest>,
+    ) -> Result<Response<QueryClientStatusResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ClientStatus(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ClientStatus(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn client_params(
+        &self,
+        request: Request<QueryClientParamsRequest>,
+    ) -> Result<Response<QueryClientParamsResponse>, Status> {
+        let height = block_height(&request)?;
+        let req = IbcNodeQueryRequest::ClientParams(convert(request.into_inner())?);
+
+        if let IbcNodeQueryResponse::ClientParams(response) = self.typed_query(req, height)? {
+            Ok(Response::new(convert(response)?))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn upgraded_client_state(
+        &self,
+        _request: Request<QueryUpgradedClientStateRequest>,
+    ) -> Result<Response<QueryUpgradedClientStateResponse>, Status> {
+        Err(Status::unimplemented(UNIMPLEMENTED_MSG))
+    }
+
+    async fn upgraded_consensus_state(
+        &self,
+        _request: Request<QueryUpgradedConsensusStateRequest>,
+    ) -> Result<Response<QueryUpgradedConsensusStateResponse>, Status> {
+        Err(Status::unimplemented(UNIMPLEMENTED_MSG))
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> QueryServer<IbcClientService<QH, QReq, QRes>>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<IbcNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    let ibc_client_service = IbcClientService {
+        app,
+        _phantom: Default::default(),
+    };
+    QueryServer::new(ibc_client_service)
+}
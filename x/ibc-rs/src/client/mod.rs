@@ -1,2 +1,3 @@
 pub mod cli;
-//pub mod rest; //TODO: add rest module back in
+pub mod grpc;
+pub mod rest;
@@ -0,0 +1,440 @@
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, RestState},
+};
+use ibc::apps::transfer::types::proto::transfer::v1::{
+    QueryDenomTraceRequest, QueryDenomTracesRequest,
+    QueryParamsRequest as QueryTransferParamsRequest,
+};
+use ibc::core::channel::types::proto::v1::{
+    QueryChannelRequest, QueryChannelsRequest, QueryConnectionChannelsRequest,
+    QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentsRequest, QueryPacketReceiptRequest,
+};
+use ibc::core::client::types::proto::v1::{
+    QueryClientParamsRequest, QueryClientStateRequest, QueryClientStatesRequest,
+    QueryClientStatusRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
+    QueryConsensusStatesRequest,
+};
+use ibc::core::connection::types::proto::v1::{
+    QueryClientConnectionsRequest, QueryConnectionRequest, QueryConnectionsRequest,
+};
+
+use crate::IbcNodeQueryRequest;
+
+/// Queries all the IBC light clients of a chain
+pub async fn client_states<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ClientStates(QueryClientStatesRequest { pagination: None });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries an IBC light client
+pub async fn client_state<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(client_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ClientState(QueryClientStateRequest { client_id });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the status of an IBC light client
+pub async fn client_status<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(client_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ClientStatus(QueryClientStatusRequest { client_id });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries all the consensus states of a client
+pub async fn consensus_states<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(client_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ConsensusStates(QueryConsensusStatesRequest {
+        client_id,
+        pagination: None,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the heights of all the consensus states of a client
+pub async fn consensus_state_heights<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(client_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ConsensusStateHeights(QueryConsensusStateHeightsRequest {
+        client_id,
+        pagination: None,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the consensus state of a client at the given height
+pub async fn consensus_state<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((client_id, revision_number, revision_height)): Path<(String, u64, u64)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ConsensusState(QueryConsensusStateRequest {
+        client_id,
+        revision_number,
+        revision_height,
+        latest_height: false,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the parameters of the IBC client submodule
+pub async fn client_params<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ClientParams(QueryClientParamsRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries all the connection ends of a chain
+pub async fn connections<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::Connections(QueryConnectionsRequest { pagination: None });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries a connection end
+pub async fn connection<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(connection_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::Connection(QueryConnectionRequest { connection_id });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the connections built on top of a client
+pub async fn client_connections<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(client_id): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ClientConnections(QueryClientConnectionsRequest { client_id });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries all the channel ends of a chain
+pub async fn channels<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::Channels(QueryChannelsRequest { pagination: None });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries a channel end
+pub async fn channel<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id)): Path<(String, String)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::Channel(QueryChannelRequest {
+        port_id,
+        channel_id,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the channel ends whose first hop is the connection
+pub async fn connection_channels<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(connection): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::ConnectionChannels(QueryConnectionChannelsRequest {
+        connection,
+        pagination: None,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the commitments of the packets sent on a channel which aren't acknowledged or
+/// timed out yet
+pub async fn packet_commitments<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id)): Path<(String, String)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::PacketCommitments(QueryPacketCommitmentsRequest {
+        port_id,
+        channel_id,
+        pagination: None,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the commitment of a packet sent on a channel
+pub async fn packet_commitment<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id, sequence)): Path<(String, String, u64)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::PacketCommitment(QueryPacketCommitmentRequest {
+        port_id,
+        channel_id,
+        sequence,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries whether a packet was received on a channel
+pub async fn packet_receipt<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id, sequence)): Path<(String, String, u64)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::PacketReceipt(QueryPacketReceiptRequest {
+        port_id,
+        channel_id,
+        sequence,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the acknowledgement commitment of a packet received on a channel
+pub async fn packet_acknowledgement<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id, sequence)): Path<(String, String, u64)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::PacketAcknowledgement(QueryPacketAcknowledgementRequest {
+        port_id,
+        channel_id,
+        sequence,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the sequence of the next packet which a channel expects to receive
+pub async fn next_sequence_receive<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((channel_id, port_id)): Path<(String, String)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::NextSequenceReceive(QueryNextSequenceReceiveRequest {
+        port_id,
+        channel_id,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the traces of all vouchers
+pub async fn denom_traces<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::DenomTraces(QueryDenomTracesRequest { pagination: None });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the trace of a voucher, the hash may be given with or without the `ibc/` prefix
+pub async fn denom_trace<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(hash): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::DenomTrace(QueryDenomTraceRequest { hash });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+/// Queries the parameters of the transfer module
+pub async fn transfer_params<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = IbcNodeQueryRequest::TransferParams(QueryTransferParamsRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<IbcNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/core/client/v1/client_states", get(client_states))
+        .route("/core/client/v1/client_states/:client_id", get(client_state))
+        .route("/core/client/v1/client_status/:client_id", get(client_status))
+        .route(
+            "/core/client/v1/consensus_states/:client_id",
+            get(consensus_states),
+        )
+        .route(
+            "/core/client/v1/consensus_states/:client_id/heights",
+            get(consensus_state_heights),
+        )
+        .route(
+            "/core/client/v1/consensus_states/:client_id/revision/:revision_number/height/:revision_height",
+            get(consensus_state),
+        )
+        .route("/core/client/v1/params", get(client_params))
+        .route("/core/connection/v1/connections", get(connections))
+        .route(
+            "/core/connection/v1/connections/:connection_id",
+            get(connection),
+        )
+        .route(
+            "/core/connection/v1/client_connections/:client_id",
+            get(client_connections),
+        )
+        .route("/core/channel/v1/channels", get(channels))
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id",
+            get(channel),
+        )
+        .route(
+            "/core/channel/v1/connections/:connection/channels",
+            get(connection_channels),
+        )
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id/packet_commitments",
+            get(packet_commitments),
+        )
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id/packet_commitments/:sequence",
+            get(packet_commitment),
+        )
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id/packet_receipts/:sequence",
+            get(packet_receipt),
+        )
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id/packet_acks/:sequence",
+            get(packet_acknowledgement),
+        )
+        .route(
+            "/core/channel/v1/channels/:channel_id/ports/:port_id/next_sequence",
+            get(next_sequence_receive),
+        )
+        .route("/apps/transfer/v1/denom_traces", get(denom_traces))
+        // the hash may be prefixed with `ibc/`
+        .route("/apps/transfer/v1/denom_traces/*hash", get(denom_trace))
+        .route("/apps/transfer/v1/params", get(transfer_params))
+}
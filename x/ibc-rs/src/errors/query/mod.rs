@@ -1 +1,24 @@
 pub mod client;
+
+use gears::baseapp::errors::QueryError;
+use ibc::core::{client::types::error::ClientError, host::types::error::IdentifierError};
+
+/// Errors of the queries of the ibc keeper
+#[derive(Debug, thiserror::Error)]
+pub enum IbcQueryError {
+    #[error("invalid identifier: {0}")]
+    Identifier(#[from] IdentifierError),
+    #[error("invalid height: {0}")]
+    Height(#[from] ClientError),
+    #[error("no block is committed at the queried height")]
+    NotCommitted,
+}
+
+impl From<IbcQueryError> for QueryError {
+    fn from(value: IbcQueryError) -> Self {
+        match value {
+            IbcQueryError::NotCommitted => Self::InvalidHeight,
+            _ => Self::Proto(value.to_string()),
+        }
+    }
+}
@@ -1 +1,30 @@
 pub mod client;
+
+use gears::application::handlers::node::{ModuleInfo, TxError};
+use ibc::{
+    apps::transfer::types::error::TokenTransferError, core::handler::types::error::ContextError,
+};
+
+/// Errors of the ibc messages
+#[derive(Debug, thiserror::Error)]
+pub enum IbcTxError {
+    #[error(transparent)]
+    Context(#[from] ContextError),
+    #[error(transparent)]
+    Transfer(#[from] TokenTransferError),
+}
+
+impl IbcTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        let code = match &self {
+            IbcTxError::Context(ContextError::ClientError(_)) => nz::u16!(1),
+            IbcTxError::Context(ContextError::ConnectionError(_)) => nz::u16!(2),
+            IbcTxError::Context(ContextError::ChannelError(_)) => nz::u16!(3),
+            IbcTxError::Context(ContextError::PacketError(_)) => nz::u16!(4),
+            IbcTxError::Context(ContextError::RouterError(_)) => nz::u16!(5),
+            IbcTxError::Transfer(_) => nz::u16!(6),
+        };
+
+        TxError::new::<MI>(self.to_string(), code)
+    }
+}
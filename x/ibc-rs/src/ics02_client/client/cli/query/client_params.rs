@@ -1,7 +1,5 @@
 use clap::Args;
-use ibc::core::{
-    client::types::proto::v1::QueryClientParamsRequest, host::types::identifiers::ClientId,
-};
+use ibc::core::client::types::proto::v1::QueryClientParamsRequest;
 
 // use proto_messages::cosmos::ibc::types::core::{
 //     client::context::types::proto::v1::QueryClientParamsRequest, host::identifiers::ClientId,
@@ -11,10 +9,8 @@ pub(crate) const PARAMS_URL: &str = "/ibc.core.client.v1.Query/ClientParams";
 
 /// Query the current ibc client parameters
 #[derive(Args, Debug, Clone)]
-pub struct CliClientParams {
-    client_id: ClientId,
-}
+pub struct CliClientParams;
 
 pub(crate) fn handle_query(_args: &CliClientParams) -> QueryClientParamsRequest {
-    QueryClientParamsRequest {} // TODO: is this struct missing something? Like client_id?
+    QueryClientParamsRequest {}
 }
@@ -0,0 +1,20 @@
+use clap::Args;
+use ibc::core::{
+    client::types::proto::v1::QueryConsensusStateHeightsRequest, host::types::identifiers::ClientId,
+};
+
+pub(crate) const CONSENSUS_STATE_HEIGHTS_URL: &str =
+    "/ibc.core.client.v1.Query/ConsensusStateHeights";
+
+/// Query the heights of all the consensus states of a client
+#[derive(Args, Debug, Clone)]
+pub struct CliConsensusStateHeights {
+    client_id: ClientId,
+}
+
+pub(crate) fn handle_query(args: &CliConsensusStateHeights) -> QueryConsensusStateHeightsRequest {
+    QueryConsensusStateHeightsRequest {
+        client_id: args.client_id.to_string(),
+        pagination: None,
+    }
+}
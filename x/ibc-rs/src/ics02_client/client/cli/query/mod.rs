@@ -5,8 +5,9 @@ use gears::baseapp::Query;
 use ibc::core::client::types::proto::v1::{
     QueryClientParamsRequest, QueryClientParamsResponse, QueryClientStateRequest,
     QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatusRequest,
-    QueryClientStatusResponse, QueryConsensusStateHeightsResponse, QueryConsensusStateRequest,
-    QueryConsensusStateResponse, QueryConsensusStatesRequest, QueryConsensusStatesResponse,
+    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
+    QueryConsensusStatesRequest, QueryConsensusStatesResponse,
 };
 use prost::Message;
 
@@ -17,7 +18,7 @@ use crate::ics02_client::types::query::QueryClientStatesResponse;
 use self::{
     client_params::PARAMS_URL, client_state::STATE_URL, client_states::STATES_URL,
     client_status::STATUS_URL, consensus_state::CONSENSUS_STATE_URL,
-    consensus_states::CONSENSUS_STATES_URL,
+    consensus_state_heights::CONSENSUS_STATE_HEIGHTS_URL, consensus_states::CONSENSUS_STATES_URL,
 };
 
 pub mod client_params;
@@ -25,6 +26,7 @@ pub mod client_state;
 pub mod client_states;
 pub mod client_status;
 pub mod consensus_state;
+pub mod consensus_state_heights;
 pub mod consensus_states;
 pub mod query_handler;
 #[allow(dead_code)]
@@ -53,6 +55,8 @@ pub enum ClientQueryCommands {
     ConsensusState(consensus_state::CliConsensusState),
     #[command(name = "consensus-states")]
     ConsensusStates(consensus_states::CliConsensusStates),
+    #[command(name = "consensus-state-heights")]
+    ConsensusStateHeights(consensus_state_heights::CliConsensusStateHeights),
     // Header(query_header::CliClientParams),
     // SelfConsensusState(self_consensus_state::CliClientParams),
 }
@@ -65,6 +69,7 @@ pub enum ClientQuery {
     ClientStatus(QueryClientStatusRequest),
     ConsensusState(QueryConsensusStateRequest),
     ConsensusStates(QueryConsensusStatesRequest),
+    ConsensusStateHeights(QueryConsensusStateHeightsRequest),
 }
 
 impl Query for ClientQuery {
@@ -76,6 +81,7 @@ impl Query for ClientQuery {
             ClientQuery::ClientStatus(_) => STATUS_URL,
             ClientQuery::ConsensusState(_) => CONSENSUS_STATE_URL,
             ClientQuery::ConsensusStates(_) => CONSENSUS_STATES_URL,
+            ClientQuery::ConsensusStateHeights(_) => CONSENSUS_STATE_HEIGHTS_URL,
         }
     }
 
@@ -87,6 +93,7 @@ impl Query for ClientQuery {
             ClientQuery::ClientStatus(var) => var.encode_to_vec(),
             ClientQuery::ConsensusState(var) => var.encode_to_vec(),
             ClientQuery::ConsensusStates(var) => var.encode_to_vec(),
+            ClientQuery::ConsensusStateHeights(var) => var.encode_to_vec(),
         }
     }
 }
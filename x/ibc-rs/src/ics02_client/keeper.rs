@@ -12,17 +12,46 @@ use gears::{
 use ibc::primitives::ToVec;
 use ibc::{core::host::types::path::ClientStatePath, primitives::proto::Protobuf};
 
-use crate::ics02_client::types::{client_state::ClientState, query::IdentifiedClientState};
-use crate::types::context::CLIENT_STATE_KEY;
+use crate::errors::{query::client::SearchError, tx::client::ClientRecoverError};
+use crate::ics02_client::types::{
+    client_state::ClientState, consensus_state::ConsensusState, query::IdentifiedClientState,
+};
+use crate::types::context::{
+    CLIENT_STATE_KEY, KEY_CONSENSUS_STATE_PREFIX, KEY_ITERATE_CONSENSUS_STATE_PREFIX,
+    KEY_PROCESSED_HEIGHT, KEY_PROCESSED_TIME,
+};
+use ibc::clients::tendermint::types::ClientState as ClientStateType;
 
-use super::{params::ClientParamsKeeper, types::query::QueryClientStatesResponse, GenesisState};
+use super::{
+    params::{ClientParams, ClientParamsKeeper},
+    types::query::QueryClientStatesResponse,
+    GenesisState,
+};
 use gears::context::{InfallibleContextMut, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
+use gears::extensions::gas::GasResultExt;
+use gears::tendermint::types::proto::event::{Event, EventAttribute};
+use gears::tendermint::types::proto::header::Header;
+use ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
+use ibc::clients::tendermint::types::{
+    proto::v1::ConsensusState as RawTmConsensusState, ConsensusState as ConsensusStateType,
+};
+use ibc::core::client::types::{error::ClientError, Height, Status};
+use ibc::core::commitment_types::proto::v1::MerkleRoot;
+use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::{
     client::types::proto::v1::QueryClientStatesRequest, host::types::identifiers::ClientId,
 };
+use ibc::primitives::proto::{Any, Timestamp as IbcProtoTimestamp};
+use prost::Message;
 
 pub const KEY_NEXT_CLIENT_SEQUENCE: &[u8; 18] = b"nextClientSequence";
 pub const KEY_CLIENT_STORE_PREFIX: &str = "clients";
+const KEY_SELF_CONSENSUS_STATE_PREFIX: &str = "selfConsensusStates";
+
+/// Number of blocks for which the consensus state of the host chain is kept. Counterparty
+/// clients can't be verified against older heights of this chain.
+pub const SELF_CONSENSUS_STATES_KEPT: u32 = 10_000;
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK, PSK> {
@@ -143,6 +172,178 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         }
     }
 
+    /// Returns the client state of the given client
+    pub fn client_state<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_id: &ClientId,
+    ) -> Result<Option<ClientState>, GasStoreErrors> {
+        let key = format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/{CLIENT_STATE_KEY}");
+        let client_state = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| ClientState::decode_vec(&raw).unwrap_or_corrupt());
+
+        Ok(client_state)
+    }
+
+    /// Returns the consensus state of the given client at the given height
+    pub fn consensus_state<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &ClientConsensusStatePath,
+    ) -> Result<Option<ConsensusState>, GasStoreErrors> {
+        let key = format!(
+            "{KEY_CLIENT_STORE_PREFIX}/{}/{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            path.client_id, path.revision_number, path.revision_height
+        );
+        let consensus_state = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| {
+                ConsensusState::try_from(
+                    <Any as Message>::decode(raw.as_slice()).unwrap_or_corrupt(),
+                )
+                .unwrap_or_corrupt()
+            });
+
+        Ok(consensus_state)
+    }
+
+    /// Returns the heights of the consensus states of the given client in ascending order
+    pub fn consensus_state_heights<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_id: &ClientId,
+    ) -> Result<Vec<Height>, GasStoreErrors> {
+        let prefix = [
+            format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/").as_bytes(),
+            KEY_ITERATE_CONSENSUS_STATE_PREFIX,
+        ]
+        .concat();
+        let store = ctx.kv_store(&self.store_key).prefix_store(prefix);
+
+        let mut heights = vec![];
+        for res in store.into_range(..) {
+            let (key, _) = res?;
+            // the key holds the big endian revision number followed by the revision height
+            let (revision_number, revision_height) = key.split_at(8);
+            let height = Height::new(
+                u64::from_be_bytes(revision_number.try_into().unwrap_or_corrupt()),
+                u64::from_be_bytes(revision_height.try_into().unwrap_or_corrupt()),
+            )
+            .unwrap_or_corrupt();
+
+            heights.push(height);
+        }
+
+        Ok(heights)
+    }
+
+    /// Returns the status of the given client at the time `now`, given in nanoseconds since
+    /// the unix epoch, or `None` if the client doesn't exist. A client expires once its
+    /// trusting period has elapsed since the time of its latest consensus state.
+    pub fn client_status<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_id: &ClientId,
+        now: i128,
+    ) -> Result<Option<Status>, GasStoreErrors> {
+        let Some(ClientState::Tendermint(client_state)) = self.client_state(ctx, client_id)? else {
+            return Ok(None);
+        };
+        let client_state = client_state.inner();
+
+        if !self
+            .params(ctx)?
+            .allowed_clients
+            .iter()
+            .any(|client_type| client_type == TENDERMINT_CLIENT_TYPE)
+        {
+            return Ok(Some(Status::Unauthorized));
+        }
+
+        if client_state.frozen_height.is_some() {
+            return Ok(Some(Status::Frozen));
+        }
+
+        let latest_height = client_state.latest_height;
+        let consensus_state = self.consensus_state(
+            ctx,
+            &ClientConsensusStatePath::new(
+                client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+        )?;
+        let Some(ConsensusState::Tendermint(consensus_state)) = consensus_state else {
+            return Ok(Some(Status::Expired));
+        };
+
+        let expiration = consensus_state.inner().timestamp.unix_timestamp_nanos()
+            + i128::try_from(client_state.trusting_period.as_nanos()).unwrap_or(i128::MAX);
+        if expiration <= now {
+            return Ok(Some(Status::Expired));
+        }
+
+        Ok(Some(Status::Active))
+    }
+
+    /// Returns the parameters of the client submodule
+    pub fn params<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<ClientParams, GasStoreErrors> {
+        self.client_params_keeper.try_get(ctx)
+    }
+
+    /// Returns the consensus state of this chain at the given height. These are stored at
+    /// the beginning of every block, see [Keeper::self_consensus_state_set].
+    pub fn self_consensus_state<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        height: u64,
+    ) -> Result<Option<ConsensusStateType>, GasStoreErrors> {
+        let key = format!("{KEY_SELF_CONSENSUS_STATE_PREFIX}/{height}");
+        let consensus_state = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| ConsensusStateType::decode_vec(&raw).unwrap_or_corrupt());
+
+        Ok(consensus_state)
+    }
+
+    /// Stores the consensus state of this chain at the height of the block being
+    /// processed and prunes the one which is [SELF_CONSENSUS_STATES_KEPT] blocks old.
+    /// Counterparty chains track this chain with clients built from the same data, the
+    /// handshakes verify these clients against the stored states.
+    pub fn self_consensus_state_set<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        header: &Header,
+    ) {
+        let raw = RawTmConsensusState {
+            timestamp: Some(IbcProtoTimestamp {
+                seconds: header.time.timestamp_seconds().into(),
+                nanos: header.time.nanoseconds().into(),
+            }),
+            root: Some(MerkleRoot {
+                hash: header.app_hash.clone(),
+            }),
+            next_validators_hash: header.next_validators_hash.clone(),
+        };
+
+        let mut store = ctx.infallible_store_mut(&self.store_key);
+        store.set(
+            format!("{KEY_SELF_CONSENSUS_STATE_PREFIX}/{}", header.height).into_bytes(),
+            raw.encode_to_vec(),
+        );
+
+        if let Some(pruned) = header.height.checked_sub(SELF_CONSENSUS_STATES_KEPT) {
+            store.delete(format!("{KEY_SELF_CONSENSUS_STATE_PREFIX}/{pruned}").as_bytes());
+        }
+    }
+
     /// Writes the client state to the store
     pub fn client_state_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
@@ -154,6 +355,130 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         store.set(CLIENT_STATE_KEY.bytes(), client_state.encode_vec())
     }
 
+    /// Replaces the frozen or expired subject client with the active substitute client,
+    /// see the `ClientUpdateProposal` of ibc-go. The client states of both must match except
+    /// for the chain id, the trusting period, the latest height and the frozen height. The
+    /// subject takes these from the substitute together with its latest consensus state.
+    pub fn recover_client<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<(), ClientRecoverError> {
+        let now = i128::from(ctx.get_time().timestamp_nanoseconds());
+
+        let subject_status = self
+            .client_status(ctx, subject_client_id, now)
+            .unwrap_gas()
+            .ok_or(SearchError::NotFound)?;
+        if subject_status == Status::Active {
+            return Err(ClientRecoverError::SubjectStatus {
+                client_id: subject_client_id.clone(),
+                status: subject_status,
+            });
+        }
+
+        let substitute_status = self
+            .client_status(ctx, substitute_client_id, now)
+            .unwrap_gas()
+            .ok_or(SearchError::NotFound)?;
+        if substitute_status != Status::Active {
+            return Err(ClientRecoverError::SubstituteStatus {
+                client_id: substitute_client_id.clone(),
+                status: substitute_status,
+            });
+        }
+
+        let subject = ClientStateType::try_from(
+            self.client_state(ctx, subject_client_id)
+                .unwrap_gas()
+                .ok_or(SearchError::NotFound)?,
+        )?;
+        let substitute = ClientStateType::try_from(
+            self.client_state(ctx, substitute_client_id)
+                .unwrap_gas()
+                .ok_or(SearchError::NotFound)?,
+        )?;
+
+        if subject.latest_height >= substitute.latest_height {
+            return Err(ClientRecoverError::InvalidHeight {
+                subject: subject.latest_height,
+                substitute: substitute.latest_height,
+            });
+        }
+
+        let mut recovered = subject.clone();
+        recovered.chain_id = substitute.chain_id.clone();
+        recovered.trusting_period = substitute.trusting_period;
+        recovered.latest_height = substitute.latest_height;
+        recovered.frozen_height = None;
+
+        let mut matching = substitute.clone();
+        matching.frozen_height = None;
+        if recovered != matching {
+            return Err(ClientError::ClientSpecific {
+                description: "subject client state does not match substitute client state"
+                    .to_owned(),
+            }
+            .into());
+        }
+
+        // the latest consensus state of the substitute and its metadata become the ones of
+        // the subject at the same height
+        let height = substitute.latest_height;
+        let consensus_state_key = format!(
+            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            height.revision_number(),
+            height.revision_height()
+        );
+        let iteration_key = [
+            KEY_ITERATE_CONSENSUS_STATE_PREFIX.as_slice(),
+            &height.revision_number().to_be_bytes(),
+            &height.revision_height().to_be_bytes(),
+        ]
+        .concat();
+        let keys = [
+            consensus_state_key.clone().into_bytes(),
+            format!("{consensus_state_key}{KEY_PROCESSED_TIME}").into_bytes(),
+            format!("{consensus_state_key}{KEY_PROCESSED_HEIGHT}").into_bytes(),
+            iteration_key,
+        ];
+
+        let substitute_prefix = format!("{KEY_CLIENT_STORE_PREFIX}/{substitute_client_id}/");
+        let subject_prefix = format!("{KEY_CLIENT_STORE_PREFIX}/{subject_client_id}/");
+        for key in keys {
+            let value = ctx
+                .infallible_store(&self.store_key)
+                .get(&[substitute_prefix.as_bytes(), &key].concat())
+                .ok_or(SearchError::NotFound)?;
+
+            ctx.infallible_store_mut(&self.store_key)
+                .set([subject_prefix.as_bytes(), &key].concat(), value);
+        }
+
+        self.client_state_set(
+            ctx,
+            ClientStatePath::new(subject_client_id.clone()),
+            recovered.into(),
+        )
+        .unwrap_gas();
+
+        ctx.push_event(Event::new(
+            "update_client_proposal",
+            [
+                EventAttribute::new(
+                    "subject_client_id".into(),
+                    subject_client_id.to_string().into(),
+                    true,
+                ),
+                EventAttribute::new("client_type".into(), TENDERMINT_CLIENT_TYPE.into(), true),
+                EventAttribute::new("consensus_height".into(), height.to_string().into(), true),
+            ],
+        ));
+
+        Ok(())
+    }
+
     /// Returns an isolated mutable prefix store for each client so they can read/write in separate
     /// namespaces without being able to read/write other client's data
     fn client_store_mut<'a, DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -8,8 +8,8 @@ use ibc::{
     core::{
         client::types::{
             error::ClientError,
-            msgs::{ClientMsg, MsgCreateClient as IBCMsgCreateClient},
-            proto::v1::MsgCreateClient as RawMsgCreateClient,
+            msgs::{self, ClientMsg, MsgCreateClient as IBCMsgCreateClient},
+            proto::v1::{self as raw, MsgCreateClient as RawMsgCreateClient},
         },
         handler::types::msgs::MsgEnvelope,
     },
@@ -17,6 +17,8 @@ use ibc::{
 };
 use serde::Serialize;
 
+use crate::types::tx::ibc_message;
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct MsgCreateClient {
     pub client_state: ClientState,
@@ -116,3 +118,27 @@ impl From<MsgCreateClient> for RawMsgCreateClient {
         }
     }
 }
+
+ibc_message!(
+    MsgUpdateClient,
+    msgs::MsgUpdateClient,
+    raw::MsgUpdateClient,
+    "/ibc.core.client.v1.MsgUpdateClient",
+    |msg| MsgEnvelope::Client(ClientMsg::UpdateClient(msg))
+);
+
+ibc_message!(
+    MsgUpgradeClient,
+    msgs::MsgUpgradeClient,
+    raw::MsgUpgradeClient,
+    "/ibc.core.client.v1.MsgUpgradeClient",
+    |msg| MsgEnvelope::Client(ClientMsg::UpgradeClient(msg))
+);
+
+ibc_message!(
+    MsgSubmitMisbehaviour,
+    msgs::MsgSubmitMisbehaviour,
+    raw::MsgSubmitMisbehaviour,
+    "/ibc.core.client.v1.MsgSubmitMisbehaviour",
+    |msg| MsgEnvelope::Client(ClientMsg::Misbehaviour(msg))
+);
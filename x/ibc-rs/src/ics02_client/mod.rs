@@ -8,4 +8,4 @@ pub mod types;
 pub use genesis::GenesisState;
 pub use keeper::Keeper;
 pub use keeper::KEY_NEXT_CLIENT_SEQUENCE; //TODO: don't export when we have a better solution
-pub use params::ClientParamsKeeper; //TODO: don't export when we have a better solution
+pub use params::{ClientParams, ClientParamsKeeper}; //TODO: don't export when we have a better solution
@@ -11,6 +11,7 @@ use gears::params::ParamKind;
 use gears::params::ParamsDeserialize;
 use gears::params::ParamsSerialize;
 use gears::params::ParamsSubspaceKey;
+use gears::params::ParamsValidationError;
 use gears::{
     context::{QueryableContext, TransactionalContext},
     store::{
@@ -55,6 +56,15 @@ impl ParamsDeserialize for ClientParams {
             .expect("conversion from json won't fail"),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match key {
+            KEY_ALLOWED_CLIENTS => serde_json::from_slice::<Vec<String>>(value)
+                .map(|_| ())
+                .map_err(|e| ParamsValidationError::invalid_value(key, e)),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
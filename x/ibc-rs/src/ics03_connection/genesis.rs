@@ -1,14 +1,26 @@
 //pub type GenesisState = ibc::core::connection::types::proto::v1::GenesisState;
 
 use gears::core::serializers::serialize_number_to_string;
-use ibc::core::connection::types::proto::v1::{ConnectionPaths, IdentifiedConnection};
+use ibc::core::{
+    connection::types::{
+        error::ConnectionError,
+        proto::v1::{ConnectionPaths, IdentifiedConnection},
+        IdentifiedConnectionEnd,
+    },
+    host::types::{
+        error::IdentifierError,
+        identifiers::{ClientId, ConnectionId},
+    },
+};
 use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_number_from_string;
 
 use super::params::ConnectionParams;
 
 /// GenesisState defines the ibc connection submodule's genesis state.
+/// The connections and the client connection paths are validated on deserialization.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "RawGenesisState")]
 pub struct GenesisState {
     pub connections: Vec<IdentifiedConnection>,
     pub client_connection_paths: Vec<ConnectionPaths>,
@@ -31,3 +43,51 @@ impl Default for GenesisState {
         }
     }
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenesisError {
+    #[error("invalid genesis connection: {0}")]
+    Connection(#[from] ConnectionError),
+    #[error("invalid genesis client connection paths: {0}")]
+    ClientConnectionPaths(#[from] IdentifierError),
+}
+
+#[derive(Deserialize)]
+struct RawGenesisState {
+    connections: Vec<IdentifiedConnection>,
+    client_connection_paths: Vec<ConnectionPaths>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    next_connection_sequence: u64,
+    params: ConnectionParams,
+}
+
+impl TryFrom<RawGenesisState> for GenesisState {
+    type Error = GenesisError;
+
+    fn try_from(
+        RawGenesisState {
+            connections,
+            client_connection_paths,
+            next_connection_sequence,
+            params,
+        }: RawGenesisState,
+    ) -> Result<Self, Self::Error> {
+        for connection in &connections {
+            IdentifiedConnectionEnd::try_from(connection.clone())?;
+        }
+
+        for ConnectionPaths { client_id, paths } in &client_connection_paths {
+            client_id.parse::<ClientId>()?;
+            for path in paths {
+                path.parse::<ConnectionId>()?;
+            }
+        }
+
+        Ok(Self {
+            connections,
+            client_connection_paths,
+            next_connection_sequence,
+            params,
+        })
+    }
+}
@@ -1,13 +1,29 @@
-use gears::context::TransactionalContext;
+use gears::context::{QueryableContext, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
+use gears::extensions::gas::GasResultExt;
+use gears::gas::store::errors::GasStoreErrors;
 use gears::{
     context::init::InitContext,
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
 };
+use ibc::core::connection::types::proto::v1::{ClientPaths, ConnectionPaths, IdentifiedConnection};
+use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ClientConnectionPath, ConnectionPath};
+use ibc::primitives::proto::Protobuf;
+use prost::Message;
 
-use super::{params::ConnectionParamsKeeper, GenesisState};
+use crate::ics02_client::KEY_CLIENT_STORE_PREFIX;
+
+use super::{
+    params::{ConnectionParams, ConnectionParamsKeeper},
+    GenesisState,
+};
 
 const KEY_NEXT_CONNECTION_SEQUENCE: &[u8; 22] = b"nextConnectionSequence";
+const KEY_CONNECTION_PREFIX: &str = "connections";
+const KEY_CLIENT_CONNECTIONS: &str = "connections";
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK, PSK> {
@@ -31,29 +47,191 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         ctx: &mut InitContext<'_, DB, SK>,
         genesis: GenesisState,
     ) {
-        // TODO: the following lines(from ibc-go) have not been implemented yet:
-        // for _, connection := range gs.Connections {
-        //     conn := types.NewConnectionEnd(connection.State, connection.ClientId, connection.Counterparty, connection.Versions, connection.DelayPeriod)
-        //     k.SetConnection(ctx, connection.Id, conn)
-        // }
-        // for _, connPaths := range gs.ClientConnectionPaths {
-        //     k.SetClientConnectionPaths(ctx, connPaths.ClientId, connPaths.Paths)
-        // }
-
-        self.set_next_connection_sequence(ctx, genesis.next_connection_sequence);
+        for connection in genesis.connections {
+            let IdentifiedConnectionEnd {
+                connection_id,
+                connection_end,
+            } = connection
+                .try_into()
+                .expect("genesis connections are validated on deserialization");
+
+            self.connection_set(ctx, &connection_id, connection_end)
+                .unwrap_gas();
+        }
+
+        for ConnectionPaths { client_id, paths } in genesis.client_connection_paths {
+            let client_id: ClientId = client_id
+                .parse()
+                .expect("genesis client connection paths are validated on deserialization");
+            let connections = paths
+                .iter()
+                .map(|path| path.parse())
+                .collect::<Result<Vec<ConnectionId>, _>>()
+                .expect("genesis client connection paths are validated on deserialization");
+
+            self.client_connections_set(ctx, &client_id, &connections)
+                .unwrap_gas();
+        }
+
+        self.set_next_connection_sequence(ctx, genesis.next_connection_sequence)
+            .unwrap_gas();
         self.connection_params_keeper
             .set(ctx, genesis.params.clone());
     }
 
-    pub fn set_next_connection_sequence<DB: Database>(
+    pub fn export_genesis<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
-        ctx: &mut InitContext<'_, DB, SK>,
+        ctx: &CTX,
+    ) -> Result<GenesisState, GasStoreErrors> {
+        let connections = self
+            .connections(ctx)?
+            .into_iter()
+            .map(IdentifiedConnection::from)
+            .collect();
+
+        let mut client_connection_paths = vec![];
+        let store = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(format!("{KEY_CLIENT_STORE_PREFIX}/").into_bytes());
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            let Some(client_id) = String::from_utf8(key.to_vec())
+                .unwrap_or_corrupt()
+                .strip_suffix(&format!("/{KEY_CLIENT_CONNECTIONS}"))
+                .map(ToOwned::to_owned)
+            else {
+                // the client store also holds client and consensus states
+                continue;
+            };
+
+            client_connection_paths.push(ConnectionPaths {
+                client_id,
+                paths: ClientPaths::decode(value.as_slice())
+                    .unwrap_or_corrupt()
+                    .paths,
+            });
+        }
+
+        Ok(GenesisState {
+            connections,
+            client_connection_paths,
+            next_connection_sequence: self.connection_counter(ctx)?,
+            params: self.params(ctx)?,
+        })
+    }
+
+    pub fn params<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<ConnectionParams, GasStoreErrors> {
+        self.connection_params_keeper.try_get(ctx)
+    }
+
+    /// Returns all connection ends ordered by their identifiers
+    pub fn connections<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, GasStoreErrors> {
+        let mut connections = vec![];
+        let store = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(format!("{KEY_CONNECTION_PREFIX}/").into_bytes());
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            let connection_id = String::from_utf8(key.to_vec()).unwrap_or_corrupt();
+
+            connections.push(IdentifiedConnectionEnd {
+                connection_id: connection_id.parse::<ConnectionId>().unwrap_or_corrupt(),
+                connection_end: ConnectionEnd::decode_vec(&value).unwrap_or_corrupt(),
+            });
+        }
+
+        Ok(connections)
+    }
+
+    /// Returns the connection end stored under the given identifier
+    pub fn connection_end<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<ConnectionEnd>, GasStoreErrors> {
+        let key = ConnectionPath(connection_id.clone()).to_string();
+        let connection_end = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| ConnectionEnd::decode_vec(&raw).unwrap_or_corrupt());
+
+        Ok(connection_end)
+    }
+
+    pub fn connection_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        connection_id: &ConnectionId,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), GasStoreErrors> {
+        let key = ConnectionPath(connection_id.clone()).to_string();
+        ctx.kv_store_mut(&self.store_key)
+            .set(key.into_bytes(), connection_end.encode_vec())
+    }
+
+    /// Returns the identifiers of all connections built on top of the given client
+    pub fn client_connections<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, GasStoreErrors> {
+        let key = ClientConnectionPath(client_id.clone()).to_string();
+        let Some(raw) = ctx.kv_store(&self.store_key).get(key.as_bytes())? else {
+            return Ok(vec![]);
+        };
+
+        Ok(ClientPaths::decode(raw.as_slice())
+            .unwrap_or_corrupt()
+            .paths
+            .iter()
+            .map(|path| path.parse::<ConnectionId>().unwrap_or_corrupt())
+            .collect())
+    }
+
+    pub fn client_connections_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        client_id: &ClientId,
+        connections: &[ConnectionId],
+    ) -> Result<(), GasStoreErrors> {
+        let key = ClientConnectionPath(client_id.clone()).to_string();
+        let paths = ClientPaths {
+            paths: connections.iter().map(ToString::to_string).collect(),
+        };
+
+        ctx.kv_store_mut(&self.store_key)
+            .set(key.into_bytes(), paths.encode_to_vec())
+    }
+
+    /// Returns the sequence used to generate the next connection identifier
+    pub fn connection_counter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<u64, GasStoreErrors> {
+        let raw = ctx
+            .kv_store(&self.store_key)
+            .get(KEY_NEXT_CONNECTION_SEQUENCE)?
+            .unwrap_or_corrupt();
+
+        Ok(u64::from_be_bytes(
+            <[u8; 8]>::try_from(raw).unwrap_or_corrupt(),
+        ))
+    }
+
+    pub fn set_next_connection_sequence<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
         sequence: u64,
-    ) {
-        let mut ibc_store = ctx.kv_store_mut(&self.store_key);
-        ibc_store.set(
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key).set(
             KEY_NEXT_CONNECTION_SEQUENCE.to_owned(),
             sequence.to_be_bytes(),
-        );
+        )
     }
 }
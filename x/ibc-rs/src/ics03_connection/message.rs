@@ -0,0 +1,41 @@
+use ibc::core::{
+    connection::types::{
+        msgs::{self, ConnectionMsg},
+        proto::v1 as raw,
+    },
+    handler::types::msgs::MsgEnvelope,
+};
+
+use crate::types::tx::ibc_message;
+
+ibc_message!(
+    MsgConnectionOpenInit,
+    msgs::MsgConnectionOpenInit,
+    raw::MsgConnectionOpenInit,
+    "/ibc.core.connection.v1.MsgConnectionOpenInit",
+    |msg| MsgEnvelope::Connection(ConnectionMsg::OpenInit(msg))
+);
+
+ibc_message!(
+    MsgConnectionOpenTry,
+    msgs::MsgConnectionOpenTry,
+    raw::MsgConnectionOpenTry,
+    "/ibc.core.connection.v1.MsgConnectionOpenTry",
+    |msg| MsgEnvelope::Connection(ConnectionMsg::OpenTry(msg))
+);
+
+ibc_message!(
+    MsgConnectionOpenAck,
+    msgs::MsgConnectionOpenAck,
+    raw::MsgConnectionOpenAck,
+    "/ibc.core.connection.v1.MsgConnectionOpenAck",
+    |msg| MsgEnvelope::Connection(ConnectionMsg::OpenAck(msg))
+);
+
+ibc_message!(
+    MsgConnectionOpenConfirm,
+    msgs::MsgConnectionOpenConfirm,
+    raw::MsgConnectionOpenConfirm,
+    "/ibc.core.connection.v1.MsgConnectionOpenConfirm",
+    |msg| MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(msg))
+);
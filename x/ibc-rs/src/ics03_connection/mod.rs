@@ -1,6 +1,8 @@
 mod genesis;
 mod keeper;
+pub mod message;
 mod params;
 
 pub use genesis::GenesisState;
 pub use keeper::Keeper;
+pub use params::ConnectionParams;
@@ -5,12 +5,16 @@ use gears::context::InfallibleContext;
 use gears::context::InfallibleContextMut;
 use gears::core::serializers::serialize_number_to_string;
 use gears::extensions::corruption::UnwrapCorrupt;
+use gears::gas::store::errors::GasStoreErrors;
+use gears::params::gas;
 use gears::params::infallible_subspace;
 use gears::params::infallible_subspace_mut;
+use gears::params::parsed::Params;
 use gears::params::ParamKind;
 use gears::params::ParamsDeserialize;
 use gears::params::ParamsSerialize;
 use gears::params::ParamsSubspaceKey;
+use gears::params::ParamsValidationError;
 use gears::store::store::prefix::immutable::ImmutablePrefixStore;
 use gears::{
     context::{QueryableContext, TransactionalContext},
@@ -67,6 +71,20 @@ impl ParamsDeserialize for ConnectionParams {
                 .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        match key {
+            KEY_MAX_EXPECTED_TIME_PER_BLOCK => match ParamKind::U64
+                .try_parse_param(value.to_vec())
+                .and_then(Params::unsigned_64)
+            {
+                Some(0) => Err(ParamsValidationError::invalid_value(key, "can't be zero")),
+                Some(_) => Ok(()),
+                None => Err(ParamsValidationError::invalid_value(key, "expected u64")),
+            },
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -84,6 +102,15 @@ impl<PSK: ParamsSubspaceKey> ConnectionParamsKeeper<PSK> {
         store.params().unwrap_or_default()
     }
 
+    pub fn try_get<DB: Database, SK: StoreKey, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<ConnectionParams, GasStoreErrors> {
+        let store = gas::subspace(ctx, &self.params_subspace_key);
+
+        Ok(store.params()?.unwrap_or_default())
+    }
+
     pub fn set<DB: Database, SK: StoreKey, CTX: InfallibleContextMut<DB, SK>>(
         &self,
         ctx: &mut CTX,
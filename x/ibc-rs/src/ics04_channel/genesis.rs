@@ -1,15 +1,25 @@
 //pub type GenesisState = ibc::core::channel::types::proto::v1::GenesisState;
 
 use gears::core::serializers::serialize_number_to_string;
-use ibc::core::channel::types::{
-    packet::PacketState,
-    proto::v1::{IdentifiedChannel, PacketSequence},
+use ibc::core::{
+    channel::types::{
+        channel::IdentifiedChannelEnd,
+        error::ChannelError,
+        packet::PacketState,
+        proto::v1::{IdentifiedChannel, PacketSequence},
+    },
+    host::types::{
+        error::IdentifierError,
+        identifiers::{ChannelId, PortId},
+    },
 };
 use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_number_from_string;
 
 /// GenesisState defines the ibc channel submodule's genesis state.
+/// The channels and the packet sequences are validated on deserialization.
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[serde(try_from = "RawGenesisState")]
 pub struct GenesisState {
     pub channels: Vec<IdentifiedChannel>,
     pub acknowledgements: Vec<PacketState>,
@@ -23,3 +33,69 @@ pub struct GenesisState {
     #[serde(serialize_with = "serialize_number_to_string")]
     pub next_channel_sequence: u64,
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenesisError {
+    #[error("invalid genesis channel: {0}")]
+    Channel(#[from] ChannelError),
+    #[error("invalid genesis packet sequence: {0}")]
+    PacketSequence(#[from] IdentifierError),
+}
+
+#[derive(Deserialize)]
+struct RawGenesisState {
+    channels: Vec<IdentifiedChannel>,
+    acknowledgements: Vec<PacketState>,
+    commitments: Vec<PacketState>,
+    receipts: Vec<PacketState>,
+    send_sequences: Vec<PacketSequence>,
+    recv_sequences: Vec<PacketSequence>,
+    ack_sequences: Vec<PacketSequence>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    next_channel_sequence: u64,
+}
+
+impl TryFrom<RawGenesisState> for GenesisState {
+    type Error = GenesisError;
+
+    fn try_from(
+        RawGenesisState {
+            channels,
+            acknowledgements,
+            commitments,
+            receipts,
+            send_sequences,
+            recv_sequences,
+            ack_sequences,
+            next_channel_sequence,
+        }: RawGenesisState,
+    ) -> Result<Self, Self::Error> {
+        for channel in &channels {
+            IdentifiedChannelEnd::try_from(channel.clone())?;
+        }
+
+        for PacketSequence {
+            port_id,
+            channel_id,
+            ..
+        } in send_sequences
+            .iter()
+            .chain(&recv_sequences)
+            .chain(&ack_sequences)
+        {
+            port_id.parse::<PortId>()?;
+            channel_id.parse::<ChannelId>()?;
+        }
+
+        Ok(Self {
+            channels,
+            acknowledgements,
+            commitments,
+            receipts,
+            send_sequences,
+            recv_sequences,
+            ack_sequences,
+            next_channel_sequence,
+        })
+    }
+}
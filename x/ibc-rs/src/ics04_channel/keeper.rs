@@ -1,10 +1,32 @@
 use gears::context::init::InitContext;
-use gears::context::TransactionalContext;
+use gears::context::{QueryableContext, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
+use gears::extensions::gas::GasResultExt;
+use gears::gas::store::errors::GasStoreErrors;
 use gears::store::{database::Database, StoreKey};
+use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::PacketState;
+use ibc::core::channel::types::proto::v1::PacketSequence;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc::primitives::proto::Protobuf;
 
 use super::GenesisState;
 
 const KEY_NEXT_CHANNEL_SEQUENCE: &[u8; 19] = b"nextChannelSequence";
+const KEY_CHANNEL_END_PREFIX: &str = "channelEnds/ports/";
+const KEY_NEXT_SEQ_SEND_PREFIX: &str = "nextSequenceSend/ports/";
+const KEY_NEXT_SEQ_RECV_PREFIX: &str = "nextSequenceRecv/ports/";
+const KEY_NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck/ports/";
+const KEY_PACKET_COMMITMENT_PREFIX: &str = "commitments/ports/";
+const KEY_PACKET_RECEIPT_PREFIX: &str = "receipts/ports/";
+const KEY_PACKET_ACK_PREFIX: &str = "acks/ports/";
+
+/// Value stored under a receipt path, ibc-go stores a single byte as well
+const RECEIPT_VALUE: [u8; 1] = [1];
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK> {
@@ -21,39 +43,426 @@ impl<SK: StoreKey> Keeper<SK> {
         ctx: &mut InitContext<'_, DB, SK>,
         genesis: GenesisState,
     ) {
-        // TODO: the following lines(from ibc-go) have not been implemented yet:
-        // for _, channel := range gs.Channels {
-        //     ch := types.NewChannel(channel.State, channel.Ordering, channel.Counterparty, channel.ConnectionHops, channel.Version)
-        //     k.SetChannel(ctx, channel.PortId, channel.ChannelId, ch)
-        // }
-        // for _, ack := range gs.Acknowledgements {
-        //     k.SetPacketAcknowledgement(ctx, ack.PortId, ack.ChannelId, ack.Sequence, ack.Data)
-        // }
-        // for _, commitment := range gs.Commitments {
-        //     k.SetPacketCommitment(ctx, commitment.PortId, commitment.ChannelId, commitment.Sequence, commitment.Data)
-        // }
-        // for _, receipt := range gs.Receipts {
-        //     k.SetPacketReceipt(ctx, receipt.PortId, receipt.ChannelId, receipt.Sequence)
-        // }
-        // for _, ss := range gs.SendSequences {
-        //     k.SetNextSequenceSend(ctx, ss.PortId, ss.ChannelId, ss.Sequence)
-        // }
-        // for _, rs := range gs.RecvSequences {
-        //     k.SetNextSequenceRecv(ctx, rs.PortId, rs.ChannelId, rs.Sequence)
-        // }
-        // for _, as := range gs.AckSequences {
-        //     k.SetNextSequenceAck(ctx, as.PortId, as.ChannelId, as.Sequence)
-        // }
-
-        self.set_next_channel_sequence(ctx, genesis.next_channel_sequence);
-    }
-
-    pub fn set_next_channel_sequence<DB: Database>(
+        for channel in genesis.channels {
+            let IdentifiedChannelEnd {
+                port_id,
+                channel_id,
+                channel_end,
+            } = channel
+                .try_into()
+                .expect("genesis channels are validated on deserialization");
+
+            self.channel_set(ctx, &port_id, &channel_id, channel_end)
+                .unwrap_gas();
+        }
+
+        for ack in genesis.acknowledgements {
+            self.packet_acknowledgement_set(
+                ctx,
+                &AckPath::new(&ack.port_id, &ack.chan_id, ack.seq),
+                ack.data.into(),
+            )
+            .unwrap_gas();
+        }
+
+        for commitment in genesis.commitments {
+            self.packet_commitment_set(
+                ctx,
+                &CommitmentPath::new(&commitment.port_id, &commitment.chan_id, commitment.seq),
+                commitment.data.into(),
+            )
+            .unwrap_gas();
+        }
+
+        for receipt in genesis.receipts {
+            self.packet_receipt_set(
+                ctx,
+                &ReceiptPath::new(&receipt.port_id, &receipt.chan_id, receipt.seq),
+            )
+            .unwrap_gas();
+        }
+
+        for (sequences, prefix) in [
+            (genesis.send_sequences, KEY_NEXT_SEQ_SEND_PREFIX),
+            (genesis.recv_sequences, KEY_NEXT_SEQ_RECV_PREFIX),
+            (genesis.ack_sequences, KEY_NEXT_SEQ_ACK_PREFIX),
+        ] {
+            for PacketSequence {
+                port_id,
+                channel_id,
+                sequence,
+            } in sequences
+            {
+                let port_id: PortId = port_id
+                    .parse()
+                    .expect("genesis packet sequences are validated on deserialization");
+                let channel_id: ChannelId = channel_id
+                    .parse()
+                    .expect("genesis packet sequences are validated on deserialization");
+
+                self.sequence_set(ctx, prefix, &port_id, &channel_id, sequence.into())
+                    .unwrap_gas();
+            }
+        }
+
+        self.set_next_channel_sequence(ctx, genesis.next_channel_sequence)
+            .unwrap_gas();
+    }
+
+    pub fn export_genesis<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
-        ctx: &mut InitContext<'_, DB, SK>,
+        ctx: &CTX,
+    ) -> Result<GenesisState, GasStoreErrors> {
+        let channels = self.channels(ctx)?.into_iter().map(Into::into).collect();
+
+        let packet_states = |prefix: &str| -> Result<Vec<PacketState>, GasStoreErrors> {
+            Ok(self
+                .prefixed_packet_entries(ctx, prefix)?
+                .into_iter()
+                .map(|((port_id, chan_id, seq), data)| PacketState {
+                    port_id,
+                    chan_id,
+                    seq,
+                    data,
+                })
+                .collect())
+        };
+
+        let packet_sequences = |prefix: &str| -> Result<Vec<PacketSequence>, GasStoreErrors> {
+            Ok(self
+                .prefixed_entries(ctx, prefix)?
+                .into_iter()
+                .map(|((port_id, channel_id), value)| PacketSequence {
+                    port_id: port_id.to_string(),
+                    channel_id: channel_id.to_string(),
+                    sequence: u64::from_be_bytes(<[u8; 8]>::try_from(value).unwrap_or_corrupt()),
+                })
+                .collect())
+        };
+
+        Ok(GenesisState {
+            channels,
+            acknowledgements: packet_states(KEY_PACKET_ACK_PREFIX)?,
+            commitments: packet_states(KEY_PACKET_COMMITMENT_PREFIX)?,
+            receipts: packet_states(KEY_PACKET_RECEIPT_PREFIX)?,
+            send_sequences: packet_sequences(KEY_NEXT_SEQ_SEND_PREFIX)?,
+            recv_sequences: packet_sequences(KEY_NEXT_SEQ_RECV_PREFIX)?,
+            ack_sequences: packet_sequences(KEY_NEXT_SEQ_ACK_PREFIX)?,
+            next_channel_sequence: self.channel_counter(ctx)?,
+        })
+    }
+
+    /// Returns all channel ends ordered by their ports and identifiers
+    pub fn channels<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Vec<IdentifiedChannelEnd>, GasStoreErrors> {
+        Ok(self
+            .prefixed_entries(ctx, KEY_CHANNEL_END_PREFIX)?
+            .into_iter()
+            .map(|((port_id, channel_id), value)| {
+                IdentifiedChannelEnd::new(
+                    port_id,
+                    channel_id,
+                    ChannelEnd::decode_vec(&value).unwrap_or_corrupt(),
+                )
+            })
+            .collect())
+    }
+
+    /// Returns the channel end stored for the given port and channel
+    pub fn channel_end<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<ChannelEnd>, GasStoreErrors> {
+        let key = ChannelEndPath::new(port_id, channel_id).to_string();
+        let channel_end = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| ChannelEnd::decode_vec(&raw).unwrap_or_corrupt());
+
+        Ok(channel_end)
+    }
+
+    pub fn channel_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_end: ChannelEnd,
+    ) -> Result<(), GasStoreErrors> {
+        let key = ChannelEndPath::new(port_id, channel_id).to_string();
+        ctx.kv_store_mut(&self.store_key)
+            .set(key.into_bytes(), channel_end.encode_vec())
+    }
+
+    pub fn next_sequence_send<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &SeqSendPath,
+    ) -> Result<Option<Sequence>, GasStoreErrors> {
+        self.sequence(ctx, KEY_NEXT_SEQ_SEND_PREFIX, &path.0, &path.1)
+    }
+
+    pub fn next_sequence_send_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &SeqSendPath,
+        sequence: Sequence,
+    ) -> Result<(), GasStoreErrors> {
+        self.sequence_set(ctx, KEY_NEXT_SEQ_SEND_PREFIX, &path.0, &path.1, sequence)
+    }
+
+    pub fn next_sequence_recv<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &SeqRecvPath,
+    ) -> Result<Option<Sequence>, GasStoreErrors> {
+        self.sequence(ctx, KEY_NEXT_SEQ_RECV_PREFIX, &path.0, &path.1)
+    }
+
+    pub fn next_sequence_recv_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &SeqRecvPath,
+        sequence: Sequence,
+    ) -> Result<(), GasStoreErrors> {
+        self.sequence_set(ctx, KEY_NEXT_SEQ_RECV_PREFIX, &path.0, &path.1, sequence)
+    }
+
+    pub fn next_sequence_ack<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &SeqAckPath,
+    ) -> Result<Option<Sequence>, GasStoreErrors> {
+        self.sequence(ctx, KEY_NEXT_SEQ_ACK_PREFIX, &path.0, &path.1)
+    }
+
+    pub fn next_sequence_ack_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &SeqAckPath,
+        sequence: Sequence,
+    ) -> Result<(), GasStoreErrors> {
+        self.sequence_set(ctx, KEY_NEXT_SEQ_ACK_PREFIX, &path.0, &path.1, sequence)
+    }
+
+    pub fn packet_commitment<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &CommitmentPath,
+    ) -> Result<Option<PacketCommitment>, GasStoreErrors> {
+        Ok(ctx
+            .kv_store(&self.store_key)
+            .get(path.to_string().as_bytes())?
+            .map(Into::into))
+    }
+
+    /// Returns the commitments of the packets sent on the channel which aren't
+    /// acknowledged or timed out yet
+    pub fn packet_commitments<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<(Sequence, PacketCommitment)>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key).prefix_store(
+            format!("{KEY_PACKET_COMMITMENT_PREFIX}{port_id}/channels/{channel_id}/sequences/")
+                .into_bytes(),
+        );
+
+        let mut commitments = vec![];
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            let sequence = String::from_utf8(key.to_vec())
+                .unwrap_or_corrupt()
+                .parse::<Sequence>()
+                .unwrap_or_corrupt();
+
+            commitments.push((sequence, value.into_owned().into()));
+        }
+
+        Ok(commitments)
+    }
+
+    pub fn packet_commitment_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .set(path.to_string().into_bytes(), commitment.into_vec())
+    }
+
+    pub fn packet_commitment_delete<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &CommitmentPath,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .delete(path.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    pub fn has_packet_receipt<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &ReceiptPath,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(ctx
+            .kv_store(&self.store_key)
+            .get(path.to_string().as_bytes())?
+            .is_some())
+    }
+
+    pub fn packet_receipt_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &ReceiptPath,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .set(path.to_string().into_bytes(), RECEIPT_VALUE)
+    }
+
+    pub fn packet_acknowledgement<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        path: &AckPath,
+    ) -> Result<Option<AcknowledgementCommitment>, GasStoreErrors> {
+        Ok(ctx
+            .kv_store(&self.store_key)
+            .get(path.to_string().as_bytes())?
+            .map(Into::into))
+    }
+
+    pub fn packet_acknowledgement_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .set(path.to_string().into_bytes(), ack_commitment.into_vec())
+    }
+
+    pub fn packet_acknowledgement_delete<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        path: &AckPath,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .delete(path.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    /// Returns the sequence used to generate the next channel identifier
+    pub fn channel_counter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<u64, GasStoreErrors> {
+        let raw = ctx
+            .kv_store(&self.store_key)
+            .get(KEY_NEXT_CHANNEL_SEQUENCE)?
+            .unwrap_or_corrupt();
+
+        Ok(u64::from_be_bytes(
+            <[u8; 8]>::try_from(raw).unwrap_or_corrupt(),
+        ))
+    }
+
+    pub fn set_next_channel_sequence<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
         sequence: u64,
-    ) {
-        let mut ibc_store = ctx.kv_store_mut(&self.store_key);
-        ibc_store.set(KEY_NEXT_CHANNEL_SEQUENCE.to_owned(), sequence.to_be_bytes());
+    ) -> Result<(), GasStoreErrors> {
+        ctx.kv_store_mut(&self.store_key)
+            .set(KEY_NEXT_CHANNEL_SEQUENCE.to_owned(), sequence.to_be_bytes())
+    }
+
+    fn sequence<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        prefix: &str,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<Sequence>, GasStoreErrors> {
+        let key = format!("{prefix}{port_id}/channels/{channel_id}");
+        let sequence = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| u64::from_be_bytes(<[u8; 8]>::try_from(raw).unwrap_or_corrupt()).into());
+
+        Ok(sequence)
+    }
+
+    fn sequence_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        prefix: &str,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), GasStoreErrors> {
+        let key = format!("{prefix}{port_id}/channels/{channel_id}");
+        ctx.kv_store_mut(&self.store_key)
+            .set(key.into_bytes(), sequence.value().to_be_bytes())
+    }
+
+    /// Returns all values stored under `{prefix}{port_id}/channels/{channel_id}` keys
+    fn prefixed_entries<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        prefix: &str,
+    ) -> Result<Vec<((PortId, ChannelId), Vec<u8>)>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key).prefix_store(prefix.bytes());
+
+        let mut entries = vec![];
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            let key = String::from_utf8(key.to_vec()).unwrap_or_corrupt();
+            let (port_id, channel_id) = key.split_once("/channels/").unwrap_or_corrupt();
+
+            entries.push((
+                (
+                    port_id.parse::<PortId>().unwrap_or_corrupt(),
+                    channel_id.parse::<ChannelId>().unwrap_or_corrupt(),
+                ),
+                value.into_owned(),
+            ));
+        }
+
+        Ok(entries)
+    }
+
+    /// Returns all values stored under
+    /// `{prefix}{port_id}/channels/{channel_id}/sequences/{sequence}` keys
+    fn prefixed_packet_entries<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        prefix: &str,
+    ) -> Result<Vec<((PortId, ChannelId, Sequence), Vec<u8>)>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key).prefix_store(prefix.bytes());
+
+        let mut entries = vec![];
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            let key = String::from_utf8(key.to_vec()).unwrap_or_corrupt();
+            let (port_id, rest) = key.split_once("/channels/").unwrap_or_corrupt();
+            let (channel_id, sequence) = rest.split_once("/sequences/").unwrap_or_corrupt();
+
+            entries.push((
+                (
+                    port_id.parse::<PortId>().unwrap_or_corrupt(),
+                    channel_id.parse::<ChannelId>().unwrap_or_corrupt(),
+                    sequence.parse::<Sequence>().unwrap_or_corrupt(),
+                ),
+                value.into_owned(),
+            ));
+        }
+
+        Ok(entries)
     }
 }
@@ -0,0 +1,89 @@
+use ibc::core::{
+    channel::types::{
+        msgs::{self, ChannelMsg, PacketMsg},
+        proto::v1 as raw,
+    },
+    handler::types::msgs::MsgEnvelope,
+};
+
+use crate::types::tx::ibc_message;
+
+ibc_message!(
+    MsgChannelOpenInit,
+    msgs::MsgChannelOpenInit,
+    raw::MsgChannelOpenInit,
+    "/ibc.core.channel.v1.MsgChannelOpenInit",
+    |msg| MsgEnvelope::Channel(ChannelMsg::OpenInit(msg))
+);
+
+ibc_message!(
+    MsgChannelOpenTry,
+    msgs::MsgChannelOpenTry,
+    raw::MsgChannelOpenTry,
+    "/ibc.core.channel.v1.MsgChannelOpenTry",
+    |msg| MsgEnvelope::Channel(ChannelMsg::OpenTry(msg))
+);
+
+ibc_message!(
+    MsgChannelOpenAck,
+    msgs::MsgChannelOpenAck,
+    raw::MsgChannelOpenAck,
+    "/ibc.core.channel.v1.MsgChannelOpenAck",
+    |msg| MsgEnvelope::Channel(ChannelMsg::OpenAck(msg))
+);
+
+ibc_message!(
+    MsgChannelOpenConfirm,
+    msgs::MsgChannelOpenConfirm,
+    raw::MsgChannelOpenConfirm,
+    "/ibc.core.channel.v1.MsgChannelOpenConfirm",
+    |msg| MsgEnvelope::Channel(ChannelMsg::OpenConfirm(msg))
+);
+
+ibc_message!(
+    MsgChannelCloseInit,
+    msgs::MsgChannelCloseInit,
+    raw::MsgChannelCloseInit,
+    "/ibc.core.channel.v1.MsgChannelCloseInit",
+    |msg| MsgEnvelope::Channel(ChannelMsg::CloseInit(msg))
+);
+
+ibc_message!(
+    MsgChannelCloseConfirm,
+    msgs::MsgChannelCloseConfirm,
+    raw::MsgChannelCloseConfirm,
+    "/ibc.core.channel.v1.MsgChannelCloseConfirm",
+    |msg| MsgEnvelope::Channel(ChannelMsg::CloseConfirm(msg))
+);
+
+ibc_message!(
+    MsgRecvPacket,
+    msgs::MsgRecvPacket,
+    raw::MsgRecvPacket,
+    "/ibc.core.channel.v1.MsgRecvPacket",
+    |msg| MsgEnvelope::Packet(PacketMsg::Recv(msg))
+);
+
+ibc_message!(
+    MsgAcknowledgement,
+    msgs::MsgAcknowledgement,
+    raw::MsgAcknowledgement,
+    "/ibc.core.channel.v1.MsgAcknowledgement",
+    |msg| MsgEnvelope::Packet(PacketMsg::Ack(msg))
+);
+
+ibc_message!(
+    MsgTimeout,
+    msgs::MsgTimeout,
+    raw::MsgTimeout,
+    "/ibc.core.channel.v1.MsgTimeout",
+    |msg| MsgEnvelope::Packet(PacketMsg::Timeout(msg))
+);
+
+ibc_message!(
+    MsgTimeoutOnClose,
+    msgs::MsgTimeoutOnClose,
+    raw::MsgTimeoutOnClose,
+    "/ibc.core.channel.v1.MsgTimeoutOnClose",
+    |msg| MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg))
+);
@@ -1,5 +1,6 @@
 mod genesis;
 mod keeper;
+pub mod message;
 
 pub use genesis::GenesisState;
 pub use keeper::Keeper;
@@ -0,0 +1,26 @@
+use clap::{Args, Subcommand};
+use ibc::apps::transfer::types::proto::transfer::v1::QueryDenomTraceRequest;
+
+pub(crate) const DENOM_TRACE_URL: &str = "/ibc.applications.transfer.v1.Query/DenomTrace";
+
+/// IBC fungible token transfer query subcommands
+#[derive(Args, Debug, Clone)]
+pub struct TransferQueryCli {
+    #[command(subcommand)]
+    pub command: TransferQueryCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TransferQueryCommands {
+    /// Query the denomination trace of a voucher
+    DenomTrace {
+        /// Hash of the trace, with or without the ibc/ prefix
+        hash: String,
+    },
+}
+
+pub(crate) fn handle_query(args: &TransferQueryCli) -> QueryDenomTraceRequest {
+    match &args.command {
+        TransferQueryCommands::DenomTrace { hash } => QueryDenomTraceRequest { hash: hash.clone() },
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use clap::{Args, Subcommand};
+use gears::types::address::AccAddress;
+use ibc::{
+    apps::transfer::types::{proto::transfer::v1::MsgTransfer as RawMsgTransfer, PrefixedCoin},
+    core::{
+        client::types::Height,
+        host::types::identifiers::{ChannelId, PortId},
+    },
+};
+
+use crate::ics20_transfer::message::MsgTransfer;
+
+/// Timeout of the packet when neither a timeout height nor a timeout timestamp is given
+const DEFAULT_PACKET_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Args, Debug, Clone)]
+pub struct TransferTxCli {
+    #[command(subcommand)]
+    pub command: TransferCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TransferCommands {
+    /// Transfer a fungible token through IBC. Vouchers can be sent by their ibc/<hash> denomination.
+    Transfer {
+        /// Port of the channel through which the token is sent, usually `transfer`
+        src_port: PortId,
+        /// Channel through which the token is sent
+        src_channel: ChannelId,
+        /// Address of the receiver on the counterparty chain
+        receiver: String,
+        /// Amount of tokens to send, e.g. 10uatom
+        amount: String,
+        /// Height of the counterparty chain after which the packet times out, e.g. 1-1000
+        #[arg(long)]
+        packet_timeout_height: Option<Height>,
+        /// Unix time in nanoseconds after which the packet times out. Defaults to 10 minutes
+        /// from now if no timeout height is given either.
+        #[arg(long)]
+        packet_timeout_timestamp: Option<u64>,
+        /// Memo to be sent along with the packet
+        #[arg(long, default_value = "")]
+        memo: String,
+    },
+}
+
+pub fn run_transfer_tx_command(
+    args: TransferTxCli,
+    from_address: AccAddress,
+) -> anyhow::Result<crate::message::Message> {
+    match args.command {
+        TransferCommands::Transfer {
+            src_port,
+            src_channel,
+            receiver,
+            amount,
+            packet_timeout_height,
+            packet_timeout_timestamp,
+            memo,
+        } => {
+            let token = amount
+                .parse::<PrefixedCoin>()
+                .map_err(|e| anyhow!("invalid amount {amount}: {e}"))?;
+
+            let timeout_timestamp = match (packet_timeout_height, packet_timeout_timestamp) {
+                (_, Some(timestamp)) => timestamp,
+                (Some(_), None) => 0,
+                (None, None) => {
+                    let timeout =
+                        SystemTime::now().duration_since(UNIX_EPOCH)? + DEFAULT_PACKET_TIMEOUT;
+                    u64::try_from(timeout.as_nanos())?
+                }
+            };
+
+            let raw_msg = RawMsgTransfer {
+                source_port: src_port.to_string(),
+                source_channel: src_channel.to_string(),
+                token: Some(token.into()),
+                sender: from_address.to_string(),
+                receiver,
+                timeout_height: packet_timeout_height.map(Into::into),
+                timeout_timestamp,
+                memo,
+            };
+
+            Ok(crate::message::Message::Transfer(MsgTransfer::try_from(
+                raw_msg,
+            )?))
+        }
+    }
+}
@@ -0,0 +1,1021 @@
+use std::{cell::RefCell, fmt::Debug};
+
+use gears::{
+    context::tx::TxContext,
+    store::{database::Database, StoreKey},
+    types::{
+        address::AccAddress,
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        msg::send::MsgSend,
+    },
+    x::{
+        keepers::{gov::GovernanceBankKeeper, mint::MintingBankKeeper},
+        module::Module as AppModule,
+    },
+};
+use ibc::{
+    apps::transfer::{
+        context::{TokenTransferExecutionContext, TokenTransferValidationContext},
+        module::{
+            on_acknowledgement_packet_execute, on_acknowledgement_packet_validate,
+            on_chan_close_confirm_execute, on_chan_close_confirm_validate,
+            on_chan_close_init_execute, on_chan_close_init_validate, on_chan_open_ack_execute,
+            on_chan_open_ack_validate, on_chan_open_confirm_execute, on_chan_open_confirm_validate,
+            on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
+            on_chan_open_try_validate, on_recv_packet_execute, on_timeout_packet_execute,
+            on_timeout_packet_validate,
+        },
+        types::{error::TokenTransferError, Memo, PrefixedCoin, PrefixedDenom},
+    },
+    core::{
+        channel::types::{
+            acknowledgement::Acknowledgement,
+            channel::{Counterparty, Order},
+            error::{ChannelError, PacketError},
+            packet::Packet,
+            Version,
+        },
+        host::types::identifiers::{ChannelId, ConnectionId, PortId},
+        router::{module::Module, types::module::ModuleExtras},
+    },
+    primitives::Signer,
+};
+
+use super::{
+    keeper::{escrow_address, local_coin, local_denom},
+    Keeper,
+};
+
+/// Account of the host chain which sends or receives tokens
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferAccount(pub AccAddress);
+
+impl TryFrom<Signer> for TransferAccount {
+    type Error = gears::types::address::AddressError;
+
+    fn try_from(signer: Signer) -> Result<Self, Self::Error> {
+        AccAddress::from_bech32(signer.as_ref()).map(Self)
+    }
+}
+
+/// ICS-20 application bound to the transfer port. It shares the transaction context
+/// with the IBC handlers, the token movements are applied through the bank keeper.
+pub struct TransferModule<'a, 'b, DB, SK, BK, M> {
+    gears_ctx: &'a RefCell<&'a mut TxContext<'b, DB, SK>>,
+    keeper: &'a Keeper<SK, BK, M>,
+}
+
+impl<'a, 'b, DB, SK, BK, M> TransferModule<'a, 'b, DB, SK, BK, M> {
+    pub fn new(
+        gears_ctx: &'a RefCell<&'a mut TxContext<'b, DB, SK>>,
+        keeper: &'a Keeper<SK, BK, M>,
+    ) -> Self {
+        Self { gears_ctx, keeper }
+    }
+}
+
+// The router requires its modules to be `Debug`, the transaction context isn't worth printing
+impl<'a, 'b, DB, SK, BK, M> Debug for TransferModule<'a, 'b, DB, SK, BK, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransferModule").finish_non_exhaustive()
+    }
+}
+
+fn other_error(e: impl ToString) -> TokenTransferError {
+    TokenTransferError::Other(e.to_string())
+}
+
+fn coins(coin: UnsignedCoin) -> Result<UnsignedCoins, TokenTransferError> {
+    UnsignedCoins::new(vec![coin]).map_err(other_error)
+}
+
+impl<
+        'a,
+        'b,
+        DB: Database,
+        SK: StoreKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: AppModule,
+    > TransferModule<'a, 'b, DB, SK, BK, M>
+{
+    /// Checks that the account holds at least the given coin
+    fn has_coin(
+        &self,
+        address: &AccAddress,
+        coin: &UnsignedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let balance = self
+            .keeper
+            .bank_keeper
+            .balance(&**self.gears_ctx.borrow(), address, &coin.denom)
+            .map_err(other_error)?
+            .amount;
+
+        if balance < coin.amount {
+            return Err(other_error(format!(
+                "insufficient funds: {} has {balance}{} but {coin} is required",
+                address, coin.denom
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn send(
+        &self,
+        from_address: &AccAddress,
+        to_address: &AccAddress,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let msg = MsgSend {
+            from_address: from_address.clone(),
+            to_address: to_address.clone(),
+            amount: coins(local_coin(coin)?)?,
+        };
+
+        self.keeper
+            .bank_keeper
+            .send_coins_from_account_to_account(&mut **self.gears_ctx.borrow_mut(), &msg)
+            .map_err(other_error)
+    }
+}
+
+impl<
+        'a,
+        'b,
+        DB: Database,
+        SK: StoreKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: AppModule,
+    > TokenTransferValidationContext for TransferModule<'a, 'b, DB, SK, BK, M>
+{
+    type AccountId = TransferAccount;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.has_coin(&from_account.0, &local_coin(coin)?)
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.has_coin(&escrow_address(port_id, channel_id), &local_coin(coin)?)
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        local_coin(coin).map(|_| ())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.has_coin(&account.0, &local_coin(coin)?)
+    }
+
+    fn denom_hash_string(&self, denom: &PrefixedDenom) -> Option<String> {
+        (!denom.trace_path.is_empty()).then(|| local_denom(denom))
+    }
+}
+
+impl<
+        'a,
+        'b,
+        DB: Database,
+        SK: StoreKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: AppModule,
+    > TokenTransferExecutionContext for TransferModule<'a, 'b, DB, SK, BK, M>
+{
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.send(&from_account.0, &escrow_address(port_id, channel_id), coin)
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.send(&escrow_address(port_id, channel_id), &to_account.0, coin)
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let amount = coins(local_coin(coin)?)?;
+        let mut gears_ctx = self.gears_ctx.borrow_mut();
+
+        if !coin.denom.trace_path.is_empty() {
+            self.keeper
+                .denom_trace_set(&mut **gears_ctx, &coin.denom)
+                .map_err(other_error)?;
+        }
+
+        self.keeper
+            .bank_keeper
+            .mint_coins(
+                &mut **gears_ctx,
+                &self.keeper.transfer_module,
+                amount.clone(),
+            )
+            .map_err(other_error)?;
+        self.keeper
+            .bank_keeper
+            .send_coins_from_module_to_account(
+                &mut **gears_ctx,
+                &account.0,
+                &self.keeper.transfer_module,
+                amount,
+            )
+            .map_err(other_error)
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        let amount = coins(local_coin(coin)?)?;
+        let mut gears_ctx = self.gears_ctx.borrow_mut();
+
+        self.keeper
+            .bank_keeper
+            .send_coins_from_account_to_module(
+                &mut **gears_ctx,
+                account.0.clone(),
+                &self.keeper.transfer_module,
+                amount.clone(),
+            )
+            .map_err(other_error)?;
+        self.keeper
+            .bank_keeper
+            .coins_burn(&mut **gears_ctx, &self.keeper.transfer_module, &amount)
+            .map_err(other_error)
+    }
+}
+
+fn channel_error(e: TokenTransferError) -> ChannelError {
+    ChannelError::AppModule {
+        description: e.to_string(),
+    }
+}
+
+fn packet_error(e: TokenTransferError) -> PacketError {
+    PacketError::AppModule {
+        description: e.to_string(),
+    }
+}
+
+impl<
+        'a,
+        'b,
+        DB: Database,
+        SK: StoreKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: AppModule,
+    > Module for TransferModule<'a, 'b, DB, SK, BK, M>
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_init_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+        .map_err(channel_error)?;
+
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_init_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+        .map_err(channel_error)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_try_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+        .map_err(channel_error)?;
+
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_try_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+        .map_err(channel_error)
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        on_chan_open_ack_validate(self, port_id, channel_id, counterparty_version)
+            .map_err(channel_error)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_open_ack_execute(self, port_id, channel_id, counterparty_version)
+            .map_err(channel_error)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        on_chan_open_confirm_validate(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_open_confirm_execute(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        on_chan_close_init_validate(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_close_init_execute(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        on_chan_close_confirm_validate(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_close_confirm_execute(self, port_id, channel_id).map_err(channel_error)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        on_recv_packet_execute(self, packet)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_acknowledgement_packet_validate(self, packet, acknowledgement, relayer)
+            .map_err(packet_error)
+    }
+
+    /// Refunds the sender if the counterparty failed to receive the tokens
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) =
+            on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer);
+
+        (extras, result.map_err(packet_error))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_timeout_packet_validate(self, packet, relayer).map_err(packet_error)
+    }
+
+    /// Refunds the sender of the packet which timed out
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) = on_timeout_packet_execute(self, packet, relayer);
+
+        (extras, result.map_err(packet_error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams, GasScheduleParams},
+        context::{QueryableContext, TransactionalContext},
+        derive::{ParamsKeys, StoreKeys},
+        extensions::{
+            pagination::{Pagination, PaginationResult},
+            testing::UnwrapTesting,
+        },
+        gas::{metering::GasMeter, store::errors::GasStoreErrors},
+        store::database::MemDB,
+        tendermint::types::proto::header::Header,
+        types::{denom::Denom, tx::metadata::Metadata, uint::Uint256},
+        utils::node::build_store,
+        x::{
+            errors::{BankKeeperError, InsufficientFundsError},
+            keepers::bank::{BalancesKeeper, BankKeeper},
+        },
+    };
+    use ibc::{
+        apps::transfer::types::{ack_success_b64, packet::PacketData},
+        core::{
+            channel::types::{
+                acknowledgement::{AcknowledgementStatus, StatusValue},
+                timeout::TimeoutHeight,
+            },
+            host::types::identifiers::Sequence,
+        },
+        primitives::Timestamp,
+    };
+
+    use super::*;
+
+    const SENDER: &str = "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux";
+    const RECEIVER: &str = "cosmos1sgr6qxx30le5wtc4mu6c4tcsxk0ka7vu2c62pz";
+    /// Voucher of `uatom` received through `channel-0`
+    const VOUCHER: &str = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    #[test]
+    fn recv_packet_mints_vouchers() {
+        let bank_keeper = FundsBankKeeper::default();
+        let (_, ack) = with_module(&bank_keeper, |module| {
+            module.on_recv_packet_execute(&packet(0, "uatom"), &relayer())
+        });
+
+        assert_eq!(
+            ack,
+            AcknowledgementStatus::success(ack_success_b64()).into()
+        );
+        assert_eq!(bank_keeper.amount(RECEIVER, VOUCHER), Uint256::from(100u32));
+        assert_eq!(
+            bank_keeper.amount(module_address(), VOUCHER),
+            Uint256::zero()
+        );
+    }
+
+    #[test]
+    fn recv_packet_of_returned_tokens_unescrows_them() {
+        let bank_keeper = FundsBankKeeper::default().with(escrow(), "uatom", 150);
+        let (_, ack) = with_module(&bank_keeper, |module| {
+            module.on_recv_packet_execute(&packet(0, "transfer/channel-7/uatom"), &relayer())
+        });
+
+        assert_eq!(
+            ack,
+            AcknowledgementStatus::success(ack_success_b64()).into()
+        );
+        assert_eq!(bank_keeper.amount(RECEIVER, "uatom"), Uint256::from(100u32));
+        assert_eq!(bank_keeper.amount(escrow(), "uatom"), Uint256::from(50u32));
+    }
+
+    #[test]
+    fn error_acknowledgement_refunds_sender() {
+        let bank_keeper = FundsBankKeeper::default().with(escrow(), "uatom", 100);
+        let ack: Acknowledgement = AcknowledgementStatus::error(
+            StatusValue::new("receiver is blocked").expect("hardcoded is valid"),
+        )
+        .into();
+
+        let (_, result) = with_module(&bank_keeper, |module| {
+            module.on_acknowledgement_packet_validate(&sent_packet("uatom"), &ack, &relayer())?;
+            Ok::<_, PacketError>(module.on_acknowledgement_packet_execute(
+                &sent_packet("uatom"),
+                &ack,
+                &relayer(),
+            ))
+        })
+        .unwrap_test();
+
+        assert!(result.is_ok());
+        assert_eq!(bank_keeper.amount(SENDER, "uatom"), Uint256::from(100u32));
+        assert_eq!(bank_keeper.amount(escrow(), "uatom"), Uint256::zero());
+    }
+
+    #[test]
+    fn success_acknowledgement_keeps_tokens_escrowed() {
+        let bank_keeper = FundsBankKeeper::default().with(escrow(), "uatom", 100);
+        let ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+
+        let (_, result) = with_module(&bank_keeper, |module| {
+            module.on_acknowledgement_packet_execute(&sent_packet("uatom"), &ack, &relayer())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(bank_keeper.amount(SENDER, "uatom"), Uint256::zero());
+        assert_eq!(bank_keeper.amount(escrow(), "uatom"), Uint256::from(100u32));
+    }
+
+    #[test]
+    fn timeout_refunds_sender() {
+        let bank_keeper = FundsBankKeeper::default().with(escrow(), "uatom", 100);
+
+        let (_, result) = with_module(&bank_keeper, |module| {
+            module.on_timeout_packet_validate(&sent_packet("uatom"), &relayer())?;
+            Ok::<_, PacketError>(
+                module.on_timeout_packet_execute(&sent_packet("uatom"), &relayer()),
+            )
+        })
+        .unwrap_test();
+
+        assert!(result.is_ok());
+        assert_eq!(bank_keeper.amount(SENDER, "uatom"), Uint256::from(100u32));
+        assert_eq!(bank_keeper.amount(escrow(), "uatom"), Uint256::zero());
+    }
+
+    #[test]
+    fn timeout_of_vouchers_mints_them_back() {
+        let bank_keeper = FundsBankKeeper::default();
+
+        let (_, result) = with_module(&bank_keeper, |module| {
+            module.on_timeout_packet_execute(&sent_packet("transfer/channel-0/uatom"), &relayer())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(bank_keeper.amount(SENDER, VOUCHER), Uint256::from(100u32));
+    }
+
+    #[test]
+    fn sent_vouchers_are_burned() {
+        let bank_keeper = FundsBankKeeper::default().with(SENDER, VOUCHER, 150);
+        let sender = TransferAccount(AccAddress::from_bech32(SENDER).unwrap_test());
+        let coin: PrefixedCoin = "100transfer/channel-0/uatom"
+            .parse()
+            .expect("hardcoded is valid");
+
+        with_module(&bank_keeper, |module| {
+            module.burn_coins_validate(&sender, &coin, &Memo::from(String::new()))?;
+            module.burn_coins_execute(&sender, &coin, &Memo::from(String::new()))
+        })
+        .unwrap_test();
+
+        assert_eq!(bank_keeper.amount(SENDER, VOUCHER), Uint256::from(50u32));
+        assert_eq!(
+            bank_keeper.amount(module_address(), VOUCHER),
+            Uint256::zero()
+        );
+        assert_eq!(bank_keeper.supply_of(VOUCHER), Uint256::from(50u32));
+
+        // the sender has only 50 vouchers left
+        let result = with_module(&bank_keeper, |module| {
+            module.burn_coins_validate(&sender, &coin, &Memo::from(String::new()))
+        });
+        assert!(result.is_err());
+    }
+
+    /// Runs `f` with the transfer module on top of a fresh transaction context
+    fn with_module<T>(
+        bank_keeper: &FundsBankKeeper,
+        f: impl FnOnce(
+            &mut TransferModule<'_, '_, MemDB, SubspaceKey, FundsBankKeeper, TransferModuleAccount>,
+        ) -> T,
+    ) -> T {
+        let keeper = Keeper::new(SubspaceKey::Ibc, bank_keeper.clone(), TransferModuleAccount);
+        let mut multi_store = build_store::<SubspaceKey>().to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasScheduleParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+        let gears_ctx = RefCell::new(&mut ctx);
+
+        f(&mut TransferModule::new(&gears_ctx, &keeper))
+    }
+
+    /// Packet from `channel-7` of the counterparty to `channel-0` of this chain
+    fn packet(sequence: u64, denom: &str) -> Packet {
+        let data = PacketData {
+            token: format!("100{denom}").parse().expect("hardcoded is valid"),
+            sender: Signer::from(SENDER.to_owned()),
+            receiver: Signer::from(RECEIVER.to_owned()),
+            memo: Memo::from(String::new()),
+        };
+
+        Packet {
+            seq_on_a: Sequence::from(sequence),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(7),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(0),
+            data: serde_json::to_vec(&data).unwrap_test(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    /// Packet from `channel-0` of this chain to `channel-7` of the counterparty
+    fn sent_packet(denom: &str) -> Packet {
+        let Packet {
+            seq_on_a,
+            port_id_on_a,
+            chan_id_on_a,
+            port_id_on_b,
+            chan_id_on_b,
+            data,
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        } = packet(1, denom);
+
+        Packet {
+            seq_on_a,
+            port_id_on_a: port_id_on_b,
+            chan_id_on_a: chan_id_on_b,
+            port_id_on_b: port_id_on_a,
+            chan_id_on_b: chan_id_on_a,
+            data,
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        }
+    }
+
+    fn relayer() -> Signer {
+        Signer::from(SENDER.to_owned())
+    }
+
+    fn escrow() -> AccAddress {
+        escrow_address(&PortId::transfer(), &ChannelId::new(0))
+    }
+
+    fn module_address() -> AccAddress {
+        TransferModuleAccount.address()
+    }
+
+    /// Bank keeper which keeps the balances in memory
+    #[derive(Debug, Clone, Default)]
+    struct FundsBankKeeper {
+        balances: Arc<Mutex<HashMap<(AccAddress, Denom), Uint256>>>,
+    }
+
+    impl FundsBankKeeper {
+        fn with(self, address: impl IntoAddress, denom: &str, amount: u32) -> Self {
+            self.add(
+                &address.into_address(),
+                &denom.parse().unwrap_test(),
+                amount.into(),
+            );
+            self
+        }
+
+        fn amount(&self, address: impl IntoAddress, denom: &str) -> Uint256 {
+            self.balances
+                .lock()
+                .unwrap_test()
+                .get(&(address.into_address(), denom.parse().unwrap_test()))
+                .copied()
+                .unwrap_or_default()
+        }
+
+        fn supply_of(&self, denom: &str) -> Uint256 {
+            let denom: Denom = denom.parse().unwrap_test();
+
+            self.balances
+                .lock()
+                .unwrap_test()
+                .iter()
+                .filter(|((_, balance_denom), _)| *balance_denom == denom)
+                .fold(Uint256::zero(), |supply, (_, amount)| supply + *amount)
+        }
+
+        fn add(&self, address: &AccAddress, denom: &Denom, amount: Uint256) {
+            *self
+                .balances
+                .lock()
+                .unwrap_test()
+                .entry((address.clone(), denom.clone()))
+                .or_default() += amount;
+        }
+
+        fn sub(
+            &self,
+            address: &AccAddress,
+            denom: &Denom,
+            amount: Uint256,
+        ) -> Result<(), BankKeeperError> {
+            let mut balances = self.balances.lock().unwrap_test();
+            let balance = balances
+                .entry((address.clone(), denom.clone()))
+                .or_default();
+            let actual = *balance;
+
+            *balance =
+                actual
+                    .checked_sub(amount)
+                    .map_err(|_| InsufficientFundsError::RequiredActual {
+                        required: amount,
+                        actual,
+                    })?;
+
+            Ok(())
+        }
+
+        fn transfer(
+            &self,
+            from: &AccAddress,
+            to: &AccAddress,
+            amount: UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            for coin in amount {
+                self.sub(from, &coin.denom, coin.amount)?;
+                self.add(to, &coin.denom, coin.amount);
+            }
+
+            Ok(())
+        }
+    }
+
+    trait IntoAddress {
+        fn into_address(self) -> AccAddress;
+    }
+
+    impl IntoAddress for AccAddress {
+        fn into_address(self) -> AccAddress {
+            self
+        }
+    }
+
+    impl IntoAddress for &str {
+        fn into_address(self) -> AccAddress {
+            AccAddress::from_bech32(self).unwrap_test()
+        }
+    }
+
+    impl<SK: StoreKey, M: AppModule> BalancesKeeper<SK, M> for FundsBankKeeper {
+        fn balance_all<DB: Database, CTX: QueryableContext<DB, SK>>(
+            &self,
+            _ctx: &CTX,
+            address: AccAddress,
+            _pagination: Option<Pagination>,
+        ) -> Result<(Option<PaginationResult>, Vec<UnsignedCoin>), GasStoreErrors> {
+            let balances = self
+                .balances
+                .lock()
+                .unwrap_test()
+                .iter()
+                .filter(|((balance_address, _), _)| *balance_address == address)
+                .map(|((_, denom), amount)| UnsignedCoin {
+                    denom: denom.clone(),
+                    amount: *amount,
+                })
+                .collect();
+
+            Ok((None, balances))
+        }
+
+        fn supply<DB: Database, CTX: QueryableContext<DB, SK>>(
+            &self,
+            _ctx: &CTX,
+            denom: &Denom,
+        ) -> Result<Option<UnsignedCoin>, GasStoreErrors> {
+            Ok(Some(UnsignedCoin {
+                denom: denom.clone(),
+                amount: self.supply_of(denom.as_str()),
+            }))
+        }
+    }
+
+    impl<SK: StoreKey, M: AppModule> BankKeeper<SK, M> for FundsBankKeeper {
+        fn send_coins_from_account_to_module<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            from_address: AccAddress,
+            to_module: &M,
+            amount: UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            self.transfer(&from_address, &to_module.address(), amount)
+        }
+
+        fn send_coins_from_module_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            address: &AccAddress,
+            module: &M,
+            amount: UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            self.transfer(&module.address(), address, amount)
+        }
+
+        fn send_coins_from_account_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            msg: &MsgSend,
+        ) -> Result<(), BankKeeperError> {
+            self.transfer(&msg.from_address, &msg.to_address, msg.amount.clone())
+        }
+
+        fn send_coins_from_module_to_module<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            sender_pool: &M,
+            recepient_pool: &M,
+            amount: UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            self.transfer(&sender_pool.address(), &recepient_pool.address(), amount)
+        }
+
+        fn denom_metadata<DB: Database, CTX: QueryableContext<DB, SK>>(
+            &self,
+            _ctx: &CTX,
+            _base: &Denom,
+        ) -> Result<Option<Metadata>, GasStoreErrors> {
+            Ok(None)
+        }
+
+        fn coins_burn<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            module: &M,
+            deposit: &UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            for coin in deposit.clone() {
+                self.sub(&module.address(), &coin.denom, coin.amount)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<SK: StoreKey, M: AppModule> MintingBankKeeper<SK, M> for FundsBankKeeper {
+        fn mint_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
+            &self,
+            _ctx: &mut CTX,
+            module: &M,
+            amount: UnsignedCoins,
+        ) -> Result<(), BankKeeperError> {
+            for coin in amount {
+                self.add(&module.address(), &coin.denom, coin.amount);
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<SK: StoreKey, M: AppModule> GovernanceBankKeeper<SK, M> for FundsBankKeeper {
+        fn balance<DB: Database, CTX: QueryableContext<DB, SK>>(
+            &self,
+            _ctx: &CTX,
+            address: &AccAddress,
+            denom: &Denom,
+        ) -> Result<UnsignedCoin, GasStoreErrors> {
+            Ok(UnsignedCoin {
+                denom: denom.clone(),
+                amount: self.amount(address.clone(), denom.as_str()),
+            })
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct TransferModuleAccount;
+
+    impl AppModule for TransferModuleAccount {
+        fn name(&self) -> String {
+            "transfer".to_owned()
+        }
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "ibc")]
+        #[pkey(to_string = "ibc")]
+        Ibc,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
+}
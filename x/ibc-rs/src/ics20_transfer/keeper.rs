@@ -0,0 +1,203 @@
+use gears::{
+    context::{query::QueryContext, QueryableContext, TransactionalContext},
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
+    gas::store::errors::GasStoreErrors,
+    store::{database::Database, StoreKey},
+    types::{address::AccAddress, base::coin::UnsignedCoin, denom::Denom, uint::Uint256},
+    x::{keepers::mint::MintingBankKeeper, module::Module},
+};
+use ibc::{
+    apps::transfer::types::{
+        error::TokenTransferError,
+        proto::transfer::v1::{DenomTrace, QueryDenomTraceRequest, QueryDenomTraceResponse},
+        PrefixedCoin, PrefixedDenom, VERSION,
+    },
+    core::host::types::identifiers::{ChannelId, PortId},
+};
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+const KEY_DENOM_TRACE_PREFIX: &str = "denomTrace";
+
+/// Prefix of the denominations of the vouchers minted for tokens received from other chains
+pub const VOUCHER_DENOM_PREFIX: &str = "ibc/";
+
+#[derive(Debug, Clone)]
+pub struct Keeper<SK, BK, M> {
+    store_key: SK,
+    pub(crate) bank_keeper: BK,
+    /// Module account which mints and burns the vouchers
+    pub(crate) transfer_module: M,
+}
+
+impl<SK: StoreKey, BK: MintingBankKeeper<SK, M>, M: Module> Keeper<SK, BK, M> {
+    pub fn new(store_key: SK, bank_keeper: BK, transfer_module: M) -> Self {
+        Self {
+            store_key,
+            bank_keeper,
+            transfer_module,
+        }
+    }
+
+    /// Returns the trace of the voucher denomination with the given hash
+    pub fn denom_trace<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        hash: &str,
+    ) -> Result<Option<DenomTrace>, GasStoreErrors> {
+        let key = format!("{KEY_DENOM_TRACE_PREFIX}/{}", hash.to_uppercase());
+        let denom_trace = ctx
+            .kv_store(&self.store_key)
+            .get(key.as_bytes())?
+            .map(|raw| DenomTrace::decode(raw.as_slice()).unwrap_or_corrupt());
+
+        Ok(denom_trace)
+    }
+
+    /// Returns the traces of all vouchers ordered by their hashes
+    pub fn denom_traces<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Vec<(String, DenomTrace)>, GasStoreErrors> {
+        let store = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(format!("{KEY_DENOM_TRACE_PREFIX}/").into_bytes());
+
+        let mut denom_traces = vec![];
+        for res in store.into_range(..) {
+            let (key, value) = res?;
+            denom_traces.push((
+                String::from_utf8(key.to_vec()).unwrap_or_corrupt(),
+                DenomTrace::decode(value.as_slice()).unwrap_or_corrupt(),
+            ));
+        }
+
+        Ok(denom_traces)
+    }
+
+    pub fn denom_trace_set<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        denom: &PrefixedDenom,
+    ) -> Result<(), GasStoreErrors> {
+        let key = format!("{KEY_DENOM_TRACE_PREFIX}/{}", denom_hash(denom));
+        let denom_trace = DenomTrace {
+            path: denom.trace_path.to_string(),
+            base_denom: denom.base_denom.to_string(),
+        };
+
+        ctx.kv_store_mut(&self.store_key)
+            .set(key.into_bytes(), denom_trace.encode_to_vec())
+    }
+
+    /// Query the trace of a voucher denomination. The hash may be given with or without
+    /// the `ibc/` prefix.
+    pub fn query_denom_trace<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDenomTraceRequest { hash }: QueryDenomTraceRequest,
+    ) -> QueryDenomTraceResponse {
+        let hash = hash.strip_prefix(VOUCHER_DENOM_PREFIX).unwrap_or(&hash);
+
+        QueryDenomTraceResponse {
+            denom_trace: self.denom_trace(ctx, hash).unwrap_gas(),
+        }
+    }
+
+    /// Users refer to vouchers by their `ibc/<hash>` denomination. ibc-rs expects the
+    /// full trace of the token, so the denomination is replaced by its stored trace.
+    pub fn prefixed_denom<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        denom: PrefixedDenom,
+    ) -> Result<PrefixedDenom, TokenTransferError> {
+        let base_denom = denom.base_denom.to_string();
+        let Some(hash) = base_denom.strip_prefix(VOUCHER_DENOM_PREFIX) else {
+            return Ok(denom);
+        };
+        if !denom.trace_path.is_empty() {
+            return Ok(denom);
+        }
+
+        let DenomTrace { path, base_denom } = self
+            .denom_trace(ctx, hash)
+            .map_err(|e| TokenTransferError::Other(e.to_string()))?
+            .ok_or_else(|| {
+                TokenTransferError::Other(format!("denomination trace not found: {hash}"))
+            })?;
+
+        format!("{path}/{base_denom}").parse()
+    }
+}
+
+/// Returns the denomination of the token on this chain, i.e. the base denomination of
+/// native tokens and `ibc/<hash>` of vouchers
+pub fn local_denom(denom: &PrefixedDenom) -> String {
+    if denom.trace_path.is_empty() {
+        denom.base_denom.to_string()
+    } else {
+        format!("{VOUCHER_DENOM_PREFIX}{}", denom_hash(denom))
+    }
+}
+
+/// Returns the uppercase hex encoded sha256 hash of the full trace of the denomination
+pub fn denom_hash(denom: &PrefixedDenom) -> String {
+    hex::encode_upper(Sha256::digest(denom.to_string()))
+}
+
+/// Converts the token into a coin of this chain
+pub fn local_coin(coin: &PrefixedCoin) -> Result<UnsignedCoin, TokenTransferError> {
+    let denom = local_denom(&coin.denom)
+        .parse::<Denom>()
+        .map_err(|e| TokenTransferError::Other(e.to_string()))?;
+    let amount = coin
+        .amount
+        .to_string()
+        .parse::<Uint256>()
+        .map_err(|e| TokenTransferError::Other(e.to_string()))?;
+
+    Ok(UnsignedCoin { denom, amount })
+}
+
+/// Returns the address of the account which escrows the tokens sent through the channel.
+/// It's derived the same way as in ibc-go so that escrow accounts match across implementations.
+pub fn escrow_address(port_id: &PortId, channel_id: &ChannelId) -> AccAddress {
+    let mut hasher = Sha256::new();
+    hasher.update(VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(format!("{port_id}/{channel_id}").as_bytes());
+    let hash = hasher.finalize();
+
+    AccAddress::try_from(hash[..20].to_vec())
+        .expect("vector of 20 bytes can't produce error because 0 < 20 < MAX_ADDR_LEN")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_denom_of_tokens() {
+        let native: PrefixedDenom = "uatom".parse().expect("hardcoded is valid");
+        assert_eq!(local_denom(&native), "uatom");
+
+        // ATOM on Osmosis
+        let voucher: PrefixedDenom = "transfer/channel-0/uatom"
+            .parse()
+            .expect("hardcoded is valid");
+        assert_eq!(
+            local_denom(&voucher),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn escrow_address_per_channel() {
+        let port_id = PortId::transfer();
+        let channel_0 = escrow_address(&port_id, &ChannelId::new(0));
+        let channel_1 = escrow_address(&port_id, &ChannelId::new(1));
+
+        assert_ne!(channel_0, channel_1);
+        assert_eq!(channel_0, escrow_address(&port_id, &ChannelId::new(0)));
+    }
+}
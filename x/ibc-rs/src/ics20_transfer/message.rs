@@ -0,0 +1,82 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError},
+    types::{address::AccAddress, tx::TxMessage},
+};
+use ibc::{
+    apps::transfer::types::{msgs::transfer, proto::transfer::v1::MsgTransfer as RawMsgTransfer},
+    primitives::proto::Protobuf,
+};
+use serde::Serialize;
+
+/// Transfers tokens to an account of the counterparty chain. Unlike the messages of
+/// IBC core, the message has no signer field, it's signed by the sender of the tokens.
+#[derive(Clone, Debug)]
+pub struct MsgTransfer {
+    pub inner: transfer::MsgTransfer,
+    pub sender: AccAddress,
+}
+
+impl MsgTransfer {
+    pub const TYPE_URL: &'static str = "/ibc.applications.transfer.v1.MsgTransfer";
+}
+
+impl TxMessage for MsgTransfer {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.sender]
+    }
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+}
+
+impl From<MsgTransfer> for Any {
+    fn from(msg: MsgTransfer) -> Self {
+        Any {
+            type_url: MsgTransfer::TYPE_URL.to_string(),
+            value: prost::Message::encode_to_vec(&RawMsgTransfer::from(msg)),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgTransfer {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        match value.type_url.as_str() {
+            Self::TYPE_URL => {
+                Self::decode_vec(&value.value).map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+            }
+            _ => Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            )),
+        }
+    }
+}
+
+impl Protobuf<RawMsgTransfer> for MsgTransfer {}
+
+impl TryFrom<RawMsgTransfer> for MsgTransfer {
+    type Error = CoreError;
+
+    fn try_from(raw: RawMsgTransfer) -> Result<Self, Self::Error> {
+        let sender = AccAddress::from_bech32(&raw.sender)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+        let inner = transfer::MsgTransfer::try_from(raw)
+            .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?;
+
+        Ok(Self { inner, sender })
+    }
+}
+
+impl From<MsgTransfer> for RawMsgTransfer {
+    fn from(msg: MsgTransfer) -> Self {
+        msg.inner.into()
+    }
+}
+
+impl Serialize for MsgTransfer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawMsgTransfer::from(self.clone()).serialize(serializer)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod client;
+mod context;
+mod keeper;
+pub mod message;
+
+pub use context::TransferModule;
+pub use keeper::Keeper;
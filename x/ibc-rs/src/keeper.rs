@@ -1,37 +1,120 @@
+use std::{borrow::Cow, cell::RefCell};
+
 use gears::{
-    context::{init::InitContext, query::QueryContext, tx::TxContext},
+    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    extensions::{gas::GasResultExt, pagination::IteratorPaginate},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
+    types::pagination::{
+        request::{PaginationKind, PaginationRequest},
+        response::PaginationResponse,
+    },
+    x::{
+        keepers::{gov::GovernanceBankKeeper, mint::MintingBankKeeper},
+        module::Module,
+    },
 };
+use vec1::Vec1;
 
 use crate::{
+    errors::query::IbcQueryError,
     ics02_client::{
-        message::MsgCreateClient, types::query::QueryClientStatesResponse, Keeper as ClientKeeper,
+        message::MsgCreateClient,
+        types::{client_state::ClientState, query::QueryClientStatesResponse},
+        Keeper as ClientKeeper,
     },
     ics03_connection::Keeper as ConnectionKeeper,
     ics04_channel::Keeper as ChannelKeeper,
+    ics20_transfer::{message::MsgTransfer, Keeper as TransferKeeper, TransferModule},
+    types::{context::Context, genesis::GenesisState, router::IbcRouter},
+};
+use ibc::apps::transfer::{
+    handler::send_transfer,
     types::{
-        context::{ClientRouter, Context},
-        genesis::GenesisState,
+        error::TokenTransferError,
+        proto::transfer::v1::{
+            Params as TransferParams, QueryDenomTraceRequest, QueryDenomTraceResponse,
+            QueryDenomTracesRequest, QueryDenomTracesResponse,
+            QueryParamsRequest as QueryTransferParamsRequest,
+            QueryParamsResponse as QueryTransferParamsResponse,
+        },
+        MODULE_ID_STR,
     },
 };
-use ibc::core::{client::types::proto::v1::QueryClientStatesRequest, entrypoint::dispatch};
+use ibc::core::{
+    channel::types::proto::v1::{
+        PacketState as RawPacketState, QueryChannelRequest, QueryChannelResponse,
+        QueryChannelsRequest, QueryChannelsResponse, QueryConnectionChannelsRequest,
+        QueryConnectionChannelsResponse, QueryNextSequenceReceiveRequest,
+        QueryNextSequenceReceiveResponse, QueryPacketAcknowledgementRequest,
+        QueryPacketAcknowledgementResponse, QueryPacketCommitmentRequest,
+        QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+        QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    },
+    client::types::{
+        proto::v1::{
+            ConsensusStateWithHeight, Params as RawClientParams, QueryClientParamsRequest,
+            QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+            QueryClientStatesRequest, QueryClientStatusRequest, QueryClientStatusResponse,
+            QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+            QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+            QueryConsensusStatesResponse,
+        },
+        Height,
+    },
+    connection::types::proto::v1::{
+        QueryClientConnectionsRequest, QueryClientConnectionsResponse, QueryConnectionRequest,
+        QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
+    },
+    entrypoint::dispatch,
+    handler::types::{error::ContextError, msgs::MsgEnvelope},
+    host::types::{
+        identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence},
+        path::{AckPath, ClientConsensusStatePath, CommitmentPath, ReceiptPath, SeqRecvPath},
+    },
+    router::types::module::ModuleId,
+};
+
+/// Status of a client which doesn't exist or whose status can't be determined
+const STATUS_UNKNOWN: &str = "Unknown";
 
+/// Keeper of the ibc module.
+///
+/// The queries return no commitment proofs. IAVL stores can prove their keys with
+/// `trees::iavl::QueryTree::get_proof`, but the multi store doesn't prove store roots against
+/// the app hash and queries don't reach a versioned tree, so a counterparty can't verify the
+/// state of this chain yet. The handshakes started by a Gears chain stop after their first step
+/// and its packets can't be relayed.
 #[derive(Debug, Clone)]
-pub struct Keeper<SK, PSK> {
+pub struct Keeper<SK, PSK, BK, M> {
     _store_key: SK, //TOOD: remove this
     client_keeper: ClientKeeper<SK, PSK>,
     connection_keeper: ConnectionKeeper<SK, PSK>,
     channel_keeper: ChannelKeeper<SK>,
+    transfer_keeper: TransferKeeper<SK, BK, M>,
 }
 
-impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
-    pub fn new(store_key: SK, params_subspace_key: PSK) -> Self {
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        BK: MintingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        M: Module,
+    > Keeper<SK, PSK, BK, M>
+{
+    /// The transfer module account mints and burns the vouchers, so it requires the
+    /// minter and burner permissions
+    pub fn new(
+        store_key: SK,
+        params_subspace_key: PSK,
+        bank_keeper: BK,
+        transfer_module: M,
+    ) -> Self {
         Self {
             _store_key: store_key.clone(),
             client_keeper: ClientKeeper::new(store_key.clone(), params_subspace_key.clone()),
             connection_keeper: ConnectionKeeper::new(store_key.clone(), params_subspace_key),
-            channel_keeper: ChannelKeeper::new(store_key),
+            channel_keeper: ChannelKeeper::new(store_key.clone()),
+            transfer_keeper: TransferKeeper::new(store_key, bank_keeper, transfer_module),
         }
     }
 
@@ -47,22 +130,69 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
             .init_genesis(ctx, genesis.channel_genesis);
     }
 
+    /// Stores the consensus state of this chain so that the handshakes can verify the
+    /// clients which counterparty chains use to track it
+    pub fn begin_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        let header = ctx.header.clone();
+        self.client_keeper.self_consensus_state_set(ctx, &header);
+    }
+
     pub fn client_create<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: MsgCreateClient,
-    ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        let mut ctx = Context {
-            gears_ctx: ctx,
+    ) -> Result<(), ContextError> {
+        self.dispatch(ctx, msg.into())
+    }
+
+    /// Validates and executes the message with the ibc-rs handlers
+    pub fn dispatch<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: MsgEnvelope,
+    ) -> Result<(), ContextError> {
+        // the handlers and the applications which they call back share the context
+        let gears_ctx = RefCell::new(ctx);
+        let mut ctx = self.context(&gears_ctx);
+
+        let mut router = IbcRouter::default().add_route(
+            PortId::transfer(),
+            ModuleId::new(MODULE_ID_STR.to_owned()),
+            TransferModule::new(&gears_ctx, &self.transfer_keeper),
+        );
+
+        dispatch(&mut ctx, &mut router, msg)
+    }
+
+    /// Escrows or burns the tokens and sends the transfer packet to the counterparty
+    pub fn transfer<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: MsgTransfer,
+    ) -> Result<(), TokenTransferError> {
+        let mut msg = msg.inner;
+        msg.packet_data.token.denom = self
+            .transfer_keeper
+            .prefixed_denom(&*ctx, msg.packet_data.token.denom)?;
+
+        let gears_ctx = RefCell::new(ctx);
+        let mut ctx = self.context(&gears_ctx);
+        let mut transfer_module = TransferModule::new(&gears_ctx, &self.transfer_keeper);
+
+        send_transfer(&mut ctx, &mut transfer_module, msg)
+    }
+
+    fn context<'a, 'b, DB: Database>(
+        &'a self,
+        gears_ctx: &'a RefCell<&'a mut TxContext<'b, DB, SK>>,
+    ) -> Context<'a, 'b, DB, SK, PSK> {
+        Context {
+            gears_ctx,
             client_keeper: &self.client_keeper,
             connection_keeper: &self.connection_keeper,
             channel_keeper: &self.channel_keeper,
             store_key: self._store_key.clone(),
-        };
-
-        let mut router = ClientRouter;
-
-        dispatch(&mut ctx, &mut router, msg.into())
+        }
     }
 
     pub fn client_states<DB: Database>(
@@ -72,4 +202,783 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
     ) -> QueryClientStatesResponse {
         self.client_keeper.client_states(ctx, req)
     }
+
+    /// Query the client state
+    pub fn client_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryClientStateRequest { client_id }: QueryClientStateRequest,
+    ) -> Result<QueryClientStateResponse, IbcQueryError> {
+        let client_state = self
+            .client_keeper
+            .client_state(ctx, &client_id.parse()?)
+            .unwrap_gas();
+
+        Ok(QueryClientStateResponse {
+            client_state: client_state.map(Into::into),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the status of the client at the time of the queried block
+    pub fn client_status<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryClientStatusRequest { client_id }: QueryClientStatusRequest,
+    ) -> Result<QueryClientStatusResponse, IbcQueryError> {
+        use gears::context::QueryableContext;
+
+        let client_id = client_id.parse::<ClientId>()?;
+
+        // the query context has no block time, the time of the block is the timestamp of the
+        // consensus state of this chain stored at its beginning
+        let now = self
+            .client_keeper
+            .self_consensus_state(ctx, ctx.height().into())
+            .unwrap_gas()
+            .map(|consensus_state| consensus_state.timestamp.unix_timestamp_nanos());
+
+        let status = match now {
+            Some(now) => self
+                .client_keeper
+                .client_status(ctx, &client_id, now)
+                .unwrap_gas(),
+            None => None,
+        };
+
+        Ok(QueryClientStatusResponse {
+            status: status
+                .map(|status| status.to_string())
+                .unwrap_or(STATUS_UNKNOWN.to_owned()),
+        })
+    }
+
+    /// Query the consensus state of the client at the given height, or at the latest height
+    /// of the client if `latest_height` is set
+    pub fn consensus_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConsensusStateRequest {
+            client_id,
+            revision_number,
+            revision_height,
+            latest_height,
+        }: QueryConsensusStateRequest,
+    ) -> Result<QueryConsensusStateResponse, IbcQueryError> {
+        let client_id = client_id.parse::<ClientId>()?;
+
+        let height = if latest_height {
+            self.client_keeper
+                .client_state(ctx, &client_id)
+                .unwrap_gas()
+                .map(|ClientState::Tendermint(client_state)| client_state.inner().latest_height)
+        } else {
+            Some(Height::new(revision_number, revision_height)?)
+        };
+
+        let consensus_state = match height {
+            Some(height) => self
+                .client_keeper
+                .consensus_state(
+                    ctx,
+                    &ClientConsensusStatePath::new(
+                        client_id,
+                        height.revision_number(),
+                        height.revision_height(),
+                    ),
+                )
+                .unwrap_gas(),
+            None => None,
+        };
+
+        Ok(QueryConsensusStateResponse {
+            consensus_state: consensus_state.map(Into::into),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the consensus states of the client
+    pub fn consensus_states<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConsensusStatesRequest {
+            client_id,
+            pagination,
+        }: QueryConsensusStatesRequest,
+    ) -> Result<QueryConsensusStatesResponse, IbcQueryError> {
+        let client_id = client_id.parse::<ClientId>()?;
+
+        let heights = self
+            .client_keeper
+            .consensus_state_heights(ctx, &client_id)
+            .unwrap_gas();
+        let (p_result, heights) = heights
+            .into_iter()
+            .map(|height| {
+                (
+                    Cow::<Vec<u8>>::Owned(height.to_string().into_bytes()),
+                    height,
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut consensus_states = vec![];
+        for (_, height) in heights {
+            let consensus_state = self
+                .client_keeper
+                .consensus_state(
+                    ctx,
+                    &ClientConsensusStatePath::new(
+                        client_id.clone(),
+                        height.revision_number(),
+                        height.revision_height(),
+                    ),
+                )
+                .unwrap_gas();
+
+            if let Some(consensus_state) = consensus_state {
+                consensus_states.push(ConsensusStateWithHeight {
+                    height: Some(height.into()),
+                    consensus_state: Some(consensus_state.into()),
+                });
+            }
+        }
+
+        let mut response = QueryConsensusStatesResponse {
+            consensus_states,
+            pagination: None,
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    /// Query the heights of the consensus states of the client
+    pub fn consensus_state_heights<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConsensusStateHeightsRequest {
+            client_id,
+            pagination,
+        }: QueryConsensusStateHeightsRequest,
+    ) -> Result<QueryConsensusStateHeightsResponse, IbcQueryError> {
+        let heights = self
+            .client_keeper
+            .consensus_state_heights(ctx, &client_id.parse()?)
+            .unwrap_gas();
+        let (p_result, heights) = heights
+            .into_iter()
+            .map(|height| {
+                (
+                    Cow::<Vec<u8>>::Owned(height.to_string().into_bytes()),
+                    height,
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryConsensusStateHeightsResponse {
+            consensus_state_heights: heights.map(|(_, height)| height.into()).collect(),
+            pagination: None,
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    pub fn client_params<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _req: QueryClientParamsRequest,
+    ) -> QueryClientParamsResponse {
+        let params = self.client_keeper.params(ctx).unwrap_gas();
+
+        QueryClientParamsResponse {
+            params: Some(RawClientParams {
+                allowed_clients: params.allowed_clients,
+            }),
+        }
+    }
+
+    pub fn denom_trace<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        req: QueryDenomTraceRequest,
+    ) -> QueryDenomTraceResponse {
+        self.transfer_keeper.query_denom_trace(ctx, req)
+    }
+
+    /// Query the traces of all vouchers
+    pub fn denom_traces<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDenomTracesRequest { pagination }: QueryDenomTracesRequest,
+    ) -> QueryDenomTracesResponse {
+        let denom_traces = self.transfer_keeper.denom_traces(ctx).unwrap_gas();
+        let (p_result, denom_traces) = denom_traces
+            .into_iter()
+            .map(|(hash, denom_trace)| (Cow::<Vec<u8>>::Owned(hash.into_bytes()), denom_trace))
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryDenomTracesResponse {
+            denom_traces: denom_traces.map(|(_, denom_trace)| denom_trace).collect(),
+            pagination: None,
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        response
+    }
+
+    /// Query the parameters of the transfer module. Sending and receiving tokens can't be
+    /// disabled, so both are always enabled.
+    pub fn transfer_params(&self, _req: QueryTransferParamsRequest) -> QueryTransferParamsResponse {
+        QueryTransferParamsResponse {
+            params: Some(TransferParams {
+                send_enabled: true,
+                receive_enabled: true,
+            }),
+        }
+    }
+
+    /// Query all the connection ends
+    pub fn connections<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConnectionsRequest { pagination }: QueryConnectionsRequest,
+    ) -> Result<QueryConnectionsResponse, IbcQueryError> {
+        let connections = self.connection_keeper.connections(ctx).unwrap_gas();
+        let (p_result, connections) = connections
+            .into_iter()
+            .map(|connection| {
+                (
+                    Cow::<Vec<u8>>::Owned(connection.connection_id.to_string().into_bytes()),
+                    connection,
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryConnectionsResponse {
+            connections: connections
+                .map(|(_, connection)| connection.into())
+                .collect(),
+            pagination: None,
+            height: Some(query_height(ctx)?.into()),
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    /// Query the connection end
+    pub fn connection<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConnectionRequest { connection_id }: QueryConnectionRequest,
+    ) -> Result<QueryConnectionResponse, IbcQueryError> {
+        let connection = self
+            .connection_keeper
+            .connection_end(ctx, &connection_id.parse()?)
+            .unwrap_gas();
+
+        Ok(QueryConnectionResponse {
+            connection: connection.map(Into::into),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the connections built on top of the client
+    pub fn client_connections<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryClientConnectionsRequest { client_id }: QueryClientConnectionsRequest,
+    ) -> Result<QueryClientConnectionsResponse, IbcQueryError> {
+        let connection_paths = self
+            .connection_keeper
+            .client_connections(ctx, &client_id.parse()?)
+            .unwrap_gas()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        Ok(QueryClientConnectionsResponse {
+            connection_paths,
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the channel end
+    pub fn channel<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryChannelRequest {
+            port_id,
+            channel_id,
+        }: QueryChannelRequest,
+    ) -> Result<QueryChannelResponse, IbcQueryError> {
+        let channel = self
+            .channel_keeper
+            .channel_end(ctx, &port_id.parse()?, &channel_id.parse()?)
+            .unwrap_gas();
+
+        Ok(QueryChannelResponse {
+            channel: channel.map(Into::into),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query all the channel ends
+    pub fn channels<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryChannelsRequest { pagination }: QueryChannelsRequest,
+    ) -> Result<QueryChannelsResponse, IbcQueryError> {
+        let channels = self.channel_keeper.channels(ctx).unwrap_gas();
+        let (p_result, channels) = channels
+            .into_iter()
+            .map(|channel| {
+                (
+                    Cow::<Vec<u8>>::Owned(
+                        format!("{}/{}", channel.port_id, channel.channel_id).into_bytes(),
+                    ),
+                    channel,
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryChannelsResponse {
+            channels: channels.map(|(_, channel)| channel.into()).collect(),
+            pagination: None,
+            height: Some(query_height(ctx)?.into()),
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    /// Query the channel ends whose first hop is the connection
+    pub fn connection_channels<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConnectionChannelsRequest {
+            connection,
+            pagination,
+        }: QueryConnectionChannelsRequest,
+    ) -> Result<QueryConnectionChannelsResponse, IbcQueryError> {
+        let connection_id = connection.parse::<ConnectionId>()?;
+
+        let channels = self.channel_keeper.channels(ctx).unwrap_gas();
+        let (p_result, channels) = channels
+            .into_iter()
+            .filter(|channel| channel.channel_end.connection_hops.first() == Some(&connection_id))
+            .map(|channel| {
+                (
+                    Cow::<Vec<u8>>::Owned(
+                        format!("{}/{}", channel.port_id, channel.channel_id).into_bytes(),
+                    ),
+                    channel,
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryConnectionChannelsResponse {
+            channels: channels.map(|(_, channel)| channel.into()).collect(),
+            pagination: None,
+            height: Some(query_height(ctx)?.into()),
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    /// Query the commitment of a packet sent on the channel, the commitment is empty once
+    /// the packet is acknowledged or timed out
+    pub fn packet_commitment<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryPacketCommitmentRequest {
+            port_id,
+            channel_id,
+            sequence,
+        }: QueryPacketCommitmentRequest,
+    ) -> Result<QueryPacketCommitmentResponse, IbcQueryError> {
+        let path = CommitmentPath::new(
+            &port_id.parse::<PortId>()?,
+            &channel_id.parse::<ChannelId>()?,
+            Sequence::from(sequence),
+        );
+        let commitment = self
+            .channel_keeper
+            .packet_commitment(ctx, &path)
+            .unwrap_gas();
+
+        Ok(QueryPacketCommitmentResponse {
+            commitment: commitment
+                .map(|commitment| commitment.into_vec())
+                .unwrap_or_default(),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the commitments of the packets sent on the channel which aren't acknowledged
+    /// or timed out yet
+    pub fn packet_commitments<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryPacketCommitmentsRequest {
+            port_id,
+            channel_id,
+            pagination,
+        }: QueryPacketCommitmentsRequest,
+    ) -> Result<QueryPacketCommitmentsResponse, IbcQueryError> {
+        let port_id = port_id.parse::<PortId>()?;
+        let channel_id = channel_id.parse::<ChannelId>()?;
+
+        let commitments = self
+            .channel_keeper
+            .packet_commitments(ctx, &port_id, &channel_id)
+            .unwrap_gas();
+        let (p_result, commitments) = commitments
+            .into_iter()
+            .map(|(sequence, commitment)| {
+                (
+                    Cow::<Vec<u8>>::Owned(sequence.to_string().into_bytes()),
+                    (sequence, commitment),
+                )
+            })
+            .maybe_paginate(
+                pagination.map(|page| pagination_request(page.key, page.offset, page.limit)),
+            );
+
+        let mut response = QueryPacketCommitmentsResponse {
+            commitments: commitments
+                .map(|(_, (sequence, commitment))| RawPacketState {
+                    port_id: port_id.to_string(),
+                    channel_id: channel_id.to_string(),
+                    sequence: sequence.value(),
+                    data: commitment.into_vec(),
+                })
+                .collect(),
+            pagination: None,
+            height: Some(query_height(ctx)?.into()),
+        };
+        if let Some(p_result) = p_result {
+            let PaginationResponse { next_key, total } = p_result.into();
+            let page = response.pagination.insert(Default::default());
+            page.next_key = next_key;
+            page.total = total;
+        }
+
+        Ok(response)
+    }
+
+    /// Query whether the packet was received on the channel
+    pub fn packet_receipt<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryPacketReceiptRequest {
+            port_id,
+            channel_id,
+            sequence,
+        }: QueryPacketReceiptRequest,
+    ) -> Result<QueryPacketReceiptResponse, IbcQueryError> {
+        let path = ReceiptPath::new(
+            &port_id.parse::<PortId>()?,
+            &channel_id.parse::<ChannelId>()?,
+            Sequence::from(sequence),
+        );
+
+        Ok(QueryPacketReceiptResponse {
+            received: self
+                .channel_keeper
+                .has_packet_receipt(ctx, &path)
+                .unwrap_gas(),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the acknowledgement commitment of a packet received on the channel
+    pub fn packet_acknowledgement<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryPacketAcknowledgementRequest {
+            port_id,
+            channel_id,
+            sequence,
+        }: QueryPacketAcknowledgementRequest,
+    ) -> Result<QueryPacketAcknowledgementResponse, IbcQueryError> {
+        let path = AckPath::new(
+            &port_id.parse::<PortId>()?,
+            &channel_id.parse::<ChannelId>()?,
+            Sequence::from(sequence),
+        );
+        let acknowledgement = self
+            .channel_keeper
+            .packet_acknowledgement(ctx, &path)
+            .unwrap_gas();
+
+        Ok(QueryPacketAcknowledgementResponse {
+            acknowledgement: acknowledgement
+                .map(|acknowledgement| acknowledgement.into_vec())
+                .unwrap_or_default(),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+
+    /// Query the sequence of the next packet which the channel expects to receive
+    pub fn next_sequence_receive<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryNextSequenceReceiveRequest {
+            port_id,
+            channel_id,
+        }: QueryNextSequenceReceiveRequest,
+    ) -> Result<QueryNextSequenceReceiveResponse, IbcQueryError> {
+        let path = SeqRecvPath::new(
+            &port_id.parse::<PortId>()?,
+            &channel_id.parse::<ChannelId>()?,
+        );
+        let sequence = self
+            .channel_keeper
+            .next_sequence_recv(ctx, &path)
+            .unwrap_gas();
+
+        Ok(QueryNextSequenceReceiveResponse {
+            next_sequence_receive: sequence
+                .map(|sequence| sequence.value())
+                .unwrap_or_default(),
+            proof: vec![],
+            proof_height: Some(query_height(ctx)?.into()),
+        })
+    }
+}
+
+/// Height of the queried block. A block at height zero is never committed.
+fn query_height<DB: Database, SK: StoreKey>(
+    ctx: &QueryContext<DB, SK>,
+) -> Result<Height, IbcQueryError> {
+    use gears::context::QueryableContext;
+
+    Height::new(ctx.chain_id().revision_number(), ctx.height().into())
+        .map_err(|_| IbcQueryError::NotCommitted)
+}
+
+/// Pagination of the request, the page request of the `ibc` crate isn't exported so it's
+/// passed by fields
+fn pagination_request(key: Vec<u8>, offset: u64, limit: u64) -> PaginationRequest {
+    PaginationRequest {
+        kind: match Vec1::try_from_vec(key) {
+            Ok(key) => PaginationKind::Key { key },
+            Err(_) => PaginationKind::Offset {
+                offset: offset.try_into().unwrap_or(u32::MAX),
+            },
+        },
+        limit: limit.try_into().unwrap_or(u8::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        derive::{ParamsKeys, StoreKeys},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        utils::node::build_init_ctx,
+        x::keepers::mocks::bank::MockBankKeeper,
+    };
+    use ibc::core::{
+        channel::types::{
+            packet::PacketState,
+            proto::v1::{
+                Counterparty as RawChannelCounterparty, IdentifiedChannel, PacketSequence,
+            },
+        },
+        commitment_types::proto::v1::MerklePrefix,
+        connection::types::proto::v1::{
+            ConnectionPaths, Counterparty as RawConnectionCounterparty, IdentifiedConnection,
+            Version as RawVersion,
+        },
+        host::types::identifiers::Sequence,
+    };
+
+    use super::*;
+
+    #[test]
+    fn genesis_roundtrip() {
+        let keeper = Keeper::new(
+            SubspaceKey::Ibc,
+            SubspaceKey::Ibc,
+            MockBankKeeper::former().form(),
+            TransferModuleAccount,
+        );
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let mut genesis = GenesisState::default();
+        genesis.connection_genesis.connections = vec![IdentifiedConnection {
+            id: "connection-0".to_owned(),
+            client_id: "07-tendermint-0".to_owned(),
+            versions: vec![RawVersion {
+                identifier: "1".to_owned(),
+                features: vec!["ORDER_ORDERED".to_owned(), "ORDER_UNORDERED".to_owned()],
+            }],
+            state: 3,
+            counterparty: Some(RawConnectionCounterparty {
+                client_id: "07-tendermint-3".to_owned(),
+                connection_id: "connection-5".to_owned(),
+                prefix: Some(MerklePrefix {
+                    key_prefix: b"ibc".to_vec(),
+                }),
+            }),
+            delay_period: 0,
+        }];
+        genesis.connection_genesis.client_connection_paths = vec![ConnectionPaths {
+            client_id: "07-tendermint-0".to_owned(),
+            paths: vec!["connection-0".to_owned()],
+        }];
+        genesis.connection_genesis.next_connection_sequence = 1;
+
+        genesis.channel_genesis.channels = vec![IdentifiedChannel {
+            state: 3,
+            ordering: 1,
+            counterparty: Some(RawChannelCounterparty {
+                port_id: "transfer".to_owned(),
+                channel_id: "channel-7".to_owned(),
+            }),
+            connection_hops: vec!["connection-0".to_owned()],
+            version: "ics20-1".to_owned(),
+            port_id: "transfer".to_owned(),
+            channel_id: "channel-0".to_owned(),
+            ..Default::default()
+        }];
+        let packet_state = |seq: u64, data: &[u8]| PacketState {
+            port_id: "transfer".parse().unwrap_test(),
+            chan_id: "channel-0".parse().unwrap_test(),
+            seq: Sequence::from(seq),
+            data: data.to_vec(),
+        };
+        let packet_sequence = |sequence: u64| PacketSequence {
+            port_id: "transfer".to_owned(),
+            channel_id: "channel-0".to_owned(),
+            sequence,
+        };
+        genesis.channel_genesis.acknowledgements = vec![packet_state(3, &[1; 32])];
+        genesis.channel_genesis.commitments =
+            vec![packet_state(4, &[2; 32]), packet_state(6, &[3; 32])];
+        genesis.channel_genesis.receipts = vec![packet_state(2, &[1])];
+        genesis.channel_genesis.send_sequences = vec![packet_sequence(7)];
+        genesis.channel_genesis.recv_sequences = vec![packet_sequence(3)];
+        genesis.channel_genesis.ack_sequences = vec![packet_sequence(4)];
+        genesis.channel_genesis.next_channel_sequence = 1;
+
+        keeper.init_genesis(&mut ctx, genesis.clone());
+
+        let connection_genesis = keeper.connection_keeper.export_genesis(&ctx).unwrap_test();
+        let channel_genesis = keeper.channel_keeper.export_genesis(&ctx).unwrap_test();
+
+        assert_eq!(
+            serde_json::to_value(connection_genesis).unwrap_test(),
+            serde_json::to_value(genesis.connection_genesis).unwrap_test()
+        );
+        assert_eq!(
+            serde_json::to_value(channel_genesis).unwrap_test(),
+            serde_json::to_value(genesis.channel_genesis).unwrap_test()
+        );
+    }
+
+    #[test]
+    fn invalid_genesis_is_rejected() {
+        let deserialize = |genesis: &GenesisState| {
+            serde_json::from_value::<GenesisState>(serde_json::to_value(genesis).unwrap_test())
+        };
+
+        assert!(deserialize(&GenesisState::default()).is_ok());
+
+        let mut genesis = GenesisState::default();
+        genesis.connection_genesis.client_connection_paths = vec![ConnectionPaths {
+            client_id: "07-tendermint-0".to_owned(),
+            paths: vec!["invalid connection".to_owned()],
+        }];
+        assert!(deserialize(&genesis).is_err());
+
+        let mut genesis = GenesisState::default();
+        genesis.channel_genesis.send_sequences = vec![PacketSequence {
+            port_id: "transfer".to_owned(),
+            channel_id: "invalid channel".to_owned(),
+            sequence: 1,
+        }];
+        assert!(deserialize(&genesis).is_err());
+    }
+
+    #[derive(Debug, Clone)]
+    struct TransferModuleAccount;
+
+    impl Module for TransferModuleAccount {
+        fn name(&self) -> String {
+            "transfer".to_owned()
+        }
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "ibc")]
+        #[pkey(to_string = "ibc")]
+        Ibc,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
 }
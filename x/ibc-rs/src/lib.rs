@@ -8,9 +8,12 @@ pub mod errors;
 mod ics02_client;
 mod ics03_connection;
 mod ics04_channel;
+mod ics20_transfer;
 pub mod keeper;
 pub mod message;
 pub mod types;
 
 pub use abci_handler::*;
+pub use ics02_client::{ClientParams, Keeper as ClientKeeper};
+pub use ics03_connection::ConnectionParams;
 pub use types::genesis::*;
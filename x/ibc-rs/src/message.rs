@@ -1,12 +1,76 @@
 use gears::derive::AppMessage;
 
-use crate::ics02_client::message::MsgCreateClient;
+pub use crate::ics02_client::message::{
+    MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
+};
+pub use crate::ics03_connection::message::{
+    MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit, MsgConnectionOpenTry,
+};
+pub use crate::ics04_channel::message::{
+    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    MsgTimeoutOnClose,
+};
+pub use crate::ics20_transfer::message::MsgTransfer;
 
 #[derive(Debug, Clone, serde::Serialize, AppMessage)]
+#[serde(tag = "@type")]
 pub enum Message {
-    #[msg(url(string = "/ibc.core.client.v1"))]
+    #[serde(rename = "/ibc.core.client.v1.MsgCreateClient")]
+    #[msg(url(string = "/ibc.core.client.v1.MsgCreateClient"))]
     ClientCreate(MsgCreateClient),
-    // ClientUpdate(MsgUpdateClient),
-    // ClientUpgrade(MsgUpgradeClient),
-    // RecoverClient(MsgRecoverClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgUpdateClient")]
+    #[msg(url(string = "/ibc.core.client.v1.MsgUpdateClient"))]
+    ClientUpdate(MsgUpdateClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgUpgradeClient")]
+    #[msg(url(string = "/ibc.core.client.v1.MsgUpgradeClient"))]
+    ClientUpgrade(MsgUpgradeClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgSubmitMisbehaviour")]
+    #[msg(url(string = "/ibc.core.client.v1.MsgSubmitMisbehaviour"))]
+    SubmitMisbehaviour(MsgSubmitMisbehaviour),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenInit")]
+    #[msg(url(string = "/ibc.core.connection.v1.MsgConnectionOpenInit"))]
+    ConnectionOpenInit(MsgConnectionOpenInit),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenTry")]
+    #[msg(url(string = "/ibc.core.connection.v1.MsgConnectionOpenTry"))]
+    ConnectionOpenTry(MsgConnectionOpenTry),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenAck")]
+    #[msg(url(string = "/ibc.core.connection.v1.MsgConnectionOpenAck"))]
+    ConnectionOpenAck(MsgConnectionOpenAck),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenConfirm")]
+    #[msg(url(string = "/ibc.core.connection.v1.MsgConnectionOpenConfirm"))]
+    ConnectionOpenConfirm(MsgConnectionOpenConfirm),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenInit")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelOpenInit"))]
+    ChannelOpenInit(MsgChannelOpenInit),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenTry")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelOpenTry"))]
+    ChannelOpenTry(MsgChannelOpenTry),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenAck")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelOpenAck"))]
+    ChannelOpenAck(MsgChannelOpenAck),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenConfirm")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelOpenConfirm"))]
+    ChannelOpenConfirm(MsgChannelOpenConfirm),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelCloseInit")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelCloseInit"))]
+    ChannelCloseInit(MsgChannelCloseInit),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelCloseConfirm")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgChannelCloseConfirm"))]
+    ChannelCloseConfirm(MsgChannelCloseConfirm),
+    #[serde(rename = "/ibc.core.channel.v1.MsgRecvPacket")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgRecvPacket"))]
+    RecvPacket(MsgRecvPacket),
+    #[serde(rename = "/ibc.core.channel.v1.MsgAcknowledgement")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgAcknowledgement"))]
+    Acknowledgement(MsgAcknowledgement),
+    #[serde(rename = "/ibc.core.channel.v1.MsgTimeout")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgTimeout"))]
+    Timeout(MsgTimeout),
+    #[serde(rename = "/ibc.core.channel.v1.MsgTimeoutOnClose")]
+    #[msg(url(string = "/ibc.core.channel.v1.MsgTimeoutOnClose"))]
+    TimeoutOnClose(MsgTimeoutOnClose),
+    #[serde(rename = "/ibc.applications.transfer.v1.MsgTransfer")]
+    #[msg(url(string = "/ibc.applications.transfer.v1.MsgTransfer"))]
+    Transfer(MsgTransfer),
 }
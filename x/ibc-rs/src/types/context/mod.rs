@@ -3,6 +3,7 @@
 #[allow(unused_imports)] //TODO: remove
 use derive_more::{From, TryInto};
 use gears::context::tx::TxContext;
+use gears::extensions::corruption::UnwrapCorrupt;
 use gears::params::ParamsSubspaceKey;
 use gears::store::database::Database;
 use gears::store::StoreKey;
@@ -14,20 +15,31 @@ use ibc::clients::tendermint::types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType,
     TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use std::cell::RefCell;
 //use ibc::core::client::context::client_state::ClientStateValidation;
 use gears::context::QueryableContext;
 use gears::context::TransactionalContext;
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Receipt;
 use ibc::core::client::context::{
     ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
 };
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::proto::Protobuf;
-use ibc::core::router::router::Router;
 use ibc::derive::{ClientState, ConsensusState};
 use ibc::primitives::proto::Any;
 use ibc::primitives::Timestamp;
@@ -57,12 +69,15 @@ pub const KEY_CONSENSUS_STATE_PREFIX: &str = "consensusStates";
 pub const KEY_PROCESSED_TIME: &str = "/processedTime";
 
 // KeyProcessedHeight is appended to consensus state key to store the processed height
-const KEY_PROCESSED_HEIGHT: &str = "/processedHeight";
-const KEY_ITERATE_CONSENSUS_STATE_PREFIX: &[u8; 22] = b"iterateConsensusStates";
+pub const KEY_PROCESSED_HEIGHT: &str = "/processedHeight";
+pub const KEY_ITERATE_CONSENSUS_STATE_PREFIX: &[u8; 22] = b"iterateConsensusStates";
+
+/// Prefix of the IBC commitments of this chain, i.e. the name of the IBC store
+pub const COMMITMENT_PREFIX: &[u8] = b"ibc";
 
 #[derive(Debug)]
 pub struct Context<'a, 'b, DB, SK, PSK> {
-    pub gears_ctx: &'a mut TxContext<'b, DB, SK>,
+    pub gears_ctx: &'a RefCell<&'a mut TxContext<'b, DB, SK>>,
     pub client_keeper: &'a ClientKeeper<SK, PSK>,
     pub connection_keeper: &'a ConnectionKeeper<SK, PSK>,
     pub channel_keeper: &'a ChannelKeeper<SK>,
@@ -88,6 +103,47 @@ impl<'a, 'b, DB, SK, PSK> Context<'a, 'b, DB, SK, PSK> {
         join[22..].copy_from_slice(&heights);
         join
     }
+
+    fn consensus_state_key(height: &Height) -> String {
+        format!(
+            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            height.revision_number(),
+            height.revision_height()
+        )
+    }
+
+    fn client_prefix(client_id: &ClientId) -> Vec<u8> {
+        format!("{KEY_CLIENT_STORE_PREFIX}/{}/", client_id).into_bytes()
+    }
+}
+
+impl<'a, 'b, DB: Database, SK: StoreKey, PSK> Context<'a, 'b, DB, SK, PSK> {
+    /// Height of the block being processed
+    fn block_height(&self) -> Result<Height, ContextError> {
+        Height::new(
+            self.gears_ctx.borrow().chain_id().revision_number(),
+            self.gears_ctx.borrow().height().into(),
+        )
+        .map_err(ContextError::ClientError)
+    }
+
+    /// Time of the block being processed
+    fn block_timestamp(&self) -> Result<Timestamp, ContextError> {
+        let nanoseconds = u64::try_from(i128::from(
+            self.gears_ctx.borrow().get_time().timestamp_nanoseconds(),
+        ))
+        .map_err(other_error)?;
+
+        Timestamp::from_nanoseconds(nanoseconds).map_err(other_error)
+    }
+}
+
+/// ibc-rs doesn't have error variants for failures of the host, e.g. store errors. These
+/// errors are reported as client errors.
+fn other_error(e: impl ToString) -> ContextError {
+    ContextError::ClientError(ClientError::Other {
+        description: e.to_string(),
+    })
 }
 
 impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientValidationContext
@@ -100,30 +156,37 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientValidatio
         &self,
         client_id: &ibc::core::host::types::identifiers::ClientId,
     ) -> Result<Self::ClientStateRef, ibc::core::handler::types::error::ContextError> {
-        // TODO: impl this
-        Err(ContextError::ClientError(
-            ClientError::ClientStateNotFound {
-                client_id: client_id.clone(),
-            },
-        ))
+        self.client_keeper
+            .client_state(&**self.gears_ctx.borrow(), client_id)
+            .map_err(other_error)?
+            .ok_or(ContextError::ClientError(
+                ClientError::ClientStateNotFound {
+                    client_id: client_id.clone(),
+                },
+            ))
     }
 
     fn consensus_state(
         &self,
         client_cons_state_path: &ibc::core::host::types::path::ClientConsensusStatePath,
     ) -> Result<Self::ConsensusStateRef, ibc::core::handler::types::error::ContextError> {
-        // TODO: check impl
-
-        Err(ContextError::ClientError(
-            ClientError::ConsensusStateNotFound {
-                client_id: client_cons_state_path.client_id.clone(),
-                height: Height::new(
-                    client_cons_state_path.revision_number,
-                    client_cons_state_path.revision_height,
-                )
-                .unwrap(), //TODO: unwrap
-            },
-        ))
+        let consensus_state = self
+            .client_keeper
+            .consensus_state(&**self.gears_ctx.borrow(), client_cons_state_path)
+            .map_err(other_error)?;
+
+        match consensus_state {
+            Some(consensus_state) => Ok(consensus_state),
+            None => Err(ContextError::ClientError(
+                ClientError::ConsensusStateNotFound {
+                    client_id: client_cons_state_path.client_id.clone(),
+                    height: Height::new(
+                        client_cons_state_path.revision_number,
+                        client_cons_state_path.revision_height,
+                    )?,
+                },
+            )),
+        }
     }
 
     fn client_update_meta(
@@ -134,7 +197,41 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientValidatio
         (ibc::primitives::Timestamp, ibc::core::client::types::Height),
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        let gears_ctx = self.gears_ctx.borrow();
+        let store = gears_ctx
+            .kv_store(&self.store_key)
+            .prefix_store(Self::client_prefix(client_id));
+        let not_found = || {
+            ContextError::ClientError(ClientError::UpdateMetaDataNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })
+        };
+
+        let key = format!("{}{KEY_PROCESSED_TIME}", Self::consensus_state_key(height));
+        let processed_time = store
+            .get(key.as_bytes())
+            .map_err(other_error)?
+            .ok_or_else(not_found)?;
+        let processed_time = Timestamp::from_nanoseconds(u64::from_be_bytes(
+            <[u8; 8]>::try_from(processed_time).unwrap_or_corrupt(),
+        ))
+        .map_err(other_error)?;
+
+        let key = format!(
+            "{}{KEY_PROCESSED_HEIGHT}",
+            Self::consensus_state_key(height)
+        );
+        let processed_height = store
+            .get(key.as_bytes())
+            .map_err(other_error)?
+            .ok_or_else(not_found)?;
+        let processed_height = String::from_utf8(processed_height)
+            .unwrap_or_corrupt()
+            .parse::<Height>()
+            .unwrap_or_corrupt();
+
+        Ok((processed_time, processed_height))
     }
 }
 
@@ -154,37 +251,49 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         &self,
     ) -> Result<ibc::core::client::types::Height, ibc::core::handler::types::error::ContextError>
     {
-        todo!()
+        self.block_height()
     }
 
     fn host_timestamp(
         &self,
     ) -> Result<ibc::primitives::Timestamp, ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.block_timestamp()
     }
 
     fn host_consensus_state(
         &self,
         height: &ibc::core::client::types::Height,
     ) -> Result<Self::HostConsensusState, ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let not_found = || {
+            other_error(format!(
+                "consensus state of the host chain at height {height} not found"
+            ))
+        };
+
+        if height.revision_number() != self.gears_ctx.borrow().chain_id().revision_number() {
+            return Err(not_found());
+        }
+
+        let consensus_state = self
+            .client_keeper
+            .self_consensus_state(&**self.gears_ctx.borrow(), height.revision_height())
+            .map_err(other_error)?
+            .ok_or_else(not_found)?;
+
+        Ok(consensus_state.into())
     }
 
     fn client_counter(&self) -> Result<u64, ibc::core::handler::types::error::ContextError> {
-        // TODO: check impl
-        let ibc_store = self.gears_ctx.kv_store(&self.store_key);
+        let gears_ctx = self.gears_ctx.borrow();
+        let ibc_store = gears_ctx.kv_store(&self.store_key);
         let raw = ibc_store
             .get(KEY_NEXT_CLIENT_SEQUENCE)
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?
-            .unwrap()
-            .try_into()
-            .unwrap();
+            .map_err(other_error)?
+            .unwrap_or_corrupt();
 
-        Ok(u64::from_be_bytes(raw))
+        Ok(u64::from_be_bytes(
+            <[u8; 8]>::try_from(raw).unwrap_or_corrupt(),
+        ))
     }
 
     fn connection_end(
@@ -194,22 +303,70 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::connection::types::ConnectionEnd,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.connection_keeper
+            .connection_end(&**self.gears_ctx.borrow(), conn_id)
+            .map_err(other_error)?
+            .ok_or(ContextError::ConnectionError(
+                ConnectionError::ConnectionNotFound {
+                    connection_id: conn_id.clone(),
+                },
+            ))
     }
 
+    /// Checks the client which the counterparty chain uses to track this chain
     fn validate_self_client(
         &self,
         client_state_of_host_on_counterparty: Self::HostClientState,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let client_state = client_state_of_host_on_counterparty.inner();
+
+        if client_state.is_frozen() {
+            return Err(other_error("client of the host chain is frozen"));
+        }
+
+        let chain_id = self.gears_ctx.borrow().chain_id().clone();
+        if client_state.chain_id.as_str() != chain_id.as_str() {
+            return Err(other_error(format!(
+                "client of the host chain tracks chain {} instead of {}",
+                client_state.chain_id, chain_id
+            )));
+        }
+
+        let host_height = self.block_height()?;
+        if client_state.latest_height.revision_number() != host_height.revision_number() {
+            return Err(other_error(format!(
+                "client of the host chain has revision {} instead of {}",
+                client_state.latest_height.revision_number(),
+                host_height.revision_number()
+            )));
+        }
+
+        if client_state.latest_height >= host_height {
+            return Err(other_error(format!(
+                "client of the host chain has height {} which is not lower than the host height {}",
+                client_state.latest_height, host_height
+            )));
+        }
+
+        if client_state.trusting_period >= client_state.unbonding_period {
+            return Err(other_error(format!(
+                "client of the host chain has trusting period {:?} which is not lower than the unbonding period {:?}",
+                client_state.trusting_period, client_state.unbonding_period
+            )));
+        }
+
+        Ok(())
     }
 
     fn commitment_prefix(&self) -> ibc::core::commitment_types::commitment::CommitmentPrefix {
-        todo!()
+        CommitmentPrefix::try_from(COMMITMENT_PREFIX.to_vec())
+            .expect("hardcoded prefix is not empty")
     }
 
     fn connection_counter(&self) -> Result<u64, ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.connection_keeper
+            .connection_counter(&**self.gears_ctx.borrow())
+            .map_err(other_error)
     }
 
     fn channel_end(
@@ -219,7 +376,15 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::channel::types::channel::ChannelEnd,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+
+        self.channel_keeper
+            .channel_end(&**self.gears_ctx.borrow(), port_id, channel_id)
+            .map_err(other_error)?
+            .ok_or(ContextError::ChannelError(ChannelError::ChannelNotFound {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            }))
     }
 
     fn get_next_sequence_send(
@@ -229,7 +394,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::host::types::identifiers::Sequence,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.channel_keeper
+            .next_sequence_send(&**self.gears_ctx.borrow(), seq_send_path)
+            .map_err(other_error)?
+            .ok_or(ContextError::PacketError(PacketError::MissingNextSendSeq {
+                port_id: seq_send_path.0.clone(),
+                channel_id: seq_send_path.1.clone(),
+            }))
     }
 
     fn get_next_sequence_recv(
@@ -239,7 +410,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::host::types::identifiers::Sequence,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.channel_keeper
+            .next_sequence_recv(&**self.gears_ctx.borrow(), seq_recv_path)
+            .map_err(other_error)?
+            .ok_or(ContextError::PacketError(PacketError::MissingNextRecvSeq {
+                port_id: seq_recv_path.0.clone(),
+                channel_id: seq_recv_path.1.clone(),
+            }))
     }
 
     fn get_next_sequence_ack(
@@ -249,7 +426,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::host::types::identifiers::Sequence,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.channel_keeper
+            .next_sequence_ack(&**self.gears_ctx.borrow(), seq_ack_path)
+            .map_err(other_error)?
+            .ok_or(ContextError::PacketError(PacketError::MissingNextAckSeq {
+                port_id: seq_ack_path.0.clone(),
+                channel_id: seq_ack_path.1.clone(),
+            }))
     }
 
     fn get_packet_commitment(
@@ -259,7 +442,14 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::channel::types::commitment::PacketCommitment,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.channel_keeper
+            .packet_commitment(&**self.gears_ctx.borrow(), commitment_path)
+            .map_err(other_error)?
+            .ok_or(ContextError::PacketError(
+                PacketError::PacketCommitmentNotFound {
+                    sequence: commitment_path.sequence,
+                },
+            ))
     }
 
     fn get_packet_receipt(
@@ -269,7 +459,15 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::channel::types::packet::Receipt,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        let has_receipt = self
+            .channel_keeper
+            .has_packet_receipt(&**self.gears_ctx.borrow(), receipt_path)
+            .map_err(other_error)?;
+
+        match has_receipt {
+            true => Ok(Receipt::Ok),
+            false => Ok(Receipt::None),
+        }
     }
 
     fn get_packet_acknowledgement(
@@ -279,22 +477,37 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ValidationConte
         ibc::core::channel::types::commitment::AcknowledgementCommitment,
         ibc::core::handler::types::error::ContextError,
     > {
-        todo!()
+        self.channel_keeper
+            .packet_acknowledgement(&**self.gears_ctx.borrow(), ack_path)
+            .map_err(other_error)?
+            .ok_or(ContextError::PacketError(
+                PacketError::PacketAcknowledgementNotFound {
+                    sequence: ack_path.sequence,
+                },
+            ))
     }
 
     fn channel_counter(&self) -> Result<u64, ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .channel_counter(&**self.gears_ctx.borrow())
+            .map_err(other_error)
     }
 
     fn max_expected_time_per_block(&self) -> std::time::Duration {
-        todo!()
+        // The method is infallible. If the read runs out of gas the following store access,
+        // the read of the client update metadata, reports the error.
+        let params = self
+            .connection_keeper
+            .params(&**self.gears_ctx.borrow())
+            .unwrap_or_default();
+
+        std::time::Duration::from_nanos(params.max_expected_time_per_block)
     }
 
     fn validate_message_signer(
         &self,
         _signer: &ibc::primitives::Signer,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        //TOOD: check impl
         // The signer is validated in our domain message types
         Ok(())
     }
@@ -312,18 +525,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
     fn increase_client_counter(
         &mut self,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        //TODO: check impl
-
         let sequence = self.client_counter()? + 1;
 
-        let mut ibc_store = self.gears_ctx.kv_store_mut(&self.store_key);
+        let mut gears_ctx = self.gears_ctx.borrow_mut();
+        let mut ibc_store = gears_ctx.kv_store_mut(&self.store_key);
         ibc_store
             .set(KEY_NEXT_CLIENT_SEQUENCE.to_owned(), sequence.to_be_bytes())
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .map_err(other_error)?;
 
         Ok(())
     }
@@ -333,7 +541,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         connection_path: &ibc::core::host::types::path::ConnectionPath,
         connection_end: ibc::core::connection::types::ConnectionEnd,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.connection_keeper
+            .connection_set(
+                &mut **self.gears_ctx.borrow_mut(),
+                &connection_path.0,
+                connection_end,
+            )
+            .map_err(other_error)
     }
 
     fn store_connection_to_client(
@@ -341,13 +555,26 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         client_connection_path: &ibc::core::host::types::path::ClientConnectionPath,
         conn_id: ibc::core::host::types::identifiers::ConnectionId,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let client_id = &client_connection_path.0;
+        let mut connections = self
+            .connection_keeper
+            .client_connections(&**self.gears_ctx.borrow(), client_id)
+            .map_err(other_error)?;
+        connections.push(conn_id);
+
+        self.connection_keeper
+            .client_connections_set(&mut **self.gears_ctx.borrow_mut(), client_id, &connections)
+            .map_err(other_error)
     }
 
     fn increase_connection_counter(
         &mut self,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let sequence = self.connection_counter()? + 1;
+
+        self.connection_keeper
+            .set_next_connection_sequence(&mut **self.gears_ctx.borrow_mut(), sequence)
+            .map_err(other_error)
     }
 
     fn store_packet_commitment(
@@ -355,22 +582,32 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         commitment_path: &ibc::core::host::types::path::CommitmentPath,
         commitment: ibc::core::channel::types::commitment::PacketCommitment,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .packet_commitment_set(
+                &mut **self.gears_ctx.borrow_mut(),
+                commitment_path,
+                commitment,
+            )
+            .map_err(other_error)
     }
 
     fn delete_packet_commitment(
         &mut self,
         commitment_path: &ibc::core::host::types::path::CommitmentPath,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .packet_commitment_delete(&mut **self.gears_ctx.borrow_mut(), commitment_path)
+            .map_err(other_error)
     }
 
     fn store_packet_receipt(
         &mut self,
         receipt_path: &ibc::core::host::types::path::ReceiptPath,
-        receipt: ibc::core::channel::types::packet::Receipt,
+        _receipt: ibc::core::channel::types::packet::Receipt,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .packet_receipt_set(&mut **self.gears_ctx.borrow_mut(), receipt_path)
+            .map_err(other_error)
     }
 
     fn store_packet_acknowledgement(
@@ -378,14 +615,22 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         ack_path: &ibc::core::host::types::path::AckPath,
         ack_commitment: ibc::core::channel::types::commitment::AcknowledgementCommitment,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .packet_acknowledgement_set(
+                &mut **self.gears_ctx.borrow_mut(),
+                ack_path,
+                ack_commitment,
+            )
+            .map_err(other_error)
     }
 
     fn delete_packet_acknowledgement(
         &mut self,
         ack_path: &ibc::core::host::types::path::AckPath,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .packet_acknowledgement_delete(&mut **self.gears_ctx.borrow_mut(), ack_path)
+            .map_err(other_error)
     }
 
     fn store_channel(
@@ -393,7 +638,16 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         channel_end_path: &ibc::core::host::types::path::ChannelEndPath,
         channel_end: ibc::core::channel::types::channel::ChannelEnd,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let ChannelEndPath(port_id, channel_id) = channel_end_path;
+
+        self.channel_keeper
+            .channel_set(
+                &mut **self.gears_ctx.borrow_mut(),
+                port_id,
+                channel_id,
+                channel_end,
+            )
+            .map_err(other_error)
     }
 
     fn store_next_sequence_send(
@@ -401,7 +655,9 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         seq_send_path: &ibc::core::host::types::path::SeqSendPath,
         seq: ibc::core::host::types::identifiers::Sequence,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .next_sequence_send_set(&mut **self.gears_ctx.borrow_mut(), seq_send_path, seq)
+            .map_err(other_error)
     }
 
     fn store_next_sequence_recv(
@@ -409,7 +665,9 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         seq_recv_path: &ibc::core::host::types::path::SeqRecvPath,
         seq: ibc::core::host::types::identifiers::Sequence,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .next_sequence_recv_set(&mut **self.gears_ctx.borrow_mut(), seq_recv_path, seq)
+            .map_err(other_error)
     }
 
     fn store_next_sequence_ack(
@@ -417,68 +675,39 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExecutionContex
         seq_ack_path: &ibc::core::host::types::path::SeqAckPath,
         seq: ibc::core::host::types::identifiers::Sequence,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        self.channel_keeper
+            .next_sequence_ack_set(&mut **self.gears_ctx.borrow_mut(), seq_ack_path, seq)
+            .map_err(other_error)
     }
 
     fn increase_channel_counter(
         &mut self,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let sequence = self.channel_counter()? + 1;
+
+        self.channel_keeper
+            .set_next_channel_sequence(&mut **self.gears_ctx.borrow_mut(), sequence)
+            .map_err(other_error)
     }
 
     fn emit_ibc_event(
         &mut self,
         event: ibc::core::handler::types::events::IbcEvent,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        //TODO: implement
-
-        match event {
-            IbcEvent::CreateClient(c) => {
-                self.gears_ctx.push_event(Event::new(
-                    "create_client",
-                    [
-                        EventAttribute::new(
-                            "client_id".into(),
-                            c.client_id().as_bytes().to_vec().into(),
-                            true,
-                        ),
-                        EventAttribute::new(
-                            "client_type".into(),
-                            c.client_type().as_str().to_owned().into(),
-                            true,
-                        ),
-                        EventAttribute::new(
-                            "consensus_height".into(),
-                            c.consensus_height().to_string().into(),
-                            true,
-                        ),
-                    ],
-                ));
-            }
-            IbcEvent::UpdateClient(_) => todo!(),
-            IbcEvent::UpgradeClient(_) => todo!(),
-            IbcEvent::ClientMisbehaviour(_) => todo!(),
-            IbcEvent::OpenInitConnection(_) => todo!(),
-            IbcEvent::OpenTryConnection(_) => todo!(),
-            IbcEvent::OpenAckConnection(_) => todo!(),
-            IbcEvent::OpenConfirmConnection(_) => todo!(),
-            IbcEvent::OpenInitChannel(_) => todo!(),
-            IbcEvent::OpenTryChannel(_) => todo!(),
-            IbcEvent::OpenAckChannel(_) => todo!(),
-            IbcEvent::OpenConfirmChannel(_) => todo!(),
-            IbcEvent::CloseInitChannel(_) => todo!(),
-            IbcEvent::CloseConfirmChannel(_) => todo!(),
-            IbcEvent::SendPacket(_) => todo!(),
-            IbcEvent::ReceivePacket(_) => todo!(),
-            IbcEvent::WriteAcknowledgement(_) => todo!(),
-            IbcEvent::AcknowledgePacket(_) => todo!(),
-            IbcEvent::TimeoutPacket(_) => todo!(),
-            IbcEvent::ChannelClosed(_) => todo!(),
-            IbcEvent::Module(_) => todo!(),
-            IbcEvent::Message(m) => {
-                //TODO: implement
-            }
-        };
+        // ibc-rs defines the attributes of its events only by the conversion into
+        // tendermint-rs events
+        let event = ibc_tendermint::abci::Event::try_from(event).map_err(other_error)?;
+
+        self.gears_ctx.borrow_mut().push_event(Event::new(
+            &event.kind,
+            event.attributes.iter().map(|attribute| {
+                EventAttribute::new(
+                    attribute.key_bytes().to_vec().into(),
+                    attribute.value_bytes().to_vec().into(),
+                    attribute.index(),
+                )
+            }),
+        ));
 
         Ok(())
     }
@@ -503,12 +732,12 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
         client_state: Self::ClientStateRef,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
         self.client_keeper
-            .client_state_set(self.gears_ctx, client_state_path, client_state)
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .client_state_set(
+                &mut **self.gears_ctx.borrow_mut(),
+                client_state_path,
+                client_state,
+            )
+            .map_err(other_error)?;
         Ok(())
     }
 
@@ -517,42 +746,13 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
         consensus_state_path: ibc::core::host::types::path::ClientConsensusStatePath,
         consensus_state: Self::ConsensusStateRef,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        //TODO: check implementation
-
         let any: Any = consensus_state.into();
         let encoded_bytes = any.to_vec();
 
-        // println!("encoded bytes:\n {:?}", encoded_bytes.clone());
-
-        // let prefix = format!(
-        //     "{KEY_CLIENT_STORE_PREFIX}/{}/",
-        //     consensus_state_path.client_id
-        // )
-        // .into_bytes();
-
-        // println!(
-        //     "revision height: {:?}",
-        //     consensus_state_path.revision_height
-        // );
-
-        // println!("prefix: {:?}", prefix.clone());
-
-        // let key = format!(
-        //     "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
-        //     consensus_state_path.revision_number, consensus_state_path.revision_height
-        // )
-        // .into_bytes();
-        // println!("key: {:?}", key);
-
         self.gears_ctx
+            .borrow_mut()
             .kv_store_mut(&self.store_key)
-            .prefix_store_mut(
-                format!(
-                    "{KEY_CLIENT_STORE_PREFIX}/{}/",
-                    consensus_state_path.client_id
-                )
-                .into_bytes(),
-            )
+            .prefix_store_mut(Self::client_prefix(&consensus_state_path.client_id))
             .set(
                 format!(
                     "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
@@ -561,11 +761,7 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
                 .into_bytes(),
                 encoded_bytes,
             )
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .map_err(other_error)?;
 
         Ok(())
     }
@@ -574,7 +770,19 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
         &mut self,
         consensus_state_path: ibc::core::host::types::path::ClientConsensusStatePath,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let key = format!(
+            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            consensus_state_path.revision_number, consensus_state_path.revision_height
+        );
+
+        self.gears_ctx
+            .borrow_mut()
+            .kv_store_mut(&self.store_key)
+            .prefix_store_mut(Self::client_prefix(&consensus_state_path.client_id))
+            .delete(key.as_bytes())
+            .map_err(other_error)?;
+
+        Ok(())
     }
 
     fn store_update_meta(
@@ -584,34 +792,23 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
         host_timestamp: ibc::primitives::Timestamp,
         host_height: ibc::core::client::types::Height,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        //TODO: check implementation
-
         // set processed time
-        let processed_time: u64 = 1714492003000000000; // TODO: hard coded for deterministic testing
-        let store = self.gears_ctx.kv_store_mut(&self.store_key);
-        let key = format!(
-            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}{KEY_PROCESSED_TIME}",
-            height.revision_number(),
-            height.revision_height()
-        )
-        .into_bytes();
+        let processed_time = host_timestamp.nanoseconds();
+        let mut gears_ctx = self.gears_ctx.borrow_mut();
+        let store = gears_ctx.kv_store_mut(&self.store_key);
+        let key =
+            format!("{}{KEY_PROCESSED_TIME}", Self::consensus_state_key(&height)).into_bytes();
         let value = processed_time.to_be_bytes();
-        let prefix = format!("{KEY_CLIENT_STORE_PREFIX}/{}/", client_id).into_bytes();
         store
-            .prefix_store_mut(prefix)
+            .prefix_store_mut(Self::client_prefix(&client_id))
             .set(key, value)
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .map_err(other_error)?;
 
         // set processed height
-        let store = self.gears_ctx.kv_store_mut(&self.store_key);
+        let store = gears_ctx.kv_store_mut(&self.store_key);
         let key = format!(
-            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}{KEY_PROCESSED_HEIGHT}",
-            height.revision_number(),
-            height.revision_height()
+            "{}{KEY_PROCESSED_HEIGHT}",
+            Self::consensus_state_key(&height)
         )
         .into_bytes();
         let value = format!(
@@ -620,35 +817,20 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
             host_height.revision_height()
         )
         .into_bytes();
-        let prefix = format!("{KEY_CLIENT_STORE_PREFIX}/{}/", client_id).into_bytes();
         store
-            .prefix_store_mut(prefix)
+            .prefix_store_mut(Self::client_prefix(&client_id))
             .set(key, value)
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .map_err(other_error)?;
 
         // set iteration key
-        let store = self.gears_ctx.kv_store_mut(&self.store_key);
+        let store = gears_ctx.kv_store_mut(&self.store_key);
         let key = Self::iteration_key(height);
-        let value = format!(
-            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
-            height.revision_number(),
-            height.revision_height()
-        )
-        .into_bytes();
-        let prefix = format!("{KEY_CLIENT_STORE_PREFIX}/{}/", client_id).into_bytes();
+        let value = Self::consensus_state_key(&height).into_bytes();
 
         store
-            .prefix_store_mut(prefix)
+            .prefix_store_mut(Self::client_prefix(&client_id))
             .set(key, value)
-            .map_err(|e| {
-                ContextError::ClientError(ClientError::Other {
-                    description: e.to_string(),
-                })
-            })?;
+            .map_err(other_error)?;
 
         Ok(())
     }
@@ -658,7 +840,24 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ClientExecution
         client_id: ibc::core::host::types::identifiers::ClientId,
         height: ibc::core::client::types::Height,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
-        todo!()
+        let mut gears_ctx = self.gears_ctx.borrow_mut();
+        let mut store = gears_ctx
+            .kv_store_mut(&self.store_key)
+            .prefix_store_mut(Self::client_prefix(&client_id));
+
+        for key in [
+            format!("{}{KEY_PROCESSED_TIME}", Self::consensus_state_key(&height)).into_bytes(),
+            format!(
+                "{}{KEY_PROCESSED_HEIGHT}",
+                Self::consensus_state_key(&height)
+            )
+            .into_bytes(),
+            Self::iteration_key(height).to_vec(),
+        ] {
+            store.delete(&key).map_err(other_error)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -668,16 +867,14 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExtClientValida
     fn host_timestamp(
         &self,
     ) -> Result<ibc::primitives::Timestamp, ibc::core::handler::types::error::ContextError> {
-        // TODO: check impl - should probably be using the timestamp from the context (block timestamp)
-        Ok(Timestamp::now())
+        self.block_timestamp()
     }
 
     fn host_height(
         &self,
     ) -> Result<ibc::core::client::types::Height, ibc::core::handler::types::error::ContextError>
     {
-        // TODO: check impl
-        Ok(Height::new(0, self.gears_ctx.height().into()).unwrap()) //TODO: unwrap
+        self.block_height()
     }
 
     fn consensus_state_heights(
@@ -685,7 +882,9 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExtClientValida
         client_id: &ibc::core::host::types::identifiers::ClientId,
     ) -> Result<Vec<ibc::core::client::types::Height>, ibc::core::handler::types::error::ContextError>
     {
-        todo!()
+        self.client_keeper
+            .consensus_state_heights(&**self.gears_ctx.borrow(), client_id)
+            .map_err(other_error)
     }
 
     fn next_consensus_state(
@@ -694,7 +893,19 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExtClientValida
         height: &ibc::core::client::types::Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ibc::core::handler::types::error::ContextError>
     {
-        todo!()
+        let next = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .find(|this| this > height);
+
+        next.map(|next| {
+            self.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                next.revision_number(),
+                next.revision_height(),
+            ))
+        })
+        .transpose()
     }
 
     fn prev_consensus_state(
@@ -703,32 +914,19 @@ impl<'a, 'b, DB: Database, SK: StoreKey, PSK: ParamsSubspaceKey> ExtClientValida
         height: &ibc::core::client::types::Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ibc::core::handler::types::error::ContextError>
     {
-        todo!()
-    }
-}
-
-#[derive(Debug)]
-pub struct ClientRouter;
-
-impl Router for ClientRouter {
-    fn get_route(
-        &self,
-        module_id: &ibc::core::router::types::module::ModuleId,
-    ) -> Option<&dyn ibc::core::router::module::Module> {
-        todo!()
-    }
-
-    fn get_route_mut(
-        &mut self,
-        module_id: &ibc::core::router::types::module::ModuleId,
-    ) -> Option<&mut dyn ibc::core::router::module::Module> {
-        todo!()
-    }
-
-    fn lookup_module(
-        &self,
-        port_id: &ibc::core::host::types::identifiers::PortId,
-    ) -> Option<ibc::core::router::types::module::ModuleId> {
-        todo!()
+        let prev = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|this| this < height);
+
+        prev.map(|prev| {
+            self.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                prev.revision_number(),
+                prev.revision_height(),
+            ))
+        })
+        .transpose()
     }
 }
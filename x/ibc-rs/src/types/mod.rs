@@ -1,4 +1,4 @@
 pub mod context;
 pub mod genesis;
+pub mod router;
 pub mod tx;
-//pub mod shim;
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use ibc::core::{
+    host::types::identifiers::PortId,
+    router::{module::Module, router::Router, types::module::ModuleId},
+};
+
+/// Routes channel and packet messages to the application modules bound to their ports
+#[derive(Debug, Default)]
+pub struct IbcRouter<'a> {
+    modules: BTreeMap<ModuleId, Box<dyn Module + 'a>>,
+    ports: BTreeMap<PortId, ModuleId>,
+}
+
+impl<'a> IbcRouter<'a> {
+    /// Binds the port to the module
+    pub fn add_route(
+        mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+        module: impl Module + 'a,
+    ) -> Self {
+        self.ports.insert(port_id, module_id.clone());
+        self.modules.insert(module_id, Box::new(module));
+        self
+    }
+}
+
+impl<'a> Router for IbcRouter<'a> {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(|module| &**module)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        self.modules
+            .get_mut(module_id)
+            .map(|module| module.as_mut() as &mut dyn Module)
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.ports.get(port_id).cloned()
+    }
+}
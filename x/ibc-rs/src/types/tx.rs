@@ -1 +1,99 @@
+/// Defines a gears message which wraps a domain message of ibc-rs. ibc-rs keeps the signer
+/// as a plain string, the wrapper holds it as an account address so that the message
+/// can be signed and its signer verified like any other message of the application.
+///
+/// ```ignore
+/// ibc_message!(
+///     MsgConnectionOpenInit,
+///     ibc::core::connection::types::msgs::MsgConnectionOpenInit,
+///     ibc::core::connection::types::proto::v1::MsgConnectionOpenInit,
+///     "/ibc.core.connection.v1.MsgConnectionOpenInit",
+///     |msg| MsgEnvelope::Connection(ConnectionMsg::OpenInit(msg))
+/// );
+/// ```
+macro_rules! ibc_message {
+    ($name:ident, $inner:ty, $raw:ty, $url:literal, $envelope:expr) => {
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            pub inner: $inner,
+            pub signer: gears::types::address::AccAddress,
+        }
 
+        impl $name {
+            pub const TYPE_URL: &'static str = $url;
+        }
+
+        impl From<$name> for ibc::core::handler::types::msgs::MsgEnvelope {
+            fn from(msg: $name) -> Self {
+                #[allow(clippy::redundant_closure_call)]
+                ($envelope)(msg.inner)
+            }
+        }
+
+        impl gears::types::tx::TxMessage for $name {
+            fn get_signers(&self) -> Vec<&gears::types::address::AccAddress> {
+                vec![&self.signer]
+            }
+
+            fn type_url(&self) -> &'static str {
+                $url
+            }
+        }
+
+        impl From<$name> for gears::core::any::google::Any {
+            fn from(msg: $name) -> Self {
+                gears::core::any::google::Any {
+                    type_url: $url.to_string(),
+                    value: prost::Message::encode_to_vec(&<$raw>::from(msg)),
+                }
+            }
+        }
+
+        impl TryFrom<gears::core::any::google::Any> for $name {
+            type Error = gears::core::errors::CoreError;
+
+            fn try_from(value: gears::core::any::google::Any) -> Result<Self, Self::Error> {
+                match value.type_url.as_str() {
+                    $url => {
+                        <$name as ibc::primitives::proto::Protobuf<$raw>>::decode_vec(&value.value)
+                            .map_err(|e| {
+                                gears::core::errors::CoreError::DecodeProtobuf(e.to_string())
+                            })
+                    }
+                    _ => Err(gears::core::errors::CoreError::DecodeGeneral(
+                        "message type not recognized".into(),
+                    )),
+                }
+            }
+        }
+
+        impl ibc::primitives::proto::Protobuf<$raw> for $name {}
+
+        impl TryFrom<$raw> for $name {
+            type Error = gears::core::errors::CoreError;
+
+            fn try_from(raw: $raw) -> Result<Self, Self::Error> {
+                let signer = gears::types::address::AccAddress::from_bech32(&raw.signer)
+                    .map_err(|e| gears::core::errors::CoreError::DecodeAddress(e.to_string()))?;
+                let inner = <$inner>::try_from(raw)
+                    .map_err(|e| gears::core::errors::CoreError::DecodeGeneral(e.to_string()))?;
+
+                Ok(Self { inner, signer })
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(msg: $name) -> Self {
+                msg.inner.into()
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&<$raw>::from(self.clone()), serializer)
+            }
+        }
+    };
+}
+
+pub(crate) use ibc_message;
@@ -129,14 +129,16 @@ impl<
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        match query {
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
             MintQueryRequest::Params(_) => Self::QRes::Params(self.query_params(ctx)),
             MintQueryRequest::Inflation(_) => Self::QRes::Inflation(self.query_inflation(ctx)),
             MintQueryRequest::AnnualProvisions(_) => {
                 Self::QRes::AnnualProvisions(self.query_annual_provisions(ctx))
             }
-        }
+        };
+
+        Ok(res)
     }
 
     fn msg<DB: Database>(
@@ -176,7 +178,7 @@ impl<
             _ => Err(QueryError::PathNotFound)?,
         };
 
-        Ok(self.typed_query(ctx, query).into_bytes())
+        Ok(self.typed_query(ctx, query)?.into_bytes())
     }
 
     fn begin_block<'a, DB: gears::store::database::Database>(
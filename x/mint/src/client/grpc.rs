@@ -1 +1,127 @@
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
 
+use crate::types::query::{
+    request::{
+        MintQueryRequest, QueryAnnualProvisionsRequest, QueryInflationRequest, QueryParamsRequest,
+        RawQueryAnnualProvisionsRequest, RawQueryInflationRequest, RawQueryParamsRequest,
+    },
+    response::{
+        MintQueryResponse, RawQueryAnnualProvisionsResponse, RawQueryInflationResponse,
+        RawQueryParamsResponse,
+    },
+};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The mint protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct MintService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> MintService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(&self, req: MintQueryRequest, height: u32) -> Result<MintQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for MintService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.mint.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for MintService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/cosmos.mint.v1beta1.Query/Params" => {
+                unary(request, move |_: RawQueryParamsRequest, height| {
+                    info!("Received a gRPC request mint::params");
+                    let req = MintQueryRequest::Params(QueryParamsRequest {});
+
+                    match service.typed_query(req, height)? {
+                        MintQueryResponse::Params(res) => Ok(RawQueryParamsResponse::from(res)),
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.mint.v1beta1.Query/Inflation" => {
+                unary(request, move |_: RawQueryInflationRequest, height| {
+                    info!("Received a gRPC request mint::inflation");
+                    let req = MintQueryRequest::Inflation(QueryInflationRequest {});
+
+                    match service.typed_query(req, height)? {
+                        MintQueryResponse::Inflation(res) => {
+                            Ok(RawQueryInflationResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.mint.v1beta1.Query/AnnualProvisions" => unary(
+                request,
+                move |_: RawQueryAnnualProvisionsRequest, height| {
+                    info!("Received a gRPC request mint::annual_provisions");
+                    let req = MintQueryRequest::AnnualProvisions(QueryAnnualProvisionsRequest {});
+
+                    match service.typed_query(req, height)? {
+                        MintQueryResponse::AnnualProvisions(res) => {
+                            Ok(RawQueryAnnualProvisionsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> MintService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<MintQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<MintQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    MintService {
+        app,
+        _phantom: Default::default(),
+    }
+}
@@ -1 +1,62 @@
+use axum::{extract::State, routing::get, Json, Router};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, RestState},
+};
 
+use crate::types::query::{
+    request::{
+        MintQueryRequest, QueryAnnualProvisionsRequest, QueryInflationRequest, QueryParamsRequest,
+    },
+    response::MintQueryResponse,
+};
+
+pub async fn params<
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintQueryRequest::Params(QueryParamsRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn inflation<
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintQueryRequest::Inflation(QueryInflationRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn annual_provisions<
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintQueryRequest::AnnualProvisions(QueryAnnualProvisionsRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<MintQueryRequest>,
+    QRes: QueryResponse + TryInto<MintQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/params", get(params))
+        .route("/v1beta1/inflation", get(inflation))
+        .route("/v1beta1/annual_provisions", get(annual_provisions))
+}
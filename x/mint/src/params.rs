@@ -7,7 +7,7 @@ use gears::{
     application::keepers::params::ParamsKeeper,
     derive::{Protobuf, Raw},
     extensions::corruption::UnwrapCorrupt,
-    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError},
     types::{
         decimal256::{CosmosDecimalProtoString, Decimal256},
         denom::Denom,
@@ -22,7 +22,7 @@ const INFLATION_MIN_KEY: &str = "InflationMin";
 const GOAL_BONDED_KEY: &str = "GoalBonded";
 const BLOCKS_PER_YEAR_KEY: &str = "BlocksPerYear";
 
-#[derive(Debug, Clone, Serialize, Deserialize, Raw, Protobuf)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
 pub struct MintParams {
     /// denom of coin to mint
     #[raw(kind(string), raw = String)]
@@ -152,6 +152,26 @@ impl ParamsDeserialize for MintParams {
             .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let value = String::from_utf8_lossy(value);
+        let is_valid = match key {
+            MINT_DENOM_KEY => Denom::from_str(&value).is_ok(),
+            INFLATION_RATE_CHANGE_KEY | INFLATION_MAX_KEY | INFLATION_MIN_KEY | GOAL_BONDED_KEY => {
+                Decimal256::from_cosmos_proto_string(&value).is_ok()
+            }
+            BLOCKS_PER_YEAR_KEY => u32::from_str(&value).is_ok(),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned()))?,
+        };
+
+        match is_valid {
+            true => Ok(()),
+            false => Err(ParamsValidationError::invalid_value(
+                key,
+                format!("can't parse `{value}`"),
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -165,28 +185,4 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for MintParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            MINT_DENOM_KEY => Denom::from_str(&String::from_utf8_lossy(value.as_ref())).is_ok(),
-            INFLATION_RATE_CHANGE_KEY => {
-                Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(value.as_ref()))
-                    .is_ok()
-            }
-            INFLATION_MAX_KEY => {
-                Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(value.as_ref()))
-                    .is_ok()
-            }
-            INFLATION_MIN_KEY => {
-                Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(value.as_ref()))
-                    .is_ok()
-            }
-            GOAL_BONDED_KEY => {
-                Decimal256::from_cosmos_proto_string(&String::from_utf8_lossy(value.as_ref()))
-                    .is_ok()
-            }
-            BLOCKS_PER_YEAR_KEY => u32::from_str(&String::from_utf8_lossy(value.as_ref())).is_ok(),
-            _ => false,
-        }
-    }
 }
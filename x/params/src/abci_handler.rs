@@ -0,0 +1,112 @@
+use std::marker::PhantomData;
+
+use gears::{
+    baseapp::{errors::QueryError, BaseAppParamsKeeper, QueryResponse},
+    context::{query::QueryContext, InfallibleContext},
+    core::Protobuf,
+    params::{infallible_subspace, parsed::Params, ParamKind, ParamsSubspaceKey},
+    store::{database::Database, StoreKey},
+    tendermint::types::request::query::RequestQuery,
+};
+
+use crate::types::{
+    query::{
+        request::{ParamsQueryRequest, QueryGasScheduleRequest, QueryParamsRequest},
+        response::{ParamsQueryResponse, QueryGasScheduleResponse, QueryParamsResponse},
+    },
+    ParamChange,
+};
+
+/// Params module has no state of its own, it only exposes raw values of other modules params
+/// and the gas schedule stored in baseapp subspace
+#[derive(Debug, Clone)]
+pub struct ParamsAbciHandler<SK, PSK: ParamsSubspaceKey> {
+    baseapp_params_keeper: BaseAppParamsKeeper<PSK>,
+    _marker: PhantomData<SK>,
+}
+
+impl<SK, PSK: ParamsSubspaceKey> ParamsAbciHandler<SK, PSK> {
+    pub fn new(baseapp_params_subspace_key: PSK) -> Self {
+        Self {
+            baseapp_params_keeper: BaseAppParamsKeeper {
+                params_subspace_key: baseapp_params_subspace_key,
+            },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey> ParamsAbciHandler<SK, PSK> {
+    /// Value is empty if there is no such subspace or key, same as in cosmos sdk
+    pub fn query_params<CTX: InfallibleContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+        QueryParamsRequest { subspace, key }: QueryParamsRequest,
+    ) -> QueryParamsResponse {
+        let value = PSK::from_subspace_str(&subspace)
+            .ok()
+            .and_then(|psk| infallible_subspace(ctx, &psk).params_field(&key, ParamKind::Bytes))
+            .and_then(Params::bytes)
+            .map(|value| String::from_utf8_lossy(&value).into_owned())
+            .unwrap_or_default();
+
+        QueryParamsResponse {
+            param: ParamChange {
+                subspace,
+                key,
+                value,
+            },
+        }
+    }
+
+    /// Gas schedule with defaults for values which aren't set
+    pub fn query_gas_schedule<CTX: InfallibleContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+        _: QueryGasScheduleRequest,
+    ) -> QueryGasScheduleResponse {
+        let schedule = self.baseapp_params_keeper.gas_schedule(ctx);
+
+        QueryGasScheduleResponse {
+            kv: schedule.kv.into(),
+            transient: schedule.transient.into(),
+        }
+    }
+
+    pub fn typed_query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: ParamsQueryRequest,
+    ) -> ParamsQueryResponse {
+        match query {
+            ParamsQueryRequest::Params(req) => {
+                ParamsQueryResponse::Params(self.query_params(ctx, req))
+            }
+            ParamsQueryRequest::GasSchedule(req) => {
+                ParamsQueryResponse::GasSchedule(self.query_gas_schedule(ctx, req))
+            }
+        }
+    }
+
+    pub fn query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        RequestQuery { data, path, .. }: RequestQuery,
+    ) -> Result<Vec<u8>, QueryError> {
+        let query = match path.as_str() {
+            QueryParamsRequest::QUERY_URL => {
+                let req = QueryParamsRequest::decode_vec(&data)?;
+                PSK::from_subspace_str(&req.subspace)
+                    .map_err(|e| QueryError::Proto(e.to_string()))?;
+
+                ParamsQueryRequest::Params(req)
+            }
+            QueryGasScheduleRequest::QUERY_URL => {
+                ParamsQueryRequest::GasSchedule(QueryGasScheduleRequest::decode_vec(&data)?)
+            }
+            _ => Err(QueryError::PathNotFound)?,
+        };
+
+        Ok(self.typed_query(ctx, query).into_bytes())
+    }
+}
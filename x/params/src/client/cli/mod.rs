@@ -0,0 +1,56 @@
+use gears::{application::handlers::client::QueryHandler, core::Protobuf};
+use query::{ParamsCommands, ParamsQueryCli};
+
+use crate::types::query::{
+    request::{ParamsQueryRequest, QueryGasScheduleRequest, QueryParamsRequest},
+    response::{ParamsQueryResponse, QueryGasScheduleResponse, QueryParamsResponse},
+};
+
+pub mod query;
+
+#[derive(Debug, Clone, Default)]
+pub struct ParamsClientHandler;
+
+impl QueryHandler for ParamsClientHandler {
+    type QueryCommands = ParamsQueryCli;
+
+    type QueryRequest = ParamsQueryRequest;
+
+    type QueryResponse = ParamsQueryResponse;
+
+    fn prepare_query_request(
+        &self,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryRequest> {
+        let request = match &command.command {
+            ParamsCommands::Subspace { subspace, key } => {
+                Self::QueryRequest::Params(QueryParamsRequest {
+                    subspace: subspace.clone(),
+                    key: key.clone(),
+                })
+            }
+            ParamsCommands::GasSchedule => {
+                Self::QueryRequest::GasSchedule(QueryGasScheduleRequest {})
+            }
+        };
+
+        Ok(request)
+    }
+
+    fn handle_raw_response(
+        &self,
+        bytes: Vec<u8>,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryResponse> {
+        let response = match &command.command {
+            ParamsCommands::Subspace { .. } => {
+                Self::QueryResponse::Params(QueryParamsResponse::decode_vec(&bytes)?)
+            }
+            ParamsCommands::GasSchedule => {
+                Self::QueryResponse::GasSchedule(QueryGasScheduleResponse::decode_vec(&bytes)?)
+            }
+        };
+
+        Ok(response)
+    }
+}
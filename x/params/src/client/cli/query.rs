@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct ParamsQueryCli {
+    #[command(subcommand)]
+    pub command: ParamsCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ParamsCommands {
+    /// Query the raw value of the param by its subspace and key
+    Subspace {
+        /// Subspace of the module params, e.g. `staking/`
+        subspace: String,
+        /// Key of the param in subspace, e.g. `MaxValidators`
+        key: String,
+    },
+    /// Query the gas schedule of store operations
+    GasSchedule,
+}
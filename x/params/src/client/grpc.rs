@@ -0,0 +1,112 @@
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{invalid_argument, unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
+
+use crate::types::query::{
+    request::{ParamsQueryRequest, RawQueryGasScheduleRequest, RawQueryParamsRequest},
+    response::{ParamsQueryResponse, RawQueryGasScheduleResponse, RawQueryParamsResponse},
+};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The params protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct ParamsService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> ParamsService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<ParamsQueryRequest>,
+    QRes: QueryResponse + TryInto<ParamsQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: ParamsQueryRequest,
+        height: u32,
+    ) -> Result<ParamsQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for ParamsService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.params.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for ParamsService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<ParamsQueryRequest>,
+    QRes: QueryResponse + TryInto<ParamsQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/cosmos.params.v1beta1.Query/Params" => {
+                unary(request, move |req: RawQueryParamsRequest, height| {
+                    info!("Received a gRPC request params::params");
+                    let req = ParamsQueryRequest::Params(req.try_into().map_err(invalid_argument)?);
+
+                    match service.typed_query(req, height)? {
+                        ParamsQueryResponse::Params(res) => Ok(RawQueryParamsResponse::from(res)),
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.params.v1beta1.Query/GasSchedule" => {
+                unary(request, move |req: RawQueryGasScheduleRequest, height| {
+                    info!("Received a gRPC request params::gas_schedule");
+                    let req =
+                        ParamsQueryRequest::GasSchedule(req.try_into().map_err(invalid_argument)?);
+
+                    match service.typed_query(req, height)? {
+                        ParamsQueryResponse::GasSchedule(res) => {
+                            Ok(RawQueryGasScheduleResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> ParamsService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<ParamsQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<ParamsQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    ParamsService {
+        app,
+        _phantom: Default::default(),
+    }
+}
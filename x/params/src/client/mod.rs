@@ -0,0 +1,3 @@
+pub mod cli;
+pub mod grpc;
+pub mod rest;
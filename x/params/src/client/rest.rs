@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, RestState},
+};
+
+use crate::types::query::{
+    request::{ParamsQueryRequest, QueryGasScheduleRequest, QueryParamsRequest},
+    response::ParamsQueryResponse,
+};
+
+pub async fn params<
+    QReq: QueryRequest + From<ParamsQueryRequest>,
+    QRes: QueryResponse + TryInto<ParamsQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Query(query): Query<QueryParamsRequest>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = ParamsQueryRequest::Params(query);
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn gas_schedule<
+    QReq: QueryRequest + From<ParamsQueryRequest>,
+    QRes: QueryResponse + TryInto<ParamsQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = ParamsQueryRequest::GasSchedule(QueryGasScheduleRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<ParamsQueryRequest>,
+    QRes: QueryResponse + TryInto<ParamsQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/params", get(params))
+        .route("/v1beta1/gas_schedule", get(gas_schedule))
+}
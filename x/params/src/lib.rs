@@ -0,0 +1,3 @@
+pub mod abci_handler;
+pub mod client;
+pub mod types;
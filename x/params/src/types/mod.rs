@@ -0,0 +1,70 @@
+use gears::{
+    derive::{Protobuf, Raw},
+    gas::metering::{config::GasConfig, FiniteGas},
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+pub mod query;
+
+/// Raw value of the param stored under the key of subspace
+#[derive(Debug, Clone, PartialEq, Raw, Protobuf, Serialize, Deserialize)]
+pub struct ParamChange {
+    #[raw(kind(string), raw = String)]
+    pub subspace: String,
+    #[raw(kind(string), raw = String)]
+    pub key: String,
+    #[raw(kind(string), raw = String)]
+    pub value: String,
+}
+
+/// Gas costs of store operations
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Raw, Protobuf, Serialize, Deserialize)]
+pub struct GasCosts {
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub has_cost: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub delete_cost: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub read_cost_flat: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub read_cost_per_byte: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub write_cost_flat: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub write_cost_per_byte: FiniteGas,
+    #[raw(kind(uint64), raw = u64)]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub iter_next_cost_flat: FiniteGas,
+}
+
+impl From<GasConfig> for GasCosts {
+    fn from(
+        GasConfig {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }: GasConfig,
+    ) -> Self {
+        Self {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use gears::{
+    baseapp::QueryRequest,
+    derive::{Protobuf, Query, Raw},
+};
+
+/// Query of the param by its subspace and key
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[query(url = "/cosmos.params.v1beta1.Query/Params")]
+pub struct QueryParamsRequest {
+    #[raw(kind(string), raw = String)]
+    pub subspace: String,
+    #[raw(kind(string), raw = String)]
+    pub key: String,
+}
+
+/// Query of the gas schedule used by stores in the current block
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[query(url = "/cosmos.params.v1beta1.Query/GasSchedule")]
+pub struct QueryGasScheduleRequest {}
+
+#[derive(Debug, Clone, PartialEq, Query)]
+pub enum ParamsQueryRequest {
+    Params(QueryParamsRequest),
+    GasSchedule(QueryGasScheduleRequest),
+}
+
+impl QueryRequest for ParamsQueryRequest {
+    fn height(&self) -> u32 {
+        0
+    }
+}
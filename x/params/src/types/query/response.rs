@@ -0,0 +1,27 @@
+use gears::derive::{Protobuf, Query, Raw};
+
+use crate::types::{GasCosts, ParamChange, RawGasCosts, RawParamChange};
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+pub struct QueryParamsResponse {
+    #[raw(kind(message), raw = RawParamChange, optional)]
+    #[proto(optional)]
+    pub param: ParamChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+pub struct QueryGasScheduleResponse {
+    #[raw(kind(message), raw = RawGasCosts, optional)]
+    #[proto(optional)]
+    pub kv: GasCosts,
+    #[raw(kind(message), raw = RawGasCosts, optional)]
+    #[proto(optional)]
+    pub transient: GasCosts,
+}
+
+#[derive(Debug, Clone, PartialEq, Query, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum ParamsQueryResponse {
+    Params(QueryParamsResponse),
+    GasSchedule(QueryGasScheduleResponse),
+}
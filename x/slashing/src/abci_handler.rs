@@ -1,7 +1,7 @@
 use crate::{
     errors::SlashingTxError, GenesisState, Keeper, Message, QueryParamsRequest,
-    QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfosRequest,
-    QuerySigningInfosResponse,
+    QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfoResponse,
+    QuerySigningInfosRequest, QuerySigningInfosResponse,
 };
 use gears::{
     baseapp::{errors::QueryError, QueryResponse},
@@ -28,14 +28,15 @@ pub struct ABCIHandler<
 
 #[derive(Clone, Debug)]
 pub enum SlashingNodeQueryRequest {
-    // TODO: check option to change signature of methods and implement typed queries
-    // SigningInfo(QuerySigningInfoRequest),
+    SigningInfo(QuerySigningInfoRequest),
     SigningInfos(QuerySigningInfosRequest),
     Params(QueryParamsRequest),
 }
 #[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
 pub enum SlashingNodeQueryResponse {
-    // SigningInfo(QuerySigningInfoResponse),
+    /// `None` if the validator has no signing info
+    SigningInfo(Option<QuerySigningInfoResponse>),
     SigningInfos(QuerySigningInfosResponse),
     Params(QueryParamsResponse),
 }
@@ -96,6 +97,9 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         query: SlashingNodeQueryRequest,
     ) -> SlashingNodeQueryResponse {
         match query {
+            SlashingNodeQueryRequest::SigningInfo(req) => SlashingNodeQueryResponse::SigningInfo(
+                self.keeper.query_signing_info(ctx, req).ok(),
+            ),
             SlashingNodeQueryRequest::SigningInfos(req) => {
                 SlashingNodeQueryResponse::SigningInfos(self.query_signing_infos(ctx, req))
             }
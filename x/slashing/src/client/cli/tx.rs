@@ -15,7 +15,7 @@ pub enum SlashingCommands {
     Unjail,
 }
 
-pub fn run_staking_tx_command(args: SlashingTxCli, from_address: AccAddress) -> Result<Message> {
+pub fn run_slashing_tx_command(args: SlashingTxCli, from_address: AccAddress) -> Result<Message> {
     match &args.command {
         SlashingCommands::Unjail => Ok(Message::Unjail(MsgUnjail {
             from_address: from_address.clone(),
@@ -1 +1,134 @@
+use crate::{
+    QueryParamsRequest, RawQueryParamsResponse, RawQuerySigningInfoRequest,
+    RawQuerySigningInfoResponse, RawQuerySigningInfosRequest, RawQuerySigningInfosResponse,
+    SlashingNodeQueryRequest, SlashingNodeQueryResponse,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{invalid_argument, unary, unimplemented},
+};
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Service, StdError},
+    server::NamedService,
+    Status,
+};
+use tracing::info;
 
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+/// The slashing protobuf definitions aren't part of `ibc_proto`, so the service is
+/// dispatched by hand on the method path
+#[derive(Debug, Clone)]
+pub struct SlashingService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH, QReq, QRes> SlashingService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    fn typed_query(
+        &self,
+        req: SlashingNodeQueryRequest,
+        height: u32,
+    ) -> Result<SlashingNodeQueryResponse, Status> {
+        self.app.typed_query_at(req, height)?.try_into()
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for SlashingService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.slashing.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for SlashingService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        match request.uri().path() {
+            "/cosmos.slashing.v1beta1.Query/Params" => {
+                unary(request, move |req: QueryParamsRequest, height| {
+                    info!("Received a gRPC request slashing::params");
+                    let req = SlashingNodeQueryRequest::Params(req);
+
+                    match service.typed_query(req, height)? {
+                        SlashingNodeQueryResponse::Params(res) => {
+                            Ok(RawQueryParamsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.slashing.v1beta1.Query/SigningInfo" => {
+                unary(request, move |req: RawQuerySigningInfoRequest, height| {
+                    info!("Received a gRPC request slashing::signing_info");
+                    let req = SlashingNodeQueryRequest::SigningInfo(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        SlashingNodeQueryResponse::SigningInfo(Some(res)) => {
+                            Ok(RawQuerySigningInfoResponse::from(res))
+                        }
+                        SlashingNodeQueryResponse::SigningInfo(None) => {
+                            Err(Status::not_found("signing info not found"))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.slashing.v1beta1.Query/SigningInfos" => {
+                unary(request, move |req: RawQuerySigningInfosRequest, height| {
+                    info!("Received a gRPC request slashing::signing_infos");
+                    let req = SlashingNodeQueryRequest::SigningInfos(
+                        req.try_into().map_err(invalid_argument)?,
+                    );
+
+                    match service.typed_query(req, height)? {
+                        SlashingNodeQueryResponse::SigningInfos(res) => {
+                            Ok(RawQuerySigningInfosResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> SlashingService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<SlashingNodeQueryRequest>,
+    QRes:
+        QueryResponse + Send + Sync + 'static + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    SlashingService {
+        app,
+        _phantom: Default::default(),
+    }
+}
@@ -1,31 +1,61 @@
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     routing::get,
     Json, Router,
 };
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
-    types::pagination::request::PaginationRequest,
+    rest::{error::HTTPError, BlockHeight, Pagination, RestState},
+    types::{address::ConsAddress, pagination::request::PaginationRequest},
 };
 
 use crate::{
-    QueryParamsRequest, QueryParamsResponse, QuerySigningInfosRequest, SlashingNodeQueryRequest,
-    SlashingNodeQueryResponse, SlashingParams,
+    QueryParamsRequest, QuerySigningInfoRequest, QuerySigningInfosRequest,
+    SlashingNodeQueryRequest, SlashingNodeQueryResponse,
 };
 
+pub async fn signing_info<
+    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(cons_address): Path<ConsAddress>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<SlashingNodeQueryResponse>, HTTPError> {
+    let req = SlashingNodeQueryRequest::SigningInfo(QuerySigningInfoRequest {
+        cons_address: cons_address.clone(),
+    });
+
+    match rest_state
+        .app
+        .typed_query_at(req, height)?
+        .try_into()
+        .map_err(|_| HTTPError::internal_server_error())?
+    {
+        SlashingNodeQueryResponse::SigningInfo(Some(res)) => {
+            Ok(Json(SlashingNodeQueryResponse::SigningInfo(Some(res))))
+        }
+        SlashingNodeQueryResponse::SigningInfo(None) => Err(HTTPError::not_found_with_msg(
+            format!("signing info of validator {cons_address} is not found"),
+        )),
+        _ => Err(HTTPError::internal_server_error()),
+    }
+}
+
 pub async fn signing_infos<
     QReq: QueryRequest + From<SlashingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = SlashingNodeQueryRequest::SigningInfos(QuerySigningInfosRequest {
         pagination: PaginationRequest::from(pagination),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -34,17 +64,11 @@ pub async fn params<
     QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = SlashingNodeQueryRequest::Params(QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
-    Ok(Json(res))
-}
-
-pub async fn const_params() -> Result<Json<QueryParamsResponse>, HTTPError> {
-    let res = QueryParamsResponse {
-        params: SlashingParams::default(),
-    };
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -55,10 +79,6 @@ pub fn get_router<
 >() -> Router<RestState<QReq, QRes, App>> {
     Router::new()
         .route("/v1beta1/signing_infos", get(signing_infos))
-        .route(
-            "/v1beta1/params/current", /* "/v1beta1/params" */
-            get(params),
-        )
-        // TODO: remove const handler and route after integration and update route
-        .route("/v1beta1/params", get(const_params))
+        .route("/v1beta1/signing_infos/:cons_address", get(signing_info))
+        .route("/v1beta1/params", get(params))
 }
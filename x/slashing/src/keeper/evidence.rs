@@ -0,0 +1,139 @@
+use super::*;
+use gears::{
+    tendermint::types::time::timestamp::Timestamp, x::keepers::slashing::EvidenceSlashingKeeper,
+};
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M: Module>
+    EvidenceSlashingKeeper<SK, M> for Keeper<SK, PSK, SSK, M>
+{
+    fn pubkey<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
+        self.get_pub_key(ctx, addr)
+    }
+
+    fn has_validator_signing_info<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self.validator_signing_info(ctx, addr)?.is_some())
+    }
+
+    fn is_tombstoned<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self
+            .validator_signing_info(ctx, addr)?
+            .map(|info| info.tombstoned)
+            .unwrap_or_default())
+    }
+
+    fn slash_fraction_double_sign<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        Ok(self
+            .slashing_params_keeper
+            .try_get(ctx)?
+            .slash_fraction_double_sign)
+    }
+
+    /// slash attempts to slash a validator. The slash is delegated to the staking
+    /// module to make the necessary validator changes.
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        amount: Decimal256,
+        validator_power: i64,
+        height: i64,
+    ) -> Result<(), GasStoreErrors> {
+        let power = VotingPower::try_from(validator_power)
+            .expect("evidence contains power of existing validator");
+        // distribution height can be negative at the beginning of the chain,
+        // in that case there is nothing to filter by height
+        let height = u32::try_from(height).unwrap_or_default();
+
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "address".into(),
+                    value: addr.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "power".into(),
+                    value: power.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "reason".into(),
+                    value: "double_sign".to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        self.staking_keeper.slash(ctx, addr, height, power, amount)
+    }
+
+    /// jail attempts to jail a validator. The slash is delegated to the staking module
+    /// to make the necessary validator changes.
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![EventAttribute {
+                key: "jailed".into(),
+                value: addr.to_string().into(),
+                index: false,
+            }],
+        });
+
+        self.staking_keeper.jail(ctx, addr)
+    }
+
+    /// jail_until attempts to set a validator's jailed_until attribute in its signing
+    /// info. It will panic if the signing info does not exist for the validator.
+    fn jail_until<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        time: Timestamp,
+    ) -> Result<(), GasStoreErrors> {
+        let mut signing_info = self.validator_signing_info(ctx, addr)?.unwrap_or_else(|| {
+            panic!("cannot jail validator {addr} that does not have any signing information")
+        });
+
+        signing_info.jailed_until = time;
+        self.set_validator_signing_info(ctx, addr, &signing_info)
+    }
+
+    /// tombstone attempts to tombstone a validator. It will panic if signing info for
+    /// the given validator does not exist or the validator is already tombstoned.
+    fn tombstone<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut signing_info = self.validator_signing_info(ctx, addr)?.unwrap_or_else(|| {
+            panic!("cannot tombstone validator {addr} that does not have any signing information")
+        });
+
+        if signing_info.tombstoned {
+            panic!("cannot tombstone validator {addr} that is already tombstoned");
+        }
+
+        signing_info.tombstoned = true;
+        self.set_validator_signing_info(ctx, addr, &signing_info)
+    }
+}
@@ -0,0 +1,142 @@
+use super::*;
+use gears::{
+    tendermint::types::time::timestamp::Timestamp,
+    x::{
+        errors::StakingHooksError,
+        keepers::{auth::AuthKeeper, staking::KeeperHooks},
+    },
+};
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M: Module>
+    Keeper<SK, PSK, SSK, M>
+{
+    /// create the signing info of a newly bonded validator if it doesn't exist yet
+    fn create_signing_info_if_not_exists<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        if self.validator_signing_info(ctx, cons_addr)?.is_some() {
+            return Ok(());
+        }
+
+        let signing_info = ValidatorSigningInfo {
+            address: cons_addr.clone(),
+            start_height: ctx.height(),
+            index_offset: 0,
+            jailed_until: Timestamp::UNIX_EPOCH,
+            tombstoned: false,
+            missed_blocks_counter: 0,
+        };
+        self.set_validator_signing_info(ctx, cons_addr, &signing_info)
+    }
+
+    /// store the relation between consensus address and public key of a new validator
+    fn add_validator_pub_key<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: &ValAddress,
+    ) -> Result<(), GasStoreErrors> {
+        if let Some(validator) = self.staking_keeper.validator(ctx, val_addr)? {
+            self.add_pub_key(ctx, validator.cons_pub_key())?;
+        }
+        Ok(())
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        SSK: SlashingStakingKeeper<SK, M>,
+        AK: AuthKeeper<SK, M>,
+        M: Module,
+    > KeeperHooks<SK, AK, M> for Keeper<SK, PSK, SSK, M>
+{
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.add_validator_pub_key(ctx, &val_addr)?)
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.delete_pub_key(ctx, &cons_addr)?)
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.create_signing_info_if_not_exists(ctx, &cons_addr)?)
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+        _fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+}
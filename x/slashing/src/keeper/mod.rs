@@ -41,6 +41,9 @@ use gears::{
 use gears::{extensions::gas::GasResultExt, gas::store::errors::GasStoreErrors};
 use std::marker::PhantomData;
 
+mod evidence;
+mod hooks;
+
 pub(crate) const VALIDATOR_SIGNING_INFO_KEY_PREFIX: [u8; 1] = [0x1];
 pub(crate) const VALIDATOR_MISSED_BLOCK_BIT_ARRAY_KEY_PREFIX: [u8; 1] = [0x2];
 pub(crate) const ADDR_PUBKEY_RELATION_KEY_PREFIX: [u8; 1] = [0x3];
@@ -91,10 +94,11 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
             .collect();
         pub_keys
             .into_iter()
-            .for_each(|pub_key| self.add_pub_key(ctx, &pub_key));
+            .for_each(|pub_key| self.add_pub_key(ctx, &pub_key).unwrap_gas());
 
         genesis.signing_infos.into_iter().for_each(|info| {
             self.set_validator_signing_info(ctx, &info.address, &info.validator_signing_info)
+                .unwrap_gas()
         });
 
         genesis.missed_blocks.into_iter().for_each(|block| {
@@ -122,6 +126,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // fetch the validator public key
         self.get_pub_key(ctx, &cons_addr)
+            .unwrap_gas()
             .ok_or(ValidatorHandlingError::ConsensusNotFound)?;
 
         // fetch signing info
@@ -205,14 +210,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // if we are past the minimum height and the validator has missed too many blocks, punish them
         if height > min_height && sign_info.missed_blocks_counter > max_missed {
-            let validator_is_jailed = self
+            let validator_is_active = self
                 .staking_keeper
                 .validator_by_cons_addr(ctx, &cons_addr)
                 .unwrap_gas()
-                .map(|v| v.is_jailed())
+                .map(|v| !v.is_jailed())
                 .unwrap_or_default();
 
-            if validator_is_jailed {
+            if validator_is_active {
                 // Downtime confirmed: slash and jail the validator
                 // We need to retrieve the stake distribution which signed the block, so we subtract ValidatorUpdateDelay from the evidence height,
                 // and subtract an additional 1 since this is the LastCommit.
@@ -258,7 +263,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
                 self.staking_keeper.jail(ctx, &cons_addr).unwrap_gas();
 
                 let time = ctx.get_time();
-                let delta = Duration::try_new(params.downtime_jail_duration, 0).unwrap();
+                let delta = Duration::try_new_from_nanos(params.downtime_jail_duration.into())
+                    .expect("jail duration is validated by params");
                 let jailed_until = time.checked_add(delta).unwrap();
                 sign_info.jailed_until = jailed_until;
                 // We need to reset the counter & array so that the validator won't be immediately slashed for downtime upon rebonding.
@@ -290,7 +296,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         }
 
         // Set the updated signing info
-        self.set_validator_signing_info(ctx, &cons_addr, &sign_info);
+        self.set_validator_signing_info(ctx, &cons_addr, &sign_info)
+            .unwrap_gas();
 
         Ok(())
     }
@@ -413,24 +420,24 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     //
 
     /// get_pub_key returns the pubkey from the adddress-pubkey relation
-    pub fn get_pub_key<DB: Database>(
+    pub fn get_pub_key<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
-        ctx: &BlockContext<'_, DB, SK>,
+        ctx: &CTX,
         addr: &ConsAddress,
-    ) -> Option<PublicKey> {
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
         let store = ctx.kv_store(&self.store_key);
         let key = addr_pubkey_relation_key(addr.clone());
         store
             .get(&key)
-            .map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            .map(|pub_key| pub_key.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt()))
     }
 
     /// add_pub_key sets a address-pubkey relation
-    pub fn add_pub_key<DB: Database>(
+    pub fn add_pub_key<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut InitContext<'_, DB, SK>,
+        ctx: &mut CTX,
         pub_key: &PublicKey,
-    ) {
+    ) -> Result<(), GasStoreErrors> {
         let mut store = ctx.kv_store_mut(&self.store_key);
         // TODO: check the addr type for genesis
         let addr = ConsAddress::from(pub_key.clone());
@@ -441,6 +448,17 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         store.set(key, value)
     }
 
+    /// delete_pub_key removes a address-pubkey relation
+    pub fn delete_pub_key<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&addr_pubkey_relation_key(addr.clone()))?;
+        Ok(())
+    }
+
     /// validator_signing_info gets the validator signing
     pub fn validator_signing_info<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -450,7 +468,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         let store = ctx.kv_store(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
         store.get(&key).map(|sign_info| {
-            sign_info.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            sign_info.map(|bytes| ValidatorSigningInfo::decode_vec(&bytes).unwrap_or_corrupt())
         })
     }
 
@@ -471,13 +489,13 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     }
 
     /// set_validator_signing_info sets the validator signing info to a consensus address key
-    pub fn set_validator_signing_info<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+    pub fn set_validator_signing_info<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         addr: &ConsAddress,
         signing_info: &ValidatorSigningInfo,
-    ) {
-        let mut store = ctx.infallible_store_mut(&self.store_key);
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
         let value = signing_info.encode_vec();
         store.set(key, value)
@@ -5,8 +5,8 @@ use gears::{
     extensions::corruption::UnwrapCorrupt,
     gas::store::errors::GasStoreErrors,
     params::{
-        gas, infallible_subspace, infallible_subspace_mut, ParamKind, ParamsDeserialize,
-        ParamsSerialize, ParamsSubspaceKey,
+        gas, infallible_subspace, infallible_subspace_mut, parsed::Params, ParamKind,
+        ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey, ParamsValidationError,
     },
     store::{database::Database, StoreKey},
     types::{
@@ -213,6 +213,32 @@ impl ParamsDeserialize for SlashingParams {
             .unwrap_or_corrupt(),
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let invalid = |reason: &str| ParamsValidationError::invalid_value(key, reason);
+
+        match key {
+            KEY_SIGNED_BLOCKS_WINDOW | KEY_DOWNTIME_JAIL_DURATION => match ParamKind::I64
+                .try_parse_param(value.to_vec())
+                .and_then(Params::signed_64)
+            {
+                Some(var) if var > 0 => Ok(()),
+                Some(_) => Err(invalid("must be positive")),
+                None => Err(invalid("expected i64")),
+            },
+            KEY_MIN_SIGNED_PER_WINDOW
+            | KEY_SLASH_FRACTION_DOUBLE_SIGN
+            | KEY_SLASH_FRACTION_DOWNTIME => match std::str::from_utf8(value)
+                .ok()
+                .and_then(|this| Decimal256::from_str(this).ok())
+            {
+                Some(var) if var <= Decimal256::one() => Ok(()),
+                Some(_) => Err(invalid("must not be greater than 1")),
+                None => Err(invalid("expected decimal")),
+            },
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 impl Default for SlashingParams {
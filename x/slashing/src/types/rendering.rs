@@ -0,0 +1,34 @@
+//! Textual sign mode rendering of slashing messages as specified in ADR-050
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Indent, Screen},
+};
+
+use super::MsgUnjail;
+
+impl ValueRenderer for MsgUnjail {
+    /// Format `MsgUnjail`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "From address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.from_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
@@ -25,7 +25,10 @@ impl From<MsgUnjail> for MsgUnjailRaw {
 
 /// MsgUnjail creates a new MsgUnjail instance
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.slashing.v1beta1.Unjail")]
+#[msg(
+    url = "/cosmos.slashing.v1beta1.Unjail",
+    amino_url = "cosmos-sdk/MsgUnjail"
+)]
 pub struct MsgUnjail {
     pub validator_address: ValAddress,
     #[msg(signer)]
@@ -124,8 +124,8 @@ impl<
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        match query {
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
             StakingNodeQueryRequest::Validator(req) => {
                 StakingNodeQueryResponse::Validator(self.query_validator(ctx, req))
             }
@@ -185,7 +185,9 @@ impl<
             StakingNodeQueryRequest::Params(_) => {
                 StakingNodeQueryResponse::Params(self.query_params(ctx))
             }
-        }
+        };
+
+        Ok(res)
     }
 
     fn run_ante_checks<DB: Database>(
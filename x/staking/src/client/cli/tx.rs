@@ -5,7 +5,11 @@ use crate::{
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use gears::{
-    tendermint::types::proto::crypto::PublicKey as TendermintPublicKey,
+    commands::client::tx::ClientTxContext,
+    tendermint::{
+        privval::Address,
+        types::{chain_id::ChainId, proto::crypto::PublicKey as TendermintPublicKey},
+    },
     types::{
         address::{AccAddress, ValAddress},
         base::coin::UnsignedCoin,
@@ -24,7 +28,11 @@ pub struct StakingTxCli {
 #[derive(Args, Debug, Clone)]
 pub struct CreateValidatorCli {
     /// The validator's Protobuf JSON encoded public key
-    pub pubkey: TendermintPublicKey,
+    #[arg(long, required_unless_present = "priv_validator_laddr")]
+    pub pubkey: Option<TendermintPublicKey>,
+    /// Fetch the validator's public key from remote signer which connects to this address (e.g. unix:///path/to/privval.sock). Only unix sockets are supported
+    #[arg(long, conflicts_with = "pubkey", value_parser = gears::tendermint::privval::parse_laddr)]
+    pub priv_validator_laddr: Option<Address>,
     /// Amount of coins to bond
     pub amount: UnsignedCoin,
     /// The validator's name
@@ -59,9 +67,14 @@ pub struct CreateValidatorCli {
 }
 
 impl CreateValidatorCli {
-    pub fn try_into_cmd(self, from_address: AccAddress) -> anyhow::Result<CreateValidator> {
+    pub fn try_into_cmd(
+        self,
+        from_address: AccAddress,
+        chain_id: &ChainId,
+    ) -> anyhow::Result<CreateValidator> {
         let CreateValidatorCli {
             pubkey,
+            priv_validator_laddr,
             amount,
             moniker,
             identity,
@@ -74,6 +87,21 @@ impl CreateValidatorCli {
             min_self_delegation,
         } = self;
 
+        let pubkey = match (pubkey, priv_validator_laddr) {
+            (Some(pubkey), _) => pubkey,
+            (None, Some(laddr)) => {
+                println!("Waiting for remote signer to connect to {laddr}");
+                gears::tendermint::privval::fetch_validator_pub_key(
+                    &laddr,
+                    chain_id,
+                    gears::tendermint::privval::CONNECT_TIMEOUT,
+                )?
+            }
+            (None, None) => Err(anyhow::anyhow!(
+                "either pubkey or priv_validator_laddr is required"
+            ))?,
+        };
+
         let delegator_address = from_address.clone();
         let validator_address = ValAddress::from(from_address);
         let description = Description {
@@ -95,8 +123,8 @@ impl CreateValidatorCli {
             min_self_delegation,
             delegator_address,
             validator_address,
-            pubkey: pubkey.clone(),
-            value: amount.clone(),
+            pubkey,
+            value: amount,
         };
 
         Ok(msg)
@@ -156,12 +184,15 @@ pub enum StakingCommands {
 }
 
 pub fn run_staking_tx_command(
+    ctx: &ClientTxContext,
     args: StakingTxCli,
     from_address: AccAddress,
 ) -> Result<StakingMessage> {
     match &args.command {
         StakingCommands::CreateValidator(msg) => {
-            let msg = StakingMessage::CreateValidator(msg.clone().try_into_cmd(from_address)?);
+            let msg = StakingMessage::CreateValidator(
+                msg.clone().try_into_cmd(from_address, &ctx.chain_id)?,
+            );
 
             Ok(msg)
 
@@ -231,3 +262,59 @@ pub fn run_staking_tx_command(
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, time::Duration};
+
+    use gears::extensions::testing::UnwrapTesting;
+    use tendermint::privval::MockSigner;
+
+    use super::*;
+
+    #[test]
+    fn create_validator_asks_remote_signer() -> anyhow::Result<()> {
+        let chain_id = ChainId::new("test-chain")?;
+        let dir = std::env::temp_dir().join(format!("create-validator-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        tendermint::write_keys_and_genesis(
+            File::create(dir.join("node_key.json"))?,
+            File::create(dir.join("priv_validator_key.json"))?,
+            File::create(dir.join("genesis.json"))?,
+            serde_json::json!({}),
+            chain_id.clone(),
+        )?;
+        let signer = MockSigner::from_key_file(
+            File::open(dir.join("priv_validator_key.json"))?,
+            chain_id.clone(),
+        )?;
+        let pub_key = signer.pub_key()?;
+
+        let laddr: Address = format!("unix://{}", dir.join("privval.sock").display()).parse()?;
+        let cli = CreateValidatorCli {
+            pubkey: None,
+            priv_validator_laddr: Some(laddr.clone()),
+            amount: "100uatom".parse()?,
+            moniker: "test".to_owned(),
+            identity: String::new(),
+            website: String::new(),
+            security_contact: String::new(),
+            details: String::new(),
+            commission_rate: Decimal256::from_atomics(1u64, 1)?,
+            commission_max_rate: Decimal256::from_atomics(2u64, 1)?,
+            commission_max_change_rate: Decimal256::from_atomics(1u64, 2)?,
+            min_self_delegation: Uint256::one(),
+        };
+
+        let handle = std::thread::spawn(move || signer.run(&laddr, Duration::from_secs(5)));
+        let msg = cli.try_into_cmd(AccAddress::try_from(vec![1; 20]).unwrap_test(), &chain_id)?;
+        handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("signer thread panicked"))??;
+
+        assert_eq!(msg.pubkey, pub_key);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::block_height,
+};
 use ibc_proto::cosmos::staking::v1beta1::{
     query_server::{Query, QueryServer},
     QueryDelegationRequest, QueryDelegationResponse, QueryDelegatorDelegationsRequest,
@@ -40,8 +43,9 @@ where
         request: Request<QueryValidatorsRequest>,
     ) -> Result<Response<QueryValidatorsResponse>, Status> {
         info!("Received a gRPC request staking::validators");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Validators(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Validators(response) = response {
@@ -58,8 +62,9 @@ where
         request: Request<QueryValidatorRequest>,
     ) -> Result<Response<QueryValidatorResponse>, Status> {
         info!("Received a gRPC request staking::validator");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Validator(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Validator(response) = response {
@@ -76,8 +81,9 @@ where
         request: Request<QueryValidatorDelegationsRequest>,
     ) -> Result<Response<QueryValidatorDelegationsResponse>, Status> {
         info!("Received a gRPC request staking::validator_delegations");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::ValidatorDelegations(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::ValidatorDelegations(response) = response {
@@ -94,10 +100,11 @@ where
         request: Request<QueryValidatorUnbondingDelegationsRequest>,
     ) -> Result<Response<QueryValidatorUnbondingDelegationsResponse>, Status> {
         info!("Received a gRPC request staking::validator_unbonding_delegations");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::ValidatorUnbondingDelegations(
             request.into_inner().try_into()?,
         );
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::ValidatorUnbondingDelegations(response) = response {
@@ -114,8 +121,9 @@ where
         request: Request<QueryDelegationRequest>,
     ) -> Result<Response<QueryDelegationResponse>, Status> {
         info!("Received a gRPC request staking::delegation");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Delegation(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Delegation(response) = response {
@@ -132,8 +140,9 @@ where
         request: Request<QueryUnbondingDelegationRequest>,
     ) -> Result<Response<QueryUnbondingDelegationResponse>, Status> {
         info!("Received a gRPC request staking::unbonding_delegation");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::UnbondingDelegation(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::UnbondingDelegation(response) = response {
@@ -150,8 +159,9 @@ where
         request: Request<QueryDelegatorDelegationsRequest>,
     ) -> Result<Response<QueryDelegatorDelegationsResponse>, Status> {
         info!("Received a gRPC request staking::delegator_delegations");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Delegations(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Delegations(response) = response {
@@ -168,8 +178,9 @@ where
         request: Request<QueryDelegatorUnbondingDelegationsRequest>,
     ) -> Result<Response<QueryDelegatorUnbondingDelegationsResponse>, Status> {
         info!("Received a gRPC request staking::delegator_unbonding_delegations");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::UnbondingDelegations(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::UnbondingDelegations(response) = response {
@@ -186,12 +197,13 @@ where
         request: Request<QueryRedelegationsRequest>,
     ) -> Result<Response<QueryRedelegationsResponse>, Status> {
         info!("Received a gRPC request staking::redelegations");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Redelegations(request.into_inner().try_into().map_err(
             |_| {
                 Status::internal("An internal error occurred while querying the application state.")
             },
         )?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Redelegations(response) = response {
@@ -208,12 +220,13 @@ where
         request: Request<QueryDelegatorValidatorsRequest>,
     ) -> Result<Response<QueryDelegatorValidatorsResponse>, Status> {
         info!("Received a gRPC request staking::delegator_validators");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::DelegatorValidators(
             request.into_inner().try_into().map_err(|_| {
                 Status::internal("An internal error occurred while querying the application state.")
             })?,
         );
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::DelegatorValidators(response) = response {
@@ -230,8 +243,9 @@ where
         request: Request<QueryDelegatorValidatorRequest>,
     ) -> Result<Response<QueryDelegatorValidatorResponse>, Status> {
         info!("Received a gRPC request staking::delegator_validator");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::DelegatorValidator(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::DelegatorValidator(response) = response {
@@ -248,8 +262,9 @@ where
         request: Request<QueryHistoricalInfoRequest>,
     ) -> Result<Response<QueryHistoricalInfoResponse>, Status> {
         info!("Received a gRPC request staking::historical_info");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::HistoricalInfo(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::HistoricalInfo(response) = response {
@@ -266,8 +281,9 @@ where
         request: Request<QueryPoolRequest>,
     ) -> Result<Response<QueryPoolResponse>, Status> {
         info!("Received a gRPC request staking::pool");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Pool(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Pool(response) = response {
@@ -284,8 +300,9 @@ where
         request: Request<QueryParamsRequest>,
     ) -> Result<Response<QueryParamsResponse>, Status> {
         info!("Received a gRPC request staking::params");
+        let height = block_height(&request)?;
         let req = StakingNodeQueryRequest::Params(request.into_inner().try_into()?);
-        let response = self.app.typed_query(req)?;
+        let response = self.app.typed_query_at(req, height)?;
         let response: StakingNodeQueryResponse = response.try_into()?;
 
         if let StakingNodeQueryResponse::Params(response) = response {
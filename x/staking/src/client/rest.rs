@@ -1,7 +1,8 @@
 use crate::{
     QueryDelegationRequest, QueryDelegatorDelegationsRequest,
-    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorValidatorsRequest, QueryPoolRequest,
-    QueryValidatorDelegationsRequest, QueryValidatorRequest,
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorValidatorRequest,
+    QueryDelegatorValidatorsRequest, QueryHistoricalInfoRequest, QueryPoolRequest,
+    QueryRedelegationsRequest, QueryValidatorDelegationsRequest, QueryValidatorRequest,
     QueryValidatorUnbondingDelegationsRequest, QueryValidatorsRequest, StakingNodeQueryRequest,
     StakingNodeQueryResponse,
 };
@@ -12,7 +13,7 @@ use axum::{
 };
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
+    rest::{error::HTTPError, BlockHeight, Pagination, RestState},
     types::{
         address::{AccAddress, ValAddress},
         pagination::request::PaginationRequest,
@@ -27,10 +28,11 @@ pub async fn validator<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_addr): Path<ValAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Validator(QueryValidatorRequest { validator_addr });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -38,7 +40,9 @@ pub async fn validator<
 pub struct ValidatorsQuery {
     status: Option<BondStatus>,
     // TODO: serde(flatten) doesn't work
+    #[serde(alias = "pagination.offset")]
     offset: Option<u32>,
+    #[serde(alias = "pagination.limit")]
     limit: Option<u8>,
 }
 
@@ -52,13 +56,14 @@ pub async fn validators<
         offset,
         limit,
     }): Query<ValidatorsQuery>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Validators(QueryValidatorsRequest {
         status: status.unwrap_or(BondStatus::Unspecified),
         pagination: Some(PaginationRequest::from(Pagination::new(offset, limit))),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -68,6 +73,7 @@ pub async fn validator_delegations<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_addr): Path<ValAddress>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
     Query(pagination): Query<Pagination>,
 ) -> Result<Json<QRes>, HTTPError> {
@@ -75,7 +81,7 @@ pub async fn validator_delegations<
         validator_addr,
         pagination: Some(PaginationRequest::from(pagination)),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -85,6 +91,7 @@ pub async fn validator_unbonding_delegations<
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path((validator_addr, delegator_addr)): Path<(ValAddress, AccAddress)>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
     Query(pagination): Query<Pagination>,
 ) -> Result<Json<StakingNodeQueryResponse>, HTTPError> {
@@ -98,7 +105,7 @@ pub async fn validator_unbonding_delegations<
     // TODO: consider to add filtering to the method
     if let StakingNodeQueryResponse::ValidatorUnbondingDelegations(mut res) = rest_state
         .app
-        .typed_query(req)?
+        .typed_query_at(req, height)?
         .try_into()
         .map_err(|_| HTTPError::internal_server_error())?
     {
@@ -112,19 +119,40 @@ pub async fn validator_unbonding_delegations<
     }
 }
 
+pub async fn validator_unbonding_delegations_list<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(validator_addr): Path<ValAddress>,
+    Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::ValidatorUnbondingDelegations(
+        QueryValidatorUnbondingDelegationsRequest {
+            validator_addr,
+            pagination: Some(PaginationRequest::from(pagination)),
+        },
+    );
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
 pub async fn delegation<
     QReq: QueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path((validator_addr, delegator_addr)): Path<(ValAddress, AccAddress)>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Delegation(QueryDelegationRequest {
         delegator_addr,
         validator_addr,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -135,13 +163,14 @@ pub async fn delegator_delegations<
 >(
     Path(delegator_addr): Path<AccAddress>,
     Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Delegations(QueryDelegatorDelegationsRequest {
         delegator_addr,
         pagination: Some(PaginationRequest::from(pagination)),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -152,13 +181,14 @@ pub async fn delegator_validators<
 >(
     Path(delegator_addr): Path<AccAddress>,
     Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::DelegatorValidators(QueryDelegatorValidatorsRequest {
         delegator_addr,
         pagination: Some(PaginationRequest::from(pagination)),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -169,6 +199,7 @@ pub async fn unbonding_delegations<
 >(
     Path(delegator_addr): Path<AccAddress>,
     Query(pagination): Query<Pagination>,
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req =
@@ -176,7 +207,75 @@ pub async fn unbonding_delegations<
             delegator_addr: delegator_addr.clone(),
             pagination: Some(PaginationRequest::from(pagination)),
         });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RedelegationsQuery {
+    src_validator_addr: Option<ValAddress>,
+    dst_validator_addr: Option<ValAddress>,
+    #[serde(alias = "pagination.offset")]
+    offset: Option<u32>,
+    #[serde(alias = "pagination.limit")]
+    limit: Option<u8>,
+}
+
+pub async fn redelegations<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_addr): Path<AccAddress>,
+    Query(RedelegationsQuery {
+        src_validator_addr,
+        dst_validator_addr,
+        offset,
+        limit,
+    }): Query<RedelegationsQuery>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::Redelegations(QueryRedelegationsRequest {
+        delegator_address: Some(delegator_addr),
+        src_validator_address: src_validator_addr,
+        dst_validator_address: dst_validator_addr,
+        pagination: Some(PaginationRequest::from(Pagination::new(offset, limit))),
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_validator<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_addr, validator_addr)): Path<(AccAddress, ValAddress)>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::DelegatorValidator(QueryDelegatorValidatorRequest {
+        delegator_addr,
+        validator_addr,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn historical_info<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(historical_height): Path<i64>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::HistoricalInfo(QueryHistoricalInfoRequest {
+        height: historical_height,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -185,10 +284,11 @@ pub async fn pool<
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Pool(QueryPoolRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -197,10 +297,11 @@ pub async fn params<
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    BlockHeight(height): BlockHeight,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Params(crate::QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query_at(req, height)?;
     Ok(Json(res))
 }
 
@@ -220,6 +321,10 @@ pub fn get_router<
             "/v1beta1/validators/:validator_addr/delegations/:delegator_addr/unbonding_delegation",
             get(validator_unbonding_delegations),
         )
+        .route(
+            "/v1beta1/validators/:validator_addr/unbonding_delegations",
+            get(validator_unbonding_delegations_list),
+        )
         .route(
             "/v1beta1/validators/:validator_addr/delegations/:delegator_addr",
             get(delegation),
@@ -232,10 +337,19 @@ pub fn get_router<
             "/v1beta1/delegators/:delegator_addr/validators",
             get(delegator_validators),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_addr/validators/:validator_addr",
+            get(delegator_validator),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_addr/redelegations",
+            get(redelegations),
+        )
         .route(
             "/v1beta1/delegators/:delegator_addr/unbonding_delegations",
             get(unbonding_delegations),
         )
+        .route("/v1beta1/historical_info/:height", get(historical_info))
         .route("/v1beta1/pool", get(pool))
         .route("/v1beta1/params", get(params))
 }
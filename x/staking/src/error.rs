@@ -2,7 +2,7 @@ use gears::{
     application::handlers::node::{ModuleInfo, TxError},
     tendermint::error::Error,
     types::{address::ValAddress, base::coin::UnsignedCoin},
-    x::types::validator::BondStatus,
+    x::{errors::StakingHooksError, types::validator::BondStatus},
 };
 use thiserror::Error;
 
@@ -18,6 +18,8 @@ pub enum StakingGenesisError {
     ValidatorNotFound(ValAddress),
     #[error("{0}")]
     VotingPower(#[from] Error),
+    #[error("{0}")]
+    Hooks(#[from] StakingHooksError),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
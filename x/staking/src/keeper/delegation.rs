@@ -36,10 +36,10 @@ impl<
         let mut delegation = if let Some(delegation) =
             self.delegation(ctx, del_addr, &validator.operator_address)?
         {
-            self.before_delegation_shares_modified(ctx, del_addr, &validator.operator_address);
+            self.before_delegation_shares_modified(ctx, del_addr, &validator.operator_address)?;
             delegation
         } else {
-            self.before_delegation_created(ctx, del_addr, &validator.operator_address);
+            self.before_delegation_created(ctx, del_addr, &validator.operator_address)?;
             Delegation {
                 delegator_address: del_addr.clone(),
                 validator_address: validator.operator_address.clone(),
@@ -113,7 +113,7 @@ impl<
             ctx,
             &delegation.delegator_address,
             &delegation.validator_address,
-        );
+        )?;
 
         Ok(new_shares)
     }
@@ -151,11 +151,17 @@ impl<
         &self,
         ctx: &mut CTX,
         delegation: &Delegation,
-    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+    ) -> Result<Option<Vec<u8>>, StakingHooksError> {
+        self.before_delegation_removed(
+            ctx,
+            &delegation.delegator_address,
+            &delegation.validator_address,
+        )?;
+
         let store = ctx.kv_store_mut(&self.store_key);
         let mut delegations_store = store.prefix_store_mut(DELEGATION_KEY);
         let mut key = delegation.delegator_address.prefix_len_bytes();
         key.extend_from_slice(&delegation.validator_address.prefix_len_bytes());
-        delegations_store.delete(&key)
+        Ok(delegations_store.delete(&key)?)
     }
 }
@@ -13,33 +13,36 @@ impl<
         &self,
         ctx: &mut CTX,
         validator: &Validator,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
-            hooks.before_validator_modified(ctx, validator.operator_address.clone());
+            hooks.before_validator_modified(ctx, validator.operator_address.clone())?;
         }
+        Ok(())
     }
     pub fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         validator: &Validator,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
-            hooks.after_validator_created(ctx, validator.operator_address.clone());
+            hooks.after_validator_created(ctx, validator.operator_address.clone())?;
         }
+        Ok(())
     }
 
     pub fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         validator: &Validator,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
             hooks.after_validator_bonded(
                 ctx,
                 validator.cons_addr(),
                 validator.operator_address.clone(),
-            );
+            )?;
         }
+        Ok(())
     }
 
     pub fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -47,10 +50,11 @@ impl<
         ctx: &mut CTX,
         del_addr: &AccAddress,
         val_addr: &ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
-            hooks.before_delegation_created(ctx, del_addr.clone(), val_addr.clone());
+            hooks.before_delegation_created(ctx, del_addr.clone(), val_addr.clone())?;
         }
+        Ok(())
     }
 
     pub fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -58,14 +62,15 @@ impl<
         ctx: &mut CTX,
         del_addr: &AccAddress,
         val_addr: &ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
             hooks.before_delegation_shares_modified::<DB, CTX>(
                 ctx,
                 del_addr.clone(),
                 val_addr.clone(),
-            );
+            )?;
         }
+        Ok(())
     }
 
     pub fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -73,9 +78,49 @@ impl<
         ctx: &mut CTX,
         del_addr: &AccAddress,
         val_addr: &ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
         if let Some(ref hooks) = self.hooks_keeper {
-            hooks.after_delegation_modified(ctx, del_addr.clone(), val_addr.clone());
+            hooks.after_delegation_modified(ctx, del_addr.clone(), val_addr.clone())?;
         }
+        Ok(())
+    }
+
+    pub fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &Validator,
+    ) -> Result<(), StakingHooksError> {
+        if let Some(ref hooks) = self.hooks_keeper {
+            hooks.after_validator_removed(
+                ctx,
+                validator.cons_addr(),
+                validator.operator_address.clone(),
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: &AccAddress,
+        val_addr: &ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        if let Some(ref hooks) = self.hooks_keeper {
+            hooks.before_delegation_removed(ctx, del_addr.clone(), val_addr.clone())?;
+        }
+        Ok(())
+    }
+
+    pub fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &Validator,
+        fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        if let Some(ref hooks) = self.hooks_keeper {
+            hooks.before_validator_slashed(ctx, validator.operator_address.clone(), fraction)?;
+        }
+        Ok(())
     }
 }
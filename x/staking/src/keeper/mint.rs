@@ -0,0 +1,27 @@
+use gears::{types::denom::Denom, x::keepers::mint::MintingStakingKeeper};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > MintingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    fn staking_denom<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Denom, GasStoreErrors> {
+        Ok(self.staking_params_keeper.try_get(ctx)?.bond_denom)
+    }
+
+    fn total_bonded_tokens<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        Ok(Decimal256::new(self.pool(ctx)?.bonded_tokens))
+    }
+}
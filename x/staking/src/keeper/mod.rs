@@ -29,6 +29,7 @@ use gears::{
         uint::Uint256,
     },
     x::{
+        errors::StakingHooksError,
         keepers::{
             auth::AuthKeeper,
             staking::{KeeperHooks, StakingBankKeeper},
@@ -44,12 +45,17 @@ use std::{cmp::Ordering, collections::HashMap};
 
 mod bonded;
 mod delegation;
+mod distribution;
 mod gov;
 mod historical_info;
 mod hooks;
+mod mint;
 mod mock_hook_keeper;
+mod multi_hooks;
 mod query;
 mod redelegation;
+mod slash;
+mod slashing;
 mod tx;
 mod unbonded;
 mod unbonding;
@@ -57,6 +63,7 @@ mod validator;
 mod validators_and_total_power;
 
 pub use mock_hook_keeper::*;
+pub use multi_hooks::*;
 
 #[derive(Debug, Clone)]
 pub struct Keeper<
@@ -133,7 +140,7 @@ impl<
                 .unwrap_gas();
 
             if !genesis.exported {
-                self.after_validator_created(ctx, &validator);
+                self.after_validator_created(ctx, &validator)?;
             }
 
             if validator.status == BondStatus::Unbonding {
@@ -161,7 +168,7 @@ impl<
                     ctx,
                     &delegation.delegator_address,
                     &delegation.validator_address,
-                );
+                )?;
             }
 
             self.set_delegation(ctx, &delegation).unwrap_gas();
@@ -171,7 +178,7 @@ impl<
                     ctx,
                     &delegation.delegator_address,
                     &delegation.validator_address,
-                );
+                )?;
             }
         }
 
@@ -0,0 +1,152 @@
+use gears::{
+    context::TransactionalContext,
+    store::{database::Database, StoreKey},
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        decimal256::Decimal256,
+    },
+    x::{
+        errors::StakingHooksError,
+        keepers::{auth::AuthKeeper, staking::KeeperHooks},
+        module::Module,
+    },
+};
+
+/// Combines two implementors of KeeperHooks trait. Every hook is invoked on the first
+/// implementor and then on the second one. The second one isn't invoked if the first
+/// one fails. More hooks can be combined by nesting, e.g.
+/// `MultiHooks<A, MultiHooks<B, C>>`.
+#[derive(Debug, Clone, Default)]
+pub struct MultiHooks<H1, H2> {
+    first: H1,
+    second: H2,
+}
+
+impl<H1, H2> MultiHooks<H1, H2> {
+    pub fn new(first: H1, second: H2) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<
+        SK: StoreKey,
+        AK: AuthKeeper<SK, M>,
+        M: Module,
+        H1: KeeperHooks<SK, AK, M>,
+        H2: KeeperHooks<SK, AK, M>,
+    > KeeperHooks<SK, AK, M> for MultiHooks<H1, H2>
+{
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first.after_validator_created(ctx, val_addr.clone())?;
+        self.second.after_validator_created(ctx, val_addr)
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .before_validator_modified(ctx, val_addr.clone())?;
+        self.second.before_validator_modified(ctx, val_addr)
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .after_validator_removed(ctx, cons_addr.clone(), val_addr.clone())?;
+        self.second
+            .after_validator_removed(ctx, cons_addr, val_addr)
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .after_validator_bonded(ctx, cons_addr.clone(), val_addr.clone())?;
+        self.second.after_validator_bonded(ctx, cons_addr, val_addr)
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .after_validator_begin_unbonding(ctx, cons_addr.clone(), val_addr.clone())?;
+        self.second
+            .after_validator_begin_unbonding(ctx, cons_addr, val_addr)
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .before_delegation_created(ctx, del_addr.clone(), val_addr.clone())?;
+        self.second
+            .before_delegation_created(ctx, del_addr, val_addr)
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .before_delegation_shares_modified(ctx, del_addr.clone(), val_addr.clone())?;
+        self.second
+            .before_delegation_shares_modified(ctx, del_addr, val_addr)
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .before_delegation_removed(ctx, del_addr.clone(), val_addr.clone())?;
+        self.second
+            .before_delegation_removed(ctx, del_addr, val_addr)
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .after_delegation_modified(ctx, del_addr.clone(), val_addr.clone())?;
+        self.second
+            .after_delegation_modified(ctx, del_addr, val_addr)
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        self.first
+            .before_validator_slashed(ctx, val_addr.clone(), fraction)?;
+        self.second
+            .before_validator_slashed(ctx, val_addr, fraction)
+    }
+}
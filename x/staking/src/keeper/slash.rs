@@ -0,0 +1,459 @@
+use super::*;
+use crate::types::keys::{redelegation_key, redelegations_from_val_src_index_key};
+use gears::{
+    core::Protobuf, extensions::corruption::UnwrapCorrupt,
+    tendermint::types::proto::validator::VotingPower, types::address::ConsAddress,
+};
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, KH, M>
+{
+    /// slash a validator for an infraction committed at a known height
+    /// Find the contributing stake at that height and burn the specified slash_factor
+    /// of it, updating unbonding delegations & redelegations appropriately
+    ///
+    /// CONTRACT:
+    ///    slash_factor is non-negative
+    /// CONTRACT:
+    ///    Infraction was committed equal to or less than an unbonding period in the past,
+    ///    so all unbonding delegations and redelegations from that height are stored
+    /// CONTRACT:
+    ///    Slash will not slash unbonded validators (for the above reason)
+    /// CONTRACT:
+    ///    Infraction was committed at the current height or at a past height,
+    ///    not at a height in the future
+    pub fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+        infraction_height: u32,
+        power: VotingPower,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        // amount of slashing = slash slash_factor * power at time of infraction
+        let amount = Uint256::from(power.power())
+            .checked_mul(Uint256::from(self.power_reduction(ctx)))
+            .expect("voting power is limited and cannot overflow");
+        let slash_amount = mul_truncate(amount, slash_factor);
+
+        let Some(mut validator) = self.validator_by_cons_addr(ctx, cons_addr)? else {
+            // If not found, the validator must have been overslashed and removed - so we don't need to do anything
+            // NOTE:  Correctness dependent on invariant that unbonding delegations / redelegations must also have been completely
+            //        slashed in this case - which we don't explicitly check, but should be true.
+            // Log the slash attempt for future reference (maybe we should tag it too)
+            tracing::info!(
+                "WARNING: ignored attempt to slash a nonexistent validator; we recommend you investigate immediately, validator: {cons_addr}",
+            );
+            return Ok(Uint256::zero());
+        };
+
+        // should not be slashing an unbonded validator
+        assert!(
+            validator.status != BondStatus::Unbonded,
+            "should not be slashing unbonded validator: {}",
+            validator.operator_address
+        );
+
+        let operator_address = validator.operator_address.clone();
+
+        // call the before-modification hook
+        log_hook_error(
+            "before validator modified",
+            &operator_address,
+            self.before_validator_modified(ctx, &validator),
+        )?;
+
+        // Track remaining slash amount for the validator
+        // This will decrease when we slash unbondings and
+        // redelegations, as that stake has since unbonded
+        let mut remaining_slash_amount = slash_amount;
+
+        let height = ctx.height();
+        match infraction_height.cmp(&height) {
+            Ordering::Greater => {
+                // Can't slash infractions in the future
+                panic!(
+                    "impossible attempt to slash future infraction at height {infraction_height} but we are at height {height}"
+                );
+            }
+            Ordering::Equal => {
+                // Special-case slash at current height for efficiency - we don't need to
+                // look through unbonding delegations or redelegations.
+                tracing::info!(
+                    "slashing at current height {height}, not scanning unbonding delegations & redelegations"
+                );
+            }
+            Ordering::Less => {
+                // Iterate through unbonding delegations from slashed validator
+                for unbonding_delegation in
+                    self.unbonding_delegations_from_validator(ctx, &operator_address)?
+                {
+                    let amount_slashed = self.slash_unbonding_delegation(
+                        ctx,
+                        unbonding_delegation,
+                        infraction_height,
+                        slash_factor,
+                    )?;
+                    remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+                }
+
+                // Iterate through redelegations from slashed source validator
+                for redelegation in self.redelegations_from_src_validator(ctx, &operator_address)? {
+                    let amount_slashed = self.slash_redelegation(
+                        ctx,
+                        &validator,
+                        redelegation,
+                        infraction_height,
+                        slash_factor,
+                    )?;
+                    remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+                }
+
+                // redelegation slashing may change the validator state
+                validator = self
+                    .validator(ctx, &operator_address)?
+                    .expect("slashed validator must exist");
+            }
+        }
+
+        // cannot decrease balance below zero
+        let tokens_to_burn = std::cmp::min(remaining_slash_amount, validator.tokens);
+        if tokens_to_burn.is_zero() {
+            return Ok(Uint256::zero());
+        }
+
+        // we need to calculate the *effective* slash fraction for distribution
+        let effective_fraction = Decimal256::checked_from_ratio(tokens_to_burn, validator.tokens)
+            .expect("validator tokens are not zero");
+        // possible if power has changed
+        let effective_fraction = std::cmp::min(effective_fraction, Decimal256::one());
+        // call the before-slashed hook
+        log_hook_error(
+            "before validator slashed",
+            &operator_address,
+            self.before_validator_slashed(ctx, &validator, effective_fraction),
+        )?;
+
+        // Deduct from validator's bonded tokens and update the validator.
+        // Burn the slashed tokens from the pool account and decrease the total supply.
+        self.remove_validator_tokens(ctx, &mut validator, tokens_to_burn)?;
+
+        match validator.status {
+            BondStatus::Bonded => self.burn_bonded_tokens(ctx, tokens_to_burn)?,
+            BondStatus::Unbonding | BondStatus::Unbonded => {
+                self.burn_not_bonded_tokens(ctx, tokens_to_burn)?
+            }
+            BondStatus::Unspecified => panic!("invalid validator status"),
+        }
+
+        tracing::info!(
+            "validator slashed by slash factor, validator: {operator_address}, slash_factor: {slash_factor}, burned: {tokens_to_burn}"
+        );
+
+        Ok(tokens_to_burn)
+    }
+
+    /// jail a validator by consensus address
+    pub fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = self
+            .validator_by_cons_addr(ctx, cons_addr)?
+            .unwrap_or_else(|| panic!("validator with consensus-address {cons_addr} not found"));
+        self.jail_validator(ctx, &mut validator)?;
+        tracing::info!("validator jailed, validator: {cons_addr}");
+        Ok(())
+    }
+
+    /// unjail a validator by consensus address
+    pub fn unjail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = self
+            .validator_by_cons_addr(ctx, cons_addr)?
+            .unwrap_or_else(|| panic!("validator with consensus-address {cons_addr} not found"));
+        assert!(
+            validator.jailed,
+            "cannot unjail already unjailed validator, validator: {}",
+            validator.operator_address
+        );
+        validator.jailed = false;
+        self.set_validator(ctx, &validator)?;
+        self.set_validator_by_power_index(ctx, &validator)?;
+        tracing::info!("validator un-jailed, validator: {cons_addr}");
+        Ok(())
+    }
+
+    /// slash an unbonding delegation and update the pool
+    /// the unbonding delegation had enough stake to slash
+    /// (the amount actually slashed may be less if there's
+    /// insufficient stake remaining)
+    fn slash_unbonding_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        mut unbonding_delegation: UnbondingDelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the unbonding delegation
+        for entry in unbonding_delegation.entries.iter_mut() {
+            // If unbonding started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Unbonding delegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            let slash_amount = mul_truncate(entry.initial_balance, slash_factor);
+            total_slash_amount += slash_amount;
+
+            // Don't slash more tokens than held
+            // Possible since the unbonding delegation may already
+            // have been slashed, and slash amounts are calculated
+            // according to stake held at time of infraction
+            let unbonding_slash_amount = std::cmp::min(slash_amount, entry.balance);
+
+            // Update unbonding delegation if necessary
+            if unbonding_slash_amount.is_zero() {
+                continue;
+            }
+
+            burned_amount += unbonding_slash_amount;
+            entry.balance -= unbonding_slash_amount;
+        }
+
+        self.set_unbonding_delegation(ctx, &unbonding_delegation)?;
+        self.burn_not_bonded_tokens(ctx, burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// slash a redelegation and update the pool
+    /// the redelegation had enough stake to slash
+    /// (the amount actually slashed may be less if there's
+    /// insufficient stake remaining)
+    /// NOTE this is only slashing for prior infractions from the source validator
+    fn slash_redelegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        src_validator: &Validator,
+        redelegation: Redelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut bonded_burned_amount = Uint256::zero();
+        let mut not_bonded_burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the redelegation
+        for entry in redelegation.entries.iter() {
+            // If redelegation started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Redelegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            let slash_amount = mul_truncate(entry.initial_balance, slash_factor);
+            total_slash_amount += slash_amount;
+
+            // Unbond from target validator
+            let shares_to_unbond = entry
+                .share_dst
+                .checked_mul(slash_factor)
+                .expect("shares are limited by the total supply");
+            if shares_to_unbond.is_zero() {
+                continue;
+            }
+
+            let Some(delegation) = self.delegation(
+                ctx,
+                &redelegation.delegator_address,
+                &redelegation.validator_dst_address,
+            )?
+            else {
+                // If deleted, delegation has zero shares, and we can't unbond any more
+                continue;
+            };
+
+            let shares_to_unbond = std::cmp::min(shares_to_unbond, delegation.shares);
+
+            let tokens_to_burn = self
+                .unbond(
+                    ctx,
+                    &redelegation.delegator_address,
+                    &redelegation.validator_dst_address,
+                    shares_to_unbond,
+                )
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "failed to unbond redelegation from validator {}: {e}",
+                        src_validator.operator_address
+                    )
+                });
+
+            let dst_validator = self
+                .validator(ctx, &redelegation.validator_dst_address)?
+                .expect("destination validator of redelegation must exist");
+
+            // tokens of a redelegation currently live in the destination validator
+            // therefor we must burn tokens from the destination-validator's bonding status
+            match dst_validator.status {
+                BondStatus::Bonded => bonded_burned_amount += tokens_to_burn,
+                BondStatus::Unbonding | BondStatus::Unbonded => {
+                    not_bonded_burned_amount += tokens_to_burn
+                }
+                BondStatus::Unspecified => panic!("unknown validator status"),
+            }
+        }
+
+        self.burn_bonded_tokens(ctx, bonded_burned_amount)?;
+        self.burn_not_bonded_tokens(ctx, not_bonded_burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// redelegations_from_src_validator returns all redelegations from a particular validator.
+    pub fn redelegations_from_src_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_addr: &ValAddress,
+    ) -> Result<Vec<Redelegation>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        let index_store = store.prefix_store(redelegations_from_val_src_index_key(val_addr));
+
+        let mut keys = vec![];
+        for r in index_store.into_range(..) {
+            let (k, _v) = r?;
+            // key is of the form del_addr.len() (1 byte) || del_addr || val_dst_addr.len() (1 byte) || val_dst_addr
+            let del_addr_len = k[0] as usize;
+            let del_addr: AccAddress = k[1..1 + del_addr_len]
+                .to_vec()
+                .try_into()
+                .unwrap_or_corrupt();
+            let val_dst_addr: ValAddress = k[2 + del_addr_len..]
+                .to_vec()
+                .try_into()
+                .unwrap_or_corrupt();
+            keys.push(redelegation_key(&del_addr, val_addr, &val_dst_addr));
+        }
+
+        let store = ctx.kv_store(&self.store_key);
+        let mut redelegations = vec![];
+        for key in keys {
+            let value = store
+                .get(&key)?
+                .expect("Expected corresponding key-value pair");
+            redelegations.push(Redelegation::decode_vec(&value).unwrap_or_corrupt());
+        }
+
+        Ok(redelegations)
+    }
+
+    /// remove_validator_tokens updates the tokens of an existing validator, updates the validators power index key
+    fn remove_validator_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &mut Validator,
+        tokens_to_remove: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.delete_validator_by_power_index(ctx, validator)?;
+        validator.tokens = validator.tokens.saturating_sub(tokens_to_remove);
+        self.set_validator(ctx, validator)?;
+        self.set_validator_by_power_index(ctx, validator)?;
+        Ok(())
+    }
+
+    /// burn_bonded_tokens removes coins from the bonded pool module account
+    fn burn_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_pool_tokens(ctx, &self.bonded_module, amount)
+    }
+
+    /// burn_not_bonded_tokens removes coins from the not bonded pool module account
+    fn burn_not_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_pool_tokens(ctx, &self.not_bonded_module, amount)
+    }
+
+    fn burn_pool_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        module: &M,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let params = self.staking_params_keeper.try_get(ctx)?;
+        // original routine is infallible, it means that the amount should be a valid number.
+        // All errors in sdk panics in this method
+        let coins = UnsignedCoins::new(vec![UnsignedCoin {
+            denom: params.bond_denom().clone(),
+            amount,
+        }])
+        .expect("shouldn't fail");
+
+        self.bank_keeper
+            .coins_burn(ctx, module, &coins)
+            .expect("pool accounts hold the slashed tokens");
+
+        Ok(())
+    }
+}
+
+/// Slashing happens outside of transactions, so there is nothing to abort. Like in cosmos sdk
+/// a failing hook is only logged, otherwise a single broken hook would halt the chain.
+fn log_hook_error(
+    hook: &str,
+    validator: &ValAddress,
+    res: Result<(), StakingHooksError>,
+) -> Result<(), GasStoreErrors> {
+    match res {
+        Ok(()) => Ok(()),
+        Err(StakingHooksError::GasError(e)) => Err(e),
+        Err(StakingHooksError::Other(e)) => {
+            tracing::error!("error in {hook} hook, validator: {validator}: {e}");
+            Ok(())
+        }
+    }
+}
+
+/// Multiplies the amount by the fraction truncating the result
+fn mul_truncate(amount: Uint256, fraction: Decimal256) -> Uint256 {
+    Decimal256::from_atomics(amount, 0)
+        .expect("amount is limited by the total supply")
+        .checked_mul(fraction)
+        .expect("fraction is not greater than one")
+        .to_uint_floor()
+}
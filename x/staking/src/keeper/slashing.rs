@@ -0,0 +1,94 @@
+use gears::{
+    core::Protobuf, extensions::corruption::UnwrapCorrupt,
+    tendermint::types::proto::validator::VotingPower, types::address::ConsAddress,
+    x::keepers::staking::SlashingStakingKeeper,
+};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > SlashingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    type Validator = Validator;
+    type Delegation = Delegation;
+
+    fn validators_iter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<impl Iterator<Item = Result<Validator, GasStoreErrors>>, GasStoreErrors> {
+        let validators = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(VALIDATORS_KEY)
+            .into_range(..)
+            .map(|this| this.map(|(_, value)| Validator::decode_vec(&value).unwrap_or_corrupt()))
+            .collect::<Vec<_>>();
+
+        Ok(validators.into_iter())
+    }
+
+    fn validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ValAddress,
+    ) -> Result<Option<Validator>, GasStoreErrors> {
+        Keeper::validator(self, ctx, addr)
+    }
+
+    fn validator_by_cons_addr<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<Validator>, GasStoreErrors> {
+        Keeper::validator_by_cons_addr(self, ctx, addr)
+    }
+
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        height: u32,
+        power: VotingPower,
+        slash_fraction_downtime: Decimal256,
+    ) -> Result<(), GasStoreErrors> {
+        Keeper::slash(self, ctx, addr, height, power, slash_fraction_downtime)?;
+        Ok(())
+    }
+
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        Keeper::jail(self, ctx, addr)
+    }
+
+    fn unjail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        Keeper::unjail(self, ctx, addr)
+    }
+
+    fn delegation<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<Option<Delegation>, GasStoreErrors> {
+        Keeper::delegation(self, ctx, delegator_address, validator_address)
+    }
+
+    fn max_validators<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<u32, GasStoreErrors> {
+        Ok(self.staking_params_keeper.try_get(ctx)?.max_validators())
+    }
+}
@@ -72,7 +72,7 @@ impl<
         self.set_new_validator_by_power_index(ctx, &validator)?;
 
         // call the after-creation hook
-        self.after_validator_created(ctx, &validator);
+        self.after_validator_created(ctx, &validator)?;
 
         // move coins from the msg.address account to a (self-delegation) delegator account
         // the validator account and global shares are updated within here
@@ -149,7 +149,7 @@ impl<
                 .create_updated_validator_commission(ctx, &validator, rate)
                 .map_err(|e| anyhow::anyhow!(e.to_string()))?;
             // call the before-modification hook since we're about to update the commission
-            self.before_validator_modified(ctx, &validator);
+            self.before_validator_modified(ctx, &validator)?;
             validator.commission = commission;
         }
 
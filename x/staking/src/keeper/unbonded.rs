@@ -26,7 +26,7 @@ impl<
         };
 
         // call the before-delegation-modified hook
-        self.before_delegation_shares_modified(ctx, del_addr, val_addr);
+        self.before_delegation_shares_modified(ctx, del_addr, val_addr)?;
 
         // ensure that we have enough shares to remove
         if delegation.shares < shares {
@@ -67,7 +67,7 @@ impl<
         } else {
             self.set_delegation(ctx, &delegation)?;
             // call the after delegation modification hook
-            self.after_delegation_modified(ctx, del_addr, &delegation.validator_address);
+            self.after_delegation_modified(ctx, del_addr, &delegation.validator_address)?;
         }
 
         // remove the shares and coins from the validator
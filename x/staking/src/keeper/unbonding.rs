@@ -238,7 +238,7 @@ impl<
                 ctx,
                 validator.cons_addr(),
                 validator.operator_address.clone(),
-            );
+            )?;
         }
         Ok(())
     }
@@ -292,7 +292,16 @@ impl<
 
                     self.unbonding_to_unbonded(ctx, &mut validator).unwrap_gas();
                     if validator.delegator_shares.is_zero() {
-                        self.remove_validator(ctx, &validator)?;
+                        // the validator is already removed from store when the hooks are
+                        // called, so a hook error is only logged and doesn't halt the chain
+                        match self.remove_validator(ctx, &validator) {
+                            Ok(_) => (),
+                            Err(StakingHooksError::GasError(e)) => return Err(e),
+                            Err(StakingHooksError::Other(e)) => tracing::error!(
+                                "error in after validator removed hook, validator: {}: {e}",
+                                validator.operator_address
+                            ),
+                        }
                     }
                 }
 
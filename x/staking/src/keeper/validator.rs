@@ -36,14 +36,27 @@ impl<
         )
     }
 
+    /// remove_validator removes the validator record and associated indexes
+    /// except for the bonded validator index which is only handled in ApplyAndReturnTendermintUpdates
     pub fn remove_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         validator: &Validator,
-    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+    ) -> Result<Option<Vec<u8>>, StakingHooksError> {
         let store = ctx.kv_store_mut(&self.store_key);
         let mut validators_store = store.prefix_store_mut(VALIDATORS_KEY);
-        validators_store.delete(validator.operator_address.to_string().as_bytes())
+        let deleted = validators_store.delete(&validator.operator_address.prefix_len_bytes())?;
+
+        let store = ctx.kv_store_mut(&self.store_key);
+        let mut validators_store = store.prefix_store_mut(VALIDATORS_BY_CONS_ADDR_KEY);
+        validators_store.delete(&validator.cons_addr().prefix_len_bytes())?;
+
+        self.delete_validator_by_power_index(ctx, validator)?;
+
+        // call hooks
+        self.after_validator_removed(ctx, validator)?;
+
+        Ok(deleted)
     }
 
     pub fn jail_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -1,5 +1,12 @@
 use crate::{CreateValidator, DelegateMsg, EditValidator, RedelegateMsg, UndelegateMsg};
-use gears::derive::AppMessage;
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
@@ -22,3 +29,15 @@ pub enum Message {
     #[msg(url(path = UndelegateMsg::TYPE_URL))]
     Undelegate(UndelegateMsg),
 }
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::CreateValidator(msg) => msg.format(get_metadata),
+            Message::EditValidator(msg) => msg.format(get_metadata),
+            Message::Delegate(msg) => msg.format(get_metadata),
+            Message::Redelegate(msg) => msg.format(get_metadata),
+            Message::Undelegate(msg) => msg.format(get_metadata),
+        }
+    }
+}
@@ -2,7 +2,10 @@ use anyhow::anyhow;
 use gears::{
     application::keepers::params::ParamsKeeper,
     extensions::corruption::UnwrapCorrupt,
-    params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    params::{
+        parsed::Params, ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey,
+        ParamsValidationError,
+    },
     tendermint::types::time::duration::Duration,
     types::denom::Denom,
 };
@@ -201,6 +204,39 @@ impl ParamsDeserialize for StakingParams {
             historical_entries,
         }
     }
+
+    fn validate_raw(key: &str, value: &[u8]) -> Result<(), ParamsValidationError> {
+        let invalid = |reason: &str| ParamsValidationError::invalid_value(key, reason);
+        // entries are stored as plain numbers without quotes
+        let entries = std::str::from_utf8(value)
+            .ok()
+            .and_then(|this| this.parse::<u32>().ok());
+
+        match key {
+            KEY_UNBONDING_TIME => match ParamKind::I64
+                .try_parse_param(value.to_vec())
+                .and_then(Params::signed_64)
+            {
+                Some(time) if time >= 0 => Ok(()),
+                Some(_) => Err(invalid("unbonding time must be non negative")),
+                None => Err(invalid("expected i64")),
+            },
+            KEY_MAX_VALIDATORS | KEY_MAX_ENTRIES => match entries {
+                Some(0) => Err(invalid("must be positive")),
+                Some(_) => Ok(()),
+                None => Err(invalid("expected u32")),
+            },
+            KEY_HISTORICAL_ENTRIES => entries.map(|_| ()).ok_or(invalid("expected u32")),
+            KEY_BOND_DENOM => std::str::from_utf8(value)
+                .ok()
+                .and_then(|this| this.strip_prefix('\"'))
+                .and_then(|this| this.strip_suffix('\"'))
+                .filter(|this| Denom::try_from(*this).is_ok())
+                .map(|_| ())
+                .ok_or(invalid("expected quoted denom")),
+            _ => Err(ParamsValidationError::UnknownKey(key.to_owned())),
+        }
+    }
 }
 
 impl StakingParams {
@@ -272,31 +308,4 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for StakingParamsKeeper<PSK> {
     fn psk(&self) -> &PSK {
         &self.params_subspace_key
     }
-
-    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
-        match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_UNBONDING_TIME => ParamKind::I64
-                .parse_param(value.as_ref().to_vec())
-                .signed_64()
-                .is_some(),
-            KEY_MAX_VALIDATORS => ParamKind::U32
-                .parse_param(value.as_ref().to_vec())
-                .signed_64()
-                .is_some(),
-            KEY_MAX_ENTRIES => ParamKind::U32
-                .parse_param(value.as_ref().to_vec())
-                .signed_64()
-                .is_some(),
-            KEY_HISTORICAL_ENTRIES => ParamKind::U32
-                .parse_param(value.as_ref().to_vec())
-                .signed_64()
-                .is_some(),
-            KEY_BOND_DENOM => ParamKind::String
-                .parse_param(value.as_ref().to_vec())
-                .string()
-                .is_some(),
-
-            _ => false,
-        }
-    }
 }
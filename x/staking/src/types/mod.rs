@@ -5,6 +5,7 @@ pub mod iter;
 pub(crate) mod keys;
 mod pool;
 mod query;
+mod rendering;
 mod tx;
 mod validator;
 
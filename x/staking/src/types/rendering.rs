@@ -0,0 +1,391 @@
+//! Textual sign mode rendering of staking messages as specified in ADR-050
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            nested_screens, DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Indent, Screen},
+};
+
+use super::{
+    CommissionRates, CreateValidator, DelegateMsg, Description, EditValidator, RedelegateMsg,
+    UndelegateMsg,
+};
+
+impl ValueRenderer for Description {
+    /// Format `Description` as a nested object. Empty fields are omitted.
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![Screen {
+            title: String::new(),
+            content: DefaultPrimitiveRenderer::try_format("Description object")?,
+            indent: None,
+            expert: false,
+        }];
+
+        for (title, value) in [
+            ("Moniker", &self.moniker),
+            ("Identity", &self.identity),
+            ("Website", &self.website),
+            ("Security contact", &self.security_contact),
+            ("Details", &self.details),
+        ] {
+            if value.is_empty() {
+                continue;
+            }
+
+            screens.push(Screen {
+                title: title.to_string(),
+                content: DefaultPrimitiveRenderer::try_format(value.as_str())?,
+                indent: Some(Indent::one()),
+                expert: false,
+            });
+        }
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for CommissionRates {
+    /// Format `CommissionRates` as a nested object.
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: String::new(),
+                content: DefaultPrimitiveRenderer::try_format("CommissionRates object")?,
+                indent: None,
+                expert: false,
+            },
+            Screen {
+                title: "Rate".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.rate()),
+                indent: Some(Indent::one()),
+                expert: false,
+            },
+            Screen {
+                title: "Max rate".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.max_rate()),
+                indent: Some(Indent::one()),
+                expert: false,
+            },
+            Screen {
+                title: "Max change rate".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.max_change_rate()),
+                indent: Some(Indent::one()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for CreateValidator {
+    /// Format `CreateValidator`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = nested_screens(
+            "Description",
+            ValueRenderer::format(&self.description, get_metadata)?,
+            2,
+        )?;
+        screens.append(&mut nested_screens(
+            "Commission",
+            ValueRenderer::format(&self.commission, get_metadata)?,
+            2,
+        )?);
+        screens.append(&mut vec![
+            Screen {
+                title: "Min self delegation".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.min_self_delegation),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ]);
+        screens.append(&mut nested_screens(
+            "Pubkey",
+            ValueRenderer::format(
+                &gears::crypto::public::PublicKey::from(self.pubkey.clone()),
+                get_metadata,
+            )?,
+            2,
+        )?);
+        screens.push(Screen {
+            title: "Value".to_string(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                self.value.clone(),
+                get_metadata,
+            )?,
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for EditValidator {
+    /// Format `EditValidator`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        // fields which are not modified are sent as `[do-not-modify]` so they should be shown
+        let description = Description::from(self.description.clone());
+
+        let mut screens = nested_screens(
+            "Description",
+            ValueRenderer::format(&description, get_metadata)?,
+            2,
+        )?;
+        screens.push(Screen {
+            title: "Validator address".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        if let Some(commission_rate) = self.commission_rate {
+            screens.push(Screen {
+                title: "Commission rate".to_string(),
+                content: DefaultPrimitiveRenderer::format(commission_rate),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+        if let Some(min_self_delegation) = self.min_self_delegation {
+            screens.push(Screen {
+                title: "Min self delegation".to_string(),
+                content: DefaultPrimitiveRenderer::format(min_self_delegation),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for DelegateMsg {
+    /// Format `DelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for RedelegateMsg {
+    /// Format `RedelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator src address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.src_validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator dst address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.dst_validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for UndelegateMsg {
+    /// Format `UndelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        signing::{handler::NoneMetadataGetter, renderer::value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use crate::{CreateValidator, DelegateMsg, RedelegateMsg};
+
+    #[test]
+    fn delegate_works() -> anyhow::Result<()> {
+        let msg: DelegateMsg = serde_json::from_str(
+            r#"{
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "amount": { "denom": "uatom", "amount": "10000000" }
+        }"#,
+        )?;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Amount", "content": "10'000'000 uatom", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn redelegate_works() -> anyhow::Result<()> {
+        let msg: RedelegateMsg = serde_json::from_str(
+            r#"{
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "src_validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "dst_validator_address": "cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk",
+            "amount": { "denom": "uatom", "amount": "1" }
+        }"#,
+        )?;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Validator src address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Validator dst address", "content": "cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk", "indent": 2 },
+            { "title": "Amount", "content": "1 uatom", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_validator_works() -> anyhow::Result<()> {
+        let msg: CreateValidator = serde_json::from_str(
+            r#"{
+            "@type": "/cosmos.staking.v1beta1.MsgCreateValidator",
+            "description": {
+                "moniker": "test",
+                "identity": "",
+                "website": "",
+                "security_contact": "",
+                "details": "first validator"
+            },
+            "commission": {
+                "rate": "0.1",
+                "max_rate": "0.2",
+                "max_change_rate": "0.01"
+            },
+            "min_self_delegation": "1",
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "pubkey": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "+uo5x4+nFiCBt2MuhVwT5XeMfj6ttkjY/JC6WyHb+rE="
+            },
+            "value": { "denom": "uatom", "amount": "100" }
+        }"#,
+        )?;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[
+            { "title": "Description", "content": "Description object", "indent": 2 },
+            { "title": "Moniker", "content": "test", "indent": 3 },
+            { "title": "Details", "content": "first validator", "indent": 3 },
+            { "title": "Commission", "content": "CommissionRates object", "indent": 2 },
+            { "title": "Rate", "content": "0.1", "indent": 3 },
+            { "title": "Max rate", "content": "0.2", "indent": 3 },
+            { "title": "Max change rate", "content": "0.01", "indent": 3 },
+            { "title": "Min self delegation", "content": "1", "indent": 2 },
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Pubkey", "content": "/cosmos.crypto.ed25519.PubKey", "indent": 2, "expert": true },
+            { "title": "Key", "content": "FAEA 39C7 8FA7 1620 81B7 632E 855C 13E5 778C 7E3E ADB6 48D8 FC90 BA5B 21DB FAB1", "indent": 3, "expert": true },
+            { "title": "Value", "content": "100 uatom", "indent": 2 }
+        ]"#,
+        )?;
+
+        let actual_screens = ValueRenderer::format(&msg, &NoneMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
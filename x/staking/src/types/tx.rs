@@ -2,7 +2,6 @@ use crate::consts::proto::*;
 use gears::{
     core::{errors::CoreError, Protobuf},
     derive::{AppMessage, Protobuf},
-    signing::renderer::value_renderer::ValueRenderer,
     tendermint::types::{proto::crypto::PublicKey, time::timestamp::Timestamp},
     types::{
         address::{AccAddress, ValAddress},
@@ -452,7 +451,10 @@ impl TryFrom<inner::MsgCreateValidator> for CreateValidator {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
 #[serde(tag = "@type")]
 #[serde(rename = "/cosmos.staking.v1beta1.MsgCreateValidator")]
-#[msg(url = "/cosmos.staking.v1beta1.MsgCreateValidator")]
+#[msg(
+    url = "/cosmos.staking.v1beta1.MsgCreateValidator",
+    amino_url = "cosmos-sdk/MsgCreateValidator"
+)]
 pub struct CreateValidator {
     pub description: Description,
     pub commission: CommissionRates,
@@ -466,20 +468,11 @@ pub struct CreateValidator {
 
 impl Protobuf<inner::MsgCreateValidator> for CreateValidator {}
 
-impl ValueRenderer for CreateValidator {
-    fn format<MG: gears::signing::handler::MetadataGetter>(
-        &self,
-        _get_metadata: &MG,
-    ) -> Result<
-        Vec<gears::types::rendering::screen::Screen>,
-        gears::signing::renderer::value_renderer::RenderError,
-    > {
-        Err(gears::signing::renderer::value_renderer::RenderError::NotImplemented)
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.staking.v1beta1.MsgEditValidator")]
+#[msg(
+    url = "/cosmos.staking.v1beta1.MsgEditValidator",
+    amino_url = "cosmos-sdk/MsgEditValidator"
+)]
 pub struct EditValidator {
     pub description: EditDescription,
     pub commission_rate: Option<Decimal256>, // TODO: add a CommissionRate type to capture the =< 1 constraint currently this is checked here https://github.com/rumos-io/gears/blob/672d6cf7e4376076c218b46121e197ac1f1029a7/x/staking/src/keeper/validator.rs#L67
@@ -657,7 +650,10 @@ impl From<RedelegateMsg> for RedelegateMsgRaw {
 
 /// Creates a new RedelegateMsg transaction message instance.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.staking.v1beta1.MsgBeginRedelegate")]
+#[msg(
+    url = "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+    amino_url = "cosmos-sdk/MsgBeginRedelegate"
+)]
 pub struct RedelegateMsg {
     #[msg(signer)]
     pub delegator_address: AccAddress,
@@ -710,7 +706,10 @@ impl From<UndelegateMsg> for UndelegateMsgRaw {
 
 /// Creates a new UndelegateMsg transaction message instance.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
-#[msg(url = "/cosmos.staking.v1beta1.MsgUndelegate")]
+#[msg(
+    url = "/cosmos.staking.v1beta1.MsgUndelegate",
+    amino_url = "cosmos-sdk/MsgUndelegate"
+)]
 pub struct UndelegateMsg {
     #[msg(signer)]
     pub delegator_address: AccAddress,
@@ -0,0 +1,267 @@
+use std::sync::{Arc, Mutex};
+
+use gears::{
+    context::TransactionalContext,
+    core::Protobuf,
+    extensions::testing::UnwrapTesting,
+    store::{database::Database, StoreKey},
+    tendermint::types::{
+        request::query::RequestQuery,
+        response::{ResponseDeliverTx, ResponseQuery},
+        time::timestamp::Timestamp,
+    },
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        decimal256::Decimal256,
+    },
+    utils::node::{generate_tx, GenesisSource, StepResponse, User},
+    x::{
+        errors::StakingHooksError,
+        keepers::{auth::AuthKeeper, staking::KeeperHooks},
+        module::Module,
+    },
+};
+use staking::{DelegateMsg, Message, MultiHooks, QueryDelegationRequest, QueryDelegationResponse};
+use utils::{set_node_with_hooks, USER_0, USER_1};
+
+#[path = "./utils.rs"]
+mod utils;
+
+const GENESIS_FILE_PATH: &str = "./tests/assets/tx_edit_validator.json";
+
+/// Hooks which record their calls into shared log and fail on `fail_on` hook
+#[derive(Debug, Clone)]
+struct RecordingHooks {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+    fail_on: Option<&'static str>,
+}
+
+impl RecordingHooks {
+    fn new(name: &'static str, calls: Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            name,
+            calls,
+            fail_on: None,
+        }
+    }
+
+    fn failing(mut self, hook: &'static str) -> Self {
+        self.fail_on = Some(hook);
+        self
+    }
+
+    fn record(&self, hook: &'static str) -> Result<(), StakingHooksError> {
+        self.calls
+            .lock()
+            .expect("poisoned lock")
+            .push(format!("{}: {hook}", self.name));
+
+        match self.fail_on == Some(hook) {
+            true => Err(StakingHooksError::Other(format!("{hook} hook failed"))),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> KeeperHooks<SK, AK, M> for RecordingHooks {
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("after_validator_created")
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("before_validator_modified")
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("after_validator_removed")
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("after_validator_bonded")
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("after_validator_begin_unbonding")
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("before_delegation_created")
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("before_delegation_shares_modified")
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("before_delegation_removed")
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.record("after_delegation_modified")
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+        _fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        self.record("before_validator_slashed")
+    }
+}
+
+/// Delegates to validator from genesis and returns tx code and delegation after the tx
+fn delegate(hooks: MultiHooks<RecordingHooks, RecordingHooks>) -> (u32, QueryDelegationResponse) {
+    let mut node = set_node_with_hooks(GenesisSource::File(GENESIS_FILE_PATH.into()), Some(hooks));
+
+    let _ = node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let user_0 = User::from_bech32(USER_0, 1).unwrap_test();
+    let user_1 = User::from_bech32(USER_1, 1).unwrap_test();
+
+    let msg = Message::Delegate(DelegateMsg {
+        validator_address: user_0.address().into(),
+        amount: "1000uatom".parse().expect("hardcoded is valid"),
+        delegator_address: user_1.address(),
+    });
+
+    let txs = generate_tx(vec1::vec1![msg], 0, &user_0, node.chain_id().clone());
+
+    let StepResponse {
+        mut tx_responses, ..
+    } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
+
+    let ResponseDeliverTx { code, .. } = tx_responses.pop().unwrap_test();
+
+    let q = QueryDelegationRequest {
+        delegator_addr: user_1.address(),
+        validator_addr: user_0.address().into(),
+    };
+
+    let ResponseQuery {
+        code: query_code,
+        value,
+        log,
+        ..
+    } = node.query(RequestQuery {
+        data: q.encode_vec().into(),
+        path: QueryDelegationRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(query_code == 0, "{log}");
+
+    (
+        code,
+        QueryDelegationResponse::decode_vec(&value).unwrap_test(),
+    )
+}
+
+#[test]
+fn multi_hooks_call_every_hook_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let (
+        code,
+        QueryDelegationResponse {
+            delegation_response,
+        },
+    ) = delegate(MultiHooks::new(
+        RecordingHooks::new("first", calls.clone()),
+        RecordingHooks::new("second", calls.clone()),
+    ));
+
+    assert_eq!(code, 0);
+    assert!(delegation_response.is_some());
+
+    pretty_assertions::assert_eq!(
+        *calls.lock().unwrap_test(),
+        vec![
+            "first: before_delegation_created",
+            "second: before_delegation_created",
+            "first: after_delegation_modified",
+            "second: after_delegation_modified",
+        ]
+    );
+}
+
+#[test]
+fn multi_hooks_stop_on_first_error() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let (code, _) = delegate(MultiHooks::new(
+        RecordingHooks::new("first", calls.clone()).failing("before_delegation_created"),
+        RecordingHooks::new("second", calls.clone()),
+    ));
+
+    assert_ne!(code, 0);
+
+    pretty_assertions::assert_eq!(
+        *calls.lock().unwrap_test(),
+        vec!["first: before_delegation_created"]
+    );
+}
+
+#[test]
+fn hook_error_aborts_tx() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let (
+        code,
+        QueryDelegationResponse {
+            delegation_response,
+        },
+    ) = delegate(MultiHooks::new(
+        RecordingHooks::new("first", calls.clone()),
+        RecordingHooks::new("second", calls.clone()).failing("after_delegation_modified"),
+    ));
+
+    assert_ne!(code, 0);
+    // the delegation was stored before the failed hook, but the whole tx is reverted
+    assert!(delegation_response.is_none());
+}
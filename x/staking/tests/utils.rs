@@ -5,7 +5,7 @@ use gears::{
     store::database::MemDB,
     types::{address::AccAddress, base::coins::UnsignedCoins},
     utils::node::{init_node, GenesisSource, MockApplication, MockNode, MockOptionsFormer},
-    x::module::Module,
+    x::{keepers::staking::KeeperHooks, module::Module},
 };
 use staking::{Keeper, MockHookKeeper, StakingABCIHandler};
 
@@ -88,12 +88,26 @@ pub enum SubspaceKey {
     BaseApp,
 }
 
+pub type AuthKeeper = auth::Keeper<SpaceKey, SubspaceKey, StakingModules>;
+
 pub fn set_node(
     genesis: GenesisSource<GenesisState>,
 ) -> MockNode<BaseApp<MemDB, SubspaceKey, MockStakingAbciHandler, MockApplication>, GenesisState> {
-    let handler = MockStakingAbciHandler::new();
+    set_node_with_hooks(genesis, None)
+}
+
+/// Creates node which staking keeper calls `hooks`
+#[allow(dead_code)]
+pub fn set_node_with_hooks<
+    KH: KeeperHooks<SpaceKey, AuthKeeper, StakingModules> + std::fmt::Debug,
+>(
+    genesis: GenesisSource<GenesisState>,
+    hooks: Option<KH>,
+) -> MockNode<BaseApp<MemDB, SubspaceKey, MockStakingAbciHandler<KH>, MockApplication>, GenesisState>
+{
+    let handler = MockStakingAbciHandler::with_hooks(hooks);
 
-    let opt: MockOptionsFormer<SubspaceKey, MockStakingAbciHandler, GenesisState> =
+    let opt: MockOptionsFormer<SubspaceKey, MockStakingAbciHandler<KH>, GenesisState> =
         MockOptionsFormer::new()
             .abci_handler(handler)
             .baseapp_sbs_key(SubspaceKey::BaseApp)
@@ -123,22 +137,13 @@ impl gears::baseapp::genesis::Genesis for GenesisState {
 }
 
 #[derive(Debug, Clone)]
-pub struct MockStakingAbciHandler {
+pub struct MockStakingAbciHandler<KH = MockHookKeeper<SpaceKey, AuthKeeper, StakingModules>> {
     pub staking: StakingABCIHandler<
         SpaceKey,
         SubspaceKey,
-        auth::Keeper<SpaceKey, SubspaceKey, StakingModules>,
-        bank::Keeper<
-            SpaceKey,
-            SubspaceKey,
-            auth::Keeper<SpaceKey, SubspaceKey, StakingModules>,
-            StakingModules,
-        >,
-        MockHookKeeper<
-            SpaceKey,
-            auth::Keeper<SpaceKey, SubspaceKey, StakingModules>,
-            StakingModules,
-        >,
+        AuthKeeper,
+        bank::Keeper<SpaceKey, SubspaceKey, AuthKeeper, StakingModules>,
+        KH,
         StakingModules,
         StakingModuleInfo,
     >,
@@ -160,6 +165,12 @@ impl Default for MockStakingAbciHandler {
 
 impl MockStakingAbciHandler {
     pub fn new() -> Self {
+        Self::with_hooks(None)
+    }
+}
+
+impl<KH: KeeperHooks<SpaceKey, AuthKeeper, StakingModules>> MockStakingAbciHandler<KH> {
+    pub fn with_hooks(hooks: Option<KH>) -> Self {
         let auth_keeper = auth::Keeper::new(
             SpaceKey::Auth,
             SubspaceKey::Auth,
@@ -181,13 +192,7 @@ impl MockStakingAbciHandler {
             SubspaceKey::Staking,
             auth_keeper,
             bank_keeper,
-            Option::<
-                MockHookKeeper<
-                    SpaceKey,
-                    auth::Keeper<SpaceKey, SubspaceKey, StakingModules>,
-                    StakingModules,
-                >,
-            >::None,
+            hooks,
             StakingModules::BondedPool,
             StakingModules::NotBondedPool,
         ));
@@ -200,7 +205,9 @@ impl MockStakingAbciHandler {
     }
 }
 
-impl ABCIHandler for MockStakingAbciHandler {
+impl<KH: KeeperHooks<SpaceKey, AuthKeeper, StakingModules> + std::fmt::Debug> ABCIHandler
+    for MockStakingAbciHandler<KH>
+{
     type Message = staking::Message;
 
     type Genesis = GenesisState;
@@ -215,7 +222,7 @@ impl ABCIHandler for MockStakingAbciHandler {
         &self,
         ctx: &gears::context::query::QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
+    ) -> Result<Self::QRes, gears::baseapp::errors::QueryError> {
         self.staking.typed_query(ctx, query)
     }
 
@@ -11,19 +11,24 @@ use gears::{
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::types::request::query::RequestQuery,
-    types::tx::NullTxMsg,
+    types::address::AccAddress,
 };
 use tracing::info;
 
 use crate::{
+    errors::UpgradeTxError,
     handler::UpgradeHandler,
     keeper::{downgrade_verified, set_downgrade_verified, UpgradeKeeper},
+    message::Message,
     types::{
         query::{
-            QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryCurrentPlanRequest,
-            QueryCurrentPlanResponse, QueryModuleVersionsRequest, QueryModuleVersionsResponse,
+            QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryAuthorityRequest,
+            QueryAuthorityResponse, QueryCurrentPlanRequest, QueryCurrentPlanResponse,
+            QueryModuleVersionsRequest, QueryModuleVersionsResponse,
+            QueryUpgradedConsensusStateRequest, QueryUpgradedConsensusStateResponse,
             UpgradeQueryRequest, UpgradeQueryResponse,
         },
+        tx::{MsgCancelUpgrade, MsgSoftwareUpgrade},
         ModuleVersion,
     },
     Module,
@@ -40,7 +45,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, UH: UpgradeHandler, MI: Mo
 where
     <M as TryFrom<Vec<u8>>>::Error: Display + Debug,
 {
-    type Message = NullTxMsg;
+    type Message = Message;
 
     type Genesis = NullGenesis;
 
@@ -54,8 +59,8 @@ where
         &self,
         ctx: &gears::context::query::QueryContext<DB, Self::StoreKey>,
         query: Self::QReq,
-    ) -> Self::QRes {
-        match query {
+    ) -> Result<Self::QRes, QueryError> {
+        let res = match query {
             UpgradeQueryRequest::Plan(_) => Self::QRes::Plan(self.query_plan(ctx)),
             UpgradeQueryRequest::Applied(query) => {
                 Self::QRes::Applied(self.query_applied(ctx, query))
@@ -63,7 +68,15 @@ where
             UpgradeQueryRequest::ModuleVersions(query) => {
                 Self::QRes::ModuleVersions(self.query_module_versions(ctx, query))
             }
-        }
+            UpgradeQueryRequest::UpgradedConsensusState(query) => {
+                Self::QRes::UpgradedConsensusState(self.query_upgraded_consensus_state(ctx, query))
+            }
+            UpgradeQueryRequest::Authority(_) => Self::QRes::Authority(QueryAuthorityResponse {
+                address: self.keeper.authority().clone(),
+            }),
+        };
+
+        Ok(res)
     }
 
     fn run_ante_checks<DB: gears::store::database::Database>(
@@ -77,17 +90,36 @@ where
 
     fn msg<DB: gears::store::database::Database>(
         &self,
-        _: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
-        _: &Self::Message,
+        ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
+        msg: &Self::Message,
     ) -> Result<(), gears::application::handlers::node::TxError> {
+        match msg {
+            Message::SoftwareUpgrade(MsgSoftwareUpgrade { authority, plan }) => {
+                self.check_authority(authority)
+                    .map_err(|e| e.into::<MI>())?;
+
+                self.keeper
+                    .schedule_upgrade(ctx, plan.clone(), true)
+                    .map_err(|e| UpgradeTxError::from(e).into::<MI>())?;
+            }
+            Message::CancelUpgrade(MsgCancelUpgrade { authority }) => {
+                self.check_authority(authority)
+                    .map_err(|e| e.into::<MI>())?;
+
+                self.keeper.delete_upgrade_plan(ctx);
+            }
+        }
+
         Ok(())
     }
 
     fn init_genesis<DB: gears::store::database::Database>(
         &self,
-        _: &mut gears::context::init::InitContext<'_, DB, Self::StoreKey>,
+        ctx: &mut gears::context::init::InitContext<'_, DB, Self::StoreKey>,
         _: Self::Genesis,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
+        self.keeper.init_genesis(ctx);
+
         Vec::new()
     }
 
@@ -111,10 +143,16 @@ where
             QueryModuleVersionsRequest::QUERY_URL => {
                 Self::QReq::ModuleVersions(QueryModuleVersionsRequest::decode(data)?)
             }
+            QueryUpgradedConsensusStateRequest::QUERY_URL => Self::QReq::UpgradedConsensusState(
+                QueryUpgradedConsensusStateRequest::decode(data)?,
+            ),
+            QueryAuthorityRequest::QUERY_URL => {
+                Self::QReq::Authority(QueryAuthorityRequest::decode(data)?)
+            }
             _ => Err(QueryError::PathNotFound)?,
         };
 
-        Ok(ABCIHandler::typed_query(self, ctx, query).into_bytes())
+        Ok(ABCIHandler::typed_query(self, ctx, query)?.into_bytes())
     }
 
     fn begin_block<'b, DB: gears::store::database::Database>(
@@ -171,7 +209,11 @@ where
             }
 
             if !self.keeper.has_handler(&plan.name) {
-                // TODO: store info https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/upgrade/keeper/keeper.go#L375-L396
+                // Write the upgrade info to disk. Process managers like cosmovisor watch
+                // this file to switch the binary
+                if let Err(err) = self.keeper.dump_upgrade_info_to_disk(ctx.height(), &plan) {
+                    panic!("unable to write upgrade info to filesystem: {err}");
+                }
 
                 // We don't have an upgrade handler for this upgrade name, meaning this software is out of date so shutdown
                 let msg = format!(
@@ -209,6 +251,13 @@ where
             panic!("{msg}");
         }
     }
+
+    fn store_upgrades<DB: Database>(
+        &self,
+        ctx: &gears::context::simple::SimpleContext<'_, DB, Self::StoreKey>,
+    ) -> Option<(u32, gears::store::bank::multi::upgrades::StoreUpgrades)> {
+        self.keeper.store_upgrades(ctx)
+    }
 }
 
 impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, UH: UpgradeHandler, MI: ModuleInfo>
@@ -216,6 +265,24 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, UH: UpgradeHandler, MI: Mo
 where
     <M as TryFrom<Vec<u8>>>::Error: Display + Debug,
 {
+    pub fn new(keeper: UpgradeKeeper<SK, M, UH>) -> Self {
+        Self {
+            keeper,
+            _marker: PhantomData,
+        }
+    }
+
+    fn check_authority(&self, authority: &AccAddress) -> Result<(), UpgradeTxError> {
+        if self.keeper.authority() != authority {
+            Err(UpgradeTxError::Unauthorized {
+                expected: self.keeper.authority().clone(),
+                actual: authority.clone(),
+            })?
+        }
+
+        Ok(())
+    }
+
     pub fn query_plan<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> QueryCurrentPlanResponse {
         QueryCurrentPlanResponse {
             plan: self.keeper.upgrade_plan(ctx),
@@ -270,4 +337,243 @@ where
             module_versions: list,
         }
     }
+
+    pub fn query_upgraded_consensus_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryUpgradedConsensusStateRequest { last_height }: QueryUpgradedConsensusStateRequest,
+    ) -> QueryUpgradedConsensusStateResponse {
+        QueryUpgradedConsensusStateResponse {
+            upgraded_consensus_state: u32::try_from(last_height)
+                .ok()
+                .and_then(|last_height| self.keeper.upgraded_consensus_state(ctx, last_height))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, num::NonZero, path::PathBuf};
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams, GasScheduleParams},
+        context::{block::BlockContext, tx::TxContext, InfallibleContextMut},
+        derive::{ParamsKeys, StoreKeys},
+        extensions::testing::UnwrapTesting,
+        gas::metering::GasMeter,
+        store::database::MemDB,
+        tendermint::types::proto::header::Header,
+        utils::{node::build_store, tendermint::TempDir},
+    };
+
+    use crate::{
+        handler::dummy::NullUpgradeHandler,
+        keeper::{UPGRADE_INFO_FILE_NAME, VERSION_MAP_PREFIX},
+        types::{
+            plan::{Plan, PlanName},
+            UpgradeInfo,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn software_upgrade_checks_authority() {
+        let handler = handler(PathBuf::new());
+        let mut multi_store = build_store::<SubspaceKey>().to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut multi_store, &mut block_gas_meter);
+
+        let err = handler
+            .msg(
+                &mut ctx,
+                &Message::SoftwareUpgrade(MsgSoftwareUpgrade {
+                    authority: address(2),
+                    plan: plan(),
+                }),
+            )
+            .expect_err("only the authority schedules upgrades");
+        assert_eq!(err.code.get(), 1);
+        assert!(handler.keeper.upgrade_plan(&ctx).is_none());
+
+        handler
+            .msg(
+                &mut ctx,
+                &Message::SoftwareUpgrade(MsgSoftwareUpgrade {
+                    authority: address(1),
+                    plan: plan(),
+                }),
+            )
+            .unwrap_test();
+        let scheduled = handler.keeper.upgrade_plan(&ctx).unwrap_test();
+        assert_eq!(scheduled.name.as_ref(), "v2");
+    }
+
+    #[test]
+    fn cancel_upgrade_checks_authority() {
+        let handler = handler(PathBuf::new());
+        let mut multi_store = build_store::<SubspaceKey>().to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut multi_store, &mut block_gas_meter);
+        handler
+            .keeper
+            .schedule_upgrade(&mut ctx, plan(), false)
+            .unwrap_test();
+
+        let err = handler
+            .msg(
+                &mut ctx,
+                &Message::CancelUpgrade(MsgCancelUpgrade {
+                    authority: address(2),
+                }),
+            )
+            .expect_err("only the authority cancels upgrades");
+        assert_eq!(err.code.get(), 1);
+        assert!(handler.keeper.upgrade_plan(&ctx).is_some());
+
+        handler
+            .msg(
+                &mut ctx,
+                &Message::CancelUpgrade(MsgCancelUpgrade {
+                    authority: address(1),
+                }),
+            )
+            .unwrap_test();
+        assert!(handler.keeper.upgrade_plan(&ctx).is_none());
+    }
+
+    #[test]
+    fn dump_upgrade_info_to_disk_writes_info() {
+        let home = TempDir::new().unwrap_test();
+
+        handler(home.path().to_path_buf())
+            .keeper
+            .dump_upgrade_info_to_disk(11, &plan())
+            .unwrap_test();
+
+        let info: UpgradeInfo = serde_json::from_slice(
+            &std::fs::read(home.path().join("data").join(UPGRADE_INFO_FILE_NAME)).unwrap_test(),
+        )
+        .unwrap_test();
+        assert_eq!(info.name, "v2");
+        assert_eq!(info.height, 11);
+        assert_eq!(info.info, "binaries");
+    }
+
+    #[test]
+    fn dump_upgrade_info_to_disk_skips_empty_home() {
+        let data_dir = std::env::current_dir().unwrap_test().join("data");
+        let existed = data_dir.exists();
+
+        handler(PathBuf::new())
+            .keeper
+            .dump_upgrade_info_to_disk(11, &plan())
+            .unwrap_test();
+
+        assert_eq!(data_dir.exists(), existed);
+        assert!(!data_dir.join(UPGRADE_INFO_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn modules_version_skips_unknown_modules() {
+        let handler = handler(PathBuf::new());
+        let mut multi_store = build_store::<SubspaceKey>();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            Default::default(),
+        );
+        handler.keeper.init_genesis(&mut ctx);
+        ctx.infallible_store_mut(&SubspaceKey::Upgrade)
+            .prefix_store_mut(VERSION_MAP_PREFIX)
+            .set(b"removed".to_vec(), 2_u64.to_be_bytes().to_vec());
+
+        assert_eq!(
+            handler.keeper.modules_version(&ctx),
+            HashMap::from([(TestModule::Upgrade, 1)])
+        );
+    }
+
+    fn handler(
+        home: PathBuf,
+    ) -> UpgradeAbciHandler<SubspaceKey, SubspaceKey, TestModule, NullUpgradeHandler, TestModuleInfo>
+    {
+        UpgradeAbciHandler::new(UpgradeKeeper::new(
+            SubspaceKey::Upgrade,
+            [],
+            address(1),
+            home,
+        ))
+    }
+
+    fn tx_ctx<'a>(
+        multi_store: &'a mut gears::store::bank::multi::TransactionMultiBank<MemDB, SubspaceKey>,
+        block_gas_meter: &'a mut GasMeter<gears::gas::metering::kind::BlockKind>,
+    ) -> TxContext<'a, MemDB, SubspaceKey> {
+        TxContext::new(
+            multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasScheduleParams::default(),
+            GasMeter::infinite(),
+            block_gas_meter,
+            NodeOptions::default(),
+        )
+    }
+
+    fn address(byte: u8) -> AccAddress {
+        AccAddress::try_from(vec![byte; 20]).unwrap_test()
+    }
+
+    fn plan() -> Plan {
+        Plan {
+            name: PlanName::try_new("v2").unwrap_test(),
+            height: NonZero::new(10).unwrap_test(),
+            info: "binaries".to_owned(),
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct TestModuleInfo;
+
+    impl ModuleInfo for TestModuleInfo {
+        const NAME: &'static str = "upgrade";
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, strum::EnumIter)]
+    enum TestModule {
+        Upgrade,
+    }
+
+    impl TryFrom<Vec<u8>> for TestModule {
+        type Error = anyhow::Error;
+
+        fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+            match value.as_slice() {
+                b"upgrade" => Ok(TestModule::Upgrade),
+                _ => Err(anyhow::anyhow!("no such module exists")),
+            }
+        }
+    }
+
+    impl Module for TestModule {
+        fn name(&self) -> &'static str {
+            "upgrade"
+        }
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "upgrade")]
+        #[pkey(to_string = "upgrade")]
+        Upgrade,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
 }
@@ -9,6 +9,7 @@ use crate::types::query::{
 };
 
 pub mod query;
+pub mod tx;
 
 #[derive(Debug, Clone)]
 pub struct UpgradeClientHandler;
@@ -0,0 +1,58 @@
+use std::num::NonZero;
+
+use clap::{Args, Subcommand};
+use gears::types::address::AccAddress;
+
+use crate::{
+    message::Message,
+    types::{
+        plan::{Plan, PlanName},
+        tx::{MsgCancelUpgrade, MsgSoftwareUpgrade},
+    },
+};
+
+#[derive(Args, Debug, Clone)]
+pub struct UpgradeTxCli {
+    #[command(subcommand)]
+    pub command: UpgradeTxCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum UpgradeTxCommands {
+    /// Schedule an upgrade, the signer must be the upgrade authority
+    SoftwareUpgrade {
+        /// name of the upgrade plan
+        name: String,
+        /// the height at which the upgrade must happen
+        #[arg(long)]
+        upgrade_height: NonZero<u32>,
+        /// info for the upgrade plan such as new version download urls, etc.
+        #[arg(long, default_value_t)]
+        upgrade_info: String,
+    },
+    /// Cancel the current software upgrade plan, the signer must be the upgrade authority
+    CancelSoftwareUpgrade,
+}
+
+pub fn run_upgrade_tx_command(
+    args: UpgradeTxCli,
+    from_address: AccAddress,
+) -> anyhow::Result<Message> {
+    match args.command {
+        UpgradeTxCommands::SoftwareUpgrade {
+            name,
+            upgrade_height,
+            upgrade_info,
+        } => Ok(Message::SoftwareUpgrade(MsgSoftwareUpgrade {
+            authority: from_address,
+            plan: Plan {
+                name: PlanName::try_new(name)?,
+                height: upgrade_height,
+                info: upgrade_info,
+            },
+        })),
+        UpgradeTxCommands::CancelSoftwareUpgrade => Ok(Message::CancelUpgrade(MsgCancelUpgrade {
+            authority: from_address,
+        })),
+    }
+}
@@ -0,0 +1,128 @@
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::block_height,
+};
+use ibc_proto::cosmos::upgrade::v1beta1::{
+    query_server::{Query, QueryServer},
+    QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryAuthorityRequest,
+    QueryAuthorityResponse, QueryCurrentPlanRequest, QueryCurrentPlanResponse,
+    QueryModuleVersionsRequest, QueryModuleVersionsResponse, QueryUpgradedConsensusStateRequest,
+    QueryUpgradedConsensusStateResponse,
+};
+use std::marker::PhantomData;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+use crate::types::query::{UpgradeQueryRequest, UpgradeQueryResponse};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+#[derive(Debug, Default)]
+pub struct UpgradeService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+#[tonic::async_trait]
+impl<
+        QReq: Send + Sync + 'static,
+        QRes: Send + Sync + 'static,
+        QH: NodeQueryHandler<QReq, QRes>,
+    > Query for UpgradeService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse, Error = Status>,
+{
+    async fn current_plan(
+        &self,
+        request: Request<QueryCurrentPlanRequest>,
+    ) -> Result<Response<QueryCurrentPlanResponse>, Status> {
+        info!("Received a gRPC request upgrade::current_plan");
+        let height = block_height(&request)?;
+        let req = UpgradeQueryRequest::Plan(request.into_inner().try_into()?);
+        let response: UpgradeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
+
+        if let UpgradeQueryResponse::Plan(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn applied_plan(
+        &self,
+        request: Request<QueryAppliedPlanRequest>,
+    ) -> Result<Response<QueryAppliedPlanResponse>, Status> {
+        info!("Received a gRPC request upgrade::applied_plan");
+        let height = block_height(&request)?;
+        let req = UpgradeQueryRequest::Applied(request.into_inner().try_into()?);
+        let response: UpgradeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
+
+        if let UpgradeQueryResponse::Applied(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn upgraded_consensus_state(
+        &self,
+        request: Request<QueryUpgradedConsensusStateRequest>,
+    ) -> Result<Response<QueryUpgradedConsensusStateResponse>, Status> {
+        info!("Received a gRPC request upgrade::upgraded_consensus_state");
+        let height = block_height(&request)?;
+        let req = UpgradeQueryRequest::UpgradedConsensusState(request.into_inner().try_into()?);
+        let response: UpgradeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
+
+        if let UpgradeQueryResponse::UpgradedConsensusState(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn module_versions(
+        &self,
+        request: Request<QueryModuleVersionsRequest>,
+    ) -> Result<Response<QueryModuleVersionsResponse>, Status> {
+        info!("Received a gRPC request upgrade::module_versions");
+        let height = block_height(&request)?;
+        let req = UpgradeQueryRequest::ModuleVersions(request.into_inner().try_into()?);
+        let response: UpgradeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
+
+        if let UpgradeQueryResponse::ModuleVersions(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+
+    async fn authority(
+        &self,
+        request: Request<QueryAuthorityRequest>,
+    ) -> Result<Response<QueryAuthorityResponse>, Status> {
+        info!("Received a gRPC request upgrade::authority");
+        let height = block_height(&request)?;
+        let req = UpgradeQueryRequest::Authority(request.into_inner().try_into()?);
+        let response: UpgradeQueryResponse = self.app.typed_query_at(req, height)?.try_into()?;
+
+        if let UpgradeQueryResponse::Authority(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> QueryServer<UpgradeService<QH, QReq, QRes>>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<UpgradeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    let upgrade_service = UpgradeService {
+        app,
+        _phantom: Default::default(),
+    };
+    QueryServer::new(upgrade_service)
+}
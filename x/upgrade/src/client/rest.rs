@@ -0,0 +1,109 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::get,
+    Json, Router,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, BlockHeight, RestState},
+};
+use serde::Deserialize;
+
+use crate::types::query::{
+    QueryAppliedPlanRequest, QueryAuthorityRequest, QueryCurrentPlanRequest,
+    QueryModuleVersionsRequest, QueryUpgradedConsensusStateRequest, UpgradeQueryRequest,
+    UpgradeQueryResponse,
+};
+
+pub async fn current_plan<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = UpgradeQueryRequest::Plan(QueryCurrentPlanRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn applied_plan<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(name): Path<String>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = UpgradeQueryRequest::Applied(QueryAppliedPlanRequest { name });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+#[derive(Deserialize)]
+pub struct ModuleVersionsQuery {
+    /// all modules are returned if the name is not set
+    #[serde(default)]
+    module_name: String,
+}
+
+pub async fn module_versions<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Query(ModuleVersionsQuery { module_name }): Query<ModuleVersionsQuery>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = UpgradeQueryRequest::ModuleVersions(QueryModuleVersionsRequest { module_name });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn upgraded_consensus_state<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(last_height): Path<i64>,
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = UpgradeQueryRequest::UpgradedConsensusState(QueryUpgradedConsensusStateRequest {
+        last_height,
+    });
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub async fn authority<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    BlockHeight(height): BlockHeight,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = UpgradeQueryRequest::Authority(QueryAuthorityRequest {});
+    let res = rest_state.app.typed_query_at(req, height)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<UpgradeQueryRequest>,
+    QRes: QueryResponse + TryInto<UpgradeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/current_plan", get(current_plan))
+        .route("/v1beta1/applied_plan/:name", get(applied_plan))
+        .route("/v1beta1/module_versions", get(module_versions))
+        .route(
+            "/v1beta1/upgraded_consensus_state/:last_height",
+            get(upgraded_consensus_state),
+        )
+        .route("/v1beta1/authority", get(authority))
+}
@@ -0,0 +1,26 @@
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    types::address::AccAddress,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpgradeTxError {
+    #[error("expected {expected} as authority, got {actual}")]
+    Unauthorized {
+        expected: AccAddress,
+        actual: AccAddress,
+    },
+    #[error("{0}")]
+    Schedule(#[from] anyhow::Error),
+}
+
+impl UpgradeTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        let code = match &self {
+            UpgradeTxError::Unauthorized { .. } => nz::u16!(1),
+            UpgradeTxError::Schedule(_) => nz::u16!(2),
+        };
+
+        TxError::new::<MI>(self.to_string(), code)
+    }
+}
@@ -1,18 +1,26 @@
 use std::{collections::HashMap, fmt::Debug};
 
-use gears::context::InfallibleContextMut;
+use gears::{context::InfallibleContextMut, store::bank::multi::upgrades::StoreUpgrades};
 
-use crate::types::plan::Plan;
+use crate::{types::plan::Plan, Module};
 
 pub trait UpgradeHandler: Debug + Clone + Send + Sync + 'static {
     fn name(&self) -> &'static str;
 
-    fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M>(
+    /// Migrate the state to the upgraded binary and return the new module versions,
+    /// usually through [crate::migrations::Migrator::run_migrations]
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M: Module>(
         &self,
         ctx: &mut CTX,
         plan: &Plan,
         versions: impl IntoIterator<Item = (M, u64)>,
     ) -> anyhow::Result<HashMap<M, u64>>;
+
+    /// Stores added, renamed or deleted by the upgrade, applied when the new binary
+    /// loads right before the plan height
+    fn store_upgrades(&self) -> StoreUpgrades {
+        StoreUpgrades::default()
+    }
 }
 
 pub mod dummy {
@@ -27,7 +35,7 @@ pub mod dummy {
             unreachable!()
         }
 
-        fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M>(
+        fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M: Module>(
             &self,
             _ctx: &mut CTX,
             _plan: &Plan,
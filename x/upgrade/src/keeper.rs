@@ -2,19 +2,21 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Display},
     marker::PhantomData,
+    path::PathBuf,
 };
 
 use gears::{
     context::{InfallibleContext, InfallibleContextMut},
     core::Protobuf,
     extensions::corruption::UnwrapCorrupt,
-    store::{database::Database, StoreKey},
+    store::{bank::multi::upgrades::StoreUpgrades, database::Database, StoreKey},
+    types::address::AccAddress,
 };
 use prost::bytes::Bytes;
 
 use crate::{
     handler::UpgradeHandler,
-    types::{plan::Plan, Upgrade},
+    types::{plan::Plan, Upgrade, UpgradeInfo},
     Module,
 };
 
@@ -25,10 +27,13 @@ const PLAN_PREFIX: [u8; 1] = [0x0];
 /// is a prefix for to look up completed upgrade plan by name
 const DONE_PREFIX: [u8; 1] = [0x1];
 /// is a prefix to look up module names (key) and versions (value)
-const VERSION_MAP_PREFIX: [u8; 1] = [0x2];
+pub(crate) const VERSION_MAP_PREFIX: [u8; 1] = [0x2];
 /// is a prefix to look up Protocol Version
 const PROTOCOL_VERSION_BYTE_PREFIX: [u8; 1] = [0x3];
 
+/// is the file in the data directory of the node with the plan which needs a new binary
+pub const UPGRADE_INFO_FILE_NAME: &str = "upgrade-info.json";
+
 /// is the key under which upgraded ibc state is stored in the upgrade store
 const UPGRADED_IBC_STATE_KEY: &[u8] = "upgradedIBCState".as_bytes();
 /// is the sub-key under which upgraded client state will be stored
@@ -59,15 +64,25 @@ pub struct UpgradeKeeper<SK, M, UH> {
     store_key: SK,
     upgrade_handlers: HashMap<&'static str, UH>,
     skip_heights: HashSet<u32>, // TODO: source https://github.com/cosmos/gaia/blob/189b57be735d64d0dbf0945717b49017a1beb11e/cmd/gaiad/cmd/root.go#L192-L195
+    /// the only address allowed to schedule and cancel upgrades with messages
+    authority: AccAddress,
+    home: PathBuf,
     _modules_marker: PhantomData<M>,
 }
 
 impl<SK, M, UH: strum::IntoEnumIterator + UpgradeHandler> UpgradeKeeper<SK, M, UH> {
-    pub fn new(store_key: SK, skip_heights: impl IntoIterator<Item = u32>) -> Self {
+    pub fn new(
+        store_key: SK,
+        skip_heights: impl IntoIterator<Item = u32>,
+        authority: AccAddress,
+        home: PathBuf,
+    ) -> Self {
         Self {
             store_key,
             upgrade_handlers: UH::iter().map(|this| (this.name(), this)).collect(),
             skip_heights: skip_heights.into_iter().collect(),
+            authority,
+            home,
             _modules_marker: PhantomData,
         }
     }
@@ -78,11 +93,15 @@ impl<SK, M, UH: UpgradeHandler> UpgradeKeeper<SK, M, UH> {
         store_key: SK,
         upgrade_handlers: impl IntoIterator<Item = (&'static str, UH)>,
         skip_heights: impl IntoIterator<Item = u32>,
+        authority: AccAddress,
+        home: PathBuf,
     ) -> Self {
         Self {
             store_key,
             upgrade_handlers: upgrade_handlers.into_iter().collect(),
             skip_heights: skip_heights.into_iter().collect(),
+            authority,
+            home,
             _modules_marker: PhantomData,
         }
     }
@@ -195,6 +214,17 @@ where
             .map(|this| Protobuf::decode::<Bytes>(this.into()).unwrap_or_corrupt())
     }
 
+    /// Height of the scheduled plan with the store upgrades of its handler
+    pub fn store_upgrades<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Option<(u32, StoreUpgrades)> {
+        let plan = self.upgrade_plan(ctx)?;
+        let handler = self.upgrade_handlers.get(plan.name.as_ref())?;
+
+        Some((plan.height.get(), handler.store_upgrades()))
+    }
+
     pub fn delete_upgrade_plan<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -209,6 +239,16 @@ where
             .is_some()
     }
 
+    /// Consensus state committed to by the chain for the upgrade after `last_height`
+    pub fn upgraded_consensus_state<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        last_height: u32,
+    ) -> Option<Vec<u8>> {
+        ctx.infallible_store(&self.store_key)
+            .get(&upgraded_const_state_key(last_height))
+    }
+
     fn clear_ibc_state<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -249,6 +289,20 @@ where
         last_upgrade
     }
 
+    /// Store the consensus version of every module, so modules added by a later upgrade
+    /// are the ones missing from the version map
+    pub fn init_genesis<DB: Database, CTX: InfallibleContextMut<DB, SK>>(&self, ctx: &mut CTX) {
+        self.set_modules_version(
+            ctx,
+            M::iter().map(|module| {
+                let version = module.consensus_version();
+                (module, version)
+            }),
+        );
+    }
+
+    /// Consensus versions of the stored modules. Versions saved for modules which are no
+    /// longer part of the app, e.g. removed by an upgrade, are skipped
     pub fn modules_version<DB: Database, CTX: InfallibleContext<DB, SK>>(
         &self,
         ctx: &CTX,
@@ -256,11 +310,23 @@ where
         ctx.infallible_store(&self.store_key)
             .prefix_store(VERSION_MAP_PREFIX)
             .into_range(..)
-            .map(|(key, value)| {
-                (
-                    M::try_from(key.as_slice().to_vec()).expect("unknown module version saved"),
+            .filter_map(|(key, value)| {
+                let module = match M::try_from(key.as_slice().to_vec()) {
+                    Ok(module) => module,
+                    Err(_) => {
+                        tracing::warn!(
+                            "skip version of unknown module `{}`",
+                            String::from_utf8_lossy(&key)
+                        );
+
+                        return None;
+                    }
+                };
+
+                Some((
+                    module,
                     u64::from_be_bytes(value.as_slice().try_into().ok().unwrap_or_corrupt()),
-                )
+                ))
             })
             .collect::<HashMap<_, _>>()
     }
@@ -272,7 +338,7 @@ where
     ) {
         let modules = modules.into_iter().collect::<HashMap<_, _>>();
 
-        if modules.is_empty() {
+        if !modules.is_empty() {
             let mut store = ctx
                 .infallible_store_mut(&self.store_key)
                 .prefix_store_mut(VERSION_MAP_PREFIX);
@@ -302,6 +368,38 @@ where
             .set(PROTOCOL_VERSION_BYTE_PREFIX, version.to_be_bytes());
     }
 
+    pub fn authority(&self) -> &AccAddress {
+        &self.authority
+    }
+
+    /// Writes the plan which needs a new binary to `data/upgrade-info.json` in the home
+    /// directory, in the format expected by cosmovisor. Nothing is written when the keeper
+    /// has no home directory, e.g. in an in-memory node
+    pub fn dump_upgrade_info_to_disk(&self, height: u32, plan: &Plan) -> std::io::Result<()> {
+        if self.home.as_os_str().is_empty() {
+            tracing::warn!(
+                "home directory is not set, skip writing {UPGRADE_INFO_FILE_NAME} for upgrade `{}`",
+                plan.name.as_ref()
+            );
+
+            return Ok(());
+        }
+
+        let data_dir = self.home.join("data");
+        std::fs::create_dir_all(&data_dir)?;
+
+        let info = UpgradeInfo {
+            name: plan.name.as_ref().to_owned(),
+            height,
+            info: plan.info.clone(),
+        };
+
+        std::fs::write(
+            data_dir.join(UPGRADE_INFO_FILE_NAME),
+            serde_json::to_vec(&info)?,
+        )
+    }
+
     pub fn is_skip_height(&self, height: u32) -> bool {
         self.skip_heights.contains(&height)
     }
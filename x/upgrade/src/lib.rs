@@ -1,13 +1,28 @@
 pub mod abci_handler;
 pub mod client;
+pub mod errors;
 mod handler;
 pub mod keeper;
+pub mod message;
+pub mod migrations;
 pub mod types;
 
 pub use crate::handler::*;
 
 pub trait Module:
-    Clone + Send + Sync + TryFrom<Vec<u8>> + std::cmp::Eq + std::hash::Hash + 'static
+    Clone
+    + Send
+    + Sync
+    + TryFrom<Vec<u8>>
+    + std::cmp::Eq
+    + std::hash::Hash
+    + strum::IntoEnumIterator
+    + 'static
 {
     fn name(&self) -> &'static str;
+
+    /// Version of the module state, increased by every change which needs a migration
+    fn consensus_version(&self) -> u64 {
+        migrations::INITIAL_CONSENSUS_VERSION
+    }
 }
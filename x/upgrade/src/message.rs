@@ -0,0 +1,28 @@
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
+use serde::Serialize;
+
+use crate::types::tx::{MsgCancelUpgrade, MsgSoftwareUpgrade};
+
+#[derive(Debug, Clone, Serialize, AppMessage)]
+#[serde(tag = "@type")]
+pub enum Message {
+    #[serde(rename = "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade")]
+    #[msg(url(path = MsgSoftwareUpgrade::TYPE_URL))]
+    SoftwareUpgrade(MsgSoftwareUpgrade),
+    #[serde(rename = "/cosmos.upgrade.v1beta1.MsgCancelUpgrade")]
+    #[msg(url(path = MsgCancelUpgrade::TYPE_URL))]
+    CancelUpgrade(MsgCancelUpgrade),
+}
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Err(RenderError::NotImplemented)
+    }
+}
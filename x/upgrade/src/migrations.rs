@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use crate::Module;
+
+/// Version of a module state before its first migration. It's the version of every module
+/// of a chain which started before versions were stored at genesis.
+pub const INITIAL_CONSENSUS_VERSION: u64 = 1;
+
+type Migration<'a, CTX> = Box<dyn Fn(&mut CTX) -> anyhow::Result<()> + 'a>;
+
+/// Registry of the state migrations of modules, each one moves the state of a module from
+/// a consensus version to the next one.
+///
+/// It's built in [crate::UpgradeHandler::handle] where the context type is known, e.g.
+/// ```ignore
+/// let mut migrator = Migrator::new();
+/// migrator.register(Modules::Bank, 1, |ctx| bank_keeper.migrate_v1_to_v2(ctx))?;
+/// migrator.register_init_genesis(Modules::Feemarket, |ctx| feemarket.init_default(ctx))?;
+/// migrator.run_migrations(ctx, versions)
+/// ```
+pub struct Migrator<'a, M, CTX> {
+    migrations: HashMap<(M, u64), Migration<'a, CTX>>,
+    /// Genesis of the modules added by the upgrade
+    init_genesis: HashMap<M, Migration<'a, CTX>>,
+}
+
+impl<M, CTX> std::fmt::Debug for Migrator<'_, M, CTX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrator")
+            .field("migrations", &self.migrations.len())
+            .field("init_genesis", &self.init_genesis.len())
+            .finish()
+    }
+}
+
+impl<M, CTX> Default for Migrator<'_, M, CTX> {
+    fn default() -> Self {
+        Self {
+            migrations: HashMap::new(),
+            init_genesis: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, M: Module, CTX> Migrator<'a, M, CTX> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the migration of `module` from `from_version` to `from_version + 1`
+    pub fn register(
+        &mut self,
+        module: M,
+        from_version: u64,
+        migration: impl Fn(&mut CTX) -> anyhow::Result<()> + 'a,
+    ) -> anyhow::Result<()> {
+        if self
+            .migrations
+            .contains_key(&(module.clone(), from_version))
+        {
+            Err(anyhow::anyhow!(
+                "migration of module {} from version {from_version} is already registered",
+                module.name()
+            ))?
+        }
+
+        self.migrations
+            .insert((module, from_version), Box::new(migration));
+
+        Ok(())
+    }
+
+    /// Register the genesis of `module` added by the upgrade. It runs instead of the
+    /// migrations of the module, which then starts at its [Module::consensus_version].
+    pub fn register_init_genesis(
+        &mut self,
+        module: M,
+        init_genesis: impl Fn(&mut CTX) -> anyhow::Result<()> + 'a,
+    ) -> anyhow::Result<()> {
+        if self.init_genesis.contains_key(&module) {
+            Err(anyhow::anyhow!(
+                "genesis of module {} is already registered",
+                module.name()
+            ))?
+        }
+
+        self.init_genesis.insert(module, Box::new(init_genesis));
+
+        Ok(())
+    }
+
+    /// Run the migrations of every module from its version in `from_versions` to its
+    /// [Module::consensus_version] and return the new version map.
+    ///
+    /// A module missing from a non empty `from_versions` is added by the upgrade, its
+    /// registered genesis runs instead. An empty `from_versions` comes from a chain which
+    /// doesn't store versions, every module is migrated from [INITIAL_CONSENSUS_VERSION].
+    pub fn run_migrations(
+        &self,
+        ctx: &mut CTX,
+        from_versions: impl IntoIterator<Item = (M, u64)>,
+    ) -> anyhow::Result<HashMap<M, u64>> {
+        let from_versions = from_versions.into_iter().collect::<HashMap<_, _>>();
+
+        let mut versions = HashMap::new();
+        for module in M::iter() {
+            let to_version = module.consensus_version();
+
+            let from_version = match from_versions.get(&module) {
+                Some(from_version) => *from_version,
+                None if from_versions.is_empty() => INITIAL_CONSENSUS_VERSION,
+                None => {
+                    let init_genesis = self.init_genesis.get(&module).ok_or(anyhow::anyhow!(
+                        "module {} is added by the upgrade but its genesis isn't registered",
+                        module.name()
+                    ))?;
+
+                    init_genesis(ctx)?;
+                    versions.insert(module, to_version);
+
+                    continue;
+                }
+            };
+
+            if from_version > to_version {
+                Err(anyhow::anyhow!(
+                    "module {} can't be downgraded from version {from_version} to {to_version}",
+                    module.name()
+                ))?
+            }
+
+            for version in from_version..to_version {
+                let migration =
+                    self.migrations
+                        .get(&(module.clone(), version))
+                        .ok_or(anyhow::anyhow!(
+                            "no migration of module {} from version {version} is registered",
+                            module.name()
+                        ))?;
+
+                migration(ctx)?;
+            }
+
+            versions.insert(module, to_version);
+        }
+
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, strum::EnumIter)]
+    enum TestModule {
+        Bank,
+        Staking,
+        Added,
+    }
+
+    impl TryFrom<Vec<u8>> for TestModule {
+        type Error = anyhow::Error;
+
+        fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+            TestModule::iter()
+                .find(|module| module.name().as_bytes() == value)
+                .ok_or(anyhow::anyhow!("no such module exists"))
+        }
+    }
+
+    impl Module for TestModule {
+        fn name(&self) -> &'static str {
+            match self {
+                TestModule::Bank => "bank",
+                TestModule::Staking => "staking",
+                TestModule::Added => "added",
+            }
+        }
+
+        fn consensus_version(&self) -> u64 {
+            match self {
+                TestModule::Bank => 3,
+                TestModule::Staking => 2,
+                TestModule::Added => 2,
+            }
+        }
+    }
+
+    /// Migrator which logs every migration it runs in the context
+    fn migrator<'a>() -> Migrator<'a, TestModule, Vec<String>> {
+        let mut migrator = Migrator::new();
+        for (module, from_version) in [
+            (TestModule::Bank, 2),
+            (TestModule::Staking, 1),
+            (TestModule::Bank, 1),
+            (TestModule::Added, 1),
+        ] {
+            let log = format!("{} {from_version}", module.name());
+            migrator
+                .register(module, from_version, move |ctx: &mut Vec<String>| {
+                    ctx.push(log.clone());
+                    Ok(())
+                })
+                .expect("migrations are unique");
+        }
+        migrator
+            .register_init_genesis(TestModule::Added, |ctx| {
+                ctx.push("added genesis".to_owned());
+                Ok(())
+            })
+            .expect("genesis is unique");
+
+        migrator
+    }
+
+    fn expected_versions() -> HashMap<TestModule, u64> {
+        TestModule::iter()
+            .map(|module| {
+                let version = module.consensus_version();
+                (module, version)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn run_migrations_runs_versions_in_order() {
+        let mut ctx = Vec::new();
+
+        let versions = migrator()
+            .run_migrations(
+                &mut ctx,
+                [
+                    (TestModule::Bank, 1),
+                    (TestModule::Staking, 1),
+                    (TestModule::Added, 2),
+                ],
+            )
+            .expect("every migration is registered");
+
+        assert_eq!(ctx, ["bank 1", "bank 2", "staking 1"]);
+        assert_eq!(versions, expected_versions());
+    }
+
+    #[test]
+    fn run_migrations_skips_modules_at_their_version() {
+        let mut ctx = Vec::new();
+
+        let versions = migrator()
+            .run_migrations(
+                &mut ctx,
+                [
+                    (TestModule::Bank, 2),
+                    (TestModule::Staking, 2),
+                    (TestModule::Added, 2),
+                ],
+            )
+            .expect("every migration is registered");
+
+        assert_eq!(ctx, ["bank 2"]);
+        assert_eq!(versions, expected_versions());
+    }
+
+    #[test]
+    fn run_migrations_inits_added_modules() {
+        let mut ctx = Vec::new();
+
+        let versions = migrator()
+            .run_migrations(&mut ctx, [(TestModule::Bank, 3), (TestModule::Staking, 2)])
+            .expect("genesis of the added module is registered");
+
+        assert_eq!(ctx, ["added genesis"]);
+        assert_eq!(versions, expected_versions());
+    }
+
+    #[test]
+    fn run_migrations_rejects_added_module_without_genesis() {
+        let mut migrator = Migrator::new();
+        migrator
+            .register(TestModule::Bank, 2, |_: &mut Vec<String>| Ok(()))
+            .expect("migration is unique");
+
+        let result = migrator.run_migrations(
+            &mut Vec::new(),
+            [(TestModule::Bank, 2), (TestModule::Staking, 2)],
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn run_migrations_migrates_all_modules_without_versions() {
+        let mut ctx = Vec::new();
+
+        let versions = migrator()
+            .run_migrations(&mut ctx, [])
+            .expect("every migration is registered");
+
+        assert_eq!(ctx, ["bank 1", "bank 2", "staking 1", "added 1"]);
+        assert_eq!(versions, expected_versions());
+    }
+
+    #[test]
+    fn run_migrations_rejects_missing_migration_and_downgrade() {
+        let migrator = Migrator::new();
+        let result = migrator.run_migrations(
+            &mut Vec::<String>::new(),
+            [
+                (TestModule::Bank, 1),
+                (TestModule::Staking, 2),
+                (TestModule::Added, 2),
+            ],
+        );
+        assert!(result.is_err());
+
+        let result = migrator.run_migrations(
+            &mut Vec::<String>::new(),
+            [
+                (TestModule::Bank, 4),
+                (TestModule::Staking, 2),
+                (TestModule::Added, 2),
+            ],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn register_rejects_duplicates() {
+        let mut migrator = migrator();
+
+        assert!(migrator.register(TestModule::Bank, 1, |_| Ok(())).is_err());
+        assert!(migrator
+            .register_init_genesis(TestModule::Added, |_| Ok(()))
+            .is_err());
+    }
+}
@@ -1,5 +1,15 @@
 pub mod plan;
 pub mod query;
+pub mod tx;
+
+/// Information about a plan which needs a new binary, written on disk for process managers
+/// such as cosmovisor
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpgradeInfo {
+    pub name: String,
+    pub height: u32,
+    pub info: String,
+}
 
 #[derive(Debug, Clone)]
 pub struct Upgrade {
@@ -1,6 +1,7 @@
 use gears::{
     baseapp::QueryRequest,
     derive::{Protobuf, Query},
+    types::address::AccAddress,
 };
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,8 @@ pub enum UpgradeQueryRequest {
     Plan(QueryCurrentPlanRequest),
     Applied(QueryAppliedPlanRequest),
     ModuleVersions(QueryModuleVersionsRequest),
+    UpgradedConsensusState(QueryUpgradedConsensusStateRequest),
+    Authority(QueryAuthorityRequest),
 }
 
 impl QueryRequest for UpgradeQueryRequest {
@@ -20,25 +23,28 @@ impl QueryRequest for UpgradeQueryRequest {
 }
 
 #[derive(Debug, Clone, Query, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
 pub enum UpgradeQueryResponse {
     Plan(QueryCurrentPlanResponse),
     Applied(QueryAppliedPlanResponse),
     ModuleVersions(QueryModuleVersionsResponse),
+    UpgradedConsensusState(QueryUpgradedConsensusStateResponse),
+    Authority(QueryAuthorityResponse),
 }
 
 mod inner {
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryAppliedPlanRequest;
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryAppliedPlanResponse;
+    pub use ibc_proto::cosmos::upgrade::v1beta1::QueryAuthorityRequest;
+    pub use ibc_proto::cosmos::upgrade::v1beta1::QueryAuthorityResponse;
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryCurrentPlanRequest;
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryCurrentPlanResponse;
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryModuleVersionsRequest;
     pub use ibc_proto::cosmos::upgrade::v1beta1::QueryModuleVersionsResponse;
-
-    /*
-       NOTE: these are deprecated
-       pub use ibc_proto::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest;
-       pub use ibc_proto::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse;
-    */
+    // deprecated in the sdk in favour of the ibc client upgrade queries, kept for relayers
+    // which still use it
+    pub use ibc_proto::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest;
+    pub use ibc_proto::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse;
 }
 
 #[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
@@ -79,3 +85,30 @@ pub struct QueryModuleVersionsResponse {
     #[proto(repeated)]
     pub module_versions: Vec<ModuleVersion>,
 }
+
+#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[proto(raw = "inner::QueryUpgradedConsensusStateRequest")]
+#[query(url = "/cosmos.upgrade.v1beta1.QueryUpgradedConsensusStateRequest")]
+pub struct QueryUpgradedConsensusStateRequest {
+    /// height of the last block before the upgrade
+    pub last_height: i64,
+}
+
+#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[proto(raw = "inner::QueryUpgradedConsensusStateResponse")]
+pub struct QueryUpgradedConsensusStateResponse {
+    /// encoded consensus state which the chain commits to after the upgrade, empty when
+    /// there is none
+    pub upgraded_consensus_state: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[proto(raw = "inner::QueryAuthorityRequest")]
+#[query(url = "/cosmos.upgrade.v1beta1.QueryAuthorityRequest")]
+pub struct QueryAuthorityRequest {}
+
+#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[proto(raw = "inner::QueryAuthorityResponse")]
+pub struct QueryAuthorityResponse {
+    pub address: AccAddress,
+}
@@ -0,0 +1,38 @@
+use gears::{
+    derive::{AppMessage, Protobuf},
+    types::address::AccAddress,
+};
+use serde::{Deserialize, Serialize};
+
+use super::plan::Plan;
+
+mod inner {
+    pub use ibc_proto::cosmos::upgrade::v1beta1::MsgCancelUpgrade;
+    pub use ibc_proto::cosmos::upgrade::v1beta1::MsgSoftwareUpgrade;
+}
+
+/// MsgSoftwareUpgrade is the authority-based message to schedule an upgrade plan
+#[derive(Debug, Clone, Serialize, Deserialize, Protobuf, AppMessage)]
+#[proto(raw = "inner::MsgSoftwareUpgrade")]
+#[msg(
+    url = "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade",
+    amino_url = "cosmos-sdk/MsgSoftwareUpgrade"
+)]
+pub struct MsgSoftwareUpgrade {
+    #[msg(signer)]
+    pub authority: AccAddress,
+    #[proto(optional)]
+    pub plan: Plan,
+}
+
+/// MsgCancelUpgrade is the authority-based message to cancel the scheduled upgrade plan
+#[derive(Debug, Clone, Serialize, Deserialize, Protobuf, AppMessage)]
+#[proto(raw = "inner::MsgCancelUpgrade")]
+#[msg(
+    url = "/cosmos.upgrade.v1beta1.MsgCancelUpgrade",
+    amino_url = "cosmos-sdk/MsgCancelUpgrade"
+)]
+pub struct MsgCancelUpgrade {
+    #[msg(signer)]
+    pub authority: AccAddress,
+}